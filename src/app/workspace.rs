@@ -1,26 +1,54 @@
 use std::collections::HashMap;
 use std::fs;
-use std::path::PathBuf;
-use std::time::Instant;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
 
 use gpui::{
-    actions, div, prelude::*, px, svg, App, AsyncApp, Context, Entity, FocusHandle, Focusable,
-    InteractiveElement, IntoElement, KeyBinding, MouseButton, ParentElement, Render, SharedString,
-    Styled, WeakEntity, Window,
+    actions, anchored, deferred, div, prelude::*, px, svg, uniform_list, AnyElement, App, AsyncApp, Context, Corner,
+    Entity, FocusHandle, Focusable,
+    InteractiveElement, IntoElement, KeyBinding, KeyDownEvent, MouseButton, MouseDownEvent, ParentElement, Render,
+    SharedString, Styled, Timer, WeakEntity, Window,
 };
 
-use crate::io::{SortKey, SortOrder};
+use crate::io::{detect_platform, SortKey, SortOrder, TraversalSummary, Watcher};
 use crate::models::{
-    current_theme, theme_colors, Device, DeviceId, FileSystem, GlobalSettings, GridConfig,
-    IconCache, PlatformAdapter, SearchEngine, ShareManager, ThemeId, ViewMode,
+    apply_pins, apply_tag_diff, context_selection, create_from_template, current_theme, effective_show_hidden, move_path_to_trash, paste_target, resolve_view_mode, theme_colors,
+    manifest_algorithm_hint, parse_manifest, template_destination, unique_destination, validate_file_name,
+    create_new_entry, creation_error_message, move_into_folder, NewEntryKind,
+    NEW_FOLDER_WITH_ITEMS_NAME,
+    verify_manifest, ArchiveJobEvent, ArchiveJobKind, ArchiveJobs, ArchiveProgress, AutomationAction,
+    BulkTagEdit, FileTemplate, LaunchTarget, TagManager, VerifyStatus,
+    archive_output_path, default_archive_name, ArchiveFormat, CompressOptions, COMPRESS_FORMATS,
+    find_duplicates, DuplicateGroup, DuplicateScanProgress,
+    restore_scroll_offset, ScrollGeometry, ScrollMemory, ColumnWidthRules, ColumnWidths,
+    LoadOperation, LoadSkeleton, MountedImages, VisibleColumns, guard_deletion, DeletionGuard,
+    ToolbarButton, ToolbarLayout, ContextMenuEntryId, ContextMenuRegistry, PaneSizes,
+    get_disk_space, volume_id, VolumeId, VolumeSpace,
+    cycle_focus_region, resolve_focus_region, FocusRegion, RegionVisibility,
+    preset_conflict_resolution, ConflictStrategy,
+    back_menu_entries, forward_menu_entries, jump_back, jump_forward, mouse_history_step, record_visit, HistoryMenuEntry, HistoryStep,
+    HISTORY_MENU_LIMIT, QuickDestination,
+    BackgroundActivity, ClipboardMirror, Device,
+    drain_batch, DeviceId, DualPaneLayout, FileOperationExecutor, FileSystem, FlattenSession, FolderViewRules, FsEvent,
+    GitRepoInfo, PaneSide, TraversalBatch, load_directory_sync,
+    GitStatusBadge, GitStatusCache, GlobalSettings, GridConfig, IconCache,
+    MotionPreference, OperationId, OperationProgress, OperationQueue, OperationType,
+    OverwriteSummary, PasteTarget, detect_executable, ExecutableAction, ExecutableKind, ExecutablePrompt,
+    PlatformAdapter, PreviewPaneState, PreviewService, PreviewTab, PreviewVisibility, GroupedResults, GroupedRow,
+    ResultGrouping, SearchEngine, ShareManager,
+    SharedClipboard, SortState, TabDropAction, ThemeId, ViewMode, COMPLETED_OPERATION_TTL,
 };
 #[cfg(not(test))]
 use crate::models::WindowManager;
 use crate::views::{
-    create_symbolic_link, ContextMenuAction, FileList, FileListView, FormatDialog, GridView,
-    GridViewComponent, PreviewView, QuickLookView, SearchInputView, SidebarView, SmartFolderDialog,
+    create_symbolic_link, focus_ring, restore_focus, TooltipLabel, BulkTagDialog, BulkTagDialogAction, ChecksumVerifyAction,
+    ChecksumVerifyPanel, CommandPaletteView, ContextMenuAction, DuplicateFinderAction,
+    DuplicateFinderPanel, DualPaneAction, DualPaneView, FileList, FileListView,
+    FocusTrap, FormatDialog, GridView, GridViewComponent, IconControl, PaletteCommand, PreviewView, ProgressPanelAction, ProgressPanelView, QuickLookView, SearchInputView, SidebarView, SmartFolderDialog,
     SmartFolderDialogAction, StatusBarAction, StatusBarView, SymlinkDialog, SymlinkDialogAction,
-    TerminalView, ThemePickerView, ToastManager, ToolAction, ConflictDialog, ConflictInfo,
+    TerminalView, ThemePickerView, ToastManager, ToolAction, TrapKey, UnreadableItem, ConflictDialog,
+    ConflictInfo, ToggleDualPane, transfer_summary,
 };
 use crate::models::ConflictResolution;
 use adabraka_ui::components::input::{Input, InputEvent, InputState};
@@ -36,9 +64,66 @@ actions!(
         FocusSearch,
         NewWindow,
         QuickLookToggle,
+        RevealInSidebar,
+        ToggleAutoReveal,
+        CopySelectedPath,
+        IncreaseUiScale,
+        DecreaseUiScale,
+        ResetUiScale,
+        CycleMotionPreference,
+        CycleIconPack,
+        CycleNameTint,
+        ToggleTrashLocation,
+        ToggleTerminalPalette,
+        ToggleClickBehavior,
+        CycleDateFormat,
+        CycleConflictStrategy,
+        ToggleFlattenView,
+        TogglePreviewPane,
+        ToggleCommandPalette,
+        FocusNextRegion,
+        FocusPreviousRegion,
+        NewFolderWithSelection,
+        GoToParent,
+        GoToHome,
+        GoToRoot,
     ]
 );
 
+const CONFLICT_FOCUS_SKIP: usize = 0;
+const CONFLICT_FOCUS_KEEP_BOTH: usize = 1;
+const CONFLICT_FOCUS_REPLACE: usize = 2;
+const CONFLICT_FOCUS_CANCEL: usize = 3;
+const CONFLICT_FOCUS_COUNT: usize = 4;
+
+const PASTE_PROGRESS_INTERVAL: Duration = Duration::from_millis(200);
+const ARCHIVE_PROGRESS_INTERVAL: Duration = Duration::from_millis(200);
+const WATCH_POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+fn copy_with_progress(src: &Path, dst: &Path, on_bytes: &mut dyn FnMut(u64)) -> std::io::Result<()> {
+    if src.is_dir() {
+        fs::create_dir_all(dst)?;
+        for entry in fs::read_dir(src)? {
+            let entry = entry?;
+            copy_with_progress(&entry.path(), &dst.join(entry.file_name()), on_bytes)?;
+        }
+        return Ok(());
+    }
+
+    let mut reader = fs::File::open(src)?;
+    let mut writer = fs::File::create(dst)?;
+    let mut buffer = vec![0u8; 256 * 1024];
+    loop {
+        let read = reader.read(&mut buffer)?;
+        if read == 0 {
+            break;
+        }
+        writer.write_all(&buffer[..read])?;
+        on_bytes(read as u64);
+    }
+    writer.set_permissions(reader.metadata()?.permissions())
+}
+
 fn copy_dir_recursive_async(src: &PathBuf, dst: &PathBuf) -> std::io::Result<()> {
     fs::create_dir_all(dst)?;
     for entry in fs::read_dir(src)? {
@@ -60,7 +145,50 @@ pub enum DialogState {
     None,
     NewFile { name: String },
     NewFolder { name: String },
+    NewFolderWithSelection { paths: Vec<PathBuf> },
     Rename { path: PathBuf, name: String },
+    Compress { paths: Vec<PathBuf>, format: ArchiveFormat, level: u8 },
+}
+
+
+type ArchiveJobFn = Box<dyn FnOnce(&dyn Fn(ArchiveProgress)) -> Result<(), String> + Send>;
+
+enum PasteEvent {
+    Sized { total_files: usize, total_bytes: u64 },
+    Progress(u64),
+    Finished(Result<(), String>),
+}
+
+enum VerifyEvent {
+    Checked(usize, VerifyStatus),
+    Finished(bool),
+}
+
+enum DuplicateScanEvent {
+    Progress(DuplicateScanProgress),
+    Finished(Option<Vec<DuplicateGroup>>),
+}
+
+enum QueuedJob {
+    Paste {
+        source: PathBuf,
+        destination: PathBuf,
+        is_move: bool,
+        from_conflict: bool,
+    },
+    Archive {
+        kind: ArchiveJobKind,
+        source: PathBuf,
+        output: PathBuf,
+        job: ArchiveJobFn,
+    },
+}
+
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum HistoryMenu {
+    Back,
+    Forward,
 }
 
 
@@ -70,6 +198,28 @@ pub enum ClipboardOperation {
     Cut(PathBuf),
 }
 
+impl ClipboardOperation {
+    fn to_shared(&self) -> crate::models::ClipboardOperation {
+        match self {
+            ClipboardOperation::Copy(path) => crate::models::ClipboardOperation::Copy {
+                paths: vec![path.clone()],
+            },
+            ClipboardOperation::Cut(path) => crate::models::ClipboardOperation::Cut {
+                paths: vec![path.clone()],
+            },
+        }
+    }
+
+    fn from_shared(operation: &crate::models::ClipboardOperation) -> Option<Self> {
+        let path = operation.paths().first()?.clone();
+        Some(if operation.is_cut() {
+            ClipboardOperation::Cut(path)
+        } else {
+            ClipboardOperation::Copy(path)
+        })
+    }
+}
+
 pub struct Workspace {
     file_system: Entity<FileSystem>,
     icon_cache: Entity<IconCache>,
@@ -79,42 +229,90 @@ pub struct Workspace {
     sidebar: Entity<SidebarView>,
     search_input: Entity<SearchInputView>,
     preview: Option<Entity<PreviewView>>,
+    preview_pane: PreviewPaneState,
     theme_picker: Entity<ThemePickerView>,
+    command_palette: Entity<CommandPaletteView>,
     status_bar: Entity<StatusBarView>,
     terminals: HashMap<crate::models::TabId, Entity<TerminalView>>,
     quick_look: Entity<QuickLookView>,
     toast_manager: Entity<ToastManager>,
+    progress_panel: Entity<ProgressPanelView>,
     tab_bar: Entity<crate::views::TabBarView>,
     smart_folder_dialog: Entity<SmartFolderDialog>,
     focus_handle: FocusHandle,
     current_path: PathBuf,
     path_history: Vec<PathBuf>,
+    forward_history: Vec<PathBuf>,
+    history_menu: Option<HistoryMenu>,
+    toolbar_layout: ToolbarLayout,
+    toolbar_customizer_open: bool,
+    history_limit: usize,
+    scroll_memory: ScrollMemory,
     is_terminal_open: bool,
     terminal_height: f32,
     is_resizing_terminal: bool,
     preview_width: f32,
     is_resizing_preview: bool,
+    sidebar_width: f32,
+    is_resizing_sidebar: bool,
     cached_entries: Vec<crate::models::FileEntry>,
     view_mode: ViewMode,
+    default_view_mode: ViewMode,
+    folder_view_rules: FolderViewRules,
+    column_width_rules: ColumnWidthRules,
+    visible_columns: VisibleColumns,
+    mounted_images: MountedImages,
+    load_skeleton: LoadSkeleton,
     dialog_state: DialogState,
     show_hidden_files: bool,
     current_theme_id: ThemeId,
+    accent_hue: Option<f32>,
     clipboard: Option<ClipboardOperation>,
+    clipboard_mirror: ClipboardMirror,
+    duplicate_on_same_directory_paste: bool,
+    conflict_strategy: ConflictStrategy,
     copy_move_mode: bool,
     dest_file_list: Entity<FileListView>,
     dest_path: PathBuf,
     dest_entries: Vec<crate::models::FileEntry>,
     dialog_input: Option<Entity<InputState>>,
     should_focus_dialog_input: bool,
+    open_created_folder: bool,
     tabs_enabled: bool,
     show_smart_folder_dialog: bool,
     conflict_dialog: Option<Entity<ConflictDialog>>,
+    conflict_focus: Option<FocusTrap>,
     pending_conflicts: Vec<(PathBuf, PathBuf)>,
     conflict_apply_to_all: Option<ConflictResolution>,
+    conflict_apply_all_checked: bool,
+    overwrite_summary: Option<OverwriteSummary>,
+    overwrite_summary_loading: bool,
+    executable_prompt: Option<ExecutablePrompt>,
     symlink_dialog: Option<Entity<SymlinkDialog>>,
+    bulk_tag_dialog: Option<Entity<BulkTagDialog>>,
+    checksum_verify_panel: Option<Entity<ChecksumVerifyPanel>>,
+    duplicate_finder_panel: Option<Entity<DuplicateFinderPanel>>,
+    focused_region: Option<FocusRegion>,
     format_dialog: Option<(Device, FormatDialog, Entity<InputState>)>,
     bootable_usb_dialog: Option<(PathBuf, Option<Device>)>,
     share_manager: ShareManager,
+    flatten_session: Option<FlattenSession>,
+    pending_select_name: Option<(PathBuf, String)>,
+    dual_pane: Entity<DualPaneView>,
+    dual_pane_layout_change: Option<DualPaneLayout>,
+    watcher: Box<dyn Watcher>,
+    watched_path: Option<PathBuf>,
+    watching_directory: bool,
+    result_grouping: ResultGrouping,
+    grouped_results: GroupedResults,
+    archive_jobs: ArchiveJobs,
+    operation_queue: OperationQueue,
+    queued_jobs: HashMap<OperationId, QueuedJob>,
+    git_repo: Option<GitRepoInfo>,
+    git_status_cache: GitStatusCache,
+    git_status_loading: Option<PathBuf>,
+    volume_id: Option<VolumeId>,
+    volume_space_loading: bool,
 }
 
 impl Workspace {
@@ -133,6 +331,162 @@ impl Workspace {
     }
 
 
+    pub fn reveal_in_window(path: PathBuf, cx: &mut App) {
+        let Some(parent) = path.parent().map(Path::to_path_buf) else {
+            return;
+        };
+        if !cx.has_global::<WindowManager>() {
+            return;
+        }
+
+        let manager = cx.global::<WindowManager>();
+        let existing = manager.window_ids().into_iter().find_map(|id| {
+            let handle = *manager.get_window(id)?;
+            let is_open = handle
+                .read(cx)
+                .is_ok_and(|workspace| workspace.current_path == parent);
+            is_open.then_some((id, handle))
+        });
+
+        let handle = match existing {
+            Some((id, handle)) => {
+                cx.update_global::<WindowManager, _>(|manager, _| manager.set_active(id));
+                handle
+            }
+            None => {
+                let opened = cx.update_global::<WindowManager, _>(|manager, cx| {
+                    manager.open_window(parent, cx)
+                });
+                let Some(handle) =
+                    opened.and_then(|id| cx.global::<WindowManager>().get_window(id).copied())
+                else {
+                    return;
+                };
+                handle
+            }
+        };
+
+        let _ = handle.update(cx, |workspace, window, cx| {
+            window.activate_window();
+            workspace.reveal_path(&path, cx);
+        });
+    }
+
+
+    pub fn run_automation(action: AutomationAction, cx: &mut App) {
+        if !cx.has_global::<WindowManager>() {
+            return;
+        }
+        if let AutomationAction::Reveal(path) = action {
+            Self::reveal_in_window(path, cx);
+            return;
+        }
+        if let AutomationAction::NewWindow(target) = action {
+            Self::open_launch_window(target, cx);
+            return;
+        }
+
+        let directory = match &action {
+            AutomationAction::Navigate(path) | AutomationAction::NewTab(path) => path.clone(),
+            AutomationAction::Select { directory, .. } => directory.clone(),
+            AutomationAction::Reveal(_) | AutomationAction::NewWindow(_) => return,
+        };
+        let manager = cx.global::<WindowManager>();
+        let showing = manager.window_ids().into_iter().find(|id| {
+            manager.get_window(*id).is_some_and(|handle| {
+                handle
+                    .read(cx)
+                    .is_ok_and(|workspace| workspace.current_path == directory)
+            })
+        });
+        let Some(id) = showing.or(manager.active_window()) else {
+            match action {
+                AutomationAction::Select { target, .. } => Self::reveal_in_window(target, cx),
+                _ => Self::open_new_window(directory, cx),
+            }
+            return;
+        };
+        let Some(handle) = manager.get_window(id).copied() else {
+            return;
+        };
+        cx.update_global::<WindowManager, _>(|manager, _| manager.set_active(id));
+
+        let _ = handle.update(cx, |workspace, window, cx| {
+            window.activate_window();
+            match action {
+                AutomationAction::NewTab(path) if workspace.tabs_enabled => {
+                    workspace.tab_bar.update(cx, |tab_bar, cx| {
+                        tab_bar.open_tab(path.clone(), cx);
+                    });
+                    workspace.load_directory(path, cx);
+                }
+                AutomationAction::NewTab(path) => {
+                    cx.defer(move |cx| Self::open_new_window(path, cx));
+                }
+                AutomationAction::Select { directory, target } => {
+                    if workspace.current_path != directory {
+                        workspace.navigate_to(directory, cx);
+                    }
+                    workspace.reveal_path(&target, cx);
+                }
+                AutomationAction::Navigate(path) => {
+                    if workspace.current_path != path {
+                        workspace.navigate_to(path, cx);
+                    }
+                }
+                AutomationAction::Reveal(_) | AutomationAction::NewWindow(_) => {}
+            }
+        });
+    }
+
+
+    pub fn open_launch_window(target: LaunchTarget, cx: &mut App) {
+        if !cx.has_global::<WindowManager>() {
+            return;
+        }
+        let opened = cx.update_global::<WindowManager, _>(|manager, cx| {
+            manager.open_window(target.path.clone(), cx)
+        });
+        let Some(handle) =
+            opened.and_then(|id| cx.global::<WindowManager>().get_window(id).copied())
+        else {
+            return;
+        };
+
+        let _ = handle.update(cx, |workspace, window, cx| {
+            window.activate_window();
+            workspace.show_launch_fallback(&target, cx);
+        });
+    }
+
+
+    pub fn show_launch_fallback(&mut self, target: &LaunchTarget, cx: &mut Context<Self>) {
+        if let Some(message) = target.fallback_message() {
+            self.toast_manager.update(cx, |toast, cx| {
+                toast.show_error(message, cx);
+            });
+        }
+    }
+
+
+    fn reveal_path(&mut self, path: &Path, cx: &mut Context<Self>) {
+        if self.load_skeleton.is_busy() {
+            if let (Some(dir), Some(name)) = (path.parent(), path.file_name()) {
+                self.pending_select_name =
+                    Some((dir.to_path_buf(), name.to_string_lossy().into_owned()));
+                return;
+            }
+        }
+        self.file_list.update(cx, |view, cx| {
+            view.reveal_entry_at_path(path, cx);
+        });
+        self.grid_view.update(cx, |view, cx| {
+            view.inner_mut().select_entry_at_path(path);
+            cx.notify();
+        });
+    }
+
+
     pub fn open_new_window_here(&self, cx: &mut App) {
         Self::open_new_window(self.current_path.clone(), cx);
     }
@@ -167,10 +521,6 @@ impl Workspace {
         self.terminals.insert(tab_id, terminal);
     }
 
-
-    fn remove_terminal_for_tab(&mut self, tab_id: crate::models::TabId) {
-        self.terminals.remove(&tab_id);
-    }
 }
 
 impl Workspace {
@@ -185,16 +535,46 @@ impl Workspace {
             KeyBinding::new("cmd-f", FocusSearch, Some("Workspace")),
             KeyBinding::new("cmd-n", NewWindow, Some("Workspace")),
             KeyBinding::new("space", QuickLookToggle, Some("FileList")),
+            KeyBinding::new("cmd-shift-j", RevealInSidebar, Some("Workspace")),
+            KeyBinding::new("cmd-shift-d", ToggleDualPane, Some("Workspace")),
+            KeyBinding::new("cmd-alt-j", ToggleAutoReveal, Some("Workspace")),
+            KeyBinding::new("cmd-alt-c", CopySelectedPath, Some("Workspace")),
+            KeyBinding::new("cmd-=", IncreaseUiScale, Some("Workspace && !FileList && !QuickLook")),
+            KeyBinding::new("cmd-+", IncreaseUiScale, Some("Workspace && !FileList && !QuickLook")),
+            KeyBinding::new("cmd--", DecreaseUiScale, Some("Workspace && !FileList && !QuickLook")),
+            KeyBinding::new("cmd-0", ResetUiScale, Some("Workspace && !FileList && !QuickLook")),
+            KeyBinding::new("cmd-alt-m", CycleMotionPreference, Some("Workspace")),
+            KeyBinding::new("cmd-alt-i", CycleIconPack, Some("Workspace")),
+            KeyBinding::new("cmd-alt-k", CycleNameTint, Some("Workspace")),
+            KeyBinding::new("cmd-alt-t", ToggleTrashLocation, Some("Workspace")),
+            KeyBinding::new("cmd-alt-p", ToggleTerminalPalette, Some("Workspace")),
+            KeyBinding::new("cmd-alt-o", ToggleClickBehavior, Some("Workspace")),
+            KeyBinding::new("cmd-alt-d", CycleDateFormat, Some("Workspace")),
+            KeyBinding::new("cmd-alt-r", CycleConflictStrategy, Some("Workspace")),
+            KeyBinding::new("cmd-shift-l", ToggleFlattenView, Some("Workspace")),
+            KeyBinding::new("cmd-shift-i", TogglePreviewPane, Some("Workspace")),
+            KeyBinding::new("cmd-shift-p", ToggleCommandPalette, Some("Workspace")),
+            KeyBinding::new("cmd-f6", FocusNextRegion, Some("Workspace")),
+            KeyBinding::new("cmd-shift-f6", FocusPreviousRegion, Some("Workspace")),
+            KeyBinding::new("cmd-ctrl-n", NewFolderWithSelection, Some("Workspace")),
+            KeyBinding::new("cmd-up", GoToParent, Some("Workspace && !Terminal")),
+            KeyBinding::new("cmd-shift-h", GoToHome, Some("Workspace && !Terminal")),
+            KeyBinding::new("cmd-shift-up", GoToRoot, Some("Workspace && !Terminal")),
         ]);
     }
 
     pub fn build(initial_path: PathBuf, cx: &mut App) -> Entity<Self> {
         SearchInputView::register_key_bindings(cx);
         FileListView::register_key_bindings(cx);
+        GridViewComponent::register_key_bindings(cx);
+        SidebarView::register_key_bindings(cx);
+        PreviewView::register_key_bindings(cx);
         Self::register_key_bindings(cx);
 
         cx.new(|cx| {
+            let settings = GlobalSettings::load();
             let mut file_system = FileSystem::new(initial_path.clone());
+            file_system.set_batch_config(settings.batch_config());
 
             let start = Instant::now();
             let op = file_system.load_path(
@@ -209,7 +589,9 @@ impl Workspace {
                 file_system.process_batch(request_id, batch);
             }
 
-            let _ = op.traversal_handle.join();
+            if let Ok(Ok(summary)) = op.traversal_handle.join() {
+                file_system.set_read_errors(request_id, summary.errors);
+            }
             file_system.finalize_load(request_id, start.elapsed());
 
             let mut share_manager = ShareManager::new();
@@ -226,9 +608,15 @@ impl Workspace {
             let mut file_list_inner = FileList::new();
             file_list_inner.set_entries(cached_entries.clone());
             file_list_inner.set_viewport_height(600.0);
+            file_list_inner.set_column_widths(settings.column_widths.for_path(&initial_path));
+            file_list_inner.set_visible_columns(settings.visible_columns.clone());
 
             let file_system = cx.new(|_| file_system);
-            let icon_cache = cx.new(|_| IconCache::new());
+            let icon_cache = cx.new(|_| {
+                let mut cache = IconCache::new();
+                cache.set_icon_pack(crate::models::current_icon_pack());
+                cache
+            });
 
             let search_engine_inner = SearchEngine::new();
             for entry in &cached_entries {
@@ -245,27 +633,41 @@ impl Workspace {
             let sidebar = cx.new(|cx| {
                 let mut sidebar_view = SidebarView::new(cx);
                 sidebar_view.set_workspace_root(initial_path.clone());
+                sidebar_view.refresh_bookmark_validity(cx);
                 sidebar_view
             });
 
             let search_input =
                 cx.new(|cx| SearchInputView::new(cx).with_search_engine(search_engine.clone()));
 
-            let settings = GlobalSettings::load();
             let view_mode = settings.view_mode;
             let show_hidden_files = settings.show_hidden_files;
             let current_theme_id = settings.theme_id;
 
             crate::models::set_current_theme(current_theme_id);
+            crate::models::set_accent_override(settings.accent_hue);
+            crate::models::set_ui_scale(settings.ui_scale());
+            crate::models::apply_motion_preference(settings.motion_preference);
+            crate::models::set_animation_speed(settings.animation_speed());
+            crate::models::set_easing_preset(settings.easing_preset);
+            crate::models::set_show_folder_sizes(settings.show_folder_sizes);
+            crate::models::set_name_tint_mode(settings.name_tint);
+            crate::models::set_terminal_palette_mode(settings.terminal_palette);
+            crate::models::set_click_behavior(settings.click_behavior);
+            crate::models::set_date_format(settings.date_format.clone());
+            crate::models::set_preview_limits(settings.preview_limits);
 
             let theme_picker =
                 cx.new(|cx| ThemePickerView::new(cx).with_selected_theme(current_theme_id));
 
+            let unreadable = UnreadableItem::from_errors(file_system.read(cx).read_errors());
             let status_bar = cx.new(|cx| {
                 let mut status_bar_view = StatusBarView::new(cx);
                 status_bar_view.update_from_entries(&cached_entries, None, cx);
+                status_bar_view.set_unreadable(unreadable, cx);
                 status_bar_view.set_current_directory(&initial_path, cx);
                 status_bar_view.set_view_mode(view_mode, cx);
+                status_bar_view.set_visible_columns(settings.visible_columns.clone(), cx);
                 status_bar_view
             });
 
@@ -276,6 +678,13 @@ impl Workspace {
 
             let quick_look = cx.new(|cx| QuickLookView::new(cx));
 
+            cx.observe(&quick_look, |workspace: &mut Workspace, quick_look, cx| {
+                if let Some(path) = quick_look.update(cx, |view, _| view.take_pending_open()) {
+                    workspace.open_file(path, cx);
+                }
+            })
+            .detach();
+
             let toast_manager = cx.new(|cx| ToastManager::new(cx));
 
             let smart_folder_dialog = cx.new(|cx| SmartFolderDialog::new(cx));
@@ -302,6 +711,23 @@ impl Workspace {
                         workspace.handle_context_menu_action(action, cx);
                     }
 
+                    let column_widths =
+                        file_list.update(cx, |view, _| view.take_column_widths_change());
+                    if let Some(widths) = column_widths {
+                        workspace.set_folder_column_widths(widths);
+                    }
+
+                    let visible_columns =
+                        file_list.update(cx, |view, _| view.take_visible_columns_change());
+                    if let Some(columns) = visible_columns {
+                        workspace.set_visible_columns(columns, cx);
+                    }
+
+                    let list_sort = *file_list.read(cx).inner().sort_state();
+                    if list_sort != workspace.status_bar.read(cx).state().sort_state {
+                        workspace.set_folder_sort(list_sort, cx);
+                    }
+
                     let selected_index = file_list.read(cx).inner().selected_index();
                     let selection_count = if selected_index.is_some() { 1 } else { 0 };
                     sidebar_for_file_list.update(cx, |view, _| {
@@ -312,6 +738,7 @@ impl Workspace {
                         view.update_from_entries(&workspace.cached_entries, selected_index, cx);
                     });
 
+                    workspace.sync_preview_with_selection(cx);
                     workspace.update_preview_for_selection(cx);
                 },
             )
@@ -333,6 +760,12 @@ impl Workspace {
                         workspace.handle_context_menu_action(action, cx);
                     }
 
+                    let tag_filter =
+                        grid_view.update(cx, |view, _| view.take_pending_tag_filter());
+                    if let Some(tag) = tag_filter {
+                        workspace.apply_tag_filter(&tag, cx);
+                    }
+
                     let selected_index = grid_view.read(cx).inner().selected_index();
                     let selection_count = if selected_index.is_some() { 1 } else { 0 };
                     sidebar_for_grid.update(cx, |view, _| {
@@ -343,6 +776,7 @@ impl Workspace {
                         view.update_from_entries(&workspace.cached_entries, selected_index, cx);
                     });
 
+                    workspace.sync_preview_with_selection(cx);
                     workspace.update_preview_for_selection(cx);
                 },
             )
@@ -352,11 +786,34 @@ impl Workspace {
                 &search_input,
                 |workspace: &mut Workspace, search_input, cx| {
                     let query = search_input.read(cx).query().to_string();
+                    workspace.tab_bar.update(cx, |view, _| {
+                        view.tab_state_mut().set_active_search_query(&query);
+                    });
                     workspace.handle_search_query_change(&query, cx);
                 },
             )
             .detach();
 
+            let mut clipboard_mirror = ClipboardMirror::new();
+            if let Some(shared) = cx.try_global::<SharedClipboard>() {
+                clipboard_mirror.sync(shared);
+            }
+            let clipboard = clipboard_mirror
+                .operation()
+                .and_then(ClipboardOperation::from_shared);
+            let has_clipboard = clipboard.is_some();
+            sidebar.update(cx, |view, _| view.set_has_clipboard(has_clipboard));
+
+            cx.observe_global::<SharedClipboard>(|workspace: &mut Workspace, cx| {
+                workspace.sync_shared_clipboard(cx);
+            })
+            .detach();
+
+            cx.observe_global::<PreviewService>(|workspace: &mut Workspace, cx| {
+                workspace.sync_background_activity(cx);
+            })
+            .detach();
+
             cx.observe(&sidebar, |workspace: &mut Workspace, sidebar, cx| {
                 let action = sidebar.update(cx, |view, _| view.take_pending_action());
                 if let Some(action) = action {
@@ -383,6 +840,27 @@ impl Workspace {
                     workspace.handle_device_format(device, cx);
                 }
 
+                let tag_filter = sidebar.update(cx, |view, _| view.take_pending_tag_filter());
+                if let Some(tag) = tag_filter {
+                    workspace.apply_tag_filter(&tag, cx);
+                }
+
+                let network_error =
+                    sidebar.update(cx, |view, _| view.take_pending_network_error());
+                if let Some(message) = network_error {
+                    workspace.toast_manager.update(cx, |toast, cx| {
+                        toast.show_error(message, cx);
+                    });
+                }
+
+                let bookmark_error =
+                    sidebar.update(cx, |view, _| view.take_pending_bookmark_error());
+                if let Some(message) = bookmark_error {
+                    workspace.toast_manager.update(cx, |toast, cx| {
+                        toast.show_error(message, cx);
+                    });
+                }
+
                 let show_dialog = sidebar.read(cx).is_smart_folder_dialog_visible();
                 if show_dialog {
                     sidebar.update(cx, |view, cx| view.hide_smart_folder_dialog(cx));
@@ -412,6 +890,8 @@ impl Workspace {
                     match action {
                         StatusBarAction::ToggleTerminal => workspace.toggle_terminal(cx),
                         StatusBarAction::ToggleViewMode => workspace.toggle_view_mode(cx),
+                        StatusBarAction::SetSort(sort) => workspace.set_folder_sort(sort, cx),
+                        StatusBarAction::ShowOperations => workspace.show_operations(cx),
                     }
                 }
             })
@@ -424,10 +904,32 @@ impl Workspace {
                     if workspace.current_theme_id != selected {
                         workspace.set_theme(selected, cx);
                     }
+                    let accent_hue = theme_picker.read(cx).accent_hue();
+                    if workspace.accent_hue != accent_hue {
+                        workspace.set_accent_hue(accent_hue, cx);
+                    }
                 },
             )
             .detach();
 
+            let command_palette = cx.new(CommandPaletteView::new);
+
+            cx.observe(&command_palette, |workspace: &mut Workspace, palette, cx| {
+                if let Some(command) = palette.update(cx, |view, _| view.take_pending_command()) {
+                    workspace.run_palette_command(command, cx);
+                }
+            })
+            .detach();
+
+            let progress_panel = cx.new(ProgressPanelView::new);
+
+            cx.observe(&progress_panel, |workspace: &mut Workspace, panel, cx| {
+                if let Some(action) = panel.update(cx, |view, _| view.take_pending_action()) {
+                    workspace.handle_progress_panel_action(action, cx);
+                }
+            })
+            .detach();
+
             let tab_bar = cx.new(|cx| crate::views::TabBarView::new(initial_path.clone(), cx));
 
             cx.observe(&tab_bar, |workspace: &mut Workspace, tab_bar, cx| {
@@ -435,12 +937,13 @@ impl Workspace {
                     if let Some(tab) = workspace.tab_bar.read(cx).tab_state().get_tab(tab_id) {
                         let path = tab.path.clone();
                         workspace.load_directory(path, cx);
+                        workspace.restore_tab_search(cx);
                     }
                 }
 
-                if let Some(closed_tab_id) = tab_bar.update(cx, |view, _| view.take_pending_close())
-                {
-                    workspace.remove_terminal_for_tab(closed_tab_id);
+                if !tab_bar.update(cx, |view, _| view.take_pending_closes()).is_empty() {
+                    let tab_state = workspace.tab_bar.read(cx).tab_state();
+                    tab_state.retain_open(&mut workspace.terminals);
                 }
 
                 if tab_bar.update(cx, |view, _| view.take_pending_new_tab()) {
@@ -448,11 +951,19 @@ impl Workspace {
                     let path = workspace.tab_bar.read(cx).active_path().to_path_buf();
                     workspace.create_terminal_for_tab(tab_id, path.clone(), cx);
                     workspace.load_directory(path, cx);
+                    workspace.restore_tab_search(cx);
+                }
+
+                if let Some(action) = tab_bar.update(cx, |view, _| view.take_pending_drop()) {
+                    workspace.apply_tab_drop(action, cx);
                 }
             })
             .detach();
 
-            Self {
+            let dual_pane = cx.new(|cx| DualPaneView::new(initial_path.clone(), cx));
+            Self::observe_dual_pane(&dual_pane, cx);
+
+            let mut workspace = Self {
                 file_system,
                 icon_cache,
                 search_engine,
@@ -461,43 +972,96 @@ impl Workspace {
                 sidebar,
                 search_input,
                 preview: None,
+                preview_pane: PreviewPaneState::default(),
                 theme_picker,
+                command_palette,
                 status_bar,
                 terminals,
                 quick_look,
                 toast_manager,
+                progress_panel,
                 tab_bar,
                 smart_folder_dialog,
                 focus_handle: cx.focus_handle(),
                 current_path: initial_path.clone(),
                 path_history: vec![initial_path.clone()],
+                forward_history: Vec::new(),
+                history_menu: None,
+                toolbar_layout: settings.toolbar_layout.clone(),
+                toolbar_customizer_open: false,
+                history_limit: settings.history_limit,
+                scroll_memory: ScrollMemory::new(),
                 is_terminal_open: false,
-                terminal_height: 300.0,
+                terminal_height: crate::models::terminal_layout::DEFAULT_HEIGHT,
                 is_resizing_terminal: false,
-                preview_width: 320.0,
+                preview_width: crate::models::preview::WIDTH,
                 is_resizing_preview: false,
+                sidebar_width: crate::models::sidebar::WIDTH,
+                is_resizing_sidebar: false,
                 cached_entries,
                 view_mode,
+                default_view_mode: view_mode,
+                folder_view_rules: settings.folder_view_rules.clone(),
+                column_width_rules: settings.column_widths.clone(),
+                visible_columns: settings.visible_columns.clone(),
+                mounted_images: MountedImages::new(),
+                load_skeleton: LoadSkeleton::new(),
                 dialog_state: DialogState::None,
                 show_hidden_files,
                 current_theme_id,
-                clipboard: None,
+                accent_hue: settings.accent_hue,
+                clipboard,
+                clipboard_mirror,
+                duplicate_on_same_directory_paste: settings.duplicate_on_same_directory_paste,
+                conflict_strategy: settings.conflict_strategy,
                 copy_move_mode: false,
                 dest_file_list: cx.new(|cx| FileListView::with_file_list(FileList::new(), cx)),
                 dest_path: initial_path,
                 dest_entries: Vec::new(),
                 dialog_input: None,
                 should_focus_dialog_input: false,
+                open_created_folder: false,
                 tabs_enabled: true,
                 show_smart_folder_dialog: false,
                 conflict_dialog: None,
+                conflict_focus: None,
                 pending_conflicts: Vec::new(),
                 conflict_apply_to_all: None,
+                conflict_apply_all_checked: false,
+                overwrite_summary: None,
+                overwrite_summary_loading: false,
+                executable_prompt: None,
                 symlink_dialog: None,
+                bulk_tag_dialog: None,
+                checksum_verify_panel: None,
+                duplicate_finder_panel: None,
+                focused_region: None,
                 format_dialog: None,
                 bootable_usb_dialog: None,
                 share_manager,
-            }
+                flatten_session: None,
+                pending_select_name: None,
+                dual_pane: dual_pane.clone(),
+                dual_pane_layout_change: None,
+                watcher: detect_platform().create_watcher(),
+                watched_path: None,
+                watching_directory: false,
+                result_grouping: settings.result_grouping,
+                grouped_results: GroupedResults::new(),
+                archive_jobs: ArchiveJobs::new(),
+                operation_queue: OperationQueue::new(),
+                queued_jobs: HashMap::new(),
+                git_repo: None,
+                git_status_cache: GitStatusCache::new(),
+                git_status_loading: None,
+                volume_id: None,
+                volume_space_loading: false,
+            };
+            workspace.update_git_context(&initial_path, cx);
+            workspace.refresh_volume_space(false, cx);
+            workspace.start_relative_date_refresh(cx);
+            workspace.start_volume_space_refresh(cx);
+            workspace
         })
     }
 
@@ -547,14 +1111,20 @@ impl Workspace {
             DialogState::NewFolder { .. } => {
                 self.create_new_folder(&name, cx);
             }
+            DialogState::NewFolderWithSelection { paths } => {
+                let paths = paths.clone();
+                self.create_folder_with_selection(&name, paths, cx);
+            }
             DialogState::Rename { .. } => {
                 self.submit_rename(cx);
-                return;
             }
-            DialogState::None => {}
+            DialogState::Compress { .. } => {
+                self.submit_compress_items(cx);
+            }
+            DialogState::None => {
+                self.dialog_input = None;
+            }
         }
-
-        self.dialog_input = None;
     }
 
     fn handle_tool_action(&mut self, action: ToolAction, cx: &mut Context<Self>) {
@@ -574,8 +1144,17 @@ impl Workspace {
             }
             ToolAction::ToggleHiddenFiles => {
                 let show_hidden = self.sidebar.read(cx).show_hidden_files();
-                self.show_hidden_files = show_hidden;
-                self.refresh_current_directory(cx);
+                let override_dir = self
+                    .folder_view_rules
+                    .hidden_override_source(&self.current_path)
+                    .map(Path::to_path_buf);
+                match override_dir {
+                    Some(dir) => self.set_folder_hidden_rule(dir, Some(show_hidden), cx),
+                    None => {
+                        self.show_hidden_files = show_hidden;
+                        self.refresh_current_directory(cx);
+                    }
+                }
             }
             ToolAction::CopyPath => {}
             ToolAction::Copy => {
@@ -627,6 +1206,9 @@ impl Workspace {
                         .to_string();
                     let path = entry.path.clone();
                     let is_dir = entry.is_dir;
+                    if !self.leave_directories_being_deleted(std::slice::from_ref(&path), cx) {
+                        return;
+                    }
 
                     let result = if is_dir {
                         fs::remove_dir_all(&path)
@@ -653,6 +1235,9 @@ impl Workspace {
                     }
                 }
             }
+            ToolAction::FindDuplicates => {
+                self.find_duplicates(false, cx);
+            }
             ToolAction::SetAsDefault => {}
         }
     }
@@ -669,6 +1254,11 @@ impl Workspace {
             return;
         };
 
+        if self.mounted_images.at_mount_point(&device_path).is_some() {
+            self.unmount_disk_image(device_path, cx);
+            return;
+        }
+
         self.toast_manager.update(cx, |toast, cx| {
             toast.show_info(format!("Ejecting {}...", device_name), cx);
         });
@@ -705,13 +1295,16 @@ impl Workspace {
 
     fn handle_device_mount(&mut self, device_path: PathBuf, cx: &mut Context<Self>) {
         let disk_id = device_path.to_string_lossy().trim_start_matches("/dev/").to_string();
-        
+
         self.toast_manager.update(cx, |toast, cx| {
             toast.show_info(format!("Mounting {}...", disk_id), cx);
         });
 
         #[cfg(target_os = "macos")]
         {
+            let device = self.sidebar.read(cx).devices().iter()
+                .find(|d| d.path == device_path)
+                .cloned();
             let output = std::process::Command::new("diskutil")
                 .args(["mountDisk", &disk_id])
                 .output();
@@ -724,6 +1317,14 @@ impl Workspace {
                         view.refresh_devices(cx);
                     });
 
+                    let mounted_path = device.as_ref().and_then(|device| {
+                        crate::models::mounted_device_for(self.sidebar.read(cx).devices(), device)
+                            .map(|d| d.path.clone())
+                    });
+                    if let Some(path) = mounted_path {
+                        self.navigate_to(path, cx);
+                    }
+
                     self.toast_manager.update(cx, |toast, cx| {
                         toast.show_success(format!("Mounted: {}", disk_id), cx);
                     });
@@ -992,42 +1593,33 @@ impl Workspace {
                 if path.is_dir() {
                     self.navigate_to(path, cx);
                 } else {
-                    #[cfg(target_os = "macos")]
-                    {
-                        let _ = std::process::Command::new("open").arg(&path).spawn();
-                    }
-                    #[cfg(target_os = "windows")]
-                    {
-                        let _ = std::process::Command::new("cmd")
-                            .args(["/C", "start", "", path.to_str().unwrap_or("")])
-                            .spawn();
-                    }
-                    #[cfg(target_os = "linux")]
-                    {
-                        let _ = std::process::Command::new("xdg-open").arg(&path).spawn();
-                    }
+                    self.open_file(path, cx);
                 }
             }
             ContextMenuAction::OpenWith(_path) => {
             }
             ContextMenuAction::OpenWithApp {
-                file_path,
+                paths,
                 app_path,
                 app_name,
             } => {
                 let app_info = crate::models::AppInfo::new(app_name.clone(), app_path);
-                match crate::models::open_file_with_app(&file_path, &app_info) {
-                    Ok(()) => {
-                        self.toast_manager.update(cx, |toast, cx| {
-                            toast.show_success(format!("Opening with {}", app_name), cx);
-                        });
+                let failures = crate::models::open_files_with_app(&paths, &app_info);
+                let opened = paths.len() - failures.len();
+                self.toast_manager.update(cx, |toast, cx| {
+                    if opened == 1 {
+                        toast.show_success(format!("Opening with {}", app_name), cx);
+                    } else if opened > 1 {
+                        toast.show_success(format!("Opening {} items with {}", opened, app_name), cx);
                     }
-                    Err(e) => {
-                        self.toast_manager.update(cx, |toast, cx| {
-                            toast.show_error(format!("Failed to open: {}", e), cx);
-                        });
+                    for (path, error) in &failures {
+                        let name = path
+                            .file_name()
+                            .map(|n| n.to_string_lossy().to_string())
+                            .unwrap_or_else(|| path.display().to_string());
+                        toast.show_error(format!("{}: {}", name, error), cx);
                     }
-                }
+                });
             }
             ContextMenuAction::OpenWithOther(path) => {
                 match crate::models::show_open_with_dialog(&path) {
@@ -1042,24 +1634,37 @@ impl Workspace {
             ContextMenuAction::OpenInNewWindow(path) => {
                 Self::open_new_window(path, cx);
             }
+            ContextMenuAction::RevealInNewWindow(path) => {
+                cx.defer(move |cx| Self::reveal_in_window(path, cx));
+            }
             ContextMenuAction::OpenInNewTab(path) => {
                 self.tab_bar.update(cx, |tab_bar, cx| {
                     tab_bar.open_tab(path.clone(), cx);
                 });
                 self.load_directory(path, cx);
             }
-            ContextMenuAction::GetInfo(path) => {
-                #[cfg(target_os = "macos")]
-                {
-                    let script = format!(
-                        "tell application \"Finder\" to open information window of (POSIX file \"{}\" as alias)",
-                        path.display()
-                    );
-                    let _ = std::process::Command::new("osascript")
-                        .args(["-e", &script])
-                        .spawn();
+            ContextMenuAction::OpenInBackgroundTab(path) => {
+                if self.tabs_enabled {
+                    let tab_id = self.tab_bar.update(cx, |tab_bar, cx| {
+                        tab_bar.open_background_tab(path.clone(), cx)
+                    });
+                    self.create_terminal_for_tab(tab_id, path, cx);
+                } else {
+                    cx.defer(move |cx| Self::open_new_window(path, cx));
                 }
             }
+            ContextMenuAction::SetFolderViewMode { path, mode } => {
+                self.set_folder_view_rule(path, mode, cx);
+            }
+            ContextMenuAction::SetFolderHiddenFiles { path, show } => {
+                self.set_folder_hidden_rule(path, show, cx);
+            }
+            ContextMenuAction::TogglePin(path) => {
+                self.toggle_pin(path, cx);
+            }
+            ContextMenuAction::GetInfo(path) => {
+                self.show_preview_pane(Some(path), Some(PreviewTab::Info), cx);
+            }
             ContextMenuAction::Rename(path) => {
                 self.start_rename(path, cx);
             }
@@ -1069,9 +1674,7 @@ impl Workspace {
                     .and_then(|n| n.to_str())
                     .unwrap_or("item")
                     .to_string();
-                self.clipboard = Some(ClipboardOperation::Copy(path));
-                self.sidebar
-                    .update(cx, |view, _| view.set_has_clipboard(true));
+                self.set_clipboard(Some(ClipboardOperation::Copy(path)), cx);
                 self.toast_manager.update(cx, |toast, cx| {
                     toast.show_info(format!("Copied: {}", name), cx);
                 });
@@ -1082,9 +1685,7 @@ impl Workspace {
                     .and_then(|n| n.to_str())
                     .unwrap_or("item")
                     .to_string();
-                self.clipboard = Some(ClipboardOperation::Cut(path));
-                self.sidebar
-                    .update(cx, |view, _| view.set_has_clipboard(true));
+                self.set_clipboard(Some(ClipboardOperation::Cut(path)), cx);
                 self.toast_manager.update(cx, |toast, cx| {
                     toast.show_info(format!("Cut: {}", name), cx);
                 });
@@ -1126,27 +1727,8 @@ impl Workspace {
                 }
             }
             ContextMenuAction::MoveToTrash(path) => {
-                let name = path
-                    .file_name()
-                    .and_then(|n| n.to_str())
-                    .unwrap_or("item")
-                    .to_string();
-                match trash::delete(&path) {
-                    Ok(()) => {
-                        self.file_list
-                            .update(cx, |view, _| view.inner_mut().set_selected_index(None));
-                        self.preview = None;
-                        self.toast_manager.update(cx, |toast, cx| {
-                            toast.show_success(format!("Moved to Trash: {}", name), cx);
-                        });
-                        self.refresh_current_directory(cx);
-                    }
-                    Err(e) => {
-                        self.toast_manager.update(cx, |toast, cx| {
-                            toast.show_error(format!("Failed to trash: {}", e), cx);
-                        });
-                    }
-                }
+                let paths = self.selection_for(&path, cx);
+                self.move_selection_to_trash(paths, cx);
             }
             ContextMenuAction::Compress(path) => {
                 let name = path
@@ -1154,53 +1736,17 @@ impl Workspace {
                     .and_then(|n| n.to_str())
                     .unwrap_or("archive");
                 let parent = path.parent().unwrap_or(&self.current_path);
-                let archive_name = format!("{}.zip", name);
-                let archive_path = parent.join(&archive_name);
-
-                self.toast_manager.update(cx, |toast, cx| {
-                    toast.show_info(format!("Compressing: {}...", name), cx);
-                });
+                let options = GlobalSettings::load().compress_options();
+                let archive_path = parent.join(format!("{}{}", name, options.format.extension()));
 
                 let path_clone = path.clone();
                 let archive_path_clone = archive_path.clone();
-                let name_clone = name.to_string();
-
-                cx.spawn(async move |this, cx| {
-                    let result = std::thread::spawn(move || {
-                        #[cfg(target_os = "macos")]
-                        {
-                            std::process::Command::new("ditto")
-                                .args(["-c", "-k", "--sequesterRsrc", "--keepParent"])
-                                .arg(&path_clone)
-                                .arg(&archive_path_clone)
-                                .output()
-                        }
-                        #[cfg(not(target_os = "macos"))]
-                        {
-                            std::process::Command::new("zip")
-                                .args(["-r"])
-                                .arg(&archive_path_clone)
-                                .arg(&path_clone)
-                                .output()
-                        }
-                    })
-                    .join();
-
-                    let _ = this.update(cx, |workspace, cx| match result {
-                        Ok(Ok(output)) if output.status.success() => {
-                            workspace.toast_manager.update(cx, |toast, cx| {
-                                toast.show_success(format!("Created: {}.zip", name_clone), cx);
-                            });
-                            workspace.refresh_current_directory(cx);
-                        }
-                        _ => {
-                            workspace.toast_manager.update(cx, |toast, cx| {
-                                toast.show_error("Failed to compress".to_string(), cx);
-                            });
-                        }
-                    });
-                })
-                .detach();
+                self.run_archive_job(ArchiveJobKind::Compress, path, archive_path, cx, move |progress| {
+                    let manager = crate::models::ArchiveManager::new();
+                    manager
+                        .compress(&[path_clone], &archive_path_clone, &options, progress)
+                        .map_err(|e| e.to_string())
+                });
             }
             ContextMenuAction::Share(path) => {
                 #[cfg(target_os = "macos")]
@@ -1240,6 +1786,18 @@ impl Workspace {
                     toast.show_success("Path copied to clipboard".to_string(), cx);
                 });
             }
+            ContextMenuAction::CopyPathAs { paths, format } => {
+                let text = crate::models::format_paths(&paths, format, &self.current_path);
+                cx.write_to_clipboard(gpui::ClipboardItem::new_string(text));
+                let message = if paths.len() > 1 {
+                    format!("{} paths copied to clipboard", paths.len())
+                } else {
+                    "Path copied to clipboard".to_string()
+                };
+                self.toast_manager.update(cx, |toast, cx| {
+                    toast.show_success(message, cx);
+                });
+            }
             ContextMenuAction::ShowInFinder(path) => {
                 #[cfg(target_os = "macos")]
                 {
@@ -1263,14 +1821,12 @@ impl Workspace {
                 }
             }
             ContextMenuAction::QuickLook(path) => {
-                if !path.is_dir() {
-                    let entries = self.cached_entries.clone();
-                    let index = entries.iter().position(|e| e.path == path).unwrap_or(0);
-                    self.quick_look.update(cx, |view, _| {
-                        view.toggle(path, entries, index);
-                    });
-                    cx.notify();
-                }
+                let entries = self.cached_entries.clone();
+                let index = entries.iter().position(|e| e.path == path).unwrap_or(0);
+                self.quick_look.update(cx, |view, cx| {
+                    view.toggle(path, entries, index, cx);
+                });
+                cx.notify();
             }
             ContextMenuAction::AddToFavorites(path) => {
                 self.sidebar.update(cx, |view, _| {
@@ -1287,160 +1843,50 @@ impl Workspace {
             ContextMenuAction::NewFile => {
                 self.open_dialog(true, cx);
             }
+            ContextMenuAction::CompressItems(paths) => {
+                self.start_compress_items(paths, cx);
+            }
             ContextMenuAction::CompressAs { path, format } => {
                 let name = path
                     .file_name()
                     .and_then(|n| n.to_str())
                     .unwrap_or("archive");
                 let parent = path.parent().unwrap_or(&self.current_path);
-                let archive_name = format!("{}{}", name, format.extension());
-                let archive_path = parent.join(&archive_name);
-
-                self.toast_manager.update(cx, |toast, cx| {
-                    toast.show_info(format!("Compressing to {}...", archive_name), cx);
-                });
+                let archive_path = parent.join(format!("{}{}", name, format.extension()));
 
+                let options = GlobalSettings::load().compress_options_for(format);
                 let path_clone = path.clone();
                 let archive_path_clone = archive_path.clone();
-                let name_clone = name.to_string();
-                let format_clone = format;
-
-                cx.spawn(async move |this, cx| {
-                    let result = std::thread::spawn(move || {
-                        let manager = crate::models::ArchiveManager::new();
-                        let options = crate::models::CompressOptions {
-                            format: format_clone,
-                            compression_level: 6,
-                            password: None,
-                        };
-                        manager.compress(&[path_clone], &archive_path_clone, &options, |_| {})
-                    })
-                    .join();
-
-                    let _ = this.update(cx, |workspace, cx| match result {
-                        Ok(Ok(())) => {
-                            workspace.toast_manager.update(cx, |toast, cx| {
-                                toast.show_success(format!("Created: {}", name_clone), cx);
-                            });
-                            workspace.refresh_current_directory(cx);
-                        }
-                        _ => {
-                            workspace.toast_manager.update(cx, |toast, cx| {
-                                toast.show_error("Failed to compress".to_string(), cx);
-                            });
-                        }
-                    });
-                })
-                .detach();
+                self.run_archive_job(ArchiveJobKind::Compress, path, archive_path, cx, move |progress| {
+                    let manager = crate::models::ArchiveManager::new();
+                    manager
+                        .compress(&[path_clone], &archive_path_clone, &options, progress)
+                        .map_err(|e| e.to_string())
+                });
             }
             ContextMenuAction::ExtractHere(path) => {
                 let parent = path.parent().unwrap_or(&self.current_path).to_path_buf();
-                let name = path
-                    .file_name()
-                    .and_then(|n| n.to_str())
-                    .unwrap_or("archive");
-
-                self.toast_manager.update(cx, |toast, cx| {
-                    toast.show_info(format!("Extracting {}...", name), cx);
-                });
-
-                let path_clone = path.clone();
-                let name_clone = name.to_string();
-
-                cx.spawn(async move |this, cx| {
-                    let result = std::thread::spawn(move || {
-                        let manager = crate::models::ArchiveManager::new();
-                        let options = crate::models::ExtractOptions {
-                            destination: parent,
-                            password: None,
-                            overwrite: crate::models::OverwriteMode::Replace,
-                        };
-                        manager.extract(&path_clone, &options, |_| {})
-                    })
-                    .join();
-
-                    let _ = this.update(cx, |workspace, cx| match result {
-                        Ok(Ok(())) => {
-                            workspace.toast_manager.update(cx, |toast, cx| {
-                                toast.show_success(format!("Extracted: {}", name_clone), cx);
-                            });
-                            workspace.refresh_current_directory(cx);
-                        }
-                        Ok(Err(e)) => {
-                            workspace.toast_manager.update(cx, |toast, cx| {
-                                toast.show_error(format!("Extraction failed: {}", e), cx);
-                            });
-                        }
-                        Err(_) => {
-                            workspace.toast_manager.update(cx, |toast, cx| {
-                                toast.show_error("Extraction failed".to_string(), cx);
-                            });
-                        }
-                    });
-                })
-                .detach();
+                self.extract_archive(path, parent, cx);
             }
             ContextMenuAction::ExtractToFolder(path) => {
                 let parent = path.parent().unwrap_or(&self.current_path);
-                let stem = path
-                    .file_stem()
-                    .and_then(|n| n.to_str())
-                    .unwrap_or("extracted");
-                let stem = if stem.ends_with(".tar") {
-                    &stem[..stem.len() - 4]
-                } else {
-                    stem
-                };
-                let dest_folder = parent.join(stem);
-                let name = path
-                    .file_name()
-                    .and_then(|n| n.to_str())
-                    .unwrap_or("archive");
-
-                self.toast_manager.update(cx, |toast, cx| {
-                    toast.show_info(format!("Extracting {} to folder...", name), cx);
-                });
-
-                let path_clone = path.clone();
-                let name_clone = name.to_string();
-                let stem_clone = stem.to_string();
-
-                cx.spawn(async move |this, cx| {
-                    let result = std::thread::spawn(move || {
-                        let manager = crate::models::ArchiveManager::new();
-                        let options = crate::models::ExtractOptions {
-                            destination: dest_folder,
-                            password: None,
-                            overwrite: crate::models::OverwriteMode::Replace,
-                        };
-                        manager.extract(&path_clone, &options, |_| {})
-                    })
-                    .join();
-
-                    let _ = this.update(cx, |workspace, cx| match result {
-                        Ok(Ok(())) => {
-                            workspace.toast_manager.update(cx, |toast, cx| {
-                                toast.show_success(format!("Extracted to: {}/", stem_clone), cx);
-                            });
-                            workspace.refresh_current_directory(cx);
-                        }
-                        Ok(Err(e)) => {
-                            workspace.toast_manager.update(cx, |toast, cx| {
-                                toast.show_error(format!("Extraction failed: {}", e), cx);
-                            });
-                        }
-                        Err(_) => {
-                            workspace.toast_manager.update(cx, |toast, cx| {
-                                toast.show_error("Extraction failed".to_string(), cx);
-                            });
-                        }
-                    });
-                })
-                .detach();
+                let stem = crate::models::archive_base_name(&path)
+                    .unwrap_or_else(|| "extracted".to_string());
+                let dest_folder = parent.join(&stem);
+                self.extract_archive(path, dest_folder, cx);
             }
             ContextMenuAction::CreateSymlink(target_path) => {
                 self.show_symlink_dialog(target_path, cx);
             }
+            ContextMenuAction::EditTags(paths) => {
+                self.show_bulk_tag_dialog(paths, cx);
+            }
+            ContextMenuAction::NewFromTemplate(template) => {
+                self.new_file_from_template(template, cx);
+            }
+            ContextMenuAction::VerifyChecksums(path) => {
+                self.verify_checksums(path, cx);
+            }
             ContextMenuAction::ShowSymlinkTarget(path) => {
                 self.show_symlink_target(path, cx);
             }
@@ -1448,11 +1894,15 @@ impl Workspace {
                 self.mount_disk_image(path, cx);
             }
             ContextMenuAction::UnmountImage(path) => {
-                self.unmount_disk_image(path, cx);
+                let mount_point = self.mounted_images.unmount_target(&path);
+                self.unmount_disk_image(mount_point, cx);
             }
             ContextMenuAction::CreateBootableUSB(path) => {
                 self.show_bootable_usb_dialog(path, cx);
             }
+            ContextMenuAction::Custom { id, paths } => {
+                self.run_custom_context_action(id, paths, cx);
+            }
         }
     }
 
@@ -1506,11 +1956,23 @@ impl Workspace {
         if new_name.is_empty() {
             return;
         }
+        if let Err(e) = validate_file_name(&new_name) {
+            self.toast_manager.update(cx, |toast, cx| {
+                toast.show_error(e.to_string(), cx);
+            });
+            return;
+        }
 
         let new_path = old_path
             .parent()
             .unwrap_or(&self.current_path)
             .join(&new_name);
+        if new_path != old_path && fs::symlink_metadata(&new_path).is_ok() {
+            self.toast_manager.update(cx, |toast, cx| {
+                toast.show_error(format!("A file named {} already exists", new_name), cx);
+            });
+            return;
+        }
 
         match fs::rename(&old_path, &new_path) {
             Ok(()) => {
@@ -1531,6 +1993,120 @@ impl Workspace {
         cx.notify();
     }
 
+    fn start_compress_items(&mut self, paths: Vec<PathBuf>, cx: &mut Context<Self>) {
+        if paths.is_empty() {
+            return;
+        }
+        let name = default_archive_name(&paths);
+        let defaults = GlobalSettings::load().compress_options();
+        self.dialog_state = DialogState::Compress {
+            paths,
+            format: defaults.format,
+            level: defaults.compression_level,
+        };
+
+        let input_state = cx.new(|cx| {
+            let mut state = InputState::new(cx);
+            state.content = name.into();
+            state.select_on_focus = true;
+            state
+        });
+
+        cx.subscribe(
+            &input_state,
+            |workspace: &mut Workspace, _input, event: &InputEvent, cx| match event {
+                InputEvent::Enter => {
+                    workspace.submit_compress_items(cx);
+                }
+                _ => {}
+            },
+        )
+        .detach();
+
+        self.dialog_input = Some(input_state);
+        self.should_focus_dialog_input = true;
+        cx.notify();
+    }
+
+    fn set_compress_format(&mut self, format: ArchiveFormat, cx: &mut Context<Self>) {
+        if let DialogState::Compress {
+            format: current,
+            level,
+            ..
+        } = &mut self.dialog_state
+        {
+            *current = format;
+            *level = format.clamp_compression_level(*level);
+            cx.notify();
+        }
+    }
+
+    fn set_compress_level(&mut self, level: u8, cx: &mut Context<Self>) {
+        if let DialogState::Compress {
+            format,
+            level: current,
+            ..
+        } = &mut self.dialog_state
+        {
+            *current = format.clamp_compression_level(level);
+            cx.notify();
+        }
+    }
+
+    fn submit_compress_items(&mut self, cx: &mut Context<Self>) {
+        let DialogState::Compress { paths, format, level } = &self.dialog_state else {
+            return;
+        };
+        let (paths, options) = (paths.clone(), CompressOptions::new(*format, *level));
+        let format = options.format;
+        let Some(name) = self
+            .dialog_input
+            .as_ref()
+            .map(|input| input.read(cx).content.trim().to_string())
+        else {
+            return;
+        };
+
+        if name.is_empty() {
+            return;
+        }
+        if let Err(e) = validate_file_name(&name) {
+            self.toast_manager.update(cx, |toast, cx| {
+                toast.show_error(e.to_string(), cx);
+            });
+            return;
+        }
+
+        let directory = paths[0]
+            .parent()
+            .map(Path::to_path_buf)
+            .unwrap_or_else(|| self.current_path.clone());
+        let archive_path = archive_output_path(&directory, &name, format);
+        if fs::symlink_metadata(&archive_path).is_ok() {
+            let existing = archive_path
+                .file_name()
+                .map(|n| n.to_string_lossy().into_owned())
+                .unwrap_or_default();
+            self.toast_manager.update(cx, |toast, cx| {
+                toast.show_error(format!("A file named {} already exists", existing), cx);
+            });
+            return;
+        }
+
+        self.dialog_state = DialogState::None;
+        self.dialog_input = None;
+
+        let source = paths[0].clone();
+        let archive_path_clone = archive_path.clone();
+        self.run_archive_job(ArchiveJobKind::Compress, source, archive_path, cx, move |progress| {
+            let manager = crate::models::ArchiveManager::new();
+            manager
+                .compress(&paths, &archive_path_clone, &options, progress)
+                .map_err(|e| e.to_string())
+        });
+        cx.notify();
+    }
+
     fn show_symlink_dialog(&mut self, target_path: PathBuf, cx: &mut Context<Self>) {
         let default_location = self.current_path.clone();
         let symlink_dialog = cx.new(|cx| SymlinkDialog::new(target_path, default_location, cx));
@@ -1584,7 +2160,261 @@ impl Workspace {
             }
             SymlinkDialogAction::Cancel => {}
         }
-        self.symlink_dialog = None;
+        self.close_symlink_dialog(cx);
+    }
+
+    fn show_bulk_tag_dialog(&mut self, paths: Vec<PathBuf>, cx: &mut Context<Self>) {
+        let Some(manager) = cx.try_global::<TagManager>() else {
+            return;
+        };
+        let edit = BulkTagEdit::new(manager, paths);
+        let tags = manager.all_tags().into_iter().cloned().collect();
+        let bulk_tag_dialog = cx.new(|cx| BulkTagDialog::new(edit, tags, cx));
+
+        cx.observe(&bulk_tag_dialog, |workspace: &mut Workspace, dialog, cx| {
+            let action = dialog.update(cx, |view, _| view.take_pending_action());
+            if let Some(action) = action {
+                workspace.handle_bulk_tag_dialog_action(action, cx);
+            }
+        })
+        .detach();
+
+        self.bulk_tag_dialog = Some(bulk_tag_dialog);
+        cx.notify();
+    }
+
+    fn handle_bulk_tag_dialog_action(&mut self, action: BulkTagDialogAction, cx: &mut Context<Self>) {
+        if let BulkTagDialogAction::Apply { paths, diff } = action {
+            let report = cx.update_global::<TagManager, _>(|manager, _| {
+                let report = apply_tag_diff(manager, &paths, &diff);
+                if report.updated > 0 {
+                    if let Err(e) = manager.save() {
+                        eprintln!("Failed to save tags: {}", e);
+                    }
+                }
+                report
+            });
+
+            self.file_list.update(cx, |view, cx| {
+                view.inner_mut().clear_marked();
+                cx.notify();
+            });
+            self.grid_view.update(cx, |view, cx| {
+                view.inner_mut().clear_marked();
+                cx.notify();
+            });
+
+            self.toast_manager.update(cx, |toast, cx| match report.failures.first() {
+                None => {
+                    let noun = if report.updated == 1 { "item" } else { "items" };
+                    toast.show_success(format!("Updated tags on {} {}", report.updated, noun), cx);
+                }
+                Some((path, reason)) => {
+                    let name = path
+                        .file_name()
+                        .map(|n| n.to_string_lossy().to_string())
+                        .unwrap_or_else(|| path.display().to_string());
+                    let message = if report.is_partial() {
+                        format!(
+                            "Updated {} of {} items; {} failed ({}: {})",
+                            report.updated,
+                            paths.len(),
+                            report.failures.len(),
+                            name,
+                            reason
+                        )
+                    } else {
+                        format!("Failed to update tags: {}: {}", name, reason)
+                    };
+                    toast.show_error(message, cx);
+                }
+            });
+        }
+        self.close_bulk_tag_dialog(cx);
+    }
+
+    fn close_bulk_tag_dialog(&mut self, cx: &mut Context<Self>) {
+        if let Some(dialog) = self.bulk_tag_dialog.take() {
+            let restore_to = dialog.update(cx, |dialog, _| dialog.take_restore_focus());
+            restore_focus(restore_to, cx);
+        }
+        cx.notify();
+    }
+
+    fn verify_checksums(&mut self, manifest_path: PathBuf, cx: &mut Context<Self>) {
+        let manifest = fs::read_to_string(&manifest_path)
+            .map_err(|e| e.to_string())
+            .and_then(|content| {
+                parse_manifest(&content, manifest_algorithm_hint(&manifest_path))
+                    .map_err(|e| e.to_string())
+            });
+        let manifest = match manifest {
+            Ok(manifest) => manifest,
+            Err(e) => {
+                self.toast_manager.update(cx, |toast, cx| {
+                    toast.show_error(format!("Cannot read checksums: {}", e), cx);
+                });
+                return;
+            }
+        };
+
+        let directory = manifest_path
+            .parent()
+            .unwrap_or(&self.current_path)
+            .to_path_buf();
+        let panel = cx.new(|cx| ChecksumVerifyPanel::new(manifest_path, manifest.clone(), cx));
+        let cancel = panel.read(cx).cancel_handle();
+
+        cx.observe(&panel, |workspace: &mut Workspace, panel, cx| {
+            let action = panel.update(cx, |view, _| view.take_pending_action());
+            if let Some(ChecksumVerifyAction::Close) = action {
+                workspace.close_checksum_verify_panel(cx);
+            }
+        })
+        .detach();
+
+        let (sender, receiver) = flume::unbounded();
+        std::thread::spawn(move || {
+            let completed = verify_manifest(&manifest, &directory, &cancel, |index, status| {
+                let _ = sender.send(VerifyEvent::Checked(index, status));
+            });
+            let _ = sender.send(VerifyEvent::Finished(completed));
+        });
+
+        let task_panel = panel.downgrade();
+        cx.spawn(async move |this, cx| {
+            while let Ok(event) = receiver.recv_async().await {
+                let finished = matches!(event, VerifyEvent::Finished(_));
+                let updated = task_panel.update(cx, |panel, cx| match event {
+                    VerifyEvent::Checked(index, status) => panel.record(index, status, cx),
+                    VerifyEvent::Finished(completed) => panel.finish(completed, cx),
+                });
+                if updated.is_err() || finished {
+                    break;
+                }
+            }
+            let _ = this.update(cx, |workspace, cx| {
+                let Some(panel) = workspace.checksum_verify_panel.clone() else {
+                    return;
+                };
+                let summary = panel.read(cx).summary().clone();
+                if summary.all_matched() {
+                    workspace.toast_manager.update(cx, |toast, cx| {
+                        toast.show_success(format!("{} files verified", summary.matched), cx);
+                    });
+                }
+            });
+        })
+        .detach();
+
+        self.checksum_verify_panel = Some(panel);
+        cx.notify();
+    }
+
+    fn close_checksum_verify_panel(&mut self, cx: &mut Context<Self>) {
+        if let Some(panel) = self.checksum_verify_panel.take() {
+            let restore_to = panel.update(cx, |panel, _| {
+                panel.cancel_handle().store(true, std::sync::atomic::Ordering::Relaxed);
+                panel.take_restore_focus()
+            });
+            restore_focus(restore_to, cx);
+        }
+        cx.notify();
+    }
+
+    fn find_duplicates(&mut self, recursive: bool, cx: &mut Context<Self>) {
+        if let Some(panel) = self.duplicate_finder_panel.take() {
+            panel.read(cx).cancel_handle().store(true, std::sync::atomic::Ordering::Relaxed);
+        }
+
+        let root = self.current_path.clone();
+        let panel = cx.new(|cx| DuplicateFinderPanel::new(root.clone(), recursive, cx));
+        let cancel = panel.read(cx).cancel_handle();
+
+        cx.observe(&panel, |workspace: &mut Workspace, panel, cx| {
+            let action = panel.update(cx, |view, _| view.take_pending_action());
+            match action {
+                Some(DuplicateFinderAction::Close) => workspace.close_duplicate_finder_panel(cx),
+                Some(DuplicateFinderAction::Rescan { recursive }) => {
+                    workspace.find_duplicates(recursive, cx)
+                }
+                Some(DuplicateFinderAction::Trash(paths)) => {
+                    workspace.trash_duplicates(&panel, paths, cx)
+                }
+                None => {}
+            }
+        })
+        .detach();
+
+        let (sender, receiver) = flume::unbounded();
+        std::thread::spawn(move || {
+            let groups = find_duplicates(&root, recursive, &cancel, |progress| {
+                let _ = sender.send(DuplicateScanEvent::Progress(progress));
+            });
+            let _ = sender.send(DuplicateScanEvent::Finished(groups));
+        });
+
+        let task_panel = panel.downgrade();
+        cx.spawn(async move |_, cx| {
+            while let Ok(event) = receiver.recv_async().await {
+                let finished = matches!(event, DuplicateScanEvent::Finished(_));
+                let updated = task_panel.update(cx, |panel, cx| match event {
+                    DuplicateScanEvent::Progress(progress) => panel.record_progress(progress, cx),
+                    DuplicateScanEvent::Finished(groups) => panel.finish(groups, cx),
+                });
+                if updated.is_err() || finished {
+                    break;
+                }
+            }
+        })
+        .detach();
+
+        self.duplicate_finder_panel = Some(panel);
+        cx.notify();
+    }
+
+    fn trash_duplicates(
+        &mut self,
+        panel: &Entity<DuplicateFinderPanel>,
+        paths: Vec<PathBuf>,
+        cx: &mut Context<Self>,
+    ) {
+        let location = GlobalSettings::load().trash_location;
+        let mut trashed = Vec::new();
+        let mut failures = 0;
+        for path in paths {
+            match move_path_to_trash(&path, location) {
+                Ok(()) => trashed.push(path),
+                Err(_) => failures += 1,
+            }
+        }
+
+        panel.update(cx, |panel, cx| panel.remove_paths(&trashed, cx));
+        self.toast_manager.update(cx, |toast, cx| {
+            if failures == 0 {
+                toast.show_success(format!("Moved {} duplicates to Trash", trashed.len()), cx);
+            } else {
+                toast.show_error(
+                    format!(
+                        "Moved {} duplicates to Trash, {} failed",
+                        trashed.len(),
+                        failures
+                    ),
+                    cx,
+                );
+            }
+        });
+        self.refresh_current_directory(cx);
+    }
+
+    fn close_duplicate_finder_panel(&mut self, cx: &mut Context<Self>) {
+        if let Some(panel) = self.duplicate_finder_panel.take() {
+            let restore_to = panel.update(cx, |panel, _| {
+                panel.cancel_handle().store(true, std::sync::atomic::Ordering::Relaxed);
+                panel.take_restore_focus()
+            });
+            restore_focus(restore_to, cx);
+        }
         cx.notify();
     }
 
@@ -1626,6 +2456,7 @@ impl Workspace {
         let toast_manager = self.toast_manager.clone();
 
         cx.spawn(async move |workspace, cx| {
+            let image = path.clone();
             let result = cx.background_executor().spawn(async move {
                 #[cfg(target_os = "windows")]
                 {
@@ -1660,6 +2491,8 @@ impl Workspace {
                             );
                         });
                         let _ = workspace.update(cx, |ws, cx| {
+                            ws.mounted_images.record(image, mount_point.clone());
+                            ws.sync_mounted_images(cx);
                             ws.navigate_to(mount_point, cx);
                         });
                     }
@@ -1722,6 +2555,8 @@ impl Workspace {
                             toast.show_success(format!("{} unmounted successfully", name), cx);
                         });
                         let _ = workspace.update(cx, |ws, cx| {
+                            ws.mounted_images.forget(&mount_point);
+                            ws.sync_mounted_images(cx);
                             if ws.current_path.starts_with(&mount_point) {
                                 if let Some(parent) = mount_point.parent() {
                                     ws.navigate_to(parent.to_path_buf(), cx);
@@ -1740,7 +2575,15 @@ impl Workspace {
         .detach();
     }
 
-    fn show_bootable_usb_dialog(&mut self, iso_path: PathBuf, cx: &mut Context<Self>) {
+    fn sync_mounted_images(&mut self, cx: &mut Context<Self>) {
+        let images = self.mounted_images.clone();
+        self.sidebar.update(cx, |view, cx| {
+            view.refresh_devices(cx);
+            view.set_mounted_images(images, cx);
+        });
+    }
+
+    fn show_bootable_usb_dialog(&mut self, iso_path: PathBuf, cx: &mut Context<Self>) {
         self.bootable_usb_dialog = Some((iso_path, None));
         self.sidebar.update(cx, |view, cx| {
             view.refresh_devices(cx);
@@ -1874,81 +2717,215 @@ impl Workspace {
         Err("Creating bootable USB is not supported on this platform".to_string())
     }
 
-    fn refresh_current_directory(&mut self, cx: &mut Context<Self>) {
-        let path = self.current_path.clone();
-        self.navigate_to(path, cx);
+    fn extract_archive(&mut self, archive: PathBuf, destination: PathBuf, cx: &mut Context<Self>) {
+        let archive_clone = archive.clone();
+        let destination_clone = destination.clone();
+        self.run_archive_job(ArchiveJobKind::Extract, archive, destination, cx, move |progress| {
+            let manager = crate::models::ArchiveManager::new();
+            let options = crate::models::ExtractOptions {
+                destination: destination_clone,
+                password: None,
+                overwrite: crate::models::OverwriteMode::Replace,
+            };
+            manager
+                .extract(&archive_clone, &options, progress)
+                .map_err(|e| e.to_string())
+        });
     }
 
-    fn paste_from_clipboard(&mut self, cx: &mut Context<Self>) {
-        let Some(clipboard_op) = self.clipboard.clone() else {
-            return;
-        };
 
-        let (source_path, is_move) = match &clipboard_op {
-            ClipboardOperation::Copy(path) => (path.clone(), false),
-            ClipboardOperation::Cut(path) => (path.clone(), true),
+    fn run_archive_job<F>(
+        &mut self,
+        kind: ArchiveJobKind,
+        source: PathBuf,
+        output: PathBuf,
+        cx: &mut Context<Self>,
+        job: F,
+    ) where
+        F: FnOnce(&dyn Fn(ArchiveProgress)) -> Result<(), String> + Send + 'static,
+    {
+        let op_type = match kind {
+            ArchiveJobKind::Compress => OperationType::Compress,
+            ArchiveJobKind::Extract => OperationType::Extract,
         };
+        let id = self
+            .operation_queue
+            .enqueue(op_type, vec![source.clone()], Some(output.clone()));
+        self.queued_jobs.insert(
+            id,
+            QueuedJob::Archive {
+                kind,
+                source,
+                output,
+                job: Box::new(job),
+            },
+        );
+        self.pump_operation_queue(cx);
+    }
 
-        let file_name = source_path
-            .file_name()
-            .and_then(|n| n.to_str())
-            .unwrap_or("unknown")
-            .to_string();
-
-        let dest_path = self.current_path.join(&file_name);
-
-        if source_path.parent() == Some(&self.current_path) && !is_move {
-            return;
-        }
+    fn enqueue_paste(
+        &mut self,
+        source: PathBuf,
+        destination: PathBuf,
+        is_move: bool,
+        from_conflict: bool,
+        cx: &mut Context<Self>,
+    ) {
+        let op_type = if is_move {
+            OperationType::Move
+        } else {
+            OperationType::Copy
+        };
+        let target_dir = destination
+            .parent()
+            .map(Path::to_path_buf)
+            .unwrap_or_else(|| destination.clone());
+        let id = self
+            .operation_queue
+            .enqueue(op_type, vec![source.clone()], Some(target_dir));
+        self.queued_jobs.insert(
+            id,
+            QueuedJob::Paste {
+                source,
+                destination,
+                is_move,
+                from_conflict,
+            },
+        );
+        self.pump_operation_queue(cx);
+    }
 
-        if dest_path.exists() {
-            if let Some(resolution) = self.conflict_apply_to_all {
-                self.handle_conflict_resolution(source_path, dest_path, is_move, resolution, cx);
-            } else {
-                self.show_conflict_dialog(source_path, dest_path, is_move, cx);
+    fn pump_operation_queue(&mut self, cx: &mut Context<Self>) {
+        for id in self.operation_queue.start_ready() {
+            match self.queued_jobs.remove(&id) {
+                Some(QueuedJob::Paste {
+                    source,
+                    destination,
+                    is_move,
+                    from_conflict,
+                }) => self.start_paste(id, source, destination, is_move, from_conflict, cx),
+                Some(QueuedJob::Archive {
+                    kind,
+                    source,
+                    output,
+                    job,
+                }) => self.start_archive_job(id, kind, source, output, job, cx),
+                None => {
+                    self.operation_queue
+                        .finish(id, Err("Operation is no longer available".to_string()));
+                }
             }
-            return;
         }
+        self.sync_archive_progress(cx);
+    }
 
-        self.clipboard = None;
+    fn finish_queued_operation(
+        &mut self,
+        id: OperationId,
+        result: Result<(), String>,
+        cx: &mut Context<Self>,
+    ) {
+        self.operation_queue.finish(id, result);
+        self.pump_operation_queue(cx);
 
-        self.sidebar.update(cx, |view, _| {
-            view.set_has_clipboard(false);
-        });
+        cx.spawn(async move |this, cx| {
+            Timer::after(COMPLETED_OPERATION_TTL).await;
+            let _ = this.update(cx, |workspace, cx| {
+                if workspace.operation_queue.prune_finished(Instant::now()) {
+                    workspace.sync_archive_progress(cx);
+                }
+            });
+        })
+        .detach();
+    }
+
+    fn start_paste(
+        &mut self,
+        id: OperationId,
+        source: PathBuf,
+        destination: PathBuf,
+        is_move: bool,
+        from_conflict: bool,
+        cx: &mut Context<Self>,
+    ) {
+        if let Some(operation) = self.operation_queue.get_mut(id) {
+            operation.progress = OperationProgress::new(1, 0);
+            operation.progress.current_file = source
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned());
+        }
 
+        let name = source
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or("unknown")
+            .to_string();
         let action = if is_move { "Moving" } else { "Copying" };
-        self.toast_manager.update(cx, |toast, cx| {
-            toast.show_info(format!("{}: {}...", action, file_name), cx);
+        let toast_id = self.toast_manager.update(cx, |toast, cx| {
+            toast.show_progress(format!("{}: {}", action, name), cx)
         });
 
-        let source = source_path.clone();
-        let dest = dest_path.clone();
-        let name = file_name.clone();
+        let source_clone = source.clone();
+        let dest_clone = destination.clone();
+        let (sender, receiver) = flume::unbounded();
+        std::thread::spawn(move || {
+            let (total_files, total_bytes) =
+                FileOperationExecutor::calculate_total_size(std::slice::from_ref(&source_clone))
+                    .unwrap_or((1, 0));
+            let _ = sender.send(PasteEvent::Sized {
+                total_files,
+                total_bytes,
+            });
 
-        cx.spawn(async move |this, cx| {
-            let result = std::thread::spawn(move || {
-                if source.is_dir() {
-                    copy_dir_recursive_async(&source, &dest)
-                } else {
-                    fs::copy(&source, &dest).map(|_| ())
+            let mut transferred = 0u64;
+            let mut last_report = Instant::now();
+            let result = copy_with_progress(&source_clone, &dest_clone, &mut |bytes| {
+                transferred += bytes;
+                if last_report.elapsed() >= PASTE_PROGRESS_INTERVAL {
+                    last_report = Instant::now();
+                    let _ = sender.send(PasteEvent::Progress(transferred));
                 }
-            })
-            .join()
-            .unwrap_or_else(|_| {
-                Err(std::io::Error::new(
-                    std::io::ErrorKind::Other,
-                    "Thread panic",
-                ))
             });
+            let _ = sender.send(PasteEvent::Finished(result.map_err(|e| e.to_string())));
+        });
+
+        cx.spawn(async move |this, cx| {
+            let mut result = Err("Transfer stopped unexpectedly".to_string());
+            while let Ok(event) = receiver.recv_async().await {
+                match event {
+                    PasteEvent::Sized {
+                        total_files,
+                        total_bytes,
+                    } => {
+                        let _ = this.update(cx, |workspace, cx| {
+                            if let Some(operation) = workspace.operation_queue.get_mut(id) {
+                                operation.progress.total_files = total_files.max(1);
+                                operation.progress.total_bytes = total_bytes;
+                            }
+                            workspace.sync_archive_progress(cx);
+                        });
+                    }
+                    PasteEvent::Progress(transferred) => {
+                        let _ = this.update(cx, |workspace, cx| {
+                            workspace.record_paste_progress(id, toast_id, transferred, cx);
+                        });
+                    }
+                    PasteEvent::Finished(outcome) => {
+                        result = outcome;
+                        break;
+                    }
+                }
+            }
 
             let _ = this.update(cx, |workspace, cx| {
-                match result {
+                workspace.toast_manager.update(cx, |toast, cx| toast.dismiss(toast_id, cx));
+                match &result {
                     Ok(()) => {
                         if is_move {
-                            let _ = if source_path.is_dir() {
-                                fs::remove_dir_all(&source_path)
+                            let _ = if source.is_dir() {
+                                fs::remove_dir_all(&source)
                             } else {
-                                fs::remove_file(&source_path)
+                                fs::remove_file(&source)
                             };
                             workspace.toast_manager.update(cx, |toast, cx| {
                                 toast.show_success(format!("Moved: {}", name), cx);
@@ -1964,131 +2941,551 @@ impl Workspace {
                         workspace.toast_manager.update(cx, |toast, cx| {
                             toast.show_error(format!("Failed: {}", e), cx);
                         });
-                        if is_move {
-                            workspace.clipboard =
-                                Some(ClipboardOperation::Cut(source_path.clone()));
-                        } else {
-                            workspace.clipboard =
-                                Some(ClipboardOperation::Copy(source_path.clone()));
+                        if !from_conflict {
+                            let restored = if is_move {
+                                ClipboardOperation::Cut(source.clone())
+                            } else {
+                                ClipboardOperation::Copy(source.clone())
+                            };
+                            workspace.set_clipboard(Some(restored), cx);
                         }
-                        workspace.sidebar.update(cx, |view, _| {
-                            view.set_has_clipboard(true);
-                        });
                     }
                 }
+                if from_conflict {
+                    workspace.finish_paste_operation(cx);
+                }
+                workspace.finish_queued_operation(id, result, cx);
             });
         })
         .detach();
     }
 
-    fn load_destination_entries(&mut self, cx: &mut Context<Self>) {
-        let path = self.dest_path.clone();
-        let show_hidden = true;
+    fn record_paste_progress(
+        &mut self,
+        id: OperationId,
+        toast_id: u64,
+        transferred: u64,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(operation) = self.operation_queue.get_mut(id) else {
+            return;
+        };
+        let elapsed = operation.elapsed();
+        operation.progress.record_transfer(transferred, elapsed);
+        if let Some(summary) = transfer_summary(&operation.progress) {
+            let percentage = operation.progress.percentage();
+            self.toast_manager.update(cx, |toast, cx| {
+                toast.set_description(toast_id, format!("{:.0}% · {}", percentage, summary), cx);
+            });
+        }
+        self.sync_operation_progress(id, cx);
+    }
+
+    fn start_archive_job(
+        &mut self,
+        operation_id: OperationId,
+        kind: ArchiveJobKind,
+        source: PathBuf,
+        output: PathBuf,
+        job: ArchiveJobFn,
+        cx: &mut Context<Self>,
+    ) {
+        let directory = source
+            .parent()
+            .map(Path::to_path_buf)
+            .unwrap_or_else(|| self.current_path.clone());
+        let label_path = if output == directory { &source } else { &output };
+        let name = label_path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or("archive")
+            .to_string();
+
+        let id = self
+            .archive_jobs
+            .start(kind, name, directory, output.clone());
+        if let Some(operation) = self.operation_queue.get_mut(operation_id) {
+            operation.progress = OperationProgress::new(1, 0);
+        }
+
+        let (sender, receiver) = flume::unbounded();
+        std::thread::spawn(move || {
+            let progress_sender = sender.clone();
+            let last_report = std::cell::Cell::new(None::<Instant>);
+            let result = job(&move |progress| {
+                if last_report
+                    .get()
+                    .is_some_and(|at| at.elapsed() < ARCHIVE_PROGRESS_INTERVAL)
+                {
+                    return;
+                }
+                last_report.set(Some(Instant::now()));
+                let _ = progress_sender.send(ArchiveJobEvent::Progress(progress));
+            });
+            let _ = sender.send(ArchiveJobEvent::Finished(result));
+        });
 
         cx.spawn(async move |this, cx| {
-            let entries = std::thread::spawn(move || {
-                let mut entries = Vec::new();
-                if let Ok(read_dir) = fs::read_dir(&path) {
-                    for entry in read_dir.flatten() {
-                        let entry_path = entry.path();
-                        let name = entry_path
-                            .file_name()
-                            .and_then(|n| n.to_str())
-                            .unwrap_or("")
-                            .to_string();
+            let mut finished = false;
+            while let Ok(event) = receiver.recv_async().await {
+                finished = matches!(event, ArchiveJobEvent::Finished(_));
+                let _ = this.update(cx, |workspace, cx| {
+                    workspace.handle_archive_event(id, operation_id, event, cx);
+                });
+                if finished {
+                    break;
+                }
+            }
 
-                        if !show_hidden && name.starts_with('.') {
-                            continue;
-                        }
+            if !finished {
+                let _ = this.update(cx, |workspace, cx| {
+                    let event = ArchiveJobEvent::Finished(Err("Job stopped unexpectedly".to_string()));
+                    workspace.handle_archive_event(id, operation_id, event, cx);
+                });
+            }
+        })
+        .detach();
+    }
 
-                        let is_dir = entry_path.is_dir();
-                        let metadata = entry_path.metadata().ok();
-                        let size = metadata.as_ref().map(|m| m.len()).unwrap_or(0);
-                        let modified = metadata
-                            .as_ref()
-                            .and_then(|m| m.modified().ok())
-                            .unwrap_or(std::time::SystemTime::UNIX_EPOCH);
+    fn handle_archive_event(
+        &mut self,
+        id: u64,
+        operation_id: OperationId,
+        event: ArchiveJobEvent,
+        cx: &mut Context<Self>,
+    ) {
+        match event {
+            ArchiveJobEvent::Progress(progress) => {
+                self.archive_jobs.set_progress(id, &progress);
+                if let Some(operation) = self.operation_queue.get_mut(operation_id) {
+                    operation.progress.total_files = progress.total_files.max(1);
+                    operation.progress.completed_files = progress.current_file_index;
+                    operation.progress.total_bytes = progress.total_bytes;
+                    let elapsed = operation.elapsed();
+                    operation
+                        .progress
+                        .record_transfer(progress.bytes_processed, elapsed);
+                    operation.progress.current_file = Some(progress.current_file);
+                }
+                self.sync_operation_progress(operation_id, cx);
+            }
+            ArchiveJobEvent::Finished(result) => {
+                let Some(placeholder) = self.archive_jobs.finish(id) else {
+                    return;
+                };
+                let is_compress = placeholder.kind == ArchiveJobKind::Compress;
 
-                        let file_type = if is_dir {
-                            crate::models::FileType::Directory
+                match &result {
+                    Ok(()) => {
+                        let message = if is_compress {
+                            format!("Created: {}", placeholder.name)
                         } else {
-                            crate::models::FileType::RegularFile
+                            format!("Extracted: {}", placeholder.name)
                         };
-
-                        let icon_key = if is_dir {
-                            crate::models::IconKey::Directory
+                        self.toast_manager.update(cx, |toast, cx| {
+                            toast.show_success(message, cx);
+                        });
+                        if placeholder.directory == self.current_path {
+                            self.refresh_current_directory(cx);
+                            if placeholder.output != placeholder.directory {
+                                self.reveal_path(&placeholder.output, cx);
+                            }
+                        }
+                    }
+                    Err(error) => {
+                        let message = if is_compress {
+                            format!("Failed to compress {}: {}", placeholder.name, error)
                         } else {
-                            entry_path
-                                .extension()
-                                .and_then(|ext| ext.to_str())
-                                .map(|ext| crate::models::IconKey::Extension(ext.to_lowercase()))
-                                .unwrap_or(crate::models::IconKey::GenericFile)
+                            format!("Extraction failed: {}", error)
                         };
-
-                        entries.push(crate::models::FileEntry {
-                            name,
-                            path: entry_path,
-                            is_dir,
-                            size,
-                            modified,
-                            file_type,
-                            icon_key,
-                            linux_permissions: None,
-                            sync_status: crate::models::CloudSyncStatus::None,
-                            is_symlink: false,
-                            symlink_target: None,
-                            is_broken_symlink: false,
-                            is_shared: false,
+                        self.toast_manager.update(cx, |toast, cx| {
+                            toast.show_error(message, cx);
                         });
                     }
                 }
-                entries.sort_by(|a, b| match (a.is_dir, b.is_dir) {
-                    (true, false) => std::cmp::Ordering::Less,
-                    (false, true) => std::cmp::Ordering::Greater,
-                    _ => a.name.to_lowercase().cmp(&b.name.to_lowercase()),
-                });
-                entries
-            })
-            .join()
-            .unwrap_or_default();
-
-            let _ = this.update(cx, |workspace, cx| {
-                workspace.dest_entries = entries;
-                cx.notify();
-            });
-        })
-        .detach();
+                self.finish_queued_operation(operation_id, result, cx);
+            }
+        }
     }
 
-    fn navigate_dest_to(&mut self, path: PathBuf, cx: &mut Context<Self>) {
-        self.dest_path = path;
-        self.load_destination_entries(cx);
-    }
+    fn sync_archive_progress(&mut self, cx: &mut Context<Self>) {
+        let placeholders = self.archive_jobs.placeholders_in(&self.current_path);
+        self.file_list.update(cx, |view, cx| {
+            view.inner_mut().set_placeholders(placeholders);
+            cx.notify();
+        });
 
-    fn paste_to_destination(&mut self, cx: &mut Context<Self>) {
-        let Some(clipboard_op) = self.clipboard.clone() else {
-            return;
-        };
+        let operations = self.operation_queue.operations().to_vec();
+        self.progress_panel.update(cx, |panel, cx| {
+            panel.update_operations(operations, cx);
+        });
+        self.sync_background_activity(cx);
+        cx.notify();
+    }
 
-        let (source_path, is_move) = match &clipboard_op {
-            ClipboardOperation::Copy(path) => (path.clone(), false),
-            ClipboardOperation::Cut(path) => (path.clone(), true),
-        };
+    fn sync_operation_progress(&mut self, id: OperationId, cx: &mut Context<Self>) {
+        let placeholders = self.archive_jobs.placeholders_in(&self.current_path);
+        self.file_list.update(cx, |view, cx| {
+            view.inner_mut().set_placeholders(placeholders);
+            cx.notify();
+        });
 
-        let file_name = source_path
-            .file_name()
-            .and_then(|n| n.to_str())
-            .unwrap_or("unknown")
-            .to_string();
+        if let Some(operation) = self.operation_queue.get(id).cloned() {
+            self.progress_panel.update(cx, |panel, cx| {
+                panel.update_operation(operation, cx);
+            });
+        }
+        self.sync_background_activity(cx);
+        cx.notify();
+    }
 
-        let dest_path = self.dest_path.join(&file_name);
+    fn sync_background_activity(&mut self, cx: &mut Context<Self>) {
+        let mut activity = BackgroundActivity::from_operations(self.operation_queue.operations());
+        let pending_previews = cx
+            .try_global::<PreviewService>()
+            .map(|service| service.queue().pending_len() + service.queue().in_flight_len())
+            .unwrap_or(0);
+        if pending_previews > 0 {
+            activity.push("Generating thumbnails", None);
+        }
+        self.status_bar.update(cx, |view, cx| {
+            view.set_background_activity(activity, cx);
+        });
+    }
 
-        if dest_path.exists() {
-            if let Some(resolution) = self.conflict_apply_to_all {
-                self.handle_conflict_resolution(source_path, dest_path, is_move, resolution, cx);
-            } else {
-                self.show_conflict_dialog(source_path, dest_path, is_move, cx);
-            }
+    fn show_operations(&mut self, cx: &mut Context<Self>) {
+        if self.operation_queue.is_empty() {
+            return;
+        }
+        self.progress_panel.update(cx, |panel, cx| {
+            panel.set_expanded(true, cx);
+        });
+    }
+
+    fn handle_progress_panel_action(&mut self, action: ProgressPanelAction, cx: &mut Context<Self>) {
+        match action {
+            ProgressPanelAction::Dismiss(id) => {
+                self.operation_queue.dismiss(id);
+            }
+            ProgressPanelAction::DismissAll => {
+                self.operation_queue.dismiss_finished();
+            }
+            ProgressPanelAction::MoveUp(id) => {
+                self.operation_queue.move_up(id);
+            }
+            ProgressPanelAction::MoveDown(id) => {
+                self.operation_queue.move_down(id);
+            }
+            ProgressPanelAction::Cancel(id) => {
+                if self.operation_queue.cancel(id) {
+                    self.queued_jobs.remove(&id);
+                } else {
+                    self.toast_manager.update(cx, |toast, cx| {
+                        toast.show_info("Running operations can't be cancelled".to_string(), cx);
+                    });
+                }
+            }
+        }
+        self.sync_archive_progress(cx);
+    }
+
+    fn run_custom_context_action(
+        &mut self,
+        id: ContextMenuEntryId,
+        paths: Vec<PathBuf>,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(registry) = cx.try_global::<ContextMenuRegistry>() else {
+            return;
+        };
+        let label = registry.label(id).unwrap_or("Action").to_string();
+        match registry.invoke(id, &paths) {
+            Some(Ok(())) => self.refresh_current_directory(cx),
+            Some(Err(message)) => {
+                self.toast_manager.update(cx, |toast, cx| {
+                    toast.show_error(format!("{} failed: {}", label, message), cx);
+                });
+            }
+            None => {}
+        }
+    }
+
+    fn refresh_current_directory(&mut self, cx: &mut Context<Self>) {
+        let path = self.current_path.clone();
+        self.git_status_cache.invalidate();
+        self.navigate_to(path, cx);
+    }
+
+    fn update_git_context(&mut self, path: &Path, cx: &mut Context<Self>) {
+        self.git_repo = GitRepoInfo::detect(path);
+
+        let Some(root) = crate::models::find_repo_root(path) else {
+            self.apply_git_badges(HashMap::new(), cx);
+            return;
+        };
+        if let Some(map) = self.git_status_cache.get(&root) {
+            let badges = map.badges_for(self.cached_entries.iter().map(|e| &e.path));
+            self.apply_git_badges(badges, cx);
+            return;
+        }
+
+        self.apply_git_badges(HashMap::new(), cx);
+        if self.git_status_loading.as_ref() == Some(&root) {
+            return;
+        }
+        self.git_status_loading = Some(root.clone());
+
+        cx.spawn(async move |this, cx| {
+            let result = cx
+                .background_executor()
+                .spawn({
+                    let root = root.clone();
+                    async move { crate::models::load_git_status(&root) }
+                })
+                .await;
+
+            let _ = this.update(cx, |workspace, cx| {
+                if workspace.git_status_loading.as_ref() == Some(&root) {
+                    workspace.git_status_loading = None;
+                }
+                let Ok(map) = result else {
+                    return;
+                };
+                workspace.git_status_cache.store(map);
+                if workspace.current_path.starts_with(&root) {
+                    let path = workspace.current_path.clone();
+                    workspace.update_git_context(&path, cx);
+                }
+            });
+        })
+        .detach();
+    }
+
+    fn apply_git_badges(&mut self, badges: HashMap<PathBuf, GitStatusBadge>, cx: &mut Context<Self>) {
+        self.grid_view.update(cx, |view, _| {
+            view.inner_mut().set_git_badges(badges.clone());
+        });
+        self.file_list.update(cx, |view, cx| {
+            view.inner_mut().set_git_badges(badges);
+            cx.notify();
+        });
+        cx.notify();
+    }
+
+    fn open_git_terminal(&mut self, cx: &mut Context<Self>) {
+        let Some(root) = self.git_repo.as_ref().map(|repo| repo.root.clone()) else {
+            return;
+        };
+        if !self.is_terminal_open {
+            self.toggle_terminal(cx);
+        } else if let Some(terminal) = self.active_terminal(cx) {
+            terminal.update(cx, |terminal, _| terminal.change_directory(root));
+        }
+    }
+
+    fn copy_git_remote_url(&mut self, cx: &mut Context<Self>) {
+        let Some(url) = self.git_repo.as_ref().and_then(|repo| repo.remote_url.clone()) else {
+            self.toast_manager.update(cx, |toast, cx| {
+                toast.show_info("This repository has no remote".to_string(), cx);
+            });
+            return;
+        };
+        cx.write_to_clipboard(gpui::ClipboardItem::new_string(url));
+        self.toast_manager.update(cx, |toast, cx| {
+            toast.show_success("Remote URL copied to clipboard".to_string(), cx);
+        });
+    }
+
+    fn show_git_status(&mut self, cx: &mut Context<Self>) {
+        let Some(root) = self.git_repo.as_ref().map(|repo| repo.root.clone()) else {
+            return;
+        };
+        let message = match self.git_status_cache.get(&root) {
+            Some(map) => map.summary(),
+            None => "Git status is still loading".to_string(),
+        };
+        self.toast_manager.update(cx, |toast, cx| {
+            toast.show_info(message, cx);
+        });
+    }
+
+    fn set_clipboard(&mut self, clipboard: Option<ClipboardOperation>, cx: &mut Context<Self>) {
+        self.clipboard = clipboard;
+        let has_clipboard = self.clipboard.is_some();
+        self.sidebar
+            .update(cx, |view, _| view.set_has_clipboard(has_clipboard));
+
+        let shared = cx.default_global::<SharedClipboard>();
+        shared.set(self.clipboard.as_ref().map(ClipboardOperation::to_shared));
+        self.clipboard_mirror.sync(shared);
+    }
+
+    fn sync_shared_clipboard(&mut self, cx: &mut Context<Self>) {
+        let Some(shared) = cx.try_global::<SharedClipboard>() else {
+            return;
+        };
+        if !self.clipboard_mirror.sync(shared) {
+            return;
+        }
+
+        self.clipboard = self
+            .clipboard_mirror
+            .operation()
+            .and_then(ClipboardOperation::from_shared);
+        let has_clipboard = self.clipboard.is_some();
+        self.sidebar
+            .update(cx, |view, _| view.set_has_clipboard(has_clipboard));
+        cx.notify();
+    }
+
+    fn paste_from_clipboard(&mut self, cx: &mut Context<Self>) {
+        let Some(clipboard_op) = self.clipboard.clone() else {
+            return;
+        };
+
+        let (source_path, is_move) = match &clipboard_op {
+            ClipboardOperation::Copy(path) => (path.clone(), false),
+            ClipboardOperation::Cut(path) => (path.clone(), true),
+        };
+
+        let dest_path = match paste_target(
+            &source_path,
+            &self.current_path,
+            is_move,
+            self.duplicate_on_same_directory_paste,
+        ) {
+            PasteTarget::Unchanged => return,
+            PasteTarget::Destination(path) => path,
+        };
+
+        if dest_path.exists() {
+            if let Some(resolution) =
+                preset_conflict_resolution(self.conflict_apply_to_all, self.conflict_strategy)
+            {
+                self.handle_conflict_resolution(source_path, dest_path, is_move, resolution, cx);
+            } else {
+                self.show_conflict_dialog(source_path, dest_path, is_move, cx);
+            }
+            return;
+        }
+
+        self.set_clipboard(None, cx);
+        self.enqueue_paste(source_path, dest_path, is_move, false, cx);
+    }
+
+    fn load_destination_entries(&mut self, cx: &mut Context<Self>) {
+        let path = self.dest_path.clone();
+        let show_hidden = true;
+
+        cx.spawn(async move |this, cx| {
+            let entries = std::thread::spawn(move || {
+                let mut entries = Vec::new();
+                if let Ok(read_dir) = fs::read_dir(&path) {
+                    for entry in read_dir.flatten() {
+                        let entry_path = entry.path();
+                        let name = entry_path
+                            .file_name()
+                            .and_then(|n| n.to_str())
+                            .unwrap_or("")
+                            .to_string();
+
+                        if !show_hidden && name.starts_with('.') {
+                            continue;
+                        }
+
+                        let is_dir = entry_path.is_dir();
+                        let metadata = entry_path.metadata().ok();
+                        let size = metadata.as_ref().map(|m| m.len()).unwrap_or(0);
+                        let modified = metadata
+                            .as_ref()
+                            .and_then(|m| m.modified().ok())
+                            .unwrap_or(std::time::SystemTime::UNIX_EPOCH);
+
+                        let file_type = if is_dir {
+                            crate::models::FileType::Directory
+                        } else {
+                            crate::models::FileType::RegularFile
+                        };
+
+                        let icon_key = if is_dir {
+                            crate::models::IconKey::Directory
+                        } else {
+                            entry_path
+                                .extension()
+                                .and_then(|ext| ext.to_str())
+                                .map(|ext| crate::models::IconKey::Extension(ext.to_lowercase()))
+                                .unwrap_or(crate::models::IconKey::GenericFile)
+                        };
+
+                        entries.push(crate::models::FileEntry {
+                            name,
+                            path: entry_path,
+                            is_dir,
+                            size,
+                            modified,
+                            file_type,
+                            icon_key,
+                            linux_permissions: None,
+                            sync_status: crate::models::CloudSyncStatus::None,
+                            is_symlink: false,
+                            symlink_target: None,
+                            is_broken_symlink: false,
+                            is_shared: false,
+                            hidden_kind: crate::models::HiddenKind::Visible,
+                            created: None,
+                            unix_mode: None,
+                        });
+                    }
+                }
+                entries.sort_by(|a, b| match (a.is_dir, b.is_dir) {
+                    (true, false) => std::cmp::Ordering::Less,
+                    (false, true) => std::cmp::Ordering::Greater,
+                    _ => a.name.to_lowercase().cmp(&b.name.to_lowercase()),
+                });
+                entries
+            })
+            .join()
+            .unwrap_or_default();
+
+            let _ = this.update(cx, |workspace, cx| {
+                workspace.dest_entries = entries;
+                cx.notify();
+            });
+        })
+        .detach();
+    }
+
+    fn navigate_dest_to(&mut self, path: PathBuf, cx: &mut Context<Self>) {
+        self.dest_path = path;
+        self.load_destination_entries(cx);
+    }
+
+    fn paste_to_destination(&mut self, cx: &mut Context<Self>) {
+        let Some(clipboard_op) = self.clipboard.clone() else {
+            return;
+        };
+
+        let (source_path, is_move) = match &clipboard_op {
+            ClipboardOperation::Copy(path) => (path.clone(), false),
+            ClipboardOperation::Cut(path) => (path.clone(), true),
+        };
+
+        let file_name = source_path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or("unknown")
+            .to_string();
+
+        let dest_path = self.dest_path.join(&file_name);
+
+        if dest_path.exists() {
+            if let Some(resolution) =
+                preset_conflict_resolution(self.conflict_apply_to_all, self.conflict_strategy)
+            {
+                self.handle_conflict_resolution(source_path, dest_path, is_move, resolution, cx);
+            } else {
+                self.show_conflict_dialog(source_path, dest_path, is_move, cx);
+            }
             return;
         }
 
@@ -2112,6 +3509,17 @@ impl Workspace {
         self.pending_conflicts.push((source, destination));
         self.conflict_dialog = Some(dialog);
 
+        let restore_to = self
+            .conflict_focus
+            .take()
+            .and_then(|mut trap| trap.take_restore_target());
+        let handles = (0..CONFLICT_FOCUS_COUNT).map(|_| cx.focus_handle()).collect();
+        self.conflict_focus = Some(
+            FocusTrap::new(handles)
+                .with_initial(CONFLICT_FOCUS_REPLACE)
+                .restoring(restore_to),
+        );
+
         if is_move {
             self.clipboard = Some(ClipboardOperation::Cut(
                 self.pending_conflicts.last().map(|(s, _)| s.clone()).unwrap_or_default()
@@ -2186,99 +3594,28 @@ impl Workspace {
     }
 
     fn execute_paste(&mut self, source: PathBuf, destination: PathBuf, is_move: bool, cx: &mut Context<Self>) {
-        self.clipboard = None;
+        self.set_clipboard(None, cx);
 
         self.copy_move_mode = false;
+        cx.notify();
 
-        let file_name = source
-            .file_name()
-            .and_then(|n| n.to_str())
-            .unwrap_or("unknown")
-            .to_string();
-
-        let action = if is_move { "Moving" } else { "Copying" };
-        self.toast_manager.update(cx, |toast, cx| {
-            toast.show_info(format!("{}: {}...", action, file_name), cx);
-        });
+        self.enqueue_paste(source, destination, is_move, true, cx);
+    }
 
+    fn finish_paste_operation(&mut self, cx: &mut Context<Self>) {
+        self.conflict_dialog = None;
+        self.release_conflict_focus(cx);
+        self.pending_conflicts.clear();
+        self.conflict_apply_to_all = None;
+        self.conflict_apply_all_checked = false;
+        self.overwrite_summary = None;
+        self.overwrite_summary_loading = false;
         cx.notify();
+    }
 
-        let source_clone = source.clone();
-        let dest_clone = destination.clone();
-        let name = file_name.clone();
-
-        cx.spawn(async move |this, cx| {
-            let result = std::thread::spawn(move || {
-                if source_clone.is_dir() {
-                    copy_dir_recursive_async(&source_clone, &dest_clone)
-                } else {
-                    fs::copy(&source_clone, &dest_clone).map(|_| ())
-                }
-            })
-            .join()
-            .unwrap_or_else(|_| {
-                Err(std::io::Error::new(
-                    std::io::ErrorKind::Other,
-                    "Thread panic",
-                ))
-            });
-
-            let _ = this.update(cx, |workspace, cx| match result {
-                Ok(()) => {
-                    if is_move {
-                        let _ = if source.is_dir() {
-                            fs::remove_dir_all(&source)
-                        } else {
-                            fs::remove_file(&source)
-                        };
-                        workspace.toast_manager.update(cx, |toast, cx| {
-                            toast.show_success(format!("Moved: {}", name), cx);
-                        });
-                    } else {
-                        workspace.toast_manager.update(cx, |toast, cx| {
-                            toast.show_success(format!("Copied: {}", name), cx);
-                        });
-                    }
-                    workspace.refresh_current_directory(cx);
-                    workspace.finish_paste_operation(cx);
-                }
-                Err(e) => {
-                    workspace.toast_manager.update(cx, |toast, cx| {
-                        toast.show_error(format!("Failed: {}", e), cx);
-                    });
-                    workspace.finish_paste_operation(cx);
-                }
-            });
-        })
-        .detach();
-    }
-
-    fn finish_paste_operation(&mut self, cx: &mut Context<Self>) {
-        self.conflict_dialog = None;
-        self.pending_conflicts.clear();
-        self.conflict_apply_to_all = None;
-        cx.notify();
-    }
-
-    fn generate_unique_name(&self, path: &PathBuf) -> PathBuf {
-        let stem = path.file_stem().and_then(|s| s.to_str()).unwrap_or("file");
-        let ext = path.extension().and_then(|e| e.to_str());
-        let parent = path.parent().unwrap_or(path);
-
-        let mut counter = 1;
-        loop {
-            let new_name = if let Some(ext) = ext {
-                format!("{} ({}).{}", stem, counter, ext)
-            } else {
-                format!("{} ({})", stem, counter)
-            };
-            let new_path = parent.join(&new_name);
-            if !new_path.exists() {
-                return new_path;
-            }
-            counter += 1;
-        }
-    }
+    fn generate_unique_name(&self, path: &PathBuf) -> PathBuf {
+        unique_destination(path)
+    }
 
     fn is_source_newer(&self, source: &PathBuf, dest: &PathBuf) -> bool {
         let source_time = source.metadata().and_then(|m| m.modified()).ok();
@@ -2301,6 +3638,13 @@ impl Workspace {
     }
 
     pub fn resolve_conflict(&mut self, resolution: ConflictResolution, apply_to_all: bool, cx: &mut Context<Self>) {
+        if self.overwrite_summary.is_some() || self.overwrite_summary_loading {
+            return;
+        }
+        if apply_to_all && resolution == ConflictResolution::Replace {
+            self.load_overwrite_summary(cx);
+            return;
+        }
         if apply_to_all {
             self.conflict_apply_to_all = Some(resolution);
         }
@@ -2309,13 +3653,204 @@ impl Workspace {
             let is_move = matches!(self.clipboard, Some(ClipboardOperation::Cut(_)));
             self.conflict_dialog = None;
             self.handle_conflict_resolution(source, destination, is_move, resolution, cx);
+            if self.conflict_dialog.is_none() {
+                self.release_conflict_focus(cx);
+            }
         }
     }
 
+    fn load_overwrite_summary(&mut self, cx: &mut Context<Self>) {
+        self.overwrite_summary_loading = true;
+        let conflicts = self.pending_conflicts.clone();
+        cx.spawn(async move |this, cx| {
+            let measured = conflicts.clone();
+            let summary = cx
+                .background_executor()
+                .spawn(async move { OverwriteSummary::from_conflicts(&measured) })
+                .await;
+            let _ = this.update(cx, |workspace, cx| {
+                if workspace.overwrite_summary_loading && workspace.pending_conflicts == conflicts {
+                    workspace.overwrite_summary_loading = false;
+                    workspace.overwrite_summary = Some(summary);
+                    cx.notify();
+                }
+            });
+        })
+        .detach();
+    }
+
     pub fn cancel_conflict_dialog(&mut self, cx: &mut Context<Self>) {
         self.conflict_dialog = None;
+        self.release_conflict_focus(cx);
         self.pending_conflicts.clear();
         self.conflict_apply_to_all = None;
+        self.conflict_apply_all_checked = false;
+        self.overwrite_summary = None;
+        self.overwrite_summary_loading = false;
+        cx.notify();
+    }
+
+    fn confirm_overwrite_summary(&mut self, cx: &mut Context<Self>) {
+        if self.overwrite_summary.take().is_none() {
+            return;
+        }
+
+        let is_move = matches!(self.clipboard, Some(ClipboardOperation::Cut(_)));
+        self.conflict_apply_to_all = Some(ConflictResolution::Replace);
+        self.conflict_dialog = None;
+        self.release_conflict_focus(cx);
+
+        let conflicts = std::mem::take(&mut self.pending_conflicts);
+        for (source, destination) in conflicts.into_iter().rev() {
+            self.handle_conflict_resolution(
+                source,
+                destination,
+                is_move,
+                ConflictResolution::Replace,
+                cx,
+            );
+        }
+    }
+
+    fn open_file(&mut self, path: PathBuf, cx: &mut Context<Self>) {
+        if let Some(kind) = detect_executable(&path) {
+            self.open_executable(path, kind, cx);
+        } else if let Err(e) = crate::models::open_with_preferred_app(&path) {
+            self.toast_manager.update(cx, |toast, cx| {
+                toast.show_error(e, cx);
+            });
+        }
+    }
+
+
+    fn open_executable(&mut self, path: PathBuf, kind: ExecutableKind, cx: &mut Context<Self>) {
+        match GlobalSettings::load().executable_open.action_for(kind) {
+            ExecutableAction::Ask => {
+                self.executable_prompt = Some(ExecutablePrompt::new(path, kind));
+                cx.notify();
+            }
+            action => self.perform_executable_action(path, action, cx),
+        }
+    }
+
+    fn perform_executable_action(&mut self, path: PathBuf, action: ExecutableAction, cx: &mut Context<Self>) {
+        let result = match action {
+            ExecutableAction::Ask => Ok(()),
+            ExecutableAction::Run => crate::models::run_executable(&path),
+            ExecutableAction::OpenInEditor => crate::models::open_in_text_editor(&path),
+            ExecutableAction::Reveal => {
+                if let Some(parent) = path.parent().filter(|parent| *parent != self.current_path) {
+                    self.navigate_to(parent.to_path_buf(), cx);
+                }
+                self.reveal_path(&path, cx);
+                Ok(())
+            }
+        };
+        if let Err(e) = result {
+            self.toast_manager.update(cx, |toast, cx| {
+                toast.show_error(e, cx);
+            });
+        }
+    }
+
+    fn resolve_executable_prompt(&mut self, action: ExecutableAction, cx: &mut Context<Self>) {
+        let Some(prompt) = self.executable_prompt.take() else {
+            return;
+        };
+        if prompt.remember {
+            let mut settings = GlobalSettings::load();
+            settings.executable_open.set(prompt.kind, action);
+            let _ = settings.save();
+        }
+        self.perform_executable_action(prompt.path, action, cx);
+        cx.notify();
+    }
+
+    fn toggle_executable_prompt_remember(&mut self, cx: &mut Context<Self>) {
+        if let Some(prompt) = self.executable_prompt.as_mut() {
+            prompt.remember = !prompt.remember;
+            cx.notify();
+        }
+    }
+
+    fn dismiss_executable_prompt(&mut self, cx: &mut Context<Self>) {
+        self.executable_prompt = None;
+        cx.notify();
+    }
+
+    fn cycle_executable_action(&mut self, kind: ExecutableKind, cx: &mut Context<Self>) {
+        let mut settings = GlobalSettings::load();
+        let action = settings.executable_open.action_for(kind).next();
+        settings.executable_open.set(kind, action);
+        let _ = settings.save();
+
+        let message = match kind {
+            ExecutableKind::Script => format!("Opening scripts: {}", action.label()),
+            ExecutableKind::Binary => format!("Opening programs: {}", action.label()),
+        };
+        self.toast_manager.update(cx, |toast, cx| {
+            toast.show_info(message, cx);
+        });
+    }
+
+    fn dismiss_overwrite_summary(&mut self, cx: &mut Context<Self>) {
+        self.overwrite_summary = None;
+        self.overwrite_summary_loading = false;
+        self.conflict_apply_all_checked = false;
+        cx.notify();
+    }
+
+    fn release_conflict_focus(&mut self, cx: &mut Context<Self>) {
+        if let Some(trap) = self.conflict_focus.take() {
+            trap.release(cx);
+        }
+    }
+
+    fn handle_conflict_key_down(
+        &mut self,
+        event: &KeyDownEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(trap) = &self.conflict_focus else {
+            return;
+        };
+        let Some(key) = TrapKey::from_keystroke(&event.keystroke) else {
+            return;
+        };
+        if self.overwrite_summary.is_some() {
+            match key {
+                TrapKey::Confirm => self.confirm_overwrite_summary(cx),
+                TrapKey::Dismiss => self.dismiss_overwrite_summary(cx),
+                TrapKey::Next | TrapKey::Previous => {}
+            }
+            cx.stop_propagation();
+            return;
+        }
+
+        let apply_to_all = self.conflict_apply_all_checked;
+        match key {
+            TrapKey::Next | TrapKey::Previous => trap.cycle(key == TrapKey::Previous, window),
+            TrapKey::Dismiss => self.cancel_conflict_dialog(cx),
+            TrapKey::Confirm => match trap.focused_index(window) {
+                Some(CONFLICT_FOCUS_SKIP) => {
+                    self.resolve_conflict(ConflictResolution::Skip, apply_to_all, cx)
+                }
+                Some(CONFLICT_FOCUS_KEEP_BOTH) => {
+                    self.resolve_conflict(ConflictResolution::KeepBoth, apply_to_all, cx)
+                }
+                Some(CONFLICT_FOCUS_CANCEL) => self.cancel_conflict_dialog(cx),
+                _ => self.resolve_conflict(ConflictResolution::Replace, apply_to_all, cx),
+            },
+        }
+        cx.stop_propagation();
+    }
+
+    fn close_symlink_dialog(&mut self, cx: &mut Context<Self>) {
+        if let Some(dialog) = self.symlink_dialog.take() {
+            let restore_to = dialog.update(cx, |dialog, _| dialog.take_restore_focus());
+            restore_focus(restore_to, cx);
+        }
         cx.notify();
     }
 
@@ -2372,124 +3907,358 @@ impl Workspace {
             return;
         }
 
-        let file_path = self.current_path.join(name);
-        if let Err(e) = fs::File::create(&file_path) {
-            eprintln!("Failed to create file: {}", e);
+        if let Err(e) = create_new_entry(&self.current_path, name, NewEntryKind::File) {
+            self.show_creation_error(NewEntryKind::File, name, &e, cx);
             return;
         }
 
         self.dialog_state = DialogState::None;
+        self.dialog_input = None;
         self.refresh_current_directory(cx);
     }
 
+    fn show_creation_error(
+        &mut self,
+        kind: NewEntryKind,
+        name: &str,
+        error: &std::io::Error,
+        cx: &mut Context<Self>,
+    ) {
+        let message = creation_error_message(kind, name, error);
+        self.toast_manager.update(cx, |toast, cx| {
+            toast.show_error(message, cx);
+        });
+        self.should_focus_dialog_input = true;
+        cx.notify();
+    }
+
+    fn new_file_from_template(&mut self, template: FileTemplate, cx: &mut Context<Self>) {
+        let destination = template_destination(&template, &self.current_path);
+        let name = destination
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_else(|| template.file_name.clone());
+
+        match create_from_template(&template, &self.current_path, &name) {
+            Ok(path) => {
+                self.pending_select_name = Some((self.current_path.clone(), name.clone()));
+                self.refresh_current_directory(cx);
+                self.start_rename(path, cx);
+            }
+            Err(e) => {
+                self.toast_manager.update(cx, |toast, cx| {
+                    toast.show_error(format!("Failed to create {}: {}", name, e), cx);
+                });
+            }
+        }
+    }
+
     fn create_new_folder(&mut self, name: &str, cx: &mut Context<Self>) {
         if name.is_empty() {
             return;
         }
 
-        let folder_path = self.current_path.join(name);
-        if let Err(e) = fs::create_dir(&folder_path) {
-            eprintln!("Failed to create folder: {}", e);
+        let folder_path = match create_new_entry(&self.current_path, name, NewEntryKind::Folder) {
+            Ok(path) => path,
+            Err(e) => {
+                self.show_creation_error(NewEntryKind::Folder, name, &e, cx);
+                return;
+            }
+        };
+
+        self.dialog_state = DialogState::None;
+        self.dialog_input = None;
+        if self.open_created_folder {
+            self.navigate_to(folder_path, cx);
+            self.open_dialog(false, cx);
             return;
         }
 
-        self.dialog_state = DialogState::None;
+        self.pending_select_name = Some((self.current_path.clone(), name.to_string()));
         self.refresh_current_directory(cx);
     }
 
-    fn cancel_dialog(&mut self, cx: &mut Context<Self>) {
-        self.dialog_state = DialogState::None;
-        self.dialog_input = None;
-        self.should_focus_dialog_input = false;
-        cx.notify();
+    fn select_entry_named(&mut self, name: &str, cx: &mut Context<Self>) {
+        self.file_list.update(cx, |view, cx| {
+            view.reveal_entry_named(name, cx);
+        });
+        self.grid_view.update(cx, |view, cx| {
+            view.inner_mut().select_entry_named(name);
+            cx.notify();
+        });
     }
 
-    fn handle_search_query_change(&mut self, query: &str, cx: &mut Context<Self>) {
-        if query.is_empty() {
-            self.file_list.update(cx, |view, _| {
-                view.inner_mut().clear_search_filter();
-            });
-        } else {
-            let matches = self.search_engine.update(cx, |engine, _| {
-                engine.set_pattern(query);
-                let snapshot = engine.snapshot();
-                snapshot.matches
-            });
+    fn selected_paths(&self, cx: &mut Context<Self>) -> Vec<PathBuf> {
+        let Some(entry) = self.get_selected_entry(cx) else {
+            return Vec::new();
+        };
+        self.selection_for(&entry.path, cx)
+    }
 
-            let file_matches: Vec<(usize, Vec<usize>, u32)> = matches
-                .iter()
-                .filter_map(|m| {
-                    self.cached_entries
-                        .iter()
-                        .position(|e| e.path == m.path)
-                        .map(|idx| (idx, m.positions.clone(), m.score))
-                })
-                .collect();
+    fn selection_for(&self, path: &Path, cx: &mut Context<Self>) -> Vec<PathBuf> {
+        let marked = match self.view_mode {
+            ViewMode::List | ViewMode::Details => self.file_list.read(cx).inner().marked().to_vec(),
+            ViewMode::Grid => self.grid_view.read(cx).inner().marked().to_vec(),
+        };
+        context_selection(&marked, path)
+    }
 
-            self.file_list.update(cx, |view, _| {
-                view.inner_mut().apply_search_filter(query, file_matches);
-            });
+    fn leave_directories_being_deleted(&mut self, paths: &[PathBuf], cx: &mut Context<Self>) -> bool {
+        match guard_deletion(paths, &self.current_path) {
+            DeletionGuard::Clear => true,
+            DeletionGuard::NavigateTo(parent) => {
+                self.navigate_to(parent, cx);
+                true
+            }
+            DeletionGuard::Blocked(path) => {
+                self.toast_manager.update(cx, |toast, cx| {
+                    toast.show_error(
+                        format!("Can't delete {}: it contains the current folder", path.display()),
+                        cx,
+                    );
+                });
+                false
+            }
         }
-        cx.notify();
     }
 
+    fn move_selection_to_trash(&mut self, paths: Vec<PathBuf>, cx: &mut Context<Self>) {
+        if paths.is_empty() || !self.leave_directories_being_deleted(&paths, cx) {
+            return;
+        }
 
-    fn load_directory(&mut self, path: PathBuf, cx: &mut Context<Self>) {
-        let start = Instant::now();
-        let show_hidden = self.show_hidden_files;
+        let location = GlobalSettings::load().trash_location;
+        let mut trashed = Vec::new();
+        let mut first_error = None;
+        for path in &paths {
+            match move_path_to_trash(path, location) {
+                Ok(()) => trashed.push(path.clone()),
+                Err(e) => {
+                    first_error.get_or_insert(e);
+                }
+            }
+        }
 
-        self.file_system.update(cx, |fs, _| {
-            let op = fs.load_path(
-                path.clone(),
-                SortKey::Name,
-                SortOrder::Ascending,
-                show_hidden,
-            );
-            let request_id = op.request_id;
-            while let Ok(batch) = op.batch_receiver.recv() {
-                fs.process_batch(request_id, batch);
+        if !trashed.is_empty() {
+            self.file_list.update(cx, |view, _| {
+                view.inner_mut().set_selected_index(None);
+                view.inner_mut().clear_marked();
+            });
+            self.grid_view.update(cx, |view, _| view.inner_mut().clear_marked());
+            self.preview = None;
+        }
+        self.toast_manager.update(cx, |toast, cx| match (&first_error, trashed.as_slice()) {
+            (None, [path]) => {
+                let name = path
+                    .file_name()
+                    .and_then(|n| n.to_str())
+                    .unwrap_or("item");
+                toast.show_success(format!("Moved to Trash: {}", name), cx);
             }
-            let _ = op.traversal_handle.join();
-            fs.finalize_load(request_id, start.elapsed());
+            (None, _) => toast.show_success(format!("Moved {} items to Trash", trashed.len()), cx),
+            (Some(e), _) => toast.show_error(format!("Failed to trash: {}", e), cx),
         });
+        if !trashed.is_empty() {
+            self.refresh_current_directory(cx);
+        }
+    }
 
-        let mut entries = self.file_system.read(cx).entries().to_vec();
-        
-        self.update_share_status_on_entries(&mut entries);
-        
-        self.cached_entries = entries.clone();
-        self.current_path = path.clone();
+    fn start_new_folder_with_selection(&mut self, cx: &mut Context<Self>) {
+        let paths = self.selected_paths(cx);
+        if paths.is_empty() {
+            self.open_dialog(false, cx);
+            return;
+        }
 
-        self.file_list.update(cx, |view, _| {
-            view.inner_mut().set_entries(entries.clone());
-        });
+        let name = unique_destination(&self.current_path.join(NEW_FOLDER_WITH_ITEMS_NAME))
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_else(|| NEW_FOLDER_WITH_ITEMS_NAME.to_string());
+        self.dialog_state = DialogState::NewFolderWithSelection { paths };
 
-        self.grid_view.update(cx, |view, _| {
-            view.inner_mut().set_entries(entries.clone());
+        let input_state = cx.new(|cx| {
+            let mut state = InputState::new(cx);
+            state.content = name.into();
+            state.select_on_focus = true;
+            state
         });
 
-        self.search_engine.update(cx, |engine, _| {
-            engine.clear();
-            for entry in &entries {
-                engine.inject(entry.path.clone());
-            }
-        });
+        cx.subscribe(
+            &input_state,
+            |workspace: &mut Workspace, _input, event: &InputEvent, cx| match event {
+                InputEvent::Enter => {
+                    workspace.submit_dialog(cx);
+                }
+                _ => {}
+            },
+        )
+        .detach();
 
-        self.sidebar.update(cx, |view, _| {
-            view.set_current_directory(path.clone());
-        });
+        self.dialog_input = Some(input_state);
+        self.should_focus_dialog_input = true;
+        cx.notify();
+    }
 
-        self.status_bar.update(cx, |view, cx| {
-            view.update_from_entries(&entries, None, cx);
-            view.set_current_directory(&path, cx);
+    fn create_folder_with_selection(&mut self, name: &str, paths: Vec<PathBuf>, cx: &mut Context<Self>) {
+        let folder_path = match create_new_entry(&self.current_path, name, NewEntryKind::Folder) {
+            Ok(path) => path,
+            Err(e) => {
+                self.show_creation_error(NewEntryKind::Folder, name, &e, cx);
+                return;
+            }
+        };
+
+        self.dialog_state = DialogState::None;
+        self.dialog_input = None;
+
+        let outcome = move_into_folder(&paths, &folder_path);
+        self.file_list.update(cx, |view, cx| {
+            view.inner_mut().clear_marked();
+            cx.notify();
+        });
+        self.grid_view.update(cx, |view, cx| {
+            view.inner_mut().clear_marked();
+            cx.notify();
         });
 
+        if let Some((_, error)) = outcome.failed.first() {
+            let message = format!(
+                "Couldn't move {} of {} items into {}: {}",
+                outcome.failed.len(),
+                paths.len(),
+                name,
+                error
+            );
+            self.toast_manager.update(cx, |toast, cx| {
+                toast.show_error(message, cx);
+            });
+        }
+
+        self.pending_select_name = Some((self.current_path.clone(), name.to_string()));
+        self.refresh_current_directory(cx);
+
+        for (source, destination) in outcome.conflicts {
+            if let Some(resolution) =
+                preset_conflict_resolution(self.conflict_apply_to_all, self.conflict_strategy)
+            {
+                self.handle_conflict_resolution(source, destination, true, resolution, cx);
+            } else {
+                self.show_conflict_dialog(source, destination, true, cx);
+            }
+        }
+    }
+
+    fn toggle_open_created_folder(&mut self, cx: &mut Context<Self>) {
+        self.open_created_folder = !self.open_created_folder;
         cx.notify();
     }
 
-    pub fn navigate_to(&mut self, path: PathBuf, cx: &mut Context<Self>) {
+    fn cancel_dialog(&mut self, cx: &mut Context<Self>) {
+        self.dialog_state = DialogState::None;
+        self.dialog_input = None;
+        self.should_focus_dialog_input = false;
+        cx.notify();
+    }
+
+    fn apply_tag_filter(&mut self, tag: &crate::models::Tag, cx: &mut Context<Self>) {
+        let query = crate::views::tag_filter_query(tag);
+        if self.search_input.read(cx).query() == query {
+            self.handle_search_query_change(&query, cx);
+        } else {
+            self.search_input.update(cx, |view, cx| {
+                view.set_query(query, cx);
+            });
+        }
+    }
+
+
+    fn restore_tab_search(&mut self, cx: &mut Context<Self>) {
+        let query = self.tab_bar.read(cx).tab_state().active_search_query().to_string();
+        if self.search_input.read(cx).query() == query {
+            self.handle_search_query_change(&query, cx);
+        } else {
+            self.search_input.update(cx, |view, cx| {
+                view.set_query(query, cx);
+            });
+        }
+    }
+
+    fn handle_search_query_change(&mut self, query: &str, cx: &mut Context<Self>) {
+        let (text_query, tag_names) = crate::views::parse_tag_query(query);
+
+        if query.is_empty() {
+            self.stop_directory_watch();
+            if self
+                .flatten_session
+                .as_ref()
+                .is_some_and(FlattenSession::is_running)
+            {
+                self.stop_flatten_view(cx);
+            }
+            self.file_list.update(cx, |view, _| {
+                view.inner_mut().clear_search_filter();
+            });
+        } else {
+            self.start_directory_watch(cx);
+            let tagged = self.entries_matching_tags(&tag_names, cx);
+
+            let file_matches: Vec<(usize, Vec<usize>, u32)> = if text_query.is_empty() {
+                tagged.into_iter().map(|idx| (idx, Vec::new(), 0)).collect()
+            } else {
+                let matches = self.search_engine.update(cx, |engine, _| {
+                    engine.set_pattern(&text_query);
+                    let snapshot = engine.snapshot();
+                    snapshot.matches
+                });
+
+                matches
+                    .iter()
+                    .filter_map(|m| {
+                        self.cached_entries
+                            .iter()
+                            .position(|e| e.path == m.path)
+                            .map(|idx| (idx, m.positions.clone(), m.score))
+                    })
+                    .filter(|(idx, _, _)| tag_names.is_empty() || tagged.contains(idx))
+                    .collect()
+            };
+
+            self.file_list.update(cx, |view, _| {
+                view.inner_mut().apply_search_filter(query, file_matches);
+            });
+        }
+        cx.notify();
+    }
+
+
+    fn entries_matching_tags(&self, tag_names: &[String], cx: &App) -> Vec<usize> {
+        if tag_names.is_empty() {
+            return Vec::new();
+        }
+        let Some(manager) = cx.try_global::<crate::models::TagManager>() else {
+            return Vec::new();
+        };
+        let tag_ids: Vec<_> = tag_names
+            .iter()
+            .filter_map(|name| manager.get_tag_by_name(name).map(|tag| tag.id))
+            .collect();
+        if tag_ids.len() != tag_names.len() {
+            return Vec::new();
+        }
+
+        self.cached_entries
+            .iter()
+            .enumerate()
+            .filter(|(_, entry)| tag_ids.iter().all(|id| manager.has_tag(&entry.path, *id)))
+            .map(|(idx, _)| idx)
+            .collect()
+    }
+
+
+    fn load_directory(&mut self, path: PathBuf, cx: &mut Context<Self>) {
+        self.stop_flatten_session(cx);
         let start = Instant::now();
-        let show_hidden = self.show_hidden_files;
+        let show_hidden = self.show_hidden_for(&path);
 
         self.file_system.update(cx, |fs, _| {
             let op = fs.load_path(
@@ -2499,29 +4268,21 @@ impl Workspace {
                 show_hidden,
             );
             let request_id = op.request_id;
-
             while let Ok(batch) = op.batch_receiver.recv() {
                 fs.process_batch(request_id, batch);
             }
-
-            let _ = op.traversal_handle.join();
+            if let Ok(Ok(summary)) = op.traversal_handle.join() {
+                fs.set_read_errors(request_id, summary.errors);
+            }
             fs.finalize_load(request_id, start.elapsed());
         });
 
-        let cloud_manager = self.sidebar.read(cx).sidebar().cloud_manager().clone();
-        self.file_system.update(cx, |fs, _| {
-            fs.update_sync_status(&cloud_manager);
-        });
-
         let mut entries = self.file_system.read(cx).entries().to_vec();
         
         self.update_share_status_on_entries(&mut entries);
         
         self.cached_entries = entries.clone();
-
-        self.search_input.update(cx, |view, cx| {
-            view.clear(cx);
-        });
+        self.current_path = path.clone();
 
         self.file_list.update(cx, |view, _| {
             view.inner_mut().set_entries(entries.clone());
@@ -2538,10 +4299,60 @@ impl Workspace {
             }
         });
 
-        self.path_history.push(path.clone());
+        self.sidebar.update(cx, |view, _| {
+            view.set_current_directory(path.clone());
+        });
+
+        let unreadable = UnreadableItem::from_errors(self.file_system.read(cx).read_errors());
+        self.status_bar.update(cx, |view, cx| {
+            view.update_from_entries(&entries, None, cx);
+            view.set_unreadable(unreadable, cx);
+            view.set_current_directory(&path, cx);
+        });
+        self.apply_folder_view_mode(&path, cx);
+        self.sync_hidden_files_toggle(show_hidden, cx);
+        self.update_git_context(&path, cx);
+        self.refresh_volume_space(false, cx);
+
+        cx.notify();
+    }
+
+    pub fn navigate_to(&mut self, path: PathBuf, cx: &mut Context<Self>) {
+        self.stop_flatten_session(cx);
+        self.remember_scroll(cx);
+        let is_new_visit = record_visit(
+            &mut self.path_history,
+            &mut self.forward_history,
+            path.clone(),
+            self.history_limit,
+        );
+        if is_new_visit {
+            self.sidebar.update(cx, |view, cx| {
+                view.record_folder_visit(&path, cx);
+            });
+        }
+        let start = Instant::now();
+        let show_hidden = self.show_hidden_for(&path);
+
+        let op = self.file_system.update(cx, |fs, _| {
+            fs.load_path(
+                path.clone(),
+                SortKey::Name,
+                SortOrder::Ascending,
+                show_hidden,
+            )
+        });
+        self.load_skeleton.begin(op.request_id, start);
+        let finished = self.receive_batches_until_deadline(&op, cx);
+
+        self.search_input.update(cx, |view, cx| {
+            view.clear(cx);
+        });
+
+        self.history_menu = None;
         self.current_path = path.clone();
 
-        if self.tabs_enabled {
+        if self.tabs_enabled && is_new_visit {
             self.tab_bar.update(cx, |view, cx| {
                 view.navigate_to(path.clone(), cx);
             });
@@ -2551,10 +4362,13 @@ impl Workspace {
             view.set_current_directory(path.clone());
         });
 
-        self.status_bar.update(cx, |view, cx| {
-            view.update_from_entries(&entries, None, cx);
-            view.set_current_directory(&path, cx);
-        });
+        if finished {
+            let LoadOperation { request_id, traversal_handle, .. } = op;
+            let summary = traversal_handle.join();
+            self.finish_directory_load(request_id, summary, start, cx);
+        } else {
+            self.stream_directory_load(op, start, cx);
+        }
 
         if self.is_terminal_open {
             if let Some(terminal) = self.active_terminal(cx) {
@@ -2568,124 +4382,339 @@ impl Workspace {
         cx.notify();
     }
 
-    pub fn navigate_back(&mut self, cx: &mut Context<Self>) {
-        if self.path_history.len() > 1 {
-            self.path_history.pop();
-            if let Some(prev_path) = self.path_history.last().cloned() {
-                let start = Instant::now();
-                let show_hidden = self.show_hidden_files;
-
-                self.file_system.update(cx, |fs, _| {
-                    let op = fs.load_path(
-                        prev_path.clone(),
-                        SortKey::Name,
-                        SortOrder::Ascending,
-                        show_hidden,
-                    );
-                    let request_id = op.request_id;
+    fn receive_batches_until_deadline(&mut self, op: &LoadOperation, cx: &mut Context<Self>) -> bool {
+        let Some(deadline) = self.load_skeleton.deadline() else {
+            return false;
+        };
+        loop {
+            match op.batch_receiver.recv_deadline(deadline) {
+                Ok(batch) => {
+                    self.file_system.update(cx, |fs, _| {
+                        fs.process_batch(op.request_id, batch);
+                    });
+                }
+                Err(flume::RecvTimeoutError::Disconnected) => return true,
+                Err(flume::RecvTimeoutError::Timeout) => return false,
+            }
+        }
+    }
 
-                    while let Ok(batch) = op.batch_receiver.recv() {
-                        fs.process_batch(request_id, batch);
-                    }
+    fn stream_directory_load(&mut self, op: LoadOperation, start: Instant, cx: &mut Context<Self>) {
+        let LoadOperation {
+            request_id,
+            batch_receiver,
+            traversal_handle,
+        } = op;
+
+        if !self.file_system.read(cx).entries().is_empty() {
+            self.load_skeleton.receive_batch(request_id);
+            self.show_partial_entries(cx);
+        } else if self.load_skeleton.tick(Instant::now()) {
+            let viewport = self.file_list.read(cx).scroll_geometry().viewport;
+            self.cached_entries.clear();
+            self.file_list.update(cx, |view, cx| {
+                view.inner_mut().show_skeleton(viewport);
+                cx.notify();
+            });
+            self.grid_view.update(cx, |view, cx| {
+                view.inner_mut().set_entries(Vec::new());
+                cx.notify();
+            });
+        }
 
-                    let _ = op.traversal_handle.join();
-                    fs.finalize_load(request_id, start.elapsed());
-                });
+        cx.spawn(async move |this, cx| {
+            while let Ok(batch) = batch_receiver.recv_async().await {
+                let keep_going = this
+                    .update(cx, |workspace, cx| {
+                        workspace.receive_directory_batch(request_id, batch, cx)
+                    })
+                    .unwrap_or(false);
+                if !keep_going {
+                    return;
+                }
+            }
 
-                let mut entries = self.file_system.read(cx).entries().to_vec();
-                
-                self.update_share_status_on_entries(&mut entries);
-                
-                self.cached_entries = entries.clone();
+            let summary = cx
+                .background_executor()
+                .spawn(async move { traversal_handle.join() })
+                .await;
+            let _ = this.update(cx, |workspace, cx| {
+                if workspace.load_skeleton.is_loading(request_id) {
+                    workspace.finish_directory_load(request_id, summary, start, cx);
+                    cx.notify();
+                }
+            });
+        })
+        .detach();
+    }
 
-                self.search_input.update(cx, |view, cx| {
-                    view.clear(cx);
-                });
+    fn receive_directory_batch(
+        &mut self,
+        request_id: usize,
+        batch: Vec<crate::models::FileEntry>,
+        cx: &mut Context<Self>,
+    ) -> bool {
+        if !self.load_skeleton.is_loading(request_id) {
+            return false;
+        }
+        let accepted = self
+            .file_system
+            .update(cx, |fs, _| fs.process_batch(request_id, batch));
+        if accepted.is_none() {
+            return false;
+        }
+        self.load_skeleton.receive_batch(request_id);
+        self.show_partial_entries(cx);
+        true
+    }
 
-                self.file_list.update(cx, |view, _| {
-                    view.inner_mut().set_entries(entries.clone());
-                });
+    fn show_partial_entries(&mut self, cx: &mut Context<Self>) {
+        let entries = self.file_system.read(cx).entries().to_vec();
+        self.cached_entries = entries.clone();
+        self.file_list.update(cx, |view, cx| {
+            view.inner_mut().set_entries(entries.clone());
+            cx.notify();
+        });
+        self.grid_view.update(cx, |view, cx| {
+            view.inner_mut().set_entries(entries);
+            cx.notify();
+        });
+    }
 
-                self.grid_view.update(cx, |view, _| {
-                    view.inner_mut().set_entries(entries.clone());
-                });
+    fn finish_directory_load(
+        &mut self,
+        request_id: usize,
+        summary: std::thread::Result<crate::models::Result<TraversalSummary>>,
+        start: Instant,
+        cx: &mut Context<Self>,
+    ) {
+        self.file_system.update(cx, |fs, _| {
+            if let Ok(Ok(summary)) = summary {
+                fs.set_read_errors(request_id, summary.errors);
+            }
+            fs.finalize_load(request_id, start.elapsed());
+        });
+        self.load_skeleton.finish(request_id);
 
-                self.search_engine.update(cx, |engine, _| {
-                    engine.clear();
-                    for entry in &entries {
-                        engine.inject(entry.path.clone());
-                    }
-                });
+        let path = self.current_path.clone();
+        let show_hidden = self.show_hidden_for(&path);
+        let cloud_manager = self.sidebar.read(cx).sidebar().cloud_manager().clone();
+        self.file_system.update(cx, |fs, _| {
+            fs.update_sync_status(&cloud_manager);
+        });
 
-                self.current_path = prev_path.clone();
+        let mut entries = self.file_system.read(cx).entries().to_vec();
+        
+        self.update_share_status_on_entries(&mut entries);
+        
+        self.cached_entries = entries.clone();
 
-                self.sidebar.update(cx, |view, _| {
-                    view.set_current_directory(prev_path.clone());
-                });
+        self.file_list.update(cx, |view, _| {
+            view.inner_mut().set_entries(entries.clone());
+        });
 
-                self.status_bar.update(cx, |view, cx| {
-                    view.update_from_entries(&entries, None, cx);
-                    view.set_current_directory(&prev_path, cx);
-                });
+        self.grid_view.update(cx, |view, _| {
+            view.inner_mut().set_entries(entries.clone());
+        });
 
-                if self.is_terminal_open {
-                    if let Some(terminal) = self.active_terminal(cx) {
-                        let terminal_path = prev_path.clone();
-                        terminal.update(cx, |terminal, _| {
-                            terminal.change_directory(terminal_path);
-                        });
-                    }
-                }
+        self.search_engine.update(cx, |engine, _| {
+            engine.clear();
+            for entry in &entries {
+                engine.inject(entry.path.clone());
+            }
+        });
 
-                cx.notify();
+        let unreadable = UnreadableItem::from_errors(self.file_system.read(cx).read_errors());
+        self.status_bar.update(cx, |view, cx| {
+            view.update_from_entries(&entries, None, cx);
+            view.set_unreadable(unreadable, cx);
+            view.set_current_directory(&path, cx);
+        });
+        self.apply_folder_view_mode(&path, cx);
+        self.restore_scroll(None, cx);
+        if let Some((dir, name)) = self.pending_select_name.take() {
+            if dir == path {
+                self.select_entry_named(&name, cx);
             }
         }
+        self.sync_hidden_files_toggle(show_hidden, cx);
+        self.update_git_context(&path, cx);
     }
 
-    pub fn navigate_up(&mut self, cx: &mut Context<Self>) {
-        if let Some(parent) = self.current_path.parent() {
-            self.navigate_to(parent.to_path_buf(), cx);
-        }
+    fn text_input_focused(&self, window: &Window, cx: &App) -> bool {
+        let input_focused = self
+            .dialog_input
+            .iter()
+            .chain(self.format_dialog.as_ref().map(|(_, _, input)| input))
+            .any(|input| input.read(cx).focus_handle(cx).is_focused(window));
+        input_focused
+            || self.search_input.focus_handle(cx).is_focused(window)
+            || self.sidebar.read(cx).is_renaming_tag(window, cx)
+            || self.sidebar.read(cx).is_renaming_favorite_group(window, cx)
     }
 
-
-    fn update_share_status_on_entries(&mut self, entries: &mut Vec<crate::models::FileEntry>) {
-        for entry in entries.iter_mut() {
-            if entry.is_dir {
-                entry.is_shared = self.share_manager.is_shared(&entry.path);
-            }
+    fn handle_mouse_navigation(&mut self, event: &MouseDownEvent, window: &mut Window, cx: &mut Context<Self>) {
+        match mouse_history_step(event.button, self.text_input_focused(window, cx)) {
+            Some(HistoryStep::Back) => self.navigate_back(cx),
+            Some(HistoryStep::Forward) => self.navigate_forward(cx),
+            None => return,
         }
+        cx.stop_propagation();
     }
 
-    pub fn toggle_terminal(&mut self, cx: &mut Context<Self>) {
-        self.is_terminal_open = !self.is_terminal_open;
+    pub fn navigate_back(&mut self, cx: &mut Context<Self>) {
+        self.navigate_back_steps(1, cx);
+    }
 
-        if self.is_terminal_open {
-            let terminal = self.get_or_create_terminal(cx);
-            let current_path = self.current_path.clone();
-            terminal.update(cx, |terminal, _| {
-                terminal.set_working_directory(current_path);
-                terminal.set_visible(true);
-                if !terminal.is_running() {
-                    let _ = terminal.start();
-                }
-            });
+    pub fn navigate_forward(&mut self, cx: &mut Context<Self>) {
+        self.navigate_forward_steps(1, cx);
+    }
+
+    fn navigate_back_steps(&mut self, steps: usize, cx: &mut Context<Self>) {
+        self.history_menu = None;
+        if let Some(path) = jump_back(&mut self.path_history, &mut self.forward_history, steps) {
+            self.show_history_path(path, cx);
         } else {
-            if let Some(terminal) = self.active_terminal(cx) {
-                terminal.update(cx, |terminal, _| {
-                    terminal.set_visible(false);
-                });
-            }
+            cx.notify();
         }
-
-        self.status_bar.update(cx, |view, cx| {
-            view.set_terminal_open(self.is_terminal_open, cx);
-        });
-        cx.notify();
     }
 
-    pub fn toggle_theme_picker(&mut self, cx: &mut Context<Self>) {
-        self.theme_picker.update(cx, |picker, cx| {
+    fn navigate_forward_steps(&mut self, steps: usize, cx: &mut Context<Self>) {
+        self.history_menu = None;
+        if let Some(path) = jump_forward(&mut self.path_history, &mut self.forward_history, steps) {
+            self.show_history_path(path, cx);
+        } else {
+            cx.notify();
+        }
+    }
+
+    fn show_history_path(&mut self, path: PathBuf, cx: &mut Context<Self>) {
+        self.stop_flatten_session(cx);
+        self.remember_scroll(cx);
+        let start = Instant::now();
+        let show_hidden = self.show_hidden_for(&path);
+
+        self.file_system.update(cx, |fs, _| {
+            let op = fs.load_path(
+                path.clone(),
+                SortKey::Name,
+                SortOrder::Ascending,
+                show_hidden,
+            );
+            let request_id = op.request_id;
+
+            while let Ok(batch) = op.batch_receiver.recv() {
+                fs.process_batch(request_id, batch);
+            }
+
+            if let Ok(Ok(summary)) = op.traversal_handle.join() {
+                fs.set_read_errors(request_id, summary.errors);
+            }
+            fs.finalize_load(request_id, start.elapsed());
+        });
+
+        let mut entries = self.file_system.read(cx).entries().to_vec();
+        
+        self.update_share_status_on_entries(&mut entries);
+        
+        self.cached_entries = entries.clone();
+
+        self.search_input.update(cx, |view, cx| {
+            view.clear(cx);
+        });
+
+        self.file_list.update(cx, |view, _| {
+            view.inner_mut().set_entries(entries.clone());
+        });
+
+        self.grid_view.update(cx, |view, _| {
+            view.inner_mut().set_entries(entries.clone());
+        });
+
+        self.search_engine.update(cx, |engine, _| {
+            engine.clear();
+            for entry in &entries {
+                engine.inject(entry.path.clone());
+            }
+        });
+
+        self.current_path = path.clone();
+
+        self.sidebar.update(cx, |view, _| {
+            view.set_current_directory(path.clone());
+        });
+
+        let unreadable =
+            UnreadableItem::from_errors(self.file_system.read(cx).read_errors());
+        self.status_bar.update(cx, |view, cx| {
+            view.update_from_entries(&entries, None, cx);
+            view.set_unreadable(unreadable, cx);
+            view.set_current_directory(&path, cx);
+        });
+        self.apply_folder_view_mode(&path, cx);
+        let anchor = self.restore_selection(cx);
+        self.restore_scroll(anchor, cx);
+        self.sync_preview_with_selection(cx);
+        self.update_preview_for_selection(cx);
+        self.sync_hidden_files_toggle(show_hidden, cx);
+        self.update_git_context(&path, cx);
+
+        if self.is_terminal_open {
+            if let Some(terminal) = self.active_terminal(cx) {
+                let terminal_path = path.clone();
+                terminal.update(cx, |terminal, _| {
+                    terminal.change_directory(terminal_path);
+                });
+            }
+        }
+
+        cx.notify();
+    }
+
+    pub fn navigate_up(&mut self, cx: &mut Context<Self>) {
+        if let Some(parent) = self.current_path.parent() {
+            self.navigate_to(parent.to_path_buf(), cx);
+        }
+    }
+
+
+    fn update_share_status_on_entries(&mut self, entries: &mut Vec<crate::models::FileEntry>) {
+        for entry in entries.iter_mut() {
+            if entry.is_dir {
+                entry.is_shared = self.share_manager.is_shared(&entry.path);
+            }
+        }
+    }
+
+    pub fn toggle_terminal(&mut self, cx: &mut Context<Self>) {
+        self.is_terminal_open = !self.is_terminal_open;
+
+        if self.is_terminal_open {
+            let terminal = self.get_or_create_terminal(cx);
+            let current_path = self.current_path.clone();
+            terminal.update(cx, |terminal, _| {
+                terminal.set_working_directory(current_path);
+                terminal.set_visible(true);
+                if !terminal.is_running() {
+                    let _ = terminal.start();
+                }
+            });
+        } else {
+            if let Some(terminal) = self.active_terminal(cx) {
+                terminal.update(cx, |terminal, _| {
+                    terminal.set_visible(false);
+                });
+            }
+        }
+
+        self.status_bar.update(cx, |view, cx| {
+            view.set_terminal_open(self.is_terminal_open, cx);
+        });
+        cx.notify();
+    }
+
+    pub fn toggle_theme_picker(&mut self, cx: &mut Context<Self>) {
+        self.theme_picker.update(cx, |picker, cx| {
             picker.toggle(cx);
         });
         cx.notify();
@@ -2746,18 +4775,51 @@ impl Workspace {
         cx.notify();
     }
 
+    pub fn set_accent_hue(&mut self, hue: Option<f32>, cx: &mut Context<Self>) {
+        self.accent_hue = hue;
+        crate::models::set_accent_override(hue);
+
+        let mut settings = GlobalSettings::load();
+        settings.accent_hue = hue;
+        let _ = settings.save();
+        cx.notify();
+    }
+
     pub fn toggle_view_mode(&mut self, cx: &mut Context<Self>) {
+        let mode = match self.view_mode {
+            ViewMode::List | ViewMode::Details => ViewMode::Grid,
+            ViewMode::Grid => ViewMode::List,
+        };
+        self.set_view_mode(mode, cx);
+    }
+
+    pub fn view_mode(&self) -> ViewMode {
+        self.view_mode
+    }
+
+    pub fn set_view_mode(&mut self, mode: ViewMode, cx: &mut Context<Self>) {
+        if self.view_mode != mode {
+            self.default_view_mode = mode;
+            self.save_settings();
+            self.switch_view_mode_in_place(mode, cx);
+        }
+    }
+
+    fn switch_view_mode_in_place(&mut self, mode: ViewMode, cx: &mut Context<Self>) {
+        if self.view_mode == mode {
+            return;
+        }
+
+        let (offset, geometry) = self.active_scroll_state(cx);
         let selected_index = match self.view_mode {
             ViewMode::List | ViewMode::Details => self.file_list.read(cx).inner().selected_index(),
             ViewMode::Grid => self.grid_view.read(cx).inner().selected_index(),
         };
+        self.scroll_memory
+            .remember(&self.current_path, self.view_mode, offset);
 
-        self.view_mode = match self.view_mode {
-            ViewMode::List | ViewMode::Details => ViewMode::Grid,
-            ViewMode::Grid => ViewMode::List,
-        };
-
-        match self.view_mode {
+        self.switch_view_mode(mode, cx);
+        match mode {
             ViewMode::List | ViewMode::Details => {
                 self.file_list.update(cx, |view, _| {
                     view.inner_mut().set_selected_index(selected_index);
@@ -2770,23 +4832,80 @@ impl Workspace {
             }
         }
 
-        self.save_settings();
+        let anchor = selected_index.unwrap_or_else(|| geometry.first_visible_index(offset));
+        self.restore_scroll(Some(anchor), cx);
+    }
 
-        self.status_bar.update(cx, |view, cx| {
-            view.set_view_mode(self.view_mode, cx);
-        });
+    fn active_scroll_state(&self, cx: &App) -> (f32, ScrollGeometry) {
+        match self.view_mode {
+            ViewMode::List | ViewMode::Details => {
+                let view = self.file_list.read(cx);
+                (view.scroll_offset(), view.scroll_geometry())
+            }
+            ViewMode::Grid => {
+                let view = self.grid_view.read(cx);
+                (view.scroll_offset(), view.scroll_geometry())
+            }
+        }
+    }
 
-        cx.notify();
+    fn remember_scroll(&mut self, cx: &mut Context<Self>) {
+        let (offset, _) = self.active_scroll_state(cx);
+        let selected = self.get_selected_entry(cx).map(|entry| entry.path);
+        self.scroll_memory
+            .remember(&self.current_path, self.view_mode, offset);
+        self.scroll_memory
+            .remember_selection(&self.current_path, selected);
     }
 
-    pub fn view_mode(&self) -> ViewMode {
-        self.view_mode
+    fn restore_selection(&mut self, cx: &mut Context<Self>) -> Option<usize> {
+        let index = match self.view_mode {
+            ViewMode::List | ViewMode::Details => {
+                let entries = self.file_list.read(cx).inner().entries().to_vec();
+                self.scroll_memory.recall_selection(&self.current_path, &entries)
+            }
+            ViewMode::Grid => {
+                let entries = self.grid_view.read(cx).inner().entries().to_vec();
+                self.scroll_memory.recall_selection(&self.current_path, &entries)
+            }
+        }?;
+
+        match self.view_mode {
+            ViewMode::List | ViewMode::Details => {
+                self.file_list.update(cx, |view, _| {
+                    view.inner_mut().set_selected_index(Some(index));
+                });
+            }
+            ViewMode::Grid => {
+                self.grid_view.update(cx, |view, _| {
+                    view.inner_mut().set_selected_index(Some(index));
+                });
+            }
+        }
+        Some(index)
     }
 
-    pub fn set_view_mode(&mut self, mode: ViewMode, cx: &mut Context<Self>) {
+    fn restore_scroll(&mut self, anchor: Option<usize>, cx: &mut Context<Self>) {
+        let saved = self.scroll_memory.offset(&self.current_path, self.view_mode);
+        match self.view_mode {
+            ViewMode::List | ViewMode::Details => {
+                self.file_list.update(cx, |view, _| {
+                    let offset = restore_scroll_offset(saved, anchor, &view.scroll_geometry());
+                    view.set_scroll_offset(offset);
+                });
+            }
+            ViewMode::Grid => {
+                self.grid_view.update(cx, |view, _| {
+                    let offset = restore_scroll_offset(saved, anchor, &view.scroll_geometry());
+                    view.set_scroll_offset(offset);
+                });
+            }
+        }
+    }
+
+    fn switch_view_mode(&mut self, mode: ViewMode, cx: &mut Context<Self>) {
         if self.view_mode != mode {
             self.view_mode = mode;
-            self.save_settings();
             self.status_bar.update(cx, |view, cx| {
                 view.set_view_mode(mode, cx);
             });
@@ -2794,1050 +4913,3934 @@ impl Workspace {
         }
     }
 
+    fn apply_folder_view_mode(&mut self, path: &Path, cx: &mut Context<Self>) {
+        let (mode, _) = resolve_view_mode(
+            &self.folder_view_rules,
+            path,
+            &self.cached_entries,
+            self.default_view_mode,
+        );
+        self.switch_view_mode(mode, cx);
 
-    fn get_selected_entry(&self, cx: &mut Context<Self>) -> Option<crate::models::FileEntry> {
-        match self.view_mode {
-            ViewMode::List | ViewMode::Details => {
-                let file_list = self.file_list.read(cx);
-                let idx = file_list.inner().selected_index();
-                idx.and_then(|i| file_list.inner().get_display_entry(i).cloned())
-            }
-            ViewMode::Grid => {
-                let grid_view = self.grid_view.read(cx);
-                let idx = grid_view.inner().selected_index();
-                idx.and_then(|i| self.cached_entries.get(i).cloned())
-            }
-        }
-    }
+        let pinned = self.folder_view_rules.pinned(path).to_vec();
+        self.file_list.update(cx, |view, _| {
+            view.inner_mut().set_pinned(pinned.clone());
+        });
+        self.grid_view.update(cx, |view, _| {
+            view.inner_mut().set_pinned(pinned);
+        });
 
+        let sort = self
+            .visible_columns
+            .restrict_sort(self.folder_view_rules.sort_for(path).unwrap_or_default());
+        self.apply_sort_state(sort, cx);
 
-    fn update_preview_for_selection(&mut self, _cx: &mut Context<Self>) {
-        // Preview panel disabled for now
-    }
+        let widths = self.column_width_rules.for_path(path);
+        self.file_list.update(cx, |view, cx| {
+            view.inner_mut().set_column_widths(widths);
+            cx.notify();
+        });
 
-    fn save_settings(&self) {
-        let mut settings = GlobalSettings::load();
-        settings.view_mode = self.view_mode;
-        settings.show_hidden_files = self.show_hidden_files;
-        let _ = settings.save();
+        let placeholders = self.archive_jobs.placeholders_in(path);
+        self.file_list.update(cx, |view, _| {
+            view.inner_mut().set_placeholders(placeholders);
+        });
     }
 
-    fn handle_new_tab(&mut self, _: &NewTab, _window: &mut Window, cx: &mut Context<Self>) {
-        if self.tabs_enabled {
-            let home = dirs::home_dir().unwrap_or_else(|| PathBuf::from("/"));
-            self.tab_bar.update(cx, |view, cx| {
-                view.open_tab(home, cx);
+    fn apply_sort_state(&mut self, sort: SortState, cx: &mut Context<Self>) {
+        let list_changed = *self.file_list.read(cx).inner().sort_state() != sort;
+        if list_changed {
+            self.file_list.update(cx, |view, cx| {
+                *view.inner_mut().sort_state_mut() = sort;
+                view.inner_mut().apply_sort();
+                cx.notify();
             });
-        } else {
-            let path = self.current_path.clone();
-            cx.defer(move |cx| {
-                if cx.has_global::<WindowManager>() {
-                    cx.update_global::<WindowManager, _>(|manager, cx| {
-                        manager.open_window(path, cx);
-                    });
-                }
+        }
+
+        let mut grid_entries = self.grid_view.read(cx).inner().entries().to_vec();
+        sort.sort_entries(&mut grid_entries);
+        apply_pins(&mut grid_entries, self.folder_view_rules.pinned(&self.current_path));
+        let grid_changed = grid_entries
+            .iter()
+            .map(|e| &e.path)
+            .ne(self.grid_view.read(cx).inner().entries().iter().map(|e| &e.path));
+        if grid_changed {
+            self.grid_view.update(cx, |view, cx| {
+                view.inner_mut().set_entries(grid_entries);
+                cx.notify();
             });
         }
+
+        self.status_bar.update(cx, |view, cx| {
+            view.set_sort_state(sort, cx);
+        });
     }
 
-    fn handle_close_tab(&mut self, _: &CloseTab, window: &mut Window, cx: &mut Context<Self>) {
-        if self.tabs_enabled {
-            let tab_count = self.tab_bar.read(cx).tab_count();
-            if tab_count > 1 {
-                self.tab_bar.update(cx, |view, cx| {
-                    view.tab_state_mut().close_active_tab();
-                    cx.notify();
-                });
-                let path = self.tab_bar.read(cx).active_path().to_path_buf();
-                self.load_directory(path, cx);
-            } else {
-                window.remove_window();
-            }
-        } else {
-            window.remove_window();
-        }
+    fn set_folder_sort(&mut self, sort: SortState, cx: &mut Context<Self>) {
+        self.apply_sort_state(sort, cx);
+        self.regroup_results(cx);
+        self.folder_view_rules.set_sort(self.current_path.clone(), sort);
+
+        let mut settings = GlobalSettings::load();
+        settings.folder_view_rules = self.folder_view_rules.clone();
+        let _ = settings.save();
     }
 
+    fn set_folder_column_widths(&mut self, widths: ColumnWidths) {
+        self.column_width_rules.set(self.current_path.clone(), widths);
 
-    fn handle_toggle_terminal(
-        &mut self,
-        _: &ToggleTerminal,
-        _window: &mut Window,
-        cx: &mut Context<Self>,
-    ) {
-        self.toggle_terminal(cx);
+        let mut settings = GlobalSettings::load();
+        settings.column_widths = self.column_width_rules.clone();
+        let _ = settings.save();
     }
 
-
-    fn handle_focus_search(
-        &mut self,
-        _: &FocusSearch,
-        window: &mut Window,
-        cx: &mut Context<Self>,
-    ) {
-        self.search_input.update(cx, |view, _cx| {
-            view.focus(window);
+    fn set_visible_columns(&mut self, columns: VisibleColumns, cx: &mut Context<Self>) {
+        self.visible_columns = columns.clone();
+        self.status_bar.update(cx, |view, cx| {
+            view.set_visible_columns(columns.clone(), cx);
         });
+
+        let mut settings = GlobalSettings::load();
+        settings.visible_columns = columns;
+        let _ = settings.save();
     }
 
-    fn handle_new_window(&mut self, _: &NewWindow, _window: &mut Window, cx: &mut Context<Self>) {
-        let path = self.current_path.clone();
-        cx.defer(move |cx| {
-            if cx.has_global::<WindowManager>() {
-                cx.update_global::<WindowManager, _>(|manager, cx| {
-                    manager.open_window(path, cx);
-                });
-            }
+    fn apply_column_widths_to_all(&mut self, cx: &mut Context<Self>) {
+        let widths = *self.file_list.read(cx).inner().column_widths();
+        self.column_width_rules.apply_to_all(widths);
+
+        let mut settings = GlobalSettings::load();
+        settings.column_widths = self.column_width_rules.clone();
+        let _ = settings.save();
+
+        self.toast_manager.update(cx, |toast, cx| {
+            toast.show_success("Column widths applied to all folders".to_string(), cx);
         });
     }
 
-    fn handle_next_tab(&mut self, _: &NextTab, _window: &mut Window, cx: &mut Context<Self>) {
-        if self.tabs_enabled {
-            self.tab_bar.update(cx, |view, cx| {
-                view.tab_state_mut().next_tab();
-                cx.notify();
-            });
-            let path = self.tab_bar.read(cx).active_path().to_path_buf();
-            self.load_directory(path, cx);
+    fn toggle_pin(&mut self, path: PathBuf, cx: &mut Context<Self>) {
+        let name = path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_else(|| path.to_string_lossy().to_string());
+        let message = if self.folder_view_rules.unpin(&path) {
+            format!("Unpinned \"{}\"", name)
+        } else if self.folder_view_rules.pin(path.clone()) {
+            format!("Pinned \"{}\" to the top", name)
+        } else {
+            return;
+        };
+
+        let mut settings = GlobalSettings::load();
+        settings.folder_view_rules = self.folder_view_rules.clone();
+        let _ = settings.save();
+
+        if path.parent() == Some(self.current_path.as_path()) {
+            let current_path = self.current_path.clone();
+            self.apply_folder_view_mode(&current_path, cx);
+            cx.notify();
         }
+
+        self.toast_manager.update(cx, |toast, cx| {
+            toast.show_success(message, cx);
+        });
     }
 
-    fn handle_prev_tab(&mut self, _: &PrevTab, _window: &mut Window, cx: &mut Context<Self>) {
-        if self.tabs_enabled {
-            self.tab_bar.update(cx, |view, cx| {
-                view.tab_state_mut().prev_tab();
-                cx.notify();
-            });
-            let path = self.tab_bar.read(cx).active_path().to_path_buf();
-            self.load_directory(path, cx);
+    fn set_folder_view_rule(
+        &mut self,
+        path: PathBuf,
+        mode: Option<ViewMode>,
+        cx: &mut Context<Self>,
+    ) {
+        let name = path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_else(|| path.to_string_lossy().to_string());
+        let message = match mode {
+            Some(ViewMode::Grid) => format!("\"{}\" will always open in grid view", name),
+            Some(_) => format!("\"{}\" will always open in list view", name),
+            None => format!("\"{}\" will use the default view", name),
+        };
+
+        match mode {
+            Some(mode) => self.folder_view_rules.set(path.clone(), mode),
+            None => {
+                self.folder_view_rules.clear(&path);
+            }
+        }
+
+        let mut settings = GlobalSettings::load();
+        settings.folder_view_rules = self.folder_view_rules.clone();
+        let _ = settings.save();
+
+        if path == self.current_path {
+            self.apply_folder_view_mode(&path, cx);
         }
+
+        self.toast_manager.update(cx, |toast, cx| {
+            toast.show_success(message, cx);
+        });
     }
 
-    fn handle_quick_look_toggle(
+
+    fn set_folder_hidden_rule(
         &mut self,
-        _: &QuickLookToggle,
-        _window: &mut Window,
+        path: PathBuf,
+        show: Option<bool>,
         cx: &mut Context<Self>,
     ) {
-        let selected_entry = match self.view_mode {
+        let name = path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_else(|| path.to_string_lossy().to_string());
+        let message = match show {
+            Some(true) => format!("\"{}\" will always show hidden files", name),
+            Some(false) => format!("\"{}\" will always hide hidden files", name),
+            None => format!("\"{}\" will follow the hidden files setting", name),
+        };
+
+        self.folder_view_rules.set_hidden(path.clone(), show);
+
+        let mut settings = GlobalSettings::load();
+        settings.folder_view_rules = self.folder_view_rules.clone();
+        let _ = settings.save();
+
+        if self.current_path.starts_with(&path) {
+            self.refresh_current_directory(cx);
+        }
+
+        self.toast_manager.update(cx, |toast, cx| {
+            toast.show_success(message, cx);
+        });
+    }
+
+
+    fn show_hidden_for(&self, path: &Path) -> bool {
+        effective_show_hidden(&self.folder_view_rules, path, self.show_hidden_files)
+    }
+
+
+    fn sync_hidden_files_toggle(&mut self, show_hidden: bool, cx: &mut Context<Self>) {
+        self.sidebar.update(cx, |view, cx| {
+            view.set_show_hidden_files(show_hidden, cx);
+        });
+    }
+
+
+    fn get_selected_entry(&self, cx: &mut Context<Self>) -> Option<crate::models::FileEntry> {
+        match self.view_mode {
             ViewMode::List | ViewMode::Details => {
-                let idx = self.file_list.read(cx).inner().selected_index();
-                idx.and_then(|i| self.cached_entries.get(i).cloned())
+                let file_list = self.file_list.read(cx);
+                let idx = file_list.inner().selected_index();
+                idx.and_then(|i| file_list.inner().get_display_entry(i).cloned())
             }
             ViewMode::Grid => {
-                let idx = self.grid_view.read(cx).inner().selected_index();
+                let grid_view = self.grid_view.read(cx);
+                let idx = grid_view.inner().selected_index();
                 idx.and_then(|i| self.cached_entries.get(i).cloned())
             }
-        };
+        }
+    }
 
-        if let Some(entry) = selected_entry {
-            if !entry.is_dir {
-                let entries = self.cached_entries.clone();
-                let index = self
-                    .cached_entries
-                    .iter()
-                    .position(|e| e.path == entry.path)
-                    .unwrap_or(0);
-                self.quick_look.update(cx, |view, _| {
-                    view.toggle(entry.path, entries, index);
-                });
+
+    fn sync_preview_with_selection(&mut self, cx: &mut Context<Self>) {
+        let file_selected = self.get_selected_entry(cx).is_some_and(|entry| !entry.is_dir);
+        match self.preview_pane.update_selection(file_selected) {
+            Some(true) => self.show_preview_pane(None, None, cx),
+            Some(false) => {
+                self.preview = None;
                 cx.notify();
             }
+            None => {}
         }
     }
 
-    fn render_breadcrumbs(&self, cx: &mut Context<Self>) -> impl IntoElement {
-        let theme = theme_colors();
-        let text_light = theme.text_primary;
-        let accent_primary = theme.accent_primary;
-        let accent_secondary = theme.accent_secondary;
-        let hover_bg = theme.bg_hover;
 
-        let mut parts: Vec<(String, PathBuf)> = Vec::new();
-        let mut current = Some(self.current_path.as_path());
+    pub fn set_preview_visibility(&mut self, visibility: PreviewVisibility, cx: &mut Context<Self>) {
+        self.preview_pane = PreviewPaneState::new(visibility);
+        match visibility {
+            PreviewVisibility::Shown => self.show_preview_pane(None, None, cx),
+            PreviewVisibility::Hidden => self.preview = None,
+            PreviewVisibility::Auto => {}
+        }
+        cx.notify();
+    }
 
-        while let Some(path) = current {
-            let name = if path.parent().is_none() {
-                "/".to_string()
-            } else {
-                path.file_name()
-                    .and_then(|n| n.to_str())
-                    .unwrap_or("")
-                    .to_string()
-            };
-            if !name.is_empty() {
-                parts.push((name, path.to_path_buf()));
-            }
-            current = path.parent();
-            if parts.len() >= 5 {
-                break;
-            }
+    fn update_preview_for_selection(&mut self, cx: &mut Context<Self>) {
+        let Some(preview) = self.preview.clone() else {
+            return;
+        };
+        let Some(entry) = self.get_selected_entry(cx) else {
+            return;
+        };
+        if preview.read(cx).preview().current_path() == Some(&entry.path) {
+            return;
         }
+        preview.update(cx, |view, cx| {
+            view.load_file(&entry.path, cx);
+            cx.notify();
+        });
+    }
 
-        parts.reverse();
 
-        div()
-            .flex()
-            .items_center()
-            .text_sm()
-            .font_weight(gpui::FontWeight::MEDIUM)
-            .children(parts.into_iter().enumerate().map(|(i, (name, path))| {
-                div()
-                    .flex()
-                    .items_center()
-                    .when(i > 0, |s| {
-                        s.child(
-                            svg()
-                                .path("assets/icons/chevron-right.svg")
-                                .size(px(14.0))
-                                .text_color(accent_secondary)
-                                .mx_1(),
-                        )
-                    })
-                    .child(
-                        div()
-                            .id(SharedString::from(format!("breadcrumb-{}", i)))
-                            .px(px(crate::models::toolbar::BREADCRUMB_PADDING))
-                            .py_0p5()
-                            .rounded_sm()
-                            .text_color(text_light)
-                            .cursor_pointer()
-                            .hover(|s| s.text_color(accent_primary).bg(hover_bg))
-                            .on_mouse_down(MouseButton::Left, {
-                                let nav_path = path.clone();
-                                cx.listener(move |view, _, _, cx| {
-                                    view.navigate_to(nav_path.clone(), cx);
-                                })
-                            })
-                            .child(name),
-                    )
-            }))
+    fn show_preview_pane(
+        &mut self,
+        path: Option<PathBuf>,
+        tab: Option<PreviewTab>,
+        cx: &mut Context<Self>,
+    ) {
+        let preview = match self.preview.clone() {
+            Some(preview) => preview,
+            None => {
+                let preview = cx.new(|cx| PreviewView::new(cx));
+                cx.observe(&preview, |workspace: &mut Workspace, preview, cx| {
+                    if let Some(path) = preview.update(cx, |view, _| view.take_pending_open()) {
+                        workspace.open_file(path, cx);
+                    }
+                })
+                .detach();
+                self.preview = Some(preview.clone());
+                preview
+            }
+        };
+        let path = path.or_else(|| self.get_selected_entry(cx).map(|e| e.path));
+
+        preview.update(cx, |view, cx| {
+            if let Some(path) = &path {
+                view.load_file(path, cx);
+            }
+            if let Some(tab) = tab {
+                view.select_tab(tab, cx);
+            }
+            cx.notify();
+        });
+        cx.notify();
     }
-}
 
-impl Focusable for Workspace {
-    fn focus_handle(&self, _cx: &App) -> FocusHandle {
-        self.focus_handle.clone()
+    fn handle_toggle_command_palette(
+        &mut self,
+        _: &ToggleCommandPalette,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if self.command_palette.read(cx).is_visible() {
+            self.command_palette.update(cx, |palette, cx| palette.hide(cx));
+        } else {
+            let has_selection = self.get_selected_entry(cx).is_some();
+            self.command_palette
+                .update(cx, |palette, cx| palette.show(has_selection, cx));
+        }
     }
-}
 
-impl Render for Workspace {
-    fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
-        if self.should_focus_dialog_input {
-            if let Some(input) = &self.dialog_input {
-                window.focus(&input.read(cx).focus_handle(cx));
+    fn run_palette_command(&mut self, command: PaletteCommand, cx: &mut Context<Self>) {
+        match command {
+            PaletteCommand::NewFolder => self.open_dialog(false, cx),
+            PaletteCommand::NewFolderWithSelection => self.start_new_folder_with_selection(cx),
+            PaletteCommand::NewFile => self.open_dialog(true, cx),
+            PaletteCommand::ToggleTerminal => self.toggle_terminal(cx),
+            PaletteCommand::ToggleHiddenFiles => {
+                self.sidebar.update(cx, |view, cx| {
+                    view.toggle_hidden_files();
+                    cx.notify();
+                });
+                self.handle_tool_action(ToolAction::ToggleHiddenFiles, cx);
+            }
+            PaletteCommand::SwitchView(mode) => self.switch_view_mode_in_place(mode, cx),
+            PaletteCommand::GoToHome => {
+                if let Some(path) = dirs::home_dir() {
+                    self.navigate_to(path, cx);
+                }
+            }
+            PaletteCommand::GoToDocuments => {
+                if let Some(path) = dirs::document_dir() {
+                    self.navigate_to(path, cx);
+                }
+            }
+            PaletteCommand::GoToDownloads => {
+                if let Some(path) = dirs::download_dir() {
+                    self.navigate_to(path, cx);
+                }
+            }
+            PaletteCommand::OpenSettings => {
+                let path = GlobalSettings::path();
+                if !path.exists() {
+                    let _ = GlobalSettings::load().save();
+                }
+                self.handle_context_menu_action(ContextMenuAction::Open(path), cx);
+            }
+            PaletteCommand::EmptyTrash => self.empty_trash(cx),
+            PaletteCommand::Refresh => self.refresh_current_directory(cx),
+            PaletteCommand::FindDuplicates => self.find_duplicates(false, cx),
+            PaletteCommand::ApplyColumnWidthsToAll => self.apply_column_widths_to_all(cx),
+            PaletteCommand::CustomizeToolbar => self.toggle_toolbar_customizer(cx),
+            PaletteCommand::CycleAnimationSpeed => self.cycle_animation_speed(cx),
+            PaletteCommand::CycleAnimationEasing => self.cycle_easing_preset(cx),
+            PaletteCommand::ToggleFolderSizes => self.toggle_folder_sizes(cx),
+            PaletteCommand::CycleScriptOpenAction => {
+                self.cycle_executable_action(ExecutableKind::Script, cx)
+            }
+            PaletteCommand::CycleProgramOpenAction => {
+                self.cycle_executable_action(ExecutableKind::Binary, cx)
+            }
+            PaletteCommand::CompressSelection
+            | PaletteCommand::RenameSelection
+            | PaletteCommand::CopySelectionPath
+            | PaletteCommand::MoveSelectionToTrash => {
+                let Some(entry) = self.get_selected_entry(cx) else {
+                    return;
+                };
+                let action = match command {
+                    PaletteCommand::CompressSelection => ContextMenuAction::Compress(entry.path),
+                    PaletteCommand::RenameSelection => ContextMenuAction::Rename(entry.path),
+                    PaletteCommand::CopySelectionPath => ContextMenuAction::CopyPath(entry.path),
+                    _ => ContextMenuAction::MoveToTrash(entry.path),
+                };
+                self.handle_context_menu_action(action, cx);
             }
-            self.should_focus_dialog_input = false;
         }
+    }
 
-        let theme = theme_colors();
-        let current = current_theme();
-        let content_bg = current.content_background();
-
-        div()
-            .id("workspace")
-            .key_context("Workspace")
-            .track_focus(&self.focus_handle)
-            .on_action(cx.listener(Self::handle_new_tab))
-            .on_action(cx.listener(Self::handle_close_tab))
-            .on_action(cx.listener(Self::handle_next_tab))
-            .on_action(cx.listener(Self::handle_prev_tab))
-            .on_action(cx.listener(Self::handle_toggle_terminal))
-            .on_action(cx.listener(Self::handle_focus_search))
-            .on_action(cx.listener(Self::handle_new_window))
-            .on_action(cx.listener(Self::handle_quick_look_toggle))
-            .on_mouse_up(
-                MouseButton::Left,
-                cx.listener(|view, _, _, cx| {
-                    if view.is_resizing_terminal {
-                        view.is_resizing_terminal = false;
-                        cx.notify();
-                    }
-                    if view.is_resizing_preview {
-                        view.is_resizing_preview = false;
-                        cx.notify();
-                    }
-                }),
-            )
-            .on_mouse_move(cx.listener(Self::handle_resize_mouse_move))
-            .size_full()
-            .flex()
-            .flex_col()
-            .bg(content_bg.base_color)
-            .text_color(theme.text_primary)
-            .font_family(".SystemUIFont")
-            .child(self.render_titlebar(cx))
-            .when(self.tabs_enabled, |this| this.child(self.tab_bar.clone()))
-            .child(self.render_main_content(cx))
-            .child(self.status_bar.clone())
-            .when(!matches!(self.dialog_state, DialogState::None), |this| {
-                this.child(self.render_dialog_overlay(cx))
-            })
-            .when(self.show_smart_folder_dialog, |this| {
-                this.child(self.smart_folder_dialog.clone())
-            })
-            .when(self.conflict_dialog.is_some(), |this| {
-                this.child(self.render_conflict_dialog_overlay(cx))
-            })
-            .when(self.symlink_dialog.is_some(), |this| {
-                this.child(self.render_symlink_dialog_overlay(cx))
-            })
-            .when(self.format_dialog.is_some(), |this| {
-                this.child(self.render_format_dialog_overlay(cx))
-            })
-            .when(self.bootable_usb_dialog.is_some(), |this| {
-                this.child(self.render_bootable_usb_dialog_overlay(cx))
-            })
-            .child(self.theme_picker.clone())
-            .child(self.quick_look.clone())
-            .child(self.toast_manager.clone())
-    }
-}
-
-impl Workspace {
-    fn handle_resize_mouse_move(
+    fn handle_toggle_preview_pane(
         &mut self,
-        event: &gpui::MouseMoveEvent,
+        _: &TogglePreviewPane,
         window: &mut Window,
         cx: &mut Context<Self>,
     ) {
-        if self.is_resizing_terminal {
-            let bounds = window.bounds();
-            let mouse_y = event.position.y;
-            let window_height = bounds.size.height;
-            let new_height = f32::from(window_height) - f32::from(mouse_y) - 30.0;
-            self.terminal_height = new_height.clamp(150.0, 600.0);
-            cx.notify();
+        if self.preview_pane.toggle(self.preview.is_some()) {
+            self.show_preview_pane(None, None, cx);
+        } else {
+            self.preview = None;
         }
-        if self.is_resizing_preview {
-            let bounds = window.bounds();
-            let mouse_x = event.position.x;
-            let window_width = bounds.size.width;
-            let new_width = f32::from(window_width) - f32::from(mouse_x);
-            self.preview_width = new_width.clamp(200.0, 600.0);
-            cx.notify();
+
+        let window_id = window.window_handle().window_id();
+        let visibility = self.preview_pane.visibility();
+        if cx.has_global::<WindowManager>() {
+            cx.update_global::<WindowManager, _>(|manager, _| {
+                if let Some(id) = manager.find_window(window_id) {
+                    manager.update_preview_visibility(id, visibility);
+                }
+            });
         }
+        cx.notify();
     }
 
-    fn render_titlebar(&self, cx: &mut Context<Self>) -> impl IntoElement {
-        let theme = theme_colors();
+    fn save_settings(&self) {
+        let mut settings = GlobalSettings::load();
+        settings.view_mode = self.default_view_mode;
+        settings.show_hidden_files = self.show_hidden_files;
+        let _ = settings.save();
+    }
 
-        div()
-            .id("titlebar")
-            .h(px(52.0))
-            .bg(theme.bg_void)
-            .flex()
-            .items_center()
-            .justify_between()
-            .px_5()
-            .py_2()
-            .border_b_1()
-            .border_color(theme.border_default)
-            .on_click(|event, window, _cx| {
-                if event.click_count() == 2 {
-                    window.titlebar_double_click();
+    fn handle_new_tab(&mut self, _: &NewTab, _window: &mut Window, cx: &mut Context<Self>) {
+        if self.tabs_enabled {
+            let home = dirs::home_dir().unwrap_or_else(|| PathBuf::from("/"));
+            self.tab_bar.update(cx, |view, cx| {
+                view.open_tab(home, cx);
+            });
+        } else {
+            let path = self.current_path.clone();
+            cx.defer(move |cx| {
+                if cx.has_global::<WindowManager>() {
+                    cx.update_global::<WindowManager, _>(|manager, cx| {
+                        manager.open_window(path, cx);
+                    });
                 }
-            })
-            .child(
-                div().flex().items_center().pl(px(70.0)).child(
-                    svg()
-                        .path("assets/icons/logo.svg")
-                        .size(px(20.0))
-                        .text_color(theme.accent_primary),
-                ),
-            )
-            .child(
-                div()
-                    .relative()
-                    .w_1_3()
-                    .max_w(px(500.0))
-                    .child(self.search_input.clone()),
-            )
-            .child(
-                div().flex().items_center().gap_3().child(
-                    div()
-                        .id("theme-picker-btn")
-                        .px_2()
-                        .py_1()
-                        .rounded_md()
-                        .cursor_pointer()
-                        .flex()
-                        .items_center()
-                        .gap_1p5()
-                        .hover(|h| h.bg(theme.bg_hover))
-                        .on_mouse_down(
-                            MouseButton::Left,
-                            cx.listener(|view, _event, _window, cx| {
-                                view.toggle_theme_picker(cx);
-                            }),
-                        )
-                        .child(
-                            svg()
-                                .path("assets/icons/sparkles.svg")
-                                .size(px(14.0))
-                                .text_color(theme.accent_primary),
-                        )
-                        .child(
-                            div()
-                                .text_xs()
-                                .text_color(theme.text_secondary)
-                                .child("Themes"),
-                        ),
-                ),
-            )
+            });
+        }
     }
 
-    fn render_main_content(&self, cx: &mut Context<Self>) -> impl IntoElement {
-        let theme = theme_colors();
+    fn apply_tab_drop(&mut self, action: TabDropAction, cx: &mut Context<Self>) {
+        let select = match action {
+            TabDropAction::Navigate { path, select, .. } => {
+                self.navigate_to(path, cx);
+                select
+            }
+            TabDropAction::OpenTab { path, select } => {
+                let tab_id = self.tab_bar.read(cx).tab_state().active_tab_id();
+                self.create_terminal_for_tab(tab_id, path.clone(), cx);
+                self.load_directory(path, cx);
+                self.restore_tab_search(cx);
+                select
+            }
+        };
+        if let Some(path) = select {
+            self.reveal_path(&path, cx);
+        }
+    }
 
-        div()
-            .flex()
-            .flex_1()
-            .overflow_hidden()
-            .child(self.render_sidebar())
-            .child(self.render_content_area(cx))
-            .when(self.copy_move_mode, |this| {
-                this.child(self.render_destination_pane(cx))
-            })
-            .when(!self.copy_move_mode, |this| {
-                this.children(self.preview.clone().map(|p| self.render_preview_pane(p, cx)))
-            })
+    fn handle_close_tab(&mut self, _: &CloseTab, window: &mut Window, cx: &mut Context<Self>) {
+        if self.tabs_enabled {
+            if self.tab_bar.read(cx).tab_state().active_tab().pinned {
+                self.toast_manager.update(cx, |toast, cx| {
+                    toast.show_info("Unpin this tab before closing it".to_string(), cx);
+                });
+                return;
+            }
+
+            let tab_count = self.tab_bar.read(cx).tab_count();
+            if tab_count > 1 {
+                self.tab_bar.update(cx, |view, cx| {
+                    view.tab_state_mut().close_active_tab();
+                    cx.notify();
+                });
+                let path = self.tab_bar.read(cx).active_path().to_path_buf();
+                self.load_directory(path, cx);
+            } else {
+                window.remove_window();
+            }
+        } else {
+            window.remove_window();
+        }
     }
 
-    fn render_sidebar(&self) -> impl IntoElement {
-        let theme = theme_colors();
 
-        div()
-            .w(px(crate::models::sidebar::WIDTH))
-            .bg(theme.bg_secondary)
-            .border_r_1()
-            .border_color(theme.border_default)
-            .flex()
-            .flex_col()
-            .child(self.sidebar.clone())
+    fn handle_toggle_terminal(
+        &mut self,
+        _: &ToggleTerminal,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.toggle_terminal(cx);
     }
 
-    fn render_content_area(&self, cx: &mut Context<Self>) -> impl IntoElement {
-        let theme = theme_colors();
-        let is_terminal_open = self.is_terminal_open;
 
-        div()
-            .flex_1()
-            .flex()
-            .flex_col()
-            .bg(theme.bg_void)
-            .min_w_0()
-            .child(self.render_toolbar(cx))
-            .child(self.render_file_view())
-            .when(is_terminal_open, |this| {
-                this.child(self.render_terminal_section(cx))
-            })
+    fn region_visibility(&self, cx: &Context<Self>) -> RegionVisibility {
+        RegionVisibility {
+            preview: !self.copy_move_mode && self.preview.is_some(),
+            terminal: self.is_terminal_open && self.active_terminal(cx).is_some(),
+        }
     }
 
-    fn render_toolbar(&self, cx: &mut Context<Self>) -> impl IntoElement {
-        let theme = theme_colors();
-        let can_go_back = self.path_history.len() > 1;
-        let is_terminal_open = self.is_terminal_open;
+    fn region_focus_handle(&self, region: FocusRegion, cx: &Context<Self>) -> Option<FocusHandle> {
+        match region {
+            FocusRegion::Sidebar => Some(self.sidebar.focus_handle(cx)),
+            FocusRegion::FileView if matches!(self.view_mode, ViewMode::Grid) => {
+                Some(self.grid_view.focus_handle(cx))
+            }
+            FocusRegion::FileView => Some(self.file_list.focus_handle(cx)),
+            FocusRegion::Preview => self.preview.as_ref().map(|preview| preview.focus_handle(cx)),
+            FocusRegion::Terminal => self.active_terminal(cx).map(|terminal| terminal.focus_handle(cx)),
+        }
+    }
 
-        div()
-            .h(px(crate::models::toolbar::HEIGHT))
-            .bg(theme.bg_secondary)
-            .border_b_1()
-            .border_color(theme.border_default)
-            .flex()
-            .items_center()
-            .justify_between()
-            .px(px(crate::models::toolbar::PADDING_X))
-            .child(self.render_toolbar_left(can_go_back, cx))
-            .child(self.render_toolbar_right(is_terminal_open, cx))
+    fn region_containing_focus(&self, window: &Window, cx: &Context<Self>) -> Option<FocusRegion> {
+        FocusRegion::ORDER.into_iter().find(|region| {
+            self.region_focus_handle(*region, cx)
+                .is_some_and(|handle| handle.contains_focused(window, cx))
+        })
     }
 
-    fn render_toolbar_left(&self, can_go_back: bool, cx: &mut Context<Self>) -> impl IntoElement {
-        let theme = theme_colors();
+    fn sync_focused_region(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let focused = self.region_containing_focus(window, cx);
+        let resolved = resolve_focus_region(focused.or(self.focused_region), &self.region_visibility(cx));
+        if focused.is_none() && resolved != self.focused_region {
+            if let Some(handle) = resolved.and_then(|region| self.region_focus_handle(region, cx)) {
+                window.focus(&handle);
+            }
+        }
+        self.focused_region = resolved;
+    }
 
-        div()
-            .flex()
-            .items_center()
-            .gap(px(crate::models::toolbar::BUTTON_GAP))
-            .child(
-                div()
-                    .id("back-btn")
-                    .size(px(crate::models::toolbar::BUTTON_SIZE))
-                    .flex()
-                    .items_center()
-                    .justify_center()
-                    .rounded_md()
-                    .cursor_pointer()
-                    .when(can_go_back, |s| s.hover(|h| h.bg(theme.bg_hover)))
-                    .when(!can_go_back, |s| s.opacity(0.3))
-                    .on_mouse_down(
-                        MouseButton::Left,
-                        cx.listener(|view, _event, _window, cx| {
-                            view.navigate_back(cx);
-                        }),
-                    )
-                    .child(
-                        svg()
-                            .path("assets/icons/arrow-left.svg")
-                            .size(px(18.0))
-                            .text_color(theme.text_muted),
-                    ),
-            )
-            .child(
-                div()
-                    .h(px(20.0))
-                    .w(px(1.0))
-                    .bg(theme.border_subtle)
-                    .mx(px(crate::models::toolbar::BUTTON_GAP)),
-            )
-            .child(self.render_breadcrumbs(cx))
+    fn cycle_region_focus(&mut self, backwards: bool, window: &mut Window, cx: &mut Context<Self>) {
+        let current = self.region_containing_focus(window, cx).or(self.focused_region);
+        let region = cycle_focus_region(current, &self.region_visibility(cx), backwards);
+        if let Some(handle) = self.region_focus_handle(region, cx) {
+            window.focus(&handle);
+        }
+        self.focused_region = Some(region);
+        cx.notify();
     }
 
-    fn render_toolbar_right(&self, is_terminal_open: bool, cx: &mut Context<Self>) -> impl IntoElement {
-        let theme = theme_colors();
-        let is_grid = matches!(self.view_mode, ViewMode::Grid);
-        let is_trash = crate::models::is_trash_path(&self.current_path);
+    fn handle_go_to_parent(&mut self, _: &GoToParent, window: &mut Window, cx: &mut Context<Self>) {
+        self.go_to_destination(QuickDestination::Parent, window, cx);
+    }
 
-        div()
-            .flex()
-            .items_center()
-            .gap(px(crate::models::toolbar::BUTTON_GAP))
-            .child(self.render_terminal_button(is_terminal_open, cx))
-            .child(div().h(px(20.0)).w(px(1.0)).bg(theme.border_subtle).mx(px(crate::models::toolbar::BUTTON_GAP)))
-            .child(self.render_copy_button())
-            .child(self.render_trash_button())
-            .when(is_trash, |this| this.child(self.render_empty_trash_button(cx)))
-            .child(div().h(px(16.0)).w(px(1.0)).bg(theme.border_subtle).mx_2())
-            .child(self.render_view_mode_toggle(is_grid, cx))
+    fn handle_go_to_home(&mut self, _: &GoToHome, window: &mut Window, cx: &mut Context<Self>) {
+        self.go_to_destination(QuickDestination::Home, window, cx);
     }
 
-    fn render_terminal_button(&self, is_terminal_open: bool, cx: &mut Context<Self>) -> impl IntoElement {
-        let theme = theme_colors();
+    fn handle_go_to_root(&mut self, _: &GoToRoot, window: &mut Window, cx: &mut Context<Self>) {
+        self.go_to_destination(QuickDestination::Root, window, cx);
+    }
 
-        div()
-            .id("terminal-btn")
-            .size(px(crate::models::toolbar::BUTTON_SIZE))
-            .flex()
-            .items_center()
-            .justify_center()
-            .rounded_md()
-            .cursor_pointer()
-            .when(is_terminal_open, |s| s.bg(theme.bg_selected))
-            .when(!is_terminal_open, |s| s.hover(|h| h.bg(theme.bg_hover)))
-            .on_mouse_down(
-                MouseButton::Left,
-                cx.listener(|view, _event, _window, cx| {
-                    view.toggle_terminal(cx);
-                }),
-            )
-            .child(
-                svg()
-                    .path("assets/icons/terminal.svg")
-                    .size(px(18.0))
-                    .text_color(if is_terminal_open { theme.accent_primary } else { theme.text_muted }),
-            )
+    fn go_to_destination(&mut self, destination: QuickDestination, window: &mut Window, cx: &mut Context<Self>) {
+        if self.text_input_focused(window, cx) {
+            cx.propagate();
+            return;
+        }
+        match destination {
+            QuickDestination::Parent => self.navigate_up(cx),
+            QuickDestination::Home | QuickDestination::Root => {
+                let home = dirs::home_dir();
+                if let Some(target) = destination.resolve(&self.current_path, home.as_deref()) {
+                    self.navigate_to(target, cx);
+                }
+            }
+        }
     }
 
-    fn render_copy_button(&self) -> impl IntoElement {
-        let theme = theme_colors();
+    fn handle_focus_next_region(
+        &mut self,
+        _: &FocusNextRegion,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.cycle_region_focus(false, window, cx);
+    }
 
-        div()
-            .id("copy-btn")
-            .size(px(crate::models::toolbar::BUTTON_SIZE))
-            .flex()
-            .items_center()
-            .justify_center()
-            .rounded_md()
-            .cursor_pointer()
-            .hover(|h| h.bg(theme.bg_hover))
-            .child(
-                svg()
-                    .path("assets/icons/copy.svg")
-                    .size(px(18.0))
-                    .text_color(theme.text_muted),
-            )
+    fn handle_focus_previous_region(
+        &mut self,
+        _: &FocusPreviousRegion,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.cycle_region_focus(true, window, cx);
     }
 
-    fn render_trash_button(&self) -> impl IntoElement {
-        let theme = theme_colors();
+    fn render_region_outline(&self, region: FocusRegion) -> Option<impl IntoElement> {
+        (self.focused_region == Some(region)).then(|| {
+            div()
+                .absolute()
+                .inset_0()
+                .border_1()
+                .border_color(theme_colors().border_emphasis)
+        })
+    }
 
-        div()
-            .id("trash-btn")
-            .size(px(crate::models::toolbar::BUTTON_SIZE))
-            .flex()
-            .items_center()
-            .justify_center()
-            .rounded_md()
-            .cursor_pointer()
-            .hover(|h| h.bg(theme.bg_hover))
-            .child(
-                svg()
-                    .path("assets/icons/trash-2.svg")
-                    .size(px(16.0))
-                    .text_color(theme.text_muted),
-            )
+    fn handle_focus_search(
+        &mut self,
+        _: &FocusSearch,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.search_input.update(cx, |view, _cx| {
+            view.focus(window);
+        });
     }
 
-    fn render_empty_trash_button(&self, cx: &mut Context<Self>) -> impl IntoElement {
-        div()
-            .id("empty-trash-btn")
-            .px_3()
-            .py(px(6.0))
-            .bg(gpui::rgb(0xda3633))
-            .text_color(gpui::rgb(0xffffff))
-            .rounded_md()
-            .text_xs()
-            .font_weight(gpui::FontWeight::SEMIBOLD)
-            .cursor_pointer()
-            .hover(|h| h.bg(gpui::rgb(0xb62324)))
-            .on_mouse_down(
-                MouseButton::Left,
-                cx.listener(|view, _, _, cx| {
-                    view.empty_trash(cx);
-                }),
-            )
-            .child("Empty Trash")
+    fn handle_new_window(&mut self, _: &NewWindow, _window: &mut Window, cx: &mut Context<Self>) {
+        let path = self.current_path.clone();
+        cx.defer(move |cx| {
+            if cx.has_global::<WindowManager>() {
+                cx.update_global::<WindowManager, _>(|manager, cx| {
+                    manager.open_window(path, cx);
+                });
+            }
+        });
     }
 
-    fn render_view_mode_toggle(&self, is_grid: bool, cx: &mut Context<Self>) -> impl IntoElement {
-        let theme = theme_colors();
+    fn handle_next_tab(&mut self, _: &NextTab, _window: &mut Window, cx: &mut Context<Self>) {
+        if self.tabs_enabled {
+            self.tab_bar.update(cx, |view, cx| {
+                view.tab_state_mut().next_tab();
+                cx.notify();
+            });
+            let path = self.tab_bar.read(cx).active_path().to_path_buf();
+            self.load_directory(path, cx);
+        }
+    }
 
-        div()
-            .flex()
-            .bg(theme.bg_tertiary)
-            .rounded_lg()
-            .p_0p5()
-            .child(
-                div()
-                    .id("grid-view-btn")
-                    .p_1()
-                    .rounded_md()
-                    .cursor_pointer()
-                    .when(is_grid, |s| s.bg(theme.bg_hover))
-                    .on_mouse_down(
-                        MouseButton::Left,
-                        cx.listener(|view, _event, _window, cx| {
-                            view.set_view_mode(ViewMode::Grid, cx);
-                        }),
-                    )
-                    .child(
-                        svg()
-                            .path("assets/icons/grid-2x2.svg")
-                            .size(px(14.0))
-                            .text_color(if is_grid { theme.text_primary } else { theme.text_muted }),
-                    ),
-            )
-            .child(
-                div()
-                    .id("list-view-btn")
-                    .p_1()
-                    .rounded_md()
-                    .cursor_pointer()
-                    .when(!is_grid, |s| s.bg(theme.bg_hover))
-                    .on_mouse_down(
-                        MouseButton::Left,
-                        cx.listener(|view, _event, _window, cx| {
-                            view.set_view_mode(ViewMode::List, cx);
-                        }),
-                    )
-                    .child(
-                        svg()
-                            .path("assets/icons/list.svg")
-                            .size(px(14.0))
-                            .text_color(if !is_grid { theme.text_primary } else { theme.text_muted }),
-                    ),
-            )
+    fn handle_prev_tab(&mut self, _: &PrevTab, _window: &mut Window, cx: &mut Context<Self>) {
+        if self.tabs_enabled {
+            self.tab_bar.update(cx, |view, cx| {
+                view.tab_state_mut().prev_tab();
+                cx.notify();
+            });
+            let path = self.tab_bar.read(cx).active_path().to_path_buf();
+            self.load_directory(path, cx);
+        }
     }
 
-    fn render_file_view(&self) -> impl IntoElement {
-        let theme = theme_colors();
-        let is_grid = matches!(self.view_mode, ViewMode::Grid);
+    fn handle_reveal_in_sidebar(
+        &mut self,
+        _: &RevealInSidebar,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.sidebar.update(cx, |view, cx| {
+            view.reveal_current_directory(cx);
+        });
+    }
 
-        div()
-            .flex_1()
-            .bg(theme.bg_void)
-            .overflow_hidden()
-            .min_h(px(100.0))
-            .when(self.copy_move_mode, |d| d.opacity(0.5))
-            .when(is_grid, |this| this.child(self.grid_view.clone()))
-            .when(!is_grid, |this| this.child(self.file_list.clone()))
+    fn handle_toggle_auto_reveal(
+        &mut self,
+        _: &ToggleAutoReveal,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let enabled = self.sidebar.update(cx, |view, cx| {
+            view.toggle_auto_reveal(cx);
+            view.is_auto_reveal()
+        });
+        let message = if enabled {
+            "Auto-reveal in sidebar enabled"
+        } else {
+            "Auto-reveal in sidebar disabled"
+        };
+        self.toast_manager.update(cx, |toast, cx| {
+            toast.show_info(message.to_string(), cx);
+        });
     }
 
-    fn render_terminal_section(&self, cx: &mut Context<Self>) -> impl IntoElement {
-        let theme = theme_colors();
-        let terminal_height = self.terminal_height;
-        let active_terminal = self.active_terminal(cx);
+    fn handle_copy_selected_path(
+        &mut self,
+        _: &CopySelectedPath,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let path = self
+            .get_selected_entry(cx)
+            .map(|entry| entry.path)
+            .unwrap_or_else(|| self.current_path.clone());
+        self.handle_context_menu_action(ContextMenuAction::CopyPath(path), cx);
+    }
 
-        div()
-            .flex()
-            .flex_col()
-            .child(
-                div()
-                    .id("terminal-resize-handle")
-                    .w_full()
-                    .h(px(6.0))
-                    .cursor_row_resize()
-                    .flex()
-                    .items_center()
-                    .justify_center()
-                    .bg(theme.bg_secondary)
-                    .border_t_1()
-                    .border_color(theme.border_default)
-                    .hover(|h| h.bg(theme.bg_hover))
-                    .on_mouse_down(
-                        MouseButton::Left,
-                        cx.listener(|view, _, _, cx| {
-                            view.is_resizing_terminal = true;
-                            cx.notify();
-                        }),
-                    )
-                    .child(
-                        div()
-                            .w(px(40.0))
-                            .h(px(3.0))
-                            .rounded_full()
-                            .bg(theme.border_default),
-                    ),
-            )
-            .when_some(active_terminal, |this, terminal| {
+    fn handle_new_folder_with_selection(
+        &mut self,
+        _: &NewFolderWithSelection,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.start_new_folder_with_selection(cx);
+    }
+
+    fn handle_increase_ui_scale(
+        &mut self,
+        _: &IncreaseUiScale,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.set_ui_scale(crate::models::scale_up(crate::models::ui_scale()), cx);
+    }
+
+    fn handle_decrease_ui_scale(
+        &mut self,
+        _: &DecreaseUiScale,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.set_ui_scale(crate::models::scale_down(crate::models::ui_scale()), cx);
+    }
+
+    fn handle_reset_ui_scale(&mut self, _: &ResetUiScale, _window: &mut Window, cx: &mut Context<Self>) {
+        self.set_ui_scale(crate::models::UI_SCALE_DEFAULT, cx);
+    }
+
+    fn set_ui_scale(&mut self, scale: f32, cx: &mut Context<Self>) {
+        let previous = crate::models::ui_scale();
+        crate::models::set_ui_scale(scale);
+        let scale = crate::models::ui_scale();
+        if scale == previous {
+            return;
+        }
+
+        let mut settings = GlobalSettings::load();
+        settings.set_ui_scale(scale);
+        let _ = settings.save();
+
+        self.toast_manager.update(cx, |toast, cx| {
+            toast.show_info(format!("UI scale: {}%", (scale * 100.0).round() as u32), cx);
+        });
+        cx.refresh_windows();
+    }
+
+    fn handle_cycle_motion_preference(
+        &mut self,
+        _: &CycleMotionPreference,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let mut settings = GlobalSettings::load();
+        settings.motion_preference = match settings.motion_preference {
+            MotionPreference::System => MotionPreference::Reduced,
+            MotionPreference::Reduced => MotionPreference::Full,
+            MotionPreference::Full => MotionPreference::System,
+        };
+        let _ = settings.save();
+        crate::models::apply_motion_preference(settings.motion_preference);
+
+        let message = format!("Motion: {}", settings.motion_preference.label());
+        self.toast_manager.update(cx, |toast, cx| {
+            toast.show_info(message, cx);
+        });
+        cx.refresh_windows();
+    }
+
+    fn cycle_animation_speed(&mut self, cx: &mut Context<Self>) {
+        let mut settings = GlobalSettings::load();
+        let speed = crate::models::next_animation_speed(settings.animation_speed());
+        settings.set_animation_speed(speed);
+        let _ = settings.save();
+        crate::models::set_animation_speed(speed);
+
+        let mut message = format!("Animation speed: {}", crate::models::animation_speed_label(speed));
+        if crate::models::reduced_motion() {
+            message.push_str(" (reduced motion is on)");
+        }
+        self.toast_manager.update(cx, |toast, cx| {
+            toast.show_info(message, cx);
+        });
+        cx.refresh_windows();
+    }
+
+    fn cycle_easing_preset(&mut self, cx: &mut Context<Self>) {
+        let mut settings = GlobalSettings::load();
+        settings.easing_preset = settings.easing_preset.next();
+        let _ = settings.save();
+        crate::models::set_easing_preset(settings.easing_preset);
+
+        let message = format!("Animation easing: {}", settings.easing_preset.label());
+        self.toast_manager.update(cx, |toast, cx| {
+            toast.show_info(message, cx);
+        });
+        cx.refresh_windows();
+    }
+
+    fn toggle_folder_sizes(&mut self, cx: &mut Context<Self>) {
+        let mut settings = GlobalSettings::load();
+        settings.show_folder_sizes = !settings.show_folder_sizes;
+        let _ = settings.save();
+        crate::models::set_show_folder_sizes(settings.show_folder_sizes);
+
+        let message = if settings.show_folder_sizes {
+            "Folder sizes shown in list view"
+        } else {
+            "Folder sizes hidden"
+        };
+        self.toast_manager.update(cx, |toast, cx| {
+            toast.show_info(message, cx);
+        });
+        cx.refresh_windows();
+    }
+
+    fn handle_cycle_name_tint(
+        &mut self,
+        _: &CycleNameTint,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let mut settings = GlobalSettings::load();
+        settings.name_tint = settings.name_tint.next();
+        let _ = settings.save();
+        crate::models::set_name_tint_mode(settings.name_tint);
+
+        let message = format!("Name colors: {}", settings.name_tint.label());
+        self.toast_manager.update(cx, |toast, cx| {
+            toast.show_info(message, cx);
+        });
+        cx.refresh_windows();
+    }
+
+    fn handle_toggle_trash_location(
+        &mut self,
+        _: &ToggleTrashLocation,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let mut settings = GlobalSettings::load();
+        settings.trash_location = settings.trash_location.toggle();
+        let _ = settings.save();
+
+        let message = format!("Deleted items go to: {}", settings.trash_location.label());
+        self.toast_manager.update(cx, |toast, cx| {
+            toast.show_info(message, cx);
+        });
+    }
+
+    fn handle_toggle_terminal_palette(
+        &mut self,
+        _: &ToggleTerminalPalette,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let mut settings = GlobalSettings::load();
+        settings.terminal_palette = settings.terminal_palette.toggle();
+        let _ = settings.save();
+        crate::models::set_terminal_palette_mode(settings.terminal_palette);
+
+        let message = format!("Terminal colors: {}", settings.terminal_palette.label());
+        self.toast_manager.update(cx, |toast, cx| {
+            toast.show_info(message, cx);
+        });
+        cx.refresh_windows();
+    }
+
+    fn handle_toggle_click_behavior(
+        &mut self,
+        _: &ToggleClickBehavior,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let mut settings = GlobalSettings::load();
+        settings.click_behavior = settings.click_behavior.toggle();
+        let _ = settings.save();
+        crate::models::set_click_behavior(settings.click_behavior);
+
+        self.toast_manager.update(cx, |toast, cx| {
+            toast.show_info(settings.click_behavior.label().to_string(), cx);
+        });
+    }
+
+    fn handle_cycle_date_format(
+        &mut self,
+        _: &CycleDateFormat,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let mut settings = GlobalSettings::load();
+        settings.date_format = settings.date_format.next();
+        let _ = settings.save();
+        crate::models::set_date_format(settings.date_format.clone());
+        self.refresh_date_columns(cx);
+
+        self.toast_manager.update(cx, |toast, cx| {
+            toast.show_info(format!("Dates: {}", settings.date_format.label()), cx);
+        });
+    }
+
+    fn handle_cycle_conflict_strategy(
+        &mut self,
+        _: &CycleConflictStrategy,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let mut settings = GlobalSettings::load();
+        settings.conflict_strategy = settings.conflict_strategy.next();
+        let _ = settings.save();
+        self.conflict_strategy = settings.conflict_strategy;
+
+        self.toast_manager.update(cx, |toast, cx| {
+            toast.show_info(format!("Conflicts: {}", settings.conflict_strategy.label()), cx);
+        });
+    }
+
+    fn start_relative_date_refresh(&self, cx: &mut Context<Self>) {
+        cx.spawn(async move |this, cx| loop {
+            cx.background_executor()
+                .timer(crate::models::RELATIVE_REFRESH_INTERVAL)
+                .await;
+            let updated = this.update(cx, |workspace, cx| {
+                if !crate::models::date_format().is_relative() {
+                    return;
+                }
+                let now = std::time::SystemTime::now();
+                let has_recent = workspace
+                    .cached_entries
+                    .iter()
+                    .any(|entry| crate::models::is_recent(entry.modified, now));
+                if has_recent {
+                    workspace.refresh_date_columns(cx);
+                }
+            });
+            if updated.is_err() {
+                break;
+            }
+        })
+        .detach();
+    }
+
+    fn start_volume_space_refresh(&self, cx: &mut Context<Self>) {
+        cx.spawn(async move |this, cx| loop {
+            cx.background_executor()
+                .timer(crate::models::VOLUME_SPACE_REFRESH_INTERVAL)
+                .await;
+            let updated = this.update(cx, |workspace, cx| {
+                workspace.refresh_volume_space(true, cx);
+            });
+            if updated.is_err() {
+                break;
+            }
+        })
+        .detach();
+    }
+
+    fn start_directory_watch(&mut self, cx: &mut Context<Self>) {
+        if self.watching_directory {
+            return;
+        }
+        self.watching_directory = true;
+        cx.spawn(async move |this, cx| loop {
+            cx.background_executor().timer(WATCH_POLL_INTERVAL).await;
+            let watching = this.update(cx, |workspace, cx| workspace.poll_directory_watch(cx));
+            if !watching.unwrap_or(false) {
+                break;
+            }
+        })
+        .detach();
+    }
+
+    fn stop_directory_watch(&mut self) {
+        self.watching_directory = false;
+        if let Some(previous) = self.watched_path.take() {
+            let _ = self.watcher.unwatch(&previous);
+        }
+    }
+
+    fn poll_directory_watch(&mut self, cx: &mut Context<Self>) -> bool {
+        if !self.watching_directory || self.search_input.read(cx).query().is_empty() {
+            self.stop_directory_watch();
+            return false;
+        }
+
+        if self.watched_path.as_ref() != Some(&self.current_path) {
+            if let Some(previous) = self.watched_path.take() {
+                let _ = self.watcher.unwatch(&previous);
+            }
+            if self.watcher.watch(&self.current_path).is_ok() {
+                self.watched_path = Some(self.current_path.clone());
+            }
+            return true;
+        }
+
+        let events = self.watcher.poll_events();
+        if !events.is_empty() {
+            self.apply_watch_events(events, cx);
+        }
+        true
+    }
+
+    fn apply_watch_events(&mut self, events: Vec<FsEvent>, cx: &mut Context<Self>) {
+        if self.flatten_session.is_some() {
+            return;
+        }
+
+        let applied: Vec<FsEvent> = self.file_system.update(cx, |fs, _| {
+            events
+                .into_iter()
+                .filter(|event| fs.process_event(event.clone()))
+                .collect()
+        });
+        if applied.is_empty() {
+            return;
+        }
+
+        let selected = self.get_selected_entry(cx).map(|entry| entry.path);
+        let mut entries = self.file_system.read(cx).entries().to_vec();
+        self.update_share_status_on_entries(&mut entries);
+        self.cached_entries = entries.clone();
+        self.git_status_cache.invalidate();
+        let current = self.current_path.clone();
+        self.update_git_context(&current, cx);
+
+        self.search_engine.update(cx, |engine, _| {
+            engine.apply_events(&applied);
+        });
+        self.file_list.update(cx, |view, _| {
+            view.inner_mut().refresh_entries(entries.clone());
+        });
+        self.grid_view.update(cx, |view, _| {
+            view.inner_mut().refresh_entries(entries.clone());
+        });
+
+        let query = self.search_input.read(cx).query().to_string();
+        if !query.is_empty() {
+            self.handle_search_query_change(&query, cx);
+            if let Some(path) = selected {
+                self.file_list.update(cx, |view, _| {
+                    view.inner_mut().select_entry_at_path(&path);
+                });
+            }
+        }
+
+        self.status_bar.update(cx, |view, cx| {
+            view.update_from_entries(&entries, None, cx);
+        });
+        cx.notify();
+    }
+
+    fn refresh_volume_space(&mut self, force: bool, cx: &mut Context<Self>) {
+        if self.volume_space_loading {
+            return;
+        }
+        self.volume_space_loading = true;
+        let path = self.current_path.clone();
+        let known = self.volume_id.filter(|_| !force);
+
+        cx.spawn(async move |this, cx| {
+            let (id, space) = cx
+                .background_executor()
+                .spawn({
+                    let path = path.clone();
+                    async move {
+                        let id = volume_id(&path);
+                        if id.is_some() && id == known {
+                            return (id, None);
+                        }
+                        let space = get_disk_space(&path)
+                            .ok()
+                            .map(|(total, free)| VolumeSpace::new(total, free));
+                        (id, Some(space))
+                    }
+                })
+                .await;
+
+            let _ = this.update(cx, |workspace, cx| {
+                workspace.volume_space_loading = false;
+                workspace.volume_id = id;
+                if let Some(space) = space {
+                    workspace.status_bar.update(cx, |view, cx| {
+                        view.set_volume_space(space, cx);
+                    });
+                }
+                if workspace.current_path != path {
+                    workspace.refresh_volume_space(false, cx);
+                }
+            });
+        })
+        .detach();
+    }
+
+    fn refresh_date_columns(&mut self, cx: &mut Context<Self>) {
+        self.file_list.update(cx, |_, cx| cx.notify());
+        self.grid_view.update(cx, |_, cx| cx.notify());
+        if let Some(preview) = &self.preview {
+            preview.update(cx, |_, cx| cx.notify());
+        }
+    }
+
+    fn handle_cycle_icon_pack(
+        &mut self,
+        _: &CycleIconPack,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let packs = crate::models::available_icon_packs();
+        let current = crate::models::current_icon_pack();
+        let next_index = packs
+            .iter()
+            .position(|pack| pack.name() == current.name())
+            .map_or(0, |index| (index + 1) % packs.len());
+        let Some(pack) = packs.get(next_index).cloned() else {
+            return;
+        };
+
+        let mut settings = GlobalSettings::load();
+        settings.icon_pack = pack.name().to_string();
+        let _ = settings.save();
+
+        let missing = pack.missing_required();
+        let message = if missing.is_empty() {
+            format!("Icon pack: {}", pack.name())
+        } else {
+            format!(
+                "Icon pack: {} (using built-in icons for {})",
+                pack.name(),
+                missing.join(", ")
+            )
+        };
+        crate::models::set_current_icon_pack(pack.clone());
+        self.icon_cache.update(cx, |cache, _| {
+            cache.set_icon_pack(pack);
+        });
+
+        self.toast_manager.update(cx, |toast, cx| {
+            toast.show_info(message, cx);
+        });
+        cx.refresh_windows();
+    }
+
+    fn handle_toggle_flatten_view(
+        &mut self,
+        _: &ToggleFlattenView,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if self.flatten_session.is_some() {
+            self.exit_flatten_view(cx);
+        } else {
+            self.start_flatten_view(cx);
+        }
+    }
+
+    fn start_flatten_view(&mut self, cx: &mut Context<Self>) {
+        self.stop_flatten_session(cx);
+
+        let root = self.current_path.clone();
+        let session = FlattenSession::new(root.clone(), crate::models::FLATTEN_ENTRY_LIMIT);
+        let cancelled = session.cancel_handle();
+        let (receiver, _handle) = crate::io::spawn_traversal(
+            root,
+            FlattenSession::traversal_config(self.show_hidden_for(&self.current_path)),
+        );
+        self.flatten_session = Some(session);
+        self.search_engine.update(cx, |engine, _| engine.clear());
+        self.set_flatten_entries(Vec::new(), cx);
+        self.sync_indexing_status(cx);
+
+        cx.spawn(async move |this, cx| {
+            while let Ok(first) = receiver.recv_async().await {
+                if cancelled.load(std::sync::atomic::Ordering::SeqCst) {
+                    break;
+                }
+
+                let batch = drain_batch(&receiver, first, crate::models::FLATTEN_BATCH_SIZE);
+                let keep_going = this
+                    .update(cx, |workspace, cx| workspace.append_flatten_batch(batch, cx))
+                    .unwrap_or(false);
+                if !keep_going {
+                    break;
+                }
+            }
+
+            let _ = this.update(cx, |workspace, cx| {
+                if let Some(session) = workspace.flatten_session.as_mut() {
+                    if std::sync::Arc::ptr_eq(&session.cancel_handle(), &cancelled) {
+                        session.finish();
+                    }
+                }
+                workspace.sync_indexing_status(cx);
+                cx.notify();
+            });
+        })
+        .detach();
+
+        cx.notify();
+    }
+
+    fn append_flatten_batch(&mut self, batch: TraversalBatch, cx: &mut Context<Self>) -> bool {
+        let Some(session) = self.flatten_session.as_mut() else {
+            return false;
+        };
+        if !session.is_running() {
+            return false;
+        }
+
+        let accepted = session.receive(batch);
+        let keep_going = session.is_running();
+        if session.is_truncated() {
+            self.toast_manager.update(cx, |toast, cx| {
+                toast.show_info(
+                    format!(
+                        "Stopped after {} files",
+                        crate::models::FLATTEN_ENTRY_LIMIT
+                    ),
+                    cx,
+                );
+            });
+        }
+        self.search_engine.update(cx, |engine, _| {
+            for entry in &accepted {
+                engine.inject(entry.path.clone());
+            }
+        });
+        self.set_flatten_entries(accepted, cx);
+        self.sync_indexing_status(cx);
+
+        let query = self.search_input.read(cx).query().to_string();
+        if !query.is_empty() {
+            self.handle_search_query_change(&query, cx);
+        }
+        keep_going
+    }
+
+    fn sync_indexing_status(&mut self, cx: &mut Context<Self>) {
+        let status = self
+            .flatten_session
+            .as_ref()
+            .map(FlattenSession::indexing_status)
+            .unwrap_or_default();
+        self.status_bar.update(cx, |view, cx| {
+            view.set_indexing_status(status, cx);
+        });
+    }
+
+    fn set_flatten_entries(&mut self, accepted: Vec<crate::models::FileEntry>, cx: &mut Context<Self>) {
+        let Some(session) = self.flatten_session.as_ref() else {
+            return;
+        };
+
+        if accepted.is_empty() && !session.is_empty() {
+            return;
+        }
+
+        let root = session.root().to_path_buf();
+        if accepted.is_empty() {
+            self.cached_entries.clear();
+            self.file_list
+                .update(cx, |view, _| view.inner_mut().set_entries(Vec::new()));
+            self.grid_view
+                .update(cx, |view, _| view.inner_mut().set_entries(Vec::new()));
+        } else {
+            self.cached_entries.extend(accepted.iter().cloned());
+            self.grid_view
+                .update(cx, |view, _| view.inner_mut().append_entries(accepted.clone()));
+            self.file_list
+                .update(cx, |view, _| view.inner_mut().append_entries(accepted));
+        }
+
+        let entries = &self.cached_entries;
+        self.status_bar.update(cx, |view, cx| {
+            view.update_from_entries(entries, None, cx);
+            view.set_current_directory(&root, cx);
+        });
+
+        self.regroup_results(cx);
+        cx.notify();
+    }
+
+    fn regroup_results(&mut self, cx: &mut Context<Self>) {
+        match self.flatten_session.as_ref() {
+            Some(session) if self.result_grouping == ResultGrouping::ByDirectory => {
+                let sort = *self.file_list.read(cx).inner().sort_state();
+                self.grouped_results.set_entries(session.entries(), &sort);
+            }
+            _ => self.grouped_results.clear(),
+        }
+    }
+
+    fn toggle_result_grouping(&mut self, cx: &mut Context<Self>) {
+        self.result_grouping = self.result_grouping.toggle();
+
+        let mut settings = GlobalSettings::load();
+        settings.result_grouping = self.result_grouping;
+        let _ = settings.save();
+
+        self.regroup_results(cx);
+        cx.notify();
+    }
+
+    fn toggle_result_group(&mut self, directory: &Path, cx: &mut Context<Self>) {
+        self.grouped_results.toggle_collapsed(directory);
+        cx.notify();
+    }
+
+    fn open_grouped_result(&mut self, path: PathBuf, cx: &mut Context<Self>) {
+        let Some(parent) = path.parent().map(Path::to_path_buf) else {
+            return;
+        };
+        self.navigate_to(parent, cx);
+        self.reveal_path(&path, cx);
+    }
+
+    fn stop_flatten_view(&mut self, cx: &mut Context<Self>) {
+        if let Some(session) = self.flatten_session.as_mut() {
+            session.stop();
+        }
+        self.sync_indexing_status(cx);
+        cx.notify();
+    }
+
+    fn exit_flatten_view(&mut self, cx: &mut Context<Self>) {
+        let path = self.current_path.clone();
+        self.load_directory(path, cx);
+    }
+
+    fn stop_flatten_session(&mut self, cx: &mut Context<Self>) {
+        if let Some(mut session) = self.flatten_session.take() {
+            session.stop();
+        }
+        self.grouped_results.clear();
+        self.sync_indexing_status(cx);
+    }
+
+    fn render_git_bar(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        let theme = theme_colors();
+        let branch = self
+            .git_repo
+            .as_ref()
+            .and_then(|repo| repo.branch.clone())
+            .unwrap_or_else(|| "detached".to_string());
+        let has_remote = self
+            .git_repo
+            .as_ref()
+            .is_some_and(|repo| repo.remote_url.is_some());
+
+        let action = |id: &'static str, icon: &'static str, label: &'static str| {
+            div()
+                .id(id)
+                .flex()
+                .items_center()
+                .gap_1()
+                .px_2()
+                .py_0p5()
+                .rounded_md()
+                .cursor_pointer()
+                .hover(|h| h.bg(theme.bg_hover))
+                .child(svg().path(icon).size(px(12.0)).text_color(theme.text_muted))
+                .child(label)
+        };
+
+        div()
+            .h(px(32.0))
+            .px(px(crate::models::scaled(crate::models::toolbar::PADDING_X)))
+            .flex()
+            .items_center()
+            .justify_between()
+            .bg(theme.bg_secondary)
+            .border_b_1()
+            .border_color(theme.border_default)
+            .text_xs()
+            .text_color(theme.text_muted)
+            .child(
+                div()
+                    .flex()
+                    .items_center()
+                    .gap_2()
+                    .child(
+                        svg()
+                            .path("assets/icons/folder-git.svg")
+                            .size(px(14.0))
+                            .text_color(theme.accent_primary),
+                    )
+                    .child(format!("Git repository • {}", branch)),
+            )
+            .child(
+                div()
+                    .flex()
+                    .items_center()
+                    .gap_2()
+                    .child(
+                        action("git-terminal-btn", "assets/icons/terminal.svg", "Terminal here")
+                            .on_mouse_down(
+                                MouseButton::Left,
+                                cx.listener(|view, _event, _window, cx| {
+                                    view.open_git_terminal(cx);
+                                }),
+                            ),
+                    )
+                    .when(has_remote, |this| {
+                        this.child(
+                            action("git-remote-btn", "assets/icons/link.svg", "Copy remote URL")
+                                .on_mouse_down(
+                                    MouseButton::Left,
+                                    cx.listener(|view, _event, _window, cx| {
+                                        view.copy_git_remote_url(cx);
+                                    }),
+                                ),
+                        )
+                    })
+                    .child(
+                        action("git-status-btn", "assets/icons/info.svg", "Status").on_mouse_down(
+                            MouseButton::Left,
+                            cx.listener(|view, _event, _window, cx| {
+                                view.show_git_status(cx);
+                            }),
+                        ),
+                    ),
+            )
+    }
+
+    fn render_flatten_bar(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        let theme = theme_colors();
+        let (count, is_running, is_truncated) = self
+            .flatten_session
+            .as_ref()
+            .map(|s| (s.len(), s.is_running(), s.is_truncated()))
+            .unwrap_or((0, false, false));
+
+        let status = if is_running {
+            format!("Flattened view • {} files (scanning…)", count)
+        } else if is_truncated {
+            format!("Flattened view • {} files (limit reached)", count)
+        } else {
+            format!("Flattened view • {} files", count)
+        };
+        let is_grouped = self.result_grouping == ResultGrouping::ByDirectory;
+
+        div()
+            .h(px(32.0))
+            .px(px(crate::models::scaled(crate::models::toolbar::PADDING_X)))
+            .flex()
+            .items_center()
+            .justify_between()
+            .bg(theme.bg_secondary)
+            .border_b_1()
+            .border_color(theme.border_default)
+            .text_xs()
+            .text_color(theme.text_muted)
+            .child(
+                div()
+                    .flex()
+                    .items_center()
+                    .gap_2()
+                    .child(
+                        svg()
+                            .path("assets/icons/files.svg")
+                            .size(px(14.0))
+                            .text_color(theme.accent_primary),
+                    )
+                    .child(status),
+            )
+            .child(
+                div()
+                    .flex()
+                    .items_center()
+                    .gap_2()
+                    .child(
+                        div()
+                            .id("flatten-grouping-btn")
+                            .flex()
+                            .items_center()
+                            .gap_1()
+                            .px_2()
+                            .py_0p5()
+                            .rounded_md()
+                            .cursor_pointer()
+                            .hover(|h| h.bg(theme.bg_hover))
+                            .when(is_grouped, |s| s.bg(theme.bg_selected))
+                            .on_mouse_down(
+                                MouseButton::Left,
+                                cx.listener(|view, _event, _window, cx| {
+                                    view.toggle_result_grouping(cx);
+                                }),
+                            )
+                            .child(
+                                svg()
+                                    .path(if is_grouped {
+                                        "assets/icons/folder.svg"
+                                    } else {
+                                        "assets/icons/list.svg"
+                                    })
+                                    .size(px(12.0))
+                                    .text_color(theme.text_muted),
+                            )
+                            .child(self.result_grouping.label()),
+                    )
+                    .when(is_running, |this| {
+                        this.child(
+                            div()
+                                .id("flatten-stop-btn")
+                                .px_2()
+                                .py_0p5()
+                                .rounded_md()
+                                .cursor_pointer()
+                                .hover(|h| h.bg(theme.bg_hover))
+                                .on_mouse_down(
+                                    MouseButton::Left,
+                                    cx.listener(|view, _event, _window, cx| {
+                                        view.stop_flatten_view(cx);
+                                    }),
+                                )
+                                .child("Stop"),
+                        )
+                    })
+                    .child(
+                        div()
+                            .id("flatten-exit-btn")
+                            .flex()
+                            .items_center()
+                            .gap_1()
+                            .px_2()
+                            .py_0p5()
+                            .rounded_md()
+                            .cursor_pointer()
+                            .hover(|h| h.bg(theme.bg_hover))
+                            .on_mouse_down(
+                                MouseButton::Left,
+                                cx.listener(|view, _event, _window, cx| {
+                                    view.exit_flatten_view(cx);
+                                }),
+                            )
+                            .child(
+                                svg()
+                                    .path("assets/icons/x.svg")
+                                    .size(px(12.0))
+                                    .text_color(theme.text_muted),
+                            )
+                            .child("Exit"),
+                    ),
+            )
+    }
+
+    fn handle_quick_look_toggle(
+        &mut self,
+        _: &QuickLookToggle,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let selected_entry = match self.view_mode {
+            ViewMode::List | ViewMode::Details => {
+                let idx = self.file_list.read(cx).inner().selected_index();
+                idx.and_then(|i| self.cached_entries.get(i).cloned())
+            }
+            ViewMode::Grid => {
+                let idx = self.grid_view.read(cx).inner().selected_index();
+                idx.and_then(|i| self.cached_entries.get(i).cloned())
+            }
+        };
+
+        if let Some(entry) = selected_entry {
+            let entries = self.cached_entries.clone();
+            let index = self
+                .cached_entries
+                .iter()
+                .position(|e| e.path == entry.path)
+                .unwrap_or(0);
+            self.quick_look.update(cx, |view, cx| {
+                view.toggle(entry.path, entries, index, cx);
+            });
+            cx.notify();
+        }
+    }
+
+    fn render_breadcrumbs(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        let theme = theme_colors();
+        let text_light = theme.text_primary;
+        let accent_primary = theme.accent_primary;
+        let accent_secondary = theme.accent_secondary;
+        let hover_bg = theme.bg_hover;
+
+        let mut parts: Vec<(String, PathBuf)> = Vec::new();
+        let mut current = Some(self.current_path.as_path());
+
+        while let Some(path) = current {
+            let name = if path.parent().is_none() {
+                "/".to_string()
+            } else {
+                path.file_name()
+                    .and_then(|n| n.to_str())
+                    .unwrap_or("")
+                    .to_string()
+            };
+            if !name.is_empty() {
+                parts.push((name, path.to_path_buf()));
+            }
+            current = path.parent();
+            if parts.len() >= 5 {
+                break;
+            }
+        }
+
+        parts.reverse();
+
+        div()
+            .flex()
+            .items_center()
+            .text_sm()
+            .font_weight(gpui::FontWeight::MEDIUM)
+            .children(parts.into_iter().enumerate().map(|(i, (name, path))| {
+                div()
+                    .flex()
+                    .items_center()
+                    .when(i > 0, |s| {
+                        s.child(
+                            svg()
+                                .path("assets/icons/chevron-right.svg")
+                                .size(px(14.0))
+                                .text_color(accent_secondary)
+                                .mx_1(),
+                        )
+                    })
+                    .child(
+                        div()
+                            .id(SharedString::from(format!("breadcrumb-{}", i)))
+                            .px(px(crate::models::scaled(crate::models::toolbar::BREADCRUMB_PADDING)))
+                            .py_0p5()
+                            .rounded_sm()
+                            .text_color(text_light)
+                            .cursor_pointer()
+                            .hover(|s| s.text_color(accent_primary).bg(hover_bg))
+                            .on_mouse_down(MouseButton::Left, {
+                                let nav_path = path.clone();
+                                cx.listener(move |view, _, _, cx| {
+                                    view.navigate_to(nav_path.clone(), cx);
+                                })
+                            })
+                            .child(name),
+                    )
+            }))
+    }
+}
+
+impl Focusable for Workspace {
+    fn focus_handle(&self, _cx: &App) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for Workspace {
+    fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        if self.should_focus_dialog_input {
+            if let Some(input) = &self.dialog_input {
+                window.focus(&input.read(cx).focus_handle(cx));
+            }
+            self.should_focus_dialog_input = false;
+        }
+
+        if let Some(trap) = self.conflict_focus.as_mut() {
+            trap.activate(window, cx);
+        }
+        self.sync_focused_region(window, cx);
+        self.persist_dual_pane_layout(window, cx);
+
+        window.set_rem_size(px(crate::models::BASE_REM_SIZE * crate::models::ui_scale()));
+
+        let theme = theme_colors();
+        let current = current_theme();
+        let content_bg = current.content_background();
+
+        div()
+            .id("workspace")
+            .key_context("Workspace")
+            .track_focus(&self.focus_handle)
+            .on_any_mouse_down(cx.listener(Self::handle_mouse_navigation))
+            .on_action(cx.listener(Self::handle_new_tab))
+            .on_action(cx.listener(Self::handle_close_tab))
+            .on_action(cx.listener(Self::handle_next_tab))
+            .on_action(cx.listener(Self::handle_prev_tab))
+            .on_action(cx.listener(Self::handle_toggle_terminal))
+            .on_action(cx.listener(Self::handle_focus_search))
+            .on_action(cx.listener(Self::handle_new_window))
+            .on_action(cx.listener(Self::handle_quick_look_toggle))
+            .on_action(cx.listener(Self::handle_reveal_in_sidebar))
+            .on_action(cx.listener(Self::handle_toggle_auto_reveal))
+            .on_action(cx.listener(Self::handle_copy_selected_path))
+            .on_action(cx.listener(Self::handle_new_folder_with_selection))
+            .on_action(cx.listener(Self::handle_increase_ui_scale))
+            .on_action(cx.listener(Self::handle_decrease_ui_scale))
+            .on_action(cx.listener(Self::handle_reset_ui_scale))
+            .on_action(cx.listener(Self::handle_cycle_motion_preference))
+            .on_action(cx.listener(Self::handle_cycle_icon_pack))
+            .on_action(cx.listener(Self::handle_cycle_name_tint))
+            .on_action(cx.listener(Self::handle_toggle_trash_location))
+            .on_action(cx.listener(Self::handle_toggle_terminal_palette))
+            .on_action(cx.listener(Self::handle_toggle_click_behavior))
+            .on_action(cx.listener(Self::handle_cycle_date_format))
+            .on_action(cx.listener(Self::handle_cycle_conflict_strategy))
+            .on_action(cx.listener(Self::handle_toggle_flatten_view))
+            .on_action(cx.listener(Self::handle_toggle_preview_pane))
+            .on_action(cx.listener(Self::handle_toggle_dual_pane))
+            .on_action(cx.listener(Self::handle_toggle_command_palette))
+            .on_action(cx.listener(Self::handle_focus_next_region))
+            .on_action(cx.listener(Self::handle_focus_previous_region))
+            .on_action(cx.listener(Self::handle_go_to_parent))
+            .on_action(cx.listener(Self::handle_go_to_home))
+            .on_action(cx.listener(Self::handle_go_to_root))
+            .on_mouse_up(
+                MouseButton::Left,
+                cx.listener(|view, _, window, cx| {
+                    if view.is_resizing_terminal || view.is_resizing_preview {
+                        view.is_resizing_terminal = false;
+                        view.is_resizing_preview = false;
+                        view.persist_pane_sizes(window, cx);
+                        cx.notify();
+                    }
+                    if view.is_resizing_sidebar {
+                        view.is_resizing_sidebar = false;
+                        view.persist_sidebar_width(window, cx);
+                        cx.notify();
+                    }
+                }),
+            )
+            .on_mouse_move(cx.listener(Self::handle_resize_mouse_move))
+            .size_full()
+            .flex()
+            .flex_col()
+            .bg(content_bg.base_color)
+            .text_color(theme.text_primary)
+            .font_family(".SystemUIFont")
+            .child(self.render_titlebar(cx))
+            .when(self.tabs_enabled, |this| this.child(self.tab_bar.clone()))
+            .child(self.render_main_content(cx))
+            .child(self.status_bar.clone())
+            .when(!matches!(self.dialog_state, DialogState::None), |this| {
+                this.child(self.render_dialog_overlay(cx))
+            })
+            .when(self.show_smart_folder_dialog, |this| {
+                this.child(self.smart_folder_dialog.clone())
+            })
+            .when(self.conflict_dialog.is_some(), |this| {
+                this.child(self.render_conflict_dialog_overlay(window, cx))
+            })
+            .when_some(self.overwrite_summary.clone(), |this, summary| {
+                this.child(self.render_overwrite_summary_overlay(summary, cx))
+            })
+            .when_some(self.executable_prompt.clone(), |this, prompt| {
+                this.child(self.render_executable_prompt_overlay(prompt, cx))
+            })
+            .when(self.symlink_dialog.is_some(), |this| {
+                this.child(self.render_symlink_dialog_overlay(cx))
+            })
+            .when(self.bulk_tag_dialog.is_some(), |this| {
+                this.child(self.render_bulk_tag_dialog_overlay(cx))
+            })
+            .when(self.checksum_verify_panel.is_some(), |this| {
+                this.child(self.render_checksum_verify_overlay(cx))
+            })
+            .when(self.duplicate_finder_panel.is_some(), |this| {
+                this.child(self.render_duplicate_finder_overlay(cx))
+            })
+            .when(self.format_dialog.is_some(), |this| {
+                this.child(self.render_format_dialog_overlay(cx))
+            })
+            .when(self.bootable_usb_dialog.is_some(), |this| {
+                this.child(self.render_bootable_usb_dialog_overlay(cx))
+            })
+            .child(self.theme_picker.clone())
+            .child(self.command_palette.clone())
+            .when(!self.operation_queue.is_empty(), |this| {
+                this.child(
+                    div()
+                        .absolute()
+                        .bottom_4()
+                        .right_4()
+                        .w(px(360.0))
+                        .child(self.progress_panel.clone()),
+                )
+            })
+            .child(self.quick_look.clone())
+            .child(self.toast_manager.clone())
+    }
+}
+
+impl Workspace {
+    fn handle_resize_mouse_move(
+        &mut self,
+        event: &gpui::MouseMoveEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if self.is_resizing_terminal {
+            let bounds = window.bounds();
+            let mouse_y = event.position.y;
+            let window_height = bounds.size.height;
+            let new_height = f32::from(window_height) - f32::from(mouse_y) - 30.0;
+            self.terminal_height = crate::models::terminal_layout::clamp_height(new_height);
+            cx.notify();
+        }
+        if self.is_resizing_preview {
+            let bounds = window.bounds();
+            let mouse_x = event.position.x;
+            let window_width = bounds.size.width;
+            let new_width = f32::from(window_width) - f32::from(mouse_x);
+            self.preview_width = crate::models::preview::clamp_width(new_width);
+            cx.notify();
+        }
+        if self.is_resizing_sidebar && !self.is_resizing_preview {
+            let mouse_x = f32::from(event.position.x);
+            self.sidebar_width =
+                crate::models::sidebar::clamp_width(mouse_x / crate::models::ui_scale());
+            cx.notify();
+        }
+    }
+
+    pub fn set_sidebar_width(&mut self, width: f32, cx: &mut Context<Self>) {
+        self.sidebar_width = crate::models::sidebar::clamp_width(width);
+        cx.notify();
+    }
+
+    fn persist_sidebar_width(&self, window: &Window, cx: &mut Context<Self>) {
+        let window_id = window.window_handle().window_id();
+        let width = self.sidebar_width;
+        if cx.has_global::<WindowManager>() {
+            cx.update_global::<WindowManager, _>(|manager, _| {
+                if let Some(id) = manager.find_window(window_id) {
+                    manager.update_sidebar_width(id, width);
+                }
+            });
+        }
+    }
+
+    pub fn set_preview_width(&mut self, width: f32, cx: &mut Context<Self>) {
+        self.preview_width = crate::models::preview::clamp_width(width);
+        cx.notify();
+    }
+
+    pub fn set_terminal_height(&mut self, height: f32, cx: &mut Context<Self>) {
+        self.terminal_height = crate::models::terminal_layout::clamp_height(height);
+        cx.notify();
+    }
+
+    fn observe_dual_pane(dual_pane: &Entity<DualPaneView>, cx: &mut Context<Self>) {
+        cx.observe(dual_pane, |workspace: &mut Workspace, dual_pane, cx| {
+            if let Some(layout) = dual_pane.update(cx, |view, _| view.take_layout_change()) {
+                workspace.dual_pane_layout_change = Some(layout);
+                cx.notify();
+            }
+            if let Some(action) = dual_pane.update(cx, |view, _| view.take_pending_action()) {
+                workspace.handle_dual_pane_action(action, cx);
+            }
+        })
+        .detach();
+    }
+
+    pub fn restore_dual_pane(&mut self, layout: DualPaneLayout, cx: &mut Context<Self>) {
+        let dual_pane = cx.new(|cx| DualPaneView::from_layout(layout, cx));
+        Self::observe_dual_pane(&dual_pane, cx);
+        let (enabled, left, right) = {
+            let view = dual_pane.read(cx);
+            (view.is_enabled(), view.left_path().clone(), view.right_path().clone())
+        };
+        self.dual_pane = dual_pane;
+        if enabled {
+            self.load_dual_pane_side(PaneSide::Left, left, cx);
+            self.load_dual_pane_side(PaneSide::Right, right, cx);
+        }
+        cx.notify();
+    }
+
+    fn handle_toggle_dual_pane(
+        &mut self,
+        _: &ToggleDualPane,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if !self.dual_pane.read(cx).is_enabled() {
+            let left = self.current_path.clone();
+            let right = self.dual_pane.update(cx, |view, _| {
+                view.inner_mut().left_pane_mut().navigate_to(left.clone());
+                view.right_path().clone()
+            });
+            self.load_dual_pane_side(PaneSide::Left, left, cx);
+            self.load_dual_pane_side(PaneSide::Right, right, cx);
+        }
+        self.dual_pane.update(cx, |view, cx| view.toggle(cx));
+    }
+
+    fn handle_dual_pane_action(&mut self, action: DualPaneAction, cx: &mut Context<Self>) {
+        match action {
+            DualPaneAction::NavigateLeft(path) => self.load_dual_pane_side(PaneSide::Left, path, cx),
+            DualPaneAction::NavigateRight(path) => {
+                self.load_dual_pane_side(PaneSide::Right, path, cx)
+            }
+            DualPaneAction::CopyFiles { sources, destination } => {
+                for source in sources {
+                    if let Some(name) = source.file_name() {
+                        let target = destination.join(name);
+                        self.enqueue_paste(source, target, false, false, cx);
+                    }
+                }
+            }
+            DualPaneAction::MoveFiles { sources, destination } => {
+                for source in sources {
+                    if let Some(name) = source.file_name() {
+                        let target = destination.join(name);
+                        self.enqueue_paste(source, target, true, false, cx);
+                    }
+                }
+            }
+        }
+    }
+
+    fn load_dual_pane_side(&mut self, side: PaneSide, path: PathBuf, cx: &mut Context<Self>) {
+        let show_hidden = self.show_hidden_for(&path);
+        let dual_pane = self.dual_pane.clone();
+        cx.spawn(async move |_, cx| {
+            let entries = cx
+                .background_executor()
+                .spawn(async move {
+                    let mut fs = FileSystem::new(path.clone());
+                    let _ = load_directory_sync(
+                        &mut fs,
+                        path,
+                        SortKey::Name,
+                        SortOrder::Ascending,
+                        show_hidden,
+                    );
+                    fs.entries().to_vec()
+                })
+                .await;
+            let _ = dual_pane.update(cx, |view, cx| view.set_pane_entries(side, entries, cx));
+        })
+        .detach();
+    }
+
+    fn persist_dual_pane_layout(&mut self, window: &Window, cx: &mut Context<Self>) {
+        let Some(layout) = self.dual_pane_layout_change.take() else {
+            return;
+        };
+        let window_id = window.window_handle().window_id();
+        if cx.has_global::<WindowManager>() {
+            cx.update_global::<WindowManager, _>(|manager, _| {
+                if let Some(id) = manager.find_window(window_id) {
+                    manager.update_dual_pane_layout(id, layout);
+                }
+            });
+        }
+    }
+
+    fn persist_pane_sizes(&self, window: &Window, cx: &mut Context<Self>) {
+        let window_id = window.window_handle().window_id();
+        let panes = PaneSizes::new(self.preview_width, self.terminal_height);
+        if cx.has_global::<WindowManager>() {
+            cx.update_global::<WindowManager, _>(|manager, _| {
+                if let Some(id) = manager.find_window(window_id) {
+                    manager.update_pane_sizes(id, panes);
+                }
+            });
+        }
+    }
+
+    fn render_titlebar(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        let theme = theme_colors();
+
+        div()
+            .id("titlebar")
+            .h(px(52.0))
+            .bg(theme.bg_void)
+            .flex()
+            .items_center()
+            .justify_between()
+            .px_5()
+            .py_2()
+            .border_b_1()
+            .border_color(theme.border_default)
+            .on_click(|event, window, _cx| {
+                if event.click_count() == 2 {
+                    window.titlebar_double_click();
+                }
+            })
+            .child(
+                div().flex().items_center().pl(px(70.0)).child(
+                    svg()
+                        .path("assets/icons/logo.svg")
+                        .size(px(20.0))
+                        .text_color(theme.accent_primary),
+                ),
+            )
+            .child(
+                div()
+                    .relative()
+                    .w_1_3()
+                    .max_w(px(500.0))
+                    .child(self.search_input.clone()),
+            )
+            .child(
+                div().flex().items_center().gap_3().child(
+                    div()
+                        .id("theme-picker-btn")
+                        .px_2()
+                        .py_1()
+                        .rounded_md()
+                        .cursor_pointer()
+                        .flex()
+                        .items_center()
+                        .gap_1p5()
+                        .hover(|h| h.bg(theme.bg_hover))
+                        .on_mouse_down(
+                            MouseButton::Left,
+                            cx.listener(|view, _event, _window, cx| {
+                                view.toggle_theme_picker(cx);
+                            }),
+                        )
+                        .child(
+                            svg()
+                                .path("assets/icons/sparkles.svg")
+                                .size(px(14.0))
+                                .text_color(theme.accent_primary),
+                        )
+                        .child(
+                            div()
+                                .text_xs()
+                                .text_color(theme.text_secondary)
+                                .child("Themes"),
+                        ),
+                ),
+            )
+    }
+
+    fn render_main_content(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        let theme = theme_colors();
+
+        div()
+            .flex()
+            .flex_1()
+            .overflow_hidden()
+            .child(self.render_sidebar(cx))
+            .child(self.render_content_area(cx))
+            .when(self.copy_move_mode, |this| {
+                this.child(self.render_destination_pane(cx))
+            })
+            .when(!self.copy_move_mode, |this| {
+                this.children(self.preview.clone().map(|p| self.render_preview_pane(p, cx)))
+            })
+    }
+
+    fn render_sidebar(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        let theme = theme_colors();
+        let is_resizing = self.is_resizing_sidebar;
+
+        div()
+            .w(px(crate::models::scaled(self.sidebar_width)))
+            .flex_shrink_0()
+            .bg(theme.bg_secondary)
+            .relative()
+            .border_r_1()
+            .border_color(theme.border_default)
+            .flex()
+            .flex_col()
+            .child(self.sidebar.clone())
+            .children(self.render_region_outline(FocusRegion::Sidebar))
+            .child(
+                div()
+                    .id("sidebar-resize-handle")
+                    .absolute()
+                    .top_0()
+                    .bottom_0()
+                    .right(px(-3.0))
+                    .w(px(6.0))
+                    .cursor_col_resize()
+                    .when(is_resizing, |this| this.bg(theme.border_emphasis))
+                    .hover(|h| h.bg(theme.border_emphasis))
+                    .on_mouse_down(
+                        MouseButton::Left,
+                        cx.listener(|view, event: &gpui::MouseDownEvent, window, cx| {
+                            if event.click_count == 2 {
+                                view.is_resizing_sidebar = false;
+                                view.set_sidebar_width(crate::models::sidebar::WIDTH, cx);
+                                view.persist_sidebar_width(window, cx);
+                            } else if !view.is_resizing_preview {
+                                view.is_resizing_sidebar = true;
+                                cx.notify();
+                            }
+                            cx.stop_propagation();
+                        }),
+                    ),
+            )
+    }
+
+    fn render_content_area(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        let theme = theme_colors();
+        let is_terminal_open = self.is_terminal_open;
+
+        div()
+            .flex_1()
+            .flex()
+            .flex_col()
+            .bg(theme.bg_void)
+            .min_w_0()
+            .child(self.render_toolbar(cx))
+            .when(self.flatten_session.is_some(), |this| {
+                this.child(self.render_flatten_bar(cx))
+            })
+            .when(self.git_repo.is_some() && self.flatten_session.is_none(), |this| {
+                this.child(self.render_git_bar(cx))
+            })
+            .child(self.render_file_view(cx))
+            .when(is_terminal_open, |this| {
+                this.child(self.render_terminal_section(cx))
+            })
+    }
+
+    fn render_toolbar(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        let theme = theme_colors();
+
+        div()
+            .h(px(crate::models::scaled(crate::models::toolbar::HEIGHT)))
+            .bg(theme.bg_secondary)
+            .border_b_1()
+            .border_color(theme.border_default)
+            .flex()
+            .items_center()
+            .justify_between()
+            .px(px(crate::models::scaled(crate::models::toolbar::PADDING_X)))
+            .on_mouse_down(
+                MouseButton::Right,
+                cx.listener(|view, _event, _window, cx| {
+                    view.toggle_toolbar_customizer(cx);
+                }),
+            )
+            .child(self.render_toolbar_left(cx))
+            .child(self.render_toolbar_right(cx))
+            .when(self.toolbar_customizer_open, |this| {
+                this.child(self.render_toolbar_customizer(cx))
+            })
+    }
+
+    fn render_toolbar_left(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        let theme = theme_colors();
+        let leading = self.toolbar_layout.leading();
+
+        div()
+            .flex()
+            .items_center()
+            .gap(px(crate::models::scaled(crate::models::toolbar::BUTTON_GAP)))
+            .children(leading.iter().map(|&button| self.render_toolbar_button(button, cx)))
+            .when(!leading.is_empty(), |this| {
+                this.child(
+                    div()
+                        .h(px(20.0))
+                        .w(px(1.0))
+                        .bg(theme.border_subtle)
+                        .mx(px(crate::models::scaled(crate::models::toolbar::BUTTON_GAP))),
+                )
+            })
+            .child(self.render_breadcrumbs(cx))
+    }
+
+    fn render_toolbar_button(&self, button: ToolbarButton, cx: &mut Context<Self>) -> AnyElement {
+        match button {
+            ToolbarButton::Back => self
+                .render_history_button(HistoryMenu::Back, self.path_history.len() > 1, cx)
+                .into_any_element(),
+            ToolbarButton::Forward => self
+                .render_history_button(HistoryMenu::Forward, !self.forward_history.is_empty(), cx)
+                .into_any_element(),
+            ToolbarButton::Terminal => self
+                .render_terminal_button(self.is_terminal_open, cx)
+                .into_any_element(),
+            ToolbarButton::Copy => self
+                .render_action_button(button, IconControl::Copy, false, cx)
+                .into_any_element(),
+            ToolbarButton::Trash => self
+                .render_action_button(button, IconControl::Trash, false, cx)
+                .into_any_element(),
+            ToolbarButton::Flatten => self.render_flatten_button(cx).into_any_element(),
+            ToolbarButton::ViewToggle => self
+                .render_view_mode_toggle(matches!(self.view_mode, ViewMode::Grid), cx)
+                .into_any_element(),
+            ToolbarButton::GoToParent => self
+                .render_action_button(button, IconControl::GoToParent, false, cx)
+                .into_any_element(),
+            ToolbarButton::NewFolder => self
+                .render_action_button(button, IconControl::NewFolder, false, cx)
+                .into_any_element(),
+            ToolbarButton::Refresh => self
+                .render_action_button(button, IconControl::Refresh, false, cx)
+                .into_any_element(),
+            ToolbarButton::ToggleHidden => {
+                let shown = self.sidebar.read(cx).show_hidden_files();
+                self.render_action_button(button, IconControl::ToggleHidden { shown }, shown, cx)
+                    .into_any_element()
+            }
+            ToolbarButton::PathBar => self.render_breadcrumbs(cx).into_any_element(),
+        }
+    }
+
+    fn render_action_button(
+        &self,
+        button: ToolbarButton,
+        control: IconControl,
+        active: bool,
+        cx: &mut Context<Self>,
+    ) -> impl IntoElement {
+        let theme = theme_colors();
+        let icon = match control {
+            IconControl::ToggleHidden { shown: true } => "eye-off",
+            _ => button.icon(),
+        };
+
+        div()
+            .id(SharedString::from(format!("toolbar-{:?}", button)))
+            .icon_control(control)
+            .size(px(crate::models::scaled(crate::models::toolbar::BUTTON_SIZE)))
+            .flex()
+            .items_center()
+            .justify_center()
+            .rounded_md()
+            .cursor_pointer()
+            .when(active, |s| s.bg(theme.bg_selected))
+            .when(!active, |s| s.hover(|h| h.bg(theme.bg_hover)))
+            .on_mouse_down(
+                MouseButton::Left,
+                cx.listener(move |view, _event, _window, cx| {
+                    view.run_toolbar_button(button, cx);
+                }),
+            )
+            .child(
+                svg()
+                    .path(SharedString::from(format!("assets/icons/{}.svg", icon)))
+                    .size(px(16.0))
+                    .text_color(if active { theme.accent_primary } else { theme.text_muted }),
+            )
+    }
+
+    fn run_toolbar_button(&mut self, button: ToolbarButton, cx: &mut Context<Self>) {
+        match button {
+            ToolbarButton::GoToParent => self.navigate_up(cx),
+            ToolbarButton::NewFolder => self.open_dialog(false, cx),
+            ToolbarButton::Refresh => self.refresh_current_directory(cx),
+            ToolbarButton::ToggleHidden => {
+                self.run_palette_command(PaletteCommand::ToggleHiddenFiles, cx)
+            }
+            ToolbarButton::Copy => self.handle_tool_action(ToolAction::Copy, cx),
+            ToolbarButton::Trash => {
+                let paths = self.selected_paths(cx);
+                self.move_selection_to_trash(paths, cx);
+            }
+            ToolbarButton::Back
+            | ToolbarButton::Forward
+            | ToolbarButton::PathBar
+            | ToolbarButton::Terminal
+            | ToolbarButton::Flatten
+            | ToolbarButton::ViewToggle => {}
+        }
+    }
+
+    fn toggle_toolbar_customizer(&mut self, cx: &mut Context<Self>) {
+        self.toolbar_customizer_open = !self.toolbar_customizer_open;
+        cx.notify();
+    }
+
+    fn update_toolbar_layout(
+        &mut self,
+        cx: &mut Context<Self>,
+        update: impl FnOnce(&mut ToolbarLayout) -> bool,
+    ) {
+        if !update(&mut self.toolbar_layout) {
+            return;
+        }
+        let mut settings = GlobalSettings::load();
+        settings.toolbar_layout = self.toolbar_layout.clone();
+        let _ = settings.save();
+        cx.notify();
+    }
+
+    fn render_toolbar_customizer(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        let theme = theme_colors();
+        let layout = &self.toolbar_layout;
+
+        let icon_button = |id: String, icon: &'static str, enabled: bool| {
+            div()
+                .id(SharedString::from(id))
+                .size(px(22.0))
+                .flex()
+                .items_center()
+                .justify_center()
+                .rounded_md()
+                .when(enabled, |s| s.cursor_pointer().hover(|h| h.bg(theme.bg_hover)))
+                .when(!enabled, |s| s.opacity(0.3))
+                .child(
+                    svg()
+                        .path(SharedString::from(format!("assets/icons/{}.svg", icon)))
+                        .size(px(12.0))
+                        .text_color(theme.text_muted),
+                )
+        };
+        let row = |button: ToolbarButton| {
+            div()
+                .flex()
+                .items_center()
+                .gap_2()
+                .px_2()
+                .py_1()
+                .rounded_md()
+                .child(
+                    svg()
+                        .path(SharedString::from(format!("assets/icons/{}.svg", button.icon())))
+                        .size(px(14.0))
+                        .flex_shrink_0()
+                        .text_color(theme.text_muted),
+                )
+                .child(div().flex_1().text_color(theme.text_primary).child(button.label()))
+        };
+
+        let last = layout.buttons().len().saturating_sub(1);
+        let shown: Vec<_> = layout.buttons().iter().enumerate().map(|(index, &button)| {
+            row(button)
+                .child(
+                    icon_button(format!("toolbar-left-{:?}", button), "chevron-left", index > 0)
+                        .on_mouse_down(
+                            MouseButton::Left,
+                            cx.listener(move |view, _event, _window, cx| {
+                                view.update_toolbar_layout(cx, |layout| layout.move_by(button, -1));
+                            }),
+                        ),
+                )
+                .child(
+                    icon_button(format!("toolbar-right-{:?}", button), "chevron-right", index < last)
+                        .on_mouse_down(
+                            MouseButton::Left,
+                            cx.listener(move |view, _event, _window, cx| {
+                                view.update_toolbar_layout(cx, |layout| layout.move_by(button, 1));
+                            }),
+                        ),
+                )
+                .child(
+                    icon_button(format!("toolbar-remove-{:?}", button), "x", button.is_removable())
+                        .on_mouse_down(
+                            MouseButton::Left,
+                            cx.listener(move |view, _event, _window, cx| {
+                                view.update_toolbar_layout(cx, |layout| layout.remove(button));
+                            }),
+                        ),
+                )
+        }).collect();
+        let available: Vec<_> = layout.available().into_iter().map(|button| {
+            row(button).child(
+                icon_button(format!("toolbar-add-{:?}", button), "plus", true).on_mouse_down(
+                    MouseButton::Left,
+                    cx.listener(move |view, _event, _window, cx| {
+                        view.update_toolbar_layout(cx, |layout| layout.add(button));
+                    }),
+                ),
+            )
+        }).collect();
+        let has_available = !available.is_empty();
+        let is_default = layout.is_default();
+
+        deferred(
+            anchored()
+                .snap_to_window_with_margin(px(8.0))
+                .anchor(Corner::TopRight)
+                .child(
+                    div()
+                        .id("toolbar-customizer")
+                        .occlude()
+                        .mt(px(crate::models::scaled(crate::models::toolbar::HEIGHT)))
+                        .w(px(280.0))
+                        .p_2()
+                        .flex()
+                        .flex_col()
+                        .gap_1()
+                        .bg(theme.bg_tertiary)
+                        .border_1()
+                        .border_color(theme.border_default)
+                        .rounded_md()
+                        .shadow_lg()
+                        .text_xs()
+                        .on_mouse_down_out(cx.listener(|view, _, _, cx| {
+                            view.toolbar_customizer_open = false;
+                            cx.notify();
+                        }))
+                        .child(
+                            div()
+                                .px_2()
+                                .py_1()
+                                .font_weight(gpui::FontWeight::SEMIBOLD)
+                                .text_color(theme.text_secondary)
+                                .child("TOOLBAR"),
+                        )
+                        .children(shown)
+                        .when(has_available, |this| {
+                            this.child(
+                                div()
+                                    .px_2()
+                                    .pt_2()
+                                    .pb_1()
+                                    .font_weight(gpui::FontWeight::SEMIBOLD)
+                                    .text_color(theme.text_secondary)
+                                    .child("AVAILABLE"),
+                            )
+                            .children(available)
+                        })
+                        .child(
+                            div()
+                                .mt_1()
+                                .pt_2()
+                                .border_t_1()
+                                .border_color(theme.border_subtle)
+                                .flex()
+                                .justify_between()
+                                .child(
+                                    div()
+                                        .id("toolbar-reset")
+                                        .px_2()
+                                        .py_1()
+                                        .rounded_md()
+                                        .text_color(theme.text_primary)
+                                        .when(is_default, |s| s.opacity(0.4))
+                                        .when(!is_default, |s| {
+                                            s.cursor_pointer().hover(|h| h.bg(theme.bg_hover))
+                                        })
+                                        .on_mouse_down(
+                                            MouseButton::Left,
+                                            cx.listener(|view, _event, _window, cx| {
+                                                view.update_toolbar_layout(cx, |layout| {
+                                                    let changed = !layout.is_default();
+                                                    layout.reset();
+                                                    changed
+                                                });
+                                            }),
+                                        )
+                                        .child("Reset to Default"),
+                                )
+                                .child(
+                                    div()
+                                        .id("toolbar-done")
+                                        .px_2()
+                                        .py_1()
+                                        .rounded_md()
+                                        .cursor_pointer()
+                                        .text_color(theme.accent_primary)
+                                        .hover(|h| h.bg(theme.bg_hover))
+                                        .on_mouse_down(
+                                            MouseButton::Left,
+                                            cx.listener(|view, _event, _window, cx| {
+                                                view.toolbar_customizer_open = false;
+                                                cx.notify();
+                                            }),
+                                        )
+                                        .child("Done"),
+                                ),
+                        ),
+                ),
+        )
+        .with_priority(1)
+    }
+
+    fn render_history_button(
+        &self,
+        kind: HistoryMenu,
+        enabled: bool,
+        cx: &mut Context<Self>,
+    ) -> impl IntoElement {
+        let theme = theme_colors();
+        let (id, control, icon) = match kind {
+            HistoryMenu::Back => ("back-btn", IconControl::Back, "assets/icons/arrow-left.svg"),
+            HistoryMenu::Forward => (
+                "forward-btn",
+                IconControl::Forward,
+                "assets/icons/arrow-right.svg",
+            ),
+        };
+        let menu_open = self.history_menu == Some(kind);
+
+        div()
+            .relative()
+            .child(
+                div()
+                    .id(id)
+                    .icon_control(control)
+                    .size(px(crate::models::scaled(crate::models::toolbar::BUTTON_SIZE)))
+                    .flex()
+                    .items_center()
+                    .justify_center()
+                    .rounded_md()
+                    .cursor_pointer()
+                    .when(menu_open, |s| s.bg(theme.bg_selected))
+                    .when(enabled, |s| s.hover(|h| h.bg(theme.bg_hover)))
+                    .when(!enabled, |s| s.opacity(0.3))
+                    .on_mouse_down(
+                        MouseButton::Left,
+                        cx.listener(move |view, _event, _window, cx| match kind {
+                            HistoryMenu::Back => view.navigate_back(cx),
+                            HistoryMenu::Forward => view.navigate_forward(cx),
+                        }),
+                    )
+                    .on_mouse_down(
+                        MouseButton::Right,
+                        cx.listener(move |view, _event, _window, cx| {
+                            view.toggle_history_menu(kind, cx);
+                            cx.stop_propagation();
+                        }),
+                    )
+                    .child(svg().path(icon).size(px(18.0)).text_color(theme.text_muted)),
+            )
+            .when(menu_open, |this| this.child(self.render_history_menu(kind, cx)))
+    }
+
+    fn toggle_history_menu(&mut self, kind: HistoryMenu, cx: &mut Context<Self>) {
+        let has_entries = match kind {
+            HistoryMenu::Back => self.path_history.len() > 1,
+            HistoryMenu::Forward => !self.forward_history.is_empty(),
+        };
+        self.history_menu = if has_entries && self.history_menu != Some(kind) {
+            Some(kind)
+        } else {
+            None
+        };
+        cx.notify();
+    }
+
+    fn render_history_menu(&self, kind: HistoryMenu, cx: &mut Context<Self>) -> impl IntoElement {
+        let theme = theme_colors();
+        let entries: Vec<HistoryMenuEntry> = match kind {
+            HistoryMenu::Back => back_menu_entries(&self.path_history, HISTORY_MENU_LIMIT),
+            HistoryMenu::Forward => forward_menu_entries(&self.forward_history, HISTORY_MENU_LIMIT),
+        };
+
+        let rows = entries.into_iter().map(|entry| {
+            let steps = entry.steps;
+            div()
+                .id(SharedString::from(format!("history-{:?}-{}", kind, steps)))
+                .px_2()
+                .py_1()
+                .rounded_md()
+                .cursor_pointer()
+                .flex()
+                .items_center()
+                .gap_2()
+                .hover(|h| h.bg(theme.bg_hover))
+                .on_mouse_down(
+                    MouseButton::Left,
+                    cx.listener(move |view, _event, _window, cx| match kind {
+                        HistoryMenu::Back => view.navigate_back_steps(steps, cx),
+                        HistoryMenu::Forward => view.navigate_forward_steps(steps, cx),
+                    }),
+                )
+                .child(
+                    svg()
+                        .path("assets/icons/folder.svg")
+                        .size(px(14.0))
+                        .flex_shrink_0()
+                        .text_color(theme.text_muted),
+                )
+                .child(
+                    div()
+                        .flex()
+                        .flex_col()
+                        .min_w_0()
+                        .child(
+                            div()
+                                .text_color(theme.text_primary)
+                                .truncate()
+                                .child(entry.label()),
+                        )
+                        .child(
+                            div()
+                                .text_color(theme.text_muted)
+                                .truncate()
+                                .child(entry.path.display().to_string()),
+                        ),
+                )
+        });
+
+        deferred(
+            anchored()
+                .snap_to_window_with_margin(px(8.0))
+                .anchor(Corner::TopLeft)
+                .child(
+                    div()
+                        .id("history-menu")
+                        .occlude()
+                        .mt(px(crate::models::scaled(crate::models::toolbar::BUTTON_SIZE) + 4.0))
+                        .min_w(px(200.0))
+                        .max_w(px(360.0))
+                        .p_1()
+                        .flex()
+                        .flex_col()
+                        .bg(theme.bg_tertiary)
+                        .border_1()
+                        .border_color(theme.border_default)
+                        .rounded_md()
+                        .shadow_lg()
+                        .text_xs()
+                        .on_mouse_down_out(cx.listener(|view, _, _, cx| {
+                            view.history_menu = None;
+                            cx.notify();
+                        }))
+                        .children(rows),
+                ),
+        )
+        .with_priority(1)
+    }
+
+    fn render_toolbar_right(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        let is_trash = crate::models::is_trash_path(&self.current_path);
+
+        div()
+            .flex()
+            .items_center()
+            .gap(px(crate::models::scaled(crate::models::toolbar::BUTTON_GAP)))
+            .when(is_trash, |this| this.child(self.render_empty_trash_button(cx)))
+            .children(
+                self.toolbar_layout
+                    .trailing()
+                    .iter()
+                    .map(|&button| self.render_toolbar_button(button, cx)),
+            )
+    }
+
+    fn render_terminal_button(&self, is_terminal_open: bool, cx: &mut Context<Self>) -> impl IntoElement {
+        let theme = theme_colors();
+
+        div()
+            .id("terminal-btn")
+            .icon_control(IconControl::Terminal {
+                open: is_terminal_open,
+            })
+            .size(px(crate::models::scaled(crate::models::toolbar::BUTTON_SIZE)))
+            .flex()
+            .items_center()
+            .justify_center()
+            .rounded_md()
+            .cursor_pointer()
+            .when(is_terminal_open, |s| s.bg(theme.bg_selected))
+            .when(!is_terminal_open, |s| s.hover(|h| h.bg(theme.bg_hover)))
+            .on_mouse_down(
+                MouseButton::Left,
+                cx.listener(|view, _event, _window, cx| {
+                    view.toggle_terminal(cx);
+                }),
+            )
+            .child(
+                svg()
+                    .path("assets/icons/terminal.svg")
+                    .size(px(18.0))
+                    .text_color(if is_terminal_open { theme.accent_primary } else { theme.text_muted }),
+            )
+    }
+
+    fn render_flatten_button(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        let theme = theme_colors();
+        let is_flattened = self.flatten_session.is_some();
+
+        div()
+            .id("flatten-btn")
+            .icon_control(IconControl::Flatten {
+                active: is_flattened,
+            })
+            .size(px(crate::models::scaled(crate::models::toolbar::BUTTON_SIZE)))
+            .flex()
+            .items_center()
+            .justify_center()
+            .rounded_md()
+            .cursor_pointer()
+            .when(is_flattened, |s| s.bg(theme.bg_selected))
+            .when(!is_flattened, |s| s.hover(|h| h.bg(theme.bg_hover)))
+            .on_mouse_down(
+                MouseButton::Left,
+                cx.listener(|view, _event, _window, cx| {
+                    if view.flatten_session.is_some() {
+                        view.exit_flatten_view(cx);
+                    } else {
+                        view.start_flatten_view(cx);
+                    }
+                }),
+            )
+            .child(
+                svg()
+                    .path("assets/icons/files.svg")
+                    .size(px(16.0))
+                    .text_color(if is_flattened { theme.accent_primary } else { theme.text_muted }),
+            )
+    }
+
+    fn render_empty_trash_button(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        div()
+            .id("empty-trash-btn")
+            .px_3()
+            .py(px(6.0))
+            .bg(gpui::rgb(0xda3633))
+            .text_color(gpui::rgb(0xffffff))
+            .rounded_md()
+            .text_xs()
+            .font_weight(gpui::FontWeight::SEMIBOLD)
+            .cursor_pointer()
+            .hover(|h| h.bg(gpui::rgb(0xb62324)))
+            .on_mouse_down(
+                MouseButton::Left,
+                cx.listener(|view, _, _, cx| {
+                    view.empty_trash(cx);
+                }),
+            )
+            .child("Empty Trash")
+    }
+
+    fn render_view_mode_toggle(&self, is_grid: bool, cx: &mut Context<Self>) -> impl IntoElement {
+        let theme = theme_colors();
+
+        div()
+            .flex()
+            .bg(theme.bg_tertiary)
+            .rounded_lg()
+            .p_0p5()
+            .child(
+                div()
+                    .id("grid-view-btn")
+                    .icon_control(IconControl::GridView { active: is_grid })
+                    .p_1()
+                    .rounded_md()
+                    .cursor_pointer()
+                    .when(is_grid, |s| s.bg(theme.bg_hover))
+                    .on_mouse_down(
+                        MouseButton::Left,
+                        cx.listener(|view, _event, _window, cx| {
+                            view.set_view_mode(ViewMode::Grid, cx);
+                        }),
+                    )
+                    .child(
+                        svg()
+                            .path("assets/icons/grid-2x2.svg")
+                            .size(px(14.0))
+                            .text_color(if is_grid { theme.text_primary } else { theme.text_muted }),
+                    ),
+            )
+            .child(
+                div()
+                    .id("list-view-btn")
+                    .icon_control(IconControl::ListView { active: !is_grid })
+                    .p_1()
+                    .rounded_md()
+                    .cursor_pointer()
+                    .when(!is_grid, |s| s.bg(theme.bg_hover))
+                    .on_mouse_down(
+                        MouseButton::Left,
+                        cx.listener(|view, _event, _window, cx| {
+                            view.set_view_mode(ViewMode::List, cx);
+                        }),
+                    )
+                    .child(
+                        svg()
+                            .path("assets/icons/list.svg")
+                            .size(px(14.0))
+                            .text_color(if !is_grid { theme.text_primary } else { theme.text_muted }),
+                    ),
+            )
+    }
+
+    fn render_file_view(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        let theme = theme_colors();
+        let is_dual = self.dual_pane.read(cx).is_enabled();
+        let is_grouped = !is_dual
+            && self.flatten_session.is_some()
+            && self.result_grouping == ResultGrouping::ByDirectory;
+        let is_grid = !is_dual && !is_grouped && matches!(self.view_mode, ViewMode::Grid);
+        let is_list = !is_dual && !is_grouped && !is_grid;
+
+        div()
+            .flex_1()
+            .bg(theme.bg_void)
+            .overflow_hidden()
+            .min_h(px(100.0))
+            .relative()
+            .when(self.copy_move_mode, |d| d.opacity(0.5))
+            .when(is_grouped, |this| this.child(self.render_grouped_results(cx)))
+            .when(is_grid, |this| this.child(self.grid_view.clone()))
+            .when(is_list, |this| this.child(self.file_list.clone()))
+            .when(is_dual, |this| this.child(self.dual_pane.clone()))
+            .children(self.render_region_outline(FocusRegion::FileView))
+    }
+
+    fn render_grouped_results(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        let row_count = self.grouped_results.rows().len();
+
+        uniform_list(
+            "grouped-results",
+            row_count,
+            cx.processor(|workspace: &mut Workspace, range: std::ops::Range<usize>, _window, cx| {
+                let theme = theme_colors();
+                let root = workspace
+                    .flatten_session
+                    .as_ref()
+                    .map(|session| session.root().to_path_buf())
+                    .unwrap_or_else(|| workspace.current_path.clone());
+                let mut items = Vec::new();
+
+                for ix in range {
+                    let Some(row) = workspace.grouped_results.rows().get(ix).cloned() else {
+                        continue;
+                    };
+                    let item = match row {
+                        GroupedRow::Header {
+                            directory,
+                            count,
+                            collapsed,
+                        } => {
+                            let label = match crate::models::flattened_name(&root, &directory) {
+                                name if name.is_empty() => ".".to_string(),
+                                name => name,
+                            };
+                            let toggle_dir = directory.clone();
+
+                            div()
+                                .id(SharedString::from(format!("result-group-{}", ix)))
+                                .h(px(28.0))
+                                .w_full()
+                                .flex()
+                                .items_center()
+                                .gap_2()
+                                .px_3()
+                                .bg(theme.bg_secondary)
+                                .border_b_1()
+                                .border_color(theme.border_subtle)
+                                .text_xs()
+                                .child(
+                                    div()
+                                        .id(SharedString::from(format!("result-group-toggle-{}", ix)))
+                                        .cursor_pointer()
+                                        .rounded_sm()
+                                        .hover(|h| h.bg(theme.bg_hover))
+                                        .on_mouse_down(
+                                            MouseButton::Left,
+                                            cx.listener(move |view, _event, _window, cx| {
+                                                view.toggle_result_group(&toggle_dir, cx);
+                                            }),
+                                        )
+                                        .child(
+                                            svg()
+                                                .path(if collapsed {
+                                                    "assets/icons/chevron-right.svg"
+                                                } else {
+                                                    "assets/icons/chevron-down.svg"
+                                                })
+                                                .size(px(12.0))
+                                                .text_color(theme.text_muted),
+                                        ),
+                                )
+                                .child(
+                                    div()
+                                        .id(SharedString::from(format!("result-group-open-{}", ix)))
+                                        .flex()
+                                        .items_center()
+                                        .gap_2()
+                                        .min_w_0()
+                                        .cursor_pointer()
+                                        .text_color(theme.text_primary)
+                                        .hover(|h| h.text_color(theme.accent_primary))
+                                        .on_mouse_down(
+                                            MouseButton::Left,
+                                            cx.listener(move |view, _event, _window, cx| {
+                                                view.navigate_to(directory.clone(), cx);
+                                            }),
+                                        )
+                                        .child(
+                                            svg()
+                                                .path("assets/icons/folder.svg")
+                                                .size(px(12.0))
+                                                .text_color(theme.folder_color),
+                                        )
+                                        .child(div().truncate().child(label)),
+                                )
+                                .child(
+                                    div()
+                                        .text_color(theme.text_muted)
+                                        .child(format!("({})", count)),
+                                )
+                        }
+                        GroupedRow::Entry(entry) => {
+                            let name = entry
+                                .path
+                                .file_name()
+                                .map(|n| n.to_string_lossy().to_string())
+                                .unwrap_or_else(|| entry.name.clone());
+                            let icon = crate::views::get_file_icon(&name, entry.is_dir);
+                            let icon_color = crate::views::get_file_icon_color(&name);
+                            let size = crate::views::format_size(entry.size, entry.is_dir);
+                            let date = crate::views::format_date(entry.modified);
+                            let path = entry.path.clone();
+
+                            div()
+                                .id(SharedString::from(format!("result-entry-{}", ix)))
+                                .h(px(28.0))
+                                .w_full()
+                                .flex()
+                                .items_center()
+                                .gap_2()
+                                .pl(px(32.0))
+                                .pr_3()
+                                .text_sm()
+                                .cursor_pointer()
+                                .border_b_1()
+                                .border_color(theme.border_subtle)
+                                .hover(|h| h.bg(theme.bg_hover))
+                                .on_click(cx.listener(move |view, event: &gpui::ClickEvent, _window, cx| {
+                                    if event.click_count() == 2 {
+                                        view.open_grouped_result(path.clone(), cx);
+                                    }
+                                }))
+                                .child(
+                                    svg()
+                                        .path(SharedString::from(format!("assets/icons/{}.svg", icon)))
+                                        .size(px(14.0))
+                                        .text_color(icon_color),
+                                )
+                                .child(
+                                    div()
+                                        .flex_1()
+                                        .min_w_0()
+                                        .truncate()
+                                        .text_color(theme.text_primary)
+                                        .child(name),
+                                )
+                                .child(
+                                    div()
+                                        .w(px(80.0))
+                                        .text_xs()
+                                        .text_color(theme.text_muted)
+                                        .child(size),
+                                )
+                                .child(
+                                    div()
+                                        .w(px(140.0))
+                                        .text_xs()
+                                        .text_color(theme.text_muted)
+                                        .child(date),
+                                )
+                        }
+                    };
+                    items.push(item);
+                }
+                items
+            }),
+        )
+        .size_full()
+    }
+
+    fn render_terminal_section(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        let theme = theme_colors();
+        let terminal_height = self.terminal_height;
+        let active_terminal = self.active_terminal(cx);
+
+        div()
+            .flex()
+            .flex_col()
+            .child(
+                div()
+                    .id("terminal-resize-handle")
+                    .w_full()
+                    .h(px(6.0))
+                    .cursor_row_resize()
+                    .flex()
+                    .items_center()
+                    .justify_center()
+                    .bg(theme.bg_secondary)
+                    .border_t_1()
+                    .border_color(theme.border_default)
+                    .hover(|h| h.bg(theme.bg_hover))
+                    .on_mouse_down(
+                        MouseButton::Left,
+                        cx.listener(|view, _, _, cx| {
+                            view.is_resizing_terminal = true;
+                            cx.notify();
+                        }),
+                    )
+                    .child(
+                        div()
+                            .w(px(40.0))
+                            .h(px(3.0))
+                            .rounded_full()
+                            .bg(theme.border_default),
+                    ),
+            )
+            .when_some(active_terminal, |this, terminal| {
                 this.child(
                     div()
                         .h(px(terminal_height))
                         .min_h(px(150.0))
                         .max_h(px(600.0))
-                        .child(terminal),
+                        .relative()
+                        .child(terminal)
+                        .children(self.render_region_outline(FocusRegion::Terminal)),
                 )
             })
     }
 
-    fn render_preview_pane(&self, preview: Entity<PreviewView>, cx: &mut Context<Self>) -> impl IntoElement {
-        let theme = theme_colors();
-        let preview_width = self.preview_width;
+    fn render_preview_pane(&self, preview: Entity<PreviewView>, cx: &mut Context<Self>) -> impl IntoElement {
+        let theme = theme_colors();
+        let preview_width = self.preview_width;
+
+        div()
+            .flex()
+            .h_full()
+            .child(
+                div()
+                    .id("preview-resize-handle")
+                    .w(px(6.0))
+                    .h_full()
+                    .cursor_col_resize()
+                    .flex()
+                    .items_center()
+                    .justify_center()
+                    .bg(theme.bg_secondary)
+                    .border_l_1()
+                    .border_color(theme.border_default)
+                    .hover(|h| h.bg(theme.bg_hover))
+                    .on_mouse_down(
+                        MouseButton::Left,
+                        cx.listener(|view, _, _, cx| {
+                            view.is_resizing_preview = true;
+                            cx.notify();
+                        }),
+                    )
+                    .child(
+                        div()
+                            .w(px(3.0))
+                            .h(px(40.0))
+                            .rounded_full()
+                            .bg(theme.border_default),
+                    ),
+            )
+            .child(
+                div()
+                    .w(px(preview_width))
+                    .min_w(px(200.0))
+                    .max_w(px(600.0))
+                    .h_full()
+                    .bg(theme.bg_secondary)
+                    .relative()
+                    .flex()
+                    .flex_col()
+                    .child(preview)
+                    .children(self.render_region_outline(FocusRegion::Preview)),
+            )
+    }
+}
+
+impl Workspace {
+    fn render_destination_pane(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        let theme = theme_colors();
+        let bg_dark = theme.bg_secondary;
+        let bg_darker = theme.bg_primary;
+        let border_color = theme.border_default;
+        let text_primary = theme.text_primary;
+        let text_muted = theme.text_muted;
+        let hover_bg = theme.bg_hover;
+        let folder_color = theme.accent_primary;
+
+        div()
+            .flex()
+            .flex_1()
+            .h_full()
+            .border_l_1()
+            .border_color(border_color)
+            .child(
+                div()
+                    .flex_1()
+                    .h_full()
+                    .bg(bg_darker)
+                    .flex()
+                    .flex_col()
+                    .child(
+                        div()
+                            .h(px(crate::models::scaled(crate::models::toolbar::HEIGHT)))
+                            .bg(bg_dark)
+                            .border_b_1()
+                            .border_color(border_color)
+                            .flex()
+                            .items_center()
+                            .justify_between()
+                            .px(px(crate::models::scaled(crate::models::toolbar::PADDING_X)))
+                            .child(
+                                div()
+                                    .flex()
+                                    .items_center()
+                                    .gap(px(crate::models::scaled(crate::models::toolbar::BUTTON_GAP)))
+                                    .child(
+                                        div()
+                                            .id("dest-up-btn")
+                                            .size(px(crate::models::scaled(crate::models::toolbar::BUTTON_SIZE)))
+                                            .flex()
+                                            .items_center()
+                                            .justify_center()
+                                            .rounded_md()
+                                            .cursor_pointer()
+                                            .hover(|h| h.bg(hover_bg))
+                                            .on_mouse_down(
+                                                MouseButton::Left,
+                                                cx.listener(|view, _, _, cx| {
+                                                    if let Some(parent) = view.dest_path.parent() {
+                                                        let parent_path = parent.to_path_buf();
+                                                        view.navigate_dest_to(parent_path, cx);
+                                                    }
+                                                }),
+                                            )
+                                            .child(
+                                                svg()
+                                                    .path("assets/icons/arrow-up.svg")
+                                                    .size(px(18.0))
+                                                    .text_color(text_muted),
+                                            ),
+                                    )
+                                    .child(
+                                        div()
+                                            .h(px(20.0))
+                                            .w(px(1.0))
+                                            .bg(theme.border_subtle)
+                                            .mx(px(crate::models::scaled(crate::models::toolbar::BUTTON_GAP))),
+                                    )
+                                    .child(self.render_dest_breadcrumbs(cx)),
+                            )
+                            .child(
+                                div()
+                                    .flex()
+                                    .items_center()
+                                    .gap_2()
+                                    .child(
+                                        div()
+                                            .id("paste-here-btn")
+                                            .px_3()
+                                            .py(px(6.0))
+                                            .bg(theme.accent_primary)
+                                            .text_color(theme.bg_primary)
+                                            .rounded_md()
+                                            .text_xs()
+                                            .font_weight(gpui::FontWeight::SEMIBOLD)
+                                            .cursor_pointer()
+                                            .hover(|h| h.bg(theme.accent_secondary))
+                                            .on_mouse_down(
+                                                MouseButton::Left,
+                                                cx.listener(|view, _, _, cx| {
+                                                    view.paste_to_destination(cx);
+                                                }),
+                                            )
+                                            .child("PASTE HERE"),
+                                    )
+                                    .child(
+                                        div()
+                                            .id("cancel-copy-btn")
+                                            .px_3()
+                                            .py(px(6.0))
+                                            .text_color(text_muted)
+                                            .rounded_md()
+                                            .text_xs()
+                                            .cursor_pointer()
+                                            .hover(|h| {
+                                                h.bg(theme.bg_hover).text_color(text_primary)
+                                            })
+                                            .on_mouse_down(
+                                                MouseButton::Left,
+                                                cx.listener(|view, _, _, cx| {
+                                                    view.cancel_copy_move_mode(cx);
+                                                }),
+                                            )
+                                            .child("CANCEL"),
+                                    ),
+                            ),
+                    )
+                    .child(
+                        div()
+                            .id("dest-file-list")
+                            .flex_1()
+                            .bg(bg_darker)
+                            .overflow_y_scroll()
+                            .children(self.dest_entries.iter().map(|entry| {
+                                let entry_path = entry.path.clone();
+                                let is_dir = entry.is_dir;
+                                let name = entry.name.clone();
+
+                                div()
+                                    .id(SharedString::from(format!("dest-{}", entry.name)))
+                                    .h(px(40.0))
+                                    .w_full()
+                                    .px(px(16.0))
+                                    .flex()
+                                    .items_center()
+                                    .gap(px(12.0))
+                                    .cursor_pointer()
+                                    .border_b_1()
+                                    .border_color(theme.border_subtle)
+                                    .hover(|h| h.bg(hover_bg))
+                                    .when(is_dir, |d| {
+                                        d.on_mouse_down(
+                                            MouseButton::Left,
+                                            cx.listener(move |view, _, _, cx| {
+                                                view.navigate_dest_to(entry_path.clone(), cx);
+                                            }),
+                                        )
+                                    })
+                                    .child(
+                                        svg()
+                                            .path(if is_dir {
+                                                "assets/icons/folder.svg"
+                                            } else {
+                                                "assets/icons/file.svg"
+                                            })
+                                            .size(px(20.0))
+                                            .text_color(if is_dir {
+                                                folder_color
+                                            } else {
+                                                text_muted
+                                            }),
+                                    )
+                                    .child(div().text_sm().text_color(text_primary).child(name))
+                            })),
+                    ),
+            )
+    }
+
+    fn render_dest_breadcrumbs(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        let theme = theme_colors();
+        let text_muted = theme.text_muted;
+        let text_primary = theme.text_primary;
+
+        let mut parts: Vec<(String, PathBuf)> = Vec::new();
+        let mut current = Some(self.dest_path.as_path());
+
+        while let Some(p) = current {
+            let name = if p.parent().is_none() {
+                "/".to_string()
+            } else {
+                p.file_name()
+                    .and_then(|n| n.to_str())
+                    .unwrap_or("")
+                    .to_string()
+            };
+            if !name.is_empty() {
+                parts.push((name, p.to_path_buf()));
+            }
+            current = p.parent();
+        }
+        parts.reverse();
+
+        div()
+            .flex()
+            .items_center()
+            .gap_1()
+            .overflow_x_hidden()
+            .children(parts.into_iter().enumerate().map(|(i, (name, path))| {
+                let is_last = i == 0;
+                div()
+                    .flex()
+                    .items_center()
+                    .when(i > 0, |d| {
+                        d.child(
+                            svg()
+                                .path("assets/icons/chevron-right.svg")
+                                .size(px(12.0))
+                                .text_color(text_muted)
+                                .mx_1(),
+                        )
+                    })
+                    .child(
+                        div()
+                            .id(SharedString::from(format!("dest-crumb-{}", i)))
+                            .px(px(crate::models::scaled(crate::models::toolbar::BREADCRUMB_PADDING)))
+                            .py_0p5()
+                            .rounded_sm()
+                            .cursor_pointer()
+                            .text_sm()
+                            .text_color(text_muted)
+                            .hover(|h| h.bg(theme.bg_hover).text_color(text_primary))
+                            .on_mouse_down(MouseButton::Left, {
+                                let nav_path = path.clone();
+                                cx.listener(move |view, _, _, cx| {
+                                    view.navigate_dest_to(nav_path.clone(), cx);
+                                })
+                            })
+                            .child(name),
+                    )
+            }))
+    }
+}
+
+impl Workspace {
+    fn render_compression_level(
+        format: ArchiveFormat,
+        level: u8,
+        text_muted: gpui::Rgba,
+        accent: gpui::Rgba,
+        border_color: gpui::Rgba,
+        cx: &mut Context<Self>,
+    ) -> impl IntoElement {
+        let Some(levels) = format.compression_levels() else {
+            return div()
+                .mt_3()
+                .text_xs()
+                .text_color(text_muted)
+                .child(format!("Compression level is not adjustable for {}", format.extension()));
+        };
+
+        div()
+            .mt_3()
+            .flex()
+            .flex_col()
+            .gap_1()
+            .text_xs()
+            .text_color(text_muted)
+            .child(
+                div()
+                    .flex()
+                    .justify_between()
+                    .child("Compression level")
+                    .child(match level {
+                        0 => "0 (store only)".to_string(),
+                        l if l == *levels.end() => format!("{} (smallest)", l),
+                        l => l.to_string(),
+                    }),
+            )
+            .child(
+                div()
+                    .id("compression-level-slider")
+                    .flex()
+                    .gap_0p5()
+                    .h(px(10.0))
+                    .children(levels.map(|step| {
+                        div()
+                            .id(("compression-level", step as usize))
+                            .flex_1()
+                            .h_full()
+                            .rounded_sm()
+                            .cursor_pointer()
+                            .bg(if step <= level { accent } else { border_color })
+                            .on_mouse_down(
+                                MouseButton::Left,
+                                cx.listener(move |view, _event, _window, cx| {
+                                    view.set_compress_level(step, cx);
+                                }),
+                            )
+                    })),
+            )
+    }
+
+    fn render_dialog_overlay(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        let theme = theme_colors();
+        let overlay_bg = gpui::rgba(0x00000099);
+        let dialog_bg = theme.bg_secondary;
+        let border_color = theme.border_default;
+        let text_primary = theme.text_primary;
+        let text_muted = theme.text_muted;
+        let accent = theme.accent_primary;
+        let hover_bg = theme.bg_hover;
+
+        let (title, placeholder, icon_path, button_text) = match &self.dialog_state {
+            DialogState::NewFile { .. } => (
+                "New File",
+                "Enter file name...",
+                "assets/icons/file-plus.svg",
+                "Create",
+            ),
+            DialogState::NewFolder { .. } => (
+                "New Folder",
+                "Enter folder name...",
+                "assets/icons/folder-plus.svg",
+                "Create",
+            ),
+            DialogState::NewFolderWithSelection { .. } => (
+                "New Folder with Selection",
+                "Enter folder name...",
+                "assets/icons/folder-plus.svg",
+                "Create",
+            ),
+            DialogState::Rename { .. } => (
+                "Rename",
+                "Enter new name...",
+                "assets/icons/pen.svg",
+                "Rename",
+            ),
+            DialogState::Compress { .. } => (
+                "Compress Items",
+                "Enter archive name...",
+                "assets/icons/file-archive.svg",
+                "Compress",
+            ),
+            DialogState::None => ("", "", "", ""),
+        };
+        let compress_selection = match &self.dialog_state {
+            DialogState::Compress { paths, format, level } => Some((paths.len(), *format, *level)),
+            _ => None,
+        };
+
+        let moved_count = match &self.dialog_state {
+            DialogState::NewFolderWithSelection { paths } => Some(paths.len()),
+            _ => None,
+        };
+
+        let is_rename = matches!(self.dialog_state, DialogState::Rename { .. });
+        let is_new_folder = matches!(self.dialog_state, DialogState::NewFolder { .. });
+        let open_created_folder = self.open_created_folder;
+
+        let input_element: Option<Input> = self
+            .dialog_input
+            .as_ref()
+            .map(|input_state| Input::new(input_state).placeholder(placeholder));
 
         div()
+            .id("dialog-overlay")
+            .absolute()
+            .inset_0()
+            .bg(overlay_bg)
             .flex()
-            .h_full()
+            .items_center()
+            .justify_center()
+            .on_mouse_down(
+                MouseButton::Left,
+                cx.listener(|view, _event, _window, cx| {
+                    view.cancel_dialog(cx);
+                }),
+            )
             .child(
                 div()
-                    .id("preview-resize-handle")
-                    .w(px(6.0))
-                    .h_full()
-                    .cursor_col_resize()
-                    .flex()
-                    .items_center()
-                    .justify_center()
-                    .bg(theme.bg_secondary)
-                    .border_l_1()
-                    .border_color(theme.border_default)
-                    .hover(|h| h.bg(theme.bg_hover))
-                    .on_mouse_down(
-                        MouseButton::Left,
-                        cx.listener(|view, _, _, cx| {
-                            view.is_resizing_preview = true;
-                            cx.notify();
-                        }),
+                    .id("dialog-content")
+                    .occlude()
+                    .w(px(400.0))
+                    .bg(dialog_bg)
+                    .rounded_lg()
+                    .border_1()
+                    .border_color(border_color)
+                    .shadow_lg()
+                    .on_mouse_down(MouseButton::Left, |_, _, _| {})
+                    .child(
+                        div()
+                            .p_4()
+                            .border_b_1()
+                            .border_color(border_color)
+                            .flex()
+                            .items_center()
+                            .gap_3()
+                            .child(
+                                svg()
+                                    .path(SharedString::from(icon_path))
+                                    .size(px(20.0))
+                                    .text_color(accent),
+                            )
+                            .child(
+                                div()
+                                    .text_base()
+                                    .font_weight(gpui::FontWeight::SEMIBOLD)
+                                    .text_color(text_primary)
+                                    .child(title),
+                            ),
                     )
                     .child(
                         div()
-                            .w(px(3.0))
-                            .h(px(40.0))
-                            .rounded_full()
-                            .bg(theme.border_default),
+                            .p_4()
+                            .child(div().w_full().children(input_element))
+                            .when_some(compress_selection, |this, (count, selected_format, level)| {
+                                this.child(
+                                    div()
+                                        .mt_3()
+                                        .flex()
+                                        .items_center()
+                                        .gap_2()
+                                        .text_sm()
+                                        .text_color(text_muted)
+                                        .child(format!("{} items as", count))
+                                        .children(COMPRESS_FORMATS.iter().enumerate().map(
+                                            |(idx, &format)| {
+                                                let is_selected = format == selected_format;
+                                                div()
+                                                    .id(("compress-format", idx))
+                                                    .px_2()
+                                                    .py_0p5()
+                                                    .rounded_md()
+                                                    .border_1()
+                                                    .cursor_pointer()
+                                                    .text_xs()
+                                                    .border_color(if is_selected {
+                                                        accent
+                                                    } else {
+                                                        border_color
+                                                    })
+                                                    .text_color(if is_selected {
+                                                        text_primary
+                                                    } else {
+                                                        text_muted
+                                                    })
+                                                    .hover(|h| h.bg(hover_bg))
+                                                    .on_mouse_down(
+                                                        MouseButton::Left,
+                                                        cx.listener(move |view, _event, _window, cx| {
+                                                            view.set_compress_format(format, cx);
+                                                        }),
+                                                    )
+                                                    .child(format.extension())
+                                            },
+                                        )),
+                                )
+                                .child(Self::render_compression_level(
+                                    selected_format,
+                                    level,
+                                    text_muted,
+                                    accent,
+                                    border_color,
+                                    cx,
+                                ))
+                            })
+                            .when_some(moved_count, |this, count| {
+                                this.child(
+                                    div()
+                                        .mt_3()
+                                        .text_sm()
+                                        .text_color(text_muted)
+                                        .child(if count == 1 {
+                                            "1 item will be moved into the new folder".to_string()
+                                        } else {
+                                            format!("{} items will be moved into the new folder", count)
+                                        }),
+                                )
+                            })
+                            .when(is_new_folder, |this| {
+                                this.child(
+                                    div()
+                                        .id("open-created-folder-toggle")
+                                        .mt_3()
+                                        .flex()
+                                        .items_center()
+                                        .gap_2()
+                                        .cursor_pointer()
+                                        .text_sm()
+                                        .text_color(text_primary)
+                                        .on_mouse_down(
+                                            MouseButton::Left,
+                                            cx.listener(|view, _event, _window, cx| {
+                                                view.toggle_open_created_folder(cx);
+                                            }),
+                                        )
+                                        .child(
+                                            div()
+                                                .size(px(14.0))
+                                                .rounded_sm()
+                                                .border_1()
+                                                .border_color(if open_created_folder {
+                                                    accent
+                                                } else {
+                                                    border_color
+                                                })
+                                                .when(open_created_folder, |s| s.bg(accent))
+                                                .flex()
+                                                .items_center()
+                                                .justify_center()
+                                                .when(open_created_folder, |s| {
+                                                    s.child(
+                                                        svg()
+                                                            .path("assets/icons/check.svg")
+                                                            .size(px(10.0))
+                                                            .text_color(theme.text_inverse),
+                                                    )
+                                                }),
+                                        )
+                                        .child("Open folder after creating"),
+                                )
+                            })
+                            .child(div().mt_2().text_xs().text_color(text_muted).child(
+                                if is_rename {
+                                    "Press Enter to rename, Escape to cancel"
+                                } else if compress_selection.is_some() {
+                                    "Press Enter to compress, Escape to cancel"
+                                } else if moved_count.is_some() {
+                                    "Press Enter to create and move, Escape to cancel"
+                                } else if is_new_folder && open_created_folder {
+                                    "Press Enter to create and open, Escape to cancel"
+                                } else {
+                                    "Press Enter to create, Escape to cancel"
+                                },
+                            )),
+                    )
+                    .child(
+                        div()
+                            .p_4()
+                            .border_t_1()
+                            .border_color(border_color)
+                            .flex()
+                            .justify_end()
+                            .gap_2()
+                            .child(
+                                div()
+                                    .id("cancel-btn")
+                                    .px_4()
+                                    .py_2()
+                                    .rounded_md()
+                                    .cursor_pointer()
+                                    .text_sm()
+                                    .text_color(text_muted)
+                                    .hover(|h| h.bg(hover_bg).text_color(text_primary))
+                                    .on_mouse_down(
+                                        MouseButton::Left,
+                                        cx.listener(|view, _event, _window, cx| {
+                                            view.cancel_dialog(cx);
+                                        }),
+                                    )
+                                    .child("Cancel"),
+                            )
+                            .child(
+                                div()
+                                    .id("submit-btn")
+                                    .px_4()
+                                    .py_2()
+                                    .rounded_md()
+                                    .cursor_pointer()
+                                    .text_sm()
+                                    .bg(accent)
+                                    .text_color(theme.text_inverse)
+                                    .hover(|h| h.opacity(0.9))
+                                    .on_mouse_down(
+                                        MouseButton::Left,
+                                        cx.listener(move |view, _event, _window, cx| {
+                                            if is_rename {
+                                                view.submit_rename(cx);
+                                            } else {
+                                                view.submit_dialog(cx);
+                                            }
+                                        }),
+                                    )
+                                    .child(button_text),
+                            ),
                     ),
             )
-            .child(
-                div()
-                    .w(px(preview_width))
-                    .min_w(px(200.0))
-                    .max_w(px(600.0))
-                    .h_full()
-                    .bg(theme.bg_secondary)
-                    .flex()
-                    .flex_col()
-                    .child(preview),
-            )
     }
-}
 
-impl Workspace {
-    fn render_destination_pane(&self, cx: &mut Context<Self>) -> impl IntoElement {
+    fn render_conflict_dialog_overlay(
+        &self,
+        window: &Window,
+        cx: &mut Context<Self>,
+    ) -> impl IntoElement {
         let theme = theme_colors();
-        let bg_dark = theme.bg_secondary;
-        let bg_darker = theme.bg_primary;
-        let border_color = theme.border_default;
-        let text_primary = theme.text_primary;
-        let text_muted = theme.text_muted;
-        let hover_bg = theme.bg_hover;
-        let folder_color = theme.accent_primary;
+        let focus_handles: Vec<FocusHandle> = (0..CONFLICT_FOCUS_COUNT)
+            .map(|index| {
+                self.conflict_focus
+                    .as_ref()
+                    .map(|trap| trap.handle(index).clone())
+                    .unwrap_or_else(|| cx.focus_handle())
+            })
+            .collect();
+        let is_focused = |index: usize| {
+            self.conflict_focus
+                .as_ref()
+                .is_some_and(|trap| trap.is_focused(index, window))
+        };
+        let overlay_bg = gpui::rgba(0x00000099);
+
+        let (source, destination) = self.pending_conflicts.last()
+            .cloned()
+            .unwrap_or_else(|| (PathBuf::new(), PathBuf::new()));
+
+        let conflict_info = ConflictInfo::new(source.clone(), destination.clone());
+        let remaining = self.pending_conflicts.len().saturating_sub(1);
+        let file_name = conflict_info.source_name().to_string();
+        let dest_folder = conflict_info.dest_folder().to_string();
+        let source_size = crate::utils::format_size(conflict_info.source_size);
+        let dest_size = crate::utils::format_size(conflict_info.dest_size);
+        let source_modified = self.format_time(conflict_info.source_modified);
+        let dest_modified = self.format_time(conflict_info.dest_modified);
 
         div()
+            .id("conflict-dialog-overlay")
+            .absolute()
+            .inset_0()
+            .bg(overlay_bg)
             .flex()
-            .flex_1()
-            .h_full()
-            .border_l_1()
-            .border_color(border_color)
+            .items_center()
+            .justify_center()
+            .on_mouse_down(
+                MouseButton::Left,
+                cx.listener(|view, _event, _window, cx| {
+                    view.cancel_conflict_dialog(cx);
+                }),
+            )
             .child(
                 div()
-                    .flex_1()
-                    .h_full()
-                    .bg(bg_darker)
+                    .id("conflict-dialog-content")
+                    .occlude()
+                    .on_key_down(cx.listener(Self::handle_conflict_key_down))
+                    .w(px(450.0))
+                    .bg(theme.bg_secondary)
+                    .rounded_lg()
+                    .border_1()
+                    .border_color(theme.border_default)
+                    .shadow_lg()
+                    .p_4()
+                    .gap_4()
                     .flex()
                     .flex_col()
+                    .on_mouse_down(MouseButton::Left, |_, _, _| {})
                     .child(
                         div()
-                            .h(px(crate::models::toolbar::HEIGHT))
-                            .bg(bg_dark)
-                            .border_b_1()
-                            .border_color(border_color)
                             .flex()
                             .items_center()
-                            .justify_between()
-                            .px(px(crate::models::toolbar::PADDING_X))
+                            .gap_2()
+                            .child(
+                                svg()
+                                    .path("assets/icons/triangle-alert.svg")
+                                    .size(px(20.0))
+                                    .text_color(theme.warning),
+                            )
+                            .child(
+                                div()
+                                    .text_lg()
+                                    .font_weight(gpui::FontWeight::SEMIBOLD)
+                                    .text_color(theme.text_primary)
+                                    .child("File Conflict"),
+                            ),
+                    )
+                    .child(
+                        div()
+                            .text_sm()
+                            .text_color(theme.text_secondary)
+                            .child(format!(
+                                "\"{}\" already exists in \"{}\"",
+                                file_name, dest_folder
+                            )),
+                    )
+                    .child(
+                        div()
+                            .flex()
+                            .gap_4()
                             .child(
                                 div()
+                                    .flex_1()
                                     .flex()
-                                    .items_center()
-                                    .gap(px(crate::models::toolbar::BUTTON_GAP))
+                                    .flex_col()
+                                    .gap_1()
+                                    .p_3()
+                                    .bg(theme.bg_primary)
+                                    .rounded_md()
                                     .child(
                                         div()
-                                            .id("dest-up-btn")
-                                            .size(px(crate::models::toolbar::BUTTON_SIZE))
-                                            .flex()
-                                            .items_center()
-                                            .justify_center()
-                                            .rounded_md()
-                                            .cursor_pointer()
-                                            .hover(|h| h.bg(hover_bg))
-                                            .on_mouse_down(
-                                                MouseButton::Left,
-                                                cx.listener(|view, _, _, cx| {
-                                                    if let Some(parent) = view.dest_path.parent() {
-                                                        let parent_path = parent.to_path_buf();
-                                                        view.navigate_dest_to(parent_path, cx);
-                                                    }
-                                                }),
-                                            )
-                                            .child(
-                                                svg()
-                                                    .path("assets/icons/arrow-up.svg")
-                                                    .size(px(18.0))
-                                                    .text_color(text_muted),
-                                            ),
+                                            .text_xs()
+                                            .font_weight(gpui::FontWeight::MEDIUM)
+                                            .text_color(theme.text_muted)
+                                            .child("Source"),
                                     )
                                     .child(
                                         div()
-                                            .h(px(20.0))
-                                            .w(px(1.0))
-                                            .bg(theme.border_subtle)
-                                            .mx(px(crate::models::toolbar::BUTTON_GAP)),
+                                            .text_sm()
+                                            .text_color(theme.text_secondary)
+                                            .child(format!("Size: {}", source_size)),
                                     )
-                                    .child(self.render_dest_breadcrumbs(cx)),
+                                    .child(
+                                        div()
+                                            .text_sm()
+                                            .text_color(theme.text_secondary)
+                                            .child(format!("Modified: {}", source_modified)),
+                                    ),
                             )
                             .child(
                                 div()
+                                    .flex_1()
                                     .flex()
-                                    .items_center()
-                                    .gap_2()
+                                    .flex_col()
+                                    .gap_1()
+                                    .p_3()
+                                    .bg(theme.bg_primary)
+                                    .rounded_md()
                                     .child(
                                         div()
-                                            .id("paste-here-btn")
-                                            .px_3()
-                                            .py(px(6.0))
-                                            .bg(theme.accent_primary)
-                                            .text_color(theme.bg_primary)
-                                            .rounded_md()
                                             .text_xs()
-                                            .font_weight(gpui::FontWeight::SEMIBOLD)
-                                            .cursor_pointer()
-                                            .hover(|h| h.bg(theme.accent_secondary))
-                                            .on_mouse_down(
-                                                MouseButton::Left,
-                                                cx.listener(|view, _, _, cx| {
-                                                    view.paste_to_destination(cx);
-                                                }),
-                                            )
-                                            .child("PASTE HERE"),
+                                            .font_weight(gpui::FontWeight::MEDIUM)
+                                            .text_color(theme.text_muted)
+                                            .child("Existing"),
                                     )
                                     .child(
                                         div()
-                                            .id("cancel-copy-btn")
-                                            .px_3()
-                                            .py(px(6.0))
-                                            .text_color(text_muted)
-                                            .rounded_md()
-                                            .text_xs()
-                                            .cursor_pointer()
-                                            .hover(|h| {
-                                                h.bg(theme.bg_hover).text_color(text_primary)
-                                            })
-                                            .on_mouse_down(
-                                                MouseButton::Left,
-                                                cx.listener(|view, _, _, cx| {
-                                                    view.cancel_copy_move_mode(cx);
-                                                }),
-                                            )
-                                            .child("CANCEL"),
+                                            .text_sm()
+                                            .text_color(theme.text_secondary)
+                                            .child(format!("Size: {}", dest_size)),
+                                    )
+                                    .child(
+                                        div()
+                                            .text_sm()
+                                            .text_color(theme.text_secondary)
+                                            .child(format!("Modified: {}", dest_modified)),
                                     ),
                             ),
                     )
+                    .when(remaining > 0, |this| {
+                        this.child(
+                            div()
+                                .id("apply-to-all-checkbox")
+                                .flex()
+                                .items_center()
+                                .gap_2()
+                                .cursor_pointer()
+                                .on_mouse_down(MouseButton::Left, cx.listener(|this, _, _window, cx| {
+                                    this.conflict_apply_all_checked = !this.conflict_apply_all_checked;
+                                    cx.notify();
+                                }))
+                                .child(
+                                    div()
+                                        .w(px(16.0))
+                                        .h(px(16.0))
+                                        .rounded_sm()
+                                        .border_1()
+                                        .border_color(theme.border_default)
+                                        .bg(theme.bg_primary)
+                                        .flex()
+                                        .items_center()
+                                        .justify_center()
+                                        .when(self.conflict_apply_all_checked, |this| {
+                                            this.child(
+                                                svg()
+                                                    .path("assets/icons/check.svg")
+                                                    .size(px(12.0))
+                                                    .text_color(theme.accent_primary),
+                                            )
+                                        }),
+                                )
+                                .child(
+                                    div()
+                                        .text_sm()
+                                        .text_color(theme.text_secondary)
+                                        .child(format!(
+                                            "Apply to all {} remaining conflicts",
+                                            remaining
+                                        )),
+                                ),
+                        )
+                    })
                     .child(
                         div()
-                            .id("dest-file-list")
-                            .flex_1()
-                            .bg(bg_darker)
-                            .overflow_y_scroll()
-                            .children(self.dest_entries.iter().map(|entry| {
-                                let entry_path = entry.path.clone();
-                                let is_dir = entry.is_dir;
-                                let name = entry.name.clone();
-
+                            .flex()
+                            .justify_end()
+                            .gap_2()
+                            .child(
                                 div()
-                                    .id(SharedString::from(format!("dest-{}", entry.name)))
-                                    .h(px(40.0))
-                                    .w_full()
-                                    .px(px(16.0))
-                                    .flex()
-                                    .items_center()
-                                    .gap(px(12.0))
+                                    .id("skip-button")
+                                    .track_focus(&focus_handles[CONFLICT_FOCUS_SKIP])
+                                    .px_4()
+                                    .py_2()
+                                    .bg(theme.bg_tertiary)
+                                    .rounded_md()
                                     .cursor_pointer()
-                                    .border_b_1()
-                                    .border_color(theme.border_subtle)
-                                    .hover(|h| h.bg(hover_bg))
-                                    .when(is_dir, |d| {
-                                        d.on_mouse_down(
-                                            MouseButton::Left,
-                                            cx.listener(move |view, _, _, cx| {
-                                                view.navigate_dest_to(entry_path.clone(), cx);
-                                            }),
-                                        )
-                                    })
+                                    .hover(|s| s.bg(theme.bg_hover))
+                                    .on_mouse_down(MouseButton::Left, cx.listener(|this, _, _window, cx| {
+                                        let apply_to_all = this.conflict_apply_all_checked;
+                                        this.resolve_conflict(ConflictResolution::Skip, apply_to_all, cx);
+                                    }))
+                                    .map(|el| focus_ring(el, is_focused(CONFLICT_FOCUS_SKIP)))
+                                    .child(
+                                        div()
+                                            .text_sm()
+                                            .text_color(theme.text_primary)
+                                            .child("Skip"),
+                                    ),
+                            )
+                            .child(
+                                div()
+                                    .id("keep-both-button")
+                                    .track_focus(&focus_handles[CONFLICT_FOCUS_KEEP_BOTH])
+                                    .px_4()
+                                    .py_2()
+                                    .bg(theme.bg_tertiary)
+                                    .rounded_md()
+                                    .cursor_pointer()
+                                    .hover(|s| s.bg(theme.bg_hover))
+                                    .on_mouse_down(MouseButton::Left, cx.listener(|this, _, _window, cx| {
+                                        let apply_to_all = this.conflict_apply_all_checked;
+                                        this.resolve_conflict(ConflictResolution::KeepBoth, apply_to_all, cx);
+                                    }))
+                                    .map(|el| focus_ring(el, is_focused(CONFLICT_FOCUS_KEEP_BOTH)))
+                                    .child(
+                                        div()
+                                            .text_sm()
+                                            .text_color(theme.text_primary)
+                                            .child("Keep Both"),
+                                    ),
+                            )
+                            .child(
+                                div()
+                                    .id("replace-button")
+                                    .track_focus(&focus_handles[CONFLICT_FOCUS_REPLACE])
+                                    .px_4()
+                                    .py_2()
+                                    .bg(theme.accent_primary)
+                                    .rounded_md()
+                                    .cursor_pointer()
+                                    .hover(|s| s.bg(theme.accent_secondary))
+                                    .on_mouse_down(MouseButton::Left, cx.listener(|this, _, _window, cx| {
+                                        let apply_to_all = this.conflict_apply_all_checked;
+                                        this.resolve_conflict(ConflictResolution::Replace, apply_to_all, cx);
+                                    }))
+                                    .map(|el| focus_ring(el, is_focused(CONFLICT_FOCUS_REPLACE)))
+                                    .child(
+                                        div()
+                                            .text_sm()
+                                            .text_color(theme.text_inverse)
+                                            .child("Replace"),
+                                    ),
+                            )
+                            .child(
+                                div()
+                                    .id("cancel-button")
+                                    .track_focus(&focus_handles[CONFLICT_FOCUS_CANCEL])
+                                    .px_4()
+                                    .py_2()
+                                    .bg(theme.error)
+                                    .rounded_md()
+                                    .cursor_pointer()
+                                    .hover(|s| s.opacity(0.9))
+                                    .on_mouse_down(MouseButton::Left, cx.listener(|this, _, _window, cx| {
+                                        this.cancel_conflict_dialog(cx);
+                                    }))
+                                    .map(|el| focus_ring(el, is_focused(CONFLICT_FOCUS_CANCEL)))
                                     .child(
-                                        svg()
-                                            .path(if is_dir {
-                                                "assets/icons/folder.svg"
-                                            } else {
-                                                "assets/icons/file.svg"
-                                            })
-                                            .size(px(20.0))
-                                            .text_color(if is_dir {
-                                                folder_color
-                                            } else {
-                                                text_muted
-                                            }),
-                                    )
-                                    .child(div().text_sm().text_color(text_primary).child(name))
-                            })),
+                                        div()
+                                            .text_sm()
+                                            .text_color(theme.text_inverse)
+                                            .child("Cancel"),
+                                    ),
+                            ),
                     ),
             )
     }
 
-    fn render_dest_breadcrumbs(&self, cx: &mut Context<Self>) -> impl IntoElement {
-        let theme = theme_colors();
-        let text_muted = theme.text_muted;
-        let text_primary = theme.text_primary;
-
-        let mut parts: Vec<(String, PathBuf)> = Vec::new();
-        let mut current = Some(self.dest_path.as_path());
-
-        while let Some(p) = current {
-            let name = if p.parent().is_none() {
-                "/".to_string()
-            } else {
-                p.file_name()
-                    .and_then(|n| n.to_str())
-                    .unwrap_or("")
-                    .to_string()
-            };
-            if !name.is_empty() {
-                parts.push((name, p.to_path_buf()));
-            }
-            current = p.parent();
-        }
-        parts.reverse();
-
-        div()
-            .flex()
-            .items_center()
-            .gap_1()
-            .overflow_x_hidden()
-            .children(parts.into_iter().enumerate().map(|(i, (name, path))| {
-                let is_last = i == 0;
-                div()
-                    .flex()
-                    .items_center()
-                    .when(i > 0, |d| {
-                        d.child(
-                            svg()
-                                .path("assets/icons/chevron-right.svg")
-                                .size(px(12.0))
-                                .text_color(text_muted)
-                                .mx_1(),
-                        )
-                    })
-                    .child(
-                        div()
-                            .id(SharedString::from(format!("dest-crumb-{}", i)))
-                            .px(px(crate::models::toolbar::BREADCRUMB_PADDING))
-                            .py_0p5()
-                            .rounded_sm()
-                            .cursor_pointer()
-                            .text_sm()
-                            .text_color(text_muted)
-                            .hover(|h| h.bg(theme.bg_hover).text_color(text_primary))
-                            .on_mouse_down(MouseButton::Left, {
-                                let nav_path = path.clone();
-                                cx.listener(move |view, _, _, cx| {
-                                    view.navigate_dest_to(nav_path.clone(), cx);
-                                })
-                            })
-                            .child(name),
-                    )
-            }))
-    }
-}
-
-impl Workspace {
-    fn render_dialog_overlay(&self, cx: &mut Context<Self>) -> impl IntoElement {
+    fn render_overwrite_summary_overlay(
+        &self,
+        summary: OverwriteSummary,
+        cx: &mut Context<Self>,
+    ) -> impl IntoElement {
         let theme = theme_colors();
         let overlay_bg = gpui::rgba(0x00000099);
-        let dialog_bg = theme.bg_secondary;
-        let border_color = theme.border_default;
-        let text_primary = theme.text_primary;
-        let text_muted = theme.text_muted;
-        let accent = theme.accent_primary;
-        let hover_bg = theme.bg_hover;
-
-        let (title, placeholder, icon_path, button_text) = match &self.dialog_state {
-            DialogState::NewFile { .. } => (
-                "New File",
-                "Enter file name...",
-                "assets/icons/file-plus.svg",
-                "Create",
-            ),
-            DialogState::NewFolder { .. } => (
-                "New Folder",
-                "Enter folder name...",
-                "assets/icons/folder-plus.svg",
-                "Create",
-            ),
-            DialogState::Rename { .. } => (
-                "Rename",
-                "Enter new name...",
-                "assets/icons/pen.svg",
-                "Rename",
-            ),
-            DialogState::None => ("", "", "", ""),
+        let title = if summary.len() == 1 {
+            "Replace 1 item?".to_string()
+        } else {
+            format!("Replace {} items?", summary.len())
         };
-
-        let is_rename = matches!(self.dialog_state, DialogState::Rename { .. });
-
-        let input_element: Option<Input> = self
-            .dialog_input
-            .as_ref()
-            .map(|input_state| Input::new(input_state).placeholder(placeholder));
+        let total = crate::utils::format_size(summary.total_size());
 
         div()
-            .id("dialog-overlay")
+            .id("overwrite-summary-overlay")
             .absolute()
             .inset_0()
             .bg(overlay_bg)
@@ -3847,126 +8850,190 @@ impl Workspace {
             .on_mouse_down(
                 MouseButton::Left,
                 cx.listener(|view, _event, _window, cx| {
-                    view.cancel_dialog(cx);
+                    view.dismiss_overwrite_summary(cx);
                 }),
             )
             .child(
                 div()
-                    .id("dialog-content")
+                    .id("overwrite-summary-content")
                     .occlude()
-                    .w(px(400.0))
-                    .bg(dialog_bg)
+                    .w(px(450.0))
+                    .bg(theme.bg_secondary)
                     .rounded_lg()
                     .border_1()
-                    .border_color(border_color)
+                    .border_color(theme.border_default)
                     .shadow_lg()
+                    .p_4()
+                    .gap_4()
+                    .flex()
+                    .flex_col()
                     .on_mouse_down(MouseButton::Left, |_, _, _| {})
                     .child(
                         div()
-                            .p_4()
-                            .border_b_1()
-                            .border_color(border_color)
                             .flex()
                             .items_center()
-                            .gap_3()
+                            .gap_2()
                             .child(
                                 svg()
-                                    .path(SharedString::from(icon_path))
+                                    .path("assets/icons/triangle-alert.svg")
                                     .size(px(20.0))
-                                    .text_color(accent),
+                                    .text_color(theme.warning),
                             )
                             .child(
                                 div()
-                                    .text_base()
+                                    .text_lg()
                                     .font_weight(gpui::FontWeight::SEMIBOLD)
-                                    .text_color(text_primary)
+                                    .text_color(theme.text_primary)
                                     .child(title),
                             ),
                     )
                     .child(
                         div()
-                            .p_4()
-                            .child(div().w_full().children(input_element))
-                            .child(div().mt_2().text_xs().text_color(text_muted).child(
-                                if is_rename {
-                                    "Press Enter to rename, Escape to cancel"
-                                } else {
-                                    "Press Enter to create, Escape to cancel"
-                                },
+                            .text_sm()
+                            .text_color(theme.text_secondary)
+                            .child(format!(
+                                "These existing items will be overwritten ({} total):",
+                                total
                             )),
                     )
                     .child(
                         div()
-                            .p_4()
-                            .border_t_1()
-                            .border_color(border_color)
+                            .id("overwrite-summary-items")
+                            .max_h(px(240.0))
+                            .overflow_y_scroll()
+                            .flex()
+                            .flex_col()
+                            .bg(theme.bg_primary)
+                            .rounded_md()
+                            .py_1()
+                            .children(summary.items.into_iter().map(|item| {
+                                let name = item
+                                    .destination
+                                    .file_name()
+                                    .map(|n| n.to_string_lossy().to_string())
+                                    .unwrap_or_else(|| item.destination.display().to_string());
+                                div()
+                                    .flex()
+                                    .justify_between()
+                                    .gap_4()
+                                    .px_3()
+                                    .py_1()
+                                    .text_sm()
+                                    .child(
+                                        div()
+                                            .flex_1()
+                                            .overflow_hidden()
+                                            .text_ellipsis()
+                                            .whitespace_nowrap()
+                                            .text_color(theme.text_primary)
+                                            .child(name),
+                                    )
+                                    .child(
+                                        div()
+                                            .text_color(theme.text_muted)
+                                            .child(crate::utils::format_size(item.size)),
+                                    )
+                            })),
+                    )
+                    .child(
+                        div()
                             .flex()
                             .justify_end()
                             .gap_2()
                             .child(
                                 div()
-                                    .id("cancel-btn")
+                                    .id("overwrite-summary-back")
                                     .px_4()
                                     .py_2()
+                                    .bg(theme.bg_tertiary)
                                     .rounded_md()
                                     .cursor_pointer()
-                                    .text_sm()
-                                    .text_color(text_muted)
-                                    .hover(|h| h.bg(hover_bg).text_color(text_primary))
-                                    .on_mouse_down(
-                                        MouseButton::Left,
-                                        cx.listener(|view, _event, _window, cx| {
-                                            view.cancel_dialog(cx);
-                                        }),
-                                    )
-                                    .child("Cancel"),
+                                    .hover(|s| s.bg(theme.bg_hover))
+                                    .on_mouse_down(MouseButton::Left, cx.listener(|this, _, _window, cx| {
+                                        this.dismiss_overwrite_summary(cx);
+                                    }))
+                                    .child(
+                                        div()
+                                            .text_sm()
+                                            .text_color(theme.text_primary)
+                                            .child("Back"),
+                                    ),
                             )
                             .child(
                                 div()
-                                    .id("submit-btn")
+                                    .id("overwrite-summary-confirm")
                                     .px_4()
                                     .py_2()
+                                    .bg(theme.error)
                                     .rounded_md()
                                     .cursor_pointer()
-                                    .text_sm()
-                                    .bg(accent)
-                                    .text_color(theme.text_inverse)
-                                    .hover(|h| h.opacity(0.9))
-                                    .on_mouse_down(
-                                        MouseButton::Left,
-                                        cx.listener(move |view, _event, _window, cx| {
-                                            if is_rename {
-                                                view.submit_rename(cx);
-                                            } else {
-                                                view.submit_dialog(cx);
-                                            }
-                                        }),
-                                    )
-                                    .child(button_text),
+                                    .hover(|s| s.opacity(0.9))
+                                    .on_mouse_down(MouseButton::Left, cx.listener(|this, _, _window, cx| {
+                                        this.confirm_overwrite_summary(cx);
+                                    }))
+                                    .child(
+                                        div()
+                                            .text_sm()
+                                            .text_color(theme.text_inverse)
+                                            .child("Replace All"),
+                                    ),
                             ),
-                    ),
-            )
-    }
-
-    fn render_conflict_dialog_overlay(&self, cx: &mut Context<Self>) -> impl IntoElement {
-        let theme = theme_colors();
-        let overlay_bg = gpui::rgba(0x00000099);
-
-        let (source, destination) = self.pending_conflicts.last()
-            .cloned()
-            .unwrap_or_else(|| (PathBuf::new(), PathBuf::new()));
-
-        let conflict_info = ConflictInfo::new(source.clone(), destination.clone());
-        let remaining = self.pending_conflicts.len().saturating_sub(1);
-        let file_name = conflict_info.source_name().to_string();
-        let dest_folder = conflict_info.dest_folder().to_string();
-        let source_size = crate::utils::format_size(conflict_info.source_size);
-        let dest_size = crate::utils::format_size(conflict_info.dest_size);
-        let source_modified = self.format_time(conflict_info.source_modified);
-        let dest_modified = self.format_time(conflict_info.dest_modified);
+                    ),
+            )
+    }
+
+    fn render_executable_prompt_overlay(
+        &self,
+        prompt: ExecutablePrompt,
+        cx: &mut Context<Self>,
+    ) -> impl IntoElement {
+        let theme = theme_colors();
+        let overlay_bg = gpui::rgba(0x00000099);
+        let name = prompt
+            .path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_else(|| prompt.path.display().to_string());
+        let kind = prompt.kind.label();
+
+        let button = |id: &'static str, label: &'static str, action: ExecutableAction, primary: bool| {
+            div()
+                .id(id)
+                .px_4()
+                .py_2()
+                .rounded_md()
+                .cursor_pointer()
+                .when(primary, |s| s.bg(theme.accent_primary).hover(|s| s.opacity(0.9)))
+                .when(!primary, |s| s.bg(theme.bg_tertiary).hover(|s| s.bg(theme.bg_hover)))
+                .on_mouse_down(
+                    MouseButton::Left,
+                    cx.listener(move |this, _, _window, cx| {
+                        this.resolve_executable_prompt(action, cx);
+                    }),
+                )
+                .child(
+                    div()
+                        .text_sm()
+                        .text_color(if primary { theme.text_inverse } else { theme.text_primary })
+                        .child(label),
+                )
+        };
+        let reveal = button("executable-prompt-reveal", "Reveal", ExecutableAction::Reveal, false);
+        let edit = button(
+            "executable-prompt-edit",
+            "Open in Editor",
+            ExecutableAction::OpenInEditor,
+            prompt.kind == ExecutableKind::Script,
+        );
+        let run = button(
+            "executable-prompt-run",
+            "Run",
+            ExecutableAction::Run,
+            prompt.kind == ExecutableKind::Binary,
+        );
 
         div()
-            .id("conflict-dialog-overlay")
+            .id("executable-prompt-overlay")
             .absolute()
             .inset_0()
             .bg(overlay_bg)
@@ -3976,14 +9043,14 @@ impl Workspace {
             .on_mouse_down(
                 MouseButton::Left,
                 cx.listener(|view, _event, _window, cx| {
-                    view.cancel_conflict_dialog(cx);
+                    view.dismiss_executable_prompt(cx);
                 }),
             )
             .child(
                 div()
-                    .id("conflict-dialog-content")
+                    .id("executable-prompt-content")
                     .occlude()
-                    .w(px(450.0))
+                    .w(px(420.0))
                     .bg(theme.bg_secondary)
                     .rounded_lg()
                     .border_1()
@@ -4001,16 +9068,20 @@ impl Workspace {
                             .gap_2()
                             .child(
                                 svg()
-                                    .path("assets/icons/triangle-alert.svg")
+                                    .path("assets/icons/file-terminal.svg")
                                     .size(px(20.0))
                                     .text_color(theme.warning),
                             )
                             .child(
                                 div()
+                                    .flex_1()
+                                    .overflow_hidden()
+                                    .text_ellipsis()
+                                    .whitespace_nowrap()
                                     .text_lg()
                                     .font_weight(gpui::FontWeight::SEMIBOLD)
                                     .text_color(theme.text_primary)
-                                    .child("File Conflict"),
+                                    .child(format!("Open \u{201c}{}\u{201d}?", name)),
                             ),
                     )
                     .child(
@@ -4018,185 +9089,53 @@ impl Workspace {
                             .text_sm()
                             .text_color(theme.text_secondary)
                             .child(format!(
-                                "\"{}\" already exists in \"{}\"",
-                                file_name, dest_folder
+                                "This file is an executable {}. Running it can change your system.",
+                                kind
                             )),
                     )
                     .child(
                         div()
+                            .id("executable-prompt-remember")
                             .flex()
-                            .gap_4()
-                            .child(
-                                div()
-                                    .flex_1()
-                                    .flex()
-                                    .flex_col()
-                                    .gap_1()
-                                    .p_3()
-                                    .bg(theme.bg_primary)
-                                    .rounded_md()
-                                    .child(
-                                        div()
-                                            .text_xs()
-                                            .font_weight(gpui::FontWeight::MEDIUM)
-                                            .text_color(theme.text_muted)
-                                            .child("Source"),
-                                    )
-                                    .child(
-                                        div()
-                                            .text_sm()
-                                            .text_color(theme.text_secondary)
-                                            .child(format!("Size: {}", source_size)),
-                                    )
-                                    .child(
-                                        div()
-                                            .text_sm()
-                                            .text_color(theme.text_secondary)
-                                            .child(format!("Modified: {}", source_modified)),
-                                    ),
+                            .items_center()
+                            .gap_2()
+                            .cursor_pointer()
+                            .text_sm()
+                            .text_color(theme.text_secondary)
+                            .on_mouse_down(
+                                MouseButton::Left,
+                                cx.listener(|view, _event, _window, cx| {
+                                    view.toggle_executable_prompt_remember(cx);
+                                }),
                             )
                             .child(
                                 div()
-                                    .flex_1()
+                                    .size(px(14.0))
+                                    .rounded_sm()
+                                    .border_1()
+                                    .border_color(theme.border_default)
                                     .flex()
-                                    .flex_col()
-                                    .gap_1()
-                                    .p_3()
-                                    .bg(theme.bg_primary)
-                                    .rounded_md()
-                                    .child(
-                                        div()
-                                            .text_xs()
-                                            .font_weight(gpui::FontWeight::MEDIUM)
-                                            .text_color(theme.text_muted)
-                                            .child("Existing"),
-                                    )
-                                    .child(
-                                        div()
-                                            .text_sm()
-                                            .text_color(theme.text_secondary)
-                                            .child(format!("Size: {}", dest_size)),
-                                    )
-                                    .child(
-                                        div()
-                                            .text_sm()
-                                            .text_color(theme.text_secondary)
-                                            .child(format!("Modified: {}", dest_modified)),
-                                    ),
-                            ),
+                                    .items_center()
+                                    .justify_center()
+                                    .when(prompt.remember, |s| {
+                                        s.bg(theme.accent_primary).child(
+                                            svg()
+                                                .path("assets/icons/check.svg")
+                                                .size(px(10.0))
+                                                .text_color(theme.text_inverse),
+                                        )
+                                    }),
+                            )
+                            .child(format!("Always do this for {}s", kind)),
                     )
-                    .when(remaining > 0, |this| {
-                        this.child(
-                            div()
-                                .id("apply-to-all-checkbox")
-                                .flex()
-                                .items_center()
-                                .gap_2()
-                                .cursor_pointer()
-                                .child(
-                                    div()
-                                        .w(px(16.0))
-                                        .h(px(16.0))
-                                        .rounded_sm()
-                                        .border_1()
-                                        .border_color(theme.border_default)
-                                        .bg(theme.bg_primary)
-                                        .flex()
-                                        .items_center()
-                                        .justify_center(),
-                                )
-                                .child(
-                                    div()
-                                        .text_sm()
-                                        .text_color(theme.text_secondary)
-                                        .child(format!(
-                                            "Apply to all {} remaining conflicts",
-                                            remaining
-                                        )),
-                                ),
-                        )
-                    })
                     .child(
                         div()
                             .flex()
                             .justify_end()
                             .gap_2()
-                            .child(
-                                div()
-                                    .id("skip-button")
-                                    .px_4()
-                                    .py_2()
-                                    .bg(theme.bg_tertiary)
-                                    .rounded_md()
-                                    .cursor_pointer()
-                                    .hover(|s| s.bg(theme.bg_hover))
-                                    .on_mouse_down(MouseButton::Left, cx.listener(|this, _, _window, cx| {
-                                        this.resolve_conflict(ConflictResolution::Skip, false, cx);
-                                    }))
-                                    .child(
-                                        div()
-                                            .text_sm()
-                                            .text_color(theme.text_primary)
-                                            .child("Skip"),
-                                    ),
-                            )
-                            .child(
-                                div()
-                                    .id("keep-both-button")
-                                    .px_4()
-                                    .py_2()
-                                    .bg(theme.bg_tertiary)
-                                    .rounded_md()
-                                    .cursor_pointer()
-                                    .hover(|s| s.bg(theme.bg_hover))
-                                    .on_mouse_down(MouseButton::Left, cx.listener(|this, _, _window, cx| {
-                                        this.resolve_conflict(ConflictResolution::KeepBoth, false, cx);
-                                    }))
-                                    .child(
-                                        div()
-                                            .text_sm()
-                                            .text_color(theme.text_primary)
-                                            .child("Keep Both"),
-                                    ),
-                            )
-                            .child(
-                                div()
-                                    .id("replace-button")
-                                    .px_4()
-                                    .py_2()
-                                    .bg(theme.accent_primary)
-                                    .rounded_md()
-                                    .cursor_pointer()
-                                    .hover(|s| s.bg(theme.accent_secondary))
-                                    .on_mouse_down(MouseButton::Left, cx.listener(|this, _, _window, cx| {
-                                        this.resolve_conflict(ConflictResolution::Replace, false, cx);
-                                    }))
-                                    .child(
-                                        div()
-                                            .text_sm()
-                                            .text_color(theme.text_inverse)
-                                            .child("Replace"),
-                                    ),
-                            )
-                            .child(
-                                div()
-                                    .id("cancel-button")
-                                    .px_4()
-                                    .py_2()
-                                    .bg(theme.error)
-                                    .rounded_md()
-                                    .cursor_pointer()
-                                    .hover(|s| s.opacity(0.9))
-                                    .on_mouse_down(MouseButton::Left, cx.listener(|this, _, _window, cx| {
-                                        this.cancel_conflict_dialog(cx);
-                                    }))
-                                    .child(
-                                        div()
-                                            .text_sm()
-                                            .text_color(theme.text_inverse)
-                                            .child("Cancel"),
-                                    ),
-                            ),
+                            .child(reveal)
+                            .child(edit)
+                            .child(run),
                     ),
             )
     }
@@ -4215,8 +9154,7 @@ impl Workspace {
             .on_mouse_down(
                 MouseButton::Left,
                 cx.listener(|view, _event, _window, cx| {
-                    view.symlink_dialog = None;
-                    cx.notify();
+                    view.close_symlink_dialog(cx);
                 }),
             )
             .when_some(self.symlink_dialog.clone(), |this, dialog| {
@@ -4229,6 +9167,87 @@ impl Workspace {
             })
     }
 
+    fn render_bulk_tag_dialog_overlay(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        let overlay_bg = gpui::rgba(0x00000099);
+
+        div()
+            .id("bulk-tag-dialog-overlay")
+            .absolute()
+            .inset_0()
+            .bg(overlay_bg)
+            .flex()
+            .items_center()
+            .justify_center()
+            .on_mouse_down(
+                MouseButton::Left,
+                cx.listener(|view, _event, _window, cx| {
+                    view.close_bulk_tag_dialog(cx);
+                }),
+            )
+            .when_some(self.bulk_tag_dialog.clone(), |this, dialog| {
+                this.child(
+                    div()
+                        .id("bulk-tag-dialog-content")
+                        .occlude()
+                        .child(dialog),
+                )
+            })
+    }
+
+    fn render_checksum_verify_overlay(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        let overlay_bg = gpui::rgba(0x00000099);
+
+        div()
+            .id("checksum-verify-overlay")
+            .absolute()
+            .inset_0()
+            .bg(overlay_bg)
+            .flex()
+            .items_center()
+            .justify_center()
+            .on_mouse_down(
+                MouseButton::Left,
+                cx.listener(|view, _event, _window, cx| {
+                    view.close_checksum_verify_panel(cx);
+                }),
+            )
+            .when_some(self.checksum_verify_panel.clone(), |this, panel| {
+                this.child(
+                    div()
+                        .id("checksum-verify-content")
+                        .occlude()
+                        .child(panel),
+                )
+            })
+    }
+
+    fn render_duplicate_finder_overlay(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        let overlay_bg = gpui::rgba(0x00000099);
+
+        div()
+            .id("duplicate-finder-overlay")
+            .absolute()
+            .inset_0()
+            .bg(overlay_bg)
+            .flex()
+            .items_center()
+            .justify_center()
+            .on_mouse_down(
+                MouseButton::Left,
+                cx.listener(|view, _event, _window, cx| {
+                    view.close_duplicate_finder_panel(cx);
+                }),
+            )
+            .when_some(self.duplicate_finder_panel.clone(), |this, panel| {
+                this.child(
+                    div()
+                        .id("duplicate-finder-content")
+                        .occlude()
+                        .child(panel),
+                )
+            })
+    }
+
     fn render_format_dialog_overlay(&self, cx: &mut Context<Self>) -> impl IntoElement {
         let theme = theme_colors();
         let overlay_bg = gpui::rgba(0x00000099);
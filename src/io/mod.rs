@@ -4,7 +4,7 @@ mod traversal;
 
 pub use pipeline::{
     create_batch_pipeline, max_batches_for_items, BatchAggregator, BatchConfig, DEFAULT_BATCH_SIZE,
-    DEFAULT_FLUSH_INTERVAL,
+    DEFAULT_FLUSH_INTERVAL, MAX_BATCH_SIZE, MAX_FLUSH_INTERVAL, MIN_BATCH_SIZE, MIN_FLUSH_INTERVAL,
 };
 pub use platform::{
     detect_platform, EventCoalescer, LinuxPlatform, LinuxWatcher, MacOsPlatform, MacOsWatcher,
@@ -12,5 +12,5 @@ pub use platform::{
 };
 pub use traversal::{
     sort_entries, spawn_sorted_traversal, spawn_traversal, traverse_directory,
-    traverse_directory_sorted, SortKey, SortOrder, TraversalConfig,
+    traverse_directory_sorted, SortKey, SortOrder, TraversalConfig, TraversalSummary,
 };
@@ -8,7 +8,15 @@ pub const DEFAULT_BATCH_SIZE: usize = 100;
 
 pub const DEFAULT_FLUSH_INTERVAL: Duration = Duration::from_millis(16);
 
-#[derive(Debug, Clone)]
+pub const MIN_BATCH_SIZE: usize = 1;
+
+pub const MAX_BATCH_SIZE: usize = 10_000;
+
+pub const MIN_FLUSH_INTERVAL: Duration = Duration::from_millis(1);
+
+pub const MAX_FLUSH_INTERVAL: Duration = Duration::from_secs(1);
+
+#[derive(Debug, Clone, PartialEq)]
 pub struct BatchConfig {
     pub batch_size: usize,
     pub flush_interval: Duration,
@@ -23,6 +31,30 @@ impl Default for BatchConfig {
     }
 }
 
+impl BatchConfig {
+    pub fn new(batch_size: usize, flush_interval: Duration) -> Self {
+        Self {
+            batch_size,
+            flush_interval,
+        }
+        .validated()
+    }
+
+
+    pub fn validated(self) -> Self {
+        Self {
+            batch_size: self.batch_size.clamp(MIN_BATCH_SIZE, MAX_BATCH_SIZE),
+            flush_interval: self
+                .flush_interval
+                .clamp(MIN_FLUSH_INTERVAL, MAX_FLUSH_INTERVAL),
+        }
+    }
+
+    pub fn max_batches_for(&self, item_count: usize) -> usize {
+        max_batches_for_items(item_count, self.batch_size)
+    }
+}
+
 
 
 
@@ -44,7 +76,7 @@ impl BatchAggregator {
         config: BatchConfig,
     ) -> Self {
         Self {
-            config,
+            config: config.validated(),
             input,
             output,
         }
@@ -243,6 +275,51 @@ mod tests {
         assert_eq!(max_batches_for_items(100, 0), 0);
     }
 
+    #[test]
+    fn test_batch_config_is_clamped_to_sane_bounds() {
+        let config = BatchConfig::new(0, Duration::ZERO);
+        assert_eq!(config.batch_size, MIN_BATCH_SIZE);
+        assert_eq!(config.flush_interval, MIN_FLUSH_INTERVAL);
+
+        let config = BatchConfig::new(usize::MAX, Duration::from_secs(60));
+        assert_eq!(config.batch_size, MAX_BATCH_SIZE);
+        assert_eq!(config.flush_interval, MAX_FLUSH_INTERVAL);
+
+        assert_eq!(BatchConfig::new(25, DEFAULT_FLUSH_INTERVAL).max_batches_for(60), 4);
+    }
+
+    #[test]
+    fn test_pipeline_honors_custom_batch_size() {
+        let config = BatchConfig::new(3, Duration::from_secs(1));
+        let (entry_tx, batch_rx, handle) = create_batch_pipeline(config.clone());
+
+        for i in 0..7 {
+            entry_tx.send(make_test_entry(i)).unwrap();
+        }
+        drop(entry_tx);
+
+        let sizes: Vec<usize> = batch_rx.iter().map(|batch| batch.len()).collect();
+        assert_eq!(sizes, vec![3, 3, 1]);
+        assert!(sizes.len() <= config.max_batches_for(7));
+        assert_eq!(handle.join().unwrap(), 7);
+    }
+
+    #[test]
+    fn test_pipeline_flushes_partial_batch_at_configured_interval() {
+        let config = BatchConfig::new(1_000, Duration::from_millis(20));
+        let (entry_tx, batch_rx, handle) = create_batch_pipeline(config);
+
+        entry_tx.send(make_test_entry(0)).unwrap();
+        entry_tx.send(make_test_entry(1)).unwrap();
+
+        let batch = batch_rx.recv_timeout(Duration::from_millis(500)).unwrap();
+        assert_eq!(batch.len(), 2);
+
+        drop(entry_tx);
+        assert_eq!(handle.join().unwrap(), 2);
+        assert!(batch_rx.try_recv().is_err());
+    }
+
     #[test]
     fn test_multiple_batches() {
         let config = BatchConfig {
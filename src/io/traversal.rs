@@ -1,3 +1,4 @@
+use std::io;
 use std::path::{Path, PathBuf};
 use std::time::SystemTime;
 
@@ -5,7 +6,7 @@ use flume::Sender;
 use jwalk::{WalkDir, WalkDirGeneric};
 use serde::{Deserialize, Serialize};
 
-use crate::models::{FileEntry, FileSystemError, Result};
+use crate::models::{classify_hidden, FileEntry, FileSystemError, HiddenAttributes, Result};
 
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
@@ -31,6 +32,7 @@ pub struct TraversalConfig {
     pub sort_order: SortOrder,
     pub include_hidden: bool,
     pub max_depth: Option<usize>,
+    pub follow_symlinks: bool,
 }
 
 impl Default for TraversalConfig {
@@ -40,19 +42,37 @@ impl Default for TraversalConfig {
             sort_order: SortOrder::Ascending,
             include_hidden: false,
             max_depth: Some(1),
+            follow_symlinks: false,
         }
     }
 }
 
+#[derive(Debug, Default)]
+pub struct TraversalSummary {
+    pub count: usize,
+    pub errors: Vec<(PathBuf, io::Error)>,
+}
 
-
+impl TraversalSummary {
+    fn record_walk_error(&mut self, root: &Path, error: jwalk::Error) {
+        let path = error
+            .path()
+            .map(Path::to_path_buf)
+            .unwrap_or_else(|| root.to_path_buf());
+        let kind = error
+            .io_error()
+            .map(io::Error::kind)
+            .unwrap_or(io::ErrorKind::Other);
+        self.errors.push((path, io::Error::new(kind, error.to_string())));
+    }
+}
 
 
 pub fn traverse_directory(
     path: &Path,
     config: &TraversalConfig,
     sender: Sender<FileEntry>,
-) -> Result<usize> {
+) -> Result<TraversalSummary> {
     if !path.exists() {
         return Err(FileSystemError::PathNotFound(path.to_path_buf()));
     }
@@ -65,7 +85,7 @@ pub fn traverse_directory(
     }
 
     let walk_dir = build_walk_dir(path, config);
-    let mut count = 0;
+    let mut summary = TraversalSummary::default();
 
     for entry_result in walk_dir {
         match entry_result {
@@ -74,31 +94,33 @@ pub fn traverse_directory(
                     continue;
                 }
 
-                if let Some(file_entry) = dir_entry_to_file_entry(&entry) {
-                    if !config.include_hidden && is_hidden(&file_entry.name) {
-                        continue;
-                    }
+                match dir_entry_to_file_entry(&entry) {
+                    Ok(file_entry) => {
+                        if !config.include_hidden && is_hidden(&file_entry.name) {
+                            continue;
+                        }
 
-                    if sender.send(file_entry).is_err() {
-                        break;
+                        if sender.send(file_entry).is_err() {
+                            break;
+                        }
+                        summary.count += 1;
                     }
-                    count += 1;
+                    Err(e) => summary.errors.push((entry.path(), e)),
                 }
             }
-            Err(e) => {
-                eprintln!("Traversal error: {}", e);
-            }
+            Err(e) => summary.record_walk_error(path, e),
         }
     }
 
-    Ok(count)
+    Ok(summary)
 }
 
 
 fn build_walk_dir(path: &Path, config: &TraversalConfig) -> WalkDirGeneric<((), ())> {
     let mut walk_dir = WalkDir::new(path)
         .parallelism(jwalk::Parallelism::RayonNewPool(num_cpus()))
-        .skip_hidden(!config.include_hidden);
+        .skip_hidden(!config.include_hidden)
+        .follow_links(config.follow_symlinks);
 
     if let Some(depth) = config.max_depth {
         walk_dir = walk_dir.max_depth(depth);
@@ -117,12 +139,13 @@ fn num_cpus() -> usize {
 }
 
 
-fn dir_entry_to_file_entry(entry: &jwalk::DirEntry<((), ())>) -> Option<FileEntry> {
+fn dir_entry_to_file_entry(entry: &jwalk::DirEntry<((), ())>) -> io::Result<FileEntry> {
     let path = entry.path();
     let name = entry.file_name().to_string_lossy().to_string();
 
-    let symlink_metadata = std::fs::symlink_metadata(&path).ok()?;
+    let symlink_metadata = std::fs::symlink_metadata(&path)?;
     let is_symlink = symlink_metadata.file_type().is_symlink();
+    let hidden_kind = classify_hidden(&name, HiddenAttributes::from_metadata(&symlink_metadata));
 
     if is_symlink {
         let target = std::fs::read_link(&path).ok();
@@ -130,7 +153,7 @@ fn dir_entry_to_file_entry(entry: &jwalk::DirEntry<((), ())>) -> Option<FileEntr
         let is_broken = !target_exists;
 
         let (is_dir, size, modified) = if target_exists {
-            let target_meta = std::fs::metadata(&path).ok()?;
+            let target_meta = std::fs::metadata(&path)?;
             (
                 target_meta.is_dir(),
                 if target_meta.is_dir() { 0 } else { target_meta.len() },
@@ -140,7 +163,9 @@ fn dir_entry_to_file_entry(entry: &jwalk::DirEntry<((), ())>) -> Option<FileEntr
             (false, 0, symlink_metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH))
         };
 
-        let mut file_entry = FileEntry::new(name, path, is_dir, size, modified);
+        let details = std::fs::metadata(&path).unwrap_or(symlink_metadata);
+        let mut file_entry =
+            FileEntry::new(name, path, is_dir, size, modified).with_metadata_details(&details);
         if let Some(target_path) = target {
             file_entry = file_entry.with_symlink_info(target_path, is_broken);
         } else {
@@ -148,13 +173,16 @@ fn dir_entry_to_file_entry(entry: &jwalk::DirEntry<((), ())>) -> Option<FileEntr
             file_entry.is_broken_symlink = true;
             file_entry.file_type = crate::models::FileType::Symlink;
         }
-        Some(file_entry)
+        file_entry.hidden_kind = hidden_kind;
+        Ok(file_entry)
     } else {
-        let metadata = entry.metadata().ok()?;
-        let is_dir = metadata.is_dir();
-        let size = if is_dir { 0 } else { metadata.len() };
-        let modified = metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH);
-        Some(FileEntry::new(name, path, is_dir, size, modified))
+        let is_dir = symlink_metadata.is_dir();
+        let size = if is_dir { 0 } else { symlink_metadata.len() };
+        let modified = symlink_metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+        let mut file_entry = FileEntry::new(name, path, is_dir, size, modified)
+            .with_metadata_details(&symlink_metadata);
+        file_entry.hidden_kind = hidden_kind;
+        Ok(file_entry)
     }
 }
 
@@ -222,7 +250,7 @@ pub fn traverse_directory_sorted(
     path: &Path,
     config: &TraversalConfig,
     sender: Sender<FileEntry>,
-) -> Result<usize> {
+) -> Result<TraversalSummary> {
     if !path.exists() {
         return Err(FileSystemError::PathNotFound(path.to_path_buf()));
     }
@@ -236,6 +264,7 @@ pub fn traverse_directory_sorted(
 
     let walk_dir = build_walk_dir(path, config);
     let mut entries = Vec::new();
+    let mut summary = TraversalSummary::default();
 
     for entry_result in walk_dir {
         match entry_result {
@@ -244,29 +273,30 @@ pub fn traverse_directory_sorted(
                     continue;
                 }
 
-                if let Some(file_entry) = dir_entry_to_file_entry(&entry) {
-                    if !config.include_hidden && is_hidden(&file_entry.name) {
-                        continue;
+                match dir_entry_to_file_entry(&entry) {
+                    Ok(file_entry) => {
+                        if !config.include_hidden && is_hidden(&file_entry.name) {
+                            continue;
+                        }
+                        entries.push(file_entry);
                     }
-                    entries.push(file_entry);
+                    Err(e) => summary.errors.push((entry.path(), e)),
                 }
             }
-            Err(e) => {
-                eprintln!("Traversal error: {}", e);
-            }
+            Err(e) => summary.record_walk_error(path, e),
         }
     }
 
     sort_entries(&mut entries, config.sort_key, config.sort_order);
 
-    let count = entries.len();
+    summary.count = entries.len();
     for entry in entries {
         if sender.send(entry).is_err() {
             break;
         }
     }
 
-    Ok(count)
+    Ok(summary)
 }
 
 
@@ -276,7 +306,7 @@ pub fn spawn_traversal(
     config: TraversalConfig,
 ) -> (
     flume::Receiver<FileEntry>,
-    std::thread::JoinHandle<Result<usize>>,
+    std::thread::JoinHandle<Result<TraversalSummary>>,
 ) {
     let (sender, receiver) = flume::unbounded();
 
@@ -292,7 +322,7 @@ pub fn spawn_sorted_traversal(
     config: TraversalConfig,
 ) -> (
     flume::Receiver<FileEntry>,
-    std::thread::JoinHandle<Result<usize>>,
+    std::thread::JoinHandle<Result<TraversalSummary>>,
 ) {
     let (sender, receiver) = flume::unbounded();
 
@@ -472,6 +502,86 @@ mod tests {
         assert_eq!(entries[3].name, "file_a.txt");
     }
 
+    #[test]
+    fn test_max_depth_truncates_walk() {
+        let temp_dir = TempDir::new().unwrap();
+        let deep = temp_dir.path().join("a").join("b").join("c");
+        fs::create_dir_all(&deep).unwrap();
+        File::create(deep.join("leaf.txt")).unwrap();
+
+        let walk = |max_depth| {
+            let config = TraversalConfig {
+                max_depth,
+                ..Default::default()
+            };
+            let (sender, receiver) = flume::unbounded();
+            traverse_directory_sorted(temp_dir.path(), &config, sender).unwrap();
+            let mut names: Vec<_> = receiver.iter().map(|e| e.name).collect();
+            names.sort();
+            names
+        };
+
+        assert_eq!(walk(Some(1)), vec!["a"]);
+        assert_eq!(walk(Some(2)), vec!["a", "b"]);
+        assert_eq!(walk(None), vec!["a", "b", "c", "leaf.txt"]);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_follow_symlinks_survives_cycles() {
+        let temp_dir = TempDir::new().unwrap();
+        let inner = temp_dir.path().join("inner");
+        fs::create_dir(&inner).unwrap();
+        File::create(inner.join("file.txt")).unwrap();
+        std::os::unix::fs::symlink(temp_dir.path(), inner.join("loop")).unwrap();
+
+        let config = TraversalConfig {
+            max_depth: None,
+            follow_symlinks: true,
+            ..Default::default()
+        };
+        let (receiver, handle) = spawn_sorted_traversal(temp_dir.path().to_path_buf(), config);
+        let summary = handle.join().unwrap().unwrap();
+
+        let entries: Vec<_> = receiver.iter().collect();
+        assert_eq!(entries.len(), summary.count);
+        assert_eq!(entries.iter().filter(|e| e.name == "file.txt").count(), 1);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_unreadable_subdir_is_recorded_as_error() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let temp_dir = TempDir::new().unwrap();
+        File::create(temp_dir.path().join("readable.txt")).unwrap();
+        let locked = temp_dir.path().join("locked");
+        fs::create_dir(&locked).unwrap();
+        File::create(locked.join("secret.txt")).unwrap();
+        fs::set_permissions(&locked, fs::Permissions::from_mode(0o000)).unwrap();
+
+        if fs::read_dir(&locked).is_ok() {
+            fs::set_permissions(&locked, fs::Permissions::from_mode(0o755)).unwrap();
+            return;
+        }
+
+        let config = TraversalConfig {
+            max_depth: None,
+            ..Default::default()
+        };
+        let (sender, receiver) = flume::unbounded();
+        let summary = traverse_directory_sorted(temp_dir.path(), &config, sender).unwrap();
+        fs::set_permissions(&locked, fs::Permissions::from_mode(0o755)).unwrap();
+
+        let mut names: Vec<_> = receiver.iter().map(|e| e.name).collect();
+        names.sort();
+        assert_eq!(names, vec!["locked", "readable.txt"]);
+        assert_eq!(summary.count, 2);
+        assert_eq!(summary.errors.len(), 1);
+        assert_eq!(summary.errors[0].0, locked);
+        assert_eq!(summary.errors[0].1.kind(), io::ErrorKind::PermissionDenied);
+    }
+
 
     fn is_sorted(entries: &[FileEntry], sort_key: SortKey, sort_order: SortOrder) -> bool {
         if entries.len() <= 1 {
@@ -561,6 +671,7 @@ mod tests {
                 sort_order,
                 include_hidden: false,
                 max_depth: Some(1),
+                follow_symlinks: false,
             };
 
             let (sender, receiver) = flume::unbounded();
@@ -8,12 +8,17 @@ use std::path::PathBuf;
 use std::sync::Arc;
 
 use gpui::{
-    px, size, Application, AssetSource, Bounds, Result, SharedString, WindowBounds, WindowOptions,
+    px, size, App, Application, AssetSource, Bounds, Result, SharedString, WindowBounds, WindowOptions,
 };
 use tokio::runtime::Runtime;
 
 use app::Workspace;
-use models::{open_with, GlobalSettings, IconCache, IconKey};
+use models::{
+    open_with, parse_cli_args, resolve_action, resolve_launch_path, AutomationCommand,
+    AutomationVerb, ContextMenuRegistry, FrequentFolders, GlobalSettings, IconCache, IconKey, PreviewService, TagManager,
+};
+#[cfg(unix)]
+use models::{automation_socket_path, send_command, AutomationResponse, AutomationServer};
 #[cfg(not(test))]
 use models::WindowManager;
 
@@ -45,14 +50,33 @@ impl AssetSource for Assets {
 }
 
 fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let cwd = std::env::current_dir().unwrap_or_else(|_| PathBuf::from("/"));
+    let automation_command = parse_cli_args(&args, &cwd);
+    #[cfg(unix)]
+    if let Some(command) = &automation_command {
+        if forward_to_running_instance(command) {
+            return;
+        }
+    }
+    let (launch_path, automation_command) = match automation_command {
+        Some(command) if command.verb == AutomationVerb::NewWindow => (Some(command.path), None),
+        command => (None, command),
+    };
+
     let assets_base = get_assets_base_path();
-    let app = Application::new().with_assets(Assets { base: assets_base });
+    let app = Application::new().with_assets(Assets {
+        base: assets_base.clone(),
+    });
 
-    app.run(|cx| {
+    app.run(move |cx| {
         adabraka_ui::init(cx);
         adabraka_ui::set_icon_base_path("assets/icons");
 
         cx.set_global(GlobalSettings::default());
+        cx.set_global(TagManager::load().unwrap_or_default());
+        cx.set_global(ContextMenuRegistry::new());
+        cx.set_global(PreviewService::new());
 
         let mut window_manager = WindowManager::new();
 
@@ -62,6 +86,8 @@ fn main() {
 
         let _icon_cache = preload_default_icons();
 
+        start_automation(automation_command, cx);
+
         cx.on_window_closed(|cx| {
             if cx.has_global::<WindowManager>() {
                 let _ = cx.global::<WindowManager>().save_state();
@@ -74,9 +100,10 @@ fn main() {
         .detach();
 
         let settings = GlobalSettings::load();
+        models::init_icon_packs(assets_base.clone(), &settings.icon_pack);
         let should_restore = settings.restore_windows_on_start();
 
-        if should_restore {
+        if should_restore && launch_path.is_none() {
             if let Some(saved_state) = WindowManager::load_state() {
                 if !saved_state.windows.is_empty() {
                     window_manager.restore_state(saved_state, cx);
@@ -87,6 +114,13 @@ fn main() {
         }
 
         let home_dir = dirs::home_dir().unwrap_or_else(|| PathBuf::from("/"));
+        let frequent = FrequentFolders::load();
+        let target = resolve_launch_path(
+            launch_path.as_deref(),
+            settings.launch_location(),
+            frequent.last_visited(),
+            &home_dir,
+        );
 
         let bounds = Bounds::centered(None, size(px(1200.0), px(800.0)), cx);
         let window_options = WindowOptions {
@@ -105,12 +139,15 @@ fn main() {
             ..Default::default()
         };
 
-        let path = home_dir.clone();
+        let path = target.path.clone();
         let handle = cx
             .open_window(window_options, |_window, cx| Workspace::build(path, cx))
             .expect("Failed to open window");
+        let _ = handle.update(cx, |workspace, _window, cx| {
+            workspace.show_launch_fallback(&target, cx);
+        });
 
-        window_manager.register_window(handle, home_dir);
+        window_manager.register_window(handle, target.path);
         cx.set_global(window_manager);
     });
 }
@@ -118,6 +155,43 @@ fn main() {
 
 
 
+#[cfg(unix)]
+fn forward_to_running_instance(command: &AutomationCommand) -> bool {
+    match send_command(&automation_socket_path(), command) {
+        Ok(AutomationResponse::Ok) => true,
+        Ok(AutomationResponse::Error(message)) => {
+            eprintln!("nexus-explorer: {}", message);
+            std::process::exit(1);
+        }
+        Err(_) => false,
+    }
+}
+
+
+fn start_automation(initial: Option<AutomationCommand>, cx: &mut App) {
+    let (sender, receiver) = flume::unbounded();
+    if let Some(action) = initial.and_then(|command| resolve_action(&command).ok()) {
+        let _ = sender.send(action);
+    }
+
+    #[cfg(unix)]
+    let server = AutomationServer::start(automation_socket_path(), sender)
+        .map_err(|e| eprintln!("Automation socket unavailable: {}", e))
+        .ok();
+    #[cfg(not(unix))]
+    drop(sender);
+
+    cx.spawn(async move |cx| {
+        #[cfg(unix)]
+        let _server = server;
+        while let Ok(action) = receiver.recv_async().await {
+            let _ = cx.update(|cx| Workspace::run_automation(action, cx));
+        }
+    })
+    .detach();
+}
+
+
 fn get_assets_base_path() -> PathBuf {
     #[cfg(target_os = "macos")]
     {
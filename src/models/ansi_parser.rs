@@ -1,4 +1,5 @@
 use crate::models::terminal::CellStyle;
+use crate::models::TerminalPalette;
 use gpui::Rgba;
 
 
@@ -181,6 +182,7 @@ pub struct AnsiParser {
     current_style: CellStyle,
     default_fg: Rgba,
     default_bg: Rgba,
+    palette: [Rgba; 16],
     utf8_buffer: Vec<u8>,
     utf8_remaining: usize,
 }
@@ -201,6 +203,7 @@ impl AnsiParser {
             current_style: CellStyle::default(),
             default_fg: DEFAULT_FG,
             default_bg: DEFAULT_BG,
+            palette: ANSI_COLORS,
             utf8_buffer: Vec::with_capacity(4),
             utf8_remaining: 0,
         }
@@ -214,6 +217,36 @@ impl AnsiParser {
         parser
     }
 
+    pub fn with_palette(palette: &TerminalPalette) -> Self {
+        let mut parser = Self::new();
+        parser.set_palette(palette);
+        parser
+    }
+
+
+    pub fn set_palette(&mut self, palette: &TerminalPalette) {
+        if self.current_style.foreground == self.default_fg {
+            self.current_style.foreground = palette.foreground;
+        }
+        if self.current_style.background == self.default_bg {
+            self.current_style.background = palette.background;
+        }
+        self.default_fg = palette.foreground;
+        self.default_bg = palette.background;
+        self.palette = palette.ansi;
+    }
+
+    pub fn default_colors(&self) -> (Rgba, Rgba) {
+        (self.default_fg, self.default_bg)
+    }
+
+    pub fn indexed_color(&self, n: usize) -> Rgba {
+        match self.palette.get(n) {
+            Some(color) => *color,
+            None => color_from_256(n),
+        }
+    }
+
     pub fn current_style(&self) -> &CellStyle {
         &self.current_style
     }
@@ -620,7 +653,7 @@ impl AnsiParser {
                 27 => self.current_style.inverse = false,
                 29 => self.current_style.strikethrough = false,
                 30..=37 => {
-                    self.current_style.foreground = ANSI_COLORS[code - 30];
+                    self.current_style.foreground = self.palette[code - 30];
                 }
                 38 => {
                     if let Some(color) = self.parse_extended_color(&mut i) {
@@ -631,7 +664,7 @@ impl AnsiParser {
                     self.current_style.foreground = self.default_fg;
                 }
                 40..=47 => {
-                    self.current_style.background = ANSI_COLORS[code - 40];
+                    self.current_style.background = self.palette[code - 40];
                 }
                 48 => {
                     if let Some(color) = self.parse_extended_color(&mut i) {
@@ -642,10 +675,10 @@ impl AnsiParser {
                     self.current_style.background = self.default_bg;
                 }
                 90..=97 => {
-                    self.current_style.foreground = ANSI_COLORS[code - 90 + 8];
+                    self.current_style.foreground = self.palette[code - 90 + 8];
                 }
                 100..=107 => {
-                    self.current_style.background = ANSI_COLORS[code - 100 + 8];
+                    self.current_style.background = self.palette[code - 100 + 8];
                 }
                 _ => {}
             }
@@ -676,7 +709,7 @@ impl AnsiParser {
                 }
                 let n = self.params[*i + 2] as usize;
                 *i += 2;
-                Some(color_from_256(n))
+                Some(self.indexed_color(n))
             }
             _ => None,
         }
@@ -0,0 +1,115 @@
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::AppInfo;
+
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AppAssociation {
+    pub name: String,
+    pub path: PathBuf,
+}
+
+impl AppAssociation {
+    pub fn to_app_info(&self) -> AppInfo {
+        AppInfo::new(self.name.clone(), self.path.clone())
+    }
+}
+
+impl From<&AppInfo> for AppAssociation {
+    fn from(app: &AppInfo) -> Self {
+        Self {
+            name: app.name.clone(),
+            path: app.path.clone(),
+        }
+    }
+}
+
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AppAssociations {
+    by_extension: HashMap<String, AppAssociation>,
+}
+
+impl AppAssociations {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get(&self, extension: &str) -> Option<&AppAssociation> {
+        self.by_extension.get(&normalize_extension(extension))
+    }
+
+    pub fn for_path(&self, path: &Path) -> Option<&AppAssociation> {
+        association_key(path).and_then(|extension| self.by_extension.get(&extension))
+    }
+
+    pub fn set(&mut self, extension: &str, app: &AppInfo) -> bool {
+        let extension = normalize_extension(extension);
+        if extension.is_empty() {
+            return false;
+        }
+        self.by_extension.insert(extension, AppAssociation::from(app));
+        true
+    }
+
+    pub fn reset(&mut self, extension: &str) -> bool {
+        self.by_extension
+            .remove(&normalize_extension(extension))
+            .is_some()
+    }
+
+    pub fn len(&self) -> usize {
+        self.by_extension.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.by_extension.is_empty()
+    }
+
+    fn config_path() -> PathBuf {
+        dirs::config_dir()
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join("nexus-explorer")
+            .join("app_associations.json")
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        self.save_to(&Self::config_path())
+    }
+
+    pub fn save_to(&self, path: &Path) -> io::Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let json = serde_json::to_string_pretty(self)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        fs::write(path, json)
+    }
+
+    pub fn load() -> Self {
+        Self::load_from(&Self::config_path())
+    }
+
+    pub fn load_from(path: &Path) -> Self {
+        fs::read_to_string(path)
+            .ok()
+            .and_then(|json| serde_json::from_str(&json).ok())
+            .unwrap_or_default()
+    }
+}
+
+
+pub fn association_key(path: &Path) -> Option<String> {
+    let extension = normalize_extension(path.extension()?.to_str()?);
+    (!extension.is_empty()).then_some(extension)
+}
+
+fn normalize_extension(extension: &str) -> String {
+    extension.trim().trim_start_matches('.').to_lowercase()
+}
@@ -0,0 +1,63 @@
+use super::app_associations::*;
+use crate::models::AppInfo;
+use std::path::{Path, PathBuf};
+use tempfile::TempDir;
+
+
+fn app(name: &str) -> AppInfo {
+    AppInfo::new(name.to_string(), PathBuf::from(format!("/Applications/{}.app", name)))
+}
+
+#[test]
+fn test_association_is_read_by_extension_case_insensitively() {
+    let mut associations = AppAssociations::new();
+    assert!(associations.set(".MD", &app("Typora")));
+
+    assert_eq!(associations.get("md").map(|a| a.name.as_str()), Some("Typora"));
+    assert_eq!(
+        associations
+            .for_path(Path::new("/notes/README.Md"))
+            .map(|a| a.name.as_str()),
+        Some("Typora")
+    );
+    assert!(associations.for_path(Path::new("/notes/Makefile")).is_none());
+    assert!(associations.get("txt").is_none());
+}
+
+#[test]
+fn test_updating_association_replaces_previous_app() {
+    let mut associations = AppAssociations::new();
+    associations.set("png", &app("Preview"));
+    associations.set("png", &app("Pixelmator"));
+
+    assert_eq!(associations.len(), 1);
+    let association = associations.get("png").unwrap();
+    assert_eq!(association.name, "Pixelmator");
+    assert_eq!(association.to_app_info().path, PathBuf::from("/Applications/Pixelmator.app"));
+    assert!(!associations.set("", &app("Nothing")));
+}
+
+#[test]
+fn test_reset_restores_system_default() {
+    let mut associations = AppAssociations::new();
+    associations.set("pdf", &app("Skim"));
+
+    assert!(associations.reset("PDF"));
+    assert!(associations.get("pdf").is_none());
+    assert!(!associations.reset("pdf"));
+    assert!(associations.is_empty());
+}
+
+#[test]
+fn test_associations_round_trip_through_store() {
+    let dir = TempDir::new().unwrap();
+    let store = dir.path().join("nested").join("app_associations.json");
+
+    let mut associations = AppAssociations::new();
+    associations.set("rs", &app("Zed"));
+    associations.save_to(&store).unwrap();
+
+    let loaded = AppAssociations::load_from(&store);
+    assert_eq!(loaded, associations);
+    assert!(AppAssociations::load_from(&dir.path().join("missing.json")).is_empty());
+}
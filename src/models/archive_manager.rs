@@ -1,16 +1,22 @@
 use std::fs::{self, File};
 use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::ops::RangeInclusive;
 use std::path::{Path, PathBuf};
 use std::time::SystemTime;
 
 use flate2::read::GzDecoder;
 use flate2::write::GzEncoder;
 use flate2::Compression;
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub const DEFAULT_COMPRESSION_LEVEL: u8 = 6;
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum ArchiveFormat {
+    #[default]
     Zip,
     TarGz,
     TarBz2,
@@ -20,21 +26,9 @@ pub enum ArchiveFormat {
 
 impl ArchiveFormat {
     pub fn from_extension(path: &Path) -> Option<Self> {
-        let name = path.file_name()?.to_str()?.to_lowercase();
-        
-        if name.ends_with(".zip") {
-            Some(ArchiveFormat::Zip)
-        } else if name.ends_with(".tar.gz") || name.ends_with(".tgz") {
-            Some(ArchiveFormat::TarGz)
-        } else if name.ends_with(".tar.bz2") || name.ends_with(".tbz2") {
-            Some(ArchiveFormat::TarBz2)
-        } else if name.ends_with(".tar.xz") || name.ends_with(".txz") {
-            Some(ArchiveFormat::TarXz)
-        } else if name.ends_with(".7z") {
-            Some(ArchiveFormat::SevenZip)
-        } else {
-            None
-        }
+        let name = path.file_name()?.to_str()?;
+        let (_, extension) = split_archive_name(name)?;
+        archive_extension_format(extension)
     }
 
     pub fn extension(&self) -> &'static str {
@@ -56,6 +50,90 @@ impl ArchiveFormat {
             ArchiveFormat::SevenZip => "7-Zip Archive",
         }
     }
+
+
+    pub fn compression_levels(&self) -> Option<RangeInclusive<u8>> {
+        match self {
+            ArchiveFormat::Zip | ArchiveFormat::TarGz | ArchiveFormat::TarXz => Some(0..=9),
+            ArchiveFormat::TarBz2 => Some(1..=9),
+            ArchiveFormat::SevenZip => None,
+        }
+    }
+
+    pub fn clamp_compression_level(&self, level: u8) -> u8 {
+        match self.compression_levels() {
+            Some(levels) => level.clamp(*levels.start(), *levels.end()),
+            None => DEFAULT_COMPRESSION_LEVEL,
+        }
+    }
+}
+
+
+const ARCHIVE_EXTENSIONS: &[(&str, Option<ArchiveFormat>)] = &[
+    (".tar.gz", Some(ArchiveFormat::TarGz)),
+    (".tgz", Some(ArchiveFormat::TarGz)),
+    (".tar.bz2", Some(ArchiveFormat::TarBz2)),
+    (".tbz2", Some(ArchiveFormat::TarBz2)),
+    (".tar.xz", Some(ArchiveFormat::TarXz)),
+    (".txz", Some(ArchiveFormat::TarXz)),
+    (".tar.zst", None),
+    (".tzst", None),
+    (".tar", None),
+    (".zip", Some(ArchiveFormat::Zip)),
+    (".7z", Some(ArchiveFormat::SevenZip)),
+];
+
+
+pub fn split_archive_name(name: &str) -> Option<(&str, &str)> {
+    ARCHIVE_EXTENSIONS.iter().find_map(|(extension, _)| {
+        let split = name.len().checked_sub(extension.len())?;
+        if split == 0 || !name.is_char_boundary(split) {
+            return None;
+        }
+        let (base, suffix) = name.split_at(split);
+        suffix.eq_ignore_ascii_case(extension).then_some((base, suffix))
+    })
+}
+
+
+pub fn archive_base_name(path: &Path) -> Option<String> {
+    let name = path.file_name()?.to_str()?;
+    let base = split_archive_name(name)
+        .map(|(base, _)| base)
+        .or_else(|| path.file_stem().and_then(|s| s.to_str()))?;
+    Some(base.to_string())
+}
+
+
+pub const COMPRESS_FORMATS: [ArchiveFormat; 3] =
+    [ArchiveFormat::Zip, ArchiveFormat::TarGz, ArchiveFormat::SevenZip];
+
+
+pub fn default_archive_name(paths: &[PathBuf]) -> String {
+    paths
+        .first()
+        .and_then(|path| path.parent())
+        .and_then(|parent| parent.file_name())
+        .and_then(|name| name.to_str())
+        .filter(|name| !name.is_empty())
+        .unwrap_or("Archive")
+        .to_string()
+}
+
+
+pub fn archive_output_path(directory: &Path, name: &str, format: ArchiveFormat) -> PathBuf {
+    let name = name.trim();
+    let base = split_archive_name(name).map_or(name, |(base, _)| base);
+    directory.join(format!("{}{}", base, format.extension()))
+}
+
+
+fn archive_extension_format(extension: &str) -> Option<ArchiveFormat> {
+    let lower = extension.to_lowercase();
+    ARCHIVE_EXTENSIONS
+        .iter()
+        .find(|(ext, _)| *ext == lower)
+        .and_then(|(_, format)| *format)
 }
 
 
@@ -70,7 +148,17 @@ impl Default for CompressOptions {
     fn default() -> Self {
         Self {
             format: ArchiveFormat::Zip,
-            compression_level: 6,
+            compression_level: DEFAULT_COMPRESSION_LEVEL,
+            password: None,
+        }
+    }
+}
+
+impl CompressOptions {
+    pub fn new(format: ArchiveFormat, compression_level: u8) -> Self {
+        Self {
+            format,
+            compression_level: format.clamp_compression_level(compression_level),
             password: None,
         }
     }
@@ -139,6 +227,110 @@ impl ArchiveProgress {
 }
 
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArchiveJobKind {
+    Compress,
+    Extract,
+}
+
+
+#[derive(Debug, Clone)]
+pub enum ArchiveJobEvent {
+    Progress(ArchiveProgress),
+    Finished(Result<(), String>),
+}
+
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ArchivePlaceholder {
+    pub id: u64,
+    pub kind: ArchiveJobKind,
+    pub name: String,
+    pub directory: PathBuf,
+    pub output: PathBuf,
+    pub percentage: f64,
+}
+
+impl ArchivePlaceholder {
+    pub fn status_label(&self) -> String {
+        let verb = match self.kind {
+            ArchiveJobKind::Compress => "Compressing",
+            ArchiveJobKind::Extract => "Extracting",
+        };
+        format!("{}… {:.0}%", verb, self.percentage)
+    }
+}
+
+
+#[derive(Debug, Default)]
+pub struct ArchiveJobs {
+    jobs: Vec<ArchivePlaceholder>,
+    next_id: u64,
+}
+
+impl ArchiveJobs {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+
+    pub fn start(
+        &mut self,
+        kind: ArchiveJobKind,
+        name: String,
+        directory: PathBuf,
+        output: PathBuf,
+    ) -> u64 {
+        self.next_id += 1;
+        self.jobs.push(ArchivePlaceholder {
+            id: self.next_id,
+            kind,
+            name,
+            directory,
+            output,
+            percentage: 0.0,
+        });
+        self.next_id
+    }
+
+    pub fn set_progress(&mut self, id: u64, progress: &ArchiveProgress) -> bool {
+        match self.jobs.iter_mut().find(|job| job.id == id) {
+            Some(job) => {
+                job.percentage = progress.percentage.clamp(0.0, 100.0);
+                true
+            }
+            None => false,
+        }
+    }
+
+
+    pub fn finish(&mut self, id: u64) -> Option<ArchivePlaceholder> {
+        let index = self.jobs.iter().position(|job| job.id == id)?;
+        Some(self.jobs.remove(index))
+    }
+
+    pub fn get(&self, id: u64) -> Option<&ArchivePlaceholder> {
+        self.jobs.iter().find(|job| job.id == id)
+    }
+
+    pub fn placeholders_in(&self, directory: &Path) -> Vec<ArchivePlaceholder> {
+        self.jobs
+            .iter()
+            .filter(|job| job.directory == directory)
+            .cloned()
+            .collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.jobs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.jobs.is_empty()
+    }
+}
+
+
 #[derive(Debug, Error)]
 pub enum ArchiveError {
     #[error("Invalid archive: {0}")]
@@ -378,9 +570,15 @@ impl ArchiveManager {
         let writer = BufWriter::new(file);
         let mut zip = zip::ZipWriter::new(writer);
         
-        let zip_options = zip::write::SimpleFileOptions::default()
-            .compression_method(zip::CompressionMethod::Deflated)
-            .compression_level(Some(options.compression_level as i64));
+        let level = ArchiveFormat::Zip.clamp_compression_level(options.compression_level);
+        let zip_options = if level == 0 {
+            zip::write::SimpleFileOptions::default()
+                .compression_method(zip::CompressionMethod::Stored)
+        } else {
+            zip::write::SimpleFileOptions::default()
+                .compression_method(zip::CompressionMethod::Deflated)
+                .compression_level(Some(level as i64))
+        };
         
         let mut bytes_written = 0u64;
         
@@ -433,11 +631,9 @@ impl ArchiveManager {
         
         let file = File::create(output)?;
         let writer = BufWriter::new(file);
-        let level = match options.compression_level {
-            0..=3 => Compression::fast(),
-            4..=6 => Compression::default(),
-            _ => Compression::best(),
-        };
+        let level = Compression::new(
+            ArchiveFormat::TarGz.clamp_compression_level(options.compression_level) as u32,
+        );
         let encoder = GzEncoder::new(writer, level);
         let mut tar = tar::Builder::new(encoder);
         
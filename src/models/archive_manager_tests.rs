@@ -543,3 +543,207 @@ proptest! {
         }
     }
 }
+
+#[test]
+fn test_split_archive_name_compound_extensions() {
+    assert_eq!(split_archive_name("file.tar.gz"), Some(("file", ".tar.gz")));
+    assert_eq!(split_archive_name("file.tgz"), Some(("file", ".tgz")));
+    assert_eq!(split_archive_name("file.tar.bz2"), Some(("file", ".tar.bz2")));
+    assert_eq!(split_archive_name("file.tbz2"), Some(("file", ".tbz2")));
+    assert_eq!(split_archive_name("file.tar.xz"), Some(("file", ".tar.xz")));
+    assert_eq!(split_archive_name("file.txz"), Some(("file", ".txz")));
+    assert_eq!(split_archive_name("file.tar.zst"), Some(("file", ".tar.zst")));
+    assert_eq!(split_archive_name("file.tzst"), Some(("file", ".tzst")));
+    assert_eq!(split_archive_name("file.tar"), Some(("file", ".tar")));
+    assert_eq!(split_archive_name("file.zip"), Some(("file", ".zip")));
+    assert_eq!(split_archive_name("file.7z"), Some(("file", ".7z")));
+}
+
+#[test]
+fn test_split_archive_name_is_case_insensitive() {
+    assert_eq!(split_archive_name("Backup.TAR.GZ"), Some(("Backup", ".TAR.GZ")));
+    assert_eq!(split_archive_name("Photos.Zip"), Some(("Photos", ".Zip")));
+}
+
+#[test]
+fn test_split_archive_name_rejects_non_archives() {
+    assert_eq!(split_archive_name("notes.txt"), None);
+    assert_eq!(split_archive_name(".tar.gz"), None);
+    assert_eq!(split_archive_name("gz"), None);
+}
+
+#[test]
+fn test_archive_base_name_strips_whole_compound_extension() {
+    assert_eq!(archive_base_name(Path::new("/tmp/file.tar.gz")), Some("file".to_string()));
+    assert_eq!(archive_base_name(Path::new("/tmp/release-1.2.tar.xz")), Some("release-1.2".to_string()));
+    assert_eq!(archive_base_name(Path::new("/tmp/data.tgz")), Some("data".to_string()));
+    assert_eq!(archive_base_name(Path::new("/tmp/report.pdf")), Some("report".to_string()));
+}
+
+#[test]
+fn test_archive_format_detection_compound_extensions() {
+    assert_eq!(ArchiveFormat::from_extension(Path::new("a.tar.bz2")), Some(ArchiveFormat::TarBz2));
+    assert_eq!(ArchiveFormat::from_extension(Path::new("a.tbz2")), Some(ArchiveFormat::TarBz2));
+    assert_eq!(ArchiveFormat::from_extension(Path::new("a.tar.xz")), Some(ArchiveFormat::TarXz));
+    assert_eq!(ArchiveFormat::from_extension(Path::new("a.txz")), Some(ArchiveFormat::TarXz));
+    assert_eq!(ArchiveFormat::from_extension(Path::new("A.TAR.GZ")), Some(ArchiveFormat::TarGz));
+    assert_eq!(ArchiveFormat::from_extension(Path::new("a.tar.zst")), None);
+    assert_eq!(ArchiveFormat::from_extension(Path::new("a.tar")), None);
+}
+
+#[test]
+fn test_extract_tar_gz_to_folder_uses_base_name() {
+    let temp_dir = TempDir::new().unwrap();
+    let source = temp_dir.path().join("source");
+    create_test_file(&source, "inner.txt", b"hello");
+
+    let archive_path = temp_dir.path().join("file.tar.gz");
+    let manager = ArchiveManager::new();
+    let compress_options = CompressOptions {
+        format: ArchiveFormat::TarGz,
+        ..Default::default()
+    };
+    manager
+        .compress(&[source.join("inner.txt")], &archive_path, &compress_options, |_| {})
+        .unwrap();
+
+    let base = archive_base_name(&archive_path).unwrap();
+    let destination = temp_dir.path().join(&base);
+    let extract_options = ExtractOptions {
+        destination: destination.clone(),
+        password: None,
+        overwrite: OverwriteMode::Replace,
+    };
+    manager.extract(&archive_path, &extract_options, |_| {}).unwrap();
+
+    assert_eq!(destination, temp_dir.path().join("file"));
+    assert!(destination.join("inner.txt").exists());
+    assert!(!temp_dir.path().join("file.tar").exists());
+}
+
+#[test]
+fn test_archive_placeholder_lifecycle() {
+    let mut jobs = ArchiveJobs::new();
+    let dir = PathBuf::from("/data/docs");
+    let id = jobs.start(
+        ArchiveJobKind::Compress,
+        "report.zip".to_string(),
+        dir.clone(),
+        dir.join("report.zip"),
+    );
+
+    let placeholders = jobs.placeholders_in(&dir);
+    assert_eq!(placeholders.len(), 1);
+    assert_eq!(placeholders[0].status_label(), "Compressing… 0%");
+    assert!(jobs.placeholders_in(Path::new("/data/other")).is_empty());
+
+    let mut progress = ArchiveProgress::new(4, 400);
+    progress.percentage = 42.4;
+    assert!(jobs.set_progress(id, &progress));
+    assert_eq!(jobs.get(id).unwrap().status_label(), "Compressing… 42%");
+
+    let finished = jobs.finish(id).unwrap();
+    assert_eq!(finished.output, dir.join("report.zip"));
+    assert!(jobs.is_empty());
+    assert!(jobs.finish(id).is_none());
+    assert!(!jobs.set_progress(id, &progress));
+}
+
+#[test]
+fn test_compress_multiple_paths_into_one_archive() {
+    let temp_dir = TempDir::new().unwrap();
+    let project = temp_dir.path().join("project");
+    let notes = create_test_file(&project, "notes.txt", b"notes");
+    let logo = create_test_file(&project, "logo.png", b"png");
+    create_test_file(&project, "src/main.rs", b"fn main() {}");
+    create_test_file(&project, "src/lib/mod.rs", b"");
+    let selection = vec![notes, logo, project.join("src")];
+
+    assert_eq!(default_archive_name(&selection), "project");
+
+    let manager = ArchiveManager::new();
+    for format in [ArchiveFormat::Zip, ArchiveFormat::TarGz] {
+        let archive_path = archive_output_path(&project, &default_archive_name(&selection), format);
+        assert_eq!(archive_path.parent(), Some(project.as_path()));
+
+        let options = CompressOptions { format, ..CompressOptions::default() };
+        manager.compress(&selection, &archive_path, &options, |_| {}).unwrap();
+
+        let mut files: Vec<String> = manager
+            .list_contents(&archive_path)
+            .unwrap()
+            .into_iter()
+            .filter(|entry| !entry.is_dir)
+            .map(|entry| entry.path.trim_start_matches("./").to_string())
+            .collect();
+        files.sort();
+        assert_eq!(
+            files,
+            vec!["logo.png", "notes.txt", "src/lib/mod.rs", "src/main.rs"],
+            "{:?}",
+            format
+        );
+        fs::remove_file(&archive_path).unwrap();
+    }
+}
+
+#[test]
+fn test_archive_output_path_replaces_typed_extension() {
+    let dir = Path::new("/tmp/out");
+    assert_eq!(
+        archive_output_path(dir, "Backup.zip", ArchiveFormat::TarGz),
+        dir.join("Backup.tar.gz")
+    );
+    assert_eq!(
+        archive_output_path(dir, " Backup ", ArchiveFormat::Zip),
+        dir.join("Backup.zip")
+    );
+    assert_eq!(default_archive_name(&[PathBuf::from("/")]), "Archive");
+}
+
+#[test]
+fn test_compression_level_validated_per_format() {
+    assert_eq!(ArchiveFormat::Zip.clamp_compression_level(12), 9);
+    assert_eq!(ArchiveFormat::TarGz.clamp_compression_level(0), 0);
+    assert_eq!(ArchiveFormat::TarBz2.clamp_compression_level(0), 1);
+    assert_eq!(ArchiveFormat::SevenZip.compression_levels(), None);
+    assert_eq!(ArchiveFormat::SevenZip.clamp_compression_level(2), DEFAULT_COMPRESSION_LEVEL);
+
+    let options = CompressOptions::new(ArchiveFormat::Zip, 42);
+    assert_eq!(options.compression_level, 9);
+}
+
+#[test]
+fn test_configured_defaults_flow_into_compress_options() {
+    let mut settings = GlobalSettings::default();
+    let defaults = settings.compress_options();
+    assert_eq!(defaults.format, ArchiveFormat::Zip);
+    assert_eq!(defaults.compression_level, DEFAULT_COMPRESSION_LEVEL);
+
+    settings.archive_format = ArchiveFormat::TarGz;
+    settings.compression_level = 42;
+    let options = settings.compress_options();
+    assert_eq!(options.format, ArchiveFormat::TarGz);
+    assert_eq!(options.compression_level, 9);
+
+    settings.archive_format = ArchiveFormat::TarXz;
+    assert_eq!(settings.compress_options().format, ArchiveFormat::Zip);
+
+    let temp_dir = TempDir::new().unwrap();
+    let source = create_test_file(temp_dir.path(), "data.txt", &b"nexus explorer ".repeat(4096));
+    let manager = ArchiveManager::new();
+    let mut archive_size = |level: u8| {
+        settings.archive_format = ArchiveFormat::Zip;
+        settings.compression_level = level;
+        let archive = temp_dir.path().join(format!("level-{}.zip", level));
+        manager
+            .compress(&[source.clone()], &archive, &settings.compress_options(), |_| {})
+            .unwrap();
+        assert_eq!(manager.list_contents(&archive).unwrap().len(), 1);
+        fs::metadata(&archive).unwrap().len()
+    };
+
+    let stored = archive_size(0);
+    let compressed = archive_size(9);
+    assert!(stored > compressed, "level 0 ({}) should be larger than level 9 ({})", stored, compressed);
+}
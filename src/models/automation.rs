@@ -0,0 +1,319 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use thiserror::Error;
+
+use crate::models::{resolve_launch_path, LaunchLocation, LaunchTarget};
+
+#[cfg(unix)]
+use std::io::{BufRead, BufReader, Write};
+#[cfg(unix)]
+use std::os::unix::net::{UnixListener, UnixStream};
+
+
+pub const AUTOMATION_SOCKET_NAME: &str = "nexus-explorer.sock";
+pub const AUTOMATION_READ_TIMEOUT: Duration = Duration::from_secs(5);
+
+
+#[derive(Debug, Error, Clone, PartialEq)]
+pub enum AutomationError {
+    #[error("Empty command")]
+    Empty,
+
+    #[error("Unknown command: {0}")]
+    UnknownVerb(String),
+
+    #[error("Missing path for '{0}'")]
+    MissingPath(String),
+
+    #[error("Path must be absolute: {0}")]
+    RelativePath(String),
+
+    #[error("Path not found: {0}")]
+    NotFound(String),
+
+    #[error("Invalid response: {0}")]
+    InvalidResponse(String),
+
+    #[error("I/O error: {0}")]
+    Io(String),
+}
+
+
+pub type AutomationResult<T> = std::result::Result<T, AutomationError>;
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AutomationVerb {
+    Open,
+    Select,
+    NewTab,
+    Reveal,
+    NewWindow,
+}
+
+impl AutomationVerb {
+    pub fn keyword(&self) -> &'static str {
+        match self {
+            AutomationVerb::Open => "open",
+            AutomationVerb::Select => "select",
+            AutomationVerb::NewTab => "new-tab",
+            AutomationVerb::Reveal => "reveal",
+            AutomationVerb::NewWindow => "new-window",
+        }
+    }
+
+    pub fn from_keyword(keyword: &str) -> Option<Self> {
+        match keyword {
+            "open" => Some(AutomationVerb::Open),
+            "select" => Some(AutomationVerb::Select),
+            "new-tab" => Some(AutomationVerb::NewTab),
+            "reveal" => Some(AutomationVerb::Reveal),
+            "new-window" => Some(AutomationVerb::NewWindow),
+            _ => None,
+        }
+    }
+}
+
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AutomationCommand {
+    pub verb: AutomationVerb,
+    pub path: PathBuf,
+}
+
+impl AutomationCommand {
+    pub fn new(verb: AutomationVerb, path: PathBuf) -> Self {
+        Self { verb, path }
+    }
+
+    pub fn parse(line: &str, home: Option<&Path>) -> AutomationResult<Self> {
+        let line = line.trim();
+        if line.is_empty() {
+            return Err(AutomationError::Empty);
+        }
+        let (keyword, argument) = match line.split_once(char::is_whitespace) {
+            Some((keyword, argument)) => (keyword, argument.trim()),
+            None => (line, ""),
+        };
+        let verb = AutomationVerb::from_keyword(keyword)
+            .ok_or_else(|| AutomationError::UnknownVerb(keyword.to_string()))?;
+        if argument.is_empty() {
+            return Err(AutomationError::MissingPath(keyword.to_string()));
+        }
+
+        let path = expand_home(argument, home);
+        if path.is_relative() {
+            return Err(AutomationError::RelativePath(argument.to_string()));
+        }
+        Ok(Self::new(verb, path))
+    }
+
+    pub fn to_line(&self) -> String {
+        format!("{} {}\n", self.verb.keyword(), self.path.display())
+    }
+}
+
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AutomationResponse {
+    Ok,
+    Error(String),
+}
+
+impl AutomationResponse {
+    pub fn parse(line: &str) -> AutomationResult<Self> {
+        let line = line.trim();
+        if line == "ok" {
+            return Ok(AutomationResponse::Ok);
+        }
+        match line.strip_prefix("error") {
+            Some(message) => Ok(AutomationResponse::Error(message.trim().to_string())),
+            None => Err(AutomationError::InvalidResponse(line.to_string())),
+        }
+    }
+
+    pub fn to_line(&self) -> String {
+        match self {
+            AutomationResponse::Ok => "ok\n".to_string(),
+            AutomationResponse::Error(message) => format!("error {}\n", message),
+        }
+    }
+}
+
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AutomationAction {
+    Navigate(PathBuf),
+    Select { directory: PathBuf, target: PathBuf },
+    NewTab(PathBuf),
+    Reveal(PathBuf),
+    NewWindow(LaunchTarget),
+}
+
+
+pub fn resolve_action(command: &AutomationCommand) -> AutomationResult<AutomationAction> {
+    let path = &command.path;
+    let metadata = match fs::metadata(path) {
+        Ok(metadata) => metadata,
+        Err(_) if command.verb == AutomationVerb::NewWindow => {
+            return Ok(AutomationAction::NewWindow(new_window_target(path)));
+        }
+        Err(_) => return Err(AutomationError::NotFound(path.display().to_string())),
+    };
+    let select = || match path.parent() {
+        Some(parent) => AutomationAction::Select {
+            directory: parent.to_path_buf(),
+            target: path.clone(),
+        },
+        None => AutomationAction::Navigate(path.clone()),
+    };
+
+    Ok(match command.verb {
+        AutomationVerb::Open if metadata.is_dir() => AutomationAction::Navigate(path.clone()),
+        AutomationVerb::Open | AutomationVerb::Select => select(),
+        AutomationVerb::NewTab if metadata.is_dir() => AutomationAction::NewTab(path.clone()),
+        AutomationVerb::NewTab => {
+            AutomationAction::NewTab(path.parent().unwrap_or(path).to_path_buf())
+        }
+        AutomationVerb::Reveal => AutomationAction::Reveal(path.clone()),
+        AutomationVerb::NewWindow => AutomationAction::NewWindow(new_window_target(path)),
+    })
+}
+
+
+fn new_window_target(path: &Path) -> LaunchTarget {
+    let home = dirs::home_dir().unwrap_or_else(|| PathBuf::from("/"));
+    resolve_launch_path(Some(path), &LaunchLocation::Home, None, &home)
+}
+
+
+pub fn parse_cli_args(args: &[String], cwd: &Path) -> Option<AutomationCommand> {
+    let first = args.first()?;
+    let Some(verb) = AutomationVerb::from_keyword(first) else {
+        if first.starts_with('-') {
+            return None;
+        }
+        let path = cwd.join(expand_home(first, dirs::home_dir().as_deref()));
+        return Some(AutomationCommand::new(AutomationVerb::NewWindow, path));
+    };
+    let path = match args.get(1) {
+        Some(argument) => cwd.join(expand_home(argument, dirs::home_dir().as_deref())),
+        None => cwd.to_path_buf(),
+    };
+    Some(AutomationCommand::new(verb, path))
+}
+
+
+pub fn automation_socket_path() -> PathBuf {
+    dirs::runtime_dir()
+        .or_else(dirs::cache_dir)
+        .unwrap_or_else(std::env::temp_dir)
+        .join(AUTOMATION_SOCKET_NAME)
+}
+
+
+#[cfg(unix)]
+pub fn send_command(
+    socket: &Path,
+    command: &AutomationCommand,
+) -> AutomationResult<AutomationResponse> {
+    let io_error = |e: std::io::Error| AutomationError::Io(e.to_string());
+    let mut stream = UnixStream::connect(socket).map_err(io_error)?;
+    stream
+        .write_all(command.to_line().as_bytes())
+        .map_err(io_error)?;
+    stream
+        .shutdown(std::net::Shutdown::Write)
+        .map_err(io_error)?;
+
+    let mut line = String::new();
+    BufReader::new(stream)
+        .read_line(&mut line)
+        .map_err(io_error)?;
+    AutomationResponse::parse(&line)
+}
+
+
+#[cfg(unix)]
+pub struct AutomationServer {
+    path: PathBuf,
+}
+
+#[cfg(unix)]
+impl AutomationServer {
+    pub fn start(
+        path: PathBuf,
+        sender: flume::Sender<AutomationAction>,
+    ) -> std::io::Result<Self> {
+        if path.exists() {
+            if UnixStream::connect(&path).is_ok() {
+                return Err(std::io::ErrorKind::AddrInUse.into());
+            }
+            fs::remove_file(&path)?;
+        }
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let listener = UnixListener::bind(&path)?;
+        std::thread::Builder::new()
+            .name("automation".to_string())
+            .spawn(move || {
+                for stream in listener.incoming().flatten() {
+                    if stream.set_read_timeout(Some(AUTOMATION_READ_TIMEOUT)).is_err() {
+                        continue;
+                    }
+                    let sender = sender.clone();
+                    let _ = std::thread::Builder::new()
+                        .name("automation-client".to_string())
+                        .spawn(move || handle_connection(stream, &sender));
+                }
+            })?;
+        Ok(Self { path })
+    }
+}
+
+#[cfg(unix)]
+impl Drop for AutomationServer {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+
+#[cfg(unix)]
+fn handle_connection(stream: UnixStream, sender: &flume::Sender<AutomationAction>) {
+    let Ok(mut writer) = stream.try_clone() else {
+        return;
+    };
+    let home = dirs::home_dir();
+    for line in BufReader::new(stream).lines() {
+        let Ok(line) = line else {
+            break;
+        };
+        if line.trim().is_empty() {
+            continue;
+        }
+        let response = match AutomationCommand::parse(&line, home.as_deref())
+            .and_then(|command| resolve_action(&command))
+        {
+            Ok(action) => match sender.send(action) {
+                Ok(()) => AutomationResponse::Ok,
+                Err(_) => AutomationResponse::Error("Explorer is shutting down".to_string()),
+            },
+            Err(e) => AutomationResponse::Error(e.to_string()),
+        };
+        if writer.write_all(response.to_line().as_bytes()).is_err() {
+            break;
+        }
+    }
+}
+
+fn expand_home(argument: &str, home: Option<&Path>) -> PathBuf {
+    match (argument, home) {
+        ("~", Some(home)) => home.to_path_buf(),
+        (_, Some(home)) if argument.starts_with("~/") => home.join(&argument[2..]),
+        _ => PathBuf::from(argument),
+    }
+}
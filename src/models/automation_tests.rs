@@ -0,0 +1,173 @@
+use super::automation::*;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tempfile::TempDir;
+
+
+fn parse(line: &str) -> AutomationResult<AutomationCommand> {
+    AutomationCommand::parse(line, Some(Path::new("/home/user")))
+}
+
+#[test]
+fn test_commands_parse_verb_and_path() {
+    assert_eq!(
+        parse("open /data/projects\n"),
+        Ok(AutomationCommand::new(
+            AutomationVerb::Open,
+            PathBuf::from("/data/projects")
+        ))
+    );
+    assert_eq!(
+        parse("new-tab ~/My Documents").unwrap(),
+        AutomationCommand::new(AutomationVerb::NewTab, PathBuf::from("/home/user/My Documents"))
+    );
+    assert_eq!(parse("reveal ~").unwrap().path, PathBuf::from("/home/user"));
+    assert_eq!(parse("select /tmp/a.txt").unwrap().verb, AutomationVerb::Select);
+}
+
+#[test]
+fn test_malformed_commands_are_rejected() {
+    assert_eq!(parse("   "), Err(AutomationError::Empty));
+    assert_eq!(
+        parse("delete /tmp"),
+        Err(AutomationError::UnknownVerb("delete".to_string()))
+    );
+    assert_eq!(
+        parse("open"),
+        Err(AutomationError::MissingPath("open".to_string()))
+    );
+    assert_eq!(
+        parse("open projects"),
+        Err(AutomationError::RelativePath("projects".to_string()))
+    );
+}
+
+#[test]
+fn test_command_and_response_lines_round_trip() {
+    let command = AutomationCommand::new(AutomationVerb::Reveal, PathBuf::from("/data/a b.txt"));
+    assert_eq!(command.to_line(), "reveal /data/a b.txt\n");
+    assert_eq!(parse(&command.to_line()), Ok(command));
+
+    for response in [
+        AutomationResponse::Ok,
+        AutomationResponse::Error("Path not found: /nope".to_string()),
+    ] {
+        assert_eq!(AutomationResponse::parse(&response.to_line()), Ok(response));
+    }
+    assert!(AutomationResponse::parse("maybe").is_err());
+}
+
+#[test]
+fn test_cli_args_resolve_against_working_directory() {
+    let args = |values: &[&str]| values.iter().map(|v| v.to_string()).collect::<Vec<_>>();
+    let cwd = Path::new("/work");
+
+    assert_eq!(
+        parse_cli_args(&args(&["open", "src"]), cwd),
+        Some(AutomationCommand::new(AutomationVerb::Open, PathBuf::from("/work/src")))
+    );
+    assert_eq!(
+        parse_cli_args(&args(&["reveal", "/etc/hosts"]), cwd),
+        Some(AutomationCommand::new(AutomationVerb::Reveal, PathBuf::from("/etc/hosts")))
+    );
+    assert_eq!(
+        parse_cli_args(&args(&["new-tab"]), cwd).map(|c| c.path),
+        Some(PathBuf::from("/work"))
+    );
+    assert_eq!(
+        parse_cli_args(&args(&["photos"]), cwd),
+        Some(AutomationCommand::new(AutomationVerb::NewWindow, PathBuf::from("/work/photos")))
+    );
+    assert_eq!(parse_cli_args(&args(&["--verbose"]), cwd), None);
+    assert_eq!(parse_cli_args(&[], cwd), None);
+}
+
+#[test]
+fn test_commands_dispatch_to_workspace_actions() {
+    let root = TempDir::new().unwrap();
+    let dir = root.path().join("projects");
+    let file = dir.join("notes.txt");
+    fs::create_dir(&dir).unwrap();
+    fs::write(&file, b"notes").unwrap();
+    let resolve = |verb, path: &Path| resolve_action(&AutomationCommand::new(verb, path.to_path_buf()));
+
+    assert_eq!(
+        resolve(AutomationVerb::Open, &dir),
+        Ok(AutomationAction::Navigate(dir.clone()))
+    );
+    let select_file = AutomationAction::Select {
+        directory: dir.clone(),
+        target: file.clone(),
+    };
+    assert_eq!(resolve(AutomationVerb::Open, &file), Ok(select_file.clone()));
+    assert_eq!(resolve(AutomationVerb::Select, &file), Ok(select_file));
+    assert_eq!(
+        resolve(AutomationVerb::NewTab, &file),
+        Ok(AutomationAction::NewTab(dir.clone()))
+    );
+    assert_eq!(
+        resolve(AutomationVerb::Reveal, &dir),
+        Ok(AutomationAction::Reveal(dir.clone()))
+    );
+    assert!(matches!(
+        resolve(AutomationVerb::NewWindow, &file),
+        Ok(AutomationAction::NewWindow(target)) if target.path == dir && target.missing.is_none()
+    ));
+    assert!(matches!(
+        resolve(AutomationVerb::NewWindow, &dir.join("missing")),
+        Ok(AutomationAction::NewWindow(target)) if target.missing == Some(dir.join("missing"))
+    ));
+    assert!(matches!(
+        resolve(AutomationVerb::Open, &dir.join("missing")),
+        Err(AutomationError::NotFound(_))
+    ));
+}
+
+#[cfg(unix)]
+#[test]
+fn test_server_answers_and_forwards_actions() {
+    let root = TempDir::new().unwrap();
+    let socket = root.path().join("run/explorer.sock");
+    let (sender, receiver) = flume::unbounded();
+    let server = AutomationServer::start(socket.clone(), sender).unwrap();
+
+    let open = AutomationCommand::new(AutomationVerb::Open, root.path().to_path_buf());
+    assert_eq!(send_command(&socket, &open), Ok(AutomationResponse::Ok));
+    assert_eq!(
+        receiver.recv().unwrap(),
+        AutomationAction::Navigate(root.path().to_path_buf())
+    );
+
+    let missing = AutomationCommand::new(AutomationVerb::Select, root.path().join("missing"));
+    assert!(matches!(
+        send_command(&socket, &missing),
+        Ok(AutomationResponse::Error(_))
+    ));
+    assert!(receiver.try_recv().is_err());
+
+    let (other, _) = flume::unbounded();
+    assert!(AutomationServer::start(socket.clone(), other).is_err());
+
+    drop(server);
+    assert!(!socket.exists());
+}
+
+#[cfg(unix)]
+#[test]
+fn test_idle_client_does_not_block_other_commands() {
+    let root = TempDir::new().unwrap();
+    let socket = root.path().join("explorer.sock");
+    let (sender, receiver) = flume::unbounded();
+    let _server = AutomationServer::start(socket.clone(), sender).unwrap();
+
+    let _idle = std::os::unix::net::UnixStream::connect(&socket).unwrap();
+
+    let open = AutomationCommand::new(AutomationVerb::Open, root.path().to_path_buf());
+    let started = std::time::Instant::now();
+    assert_eq!(send_command(&socket, &open), Ok(AutomationResponse::Ok));
+    assert!(started.elapsed() < AUTOMATION_READ_TIMEOUT);
+    assert_eq!(
+        receiver.recv().unwrap(),
+        AutomationAction::Navigate(root.path().to_path_buf())
+    );
+}
@@ -0,0 +1,70 @@
+use crate::models::FileOperation;
+
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ActiveOperation {
+    pub label: String,
+    pub progress: Option<f32>,
+}
+
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BackgroundActivity {
+    operations: Vec<ActiveOperation>,
+}
+
+impl BackgroundActivity {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn from_operations(operations: &[FileOperation]) -> Self {
+        let mut activity = Self::new();
+        for op in operations.iter().filter(|op| op.status.is_active()) {
+            activity.push(op.op_type.to_string(), Some(op.progress.percentage() / 100.0));
+        }
+        activity
+    }
+
+    pub fn push(&mut self, label: impl Into<String>, progress: Option<f32>) {
+        self.operations.push(ActiveOperation {
+            label: label.into(),
+            progress: progress.map(|p| p.clamp(0.0, 1.0)),
+        });
+    }
+
+    pub fn extend(&mut self, other: BackgroundActivity) {
+        self.operations.extend(other.operations);
+    }
+
+    pub fn operations(&self) -> &[ActiveOperation] {
+        &self.operations
+    }
+
+    pub fn count(&self) -> usize {
+        self.operations.len()
+    }
+
+    pub fn is_idle(&self) -> bool {
+        self.operations.is_empty()
+    }
+
+    pub fn progress(&self) -> Option<f32> {
+        let known: Vec<f32> = self.operations.iter().filter_map(|op| op.progress).collect();
+        if known.is_empty() {
+            return None;
+        }
+        Some(known.iter().sum::<f32>() / known.len() as f32)
+    }
+
+    pub fn summary(&self) -> Option<String> {
+        match self.operations.as_slice() {
+            [] => None,
+            [single] => Some(match single.progress {
+                Some(progress) => format!("{} {:.0}%", single.label, progress * 100.0),
+                None => single.label.clone(),
+            }),
+            many => Some(format!("{} operations running", many.len())),
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use super::background_activity::*;
+use crate::models::{FileOperation, OperationId, OperationStatus, OperationType};
+use std::path::PathBuf;
+
+
+fn operation(id: u64, op_type: OperationType, status: OperationStatus) -> FileOperation {
+    let mut op = FileOperation::new(
+        OperationId(id),
+        op_type,
+        vec![PathBuf::from("/tmp/source")],
+        Some(PathBuf::from("/tmp/dest")),
+    );
+    op.status = status;
+    op
+}
+
+#[test]
+fn test_single_operation_summarized_with_progress() {
+    let mut activity = BackgroundActivity::new();
+    assert!(activity.is_idle());
+    assert_eq!(activity.summary(), None);
+
+    activity.push("Copying", Some(0.42));
+    assert_eq!(activity.summary().as_deref(), Some("Copying 42%"));
+
+    let mut thumbnails = BackgroundActivity::new();
+    thumbnails.push("Generating thumbnails", None);
+    assert_eq!(thumbnails.summary().as_deref(), Some("Generating thumbnails"));
+    assert_eq!(thumbnails.progress(), None);
+}
+
+#[test]
+fn test_concurrent_operations_aggregated() {
+    let operations = vec![
+        operation(1, OperationType::Copy, OperationStatus::Running),
+        operation(2, OperationType::Compress, OperationStatus::Pending),
+        operation(3, OperationType::Delete, OperationStatus::Completed),
+    ];
+    let mut activity = BackgroundActivity::from_operations(&operations);
+    assert_eq!(activity.count(), 2);
+
+    activity.push("Searching", None);
+    assert_eq!(activity.count(), 3);
+    assert_eq!(activity.summary().as_deref(), Some("3 operations running"));
+    assert!(activity.progress().is_some());
+}
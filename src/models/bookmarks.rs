@@ -2,6 +2,9 @@ use serde::{Deserialize, Serialize};
 use std::collections::VecDeque;
 use std::fs;
 use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
 use thiserror::Error;
 
 
@@ -11,6 +14,15 @@ pub const MAX_BOOKMARKS: usize = 50;
 pub const MAX_RECENT_LOCATIONS: usize = 20;
 
 
+pub const BOOKMARK_CHECK_TIMEOUT: Duration = Duration::from_secs(2);
+
+
+pub const BOOKMARK_CHECK_WORKERS: usize = 4;
+
+
+pub const BOOKMARK_CHECK_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct BookmarkId(pub u64);
 
@@ -313,6 +325,47 @@ impl BookmarkManager {
     }
 
 
+    pub fn paths_to_check(&self) -> Vec<(BookmarkId, PathBuf)> {
+        self.bookmarks
+            .iter()
+            .map(|b| (b.id, b.path.clone()))
+            .collect()
+    }
+
+
+    pub fn apply_validity(&mut self, results: &[(BookmarkId, bool)]) -> bool {
+        let mut changed = false;
+        for (id, is_valid) in results {
+            if let Some(bookmark) = self.bookmarks.iter_mut().find(|b| b.id == *id) {
+                if bookmark.is_valid != *is_valid {
+                    bookmark.is_valid = *is_valid;
+                    changed = true;
+                }
+            }
+        }
+        changed
+    }
+
+
+    pub fn repoint(&mut self, id: BookmarkId, path: PathBuf) -> Result<(), BookmarkError> {
+        if self.bookmarks.iter().any(|b| b.path == path && b.id != id) {
+            return Err(BookmarkError::AlreadyExists);
+        }
+
+        if !path.exists() {
+            return Err(BookmarkError::InvalidPath(path.display().to_string()));
+        }
+
+        if let Some(bookmark) = self.bookmarks.iter_mut().find(|b| b.id == id) {
+            bookmark.path = path;
+            bookmark.is_valid = true;
+            Ok(())
+        } else {
+            Err(BookmarkError::NotFound(id.0))
+        }
+    }
+
+
     pub fn recent(&self) -> &VecDeque<PathBuf> {
         &self.recent_locations
     }
@@ -381,12 +434,85 @@ impl BookmarkManager {
 
         manager.next_id = manager.bookmarks.iter().map(|b| b.id.0).max().unwrap_or(0) + 1;
 
-        manager.validate_all();
-
         Ok(manager)
     }
 }
 
+
+pub struct BookmarkCheck {
+    results: Vec<(BookmarkId, bool)>,
+    answered: usize,
+    receiver: flume::Receiver<(BookmarkId, bool)>,
+    deadline: Instant,
+}
+
+impl BookmarkCheck {
+    pub fn start(paths: Vec<(BookmarkId, PathBuf)>, timeout: Duration) -> Self {
+        let results = paths.iter().map(|(id, _)| (*id, false)).collect();
+        let workers = paths.len().min(BOOKMARK_CHECK_WORKERS);
+        let queue = Arc::new(Mutex::new(VecDeque::from(paths)));
+        let (tx, receiver) = flume::unbounded();
+        for _ in 0..workers {
+            let queue = queue.clone();
+            let tx = tx.clone();
+            thread::spawn(move || {
+                while let Some((id, path)) = queue.lock().ok().and_then(|mut q| q.pop_front()) {
+                    if tx.send((id, path.exists())).is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+
+        Self {
+            results,
+            answered: 0,
+            receiver,
+            deadline: Instant::now() + timeout,
+        }
+    }
+
+    pub fn poll(&mut self) -> bool {
+        while let Ok(answer) = self.receiver.try_recv() {
+            self.record(answer);
+        }
+        self.is_finished()
+    }
+
+    pub fn wait(mut self) -> Vec<(BookmarkId, bool)> {
+        while !self.is_finished() {
+            match self.receiver.recv_deadline(self.deadline) {
+                Ok(answer) => self.record(answer),
+                Err(_) => break,
+            }
+        }
+        self.results
+    }
+
+    pub fn into_results(self) -> Vec<(BookmarkId, bool)> {
+        self.results
+    }
+
+    fn record(&mut self, (id, exists): (BookmarkId, bool)) {
+        if let Some(result) = self.results.iter_mut().find(|(checked, _)| *checked == id) {
+            result.1 = exists;
+            self.answered += 1;
+        }
+    }
+
+    fn is_finished(&self) -> bool {
+        self.answered >= self.results.len() || Instant::now() >= self.deadline
+    }
+}
+
+
+pub fn check_bookmark_paths(
+    paths: Vec<(BookmarkId, PathBuf)>,
+    timeout: Duration,
+) -> Vec<(BookmarkId, bool)> {
+    BookmarkCheck::start(paths, timeout).wait()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -515,6 +641,94 @@ mod tests {
         assert!(display.contains("Ctrl"));
     }
 
+    #[test]
+    fn test_validity_refresh_flags_missing_paths() {
+        let dir = tempfile::tempdir().unwrap();
+        let kept = dir.path().join("kept");
+        let moved = dir.path().join("moved");
+        fs::create_dir(&kept).unwrap();
+        fs::create_dir(&moved).unwrap();
+
+        let mut manager = BookmarkManager::new();
+        let kept_id = manager.add(kept).unwrap();
+        let moved_id = manager.add(moved.clone()).unwrap();
+        fs::remove_dir(&moved).unwrap();
+
+        let results = check_bookmark_paths(manager.paths_to_check(), BOOKMARK_CHECK_TIMEOUT);
+        assert_eq!(results.len(), 2);
+        assert!(manager.apply_validity(&results));
+        assert!(manager.get(kept_id).unwrap().is_valid);
+        assert!(!manager.get(moved_id).unwrap().is_valid);
+
+        assert!(!manager.apply_validity(&results));
+
+        fs::create_dir(&moved).unwrap();
+        let results = check_bookmark_paths(manager.paths_to_check(), BOOKMARK_CHECK_TIMEOUT);
+        assert!(manager.apply_validity(&results));
+        assert!(manager.get(moved_id).unwrap().is_valid);
+    }
+
+    #[test]
+    fn test_checks_more_bookmarks_than_workers() {
+        let dir = tempfile::tempdir().unwrap();
+        let paths: Vec<_> = (0..BOOKMARK_CHECK_WORKERS as u64 * 3)
+            .map(|i| (BookmarkId::new(i), dir.path().join(format!("missing-{i}"))))
+            .chain(std::iter::once((BookmarkId::new(99), dir.path().to_path_buf())))
+            .collect();
+
+        let mut check = BookmarkCheck::start(paths, BOOKMARK_CHECK_TIMEOUT);
+        while !check.poll() {
+            thread::yield_now();
+        }
+        let results = check.into_results();
+
+        assert_eq!(results.len(), BOOKMARK_CHECK_WORKERS * 3 + 1);
+        assert!(results.iter().all(|(id, exists)| *exists == (*id == BookmarkId::new(99))));
+    }
+
+    #[test]
+    fn test_unanswered_checks_count_as_invalid() {
+        let paths = vec![(BookmarkId::new(1), temp_path())];
+        let results = check_bookmark_paths(paths, Duration::ZERO);
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].0, BookmarkId::new(1));
+        assert!(!results[0].1);
+    }
+
+    #[test]
+    fn test_repoint_broken_bookmark() {
+        let dir = tempfile::tempdir().unwrap();
+        let old = dir.path().join("old");
+        let new = dir.path().join("new");
+        fs::create_dir(&old).unwrap();
+
+        let mut manager = BookmarkManager::new();
+        let id = manager.add(old.clone()).unwrap();
+        let other = manager.add(dir.path().to_path_buf()).unwrap();
+        fs::rename(&old, &new).unwrap();
+        manager.apply_validity(&[(id, false)]);
+
+        assert!(matches!(
+            manager.repoint(id, old),
+            Err(BookmarkError::InvalidPath(_))
+        ));
+        assert_eq!(
+            manager.repoint(id, dir.path().to_path_buf()),
+            Err(BookmarkError::AlreadyExists)
+        );
+        assert_eq!(
+            manager.repoint(BookmarkId::new(99), new.clone()),
+            Err(BookmarkError::NotFound(99))
+        );
+
+        assert!(manager.repoint(id, new.clone()).is_ok());
+        let bookmark = manager.get(id).unwrap();
+        assert_eq!(bookmark.path, new);
+        assert!(bookmark.is_valid);
+        assert!(manager.get(other).is_some());
+    }
+
     #[test]
     fn test_serialization_round_trip() {
         let mut manager = BookmarkManager::new();
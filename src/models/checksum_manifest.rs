@@ -0,0 +1,204 @@
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use thiserror::Error;
+
+use crate::models::{calculate_file_hash, detect_algorithm, is_valid_hex, HashAlgorithm};
+
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum ManifestError {
+    #[error("Manifest contains no checksums")]
+    Empty,
+
+    #[error("Line {line}: not a checksum entry")]
+    Malformed { line: usize },
+
+    #[error("Line {line}: digest does not match {algorithm}")]
+    MixedAlgorithms { line: usize, algorithm: &'static str },
+
+    #[error("Unrecognized digest length")]
+    UnknownAlgorithm,
+}
+
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ManifestEntry {
+    pub digest: String,
+    pub file_name: String,
+}
+
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChecksumManifest {
+    pub algorithm: HashAlgorithm,
+    pub entries: Vec<ManifestEntry>,
+}
+
+
+pub fn manifest_algorithm_hint(path: &Path) -> Option<HashAlgorithm> {
+    let name = path.file_name()?.to_str()?.to_lowercase();
+    let stem = name
+        .rsplit('.')
+        .next()
+        .unwrap_or(&name)
+        .trim_end_matches("sums")
+        .trim_end_matches("sum");
+    match stem {
+        "md5" => Some(HashAlgorithm::Md5),
+        "sha1" => Some(HashAlgorithm::Sha1),
+        "sha256" => Some(HashAlgorithm::Sha256),
+        "sha512" => Some(HashAlgorithm::Sha512),
+        _ => None,
+    }
+}
+
+
+pub fn is_checksum_manifest(path: &Path) -> bool {
+    manifest_algorithm_hint(path).is_some()
+}
+
+
+pub fn parse_manifest(
+    content: &str,
+    hint: Option<HashAlgorithm>,
+) -> Result<ChecksumManifest, ManifestError> {
+    let mut algorithm = hint;
+    let mut entries = Vec::new();
+
+    for (index, raw) in content.lines().enumerate() {
+        let line = raw.trim_end_matches('\r').trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let number = index + 1;
+        let (digest, file_name) =
+            parse_line(line).ok_or(ManifestError::Malformed { line: number })?;
+
+        let detected = detect_algorithm(digest).ok_or(ManifestError::UnknownAlgorithm)?;
+        match algorithm {
+            Some(expected) if expected != detected => {
+                return Err(ManifestError::MixedAlgorithms {
+                    line: number,
+                    algorithm: expected.display_name(),
+                });
+            }
+            _ => algorithm = Some(detected),
+        }
+
+        entries.push(ManifestEntry {
+            digest: digest.to_lowercase(),
+            file_name: file_name.to_string(),
+        });
+    }
+
+    match algorithm {
+        Some(algorithm) if !entries.is_empty() => Ok(ChecksumManifest { algorithm, entries }),
+        _ => Err(ManifestError::Empty),
+    }
+}
+
+fn parse_line(line: &str) -> Option<(&str, &str)> {
+    if let Some((prefix, digest)) = line.rsplit_once(" = ") {
+        let open = prefix.find(" (")?;
+        let file_name = prefix[open + 2..].strip_suffix(')')?;
+        let digest = digest.trim();
+        return (is_valid_hex(digest) && !file_name.is_empty()).then_some((digest, file_name));
+    }
+
+    let (digest, rest) = line.split_once(char::is_whitespace)?;
+    let file_name = rest.trim_start();
+    let file_name = file_name.strip_prefix('*').unwrap_or(file_name);
+    (is_valid_hex(digest) && !file_name.is_empty()).then_some((digest, file_name))
+}
+
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VerifyStatus {
+    Match,
+    Mismatch { actual: String },
+    Missing,
+    Unreadable(String),
+}
+
+impl VerifyStatus {
+    pub fn label(&self) -> &'static str {
+        match self {
+            VerifyStatus::Match => "OK",
+            VerifyStatus::Mismatch { .. } => "Mismatch",
+            VerifyStatus::Missing => "Missing",
+            VerifyStatus::Unreadable(_) => "Unreadable",
+        }
+    }
+}
+
+
+pub fn classify_digest(expected: &str, actual: &str) -> VerifyStatus {
+    if expected.trim().eq_ignore_ascii_case(actual.trim()) {
+        VerifyStatus::Match
+    } else {
+        VerifyStatus::Mismatch {
+            actual: actual.to_lowercase(),
+        }
+    }
+}
+
+
+pub fn verify_entry(algorithm: HashAlgorithm, entry: &ManifestEntry, directory: &Path) -> VerifyStatus {
+    let path = resolve_entry_path(directory, &entry.file_name);
+    if !path.is_file() {
+        return VerifyStatus::Missing;
+    }
+    match calculate_file_hash(&path, algorithm) {
+        Ok(actual) => classify_digest(&entry.digest, &actual),
+        Err(e) => VerifyStatus::Unreadable(e.to_string()),
+    }
+}
+
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct VerifySummary {
+    pub matched: usize,
+    pub mismatched: usize,
+    pub missing: usize,
+    pub unreadable: usize,
+}
+
+impl VerifySummary {
+    pub fn record(&mut self, status: &VerifyStatus) {
+        match status {
+            VerifyStatus::Match => self.matched += 1,
+            VerifyStatus::Mismatch { .. } => self.mismatched += 1,
+            VerifyStatus::Missing => self.missing += 1,
+            VerifyStatus::Unreadable(_) => self.unreadable += 1,
+        }
+    }
+
+    pub fn checked(&self) -> usize {
+        self.matched + self.mismatched + self.missing + self.unreadable
+    }
+
+    pub fn all_matched(&self) -> bool {
+        self.checked() > 0 && self.matched == self.checked()
+    }
+}
+
+
+pub fn verify_manifest(
+    manifest: &ChecksumManifest,
+    directory: &Path,
+    cancel: &AtomicBool,
+    mut on_checked: impl FnMut(usize, VerifyStatus),
+) -> bool {
+    for (index, entry) in manifest.entries.iter().enumerate() {
+        if cancel.load(Ordering::Relaxed) {
+            return false;
+        }
+        on_checked(index, verify_entry(manifest.algorithm, entry, directory));
+    }
+    !cancel.load(Ordering::Relaxed)
+}
+
+fn resolve_entry_path(directory: &Path, file_name: &str) -> PathBuf {
+    directory.join(file_name.trim_start_matches("./"))
+}
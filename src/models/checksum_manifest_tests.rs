@@ -0,0 +1,126 @@
+use super::checksum_manifest::*;
+use crate::models::HashAlgorithm;
+use std::fs;
+use std::path::Path;
+use std::sync::atomic::AtomicBool;
+use tempfile::TempDir;
+
+
+const HELLO_SHA256: &str = "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824";
+const WORLD_SHA256: &str = "486ea46224d1bb4fb680f34f7c9ad96a8f24ec88be73ea8e5a6c65260e9cb8a7";
+const HELLO_MD5: &str = "5d41402abc4b2a76b9719d911017c592";
+
+#[test]
+fn test_parse_gnu_and_bsd_manifest_lines() {
+    let content = format!(
+        "# release artifacts\n{}  hello.txt\n{} *world.bin\r\n\nSHA256 (dir/notes v2.txt) = {}\n",
+        HELLO_SHA256,
+        WORLD_SHA256.to_uppercase(),
+        HELLO_SHA256
+    );
+    let manifest = parse_manifest(&content, None).unwrap();
+
+    assert_eq!(manifest.algorithm, HashAlgorithm::Sha256);
+    let names: Vec<_> = manifest.entries.iter().map(|e| e.file_name.as_str()).collect();
+    assert_eq!(names, vec!["hello.txt", "world.bin", "dir/notes v2.txt"]);
+    assert_eq!(manifest.entries[1].digest, WORLD_SHA256);
+
+    let md5 = parse_manifest(&format!("{}  hello.txt\n", HELLO_MD5), None).unwrap();
+    assert_eq!(md5.algorithm, HashAlgorithm::Md5);
+}
+
+#[test]
+fn test_parse_rejects_malformed_manifests() {
+    assert_eq!(parse_manifest("\n# nothing\n", None), Err(ManifestError::Empty));
+    assert_eq!(
+        parse_manifest(&format!("{}  a\nnot a checksum\n", HELLO_MD5), None),
+        Err(ManifestError::Malformed { line: 2 })
+    );
+    assert_eq!(
+        parse_manifest(&format!("{}  a\n", HELLO_MD5), Some(HashAlgorithm::Sha256)),
+        Err(ManifestError::MixedAlgorithms {
+            line: 1,
+            algorithm: "SHA-256"
+        })
+    );
+    assert_eq!(
+        parse_manifest("abc123  a\n", None),
+        Err(ManifestError::UnknownAlgorithm)
+    );
+}
+
+#[test]
+fn test_manifest_names_hint_algorithm() {
+    assert_eq!(
+        manifest_algorithm_hint(Path::new("/dl/app.tar.gz.sha256")),
+        Some(HashAlgorithm::Sha256)
+    );
+    assert_eq!(
+        manifest_algorithm_hint(Path::new("SHA512SUMS")),
+        Some(HashAlgorithm::Sha512)
+    );
+    assert_eq!(
+        manifest_algorithm_hint(Path::new("files.md5")),
+        Some(HashAlgorithm::Md5)
+    );
+    assert!(!is_checksum_manifest(Path::new("notes.txt")));
+}
+
+#[test]
+fn test_classification_against_known_digests() {
+    let dir = TempDir::new().unwrap();
+    fs::write(dir.path().join("hello.txt"), "hello").unwrap();
+    fs::write(dir.path().join("world.bin"), "tampered").unwrap();
+
+    assert_eq!(classify_digest(HELLO_SHA256, &HELLO_SHA256.to_uppercase()), VerifyStatus::Match);
+    assert_eq!(
+        classify_digest(HELLO_SHA256, WORLD_SHA256),
+        VerifyStatus::Mismatch {
+            actual: WORLD_SHA256.to_string()
+        }
+    );
+
+    let manifest = parse_manifest(
+        &format!(
+            "{}  ./hello.txt\n{}  world.bin\n{}  gone.iso\n",
+            HELLO_SHA256, WORLD_SHA256, HELLO_SHA256
+        ),
+        None,
+    )
+    .unwrap();
+    let mut results = Vec::new();
+    let mut summary = VerifySummary::default();
+    let completed = verify_manifest(&manifest, dir.path(), &AtomicBool::new(false), |index, status| {
+        summary.record(&status);
+        results.push((index, status));
+    });
+
+    assert!(completed);
+    assert_eq!(results[0], (0, VerifyStatus::Match));
+    assert!(matches!(results[1], (1, VerifyStatus::Mismatch { .. })));
+    assert_eq!(results[2], (2, VerifyStatus::Missing));
+    assert_eq!(
+        summary,
+        VerifySummary {
+            matched: 1,
+            mismatched: 1,
+            missing: 1,
+            unreadable: 0,
+        }
+    );
+    assert!(!summary.all_matched());
+}
+
+#[test]
+fn test_cancelled_verification_stops_early() {
+    let dir = TempDir::new().unwrap();
+    let manifest = parse_manifest(&format!("{}  a\n{}  b\n", HELLO_MD5, HELLO_MD5), None).unwrap();
+    let mut checked = 0;
+
+    let completed = verify_manifest(&manifest, dir.path(), &AtomicBool::new(true), |_, _| {
+        checked += 1;
+    });
+
+    assert!(!completed);
+    assert_eq!(checked, 0);
+}
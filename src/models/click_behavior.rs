@@ -0,0 +1,115 @@
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU8, Ordering};
+
+use gpui::Modifiers;
+use serde::{Deserialize, Serialize};
+
+
+static CLICK_BEHAVIOR: AtomicU8 = AtomicU8::new(0);
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ClickBehavior {
+    #[default]
+    DoubleClick,
+    SingleClick,
+}
+
+impl ClickBehavior {
+    pub fn label(&self) -> &'static str {
+        match self {
+            ClickBehavior::DoubleClick => "Double-click to open",
+            ClickBehavior::SingleClick => "Single-click to open",
+        }
+    }
+
+    pub fn toggle(&self) -> Self {
+        match self {
+            ClickBehavior::DoubleClick => ClickBehavior::SingleClick,
+            ClickBehavior::SingleClick => ClickBehavior::DoubleClick,
+        }
+    }
+}
+
+
+pub fn set_click_behavior(behavior: ClickBehavior) {
+    let value = match behavior {
+        ClickBehavior::DoubleClick => 0,
+        ClickBehavior::SingleClick => 1,
+    };
+    CLICK_BEHAVIOR.store(value, Ordering::SeqCst);
+}
+
+
+pub fn click_behavior() -> ClickBehavior {
+    match CLICK_BEHAVIOR.load(Ordering::SeqCst) {
+        1 => ClickBehavior::SingleClick,
+        _ => ClickBehavior::DoubleClick,
+    }
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClickIntent {
+    Select,
+    Open,
+}
+
+
+pub fn click_intent(behavior: ClickBehavior, click_count: usize, modifiers: &Modifiers) -> ClickIntent {
+    if modifiers.shift || modifiers.secondary() {
+        return ClickIntent::Select;
+    }
+    let opens = match behavior {
+        ClickBehavior::DoubleClick => click_count == 2,
+        ClickBehavior::SingleClick => click_count == 1,
+    };
+    if opens {
+        ClickIntent::Open
+    } else {
+        ClickIntent::Select
+    }
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MiddleClickIntent {
+    OpenInBackgroundTab,
+    OpenInNewTab,
+    OpenExternally,
+}
+
+
+pub fn middle_click_intent(is_dir: bool, modifiers: &Modifiers) -> MiddleClickIntent {
+    if !is_dir {
+        MiddleClickIntent::OpenExternally
+    } else if modifiers.shift {
+        MiddleClickIntent::OpenInNewTab
+    } else {
+        MiddleClickIntent::OpenInBackgroundTab
+    }
+}
+
+
+pub fn toggle_marked(marked: &mut Vec<PathBuf>, current: Option<&Path>, path: PathBuf) {
+    if marked.is_empty() {
+        if let Some(current) = current.filter(|current| *current != path) {
+            marked.push(current.to_path_buf());
+        }
+    }
+    match marked.iter().position(|marked| *marked == path) {
+        Some(index) => {
+            marked.remove(index);
+        }
+        None => marked.push(path),
+    }
+}
+
+
+pub fn context_selection(marked: &[PathBuf], clicked: &Path) -> Vec<PathBuf> {
+    if marked.iter().any(|path| path == clicked) {
+        marked.to_vec()
+    } else {
+        vec![clicked.to_path_buf()]
+    }
+}
@@ -0,0 +1,89 @@
+use super::click_behavior::*;
+use gpui::Modifiers;
+use std::path::{Path, PathBuf};
+
+
+fn plain() -> Modifiers {
+    Modifiers::default()
+}
+
+#[test]
+fn test_double_click_mode_opens_only_on_second_click() {
+    let behavior = ClickBehavior::DoubleClick;
+
+    assert_eq!(click_intent(behavior, 1, &plain()), ClickIntent::Select);
+    assert_eq!(click_intent(behavior, 2, &plain()), ClickIntent::Open);
+    assert_eq!(click_intent(behavior, 3, &plain()), ClickIntent::Select);
+}
+
+#[test]
+fn test_single_click_mode_opens_on_first_click() {
+    let behavior = ClickBehavior::SingleClick;
+
+    assert_eq!(click_intent(behavior, 1, &plain()), ClickIntent::Open);
+    assert_eq!(click_intent(behavior, 2, &plain()), ClickIntent::Select);
+}
+
+#[test]
+fn test_selection_modifiers_never_open() {
+    let shift = Modifiers::shift();
+    let secondary = Modifiers::secondary_key();
+
+    for behavior in [ClickBehavior::SingleClick, ClickBehavior::DoubleClick] {
+        for count in 1..=2 {
+            assert_eq!(click_intent(behavior, count, &shift), ClickIntent::Select);
+            assert_eq!(click_intent(behavior, count, &secondary), ClickIntent::Select);
+        }
+    }
+}
+
+#[test]
+fn test_default_behavior_is_double_click() {
+    assert_eq!(ClickBehavior::default(), ClickBehavior::DoubleClick);
+    assert_eq!(ClickBehavior::DoubleClick.toggle(), ClickBehavior::SingleClick);
+    assert_eq!(ClickBehavior::SingleClick.toggle(), ClickBehavior::DoubleClick);
+}
+
+#[test]
+fn test_marking_extends_the_current_selection() {
+    let a = PathBuf::from("/data/a.txt");
+    let b = PathBuf::from("/data/b.txt");
+    let mut marked = Vec::new();
+
+    toggle_marked(&mut marked, Some(&a), b.clone());
+    assert_eq!(marked, vec![a.clone(), b.clone()]);
+
+    toggle_marked(&mut marked, Some(&b), a.clone());
+    assert_eq!(marked, vec![b.clone()]);
+
+    assert_eq!(context_selection(&marked, &b), vec![b.clone()]);
+    assert_eq!(
+        context_selection(&[a.clone(), b.clone()], Path::new("/data/b.txt")),
+        vec![a.clone(), b.clone()]
+    );
+    assert_eq!(context_selection(&[a.clone(), b], Path::new("/data/c.txt")).len(), 1);
+}
+
+#[test]
+fn test_middle_click_opens_directories_in_new_tab() {
+    assert_eq!(
+        middle_click_intent(true, &plain()),
+        MiddleClickIntent::OpenInBackgroundTab
+    );
+    assert_eq!(
+        middle_click_intent(true, &Modifiers::secondary_key()),
+        MiddleClickIntent::OpenInBackgroundTab
+    );
+    assert_eq!(
+        middle_click_intent(true, &Modifiers::shift()),
+        MiddleClickIntent::OpenInNewTab
+    );
+    assert_eq!(
+        middle_click_intent(false, &plain()),
+        MiddleClickIntent::OpenExternally
+    );
+    assert_eq!(
+        middle_click_intent(false, &Modifiers::shift()),
+        MiddleClickIntent::OpenExternally
+    );
+}
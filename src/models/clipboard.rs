@@ -1,10 +1,11 @@
 use std::collections::VecDeque;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 
 use flume::{Receiver, Sender};
+use gpui::Global;
 
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -329,6 +330,189 @@ impl Default for ClipboardManager {
 }
 
 
+#[derive(Debug, Clone, Default)]
+pub struct SharedClipboard {
+    operation: Option<ClipboardOperation>,
+    generation: u64,
+}
+
+impl Global for SharedClipboard {}
+
+impl SharedClipboard {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn set(&mut self, operation: Option<ClipboardOperation>) {
+        if self.operation != operation {
+            self.operation = operation;
+            self.generation += 1;
+        }
+    }
+
+    pub fn operation(&self) -> Option<&ClipboardOperation> {
+        self.operation.as_ref()
+    }
+
+    pub fn has_content(&self) -> bool {
+        self.operation.is_some()
+    }
+
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+}
+
+
+#[derive(Debug, Clone, Default)]
+pub struct ClipboardMirror {
+    operation: Option<ClipboardOperation>,
+    generation: u64,
+}
+
+impl ClipboardMirror {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+
+    pub fn sync(&mut self, shared: &SharedClipboard) -> bool {
+        if self.generation == shared.generation {
+            return false;
+        }
+        self.generation = shared.generation;
+        self.operation = shared.operation.clone();
+        true
+    }
+
+    pub fn operation(&self) -> Option<&ClipboardOperation> {
+        self.operation.as_ref()
+    }
+
+    pub fn can_paste(&self) -> bool {
+        self.operation.is_some()
+    }
+}
+
+
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PasteTarget {
+    Unchanged,
+    Destination(PathBuf),
+}
+
+
+pub fn paste_target(
+    source: &Path,
+    dest_dir: &Path,
+    is_move: bool,
+    duplicate_in_same_dir: bool,
+) -> PasteTarget {
+    let dest_path = match source.file_name() {
+        Some(name) => dest_dir.join(name),
+        None => return PasteTarget::Unchanged,
+    };
+
+    if source.parent() != Some(dest_dir) {
+        return PasteTarget::Destination(dest_path);
+    }
+    if is_move || !duplicate_in_same_dir {
+        return PasteTarget::Unchanged;
+    }
+    PasteTarget::Destination(unique_destination(&dest_path))
+}
+
+
+pub fn unique_destination(path: &Path) -> PathBuf {
+    let stem = path.file_stem().and_then(|s| s.to_str()).unwrap_or("file");
+    let ext = path.extension().and_then(|e| e.to_str());
+    let parent = path.parent().unwrap_or(path);
+
+    let mut counter = 1;
+    loop {
+        let new_name = if let Some(ext) = ext {
+            format!("{} ({}).{}", stem, counter, ext)
+        } else {
+            format!("{} ({})", stem, counter)
+        };
+        let new_path = parent.join(&new_name);
+        if !new_path.exists() {
+            return new_path;
+        }
+        counter += 1;
+    }
+}
+
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OverwriteItem {
+    pub destination: PathBuf,
+    pub size: u64,
+}
+
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OverwriteSummary {
+    pub items: Vec<OverwriteItem>,
+}
+
+impl OverwriteSummary {
+    pub fn from_conflicts(conflicts: &[(PathBuf, PathBuf)]) -> Self {
+        let mut items: Vec<OverwriteItem> = Vec::new();
+        for (_, destination) in conflicts {
+            if !destination.exists() || items.iter().any(|item| &item.destination == destination) {
+                continue;
+            }
+            items.push(OverwriteItem {
+                destination: destination.clone(),
+                size: disk_usage(destination),
+            });
+        }
+        Self { items }
+    }
+
+    pub fn total_size(&self) -> u64 {
+        self.items.iter().map(|item| item.size).sum()
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+}
+
+fn disk_usage(path: &Path) -> u64 {
+    let Ok(metadata) = std::fs::symlink_metadata(path) else {
+        return 0;
+    };
+    if !metadata.is_dir() {
+        return metadata.len();
+    }
+
+    let mut total = 0u64;
+    let mut pending = vec![path.to_path_buf()];
+    while let Some(dir) = pending.pop() {
+        let Ok(entries) = std::fs::read_dir(&dir) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            let Ok(metadata) = entry.metadata() else {
+                continue;
+            };
+            if metadata.is_dir() {
+                pending.push(entry.path());
+            } else if metadata.is_file() {
+                total += metadata.len();
+            }
+        }
+    }
+    total
+}
+
 
 pub struct PasteExecutor {
     cancellation_token: PasteCancellationToken,
@@ -553,23 +737,7 @@ impl PasteExecutor {
     }
 
     fn generate_unique_name(&self, path: &PathBuf) -> PathBuf {
-        let stem = path.file_stem().and_then(|s| s.to_str()).unwrap_or("file");
-        let ext = path.extension().and_then(|e| e.to_str());
-        let parent = path.parent().unwrap_or(path);
-
-        let mut counter = 1;
-        loop {
-            let new_name = if let Some(ext) = ext {
-                format!("{} ({}). {}", stem, counter, ext)
-            } else {
-                format!("{} ({})", stem, counter)
-            };
-            let new_path = parent.join(&new_name);
-            if !new_path.exists() {
-                return new_path;
-            }
-            counter += 1;
-        }
+        unique_destination(path)
     }
 
     fn is_source_newer(&self, source: &PathBuf, dest: &PathBuf) -> bool {
@@ -740,3 +740,117 @@ fn test_paste_executor_directory() {
     assert!(dest_subdir.join("file1.txt").exists());
     assert!(dest_subdir.join("file2.txt").exists());
 }
+
+#[test]
+fn test_paste_target_duplicates_copy_into_same_dir() {
+    let temp_dir = TempDir::new().unwrap();
+    let source = create_test_file(temp_dir.path(), "notes.txt", b"original");
+
+    let target = paste_target(&source, temp_dir.path(), false, true);
+    let PasteTarget::Destination(dest) = target else {
+        panic!("expected a duplicate destination, got {:?}", target);
+    };
+    assert_eq!(dest, temp_dir.path().join("notes (1).txt"));
+
+    fs::copy(&source, &dest).unwrap();
+    assert_eq!(fs::read(&source).unwrap(), b"original");
+    assert_eq!(fs::read(&dest).unwrap(), b"original");
+    assert_eq!(
+        paste_target(&source, temp_dir.path(), false, true),
+        PasteTarget::Destination(temp_dir.path().join("notes (2).txt"))
+    );
+    assert_eq!(
+        paste_target(&source, temp_dir.path(), false, false),
+        PasteTarget::Unchanged
+    );
+}
+
+#[test]
+fn test_paste_target_ignores_move_into_same_dir() {
+    let temp_dir = TempDir::new().unwrap();
+    let other_dir = temp_dir.path().join("other");
+    fs::create_dir_all(&other_dir).unwrap();
+    let source = create_test_file(temp_dir.path(), "notes.txt", b"original");
+
+    assert_eq!(
+        paste_target(&source, temp_dir.path(), true, true),
+        PasteTarget::Unchanged
+    );
+    assert_eq!(fs::read_dir(temp_dir.path()).unwrap().count(), 2);
+    assert_eq!(
+        paste_target(&source, &other_dir, true, true),
+        PasteTarget::Destination(other_dir.join("notes.txt"))
+    );
+}
+
+#[test]
+fn test_overwrite_summary_lists_only_conflicting_destinations() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_dir = temp_dir.path().join("source");
+    let dest_dir = temp_dir.path().join("dest");
+    fs::create_dir_all(&source_dir).unwrap();
+    fs::create_dir_all(dest_dir.join("assets")).unwrap();
+
+    let existing_file = create_test_file(&dest_dir, "notes.txt", b"old notes");
+    create_test_file(&dest_dir.join("assets"), "logo.png", b"0123456789");
+    let conflicts = vec![
+        (source_dir.join("notes.txt"), existing_file.clone()),
+        (source_dir.join("assets"), dest_dir.join("assets")),
+        (source_dir.join("fresh.txt"), dest_dir.join("fresh.txt")),
+        (source_dir.join("notes.txt"), existing_file.clone()),
+    ];
+
+    let summary = OverwriteSummary::from_conflicts(&conflicts);
+
+    let destinations: Vec<&PathBuf> = summary.items.iter().map(|item| &item.destination).collect();
+    assert_eq!(destinations, vec![&existing_file, &dest_dir.join("assets")]);
+    assert_eq!(summary.items[0].size, 9);
+    assert_eq!(summary.items[1].size, 10);
+    assert_eq!(summary.total_size(), 19);
+    assert!(OverwriteSummary::from_conflicts(&[]).is_empty());
+}
+
+#[cfg(unix)]
+#[test]
+fn test_overwrite_summary_does_not_follow_symlinked_folders() {
+    let temp_dir = TempDir::new().unwrap();
+    let outside = temp_dir.path().join("outside");
+    let dest_dir = temp_dir.path().join("dest");
+    fs::create_dir_all(&outside).unwrap();
+    fs::create_dir_all(&dest_dir).unwrap();
+    create_test_file(&outside, "large.bin", b"0123456789");
+    create_test_file(&dest_dir, "small.txt", b"abc");
+    std::os::unix::fs::symlink(&outside, dest_dir.join("linked")).unwrap();
+    std::os::unix::fs::symlink(&dest_dir, dest_dir.join("loop")).unwrap();
+
+    let conflicts = vec![(temp_dir.path().join("source"), dest_dir.clone())];
+    let summary = OverwriteSummary::from_conflicts(&conflicts);
+
+    assert_eq!(summary.items.len(), 1);
+    assert_eq!(summary.items[0].size, 3);
+}
+
+#[test]
+fn test_shared_clipboard_enables_paste_in_other_windows() {
+    let mut shared = SharedClipboard::new();
+    let mut first_window = ClipboardMirror::new();
+    let mut second_window = ClipboardMirror::new();
+    assert!(!first_window.sync(&shared));
+    assert!(!second_window.can_paste());
+
+    let cut = ClipboardOperation::Cut {
+        paths: vec![PathBuf::from("/tmp/report.pdf")],
+    };
+    shared.set(Some(cut.clone()));
+    assert!(first_window.sync(&shared));
+
+    assert!(second_window.sync(&shared));
+    assert!(second_window.can_paste());
+    assert_eq!(second_window.operation(), Some(&cut));
+    assert!(!second_window.sync(&shared));
+
+    shared.set(None);
+    assert!(second_window.sync(&shared));
+    assert!(!second_window.can_paste());
+    assert!(!shared.has_content());
+}
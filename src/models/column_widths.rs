@@ -0,0 +1,131 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::SortColumn;
+
+
+pub const DETAILS_COLUMNS: [SortColumn; 4] = [
+    SortColumn::Name,
+    SortColumn::Date,
+    SortColumn::Type,
+    SortColumn::Size,
+];
+
+pub const MIN_COLUMN_WIDTH: f32 = 56.0;
+
+const DEFAULT_FRACTIONS: [f32; 4] = [0.625, 0.15, 0.125, 0.1];
+
+
+fn column_index(column: SortColumn) -> usize {
+    match column {
+        SortColumn::Name => 0,
+        SortColumn::Date => 1,
+        SortColumn::Type => 2,
+        SortColumn::Size => 3,
+    }
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ColumnWidths {
+    fractions: [f32; 4],
+}
+
+impl Default for ColumnWidths {
+    fn default() -> Self {
+        Self {
+            fractions: DEFAULT_FRACTIONS,
+        }
+    }
+}
+
+impl ColumnWidths {
+    pub fn from_widths(widths: [f32; 4]) -> Self {
+        let total: f32 = widths.iter().map(|w| w.max(0.0)).sum();
+        if total <= 0.0 {
+            return Self::default();
+        }
+        Self {
+            fractions: widths.map(|w| w.max(0.0) / total),
+        }
+    }
+
+    pub fn fraction(&self, column: SortColumn) -> f32 {
+        self.fractions[column_index(column)]
+    }
+
+    pub fn width(&self, column: SortColumn, total: f32) -> f32 {
+        self.fraction(column) * total.max(0.0)
+    }
+
+    pub fn widths(&self, total: f32) -> [f32; 4] {
+        self.fractions.map(|f| f * total.max(0.0))
+    }
+
+    pub fn resize(&mut self, divider: SortColumn, delta: f32, total: f32) -> bool {
+        let right = column_index(divider);
+        if right == 0 || total <= 0.0 {
+            return false;
+        }
+        let left = right - 1;
+        let mut widths = self.widths(total);
+        let pair = widths[left] + widths[right];
+        if pair < MIN_COLUMN_WIDTH * 2.0 {
+            return false;
+        }
+
+        let new_left = (widths[left] + delta).clamp(MIN_COLUMN_WIDTH, pair - MIN_COLUMN_WIDTH);
+        if new_left == widths[left] {
+            return false;
+        }
+        widths[left] = new_left;
+        widths[right] = pair - new_left;
+        *self = Self::from_widths(widths);
+        true
+    }
+}
+
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ColumnWidthRules {
+    default: ColumnWidths,
+    folders: HashMap<PathBuf, ColumnWidths>,
+}
+
+impl ColumnWidthRules {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn for_path(&self, path: &Path) -> ColumnWidths {
+        self.folders.get(path).copied().unwrap_or(self.default)
+    }
+
+    pub fn has_override(&self, path: &Path) -> bool {
+        self.folders.contains_key(path)
+    }
+
+    pub fn set(&mut self, path: PathBuf, widths: ColumnWidths) {
+        if widths == self.default {
+            self.folders.remove(&path);
+        } else {
+            self.folders.insert(path, widths);
+        }
+    }
+
+    pub fn apply_to_all(&mut self, widths: ColumnWidths) {
+        self.default = widths;
+        self.folders.clear();
+    }
+
+    pub fn len(&self) -> usize {
+        self.folders.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.folders.is_empty()
+    }
+}
@@ -0,0 +1,78 @@
+use super::column_widths::*;
+use crate::models::SortColumn;
+use std::path::Path;
+
+
+fn assert_close(actual: f32, expected: f32) {
+    assert!((actual - expected).abs() < 0.01, "{} != {}", actual, expected);
+}
+
+#[test]
+fn test_widths_restore_per_folder_across_navigation() {
+    let mut rules = ColumnWidthRules::new();
+    let mut photos = ColumnWidths::default();
+    assert!(photos.resize(SortColumn::Date, -100.0, 800.0));
+    rules.set("/photos".into(), photos);
+
+    let visit = |path: &str| rules.for_path(Path::new(path));
+    assert_eq!(visit("/photos"), photos);
+    assert_eq!(visit("/code"), ColumnWidths::default());
+    assert_eq!(visit("/photos"), photos);
+    assert_close(visit("/photos").width(SortColumn::Name, 800.0), 400.0);
+    assert_close(visit("/photos").width(SortColumn::Date, 800.0), 220.0);
+}
+
+#[test]
+fn test_widths_survive_settings_round_trip() {
+    let mut rules = ColumnWidthRules::new();
+    let mut widths = ColumnWidths::default();
+    widths.resize(SortColumn::Size, 40.0, 1000.0);
+    rules.set("/music".into(), widths);
+
+    let json = serde_json::to_string(&rules).unwrap();
+    let restored: ColumnWidthRules = serde_json::from_str(&json).unwrap();
+    assert_eq!(restored, rules);
+    assert_eq!(restored.for_path(Path::new("/music")), widths);
+
+    let empty: ColumnWidthRules = serde_json::from_str("{}").unwrap();
+    assert_eq!(empty.for_path(Path::new("/music")), ColumnWidths::default());
+}
+
+#[test]
+fn test_apply_to_all_replaces_folder_overrides() {
+    let mut rules = ColumnWidthRules::new();
+    let mut narrow = ColumnWidths::default();
+    narrow.resize(SortColumn::Date, -150.0, 800.0);
+    rules.set("/a".into(), narrow);
+    rules.set("/b".into(), ColumnWidths::default());
+    assert_eq!(rules.len(), 1);
+    assert!(!rules.has_override(Path::new("/b")));
+
+    let wide = ColumnWidths::from_widths([300.0, 200.0, 200.0, 100.0]);
+    rules.apply_to_all(wide);
+    assert!(rules.is_empty());
+    assert_eq!(rules.for_path(Path::new("/a")), wide);
+    assert_eq!(rules.for_path(Path::new("/anywhere")), wide);
+}
+
+#[test]
+fn test_dragging_divider_respects_minimum_width() {
+    let mut widths = ColumnWidths::default();
+    assert!(widths.resize(SortColumn::Size, 500.0, 800.0));
+    assert_close(widths.width(SortColumn::Size, 800.0), MIN_COLUMN_WIDTH);
+    assert_close(widths.width(SortColumn::Type, 800.0), 180.0 - MIN_COLUMN_WIDTH);
+
+    assert!(!widths.resize(SortColumn::Size, 10.0, 800.0));
+    assert!(!widths.resize(SortColumn::Name, 10.0, 800.0));
+}
+
+#[test]
+fn test_window_resize_redistributes_proportionally() {
+    let widths = ColumnWidths::from_widths([400.0, 200.0, 100.0, 100.0]);
+    let small = widths.widths(800.0);
+    let large = widths.widths(1200.0);
+    for (s, l) in small.iter().zip(large.iter()) {
+        assert_close(l / s, 1.5);
+    }
+    assert_close(large.iter().sum(), 1200.0);
+}
@@ -0,0 +1,62 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::ConflictResolution;
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ConflictStrategy {
+    #[default]
+    Ask,
+    Skip,
+    Replace,
+    KeepBoth,
+    ReplaceIfNewer,
+}
+
+impl ConflictStrategy {
+    pub fn label(&self) -> &'static str {
+        match self {
+            ConflictStrategy::Ask => "Ask on conflict",
+            ConflictStrategy::Skip => "Skip conflicting files",
+            ConflictStrategy::Replace => "Replace conflicting files",
+            ConflictStrategy::KeepBoth => "Keep both files",
+            ConflictStrategy::ReplaceIfNewer => "Replace if newer",
+        }
+    }
+
+    pub fn next(&self) -> Self {
+        match self {
+            ConflictStrategy::Ask => ConflictStrategy::Skip,
+            ConflictStrategy::Skip => ConflictStrategy::Replace,
+            ConflictStrategy::Replace => ConflictStrategy::KeepBoth,
+            ConflictStrategy::KeepBoth => ConflictStrategy::ReplaceIfNewer,
+            ConflictStrategy::ReplaceIfNewer => ConflictStrategy::Ask,
+        }
+    }
+
+    pub fn resolution(&self) -> Option<ConflictResolution> {
+        match self {
+            ConflictStrategy::Ask => None,
+            ConflictStrategy::Skip => Some(ConflictResolution::Skip),
+            ConflictStrategy::Replace => Some(ConflictResolution::Replace),
+            ConflictStrategy::KeepBoth => Some(ConflictResolution::KeepBoth),
+            ConflictStrategy::ReplaceIfNewer => Some(ConflictResolution::ReplaceIfNewer),
+        }
+    }
+}
+
+
+pub fn preset_conflict_resolution(
+    session: Option<ConflictResolution>,
+    strategy: ConflictStrategy,
+) -> Option<ConflictResolution> {
+    session.or_else(|| strategy.resolution())
+}
+
+
+pub fn unattended_conflict_resolution(
+    session: Option<ConflictResolution>,
+    strategy: ConflictStrategy,
+) -> ConflictResolution {
+    preset_conflict_resolution(session, strategy).unwrap_or(ConflictResolution::Skip)
+}
@@ -0,0 +1,64 @@
+use super::conflict_strategy::*;
+use crate::models::{ConflictResolution, GlobalSettings};
+
+
+#[test]
+fn test_non_ask_default_is_applied_without_dialog() {
+    assert_eq!(
+        preset_conflict_resolution(None, ConflictStrategy::KeepBoth),
+        Some(ConflictResolution::KeepBoth)
+    );
+    assert_eq!(
+        preset_conflict_resolution(None, ConflictStrategy::ReplaceIfNewer),
+        Some(ConflictResolution::ReplaceIfNewer)
+    );
+    assert_eq!(
+        preset_conflict_resolution(None, ConflictStrategy::Skip),
+        Some(ConflictResolution::Skip)
+    );
+}
+
+#[test]
+fn test_ask_leaves_resolution_to_dialog() {
+    assert_eq!(preset_conflict_resolution(None, ConflictStrategy::Ask), None);
+    assert_eq!(
+        unattended_conflict_resolution(None, ConflictStrategy::Ask),
+        ConflictResolution::Skip
+    );
+}
+
+#[test]
+fn test_apply_to_all_overrides_default_for_session() {
+    assert_eq!(
+        preset_conflict_resolution(Some(ConflictResolution::Replace), ConflictStrategy::KeepBoth),
+        Some(ConflictResolution::Replace)
+    );
+    assert_eq!(
+        unattended_conflict_resolution(Some(ConflictResolution::KeepBoth), ConflictStrategy::Ask),
+        ConflictResolution::KeepBoth
+    );
+}
+
+#[test]
+fn test_cycle_covers_every_strategy() {
+    let mut strategy = ConflictStrategy::Ask;
+    let mut seen = vec![strategy];
+    for _ in 0..4 {
+        strategy = strategy.next();
+        seen.push(strategy);
+    }
+    assert_eq!(strategy.next(), ConflictStrategy::Ask);
+    seen.sort_by_key(|s| s.label());
+    seen.dedup();
+    assert_eq!(seen.len(), 5);
+}
+
+#[test]
+fn test_settings_default_to_ask_when_missing() {
+    let json = serde_json::to_string(&GlobalSettings::default()).unwrap();
+    let mut value: serde_json::Value = serde_json::from_str(&json).unwrap();
+    value.as_object_mut().unwrap().remove("conflict_strategy");
+
+    let settings: GlobalSettings = serde_json::from_value(value).unwrap();
+    assert_eq!(settings.conflict_strategy, ConflictStrategy::Ask);
+}
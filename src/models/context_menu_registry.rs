@@ -0,0 +1,149 @@
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use gpui::Global;
+
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EntryPredicate {
+    Any,
+    Files,
+    Directories,
+    Extensions(Vec<String>),
+}
+
+impl EntryPredicate {
+    pub fn extensions<I, S>(extensions: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        EntryPredicate::Extensions(
+            extensions
+                .into_iter()
+                .map(|ext| ext.as_ref().trim_start_matches('.').to_lowercase())
+                .collect(),
+        )
+    }
+
+    pub fn matches(&self, path: &Path, is_dir: bool) -> bool {
+        match self {
+            EntryPredicate::Any => true,
+            EntryPredicate::Files => !is_dir,
+            EntryPredicate::Directories => is_dir,
+            EntryPredicate::Extensions(extensions) => {
+                !is_dir
+                    && path
+                        .extension()
+                        .and_then(|ext| ext.to_str())
+                        .is_some_and(|ext| {
+                            extensions.iter().any(|e| e.eq_ignore_ascii_case(ext))
+                        })
+            }
+        }
+    }
+}
+
+
+pub type ContextMenuHandler = Arc<dyn Fn(&[PathBuf]) -> Result<(), String> + Send + Sync>;
+
+
+#[derive(Clone)]
+pub struct ContextMenuEntry {
+    pub label: String,
+    pub icon: String,
+    pub predicate: EntryPredicate,
+    pub action: ContextMenuHandler,
+}
+
+impl ContextMenuEntry {
+    pub fn new<F>(
+        label: impl Into<String>,
+        icon: impl Into<String>,
+        predicate: EntryPredicate,
+        action: F,
+    ) -> Self
+    where
+        F: Fn(&[PathBuf]) -> Result<(), String> + Send + Sync + 'static,
+    {
+        Self {
+            label: label.into(),
+            icon: icon.into(),
+            predicate,
+            action: Arc::new(action),
+        }
+    }
+}
+
+impl fmt::Debug for ContextMenuEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ContextMenuEntry")
+            .field("label", &self.label)
+            .field("icon", &self.icon)
+            .field("predicate", &self.predicate)
+            .finish_non_exhaustive()
+    }
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ContextMenuEntryId(u64);
+
+
+#[derive(Debug, Default)]
+pub struct ContextMenuRegistry {
+    entries: Vec<(ContextMenuEntryId, ContextMenuEntry)>,
+    next_id: u64,
+}
+
+impl Global for ContextMenuRegistry {}
+
+impl ContextMenuRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn register(&mut self, entry: ContextMenuEntry) -> ContextMenuEntryId {
+        self.next_id += 1;
+        let id = ContextMenuEntryId(self.next_id);
+        self.entries.push((id, entry));
+        id
+    }
+
+    pub fn unregister(&mut self, id: ContextMenuEntryId) -> bool {
+        let before = self.entries.len();
+        self.entries.retain(|(entry_id, _)| *entry_id != id);
+        self.entries.len() != before
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn entries_for(&self, path: &Path, is_dir: bool) -> Vec<(ContextMenuEntryId, &ContextMenuEntry)> {
+        self.entries
+            .iter()
+            .filter(|(_, entry)| entry.predicate.matches(path, is_dir))
+            .map(|(id, entry)| (*id, entry))
+            .collect()
+    }
+
+    pub fn invoke(&self, id: ContextMenuEntryId, paths: &[PathBuf]) -> Option<Result<(), String>> {
+        self.entries
+            .iter()
+            .find(|(entry_id, _)| *entry_id == id)
+            .map(|(_, entry)| (entry.action)(paths))
+    }
+
+    pub fn label(&self, id: ContextMenuEntryId) -> Option<&str> {
+        self.entries
+            .iter()
+            .find(|(entry_id, _)| *entry_id == id)
+            .map(|(_, entry)| entry.label.as_str())
+    }
+}
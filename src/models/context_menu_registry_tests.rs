@@ -0,0 +1,79 @@
+use super::context_menu_registry::*;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+
+fn labels(registry: &ContextMenuRegistry, path: &str, is_dir: bool) -> Vec<String> {
+    registry
+        .entries_for(Path::new(path), is_dir)
+        .into_iter()
+        .map(|(_, entry)| entry.label.clone())
+        .collect()
+}
+
+#[test]
+fn test_registered_entry_only_appears_for_matching_entries() {
+    let mut registry = ContextMenuRegistry::new();
+    registry.register(ContextMenuEntry::new(
+        "Convert Image",
+        "image",
+        EntryPredicate::extensions([".PNG", "jpg"]),
+        |_| Ok(()),
+    ));
+    registry.register(ContextMenuEntry::new(
+        "Open Project",
+        "folder",
+        EntryPredicate::Directories,
+        |_| Ok(()),
+    ));
+    registry.register(ContextMenuEntry::new("Inspect", "info", EntryPredicate::Files, |_| Ok(())));
+
+    assert_eq!(labels(&registry, "/photos/cat.png", false), vec!["Convert Image", "Inspect"]);
+    assert_eq!(labels(&registry, "/photos/DOG.JPG", false), vec!["Convert Image", "Inspect"]);
+    assert_eq!(labels(&registry, "/notes/todo.txt", false), vec!["Inspect"]);
+    assert_eq!(labels(&registry, "/photos.png", true), vec!["Open Project"]);
+    assert_eq!(labels(&registry, "/README", false), vec!["Inspect"]);
+}
+
+#[test]
+fn test_selecting_entry_invokes_its_handler() {
+    let received: Arc<Mutex<Vec<PathBuf>>> = Arc::default();
+    let mut registry = ContextMenuRegistry::new();
+    let sink = received.clone();
+    let id = registry.register(ContextMenuEntry::new(
+        "Collect",
+        "copy",
+        EntryPredicate::Any,
+        move |paths| {
+            sink.lock().unwrap().extend_from_slice(paths);
+            Ok(())
+        },
+    ));
+    let failing = registry.register(ContextMenuEntry::new(
+        "Fail",
+        "x",
+        EntryPredicate::Any,
+        |_| Err("not supported".to_string()),
+    ));
+
+    let paths = vec![PathBuf::from("/a.txt"), PathBuf::from("/b.txt")];
+    assert_eq!(registry.invoke(id, &paths), Some(Ok(())));
+    assert_eq!(*received.lock().unwrap(), paths);
+    assert_eq!(
+        registry.invoke(failing, &paths),
+        Some(Err("not supported".to_string()))
+    );
+}
+
+#[test]
+fn test_unregistered_entry_is_gone() {
+    let mut registry = ContextMenuRegistry::new();
+    let id = registry.register(ContextMenuEntry::new("Temp", "x", EntryPredicate::Any, |_| Ok(())));
+    assert_eq!(registry.label(id), Some("Temp"));
+
+    assert!(registry.unregister(id));
+    assert!(!registry.unregister(id));
+    assert!(registry.is_empty());
+    assert!(labels(&registry, "/a.txt", false).is_empty());
+    assert_eq!(registry.invoke(id, &[]), None);
+}
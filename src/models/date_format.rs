@@ -0,0 +1,248 @@
+use std::sync::{OnceLock, RwLock};
+use std::time::{Duration, SystemTime};
+
+use chrono::format::{Item, StrftimeItems};
+use chrono::{DateTime, Datelike, Local};
+use serde::{Deserialize, Serialize};
+
+const MINUTE: u64 = 60;
+const HOUR: u64 = 60 * MINUTE;
+const DAY: u64 = 24 * HOUR;
+const RELATIVE_DAYS: u64 = 7;
+
+const ISO_PATTERN: &str = "%Y-%m-%d";
+const SHORT_PATTERN: &str = "%b %-d, %Y";
+
+
+pub const RELATIVE_REFRESH_INTERVAL: Duration = Duration::from_secs(30);
+
+static DATE_FORMAT: RwLock<DateFormat> = RwLock::new(DateFormat::Iso);
+static DATE_LOCALE: OnceLock<DateLocale> = OnceLock::new();
+
+
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum DateFormat {
+    #[default]
+    Iso,
+    Short,
+    Relative,
+    Custom(String),
+}
+
+impl DateFormat {
+    pub fn label(&self) -> &'static str {
+        match self {
+            DateFormat::Iso => "ISO (2024-03-09)",
+            DateFormat::Short => "Short (Mar 9, 2024)",
+            DateFormat::Relative => "Relative (2 hours ago)",
+            DateFormat::Custom(_) => "Custom pattern",
+        }
+    }
+
+    pub fn next(&self) -> Self {
+        match self {
+            DateFormat::Iso => DateFormat::Short,
+            DateFormat::Short => DateFormat::Relative,
+            DateFormat::Relative | DateFormat::Custom(_) => DateFormat::Iso,
+        }
+    }
+
+    pub fn is_relative(&self) -> bool {
+        matches!(self, DateFormat::Relative)
+    }
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DateLocale {
+    #[default]
+    English,
+    German,
+    French,
+    Spanish,
+    Italian,
+    Portuguese,
+    Dutch,
+}
+
+impl DateLocale {
+    pub fn from_tag(tag: &str) -> Self {
+        let language = tag
+            .split(['_', '-', '.', '@'])
+            .next()
+            .unwrap_or_default()
+            .to_lowercase();
+        match language.as_str() {
+            "de" => DateLocale::German,
+            "fr" => DateLocale::French,
+            "es" => DateLocale::Spanish,
+            "it" => DateLocale::Italian,
+            "pt" => DateLocale::Portuguese,
+            "nl" => DateLocale::Dutch,
+            _ => DateLocale::English,
+        }
+    }
+
+    pub fn from_env() -> Self {
+        ["LC_ALL", "LC_TIME", "LANG"]
+            .iter()
+            .filter_map(|key| std::env::var(key).ok())
+            .find(|value| !value.is_empty())
+            .map(|tag| Self::from_tag(&tag))
+            .unwrap_or_default()
+    }
+
+    pub fn month_name(&self, month0: usize) -> &'static str {
+        let names: [&str; 12] = match self {
+            DateLocale::English => [
+                "January", "February", "March", "April", "May", "June", "July", "August",
+                "September", "October", "November", "December",
+            ],
+            DateLocale::German => [
+                "Januar", "Februar", "März", "April", "Mai", "Juni", "Juli", "August",
+                "September", "Oktober", "November", "Dezember",
+            ],
+            DateLocale::French => [
+                "janvier", "février", "mars", "avril", "mai", "juin", "juillet", "août",
+                "septembre", "octobre", "novembre", "décembre",
+            ],
+            DateLocale::Spanish => [
+                "enero", "febrero", "marzo", "abril", "mayo", "junio", "julio", "agosto",
+                "septiembre", "octubre", "noviembre", "diciembre",
+            ],
+            DateLocale::Italian => [
+                "gennaio", "febbraio", "marzo", "aprile", "maggio", "giugno", "luglio",
+                "agosto", "settembre", "ottobre", "novembre", "dicembre",
+            ],
+            DateLocale::Portuguese => [
+                "janeiro", "fevereiro", "março", "abril", "maio", "junho", "julho", "agosto",
+                "setembro", "outubro", "novembro", "dezembro",
+            ],
+            DateLocale::Dutch => [
+                "januari", "februari", "maart", "april", "mei", "juni", "juli", "augustus",
+                "september", "oktober", "november", "december",
+            ],
+        };
+        names[month0 % 12]
+    }
+
+    pub fn month_abbreviation(&self, month0: usize) -> String {
+        let name = self.month_name(month0);
+        match self {
+            DateLocale::English => name.chars().take(3).collect(),
+            _ if name.chars().count() <= 4 => name.to_string(),
+            _ => name.chars().take(3).collect(),
+        }
+    }
+}
+
+
+pub fn set_date_format(format: DateFormat) {
+    if let Ok(mut current) = DATE_FORMAT.write() {
+        *current = format;
+    }
+}
+
+
+pub fn date_format() -> DateFormat {
+    DATE_FORMAT
+        .read()
+        .map(|format| format.clone())
+        .unwrap_or_default()
+}
+
+
+pub fn date_locale() -> DateLocale {
+    *DATE_LOCALE.get_or_init(DateLocale::from_env)
+}
+
+
+pub fn format_file_date(time: SystemTime) -> String {
+    format_date_with(time, &date_format(), date_locale(), SystemTime::now())
+}
+
+
+pub fn format_date_with(
+    time: SystemTime,
+    format: &DateFormat,
+    locale: DateLocale,
+    now: SystemTime,
+) -> String {
+    let local = DateTime::<Local>::from(time);
+    match format {
+        DateFormat::Iso => format_pattern(&local, ISO_PATTERN, locale),
+        DateFormat::Short => format_pattern(&local, SHORT_PATTERN, locale),
+        DateFormat::Relative => format_relative(time, now)
+            .unwrap_or_else(|| format_pattern(&local, SHORT_PATTERN, locale)),
+        DateFormat::Custom(pattern) if is_valid_pattern(pattern) => {
+            format_pattern(&local, pattern, locale)
+        }
+        DateFormat::Custom(_) => format_pattern(&local, ISO_PATTERN, locale),
+    }
+}
+
+
+pub fn format_relative(time: SystemTime, now: SystemTime) -> Option<String> {
+    if time
+        .duration_since(now)
+        .is_ok_and(|ahead| ahead.as_secs() >= MINUTE)
+    {
+        return None;
+    }
+    let elapsed = now.duration_since(time).unwrap_or_default().as_secs();
+    let ago = |count: u64, unit: &str| {
+        if count == 1 {
+            format!("1 {} ago", unit)
+        } else {
+            format!("{} {}s ago", count, unit)
+        }
+    };
+
+    match elapsed {
+        0..=59 => Some("just now".to_string()),
+        _ if elapsed < HOUR => Some(ago(elapsed / MINUTE, "minute")),
+        _ if elapsed < DAY => Some(ago(elapsed / HOUR, "hour")),
+        _ if elapsed < RELATIVE_DAYS * DAY => Some(ago(elapsed / DAY, "day")),
+        _ => None,
+    }
+}
+
+
+pub fn is_recent(time: SystemTime, now: SystemTime) -> bool {
+    now.duration_since(time)
+        .map_or(true, |elapsed| elapsed.as_secs() < DAY)
+}
+
+
+pub fn is_valid_pattern(pattern: &str) -> bool {
+    !pattern.is_empty() && !StrftimeItems::new(pattern).any(|item| matches!(item, Item::Error))
+}
+
+fn format_pattern(date: &DateTime<Local>, pattern: &str, locale: DateLocale) -> String {
+    let pattern = localize_months(pattern, date.month0() as usize, locale);
+    date.format(&pattern).to_string()
+}
+
+fn localize_months(pattern: &str, month0: usize, locale: DateLocale) -> String {
+    if locale == DateLocale::English {
+        return pattern.to_string();
+    }
+    let mut localized = String::with_capacity(pattern.len());
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            localized.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('b') | Some('h') => localized.push_str(&locale.month_abbreviation(month0)),
+            Some('B') => localized.push_str(locale.month_name(month0)),
+            Some(other) => {
+                localized.push('%');
+                localized.push(other);
+            }
+            None => localized.push('%'),
+        }
+    }
+    localized
+}
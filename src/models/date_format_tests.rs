@@ -0,0 +1,95 @@
+use super::date_format::*;
+use chrono::{Local, TimeZone};
+use std::time::{Duration, SystemTime};
+
+
+fn known_time() -> SystemTime {
+    SystemTime::from(Local.with_ymd_and_hms(2024, 3, 9, 14, 5, 30).unwrap())
+}
+
+fn format(format: DateFormat, locale: DateLocale) -> String {
+    let now = known_time() + Duration::from_secs(30 * 86400);
+    format_date_with(known_time(), &format, locale, now)
+}
+
+#[test]
+fn test_each_mode_formats_known_timestamp() {
+    assert_eq!(format(DateFormat::Iso, DateLocale::English), "2024-03-09");
+    assert_eq!(format(DateFormat::Short, DateLocale::English), "Mar 9, 2024");
+    assert_eq!(
+        format(DateFormat::Relative, DateLocale::English),
+        "Mar 9, 2024"
+    );
+    assert_eq!(
+        format(
+            DateFormat::Custom("%d/%m/%Y %H:%M".to_string()),
+            DateLocale::English
+        ),
+        "09/03/2024 14:05"
+    );
+}
+
+#[test]
+fn test_month_names_follow_locale() {
+    assert_eq!(DateLocale::from_tag("de_DE.UTF-8"), DateLocale::German);
+    assert_eq!(DateLocale::from_tag("fr-CA"), DateLocale::French);
+    assert_eq!(DateLocale::from_tag("C"), DateLocale::English);
+
+    assert_eq!(format(DateFormat::Short, DateLocale::German), "März 9, 2024");
+    assert_eq!(
+        format(
+            DateFormat::Custom("%-d. %B %Y (%%b)".to_string()),
+            DateLocale::German
+        ),
+        "9. März 2024 (%b)"
+    );
+    assert_eq!(
+        format(DateFormat::Custom("%-d %B %Y".to_string()), DateLocale::French),
+        "9 mars 2024"
+    );
+}
+
+#[test]
+fn test_invalid_custom_pattern_falls_back_to_iso() {
+    assert!(!is_valid_pattern("%Q"));
+    assert!(!is_valid_pattern(""));
+    assert_eq!(
+        format(DateFormat::Custom("%Q".to_string()), DateLocale::English),
+        "2024-03-09"
+    );
+}
+
+#[test]
+fn test_relative_formatting_buckets() {
+    let now = known_time();
+    let ago = |secs: u64| format_relative(now - Duration::from_secs(secs), now);
+
+    assert_eq!(ago(0).as_deref(), Some("just now"));
+    assert_eq!(ago(59).as_deref(), Some("just now"));
+    assert_eq!(ago(60).as_deref(), Some("1 minute ago"));
+    assert_eq!(ago(45 * 60).as_deref(), Some("45 minutes ago"));
+    assert_eq!(ago(3600).as_deref(), Some("1 hour ago"));
+    assert_eq!(ago(23 * 3600 + 59 * 60).as_deref(), Some("23 hours ago"));
+    assert_eq!(ago(86400).as_deref(), Some("1 day ago"));
+    assert_eq!(ago(6 * 86400).as_deref(), Some("6 days ago"));
+    assert_eq!(ago(7 * 86400), None);
+
+    assert_eq!(
+        format_relative(now + Duration::from_secs(5), now).as_deref(),
+        Some("just now")
+    );
+    assert_eq!(format_relative(now + Duration::from_secs(3600), now), None);
+
+    assert!(is_recent(now - Duration::from_secs(3600), now));
+    assert!(!is_recent(now - Duration::from_secs(2 * 86400), now));
+}
+
+#[test]
+fn test_date_format_cycles_through_presets() {
+    assert_eq!(DateFormat::default(), DateFormat::Iso);
+    assert_eq!(DateFormat::Iso.next(), DateFormat::Short);
+    assert_eq!(DateFormat::Short.next(), DateFormat::Relative);
+    assert_eq!(DateFormat::Relative.next(), DateFormat::Iso);
+    assert_eq!(DateFormat::Custom("%Y".to_string()).next(), DateFormat::Iso);
+    assert!(DateFormat::Relative.is_relative());
+}
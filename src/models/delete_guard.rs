@@ -0,0 +1,49 @@
+use std::path::{Path, PathBuf};
+
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeletionGuard {
+    Clear,
+    NavigateTo(PathBuf),
+    Blocked(PathBuf),
+}
+
+
+fn normalized(path: &Path) -> PathBuf {
+    match (path.parent(), path.file_name()) {
+        (Some(parent), Some(name)) => parent
+            .canonicalize()
+            .map(|parent| parent.join(name))
+            .unwrap_or_else(|_| path.to_path_buf()),
+        _ => path.to_path_buf(),
+    }
+}
+
+
+pub fn contains_current_directory(path: &Path, current: &Path) -> bool {
+    if current.starts_with(path) {
+        return true;
+    }
+    let current = current
+        .canonicalize()
+        .unwrap_or_else(|_| current.to_path_buf());
+    current.starts_with(normalized(path))
+}
+
+
+pub fn guard_deletion(paths: &[PathBuf], current: &Path) -> DeletionGuard {
+    let outermost = paths
+        .iter()
+        .filter(|path| contains_current_directory(path, current))
+        .min_by_key(|path| path.components().count());
+
+    match outermost {
+        None => DeletionGuard::Clear,
+        Some(path) => match path.parent() {
+            Some(parent) if !parent.as_os_str().is_empty() => {
+                DeletionGuard::NavigateTo(parent.to_path_buf())
+            }
+            _ => DeletionGuard::Blocked(path.clone()),
+        },
+    }
+}
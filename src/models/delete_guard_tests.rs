@@ -0,0 +1,64 @@
+use super::delete_guard::*;
+use std::path::{Path, PathBuf};
+use tempfile::TempDir;
+
+
+fn paths(items: &[&str]) -> Vec<PathBuf> {
+    items.iter().map(PathBuf::from).collect()
+}
+
+#[test]
+fn test_current_directory_and_ancestors_are_detected() {
+    let current = Path::new("/home/me/projects/app");
+    assert!(contains_current_directory(Path::new("/home/me/projects/app"), current));
+    assert!(contains_current_directory(Path::new("/home/me"), current));
+    assert!(!contains_current_directory(Path::new("/home/me/projects/app/src"), current));
+    assert!(!contains_current_directory(Path::new("/home/me/proj"), current));
+    assert!(!contains_current_directory(Path::new("/home/me/projects/app-old"), current));
+}
+
+#[test]
+fn test_unrelated_selection_is_clear() {
+    let current = Path::new("/home/me/projects");
+    assert_eq!(
+        guard_deletion(&paths(&["/home/me/projects/a.txt", "/home/me/projects/b"]), current),
+        DeletionGuard::Clear
+    );
+    assert_eq!(guard_deletion(&[], current), DeletionGuard::Clear);
+}
+
+#[test]
+fn test_multi_selection_navigates_above_outermost_ancestor() {
+    let current = Path::new("/home/me/projects/app/src");
+    let selection = paths(&[
+        "/home/me/projects/notes.md",
+        "/home/me/projects/app/src",
+        "/home/me/projects/app",
+    ]);
+    assert_eq!(
+        guard_deletion(&selection, current),
+        DeletionGuard::NavigateTo(PathBuf::from("/home/me/projects"))
+    );
+}
+
+#[test]
+fn test_root_cannot_be_left_and_is_blocked() {
+    assert_eq!(
+        guard_deletion(&paths(&["/"]), Path::new("/home/me")),
+        DeletionGuard::Blocked(PathBuf::from("/"))
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn test_symlinked_locations_resolve_before_comparing() {
+    let dir = TempDir::new().unwrap();
+    let real = dir.path().join("real");
+    std::fs::create_dir_all(real.join("inner")).unwrap();
+    let alias = dir.path().join("alias");
+    std::os::unix::fs::symlink(&real, &alias).unwrap();
+
+    let current = alias.join("inner");
+    assert!(contains_current_directory(&real, &current));
+    assert!(!contains_current_directory(&alias, &real.join("inner")));
+}
@@ -0,0 +1,160 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::{ColumnWidths, SortColumn, SortState, MAX_SORT_TIEBREAKERS};
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum DetailsColumn {
+    Modified,
+    Kind,
+    Size,
+    Created,
+    Permissions,
+    Tags,
+}
+
+impl DetailsColumn {
+    pub const ALL: [DetailsColumn; 6] = [
+        DetailsColumn::Modified,
+        DetailsColumn::Kind,
+        DetailsColumn::Size,
+        DetailsColumn::Created,
+        DetailsColumn::Permissions,
+        DetailsColumn::Tags,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            DetailsColumn::Modified => "Date Modified",
+            DetailsColumn::Kind => "Kind",
+            DetailsColumn::Size => "Size",
+            DetailsColumn::Created => "Date Created",
+            DetailsColumn::Permissions => "Permissions",
+            DetailsColumn::Tags => "Tags",
+        }
+    }
+
+    pub fn header(self) -> &'static str {
+        match self {
+            DetailsColumn::Modified => "MODIFIED",
+            DetailsColumn::Kind => "TYPE",
+            DetailsColumn::Size => "SIZE",
+            DetailsColumn::Created => "CREATED",
+            DetailsColumn::Permissions => "PERMISSIONS",
+            DetailsColumn::Tags => "TAGS",
+        }
+    }
+
+    pub fn sort_column(self) -> Option<SortColumn> {
+        match self {
+            DetailsColumn::Modified => Some(SortColumn::Date),
+            DetailsColumn::Kind => Some(SortColumn::Type),
+            DetailsColumn::Size => Some(SortColumn::Size),
+            DetailsColumn::Created | DetailsColumn::Permissions | DetailsColumn::Tags => None,
+        }
+    }
+
+    pub fn width(self, widths: &ColumnWidths, total: f32) -> f32 {
+        match self.sort_column() {
+            Some(column) => widths.width(column, total),
+            None => match self {
+                DetailsColumn::Created => 150.0,
+                DetailsColumn::Permissions => 110.0,
+                _ => 96.0,
+            },
+        }
+    }
+}
+
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct VisibleColumns {
+    columns: Vec<DetailsColumn>,
+}
+
+impl Default for VisibleColumns {
+    fn default() -> Self {
+        Self {
+            columns: vec![DetailsColumn::Modified, DetailsColumn::Kind, DetailsColumn::Size],
+        }
+    }
+}
+
+impl VisibleColumns {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_visible(&self, column: DetailsColumn) -> bool {
+        self.columns.contains(&column)
+    }
+
+    pub fn set_visible(&mut self, column: DetailsColumn, visible: bool) {
+        if visible == self.is_visible(column) {
+            return;
+        }
+        if visible {
+            self.columns.push(column);
+        } else {
+            self.columns.retain(|c| *c != column);
+        }
+    }
+
+    pub fn toggle(&mut self, column: DetailsColumn) -> bool {
+        let visible = !self.is_visible(column);
+        self.set_visible(column, visible);
+        visible
+    }
+
+    pub fn columns(&self) -> Vec<DetailsColumn> {
+        DetailsColumn::ALL
+            .into_iter()
+            .filter(|column| self.is_visible(*column))
+            .collect()
+    }
+
+    pub fn sort_options(&self) -> Vec<SortColumn> {
+        std::iter::once(SortColumn::Name)
+            .chain(self.columns().into_iter().filter_map(DetailsColumn::sort_column))
+            .collect()
+    }
+
+    pub fn allows_sort(&self, column: SortColumn) -> bool {
+        column == SortColumn::Name
+            || self
+                .columns
+                .iter()
+                .any(|c| c.sort_column() == Some(column))
+    }
+
+    pub fn restrict_sort(&self, sort: SortState) -> SortState {
+        if !self.allows_sort(sort.column) {
+            return SortState {
+                directories_first: sort.directories_first,
+                ..SortState::default()
+            };
+        }
+        let mut restricted = sort;
+        restricted.tiebreakers = [None; MAX_SORT_TIEBREAKERS];
+        let kept = sort
+            .tiebreakers
+            .iter()
+            .flatten()
+            .filter(|(column, _)| self.allows_sort(*column));
+        for (slot, tiebreaker) in restricted.tiebreakers.iter_mut().zip(kept) {
+            *slot = Some(*tiebreaker);
+        }
+        restricted
+    }
+
+    pub fn resize_divider(&self, column: DetailsColumn) -> Option<SortColumn> {
+        let divider = column.sort_column()?;
+        let left_visible = match column {
+            DetailsColumn::Kind => self.is_visible(DetailsColumn::Modified),
+            DetailsColumn::Size => self.is_visible(DetailsColumn::Kind),
+            _ => true,
+        };
+        left_visible.then_some(divider)
+    }
+}
@@ -0,0 +1,98 @@
+use super::details_columns::*;
+use crate::models::{SortColumn, SortDirection, SortState};
+
+
+#[test]
+fn test_toggling_visibility_updates_rendered_columns() {
+    let mut columns = VisibleColumns::new();
+    assert_eq!(
+        columns.columns(),
+        vec![DetailsColumn::Modified, DetailsColumn::Kind, DetailsColumn::Size]
+    );
+
+    assert!(!columns.toggle(DetailsColumn::Kind));
+    assert!(columns.toggle(DetailsColumn::Permissions));
+    assert!(columns.toggle(DetailsColumn::Created));
+    assert_eq!(
+        columns.columns(),
+        vec![
+            DetailsColumn::Modified,
+            DetailsColumn::Size,
+            DetailsColumn::Created,
+            DetailsColumn::Permissions,
+        ]
+    );
+
+    assert!(columns.toggle(DetailsColumn::Kind));
+    assert!(columns.is_visible(DetailsColumn::Kind));
+    assert_eq!(columns.columns()[1], DetailsColumn::Kind);
+}
+
+#[test]
+fn test_sort_options_only_offer_visible_columns() {
+    let mut columns = VisibleColumns::new();
+    assert_eq!(
+        columns.sort_options(),
+        vec![SortColumn::Name, SortColumn::Date, SortColumn::Type, SortColumn::Size]
+    );
+
+    columns.toggle(DetailsColumn::Size);
+    columns.toggle(DetailsColumn::Tags);
+    assert_eq!(
+        columns.sort_options(),
+        vec![SortColumn::Name, SortColumn::Date, SortColumn::Type]
+    );
+    assert!(!columns.allows_sort(SortColumn::Size));
+
+    columns.toggle(DetailsColumn::Modified);
+    columns.toggle(DetailsColumn::Kind);
+    assert_eq!(columns.sort_options(), vec![SortColumn::Name]);
+}
+
+#[test]
+fn test_hiding_sorted_column_falls_back_to_name() {
+    let mut columns = VisibleColumns::new();
+    let mut sort = SortState::default();
+    sort.toggle_column(SortColumn::Size);
+    sort.add_tiebreaker(SortColumn::Date);
+    sort.add_tiebreaker(SortColumn::Type);
+    assert_eq!(columns.restrict_sort(sort), sort);
+
+    columns.toggle(DetailsColumn::Modified);
+    let restricted = columns.restrict_sort(sort);
+    assert_eq!(restricted.column, SortColumn::Size);
+    assert_eq!(
+        restricted.keys(),
+        vec![
+            (SortColumn::Size, SortDirection::Descending),
+            (SortColumn::Type, SortDirection::Ascending),
+        ]
+    );
+
+    columns.toggle(DetailsColumn::Size);
+    let restricted = columns.restrict_sort(sort);
+    assert_eq!(restricted.column, SortColumn::Name);
+    assert_eq!(restricted.keys().len(), 1);
+}
+
+#[test]
+fn test_visible_columns_persist_through_settings() {
+    let mut columns = VisibleColumns::new();
+    columns.toggle(DetailsColumn::Tags);
+    columns.toggle(DetailsColumn::Kind);
+
+    let json = serde_json::to_string(&columns).unwrap();
+    let restored: VisibleColumns = serde_json::from_str(&json).unwrap();
+    assert_eq!(restored.columns(), columns.columns());
+}
+
+#[test]
+fn test_dividers_only_resize_adjacent_columns() {
+    let mut columns = VisibleColumns::new();
+    assert_eq!(columns.resize_divider(DetailsColumn::Kind), Some(SortColumn::Type));
+
+    columns.toggle(DetailsColumn::Modified);
+    assert_eq!(columns.resize_divider(DetailsColumn::Kind), None);
+    assert_eq!(columns.resize_divider(DetailsColumn::Size), Some(SortColumn::Size));
+    assert_eq!(columns.resize_divider(DetailsColumn::Tags), None);
+}
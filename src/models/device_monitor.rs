@@ -122,6 +122,62 @@ impl Device {
             Some(HealthStatus::Warning) | Some(HealthStatus::Critical)
         )
     }
+
+
+    pub fn needs_mount(&self) -> bool {
+        !self.is_mounted || self.path.starts_with("/dev/")
+    }
+
+    pub fn can_eject(&self) -> bool {
+        self.is_removable
+            && self.device_type != DeviceType::WslDistribution
+            && !self.needs_mount()
+    }
+
+
+    pub fn click_action(&self) -> DeviceAction {
+        if self.needs_mount() {
+            DeviceAction::Mount
+        } else {
+            DeviceAction::Open
+        }
+    }
+
+
+    pub fn quick_action(&self) -> Option<DeviceAction> {
+        if self.needs_mount() {
+            Some(DeviceAction::Mount)
+        } else if self.can_eject() {
+            Some(DeviceAction::Eject)
+        } else {
+            None
+        }
+    }
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeviceAction {
+    Open,
+    Mount,
+    Eject,
+}
+
+impl DeviceAction {
+    pub fn label(&self) -> &'static str {
+        match self {
+            DeviceAction::Open => "Open",
+            DeviceAction::Mount => "Mount",
+            DeviceAction::Eject => "Eject",
+        }
+    }
+}
+
+
+pub fn mounted_device_for<'a>(devices: &'a [Device], unmounted: &Device) -> Option<&'a Device> {
+    devices
+        .iter()
+        .find(|d| d.name == unmounted.name && !d.needs_mount())
 }
 
 
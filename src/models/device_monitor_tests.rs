@@ -649,6 +649,92 @@ fn test_device_with_encrypted() {
     assert!(device.is_encrypted);
 }
 
+#[test]
+fn test_mounted_fixed_drive_opens_without_quick_action() {
+    let device = Device::new(
+        DeviceId::new(1),
+        "Macintosh HD".to_string(),
+        PathBuf::from("/"),
+        DeviceType::InternalDrive,
+    );
+
+    assert_eq!(device.click_action(), DeviceAction::Open);
+    assert_eq!(device.quick_action(), None);
+    assert!(!device.can_eject());
+}
+
+#[test]
+fn test_mounted_removable_drive_opens_and_offers_eject() {
+    let device = Device::new(
+        DeviceId::new(2),
+        "USB Stick".to_string(),
+        PathBuf::from("/Volumes/USB Stick"),
+        DeviceType::UsbDrive,
+    )
+    .with_removable(true);
+
+    assert_eq!(device.click_action(), DeviceAction::Open);
+    assert_eq!(device.quick_action(), Some(DeviceAction::Eject));
+
+    let wsl = Device::new(
+        DeviceId::new(3),
+        "Ubuntu".to_string(),
+        PathBuf::from("\\\\wsl$\\Ubuntu"),
+        DeviceType::WslDistribution,
+    )
+    .with_removable(true);
+    assert_eq!(wsl.quick_action(), None);
+}
+
+#[test]
+fn test_unmounted_device_offers_mount_instead_of_eject() {
+    let raw = Device::new(
+        DeviceId::new(4),
+        "Backup".to_string(),
+        PathBuf::from("/dev/disk4s1"),
+        DeviceType::ExternalDrive,
+    )
+    .with_removable(true);
+
+    assert!(raw.needs_mount());
+    assert_eq!(raw.click_action(), DeviceAction::Mount);
+    assert_eq!(raw.quick_action(), Some(DeviceAction::Mount));
+
+    let mut flagged = Device::new(
+        DeviceId::new(5),
+        "Card".to_string(),
+        PathBuf::from("/media/card"),
+        DeviceType::UsbDrive,
+    )
+    .with_removable(true);
+    flagged.is_mounted = false;
+    assert_eq!(flagged.click_action(), DeviceAction::Mount);
+    assert!(!flagged.can_eject());
+}
+
+#[test]
+fn test_mounted_device_found_after_mount() {
+    let raw = Device::new(
+        DeviceId::new(4),
+        "Backup".to_string(),
+        PathBuf::from("/dev/disk4s1"),
+        DeviceType::ExternalDrive,
+    );
+    let mounted = Device::new(
+        DeviceId::new(6),
+        "Backup".to_string(),
+        PathBuf::from("/Volumes/Backup"),
+        DeviceType::ExternalDrive,
+    );
+
+    assert_eq!(mounted_device_for(&[raw.clone()], &raw), None);
+    let devices = vec![raw.clone(), mounted];
+    assert_eq!(
+        mounted_device_for(&devices, &raw).map(|d| d.path.clone()),
+        Some(PathBuf::from("/Volumes/Backup"))
+    );
+}
+
 #[cfg(test)]
 mod property_tests {
     use super::*;
@@ -0,0 +1,215 @@
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::SystemTime;
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum FileKind {
+    Folder,
+    Image,
+    Video,
+    Audio,
+    Document,
+    Code,
+    Archive,
+    Other,
+}
+
+impl FileKind {
+    pub fn from_path(path: &Path, is_dir: bool) -> Self {
+        if is_dir {
+            return FileKind::Folder;
+        }
+
+        let extension = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .map(|ext| ext.to_lowercase())
+            .unwrap_or_default();
+
+        match extension.as_str() {
+            "png" | "jpg" | "jpeg" | "gif" | "bmp" | "webp" | "ico" | "svg" | "tiff" | "tif"
+            | "heic" => FileKind::Image,
+            "mp4" | "mov" | "mkv" | "avi" | "webm" | "m4v" | "wmv" => FileKind::Video,
+            "mp3" | "wav" | "flac" | "aac" | "ogg" | "m4a" | "opus" => FileKind::Audio,
+            "pdf" | "doc" | "docx" | "xls" | "xlsx" | "ppt" | "pptx" | "odt" | "ods" | "rtf"
+            | "txt" | "md" | "csv" => FileKind::Document,
+            "rs" | "js" | "ts" | "jsx" | "tsx" | "py" | "rb" | "go" | "java" | "c" | "cpp"
+            | "h" | "hpp" | "css" | "scss" | "html" | "htm" | "json" | "yaml" | "yml"
+            | "toml" | "xml" | "sh" | "swift" | "kt" | "php" | "lua" | "sql" => FileKind::Code,
+            "zip" | "tar" | "gz" | "tgz" | "bz2" | "xz" | "zst" | "7z" | "rar" | "dmg"
+            | "iso" => FileKind::Archive,
+            _ => FileKind::Other,
+        }
+    }
+
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            FileKind::Folder => "Folders",
+            FileKind::Image => "Images",
+            FileKind::Video => "Videos",
+            FileKind::Audio => "Audio",
+            FileKind::Document => "Documents",
+            FileKind::Code => "Code",
+            FileKind::Archive => "Archives",
+            FileKind::Other => "Other",
+        }
+    }
+}
+
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SummaryFile {
+    pub name: String,
+    pub modified: SystemTime,
+}
+
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DirectorySummary {
+    pub item_count: usize,
+    pub total_size: u64,
+    pub subdir_count: usize,
+    pub file_count: usize,
+    pub newest: Option<SummaryFile>,
+    pub oldest: Option<SummaryFile>,
+    pub kinds: Vec<(FileKind, usize)>,
+}
+
+impl DirectorySummary {
+    pub fn kind_count(&self, kind: FileKind) -> usize {
+        self.kinds
+            .iter()
+            .find(|(k, _)| *k == kind)
+            .map(|(_, count)| *count)
+            .unwrap_or(0)
+    }
+}
+
+
+pub fn calculate_directory_stats(path: &Path) -> io::Result<(usize, u64, usize, usize)> {
+    let mut item_count = 0;
+    let mut total_size = 0u64;
+    let mut subdir_count = 0;
+    let mut file_count = 0;
+
+    for entry in fs::read_dir(path)? {
+        let entry = entry?;
+        let metadata = entry.metadata()?;
+        item_count += 1;
+
+        if metadata.is_dir() {
+            subdir_count += 1;
+        } else {
+            file_count += 1;
+            total_size += metadata.len();
+        }
+    }
+
+    Ok((item_count, total_size, subdir_count, file_count))
+}
+
+
+pub fn summarize_directory(path: &Path, cancelled: &AtomicBool) -> io::Result<DirectorySummary> {
+    let (item_count, total_size, subdir_count, file_count) = calculate_directory_stats(path)?;
+
+    let mut newest: Option<SummaryFile> = None;
+    let mut oldest: Option<SummaryFile> = None;
+    let mut kinds: HashMap<FileKind, usize> = HashMap::new();
+
+    for entry in fs::read_dir(path)? {
+        if cancelled.load(Ordering::SeqCst) {
+            return Err(io::Error::new(
+                io::ErrorKind::Interrupted,
+                "Directory summary cancelled",
+            ));
+        }
+
+        let entry = entry?;
+        let metadata = entry.metadata()?;
+        let entry_path = entry.path();
+        *kinds
+            .entry(FileKind::from_path(&entry_path, metadata.is_dir()))
+            .or_insert(0) += 1;
+
+        if metadata.is_dir() {
+            continue;
+        }
+
+        let modified = metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+        let name = entry.file_name().to_string_lossy().to_string();
+
+        if newest.as_ref().map_or(true, |f| modified > f.modified) {
+            newest = Some(SummaryFile {
+                name: name.clone(),
+                modified,
+            });
+        }
+        if oldest.as_ref().map_or(true, |f| modified < f.modified) {
+            oldest = Some(SummaryFile { name, modified });
+        }
+    }
+
+    let mut kinds: Vec<(FileKind, usize)> = kinds.into_iter().collect();
+    kinds.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+
+    Ok(DirectorySummary {
+        item_count,
+        total_size,
+        subdir_count,
+        file_count,
+        newest,
+        oldest,
+        kinds,
+    })
+}
+
+
+#[derive(Debug)]
+pub struct MtimeCache<T> {
+    entries: HashMap<PathBuf, (SystemTime, T)>,
+}
+
+impl<T> Default for MtimeCache<T> {
+    fn default() -> Self {
+        Self {
+            entries: HashMap::new(),
+        }
+    }
+}
+
+impl<T> MtimeCache<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+
+    pub fn get(&self, path: &Path, mtime: SystemTime) -> Option<&T> {
+        self.entries
+            .get(path)
+            .filter(|(cached_mtime, _)| *cached_mtime == mtime)
+            .map(|(_, value)| value)
+    }
+
+
+    pub fn insert(&mut self, path: PathBuf, mtime: SystemTime, value: T) {
+        self.entries.insert(path, (mtime, value));
+    }
+
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+
+pub type DirectorySummaryCache = MtimeCache<DirectorySummary>;
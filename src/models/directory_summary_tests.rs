@@ -0,0 +1,124 @@
+use super::directory_summary::*;
+use std::fs::{self, File};
+use std::path::Path;
+use std::sync::atomic::AtomicBool;
+use std::time::{Duration, SystemTime};
+use tempfile::TempDir;
+
+
+fn write_file(dir: &Path, name: &str, content: &[u8], age_secs: u64) {
+    let path = dir.join(name);
+    fs::write(&path, content).unwrap();
+    let file = File::options().write(true).open(&path).unwrap();
+    file.set_modified(SystemTime::now() - Duration::from_secs(age_secs))
+        .unwrap();
+}
+
+
+fn create_fixture() -> TempDir {
+    let temp = TempDir::new().unwrap();
+    let root = temp.path();
+    fs::create_dir(root.join("src")).unwrap();
+    fs::create_dir(root.join("assets")).unwrap();
+    write_file(&root.join("src"), "nested.rs", &[0u8; 500], 10);
+    write_file(root, "photo.png", &[0u8; 100], 3600);
+    write_file(root, "icon.svg", &[0u8; 50], 60);
+    write_file(root, "notes.md", &[0u8; 20], 86400);
+    write_file(root, "main.rs", &[0u8; 30], 5);
+    write_file(root, "bundle.tar.gz", &[0u8; 200], 120);
+    write_file(root, "LICENSE", &[0u8; 10], 600);
+    temp
+}
+
+#[test]
+fn test_summary_counts_and_size() {
+    let temp = create_fixture();
+    let cancelled = AtomicBool::new(false);
+    let summary = summarize_directory(temp.path(), &cancelled).unwrap();
+
+    assert_eq!(summary.item_count, 8);
+    assert_eq!(summary.subdir_count, 2);
+    assert_eq!(summary.file_count, 6);
+    assert_eq!(summary.total_size, 410);
+}
+
+#[test]
+fn test_summary_matches_directory_stats() {
+    let temp = create_fixture();
+    let cancelled = AtomicBool::new(false);
+    let summary = summarize_directory(temp.path(), &cancelled).unwrap();
+    let stats = calculate_directory_stats(temp.path()).unwrap();
+
+    assert_eq!(
+        (summary.item_count, summary.total_size, summary.subdir_count, summary.file_count),
+        stats
+    );
+}
+
+#[test]
+fn test_summary_newest_and_oldest_files() {
+    let temp = create_fixture();
+    let cancelled = AtomicBool::new(false);
+    let summary = summarize_directory(temp.path(), &cancelled).unwrap();
+
+    assert_eq!(summary.newest.unwrap().name, "main.rs");
+    assert_eq!(summary.oldest.unwrap().name, "notes.md");
+}
+
+#[test]
+fn test_summary_breakdown_by_kind() {
+    let temp = create_fixture();
+    let cancelled = AtomicBool::new(false);
+    let summary = summarize_directory(temp.path(), &cancelled).unwrap();
+
+    assert_eq!(summary.kind_count(FileKind::Folder), 2);
+    assert_eq!(summary.kind_count(FileKind::Image), 2);
+    assert_eq!(summary.kind_count(FileKind::Code), 1);
+    assert_eq!(summary.kind_count(FileKind::Document), 1);
+    assert_eq!(summary.kind_count(FileKind::Archive), 1);
+    assert_eq!(summary.kind_count(FileKind::Other), 1);
+    assert_eq!(summary.kind_count(FileKind::Video), 0);
+
+    let total: usize = summary.kinds.iter().map(|(_, count)| count).sum();
+    assert_eq!(total, summary.item_count);
+    assert!(summary.kinds.windows(2).all(|w| w[0].1 >= w[1].1));
+}
+
+#[test]
+fn test_summary_of_empty_directory() {
+    let temp = TempDir::new().unwrap();
+    let cancelled = AtomicBool::new(false);
+    let summary = summarize_directory(temp.path(), &cancelled).unwrap();
+
+    assert_eq!(summary.item_count, 0);
+    assert_eq!(summary.total_size, 0);
+    assert!(summary.newest.is_none());
+    assert!(summary.oldest.is_none());
+    assert!(summary.kinds.is_empty());
+}
+
+#[test]
+fn test_summary_respects_cancellation() {
+    let temp = create_fixture();
+    let cancelled = AtomicBool::new(true);
+    let err = summarize_directory(temp.path(), &cancelled).unwrap_err();
+
+    assert_eq!(err.kind(), std::io::ErrorKind::Interrupted);
+}
+
+#[test]
+fn test_summary_cache_keyed_by_mtime() {
+    let temp = create_fixture();
+    let cancelled = AtomicBool::new(false);
+    let summary = summarize_directory(temp.path(), &cancelled).unwrap();
+    let mtime = SystemTime::UNIX_EPOCH + Duration::from_secs(1000);
+
+    let mut cache = DirectorySummaryCache::new();
+    cache.insert(temp.path().to_path_buf(), mtime, summary.clone());
+
+    assert_eq!(cache.get(temp.path(), mtime), Some(&summary));
+    assert!(cache
+        .get(temp.path(), mtime + Duration::from_secs(1))
+        .is_none());
+    assert!(cache.get(&temp.path().join("src"), mtime).is_none());
+}
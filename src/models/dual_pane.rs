@@ -1,9 +1,16 @@
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use super::{FileEntry, SortState};
 
 
+pub const DEFAULT_SPLIT_RATIO: f32 = 0.5;
+
+pub const MIN_SPLIT_RATIO: f32 = 0.2;
+
+pub const MAX_SPLIT_RATIO: f32 = 0.8;
+
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub enum PaneSide {
     #[default]
@@ -120,12 +127,52 @@ impl Default for PaneState {
 
 
 
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DualPaneLayout {
+    pub left_path: PathBuf,
+    pub right_path: PathBuf,
+    pub active: PaneSide,
+    #[serde(default = "default_split_ratio")]
+    pub split_ratio: f32,
+    pub is_enabled: bool,
+}
+
+impl DualPaneLayout {
+    pub fn with_fallback(mut self, fallback: &Path) -> Self {
+        if !self.left_path.is_dir() {
+            self.left_path = fallback.to_path_buf();
+        }
+        if !self.right_path.is_dir() {
+            self.right_path = fallback.to_path_buf();
+        }
+        self.split_ratio = clamp_split_ratio(self.split_ratio);
+        self
+    }
+}
+
+
+fn default_split_ratio() -> f32 {
+    DEFAULT_SPLIT_RATIO
+}
+
+
+pub fn clamp_split_ratio(ratio: f32) -> f32 {
+    if ratio.is_finite() {
+        ratio.clamp(MIN_SPLIT_RATIO, MAX_SPLIT_RATIO)
+    } else {
+        DEFAULT_SPLIT_RATIO
+    }
+}
+
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DualPane {
     left: PaneState,
     right: PaneState,
     active: PaneSide,
     is_enabled: bool,
+    #[serde(default = "default_split_ratio")]
+    split_ratio: f32,
 }
 
 impl DualPane {
@@ -136,6 +183,7 @@ impl DualPane {
             right: PaneState::new(initial_path),
             active: PaneSide::Left,
             is_enabled: false,
+            split_ratio: DEFAULT_SPLIT_RATIO,
         }
     }
 
@@ -146,10 +194,44 @@ impl DualPane {
             right: PaneState::new(right_path),
             active: PaneSide::Left,
             is_enabled: false,
+            split_ratio: DEFAULT_SPLIT_RATIO,
         }
     }
 
 
+    pub fn from_layout(layout: DualPaneLayout, fallback: &Path) -> Self {
+        let layout = layout.with_fallback(fallback);
+        Self {
+            left: PaneState::new(layout.left_path),
+            right: PaneState::new(layout.right_path),
+            active: layout.active,
+            is_enabled: layout.is_enabled,
+            split_ratio: layout.split_ratio,
+        }
+    }
+
+
+    pub fn layout(&self) -> DualPaneLayout {
+        DualPaneLayout {
+            left_path: self.left.path.clone(),
+            right_path: self.right.path.clone(),
+            active: self.active,
+            split_ratio: self.split_ratio,
+            is_enabled: self.is_enabled,
+        }
+    }
+
+
+    pub fn split_ratio(&self) -> f32 {
+        self.split_ratio
+    }
+
+
+    pub fn set_split_ratio(&mut self, ratio: f32) {
+        self.split_ratio = clamp_split_ratio(ratio);
+    }
+
+
     pub fn enable(&mut self) {
         self.is_enabled = true;
     }
@@ -1,4 +1,6 @@
-use crate::models::{DualPane, FileEntry, PaneSide, PaneState};
+use crate::models::{
+    DualPane, DualPaneLayout, FileEntry, PaneSide, PaneState, MAX_SPLIT_RATIO,
+};
 use proptest::prelude::*;
 use std::path::PathBuf;
 use std::time::SystemTime;
@@ -13,6 +15,49 @@ fn create_test_entry(name: &str, is_dir: bool) -> FileEntry {
     )
 }
 
+#[test]
+fn test_dual_pane_layout_round_trips() {
+    let temp = tempfile::TempDir::new().unwrap();
+    let left = temp.path().join("left");
+    let right = temp.path().join("right");
+    std::fs::create_dir_all(&left).unwrap();
+    std::fs::create_dir_all(&right).unwrap();
+
+    let mut dual = DualPane::with_paths(left.clone(), right.clone());
+    dual.enable();
+    dual.set_active(PaneSide::Right);
+    dual.set_split_ratio(0.35);
+
+    let json = serde_json::to_string(&dual.layout()).unwrap();
+    let layout: DualPaneLayout = serde_json::from_str(&json).unwrap();
+    let restored = DualPane::from_layout(layout, temp.path());
+
+    assert!(restored.is_enabled());
+    assert_eq!(restored.active_side(), PaneSide::Right);
+    assert_eq!(restored.split_ratio(), 0.35);
+    assert_eq!(restored.left_pane().path, left);
+    assert_eq!(restored.destination_path(), &left);
+    assert_eq!(restored.active_pane().path, right);
+}
+
+#[test]
+fn test_dual_pane_layout_falls_back_for_missing_paths() {
+    let temp = tempfile::TempDir::new().unwrap();
+    let layout = DualPaneLayout {
+        left_path: temp.path().join("gone"),
+        right_path: temp.path().to_path_buf(),
+        active: PaneSide::Left,
+        split_ratio: 4.0,
+        is_enabled: true,
+    };
+
+    let restored = DualPane::from_layout(layout, &PathBuf::from("/home/fallback"));
+
+    assert_eq!(restored.left_pane().path, PathBuf::from("/home/fallback"));
+    assert_eq!(restored.right_pane().path, temp.path());
+    assert_eq!(restored.split_ratio(), MAX_SPLIT_RATIO);
+}
+
 #[test]
 fn test_pane_side_opposite() {
     assert_eq!(PaneSide::Left.opposite(), PaneSide::Right);
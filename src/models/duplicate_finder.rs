@@ -0,0 +1,166 @@
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use crate::models::{calculate_file_hash, HashAlgorithm};
+
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DuplicateCandidate {
+    pub path: PathBuf,
+    pub size: u64,
+}
+
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SizeBucket {
+    pub size: u64,
+    pub paths: Vec<PathBuf>,
+}
+
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DuplicateGroup {
+    pub size: u64,
+    pub digest: String,
+    pub paths: Vec<PathBuf>,
+}
+
+impl DuplicateGroup {
+    pub fn reclaimable_bytes(&self) -> u64 {
+        self.size * self.paths.len().saturating_sub(1) as u64
+    }
+
+    pub fn extras(&self, keep: usize) -> Vec<PathBuf> {
+        self.paths
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| *index != keep)
+            .map(|(_, path)| path.clone())
+            .collect()
+    }
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DuplicateScanProgress {
+    pub hashed: usize,
+    pub to_hash: usize,
+}
+
+
+pub fn collect_candidates(root: &Path, recursive: bool, cancel: &AtomicBool) -> Vec<DuplicateCandidate> {
+    let mut candidates = Vec::new();
+    let mut pending = vec![root.to_path_buf()];
+
+    while let Some(dir) = pending.pop() {
+        if cancel.load(Ordering::Relaxed) {
+            break;
+        }
+        let Ok(entries) = fs::read_dir(&dir) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            let Ok(file_type) = entry.file_type() else {
+                continue;
+            };
+            if file_type.is_symlink() {
+                continue;
+            }
+            if file_type.is_dir() {
+                if recursive {
+                    pending.push(entry.path());
+                }
+            } else if let Ok(metadata) = entry.metadata() {
+                if metadata.len() > 0 {
+                    candidates.push(DuplicateCandidate {
+                        path: entry.path(),
+                        size: metadata.len(),
+                    });
+                }
+            }
+        }
+    }
+
+    candidates
+}
+
+
+pub fn bucket_by_size(candidates: Vec<DuplicateCandidate>) -> Vec<SizeBucket> {
+    let mut by_size: HashMap<u64, Vec<PathBuf>> = HashMap::new();
+    for candidate in candidates {
+        by_size.entry(candidate.size).or_default().push(candidate.path);
+    }
+
+    let mut buckets: Vec<SizeBucket> = by_size
+        .into_iter()
+        .filter(|(_, paths)| paths.len() > 1)
+        .map(|(size, mut paths)| {
+            paths.sort();
+            SizeBucket { size, paths }
+        })
+        .collect();
+    buckets.sort_by(|a, b| b.size.cmp(&a.size).then_with(|| a.paths.cmp(&b.paths)));
+    buckets
+}
+
+
+pub fn group_by_digest(
+    buckets: &[SizeBucket],
+    cancel: &AtomicBool,
+    mut hash: impl FnMut(&Path) -> io::Result<String>,
+    mut on_progress: impl FnMut(DuplicateScanProgress),
+) -> Option<Vec<DuplicateGroup>> {
+    let to_hash = buckets.iter().map(|bucket| bucket.paths.len()).sum();
+    let mut hashed = 0;
+    let mut groups = Vec::new();
+
+    for bucket in buckets {
+        let mut by_digest: HashMap<String, Vec<PathBuf>> = HashMap::new();
+        for path in &bucket.paths {
+            if cancel.load(Ordering::Relaxed) {
+                return None;
+            }
+            if let Ok(digest) = hash(path) {
+                by_digest.entry(digest).or_default().push(path.clone());
+            }
+            hashed += 1;
+            on_progress(DuplicateScanProgress { hashed, to_hash });
+        }
+
+        let mut bucket_groups: Vec<DuplicateGroup> = by_digest
+            .into_iter()
+            .filter(|(_, paths)| paths.len() > 1)
+            .map(|(digest, paths)| DuplicateGroup {
+                size: bucket.size,
+                digest,
+                paths,
+            })
+            .collect();
+        bucket_groups.sort_by(|a, b| a.paths.cmp(&b.paths));
+        groups.extend(bucket_groups);
+    }
+
+    Some(groups)
+}
+
+
+pub fn find_duplicates(
+    root: &Path,
+    recursive: bool,
+    cancel: &AtomicBool,
+    on_progress: impl FnMut(DuplicateScanProgress),
+) -> Option<Vec<DuplicateGroup>> {
+    let buckets = bucket_by_size(collect_candidates(root, recursive, cancel));
+    if cancel.load(Ordering::Relaxed) {
+        return None;
+    }
+    group_by_digest(
+        &buckets,
+        cancel,
+        |path| calculate_file_hash(path, HashAlgorithm::Sha256),
+        on_progress,
+    )
+}
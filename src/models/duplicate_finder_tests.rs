@@ -0,0 +1,116 @@
+use super::duplicate_finder::*;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use tempfile::TempDir;
+
+
+fn fixtures() -> TempDir {
+    let dir = TempDir::new().unwrap();
+    let root = dir.path();
+    fs::write(root.join("a.txt"), "hello").unwrap();
+    fs::write(root.join("b.txt"), "hello").unwrap();
+    fs::write(root.join("c.txt"), "hellp").unwrap();
+    fs::write(root.join("unique.txt"), "only one of these").unwrap();
+    fs::write(root.join("empty1"), "").unwrap();
+    fs::write(root.join("empty2"), "").unwrap();
+    fs::create_dir(root.join("nested")).unwrap();
+    fs::write(root.join("nested").join("d.txt"), "hello").unwrap();
+    fs::write(root.join("nested").join("e.bin"), "same bytes").unwrap();
+    fs::write(root.join("f.bin"), "same bytes").unwrap();
+    dir
+}
+
+fn content_hash(path: &Path) -> io::Result<String> {
+    fs::read_to_string(path)
+}
+
+fn names(paths: &[PathBuf]) -> Vec<String> {
+    paths
+        .iter()
+        .map(|p| p.file_name().unwrap().to_string_lossy().into_owned())
+        .collect()
+}
+
+#[test]
+fn test_size_buckets_only_keep_collisions() {
+    let dir = fixtures();
+    let cancel = AtomicBool::new(false);
+    let buckets = bucket_by_size(collect_candidates(dir.path(), false, &cancel));
+
+    assert_eq!(buckets.len(), 1);
+    assert_eq!(buckets[0].size, 5);
+    assert_eq!(names(&buckets[0].paths), vec!["a.txt", "b.txt", "c.txt"]);
+}
+
+#[test]
+fn test_only_size_collisions_are_hashed() {
+    let dir = fixtures();
+    let cancel = AtomicBool::new(false);
+    let buckets = bucket_by_size(collect_candidates(dir.path(), false, &cancel));
+
+    let mut hashed = Vec::new();
+    let groups = group_by_digest(
+        &buckets,
+        &cancel,
+        |path| {
+            hashed.push(path.to_path_buf());
+            content_hash(path)
+        },
+        |_| {},
+    )
+    .unwrap();
+
+    assert_eq!(names(&hashed), vec!["a.txt", "b.txt", "c.txt"]);
+    assert_eq!(groups.len(), 1);
+    assert_eq!(names(&groups[0].paths), vec!["a.txt", "b.txt"]);
+    assert_eq!(groups[0].reclaimable_bytes(), 5);
+}
+
+#[test]
+fn test_recursive_scan_finds_duplicates_in_subfolders() {
+    let dir = fixtures();
+    let cancel = AtomicBool::new(false);
+    let mut progress = Vec::new();
+    let groups = find_duplicates(dir.path(), true, &cancel, |p| progress.push(p)).unwrap();
+
+    assert_eq!(groups.len(), 2);
+    assert_eq!(groups[0].size, 10);
+    assert_eq!(names(&groups[0].paths), vec!["f.bin", "e.bin"]);
+    assert_eq!(names(&groups[1].paths), vec!["a.txt", "b.txt", "d.txt"]);
+    assert_eq!(groups[1].digest.len(), 64);
+
+    let last = progress.last().unwrap();
+    assert_eq!((last.hashed, last.to_hash), (6, 6));
+}
+
+#[test]
+fn test_extras_keep_one_copy() {
+    let group = DuplicateGroup {
+        size: 5,
+        digest: "abc".to_string(),
+        paths: vec![PathBuf::from("/a"), PathBuf::from("/b"), PathBuf::from("/c")],
+    };
+
+    assert_eq!(group.extras(1), vec![PathBuf::from("/a"), PathBuf::from("/c")]);
+    assert_eq!(group.reclaimable_bytes(), 10);
+}
+
+#[test]
+fn test_cancelled_scan_returns_none() {
+    let dir = fixtures();
+    let cancel = AtomicBool::new(false);
+    let buckets = bucket_by_size(collect_candidates(dir.path(), true, &cancel));
+
+    let result = group_by_digest(
+        &buckets,
+        &cancel,
+        |path| {
+            cancel.store(true, Ordering::Relaxed);
+            content_hash(path)
+        },
+        |_| {},
+    );
+    assert!(result.is_none());
+}
@@ -0,0 +1,218 @@
+use std::fs;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use serde::{Deserialize, Serialize};
+
+
+const UNIX_SCRIPT_EXTENSIONS: &[&str] = &[
+    "sh", "bash", "zsh", "fish", "ksh", "csh", "command", "py", "rb", "pl", "php", "lua", "tcl",
+    "js",
+];
+
+const WINDOWS_SCRIPT_EXTENSIONS: &[&str] =
+    &["bat", "cmd", "ps1", "psm1", "vbs", "vbe", "js", "jse", "wsf", "wsh"];
+
+const WINDOWS_BINARY_EXTENSIONS: &[&str] = &["exe", "com", "scr", "msi", "pif", "cpl"];
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExecutableKind {
+    Script,
+    Binary,
+}
+
+impl ExecutableKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            ExecutableKind::Script => "script",
+            ExecutableKind::Binary => "program",
+        }
+    }
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExecutablePlatform {
+    Unix,
+    Windows,
+}
+
+impl ExecutablePlatform {
+    pub fn current() -> Self {
+        if cfg!(windows) {
+            ExecutablePlatform::Windows
+        } else {
+            ExecutablePlatform::Unix
+        }
+    }
+}
+
+
+pub fn classify_executable(
+    path: &Path,
+    unix_mode: Option<u32>,
+    header: &[u8],
+    platform: ExecutablePlatform,
+) -> Option<ExecutableKind> {
+    let extension = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(|ext| ext.to_lowercase())
+        .unwrap_or_default();
+
+    match platform {
+        ExecutablePlatform::Windows => {
+            if WINDOWS_BINARY_EXTENSIONS.contains(&extension.as_str()) {
+                Some(ExecutableKind::Binary)
+            } else if WINDOWS_SCRIPT_EXTENSIONS.contains(&extension.as_str()) {
+                Some(ExecutableKind::Script)
+            } else {
+                None
+            }
+        }
+        ExecutablePlatform::Unix => {
+            if unix_mode? & 0o111 == 0 {
+                return None;
+            }
+            if header.starts_with(b"#!") || UNIX_SCRIPT_EXTENSIONS.contains(&extension.as_str()) {
+                Some(ExecutableKind::Script)
+            } else {
+                Some(ExecutableKind::Binary)
+            }
+        }
+    }
+}
+
+
+pub fn detect_executable(path: &Path) -> Option<ExecutableKind> {
+    let metadata = fs::metadata(path).ok()?;
+    if !metadata.is_file() {
+        return None;
+    }
+
+    #[cfg(unix)]
+    let unix_mode = {
+        use std::os::unix::fs::PermissionsExt;
+        Some(metadata.permissions().mode())
+    };
+    #[cfg(not(unix))]
+    let unix_mode = None;
+
+    let mut header = [0u8; 2];
+    let read = fs::File::open(path)
+        .and_then(|mut file| file.read(&mut header))
+        .unwrap_or(0);
+    classify_executable(path, unix_mode, &header[..read], ExecutablePlatform::current())
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ExecutableAction {
+    #[default]
+    Ask,
+    Run,
+    OpenInEditor,
+    Reveal,
+}
+
+impl ExecutableAction {
+    pub const ALL: [ExecutableAction; 4] = [
+        ExecutableAction::Ask,
+        ExecutableAction::Run,
+        ExecutableAction::OpenInEditor,
+        ExecutableAction::Reveal,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ExecutableAction::Ask => "Ask Every Time",
+            ExecutableAction::Run => "Run",
+            ExecutableAction::OpenInEditor => "Open in Editor",
+            ExecutableAction::Reveal => "Reveal",
+        }
+    }
+
+    pub fn next(&self) -> Self {
+        let index = Self::ALL.iter().position(|a| a == self).unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExecutableOpenBehavior {
+    #[serde(default = "default_script_action")]
+    pub scripts: ExecutableAction,
+    #[serde(default)]
+    pub binaries: ExecutableAction,
+}
+
+fn default_script_action() -> ExecutableAction {
+    ExecutableAction::OpenInEditor
+}
+
+impl Default for ExecutableOpenBehavior {
+    fn default() -> Self {
+        Self {
+            scripts: default_script_action(),
+            binaries: ExecutableAction::Ask,
+        }
+    }
+}
+
+impl ExecutableOpenBehavior {
+    pub fn action_for(&self, kind: ExecutableKind) -> ExecutableAction {
+        match kind {
+            ExecutableKind::Script => self.scripts,
+            ExecutableKind::Binary => self.binaries,
+        }
+    }
+
+    pub fn set(&mut self, kind: ExecutableKind, action: ExecutableAction) {
+        match kind {
+            ExecutableKind::Script => self.scripts = action,
+            ExecutableKind::Binary => self.binaries = action,
+        }
+    }
+}
+
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExecutablePrompt {
+    pub path: PathBuf,
+    pub kind: ExecutableKind,
+    pub remember: bool,
+}
+
+impl ExecutablePrompt {
+    pub fn new(path: PathBuf, kind: ExecutableKind) -> Self {
+        Self {
+            path,
+            kind,
+            remember: true,
+        }
+    }
+}
+
+
+pub fn run_executable(path: &Path) -> Result<(), String> {
+    #[cfg(target_os = "windows")]
+    let mut command = {
+        let mut command = Command::new("cmd");
+        command.args(["/C", "start", ""]).arg(path);
+        command
+    };
+
+    #[cfg(not(target_os = "windows"))]
+    let mut command = Command::new(path);
+
+    if let Some(parent) = path.parent() {
+        command.current_dir(parent);
+    }
+    command
+        .spawn()
+        .map(|_| ())
+        .map_err(|e| format!("Failed to run: {}", e))
+}
@@ -0,0 +1,65 @@
+use super::executable_launch::*;
+use std::path::Path;
+
+
+#[test]
+fn test_unix_executables_detected_by_permission_bits() {
+    let unix = ExecutablePlatform::Unix;
+    let classify = |name: &str, mode: u32, header: &[u8]| {
+        classify_executable(Path::new(name), Some(mode), header, unix)
+    };
+
+    assert_eq!(classify("build", 0o755, b"\x7fELF"), Some(ExecutableKind::Binary));
+    assert_eq!(classify("deploy", 0o700, b"#!/bin/sh"), Some(ExecutableKind::Script));
+    assert_eq!(classify("tool.py", 0o744, b"im"), Some(ExecutableKind::Script));
+    assert_eq!(classify("notes.sh", 0o644, b"#!"), None);
+    assert_eq!(classify("setup.exe", 0o644, b"MZ"), None);
+    assert_eq!(classify_executable(Path::new("build"), None, b"", unix), None);
+}
+
+#[test]
+fn test_windows_executables_detected_by_extension() {
+    let windows = ExecutablePlatform::Windows;
+    let classify = |name: &str| classify_executable(Path::new(name), None, b"", windows);
+
+    assert_eq!(classify("setup.EXE"), Some(ExecutableKind::Binary));
+    assert_eq!(classify("installer.msi"), Some(ExecutableKind::Binary));
+    assert_eq!(classify("build.bat"), Some(ExecutableKind::Script));
+    assert_eq!(classify("profile.ps1"), Some(ExecutableKind::Script));
+    assert_eq!(classify("readme.txt"), None);
+    assert_eq!(
+        classify_executable(Path::new("run"), Some(0o755), b"#!", windows),
+        None
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn test_detect_executable_reads_file_permissions() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let dir = tempfile::tempdir().unwrap();
+    let script = dir.path().join("run");
+    std::fs::write(&script, "#!/bin/sh\necho hi\n").unwrap();
+    assert_eq!(detect_executable(&script), None);
+
+    std::fs::set_permissions(&script, std::fs::Permissions::from_mode(0o755)).unwrap();
+    assert_eq!(detect_executable(&script), Some(ExecutableKind::Script));
+    assert_eq!(detect_executable(dir.path()), None);
+}
+
+#[test]
+fn test_default_behavior_edits_scripts_and_asks_for_binaries() {
+    let mut behavior = ExecutableOpenBehavior::default();
+    assert_eq!(behavior.action_for(ExecutableKind::Script), ExecutableAction::OpenInEditor);
+    assert_eq!(behavior.action_for(ExecutableKind::Binary), ExecutableAction::Ask);
+
+    behavior.set(ExecutableKind::Binary, ExecutableAction::Reveal);
+    let json = serde_json::to_string(&behavior).unwrap();
+    let restored: ExecutableOpenBehavior = serde_json::from_str(&json).unwrap();
+    assert_eq!(restored.binaries, ExecutableAction::Reveal);
+
+    let partial: ExecutableOpenBehavior = serde_json::from_str("{}").unwrap();
+    assert_eq!(partial, ExecutableOpenBehavior::default());
+    assert_eq!(ExecutableAction::Reveal.next(), ExecutableAction::Ask);
+}
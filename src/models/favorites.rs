@@ -21,6 +21,12 @@ pub enum FavoritesError {
     #[error("Index out of bounds: {0}")]
     IndexOutOfBounds(usize),
 
+    #[error("Favorite group not found")]
+    GroupNotFound,
+
+    #[error("Favorite group name cannot be empty")]
+    EmptyGroupName,
+
     #[error("IO error: {0}")]
     Io(String),
 
@@ -35,6 +41,8 @@ pub struct Favorite {
     pub path: PathBuf,
     #[serde(default)]
     pub is_valid: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub group: Option<FavoriteGroupId>,
 }
 
 impl Favorite {
@@ -51,6 +59,7 @@ impl Favorite {
             name,
             path,
             is_valid,
+            group: None,
         }
     }
 
@@ -60,6 +69,7 @@ impl Favorite {
             name,
             path,
             is_valid,
+            group: None,
         }
     }
 
@@ -71,9 +81,24 @@ impl Favorite {
 }
 
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct FavoriteGroupId(pub u64);
+
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct FavoriteGroup {
+    pub id: FavoriteGroupId,
+    pub name: String,
+    #[serde(default)]
+    pub collapsed: bool,
+}
+
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Favorites {
     items: Vec<Favorite>,
+    #[serde(default)]
+    groups: Vec<FavoriteGroup>,
     #[serde(skip)]
     max_count: usize,
 }
@@ -88,6 +113,7 @@ impl Favorites {
     pub fn new() -> Self {
         Self {
             items: Vec::new(),
+            groups: Vec::new(),
             max_count: MAX_FAVORITES,
         }
     }
@@ -147,6 +173,113 @@ impl Favorites {
     }
 
 
+    pub fn move_favorite(&mut self, from: usize, to: usize) -> Result<(), FavoritesError> {
+        let group = self
+            .items
+            .get(to)
+            .ok_or(FavoritesError::IndexOutOfBounds(to))?
+            .group;
+        self.reorder(from, to)?;
+        self.items[to].group = group;
+        Ok(())
+    }
+
+
+    pub fn move_to_group(
+        &mut self,
+        index: usize,
+        group: Option<FavoriteGroupId>,
+    ) -> Result<(), FavoritesError> {
+        if index >= self.items.len() {
+            return Err(FavoritesError::IndexOutOfBounds(index));
+        }
+        if let Some(id) = group {
+            self.group(id).ok_or(FavoritesError::GroupNotFound)?;
+        }
+
+        let mut item = self.items.remove(index);
+        item.group = group;
+        self.items.push(item);
+        Ok(())
+    }
+
+
+    pub fn add_group(&mut self, name: &str) -> Result<FavoriteGroupId, FavoritesError> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(FavoritesError::EmptyGroupName);
+        }
+
+        let next = self.groups.iter().map(|g| g.id.0).max().unwrap_or(0) + 1;
+        let id = FavoriteGroupId(next);
+        self.groups.push(FavoriteGroup {
+            id,
+            name: name.to_string(),
+            collapsed: false,
+        });
+        Ok(id)
+    }
+
+
+    pub fn remove_group(&mut self, id: FavoriteGroupId) -> Result<FavoriteGroup, FavoritesError> {
+        let index = self
+            .groups
+            .iter()
+            .position(|g| g.id == id)
+            .ok_or(FavoritesError::GroupNotFound)?;
+
+        for item in self.items.iter_mut().filter(|f| f.group == Some(id)) {
+            item.group = None;
+        }
+        Ok(self.groups.remove(index))
+    }
+
+
+    pub fn rename_group(&mut self, id: FavoriteGroupId, name: &str) -> Result<(), FavoritesError> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(FavoritesError::EmptyGroupName);
+        }
+        let group = self
+            .groups
+            .iter_mut()
+            .find(|g| g.id == id)
+            .ok_or(FavoritesError::GroupNotFound)?;
+        group.name = name.to_string();
+        Ok(())
+    }
+
+
+    pub fn toggle_group_collapsed(&mut self, id: FavoriteGroupId) -> Result<bool, FavoritesError> {
+        let group = self
+            .groups
+            .iter_mut()
+            .find(|g| g.id == id)
+            .ok_or(FavoritesError::GroupNotFound)?;
+        group.collapsed = !group.collapsed;
+        Ok(group.collapsed)
+    }
+
+
+    pub fn groups(&self) -> &[FavoriteGroup] {
+        &self.groups
+    }
+
+
+    pub fn group(&self, id: FavoriteGroupId) -> Option<&FavoriteGroup> {
+        self.groups.iter().find(|g| g.id == id)
+    }
+
+
+    pub fn members(&self, group: Option<FavoriteGroupId>) -> Vec<(usize, &Favorite)> {
+        self.items
+            .iter()
+            .enumerate()
+            .filter(|(_, f)| f.group == group)
+            .collect()
+    }
+
+
     pub fn items(&self) -> &[Favorite] {
         &self.items
     }
@@ -222,6 +355,13 @@ impl Favorites {
 
         favorites.max_count = MAX_FAVORITES;
 
+        let groups: Vec<FavoriteGroupId> = favorites.groups.iter().map(|g| g.id).collect();
+        for item in favorites.items.iter_mut() {
+            if item.group.is_some_and(|id| !groups.contains(&id)) {
+                item.group = None;
+            }
+        }
+
         favorites.validate_all();
 
         Ok(favorites)
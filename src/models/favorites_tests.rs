@@ -46,6 +46,7 @@ fn test_favorite_validate_nonexistent() {
         name: "Test".to_string(),
         path: PathBuf::from("/nonexistent/path/that/does/not/exist"),
         is_valid: true,
+        group: None,
     };
 
     assert!(!fav.validate());
@@ -212,6 +213,7 @@ fn test_favorites_validate_all() {
         name: "Invalid".to_string(),
         path: PathBuf::from("/nonexistent/path"),
         is_valid: true,
+        group: None,
     });
 
     let invalid = favs.validate_all();
@@ -273,6 +275,83 @@ fn test_favorites_is_full() {
     assert!(favs.is_full());
 }
 
+fn grouped_favorites(temp: &TempDir, count: usize) -> (Favorites, Vec<PathBuf>) {
+    let mut favs = Favorites::new();
+    let paths: Vec<PathBuf> = (0..count)
+        .map(|i| {
+            let p = temp.path().join(format!("dir{}", i));
+            std::fs::create_dir(&p).unwrap();
+            favs.add(p.clone()).unwrap();
+            p
+        })
+        .collect();
+    (favs, paths)
+}
+
+fn member_paths(favs: &Favorites, group: Option<FavoriteGroupId>) -> Vec<PathBuf> {
+    favs.members(group)
+        .into_iter()
+        .map(|(_, f)| f.path.clone())
+        .collect()
+}
+
+#[test]
+fn test_grouped_favorites_serialization_roundtrip() {
+    let temp = create_temp_dir();
+    let (mut favs, paths) = grouped_favorites(&temp, 3);
+
+    let projects = favs.add_group("Projects").unwrap();
+    let media = favs.add_group("  Media ").unwrap();
+    favs.move_to_group(0, Some(projects)).unwrap();
+    favs.toggle_group_collapsed(media).unwrap();
+    favs.rename_group(projects, "Work").unwrap();
+
+    let json = serde_json::to_string(&favs).unwrap();
+    let restored: Favorites = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(restored.groups(), favs.groups());
+    assert_eq!(restored.group(projects).unwrap().name, "Work");
+    assert_eq!(restored.group(media).unwrap().name, "Media");
+    assert!(restored.group(media).unwrap().collapsed);
+    assert_eq!(member_paths(&restored, Some(projects)), vec![paths[0].clone()]);
+    assert_eq!(member_paths(&restored, None), vec![paths[1].clone(), paths[2].clone()]);
+
+    let legacy = r#"{"items":[{"name":"dir0","path":"/tmp/dir0"}]}"#;
+    let legacy: Favorites = serde_json::from_str(legacy).unwrap();
+    assert!(legacy.groups().is_empty());
+    assert_eq!(legacy.items()[0].group, None);
+}
+
+#[test]
+fn test_favorites_reorder_within_group() {
+    let temp = create_temp_dir();
+    let (mut favs, paths) = grouped_favorites(&temp, 4);
+    let projects = favs.add_group("Projects").unwrap();
+
+    favs.move_to_group(favs.find_index(&paths[1]).unwrap(), Some(projects)).unwrap();
+    favs.move_to_group(favs.find_index(&paths[3]).unwrap(), Some(projects)).unwrap();
+    assert_eq!(member_paths(&favs, Some(projects)), vec![paths[1].clone(), paths[3].clone()]);
+
+    let from = favs.find_index(&paths[3]).unwrap();
+    let to = favs.find_index(&paths[1]).unwrap();
+    favs.move_favorite(from, to).unwrap();
+    assert_eq!(member_paths(&favs, Some(projects)), vec![paths[3].clone(), paths[1].clone()]);
+    assert_eq!(member_paths(&favs, None), vec![paths[0].clone(), paths[2].clone()]);
+
+    let from = favs.find_index(&paths[2]).unwrap();
+    let to = favs.find_index(&paths[3]).unwrap();
+    favs.move_favorite(from, to).unwrap();
+    assert_eq!(
+        member_paths(&favs, Some(projects)),
+        vec![paths[3].clone(), paths[2].clone(), paths[1].clone()]
+    );
+
+    favs.remove_group(projects).unwrap();
+    assert_eq!(favs.members(None).len(), 4);
+    assert_eq!(favs.move_to_group(0, Some(projects)), Err(FavoritesError::GroupNotFound));
+    assert_eq!(favs.add_group("   "), Err(FavoritesError::EmptyGroupName));
+}
+
 #[cfg(test)]
 mod property_tests {
     use super::*;
@@ -346,6 +425,7 @@ mod property_tests {
                 name: "Test".to_string(),
                 path: invalid_path,
                 is_valid: true,
+                group: None,
             };
 
             let result = fav.validate();
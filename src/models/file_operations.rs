@@ -6,6 +6,8 @@ use std::time::{Duration, Instant};
 use flume::{Receiver, Sender};
 use serde::{Deserialize, Serialize};
 
+use crate::models::TransferRate;
+
 
 #[derive(Debug, Clone)]
 pub struct UndoableOperation {
@@ -186,6 +188,8 @@ pub enum OperationType {
     Copy,
     Move,
     Delete,
+    Compress,
+    Extract,
 }
 
 impl std::fmt::Display for OperationType {
@@ -194,6 +198,8 @@ impl std::fmt::Display for OperationType {
             OperationType::Copy => write!(f, "Copying"),
             OperationType::Move => write!(f, "Moving"),
             OperationType::Delete => write!(f, "Deleting"),
+            OperationType::Compress => write!(f, "Compressing"),
+            OperationType::Extract => write!(f, "Extracting"),
         }
     }
 }
@@ -208,6 +214,7 @@ pub struct OperationProgress {
     pub current_file: Option<String>,
     pub speed_bytes_per_sec: u64,
     pub estimated_remaining: Duration,
+    pub rate: TransferRate,
 }
 
 impl OperationProgress {
@@ -220,6 +227,7 @@ impl OperationProgress {
             current_file: None,
             speed_bytes_per_sec: 0,
             estimated_remaining: Duration::ZERO,
+            rate: TransferRate::new(),
         }
     }
 
@@ -234,15 +242,22 @@ impl OperationProgress {
     }
 
     pub fn update_speed(&mut self, bytes_transferred: u64, elapsed: Duration) {
-        if elapsed.as_secs_f64() > 0.0 {
-            self.speed_bytes_per_sec = (bytes_transferred as f64 / elapsed.as_secs_f64()) as u64;
+        self.rate.record(elapsed, bytes_transferred);
+        let remaining_bytes = self.total_bytes.saturating_sub(self.transferred_bytes);
+        self.speed_bytes_per_sec = self.rate.bytes_per_sec().unwrap_or(0);
+        self.estimated_remaining = self
+            .rate
+            .estimate_remaining(remaining_bytes)
+            .unwrap_or(Duration::ZERO);
+    }
 
-            let remaining_bytes = self.total_bytes.saturating_sub(self.transferred_bytes);
-            if self.speed_bytes_per_sec > 0 {
-                let remaining_secs = remaining_bytes / self.speed_bytes_per_sec;
-                self.estimated_remaining = Duration::from_secs(remaining_secs);
-            }
-        }
+    pub fn record_transfer(&mut self, transferred_bytes: u64, elapsed: Duration) {
+        self.transferred_bytes = transferred_bytes;
+        self.update_speed(transferred_bytes, elapsed);
+    }
+
+    pub fn is_calculating_rate(&self) -> bool {
+        self.total_bytes > 0 && self.rate.is_calculating()
     }
 }
 
@@ -2050,6 +2065,8 @@ mod tests {
         assert_eq!(format!("{}", OperationType::Copy), "Copying");
         assert_eq!(format!("{}", OperationType::Move), "Moving");
         assert_eq!(format!("{}", OperationType::Delete), "Deleting");
+        assert_eq!(format!("{}", OperationType::Compress), "Compressing");
+        assert_eq!(format!("{}", OperationType::Extract), "Extracting");
     }
 
 
@@ -6,11 +6,12 @@ use lru::LruCache;
 use std::num::NonZeroUsize;
 
 use super::{
-    CachedDirectory, CloudStorageManager, CloudSyncStatus, FileEntry, LoadState, SyncStatus,
+    classify_hidden, CachedDirectory, CloudStorageManager, CloudSyncStatus, FileEntry,
+    HiddenAttributes, LoadState, SyncStatus,
 };
 use crate::io::{
     create_batch_pipeline, traverse_directory_sorted, BatchConfig, SortKey, SortOrder,
-    TraversalConfig,
+    TraversalConfig, TraversalSummary,
 };
 
 
@@ -27,6 +28,9 @@ pub struct FileSystem {
     state: LoadState,
     request_id: usize,
     cache: LruCache<PathBuf, CachedDirectory>,
+    read_errors: Vec<(PathBuf, std::io::Error)>,
+    batch_config: BatchConfig,
+    include_hidden: bool,
 }
 
 impl FileSystem {
@@ -41,6 +45,9 @@ impl FileSystem {
             state: LoadState::Idle,
             request_id: 0,
             cache: LruCache::new(cache_capacity),
+            read_errors: Vec::new(),
+            batch_config: BatchConfig::default(),
+            include_hidden: false,
         }
     }
 
@@ -55,6 +62,9 @@ impl FileSystem {
             state: LoadState::Idle,
             request_id: 0,
             cache: LruCache::new(cache_capacity),
+            read_errors: Vec::new(),
+            batch_config: BatchConfig::default(),
+            include_hidden: false,
         }
     }
 
@@ -79,6 +89,21 @@ impl FileSystem {
     }
 
 
+    pub fn batch_config(&self) -> &BatchConfig {
+        &self.batch_config
+    }
+
+
+    pub fn set_batch_config(&mut self, config: BatchConfig) {
+        self.batch_config = config.validated();
+    }
+
+
+    pub fn read_errors(&self) -> &[(PathBuf, std::io::Error)] {
+        &self.read_errors
+    }
+
+
 
 
 
@@ -89,6 +114,7 @@ impl FileSystem {
         self.current_path = path.clone();
 
         self.entries.clear();
+        self.read_errors.clear();
         self.state = LoadState::Loading {
             request_id: self.request_id,
         };
@@ -133,6 +159,20 @@ impl FileSystem {
 
 
 
+    pub fn set_read_errors(
+        &mut self,
+        request_id: usize,
+        errors: Vec<(PathBuf, std::io::Error)>,
+    ) -> bool {
+        if !self.is_valid_request(request_id) {
+            return false;
+        }
+
+        self.read_errors = errors;
+        true
+    }
+
+
     pub fn set_error(&mut self, request_id: usize, message: String) -> bool {
         if !self.is_valid_request(request_id) {
             return false;
@@ -180,7 +220,7 @@ impl FileSystem {
 pub struct LoadOperation {
     pub request_id: usize,
     pub batch_receiver: Receiver<Vec<FileEntry>>,
-    pub traversal_handle: std::thread::JoinHandle<crate::models::Result<usize>>,
+    pub traversal_handle: std::thread::JoinHandle<crate::models::Result<TraversalSummary>>,
 }
 
 impl FileSystem {
@@ -201,19 +241,24 @@ impl FileSystem {
         include_hidden: bool,
     ) -> LoadOperation {
         let request_id = self.begin_load(path.clone());
+        self.include_hidden = include_hidden;
 
         if super::is_trash_path(&path) {
-            let batch_config = BatchConfig::default();
+            let batch_config = self.batch_config.clone();
             let (entry_tx, batch_rx, _batch_handle) = create_batch_pipeline(batch_config);
 
-            let traversal_handle = std::thread::spawn(move || -> crate::models::Result<usize> {
-                let entries = super::list_trash_items();
-                let count = entries.len();
-                for entry in entries {
-                    let _ = entry_tx.send(entry);
-                }
-                Ok(count)
-            });
+            let traversal_handle =
+                std::thread::spawn(move || -> crate::models::Result<TraversalSummary> {
+                    let entries = super::list_trash_items();
+                    let count = entries.len();
+                    for entry in entries {
+                        let _ = entry_tx.send(entry);
+                    }
+                    Ok(TraversalSummary {
+                        count,
+                        errors: Vec::new(),
+                    })
+                });
 
             return LoadOperation {
                 request_id,
@@ -227,9 +272,10 @@ impl FileSystem {
             sort_order,
             include_hidden,
             max_depth: Some(1),
+            follow_symlinks: false,
         };
 
-        let batch_config = BatchConfig::default();
+        let batch_config = self.batch_config.clone();
         let (entry_tx, batch_rx, _batch_handle) = create_batch_pipeline(batch_config);
 
         let traversal_handle =
@@ -323,14 +369,15 @@ pub fn load_directory_sync(
         fs.process_batch(request_id, batch);
     }
 
-    let result = op.traversal_handle.join().map_err(|_| {
+    let summary = op.traversal_handle.join().map_err(|_| {
         crate::models::FileSystemError::Platform("Traversal thread panicked".to_string())
-    })?;
+    })??;
 
     let duration = start.elapsed();
+    fs.set_read_errors(request_id, summary.errors);
     fs.finalize_load(request_id, duration);
 
-    result
+    Ok(summary.count)
 }
 
 impl Default for FileSystem {
@@ -384,6 +431,9 @@ impl FileSystem {
         }
 
         if let Some(entry) = Self::create_entry_from_path(&path) {
+            if !self.include_hidden && entry.hidden_kind.is_dimmed() {
+                return false;
+            }
             let insert_pos = self
                 .entries
                 .binary_search_by(|e| e.name.cmp(&entry.name))
@@ -434,6 +484,11 @@ impl FileSystem {
 
         match (from_in_dir, to_in_dir) {
             (true, true) => {
+                let now_hidden = Self::create_entry_from_path(&to)
+                    .is_some_and(|entry| entry.hidden_kind.is_dimmed());
+                if !self.include_hidden && now_hidden {
+                    return self.handle_deleted(from);
+                }
                 if let Some(entry) = self.entries.iter_mut().find(|e| e.path == from) {
                     entry.path = to.clone();
                     entry.name = to
@@ -445,7 +500,7 @@ impl FileSystem {
                     self.invalidate_cache_for_current();
                     true
                 } else {
-                    false
+                    self.handle_created(to)
                 }
             }
             (true, false) => self.handle_deleted(from),
@@ -455,7 +510,12 @@ impl FileSystem {
     }
 
     fn create_entry_from_path(path: &Path) -> Option<FileEntry> {
-        FileEntry::from_path(path)
+        let mut entry = FileEntry::from_path(path)?;
+        if let Ok(metadata) = std::fs::symlink_metadata(path) {
+            entry.hidden_kind =
+                classify_hidden(&entry.name, HiddenAttributes::from_metadata(&metadata));
+        }
+        Some(entry)
     }
 
     fn invalidate_cache_for_current(&mut self) {
@@ -1,7 +1,7 @@
 
 
 use super::*;
-use crate::models::{CloudSyncStatus, FileEntry, FileType, IconKey, LoadState};
+use crate::models::{CloudSyncStatus, FileEntry, FileType, HiddenKind, IconKey, LoadState};
 use proptest::prelude::*;
 use std::path::PathBuf;
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
@@ -41,6 +41,9 @@ fn arb_file_entry() -> impl Strategy<Value = FileEntry> {
             symlink_target: None,
             is_broken_symlink: false,
             is_shared: false,
+            hidden_kind: HiddenKind::Visible,
+            created: None,
+            unix_mode: None,
         })
 }
 
@@ -384,6 +387,29 @@ proptest! {
 mod unit_tests {
     use super::*;
 
+    #[test]
+    fn test_watch_events_respect_hidden_setting() {
+        let (_temp_dir, dir_path) = setup_temp_dir_with_files(&["notes.txt"]);
+        let mut fs = create_fs_with_entries(&dir_path, &["notes.txt"]);
+
+        let dotfile = dir_path.join(".env");
+        File::create(&dotfile).unwrap();
+        assert!(!fs.process_event(FsEvent::Created(dotfile.clone())));
+        assert!(!fs.contains_path(&dotfile));
+
+        let notes = dir_path.join("notes.txt");
+        let renamed = dir_path.join(".notes.txt");
+        std::fs::rename(&notes, &renamed).unwrap();
+        assert!(fs.process_event(FsEvent::Renamed { from: notes.clone(), to: renamed.clone() }));
+        assert!(!fs.contains_path(&notes));
+        assert!(!fs.contains_path(&renamed));
+
+        let restored = dir_path.join("restored.txt");
+        std::fs::rename(&dotfile, &restored).unwrap();
+        assert!(fs.process_event(FsEvent::Renamed { from: dotfile, to: restored.clone() }));
+        assert!(fs.contains_path(&restored));
+    }
+
     #[test]
     fn test_new_filesystem_has_idle_state() {
         let fs = FileSystem::new(PathBuf::from("/home"));
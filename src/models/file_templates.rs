@@ -0,0 +1,271 @@
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use thiserror::Error;
+
+use crate::models::unique_destination;
+
+
+#[derive(Debug, Error)]
+pub enum TemplateError {
+    #[error("Invalid file name: {0}")]
+    InvalidName(String),
+
+    #[error("A file named {0} already exists")]
+    AlreadyExists(String),
+
+    #[error("IO error: {0}")]
+    Io(#[from] io::Error),
+}
+
+pub type TemplateResult<T> = Result<T, TemplateError>;
+
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileTemplate {
+    pub label: String,
+    pub file_name: String,
+    pub content: String,
+    pub icon: &'static str,
+    pub is_custom: bool,
+}
+
+impl FileTemplate {
+    fn builtin(label: &str, file_name: &str, icon: &'static str, content: &str) -> Self {
+        Self {
+            label: label.to_string(),
+            file_name: file_name.to_string(),
+            content: content.to_string(),
+            icon,
+            is_custom: false,
+        }
+    }
+
+
+    pub fn render(&self, path: &Path) -> String {
+        let name = path
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .unwrap_or("Untitled");
+        let folder = path
+            .parent()
+            .and_then(|p| p.file_name())
+            .and_then(|s| s.to_str())
+            .unwrap_or("project");
+        self.content
+            .replace("{{name}}", name)
+            .replace("{{folder}}", &crate_name(folder))
+    }
+}
+
+
+pub fn builtin_templates() -> Vec<FileTemplate> {
+    vec![
+        FileTemplate::builtin("Markdown Document", "Untitled.md", "file-text", "# {{name}}\n\n"),
+        FileTemplate::builtin(
+            "Git Ignore",
+            ".gitignore",
+            "file",
+            "# Build output\n/target/\n/dist/\n/build/\n\n# Dependencies\nnode_modules/\n\n# Editors and OS files\n.idea/\n.vscode/\n*.swp\n.DS_Store\nThumbs.db\n\n# Environment\n.env\n",
+        ),
+        FileTemplate::builtin(
+            "Cargo Manifest",
+            "Cargo.toml",
+            "file-code",
+            "[package]\nname = \"{{folder}}\"\nversion = \"0.1.0\"\nedition = \"2021\"\n\n[dependencies]\n",
+        ),
+        FileTemplate::builtin(
+            "HTML Page",
+            "index.html",
+            "file-code",
+            "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n    <meta charset=\"utf-8\">\n    <meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\n    <title>{{name}}</title>\n</head>\n<body>\n\n</body>\n</html>\n",
+        ),
+    ]
+}
+
+
+pub fn templates_dir() -> PathBuf {
+    dirs::config_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("nexus-explorer")
+        .join("templates")
+}
+
+
+pub fn load_templates(dir: &Path) -> Vec<FileTemplate> {
+    let mut templates = builtin_templates();
+    let Ok(entries) = fs::read_dir(dir) else {
+        return templates;
+    };
+
+    let mut custom: Vec<FileTemplate> = entries
+        .flatten()
+        .filter(|entry| entry.file_type().is_ok_and(|t| t.is_file()))
+        .filter_map(|entry| {
+            let file_name = entry.file_name().to_str()?.to_string();
+            let content = fs::read_to_string(entry.path()).ok()?;
+            Some(FileTemplate {
+                label: file_name.clone(),
+                file_name,
+                content,
+                icon: "file",
+                is_custom: true,
+            })
+        })
+        .collect();
+    custom.sort_by_key(|template| template.file_name.to_lowercase());
+
+    for template in custom {
+        match templates
+            .iter_mut()
+            .find(|existing| existing.file_name == template.file_name)
+        {
+            Some(existing) => {
+                existing.content = template.content;
+                existing.is_custom = true;
+            }
+            None => templates.push(template),
+        }
+    }
+    templates
+}
+
+
+pub fn validate_file_name(name: &str) -> TemplateResult<()> {
+    let trimmed = name.trim();
+    if trimmed.is_empty()
+        || trimmed == "."
+        || trimmed == ".."
+        || trimmed != name
+        || name.contains(['/', '\\', '\0'])
+    {
+        return Err(TemplateError::InvalidName(name.to_string()));
+    }
+    Ok(())
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NewEntryKind {
+    File,
+    Folder,
+}
+
+impl NewEntryKind {
+    fn noun(&self) -> &'static str {
+        match self {
+            NewEntryKind::File => "file",
+            NewEntryKind::Folder => "folder",
+        }
+    }
+}
+
+
+pub fn create_new_entry(directory: &Path, name: &str, kind: NewEntryKind) -> io::Result<PathBuf> {
+    if let Err(e) = validate_file_name(name) {
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, e.to_string()));
+    }
+    let path = directory.join(name);
+    match kind {
+        NewEntryKind::File => {
+            OpenOptions::new().write(true).create_new(true).open(&path)?;
+        }
+        NewEntryKind::Folder => fs::create_dir(&path)?,
+    }
+    Ok(path)
+}
+
+
+pub const NEW_FOLDER_WITH_ITEMS_NAME: &str = "New Folder With Items";
+
+
+#[derive(Debug, Default)]
+pub struct MoveIntoFolder {
+    pub moved: Vec<PathBuf>,
+    pub conflicts: Vec<(PathBuf, PathBuf)>,
+    pub failed: Vec<(PathBuf, io::Error)>,
+}
+
+
+pub fn move_into_folder(sources: &[PathBuf], folder: &Path) -> MoveIntoFolder {
+    let mut outcome = MoveIntoFolder::default();
+    for source in sources {
+        let Some(file_name) = source.file_name() else {
+            continue;
+        };
+        if source == folder || folder.starts_with(source) {
+            continue;
+        }
+        let destination = folder.join(file_name);
+        if fs::symlink_metadata(&destination).is_ok() {
+            outcome.conflicts.push((source.clone(), destination));
+            continue;
+        }
+        match fs::rename(source, &destination) {
+            Ok(()) => outcome.moved.push(destination),
+            Err(e) => outcome.failed.push((source.clone(), e)),
+        }
+    }
+    outcome
+}
+
+
+pub fn creation_error_message(kind: NewEntryKind, name: &str, error: &io::Error) -> String {
+    let reason = match error.kind() {
+        io::ErrorKind::AlreadyExists => format!("an item named \"{}\" already exists", name),
+        io::ErrorKind::PermissionDenied => "permission denied".to_string(),
+        io::ErrorKind::InvalidInput => format!("\"{}\" isn't a valid name", name),
+        _ => error.to_string(),
+    };
+    format!("Couldn't create {}: {}", kind.noun(), reason)
+}
+
+
+pub fn template_destination(template: &FileTemplate, directory: &Path) -> PathBuf {
+    let path = directory.join(&template.file_name);
+    if fs::symlink_metadata(&path).is_ok() {
+        unique_destination(&path)
+    } else {
+        path
+    }
+}
+
+
+pub fn create_from_template(
+    template: &FileTemplate,
+    directory: &Path,
+    name: &str,
+) -> TemplateResult<PathBuf> {
+    validate_file_name(name)?;
+    let path = directory.join(name);
+    let mut file = OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&path)
+        .map_err(|e| match e.kind() {
+            io::ErrorKind::AlreadyExists => TemplateError::AlreadyExists(name.to_string()),
+            _ => TemplateError::Io(e),
+        })?;
+    file.write_all(template.render(&path).as_bytes())?;
+    Ok(path)
+}
+
+fn crate_name(folder: &str) -> String {
+    let name: String = folder
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                c.to_ascii_lowercase()
+            } else {
+                '-'
+            }
+        })
+        .collect();
+    let name = name.trim_matches('-');
+    if name.is_empty() {
+        "project".to_string()
+    } else {
+        name.to_string()
+    }
+}
@@ -0,0 +1,179 @@
+use super::file_templates::*;
+use std::fs;
+use tempfile::TempDir;
+
+
+fn builtin(file_name: &str) -> FileTemplate {
+    builtin_templates()
+        .into_iter()
+        .find(|template| template.file_name == file_name)
+        .unwrap()
+}
+
+#[test]
+fn test_builtin_templates_write_expected_content() {
+    let dir = TempDir::new().unwrap();
+    let project = dir.path().join("My Tool");
+    fs::create_dir(&project).unwrap();
+
+    let notes = create_from_template(&builtin("Untitled.md"), &project, "Release Notes.md").unwrap();
+    assert_eq!(notes, project.join("Release Notes.md"));
+    assert_eq!(fs::read_to_string(&notes).unwrap(), "# Release Notes\n\n");
+
+    let manifest = create_from_template(&builtin("Cargo.toml"), &project, "Cargo.toml").unwrap();
+    let manifest = fs::read_to_string(manifest).unwrap();
+    assert!(manifest.starts_with("[package]\nname = \"my-tool\"\n"));
+    assert!(manifest.contains("[dependencies]"));
+
+    let page = create_from_template(&builtin("index.html"), &project, "about.html").unwrap();
+    let page = fs::read_to_string(page).unwrap();
+    assert!(page.starts_with("<!DOCTYPE html>"));
+    assert!(page.contains("<title>about</title>"));
+
+    let ignore = create_from_template(&builtin(".gitignore"), &project, ".gitignore").unwrap();
+    assert!(fs::read_to_string(ignore).unwrap().contains("/target/"));
+}
+
+#[test]
+fn test_existing_files_are_never_overwritten() {
+    let dir = TempDir::new().unwrap();
+    let existing = dir.path().join("Untitled.md");
+    fs::write(&existing, "keep me").unwrap();
+    let template = builtin("Untitled.md");
+
+    assert!(matches!(
+        create_from_template(&template, dir.path(), "Untitled.md"),
+        Err(TemplateError::AlreadyExists(_))
+    ));
+    assert_eq!(fs::read_to_string(&existing).unwrap(), "keep me");
+
+    let destination = template_destination(&template, dir.path());
+    assert_eq!(destination, dir.path().join("Untitled (1).md"));
+    let name = destination.file_name().unwrap().to_str().unwrap();
+    assert!(create_from_template(&template, dir.path(), name).is_ok());
+}
+
+#[test]
+fn test_invalid_names_are_rejected() {
+    let dir = TempDir::new().unwrap();
+    let template = builtin("Untitled.md");
+    for name in ["", "   ", ".", "..", "a/b.md", "a\\b.md", " padded.md"] {
+        assert!(
+            matches!(
+                create_from_template(&template, dir.path(), name),
+                Err(TemplateError::InvalidName(_))
+            ),
+            "{:?} should be rejected",
+            name
+        );
+    }
+    assert_eq!(fs::read_dir(dir.path()).unwrap().count(), 0);
+}
+
+#[test]
+fn test_user_templates_extend_and_override_builtins() {
+    let dir = TempDir::new().unwrap();
+    fs::write(dir.path().join("notes.txt"), "Notes for {{name}}\n").unwrap();
+    fs::write(dir.path().join(".gitignore"), "*.log\n").unwrap();
+    fs::create_dir(dir.path().join("nested")).unwrap();
+
+    let templates = load_templates(dir.path());
+    assert_eq!(templates.len(), builtin_templates().len() + 1);
+
+    let ignore = templates.iter().find(|t| t.file_name == ".gitignore").unwrap();
+    assert!(ignore.is_custom);
+    assert_eq!(ignore.content, "*.log\n");
+
+    let notes = templates.iter().find(|t| t.file_name == "notes.txt").unwrap();
+    let target = TempDir::new().unwrap();
+    let created = create_from_template(notes, target.path(), "meeting.txt").unwrap();
+    assert_eq!(fs::read_to_string(created).unwrap(), "Notes for meeting\n");
+
+    assert_eq!(load_templates(&dir.path().join("missing")), builtin_templates());
+}
+
+#[test]
+fn test_creation_errors_map_to_user_messages() {
+    let exists = std::io::Error::from(std::io::ErrorKind::AlreadyExists);
+    assert_eq!(
+        creation_error_message(NewEntryKind::Folder, "src", &exists),
+        "Couldn't create folder: an item named \"src\" already exists"
+    );
+
+    let denied = std::io::Error::from(std::io::ErrorKind::PermissionDenied);
+    assert_eq!(
+        creation_error_message(NewEntryKind::Folder, "src", &denied),
+        "Couldn't create folder: permission denied"
+    );
+
+    let invalid = std::io::Error::from(std::io::ErrorKind::InvalidInput);
+    assert_eq!(
+        creation_error_message(NewEntryKind::File, "a/b", &invalid),
+        "Couldn't create file: \"a/b\" isn't a valid name"
+    );
+
+    let other = std::io::Error::other("disk full");
+    assert_eq!(
+        creation_error_message(NewEntryKind::File, "notes.txt", &other),
+        "Couldn't create file: disk full"
+    );
+}
+
+#[test]
+fn test_create_new_entry_reports_error_kinds() {
+    let dir = TempDir::new().unwrap();
+    fs::write(dir.path().join("notes.txt"), "keep me").unwrap();
+
+    let err = create_new_entry(dir.path(), "notes.txt", NewEntryKind::File).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::AlreadyExists);
+    assert_eq!(fs::read_to_string(dir.path().join("notes.txt")).unwrap(), "keep me");
+
+    let err = create_new_entry(dir.path(), "../escape", NewEntryKind::Folder).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+
+    let folder = create_new_entry(dir.path(), "docs", NewEntryKind::Folder).unwrap();
+    assert!(folder.is_dir());
+    let err = create_new_entry(dir.path(), "docs", NewEntryKind::Folder).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::AlreadyExists);
+}
+
+#[test]
+fn test_selected_entries_move_into_new_folder() {
+    let dir = TempDir::new().unwrap();
+    let root = dir.path();
+    fs::write(root.join("a.txt"), "a").unwrap();
+    fs::write(root.join("b.txt"), "b").unwrap();
+    fs::create_dir(root.join("photos")).unwrap();
+    fs::write(root.join("photos").join("cat.jpg"), "meow").unwrap();
+    fs::write(root.join("stays.txt"), "stays").unwrap();
+
+    let folder = create_new_entry(root, NEW_FOLDER_WITH_ITEMS_NAME, NewEntryKind::Folder).unwrap();
+    let selection = vec![root.join("a.txt"), root.join("b.txt"), root.join("photos")];
+    let outcome = move_into_folder(&selection, &folder);
+
+    assert_eq!(outcome.moved.len(), 3);
+    assert!(outcome.conflicts.is_empty());
+    assert!(outcome.failed.is_empty());
+    for source in &selection {
+        assert!(!source.exists(), "{} should have moved", source.display());
+    }
+    assert_eq!(fs::read_to_string(folder.join("a.txt")).unwrap(), "a");
+    assert_eq!(fs::read_to_string(folder.join("photos").join("cat.jpg")).unwrap(), "meow");
+    assert!(root.join("stays.txt").exists());
+}
+
+#[test]
+fn test_move_into_folder_reports_conflicts_without_overwriting() {
+    let dir = TempDir::new().unwrap();
+    let root = dir.path();
+    let folder = create_new_entry(root, "bundle", NewEntryKind::Folder).unwrap();
+    fs::write(root.join("notes.txt"), "new").unwrap();
+    fs::write(folder.join("notes.txt"), "old").unwrap();
+
+    let outcome = move_into_folder(&[root.join("notes.txt"), folder.clone()], &folder);
+
+    assert!(outcome.moved.is_empty());
+    assert_eq!(outcome.conflicts, vec![(root.join("notes.txt"), folder.join("notes.txt"))]);
+    assert_eq!(fs::read_to_string(folder.join("notes.txt")).unwrap(), "old");
+    assert!(root.join("notes.txt").exists());
+}
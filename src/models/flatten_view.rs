@@ -0,0 +1,185 @@
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use crate::io::TraversalConfig;
+use crate::models::FileEntry;
+
+
+pub const FLATTEN_ENTRY_LIMIT: usize = 50_000;
+
+
+pub const FLATTEN_BATCH_SIZE: usize = 256;
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum IndexingStatus {
+    #[default]
+    Idle,
+    Scanning { scanned: usize },
+    Ready { total: usize },
+}
+
+impl IndexingStatus {
+    pub fn is_scanning(&self) -> bool {
+        matches!(self, IndexingStatus::Scanning { .. })
+    }
+
+    pub fn label(&self) -> Option<String> {
+        match self {
+            IndexingStatus::Scanning { scanned } => Some(format!("Indexing {} items…", scanned)),
+            IndexingStatus::Idle | IndexingStatus::Ready { .. } => None,
+        }
+    }
+}
+
+
+#[derive(Debug, Default)]
+pub struct TraversalBatch {
+    pub entries: Vec<FileEntry>,
+    pub closed: bool,
+}
+
+
+pub fn drain_batch(receiver: &flume::Receiver<FileEntry>, first: FileEntry, limit: usize) -> TraversalBatch {
+    let mut entries = vec![first];
+    let mut closed = false;
+    while entries.len() < limit {
+        match receiver.try_recv() {
+            Ok(entry) => entries.push(entry),
+            Err(flume::TryRecvError::Empty) => break,
+            Err(flume::TryRecvError::Disconnected) => {
+                closed = true;
+                break;
+            }
+        }
+    }
+    TraversalBatch { entries, closed }
+}
+
+
+#[derive(Debug)]
+pub struct FlattenSession {
+    root: PathBuf,
+    entries: Vec<FileEntry>,
+    limit: usize,
+    scanned: usize,
+    is_running: bool,
+    is_truncated: bool,
+    cancelled: Arc<AtomicBool>,
+}
+
+impl FlattenSession {
+    pub fn new(root: PathBuf, limit: usize) -> Self {
+        Self {
+            root,
+            entries: Vec::new(),
+            limit,
+            scanned: 0,
+            is_running: true,
+            is_truncated: false,
+            cancelled: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+
+    pub fn traversal_config(show_hidden: bool) -> TraversalConfig {
+        TraversalConfig {
+            include_hidden: show_hidden,
+            max_depth: None,
+            ..Default::default()
+        }
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    pub fn entries(&self) -> &[FileEntry] {
+        &self.entries
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.is_running
+    }
+
+    pub fn is_truncated(&self) -> bool {
+        self.is_truncated
+    }
+
+    pub fn cancel_handle(&self) -> Arc<AtomicBool> {
+        self.cancelled.clone()
+    }
+
+    pub fn indexing_status(&self) -> IndexingStatus {
+        if self.is_running {
+            IndexingStatus::Scanning {
+                scanned: self.scanned,
+            }
+        } else {
+            IndexingStatus::Ready {
+                total: self.entries.len(),
+            }
+        }
+    }
+
+
+    pub fn receive(&mut self, batch: TraversalBatch) -> Vec<FileEntry> {
+        let accepted = self.push_batch(batch.entries);
+        if batch.closed {
+            self.finish();
+        }
+        accepted
+    }
+
+
+    pub fn push_batch(&mut self, batch: Vec<FileEntry>) -> Vec<FileEntry> {
+        let mut accepted = Vec::new();
+        for entry in batch {
+            self.scanned += 1;
+            if entry.is_dir {
+                continue;
+            }
+            if self.entries.len() >= self.limit {
+                self.is_truncated = true;
+                self.stop();
+                break;
+            }
+            let flattened = self.flatten_entry(entry);
+            self.entries.push(flattened.clone());
+            accepted.push(flattened);
+        }
+        accepted
+    }
+
+
+    pub fn stop(&mut self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+        self.is_running = false;
+    }
+
+
+    pub fn finish(&mut self) {
+        self.is_running = false;
+    }
+
+    fn flatten_entry(&self, mut entry: FileEntry) -> FileEntry {
+        entry.name = flattened_name(&self.root, &entry.path);
+        entry
+    }
+}
+
+
+pub fn flattened_name(root: &Path, path: &Path) -> String {
+    path.strip_prefix(root)
+        .map(|relative| relative.to_string_lossy().replace('\\', "/"))
+        .unwrap_or_else(|_| path.to_string_lossy().to_string())
+}
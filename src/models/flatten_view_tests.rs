@@ -0,0 +1,134 @@
+use super::flatten_view::*;
+use crate::io::spawn_traversal;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tempfile::TempDir;
+
+
+fn create_tree() -> TempDir {
+    let temp = TempDir::new().unwrap();
+    let root = temp.path();
+    fs::create_dir_all(root.join("src/views")).unwrap();
+    fs::create_dir_all(root.join(".git/objects")).unwrap();
+    fs::write(root.join("README.md"), "readme").unwrap();
+    fs::write(root.join("src/main.rs"), "fn main() {}").unwrap();
+    fs::write(root.join("src/views/list.rs"), "").unwrap();
+    fs::write(root.join(".git/objects/abc"), "").unwrap();
+    fs::write(root.join(".env"), "").unwrap();
+    temp
+}
+
+
+fn collect(root: &Path, show_hidden: bool, limit: usize) -> FlattenSession {
+    let mut session = FlattenSession::new(root.to_path_buf(), limit);
+    let (receiver, handle) = spawn_traversal(
+        root.to_path_buf(),
+        FlattenSession::traversal_config(show_hidden),
+    );
+    let batch: Vec<_> = receiver.iter().collect();
+    let _ = handle.join();
+    session.push_batch(batch);
+    session.finish();
+    session
+}
+
+
+fn sorted_names(session: &FlattenSession) -> Vec<String> {
+    let mut names: Vec<String> = session.entries().iter().map(|e| e.name.clone()).collect();
+    names.sort();
+    names
+}
+
+#[test]
+fn test_flatten_lists_files_recursively_with_relative_names() {
+    let temp = create_tree();
+    let session = collect(temp.path(), false, FLATTEN_ENTRY_LIMIT);
+
+    assert_eq!(
+        sorted_names(&session),
+        vec!["README.md", "src/main.rs", "src/views/list.rs"]
+    );
+    assert!(!session.is_running());
+    assert!(!session.is_truncated());
+}
+
+#[test]
+fn test_flatten_includes_hidden_when_enabled() {
+    let temp = create_tree();
+    let session = collect(temp.path(), true, FLATTEN_ENTRY_LIMIT);
+
+    let names = sorted_names(&session);
+    assert!(names.contains(&".env".to_string()));
+    assert!(names.contains(&".git/objects/abc".to_string()));
+    assert_eq!(names.len(), 5);
+}
+
+#[test]
+fn test_flatten_stops_at_limit() {
+    let temp = create_tree();
+    let session = collect(temp.path(), true, 2);
+
+    assert_eq!(session.len(), 2);
+    assert!(session.is_truncated());
+    assert!(!session.is_running());
+    assert!(session
+        .cancel_handle()
+        .load(std::sync::atomic::Ordering::SeqCst));
+}
+
+#[test]
+fn test_stop_marks_session_cancelled() {
+    let mut session = FlattenSession::new(PathBuf::from("/tmp"), FLATTEN_ENTRY_LIMIT);
+    let handle = session.cancel_handle();
+    assert!(session.is_running());
+
+    session.stop();
+
+    assert!(!session.is_running());
+    assert!(handle.load(std::sync::atomic::Ordering::SeqCst));
+}
+
+#[test]
+fn test_flattened_name_outside_root_keeps_full_path() {
+    let root = Path::new("/projects/app");
+
+    assert_eq!(
+        flattened_name(root, Path::new("/projects/app/src/lib.rs")),
+        "src/lib.rs"
+    );
+    assert_eq!(
+        flattened_name(root, Path::new("/elsewhere/file.txt")),
+        "/elsewhere/file.txt"
+    );
+}
+
+#[test]
+fn test_indexing_status_becomes_ready_when_traversal_channel_closes() {
+    let temp = create_tree();
+    let mut session = FlattenSession::new(temp.path().to_path_buf(), FLATTEN_ENTRY_LIMIT);
+    assert_eq!(session.indexing_status(), IndexingStatus::Scanning { scanned: 0 });
+
+    let (receiver, handle) = spawn_traversal(
+        temp.path().to_path_buf(),
+        FlattenSession::traversal_config(false),
+    );
+    let _ = handle.join();
+
+    let first = receiver.recv().unwrap();
+    let partial = drain_batch(&receiver, first, 1);
+    assert!(!partial.closed);
+    session.receive(partial);
+    let status = session.indexing_status();
+    assert_eq!(status, IndexingStatus::Scanning { scanned: 1 });
+    assert!(status.label().is_some());
+
+    let next = receiver.recv().unwrap();
+    let rest = drain_batch(&receiver, next, FLATTEN_BATCH_SIZE);
+    assert!(rest.closed);
+    session.receive(rest);
+
+    let status = session.indexing_status();
+    assert_eq!(status, IndexingStatus::Ready { total: 3 });
+    assert!(!status.is_scanning());
+    assert!(status.label().is_none());
+}
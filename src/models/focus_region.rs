@@ -0,0 +1,87 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FocusRegion {
+    Sidebar,
+    FileView,
+    Preview,
+    Terminal,
+}
+
+impl FocusRegion {
+    pub const ORDER: [FocusRegion; 4] = [
+        FocusRegion::Sidebar,
+        FocusRegion::FileView,
+        FocusRegion::Preview,
+        FocusRegion::Terminal,
+    ];
+
+    fn position(self) -> usize {
+        Self::ORDER
+            .iter()
+            .position(|region| *region == self)
+            .unwrap_or(0)
+    }
+}
+
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RegionVisibility {
+    pub preview: bool,
+    pub terminal: bool,
+}
+
+impl RegionVisibility {
+    pub fn is_visible(&self, region: FocusRegion) -> bool {
+        match region {
+            FocusRegion::Sidebar | FocusRegion::FileView => true,
+            FocusRegion::Preview => self.preview,
+            FocusRegion::Terminal => self.terminal,
+        }
+    }
+
+    pub fn visible_regions(&self) -> Vec<FocusRegion> {
+        FocusRegion::ORDER
+            .into_iter()
+            .filter(|region| self.is_visible(*region))
+            .collect()
+    }
+}
+
+
+pub fn cycle_focus_region(
+    current: Option<FocusRegion>,
+    visibility: &RegionVisibility,
+    backwards: bool,
+) -> FocusRegion {
+    let len = FocusRegion::ORDER.len();
+    let start = match (current, backwards) {
+        (Some(region), _) => region.position(),
+        (None, false) => len - 1,
+        (None, true) => 0,
+    };
+
+    (1..=len)
+        .map(|step| {
+            let index = if backwards {
+                (start + len - step) % len
+            } else {
+                (start + step) % len
+            };
+            FocusRegion::ORDER[index]
+        })
+        .find(|region| visibility.is_visible(*region))
+        .unwrap_or(FocusRegion::FileView)
+}
+
+
+pub fn resolve_focus_region(
+    current: Option<FocusRegion>,
+    visibility: &RegionVisibility,
+) -> Option<FocusRegion> {
+    current.map(|region| {
+        if visibility.is_visible(region) {
+            region
+        } else {
+            FocusRegion::FileView
+        }
+    })
+}
@@ -0,0 +1,96 @@
+use super::focus_region::*;
+
+
+const ALL_VISIBLE: RegionVisibility = RegionVisibility {
+    preview: true,
+    terminal: true,
+};
+
+#[test]
+fn test_cycle_visits_regions_in_order_and_wraps() {
+    let mut current = None;
+    let mut visited = Vec::new();
+    for _ in 0..5 {
+        let next = cycle_focus_region(current, &ALL_VISIBLE, false);
+        visited.push(next);
+        current = Some(next);
+    }
+
+    assert_eq!(
+        visited,
+        vec![
+            FocusRegion::Sidebar,
+            FocusRegion::FileView,
+            FocusRegion::Preview,
+            FocusRegion::Terminal,
+            FocusRegion::Sidebar,
+        ]
+    );
+}
+
+#[test]
+fn test_cycle_backwards_reverses_order() {
+    assert_eq!(
+        cycle_focus_region(None, &ALL_VISIBLE, true),
+        FocusRegion::Terminal
+    );
+    assert_eq!(
+        cycle_focus_region(Some(FocusRegion::Sidebar), &ALL_VISIBLE, true),
+        FocusRegion::Terminal
+    );
+    assert_eq!(
+        cycle_focus_region(Some(FocusRegion::Preview), &ALL_VISIBLE, true),
+        FocusRegion::FileView
+    );
+}
+
+#[test]
+fn test_cycle_skips_hidden_regions() {
+    let no_preview = RegionVisibility {
+        preview: false,
+        terminal: true,
+    };
+    assert_eq!(
+        cycle_focus_region(Some(FocusRegion::FileView), &no_preview, false),
+        FocusRegion::Terminal
+    );
+    assert_eq!(
+        cycle_focus_region(Some(FocusRegion::Terminal), &no_preview, true),
+        FocusRegion::FileView
+    );
+
+    let neither = RegionVisibility::default();
+    assert_eq!(
+        neither.visible_regions(),
+        vec![FocusRegion::Sidebar, FocusRegion::FileView]
+    );
+    assert_eq!(
+        cycle_focus_region(Some(FocusRegion::FileView), &neither, false),
+        FocusRegion::Sidebar
+    );
+    assert_eq!(
+        cycle_focus_region(None, &neither, true),
+        FocusRegion::FileView
+    );
+}
+
+#[test]
+fn test_closed_region_hands_focus_to_next_visible() {
+    let closed_terminal = RegionVisibility {
+        preview: true,
+        terminal: false,
+    };
+    assert_eq!(
+        cycle_focus_region(Some(FocusRegion::Terminal), &closed_terminal, false),
+        FocusRegion::Sidebar
+    );
+    assert_eq!(
+        resolve_focus_region(Some(FocusRegion::Terminal), &closed_terminal),
+        Some(FocusRegion::FileView)
+    );
+    assert_eq!(
+        resolve_focus_region(Some(FocusRegion::Preview), &closed_terminal),
+        Some(FocusRegion::Preview)
+    );
+    assert_eq!(resolve_focus_region(None, &closed_terminal), None);
+}
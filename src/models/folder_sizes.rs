@@ -0,0 +1,226 @@
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime};
+
+use crate::models::MtimeCache;
+
+
+pub const FOLDER_SIZE_PLACEHOLDER: &str = "…";
+
+static SHOW_FOLDER_SIZES: AtomicBool = AtomicBool::new(false);
+
+
+pub fn set_show_folder_sizes(show: bool) {
+    SHOW_FOLDER_SIZES.store(show, Ordering::SeqCst);
+}
+
+
+pub fn show_folder_sizes() -> bool {
+    SHOW_FOLDER_SIZES.load(Ordering::SeqCst)
+}
+
+
+pub fn folder_size(path: &Path, cancelled: &AtomicBool) -> io::Result<u64> {
+    let mut total = 0u64;
+    let mut pending = vec![path.to_path_buf()];
+
+    while let Some(dir) = pending.pop() {
+        let entries = match fs::read_dir(&dir) {
+            Ok(entries) => entries,
+            Err(e) if dir == path => return Err(e),
+            Err(_) => continue,
+        };
+
+        for entry in entries.flatten() {
+            if cancelled.load(Ordering::SeqCst) {
+                return Err(io::Error::new(
+                    io::ErrorKind::Interrupted,
+                    "Folder size cancelled",
+                ));
+            }
+
+            let Ok(metadata) = entry.metadata() else {
+                continue;
+            };
+            if metadata.is_dir() {
+                pending.push(entry.path());
+            } else {
+                total += metadata.len();
+            }
+        }
+    }
+
+    Ok(total)
+}
+
+
+pub const DEFAULT_FOLDER_SIZE_CONCURRENCY: usize = 2;
+
+
+pub const FOLDER_SIZE_TTL: Duration = Duration::from_secs(30);
+
+
+pub type FolderSizeCache = MtimeCache<(Instant, Option<u64>)>;
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FolderSize {
+    Pending,
+    Ready(u64),
+    Unavailable,
+}
+
+
+#[derive(Debug, Clone)]
+pub struct FolderSizeJob {
+    pub path: PathBuf,
+    pub mtime: SystemTime,
+    pub cancelled: Arc<AtomicBool>,
+}
+
+
+#[derive(Debug)]
+pub struct FolderSizes {
+    cache: FolderSizeCache,
+    pending: Vec<(PathBuf, SystemTime)>,
+    in_flight: HashMap<PathBuf, (SystemTime, Arc<AtomicBool>)>,
+    max_concurrency: usize,
+    ttl: Duration,
+}
+
+impl Default for FolderSizes {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FolderSizes {
+    pub fn new() -> Self {
+        Self::with_limits(DEFAULT_FOLDER_SIZE_CONCURRENCY, FOLDER_SIZE_TTL)
+    }
+
+    pub fn with_limits(max_concurrency: usize, ttl: Duration) -> Self {
+        Self {
+            cache: FolderSizeCache::new(),
+            pending: Vec::new(),
+            in_flight: HashMap::new(),
+            max_concurrency: max_concurrency.max(1),
+            ttl,
+        }
+    }
+
+    pub fn get(&self, path: &Path, mtime: SystemTime) -> FolderSize {
+        match self.cache.get(path, mtime) {
+            Some((_, Some(size))) => FolderSize::Ready(*size),
+            Some((_, None)) => FolderSize::Unavailable,
+            None => FolderSize::Pending,
+        }
+    }
+
+    pub fn request(&mut self, path: &Path, mtime: SystemTime) -> bool {
+        if self
+            .cache
+            .get(path, mtime)
+            .is_some_and(|(computed, _)| computed.elapsed() < self.ttl)
+        {
+            return false;
+        }
+        if let Some((pending_mtime, cancelled)) = self.in_flight.get(path) {
+            if *pending_mtime == mtime {
+                return false;
+            }
+            cancelled.store(true, Ordering::SeqCst);
+            self.in_flight.remove(path);
+        }
+
+        match self.pending.iter_mut().find(|(queued, _)| queued == path) {
+            Some((_, queued_mtime)) if *queued_mtime == mtime => false,
+            Some((_, queued_mtime)) => {
+                *queued_mtime = mtime;
+                true
+            }
+            None => {
+                self.pending.push((path.to_path_buf(), mtime));
+                true
+            }
+        }
+    }
+
+    pub fn next_job(&mut self) -> Option<FolderSizeJob> {
+        if self.pending.is_empty() || self.in_flight.len() >= self.max_concurrency {
+            return None;
+        }
+
+        let (path, mtime) = self.pending.remove(0);
+        let cancelled = Arc::new(AtomicBool::new(false));
+        self.in_flight
+            .insert(path.clone(), (mtime, cancelled.clone()));
+        Some(FolderSizeJob {
+            path,
+            mtime,
+            cancelled,
+        })
+    }
+
+    pub fn complete(&mut self, job: &FolderSizeJob, result: io::Result<u64>) -> bool {
+        if self
+            .in_flight
+            .get(&job.path)
+            .is_some_and(|(_, flag)| Arc::ptr_eq(flag, &job.cancelled))
+        {
+            self.in_flight.remove(&job.path);
+        }
+        if job.cancelled.load(Ordering::SeqCst) {
+            return false;
+        }
+
+        match result {
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => return false,
+            result => self
+                .cache
+                .insert(job.path.clone(), job.mtime, (Instant::now(), result.ok())),
+        }
+        true
+    }
+
+    pub fn cancel_hidden(&mut self, visible: &[PathBuf]) -> usize {
+        let before = self.pending.len() + self.in_flight.len();
+        self.pending.retain(|(path, _)| visible.contains(path));
+        self.in_flight.retain(|path, (_, cancelled)| {
+            let keep = visible.contains(path);
+            if !keep {
+                cancelled.store(true, Ordering::SeqCst);
+            }
+            keep
+        });
+        before - self.pending.len() - self.in_flight.len()
+    }
+
+    pub fn cancel_all(&mut self) -> usize {
+        self.cancel_hidden(&[])
+    }
+
+    pub fn is_computing(&self, path: &Path) -> bool {
+        self.in_flight.contains_key(path)
+    }
+
+    pub fn is_queued(&self, path: &Path) -> bool {
+        self.pending.iter().any(|(queued, _)| queued == path)
+    }
+
+    pub fn computing_count(&self) -> usize {
+        self.in_flight.len()
+    }
+
+    pub fn queued_count(&self) -> usize {
+        self.pending.len()
+    }
+
+    pub fn cached_count(&self) -> usize {
+        self.cache.len()
+    }
+}
@@ -0,0 +1,129 @@
+use super::folder_sizes::*;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, SystemTime};
+
+
+fn create_fixture() -> tempfile::TempDir {
+    let temp = tempfile::tempdir().unwrap();
+    fs::write(temp.path().join("a.txt"), vec![0u8; 100]).unwrap();
+    fs::create_dir_all(temp.path().join("nested/deeper")).unwrap();
+    fs::write(temp.path().join("nested/b.bin"), vec![0u8; 250]).unwrap();
+    fs::write(temp.path().join("nested/deeper/c.bin"), vec![0u8; 50]).unwrap();
+    temp
+}
+
+#[test]
+fn test_folder_size_is_recursive_and_cancellable() {
+    let temp = create_fixture();
+
+    assert_eq!(folder_size(temp.path(), &AtomicBool::new(false)).unwrap(), 400);
+    assert_eq!(
+        folder_size(&temp.path().join("nested"), &AtomicBool::new(false)).unwrap(),
+        300
+    );
+
+    let err = folder_size(temp.path(), &AtomicBool::new(true)).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::Interrupted);
+    assert!(folder_size(&temp.path().join("missing"), &AtomicBool::new(false)).is_err());
+}
+
+#[test]
+fn test_folder_size_pending_until_computed_then_cached() {
+    let temp = create_fixture();
+    let path = temp.path().to_path_buf();
+    let mtime = SystemTime::UNIX_EPOCH + Duration::from_secs(1000);
+    let mut sizes = FolderSizes::new();
+
+    assert_eq!(sizes.get(&path, mtime), FolderSize::Pending);
+    assert!(sizes.request(&path, mtime));
+    assert!(!sizes.request(&path, mtime));
+    assert!(sizes.is_queued(&path));
+
+    let job = sizes.next_job().unwrap();
+    assert!(sizes.is_computing(&path));
+    assert!(!sizes.request(&path, mtime));
+
+    let result = folder_size(&job.path, &job.cancelled);
+    assert!(sizes.complete(&job, result));
+    assert_eq!(sizes.get(&path, mtime), FolderSize::Ready(400));
+    assert!(!sizes.is_computing(&path));
+
+    assert!(!sizes.request(&path, mtime));
+    assert_eq!(sizes.cached_count(), 1);
+
+    let changed = mtime + Duration::from_secs(1);
+    assert_eq!(sizes.get(&path, changed), FolderSize::Pending);
+    assert!(sizes.request(&path, changed));
+}
+
+#[test]
+fn test_hidden_folders_cancelled_and_not_cached() {
+    let mtime = SystemTime::UNIX_EPOCH;
+    let visible = PathBuf::from("/projects/visible");
+    let hidden = PathBuf::from("/projects/hidden");
+    let mut sizes = FolderSizes::new();
+
+    sizes.request(&visible, mtime);
+    sizes.request(&hidden, mtime);
+    let visible_job = sizes.next_job().unwrap();
+    let hidden_job = sizes.next_job().unwrap();
+
+    assert_eq!(sizes.cancel_hidden(std::slice::from_ref(&visible)), 1);
+    assert!(hidden_job.cancelled.load(Ordering::SeqCst));
+    assert!(!visible_job.cancelled.load(Ordering::SeqCst));
+    assert_eq!(sizes.computing_count(), 1);
+
+    assert!(!sizes.complete(&hidden_job, Ok(10)));
+    assert_eq!(sizes.get(&hidden, mtime), FolderSize::Pending);
+
+    assert!(sizes.complete(&visible_job, Err(std::io::ErrorKind::PermissionDenied.into())));
+    assert_eq!(sizes.get(&visible, mtime), FolderSize::Unavailable);
+
+    sizes.request(&hidden, mtime);
+    assert_eq!(sizes.cancel_all(), 1);
+    assert_eq!(sizes.queued_count(), 0);
+}
+
+#[test]
+fn test_folder_size_walks_are_bounded() {
+    let mtime = SystemTime::UNIX_EPOCH;
+    let mut sizes = FolderSizes::with_limits(2, FOLDER_SIZE_TTL);
+    for name in ["a", "b", "c", "d"] {
+        assert!(sizes.request(&PathBuf::from(name), mtime));
+    }
+
+    let first = sizes.next_job().unwrap();
+    let second = sizes.next_job().unwrap();
+    assert!(sizes.next_job().is_none());
+    assert_eq!(sizes.computing_count(), 2);
+    assert_eq!(sizes.queued_count(), 2);
+
+    assert!(sizes.complete(&first, Ok(1)));
+    let third = sizes.next_job().unwrap();
+    assert_eq!(third.path, PathBuf::from("c"));
+    assert!(sizes.next_job().is_none());
+
+    assert!(sizes.complete(&second, Ok(2)));
+    assert!(sizes.complete(&third, Ok(3)));
+    assert_eq!(sizes.next_job().unwrap().path, PathBuf::from("d"));
+}
+
+#[test]
+fn test_folder_size_recomputed_after_ttl_but_kept_visible() {
+    let mtime = SystemTime::UNIX_EPOCH;
+    let path = PathBuf::from("/projects/stale");
+    let mut sizes = FolderSizes::with_limits(1, Duration::ZERO);
+
+    sizes.request(&path, mtime);
+    let job = sizes.next_job().unwrap();
+    assert!(sizes.complete(&job, Ok(42)));
+
+    assert!(sizes.request(&path, mtime));
+    assert_eq!(sizes.get(&path, mtime), FolderSize::Ready(42));
+
+    let job = sizes.next_job().unwrap();
+    assert!(sizes.complete(&job, Ok(64)));
+    assert_eq!(sizes.get(&path, mtime), FolderSize::Ready(64));
+}
@@ -0,0 +1,215 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::{FileEntry, SortState, ViewMode};
+
+
+const MIN_FILES_FOR_HEURISTIC: usize = 4;
+
+
+const DOMINANT_SHARE: f32 = 0.6;
+
+const MEDIA_EXTENSIONS: &[&str] = &[
+    "jpg", "jpeg", "png", "gif", "bmp", "webp", "heic", "heif", "tif", "tiff", "svg", "raw",
+    "cr2", "nef", "arw", "dng", "mp4", "mov", "mkv", "avi", "webm", "m4v",
+];
+
+const CODE_EXTENSIONS: &[&str] = &[
+    "rs", "c", "h", "cpp", "hpp", "cc", "go", "py", "rb", "js", "jsx", "ts", "tsx", "java", "kt",
+    "swift", "cs", "php", "lua", "sh", "toml", "yaml", "yml", "json", "md", "lock", "html",
+    "css", "scss", "sql", "zig",
+];
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ViewModeSource {
+    FolderRule,
+    ContentHeuristic,
+    GlobalDefault,
+}
+
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FolderViewRules {
+    folders: HashMap<PathBuf, ViewMode>,
+    sorts: HashMap<PathBuf, SortState>,
+    pins: HashMap<PathBuf, Vec<PathBuf>>,
+    hidden: HashMap<PathBuf, bool>,
+}
+
+impl FolderViewRules {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get(&self, path: &Path) -> Option<ViewMode> {
+        self.folders.get(path).copied()
+    }
+
+    pub fn set(&mut self, path: PathBuf, mode: ViewMode) {
+        self.folders.insert(path, mode);
+    }
+
+    pub fn clear(&mut self, path: &Path) -> bool {
+        self.folders.remove(path).is_some()
+    }
+
+    pub fn sort_for(&self, path: &Path) -> Option<SortState> {
+        self.sorts.get(path).copied()
+    }
+
+    pub fn set_sort(&mut self, path: PathBuf, sort: SortState) {
+        if sort == SortState::default() {
+            self.sorts.remove(&path);
+        } else {
+            self.sorts.insert(path, sort);
+        }
+    }
+
+    pub fn pinned(&self, dir: &Path) -> &[PathBuf] {
+        self.pins.get(dir).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    pub fn is_pinned(&self, path: &Path) -> bool {
+        path.parent()
+            .is_some_and(|dir| self.pinned(dir).iter().any(|pinned| pinned == path))
+    }
+
+    pub fn pin(&mut self, path: PathBuf) -> bool {
+        let Some(dir) = path.parent() else {
+            return false;
+        };
+        let pins = self.pins.entry(dir.to_path_buf()).or_default();
+        if pins.contains(&path) {
+            return false;
+        }
+        pins.push(path);
+        true
+    }
+
+    pub fn unpin(&mut self, path: &Path) -> bool {
+        let Some(dir) = path.parent() else {
+            return false;
+        };
+        let Some(pins) = self.pins.get_mut(dir) else {
+            return false;
+        };
+        let before = pins.len();
+        pins.retain(|pinned| pinned != path);
+        let removed = pins.len() != before;
+        if pins.is_empty() {
+            self.pins.remove(dir);
+        }
+        removed
+    }
+
+    pub fn hidden_for(&self, path: &Path) -> Option<bool> {
+        self.hidden.get(path).copied()
+    }
+
+    pub fn set_hidden(&mut self, path: PathBuf, show: Option<bool>) {
+        match show {
+            Some(show) => {
+                self.hidden.insert(path, show);
+            }
+            None => {
+                self.hidden.remove(&path);
+            }
+        }
+    }
+
+    pub fn hidden_override_source(&self, path: &Path) -> Option<&Path> {
+        path.ancestors().find(|dir| self.hidden.contains_key(*dir))
+    }
+
+    pub fn len(&self) -> usize {
+        self.folders.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.folders.is_empty()
+    }
+}
+
+
+pub fn content_view_mode(path: &Path, entries: &[FileEntry]) -> Option<ViewMode> {
+    let media_dirs = [dirs::picture_dir(), dirs::video_dir()];
+    if media_dirs.iter().flatten().any(|dir| dir == path) {
+        return Some(ViewMode::Grid);
+    }
+
+    let extensions: Vec<String> = entries
+        .iter()
+        .filter(|entry| !entry.is_dir)
+        .map(|entry| {
+            entry
+                .path
+                .extension()
+                .and_then(|ext| ext.to_str())
+                .map(|ext| ext.to_lowercase())
+                .unwrap_or_default()
+        })
+        .collect();
+    if extensions.len() < MIN_FILES_FOR_HEURISTIC {
+        return None;
+    }
+
+    let share = |known: &[&str]| {
+        let count = extensions
+            .iter()
+            .filter(|ext| known.contains(&ext.as_str()))
+            .count();
+        count as f32 / extensions.len() as f32
+    };
+
+    if share(MEDIA_EXTENSIONS) >= DOMINANT_SHARE {
+        Some(ViewMode::Grid)
+    } else if share(CODE_EXTENSIONS) >= DOMINANT_SHARE {
+        Some(ViewMode::List)
+    } else {
+        None
+    }
+}
+
+
+pub fn resolve_view_mode(
+    rules: &FolderViewRules,
+    path: &Path,
+    entries: &[FileEntry],
+    default: ViewMode,
+) -> (ViewMode, ViewModeSource) {
+    if let Some(mode) = rules.get(path) {
+        return (mode, ViewModeSource::FolderRule);
+    }
+    if let Some(mode) = content_view_mode(path, entries) {
+        return (mode, ViewModeSource::ContentHeuristic);
+    }
+    (default, ViewModeSource::GlobalDefault)
+}
+
+
+pub fn apply_pins(entries: &mut [FileEntry], pinned: &[PathBuf]) {
+    if pinned.is_empty() {
+        return;
+    }
+    entries.sort_by_key(|entry| pin_rank(pinned, entry));
+}
+
+
+pub fn pin_rank(pinned: &[PathBuf], entry: &FileEntry) -> usize {
+    pinned
+        .iter()
+        .position(|path| *path == entry.path)
+        .unwrap_or(pinned.len())
+}
+
+
+pub fn effective_show_hidden(rules: &FolderViewRules, path: &Path, global: bool) -> bool {
+    rules
+        .hidden_override_source(path)
+        .and_then(|dir| rules.hidden_for(dir))
+        .unwrap_or(global)
+}
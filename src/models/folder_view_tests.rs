@@ -0,0 +1,190 @@
+use super::folder_view::*;
+use crate::models::{FileEntry, SortColumn, SortDirection, SortState, ViewMode};
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+
+fn files(dir: &str, names: &[&str]) -> Vec<FileEntry> {
+    names
+        .iter()
+        .map(|name| {
+            FileEntry::new(
+                name.to_string(),
+                PathBuf::from(dir).join(name),
+                false,
+                1024,
+                SystemTime::now(),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn test_folder_rule_wins_over_heuristic_and_default() {
+    let dir = Path::new("/projects/photos");
+    let entries = files("/projects/photos", &["a.jpg", "b.png", "c.heic", "d.jpg"]);
+    let mut rules = FolderViewRules::new();
+    rules.set(dir.to_path_buf(), ViewMode::List);
+
+    assert_eq!(
+        resolve_view_mode(&rules, dir, &entries, ViewMode::Details),
+        (ViewMode::List, ViewModeSource::FolderRule)
+    );
+}
+
+#[test]
+fn test_heuristic_wins_over_default() {
+    let rules = FolderViewRules::new();
+
+    let photos = files("/data/trip", &["a.jpg", "b.png", "c.heic", "d.mov", "notes.txt"]);
+    assert_eq!(
+        resolve_view_mode(&rules, Path::new("/data/trip"), &photos, ViewMode::Details),
+        (ViewMode::Grid, ViewModeSource::ContentHeuristic)
+    );
+
+    let code = files("/data/app", &["main.rs", "lib.rs", "Cargo.toml", "README.md"]);
+    assert_eq!(
+        resolve_view_mode(&rules, Path::new("/data/app"), &code, ViewMode::Grid),
+        (ViewMode::List, ViewModeSource::ContentHeuristic)
+    );
+}
+
+#[test]
+fn test_global_default_when_nothing_matches() {
+    let rules = FolderViewRules::new();
+
+    let mixed = files("/data/mixed", &["a.jpg", "b.rs", "c.pdf", "d.zip"]);
+    assert_eq!(
+        resolve_view_mode(&rules, Path::new("/data/mixed"), &mixed, ViewMode::Details),
+        (ViewMode::Details, ViewModeSource::GlobalDefault)
+    );
+
+    let few = files("/data/few", &["a.jpg", "b.jpg"]);
+    assert_eq!(
+        resolve_view_mode(&rules, Path::new("/data/few"), &few, ViewMode::List),
+        (ViewMode::List, ViewModeSource::GlobalDefault)
+    );
+}
+
+#[test]
+fn test_clearing_rule_falls_back() {
+    let dir = PathBuf::from("/data/docs");
+    let mut rules = FolderViewRules::new();
+    rules.set(dir.clone(), ViewMode::Grid);
+    assert_eq!(rules.len(), 1);
+
+    assert!(rules.clear(&dir));
+    assert!(!rules.clear(&dir));
+    assert!(rules.is_empty());
+    assert_eq!(
+        resolve_view_mode(&rules, &dir, &[], ViewMode::List),
+        (ViewMode::List, ViewModeSource::GlobalDefault)
+    );
+}
+
+#[test]
+fn test_rules_round_trip_through_json() {
+    let mut rules = FolderViewRules::new();
+    rules.set(PathBuf::from("/data/photos"), ViewMode::Grid);
+
+    let json = serde_json::to_string(&rules).unwrap();
+    let restored: FolderViewRules = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(restored.get(Path::new("/data/photos")), Some(ViewMode::Grid));
+}
+
+#[test]
+fn test_sort_rules_are_per_folder() {
+    let mut rules = FolderViewRules::new();
+    let by_size = SortState {
+        column: SortColumn::Size,
+        direction: SortDirection::Descending,
+        ..SortState::default()
+    };
+    rules.set_sort(PathBuf::from("/data/downloads"), by_size);
+
+    assert_eq!(rules.sort_for(Path::new("/data/downloads")), Some(by_size));
+    assert_eq!(rules.sort_for(Path::new("/data/docs")), None);
+
+    rules.set_sort(PathBuf::from("/data/downloads"), SortState::default());
+    assert_eq!(rules.sort_for(Path::new("/data/downloads")), None);
+}
+
+#[test]
+fn test_pinned_entries_precede_sorted_remainder() {
+    let mut entries = files("/data/docs", &["a.txt", "b.txt", "c.txt", "d.txt"]);
+    let mut rules = FolderViewRules::new();
+    assert!(rules.pin(PathBuf::from("/data/docs/d.txt")));
+    assert!(rules.pin(PathBuf::from("/data/docs/b.txt")));
+    assert!(!rules.pin(PathBuf::from("/data/docs/b.txt")));
+    assert!(rules.is_pinned(Path::new("/data/docs/d.txt")));
+
+    SortState::default().sort_entries(&mut entries);
+    apply_pins(&mut entries, rules.pinned(Path::new("/data/docs")));
+
+    let names: Vec<&str> = entries.iter().map(|e| e.name.as_str()).collect();
+    assert_eq!(names, vec!["d.txt", "b.txt", "a.txt", "c.txt"]);
+}
+
+#[test]
+fn test_unpinning_restores_normal_order() {
+    let mut entries = files("/data/docs", &["a.txt", "b.txt", "c.txt"]);
+    let mut rules = FolderViewRules::new();
+    rules.pin(PathBuf::from("/data/docs/c.txt"));
+
+    assert!(rules.unpin(Path::new("/data/docs/c.txt")));
+    assert!(!rules.unpin(Path::new("/data/docs/c.txt")));
+    assert!(rules.pinned(Path::new("/data/docs")).is_empty());
+
+    SortState::default().sort_entries(&mut entries);
+    apply_pins(&mut entries, rules.pinned(Path::new("/data/docs")));
+
+    let names: Vec<&str> = entries.iter().map(|e| e.name.as_str()).collect();
+    assert_eq!(names, vec!["a.txt", "b.txt", "c.txt"]);
+}
+
+#[test]
+fn test_hidden_override_takes_precedence_over_global() {
+    let mut rules = FolderViewRules::new();
+    let config = Path::new("/home/user/.config");
+    let downloads = Path::new("/home/user/Downloads");
+    rules.set_hidden(config.to_path_buf(), Some(true));
+    rules.set_hidden(downloads.to_path_buf(), Some(false));
+
+    assert!(effective_show_hidden(&rules, config, false));
+    assert!(!effective_show_hidden(&rules, downloads, true));
+    let docs = Path::new("/home/user/docs");
+    assert!(effective_show_hidden(&rules, docs, true));
+    assert!(!effective_show_hidden(&rules, docs, false));
+}
+
+#[test]
+fn test_nearest_hidden_override_applies_to_subfolders() {
+    let mut rules = FolderViewRules::new();
+    rules.set_hidden(PathBuf::from("/home/user/.config"), Some(true));
+    rules.set_hidden(PathBuf::from("/home/user/.config/cache"), Some(false));
+
+    let nvim = Path::new("/home/user/.config/nvim");
+    assert_eq!(
+        rules.hidden_override_source(nvim),
+        Some(Path::new("/home/user/.config"))
+    );
+    assert!(effective_show_hidden(&rules, nvim, false));
+    assert!(!effective_show_hidden(
+        &rules,
+        Path::new("/home/user/.config/cache/fonts"),
+        true
+    ));
+}
+
+#[test]
+fn test_clearing_hidden_override_falls_back_to_global() {
+    let mut rules = FolderViewRules::new();
+    let dir = PathBuf::from("/data/project");
+    rules.set_hidden(dir.clone(), Some(true));
+    rules.set_hidden(dir.clone(), None);
+
+    assert_eq!(rules.hidden_for(&dir), None);
+    assert_eq!(rules.hidden_override_source(&dir), None);
+    assert!(!effective_show_hidden(&rules, &dir, false));
+}
@@ -0,0 +1,181 @@
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::is_trash_path;
+
+
+pub const FREQUENT_HALF_LIFE: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+
+
+pub const MAX_FREQUENT_FOLDERS: usize = 8;
+
+const MAX_TRACKED_FOLDERS: usize = 200;
+
+const MIN_TRACKED_SCORE: f64 = 0.05;
+
+const EXCLUDED_PREFIXES: &[&str] = &[
+    "/proc",
+    "/sys",
+    "/dev",
+    "/run",
+    "/System",
+    "/private/var",
+    "/Volumes/.Trashes",
+    "C:\\Windows",
+    "C:\\$Recycle.Bin",
+];
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+struct FolderVisits {
+    score: f64,
+    updated_at: u64,
+}
+
+impl FolderVisits {
+    fn score_at(&self, now: u64) -> f64 {
+        let elapsed = now.saturating_sub(self.updated_at) as f64;
+        self.score * 0.5_f64.powf(elapsed / FREQUENT_HALF_LIFE.as_secs_f64())
+    }
+}
+
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FrequentFolders {
+    folders: HashMap<PathBuf, FolderVisits>,
+    last_visited: Option<PathBuf>,
+}
+
+impl FrequentFolders {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record_visit(&mut self, path: &Path, at: SystemTime) -> bool {
+        if is_excluded_from_frequent(path) {
+            return false;
+        }
+
+        let now = unix_secs(at);
+        let visits = self.folders.entry(path.to_path_buf()).or_insert(FolderVisits {
+            score: 0.0,
+            updated_at: now,
+        });
+        visits.score = visits.score_at(now) + 1.0;
+        visits.updated_at = now;
+
+        self.prune(now);
+        self.last_visited = Some(path.to_path_buf());
+        true
+    }
+
+    pub fn last_visited(&self) -> Option<&Path> {
+        self.last_visited.as_deref()
+    }
+
+    pub fn score(&self, path: &Path, at: SystemTime) -> f64 {
+        self.folders
+            .get(path)
+            .map(|visits| visits.score_at(unix_secs(at)))
+            .unwrap_or(0.0)
+    }
+
+
+    pub fn ranked(&self, at: SystemTime, limit: usize) -> Vec<PathBuf> {
+        self.ranked_at(unix_secs(at), limit)
+    }
+
+    fn ranked_at(&self, now: u64, limit: usize) -> Vec<PathBuf> {
+        let mut scored: Vec<(&PathBuf, f64)> = self
+            .folders
+            .iter()
+            .map(|(path, visits)| (path, visits.score_at(now)))
+            .filter(|(_, score)| *score >= MIN_TRACKED_SCORE)
+            .collect();
+        scored.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+        scored
+            .into_iter()
+            .take(limit)
+            .map(|(path, _)| path.clone())
+            .collect()
+    }
+
+    pub fn remove(&mut self, path: &Path) -> bool {
+        self.folders.remove(path).is_some()
+    }
+
+    pub fn clear(&mut self) {
+        self.folders.clear();
+        self.last_visited = None;
+    }
+
+    pub fn len(&self) -> usize {
+        self.folders.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.folders.is_empty()
+    }
+
+    fn prune(&mut self, now: u64) {
+        self.folders
+            .retain(|_, visits| visits.score_at(now) >= MIN_TRACKED_SCORE);
+
+        if self.folders.len() > MAX_TRACKED_FOLDERS {
+            let keep = self.ranked_at(now, MAX_TRACKED_FOLDERS);
+            self.folders.retain(|path, _| keep.contains(path));
+        }
+    }
+
+    fn config_path() -> PathBuf {
+        dirs::config_dir()
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join("nexus-explorer")
+            .join("frequent_folders.json")
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        let path = Self::config_path();
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let json = serde_json::to_string_pretty(self)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        fs::write(path, json)
+    }
+
+    pub fn load() -> Self {
+        fs::read_to_string(Self::config_path())
+            .ok()
+            .and_then(|json| serde_json::from_str(&json).ok())
+            .unwrap_or_default()
+    }
+}
+
+
+pub fn is_excluded_from_frequent(path: &Path) -> bool {
+    if is_trash_path(&path.to_path_buf()) {
+        return true;
+    }
+
+    path.components().any(|component| {
+        let name = component.as_os_str().to_string_lossy();
+        name == ".Trash" || name == ".Trashes" || name == "$Recycle.Bin"
+    }) || path.ends_with("Trash/files")
+        || EXCLUDED_PREFIXES
+            .iter()
+            .any(|prefix| path.starts_with(prefix))
+}
+
+fn unix_secs(at: SystemTime) -> u64 {
+    at.duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs())
+        .unwrap_or(0)
+}
@@ -0,0 +1,66 @@
+use super::frequent_folders::*;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+
+fn at_days(days: u64) -> SystemTime {
+    SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000 + days * 24 * 60 * 60)
+}
+
+#[test]
+fn test_ranking_follows_visit_frequency() {
+    let mut frequent = FrequentFolders::new();
+    for _ in 0..3 {
+        frequent.record_visit(Path::new("/home/user/projects"), at_days(0));
+    }
+    frequent.record_visit(Path::new("/home/user/music"), at_days(0));
+    for _ in 0..2 {
+        frequent.record_visit(Path::new("/home/user/docs"), at_days(0));
+    }
+    assert_eq!(frequent.last_visited(), Some(Path::new("/home/user/docs")));
+
+    assert_eq!(
+        frequent.ranked(at_days(0), 2),
+        vec![
+            PathBuf::from("/home/user/projects"),
+            PathBuf::from("/home/user/docs"),
+        ]
+    );
+}
+
+#[test]
+fn test_old_visits_decay_below_recent_ones() {
+    let mut frequent = FrequentFolders::new();
+    for _ in 0..4 {
+        frequent.record_visit(Path::new("/data/old"), at_days(0));
+    }
+    for _ in 0..2 {
+        frequent.record_visit(Path::new("/data/new"), at_days(14));
+    }
+
+    let old = frequent.score(Path::new("/data/old"), at_days(14));
+    assert!((old - 1.0).abs() < 1e-9);
+    assert_eq!(frequent.ranked(at_days(14), 1), vec![PathBuf::from("/data/new")]);
+
+    assert!(frequent.ranked(at_days(365), 10).is_empty());
+}
+
+#[test]
+fn test_trash_and_system_paths_are_excluded() {
+    let mut frequent = FrequentFolders::new();
+    assert!(!frequent.record_visit(Path::new("/home/user/.Trash"), at_days(0)));
+    assert!(!frequent.record_visit(Path::new("/home/user/.local/share/Trash/files"), at_days(0)));
+    assert!(!frequent.record_visit(Path::new("/proc/self"), at_days(0)));
+    assert!(frequent.record_visit(Path::new("/home/user"), at_days(0)));
+    assert_eq!(frequent.len(), 1);
+}
+
+#[test]
+fn test_clear_removes_all_stats() {
+    let mut frequent = FrequentFolders::new();
+    frequent.record_visit(Path::new("/home/user"), at_days(0));
+    frequent.clear();
+    assert!(frequent.is_empty());
+    assert_eq!(frequent.last_visited(), None);
+    assert!(frequent.ranked(at_days(0), 10).is_empty());
+}
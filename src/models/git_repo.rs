@@ -0,0 +1,333 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GitRepoInfo {
+    pub root: PathBuf,
+    pub git_dir: PathBuf,
+    pub branch: Option<String>,
+    pub remote_url: Option<String>,
+}
+
+impl GitRepoInfo {
+    pub fn detect(root: &Path) -> Option<Self> {
+        let git_dir = resolve_git_dir(root)?;
+        let remote_url = fs::read_to_string(common_git_dir(&git_dir).join("config"))
+            .ok()
+            .and_then(|config| parse_remote_url(&config));
+        Some(Self {
+            root: root.to_path_buf(),
+            branch: read_branch(&git_dir),
+            git_dir,
+            remote_url,
+        })
+    }
+}
+
+
+pub fn resolve_git_dir(dir: &Path) -> Option<PathBuf> {
+    let dot_git = dir.join(".git");
+    if dot_git.is_dir() {
+        return Some(dot_git);
+    }
+    if dot_git.is_file() {
+        let content = fs::read_to_string(&dot_git).ok()?;
+        let target = content.trim().strip_prefix("gitdir:")?.trim();
+        return Some(dir.join(target));
+    }
+    None
+}
+
+
+pub fn find_repo_root(path: &Path) -> Option<PathBuf> {
+    path.ancestors()
+        .find(|dir| resolve_git_dir(dir).is_some())
+        .map(Path::to_path_buf)
+}
+
+
+pub fn read_branch(git_dir: &Path) -> Option<String> {
+    let head = fs::read_to_string(git_dir.join("HEAD")).ok()?;
+    let head = head.trim();
+    if let Some(branch) = head.strip_prefix("ref: refs/heads/") {
+        Some(branch.to_string())
+    } else if head.len() >= 7 {
+        Some(head[..7].to_string())
+    } else {
+        None
+    }
+}
+
+
+pub fn parse_remote_url(config: &str) -> Option<String> {
+    let mut remotes: Vec<(String, String)> = Vec::new();
+    let mut section: Option<String> = None;
+
+    for line in config.lines().map(str::trim) {
+        if line.is_empty() || line.starts_with('#') || line.starts_with(';') {
+            continue;
+        }
+        if let Some(header) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+            section = header
+                .trim()
+                .strip_prefix("remote")
+                .map(str::trim)
+                .and_then(|name| name.strip_prefix('"')?.strip_suffix('"'))
+                .map(str::to_string);
+            continue;
+        }
+        let Some(name) = &section else {
+            continue;
+        };
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+        if key.trim().eq_ignore_ascii_case("url") && !remotes.iter().any(|(n, _)| n == name) {
+            let value = value.trim().trim_matches('"');
+            remotes.push((name.clone(), value.to_string()));
+        }
+    }
+
+    remotes
+        .iter()
+        .find(|(name, _)| name == "origin")
+        .or_else(|| remotes.first())
+        .map(|(_, url)| url.clone())
+}
+
+fn common_git_dir(git_dir: &Path) -> PathBuf {
+    match fs::read_to_string(git_dir.join("commondir")) {
+        Ok(common) => git_dir.join(common.trim()),
+        Err(_) => git_dir.to_path_buf(),
+    }
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum GitStatusBadge {
+    Ignored,
+    Untracked,
+    Renamed,
+    Deleted,
+    Added,
+    Modified,
+    Conflicted,
+}
+
+impl GitStatusBadge {
+    pub fn from_xy(x: char, y: char) -> Option<Self> {
+        match (x, y) {
+            ('!', '!') => Some(GitStatusBadge::Ignored),
+            ('?', '?') => Some(GitStatusBadge::Untracked),
+            ('U', _) | (_, 'U') | ('A', 'A') | ('D', 'D') => Some(GitStatusBadge::Conflicted),
+            ('A', _) => Some(GitStatusBadge::Added),
+            ('R', _) | ('C', _) => Some(GitStatusBadge::Renamed),
+            ('M', _) | ('T', _) | (_, 'M') | (_, 'T') => Some(GitStatusBadge::Modified),
+            ('D', _) | (_, 'D') => Some(GitStatusBadge::Deleted),
+            _ => None,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            GitStatusBadge::Ignored => "I",
+            GitStatusBadge::Untracked => "U",
+            GitStatusBadge::Renamed => "R",
+            GitStatusBadge::Deleted => "D",
+            GitStatusBadge::Added => "A",
+            GitStatusBadge::Modified => "M",
+            GitStatusBadge::Conflicted => "!",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            GitStatusBadge::Ignored => "Ignored",
+            GitStatusBadge::Untracked => "Untracked",
+            GitStatusBadge::Renamed => "Renamed",
+            GitStatusBadge::Deleted => "Deleted",
+            GitStatusBadge::Added => "Added",
+            GitStatusBadge::Modified => "Modified",
+            GitStatusBadge::Conflicted => "Conflicted",
+        }
+    }
+
+    pub fn color(&self) -> u32 {
+        match self {
+            GitStatusBadge::Ignored => 0x6e7681,
+            GitStatusBadge::Untracked => 0x3fb950,
+            GitStatusBadge::Renamed => 0x58a6ff,
+            GitStatusBadge::Deleted => 0xf85149,
+            GitStatusBadge::Added => 0x3fb950,
+            GitStatusBadge::Modified => 0xd29922,
+            GitStatusBadge::Conflicted => 0xf85149,
+        }
+    }
+
+    fn covers_contents(&self) -> bool {
+        matches!(self, GitStatusBadge::Ignored | GitStatusBadge::Untracked)
+    }
+}
+
+
+pub fn parse_porcelain_status(output: &str, root: &Path) -> Vec<(PathBuf, GitStatusBadge)> {
+    let mut statuses = Vec::new();
+    let mut fields = output.split('\0').filter(|f| !f.is_empty());
+
+    while let Some(field) = fields.next() {
+        let mut chars = field.chars();
+        let (Some(x), Some(y), Some(' ')) = (chars.next(), chars.next(), chars.next()) else {
+            continue;
+        };
+        if matches!(x, 'R' | 'C') {
+            fields.next();
+        }
+        let Some(badge) = GitStatusBadge::from_xy(x, y) else {
+            continue;
+        };
+        let relative = chars.as_str().trim_end_matches('/');
+        statuses.push((root.join(relative), badge));
+    }
+    statuses
+}
+
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GitStatusMap {
+    root: PathBuf,
+    statuses: HashMap<PathBuf, GitStatusBadge>,
+    folders: HashMap<PathBuf, GitStatusBadge>,
+}
+
+impl GitStatusMap {
+    pub fn new(root: PathBuf, statuses: Vec<(PathBuf, GitStatusBadge)>) -> Self {
+        let statuses: HashMap<PathBuf, GitStatusBadge> = statuses.into_iter().collect();
+        let mut folders: HashMap<PathBuf, GitStatusBadge> = HashMap::new();
+        for (changed, badge) in &statuses {
+            let rolled_up = match badge {
+                GitStatusBadge::Ignored => continue,
+                GitStatusBadge::Conflicted => GitStatusBadge::Conflicted,
+                GitStatusBadge::Untracked => GitStatusBadge::Untracked,
+                _ => GitStatusBadge::Modified,
+            };
+            for ancestor in changed.ancestors().take_while(|a| a.starts_with(&root)) {
+                let slot = folders.entry(ancestor.to_path_buf()).or_insert(rolled_up);
+                *slot = (*slot).max(rolled_up);
+            }
+        }
+        Self {
+            root,
+            statuses,
+            folders,
+        }
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    pub fn len(&self) -> usize {
+        self.statuses.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.statuses.is_empty()
+    }
+
+
+    pub fn badge_for(&self, path: &Path) -> Option<GitStatusBadge> {
+        if let Some(badge) = self.statuses.get(path) {
+            return Some(*badge);
+        }
+        let inherited = path
+            .ancestors()
+            .skip(1)
+            .take_while(|ancestor| ancestor.starts_with(&self.root))
+            .find_map(|ancestor| self.statuses.get(ancestor).filter(|b| b.covers_contents()));
+        if let Some(badge) = inherited {
+            return Some(*badge);
+        }
+        self.folders.get(path).copied()
+    }
+
+    pub fn summary(&self) -> String {
+        let mut counts: Vec<(GitStatusBadge, usize)> = Vec::new();
+        for badge in self.statuses.values() {
+            if *badge == GitStatusBadge::Ignored {
+                continue;
+            }
+            match counts.iter_mut().find(|(b, _)| b == badge) {
+                Some((_, count)) => *count += 1,
+                None => counts.push((*badge, 1)),
+            }
+        }
+        if counts.is_empty() {
+            return "Working tree clean".to_string();
+        }
+        counts.sort_by(|a, b| b.0.cmp(&a.0));
+        counts
+            .iter()
+            .map(|(badge, count)| format!("{} {}", count, badge.description().to_lowercase()))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    pub fn badges_for<'a>(
+        &self,
+        paths: impl IntoIterator<Item = &'a PathBuf>,
+    ) -> HashMap<PathBuf, GitStatusBadge> {
+        paths
+            .into_iter()
+            .filter_map(|path| self.badge_for(path).map(|badge| (path.clone(), badge)))
+            .collect()
+    }
+}
+
+
+pub fn load_git_status(root: &Path) -> Result<GitStatusMap, String> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(root)
+        .args(["status", "--porcelain=v1", "-z", "--ignored=matching"])
+        .output()
+        .map_err(|e| e.to_string())?;
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+    }
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    Ok(GitStatusMap::new(
+        root.to_path_buf(),
+        parse_porcelain_status(&stdout, root),
+    ))
+}
+
+
+#[derive(Debug, Default)]
+pub struct GitStatusCache {
+    current: Option<GitStatusMap>,
+    stale: bool,
+}
+
+impl GitStatusCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get(&self, root: &Path) -> Option<&GitStatusMap> {
+        self.current
+            .as_ref()
+            .filter(|map| !self.stale && map.root() == root)
+    }
+
+    pub fn store(&mut self, map: GitStatusMap) {
+        self.current = Some(map);
+        self.stale = false;
+    }
+
+    pub fn invalidate(&mut self) {
+        self.stale = true;
+    }
+}
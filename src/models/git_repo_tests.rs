@@ -0,0 +1,132 @@
+use super::git_repo::*;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tempfile::TempDir;
+
+
+fn root() -> PathBuf {
+    PathBuf::from("/work/repo")
+}
+
+#[test]
+fn test_remote_url_prefers_origin() {
+    let config = r#"
+[core]
+	repositoryformatversion = 0
+[remote "upstream"]
+	url = https://github.com/upstream/project.git
+	fetch = +refs/heads/*:refs/remotes/upstream/*
+[remote "origin"]
+	url = git@github.com:me/project.git
+[branch "main"]
+	remote = origin
+"#;
+
+    assert_eq!(
+        parse_remote_url(config),
+        Some("git@github.com:me/project.git".to_string())
+    );
+}
+
+#[test]
+fn test_remote_url_falls_back_to_first_remote() {
+    let config = "[remote \"fork\"]\n\turl = \"https://example.com/fork.git\"\n[remote \"other\"]\n\turl = https://example.com/other.git\n";
+    assert_eq!(
+        parse_remote_url(config),
+        Some("https://example.com/fork.git".to_string())
+    );
+
+    assert_eq!(parse_remote_url("[core]\n\tbare = false\n"), None);
+    assert_eq!(parse_remote_url("[branch \"main\"]\n\turl = nope\n"), None);
+}
+
+#[test]
+fn test_porcelain_codes_map_to_badges() {
+    let cases = [
+        (' ', 'M', Some(GitStatusBadge::Modified)),
+        ('M', ' ', Some(GitStatusBadge::Modified)),
+        ('A', 'M', Some(GitStatusBadge::Added)),
+        (' ', 'D', Some(GitStatusBadge::Deleted)),
+        ('R', ' ', Some(GitStatusBadge::Renamed)),
+        ('U', 'U', Some(GitStatusBadge::Conflicted)),
+        ('A', 'A', Some(GitStatusBadge::Conflicted)),
+        ('?', '?', Some(GitStatusBadge::Untracked)),
+        ('!', '!', Some(GitStatusBadge::Ignored)),
+        (' ', ' ', None),
+    ];
+
+    for (x, y, expected) in cases {
+        assert_eq!(GitStatusBadge::from_xy(x, y), expected, "{}{}", x, y);
+    }
+}
+
+#[test]
+fn test_porcelain_output_is_parsed_with_renames() {
+    let output = " M src/main.rs\0R  new.rs\0old.rs\0?? notes/\0!! target/\0";
+    let statuses = parse_porcelain_status(output, &root());
+
+    assert_eq!(
+        statuses,
+        vec![
+            (root().join("src/main.rs"), GitStatusBadge::Modified),
+            (root().join("new.rs"), GitStatusBadge::Renamed),
+            (root().join("notes"), GitStatusBadge::Untracked),
+            (root().join("target"), GitStatusBadge::Ignored),
+        ]
+    );
+}
+
+#[test]
+fn test_directories_summarise_their_contents() {
+    let output = " M src/views/list.rs\0UU src/app.rs\0?? docs/draft.md\0!! src/gen.rs\0!! build/\0";
+    let map = GitStatusMap::new(root(), parse_porcelain_status(output, &root()));
+
+    assert_eq!(map.badge_for(&root().join("src")), Some(GitStatusBadge::Conflicted));
+    assert_eq!(map.badge_for(&root().join("src/views")), Some(GitStatusBadge::Modified));
+    assert_eq!(map.badge_for(&root().join("docs")), Some(GitStatusBadge::Untracked));
+    assert_eq!(map.badge_for(&root().join("build/out.o")), Some(GitStatusBadge::Ignored));
+    assert_eq!(map.badge_for(&root().join("README.md")), None);
+    assert_eq!(map.badge_for(&root()), Some(GitStatusBadge::Conflicted));
+    assert_eq!(map.badge_for(&root().join("src/models")), None);
+
+    assert_eq!(map.summary(), "1 conflicted, 1 modified, 1 untracked");
+    assert_eq!(GitStatusMap::new(root(), Vec::new()).summary(), "Working tree clean");
+
+    let entries = vec![root().join("src"), root().join("README.md")];
+    let badges = map.badges_for(&entries);
+    assert_eq!(badges.len(), 1);
+    assert_eq!(badges.get(&root().join("src")), Some(&GitStatusBadge::Conflicted));
+}
+
+#[test]
+fn test_repo_detection_reads_branch_and_remote() {
+    let dir = TempDir::new().unwrap();
+    let git_dir = dir.path().join(".git");
+    fs::create_dir_all(&git_dir).unwrap();
+    fs::write(git_dir.join("HEAD"), "ref: refs/heads/feature/x\n").unwrap();
+    fs::write(
+        git_dir.join("config"),
+        "[remote \"origin\"]\n\turl = https://example.com/repo.git\n",
+    )
+    .unwrap();
+    fs::create_dir_all(dir.path().join("src")).unwrap();
+
+    let info = GitRepoInfo::detect(dir.path()).unwrap();
+    assert_eq!(info.branch.as_deref(), Some("feature/x"));
+    assert_eq!(info.remote_url.as_deref(), Some("https://example.com/repo.git"));
+    assert!(GitRepoInfo::detect(&dir.path().join("src")).is_none());
+    assert_eq!(find_repo_root(&dir.path().join("src")), Some(dir.path().to_path_buf()));
+}
+
+#[test]
+fn test_status_cache_is_invalidated() {
+    let mut cache = GitStatusCache::new();
+    assert!(cache.get(&root()).is_none());
+
+    cache.store(GitStatusMap::new(root(), Vec::new()));
+    assert!(cache.get(&root()).is_some());
+    assert!(cache.get(Path::new("/elsewhere")).is_none());
+
+    cache.invalidate();
+    assert!(cache.get(&root()).is_none());
+}
@@ -1,11 +1,11 @@
 use std::collections::{HashMap, HashSet};
 use std::num::NonZeroUsize;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use flume::{Receiver, Sender};
 use lru::LruCache;
 
-use super::IconKey;
+use super::{IconKey, IconPack};
 use crate::utils::rgba_to_bgra_inplace;
 
 
@@ -64,6 +64,7 @@ pub struct IconCache {
     max_entries: usize,
     default_icon: RenderImage,
     folder_icon: RenderImage,
+    pack: IconPack,
 }
 
 impl IconCache {
@@ -84,6 +85,7 @@ impl IconCache {
             max_entries: max_entries.max(1),
             default_icon: RenderImage::default_placeholder(),
             folder_icon: RenderImage::default_folder(),
+            pack: IconPack::builtin(),
         }
     }
 
@@ -181,6 +183,26 @@ impl IconCache {
     }
 
 
+    pub fn icon_pack(&self) -> &IconPack {
+        &self.pack
+    }
+
+
+    pub fn set_icon_pack(&mut self, pack: IconPack) -> bool {
+        if self.pack == pack {
+            return false;
+        }
+        self.pack = pack;
+        self.clear();
+        true
+    }
+
+
+    pub fn resolve_path(&self, key: &IconKey) -> PathBuf {
+        self.pack.resolve(key)
+    }
+
+
     pub fn default_icon(&self) -> &RenderImage {
         &self.default_icon
     }
@@ -1,4 +1,8 @@
 use super::*;
+use crate::models::{
+    discover_icon_packs, find_icon_pack, BUILTIN_ICON_DIR, BUILTIN_ICON_PACK, ICON_PACKS_DIR,
+    REQUIRED_ICONS,
+};
 
 #[test]
 fn test_new_cache_is_empty() {
@@ -284,3 +288,64 @@ proptest! {
         prop_assert_eq!(retrieved.unwrap(), &image, "Retrieved icon should match inserted icon");
     }
 }
+
+fn write_pack(root: &std::path::Path, name: &str, icons: &[&str]) {
+    let dir = root.join(ICON_PACKS_DIR).join(name);
+    std::fs::create_dir_all(&dir).unwrap();
+    for icon in icons {
+        std::fs::write(dir.join(format!("{}.svg", icon)), "<svg/>").unwrap();
+    }
+}
+
+#[test]
+fn test_switching_icon_pack_clears_cache_and_resolves_new_path() {
+    let root = tempfile::TempDir::new().unwrap();
+    write_pack(root.path(), "mono", REQUIRED_ICONS);
+
+    let mut cache = IconCache::new();
+    cache.insert(IconKey::Directory, RenderImage::default_folder());
+    assert_eq!(
+        cache.resolve_path(&IconKey::Directory),
+        std::path::Path::new(BUILTIN_ICON_DIR).join("folder.svg")
+    );
+
+    let mono = find_icon_pack(root.path(), "mono");
+    assert!(mono.missing_required().is_empty());
+    assert!(cache.set_icon_pack(mono.clone()));
+    assert!(cache.is_empty());
+    assert!(!cache.contains(&IconKey::Directory));
+    assert_eq!(
+        cache.resolve_path(&IconKey::Directory),
+        std::path::Path::new(ICON_PACKS_DIR).join("mono").join("folder.svg")
+    );
+    assert!(!cache.set_icon_pack(mono));
+}
+
+#[test]
+fn test_incomplete_icon_pack_falls_back_to_builtin() {
+    let root = tempfile::TempDir::new().unwrap();
+    write_pack(root.path(), "partial", &["folder", "rs"]);
+    write_pack(root.path(), "alpha", REQUIRED_ICONS);
+
+    let names: Vec<String> = discover_icon_packs(root.path())
+        .iter()
+        .map(|pack| pack.name().to_string())
+        .collect();
+    assert_eq!(names, vec![BUILTIN_ICON_PACK, "alpha", "partial"]);
+
+    let partial = find_icon_pack(root.path(), "partial");
+    assert!(partial.missing_required().contains(&"file"));
+    assert_eq!(
+        partial.resolve(&IconKey::Directory),
+        std::path::Path::new(ICON_PACKS_DIR).join("partial").join("folder.svg")
+    );
+    assert_eq!(
+        partial.resolve(&IconKey::GenericFile),
+        std::path::Path::new(BUILTIN_ICON_DIR).join("file.svg")
+    );
+    assert_eq!(
+        partial.resolve(&IconKey::Extension("rs".to_string())),
+        std::path::Path::new(ICON_PACKS_DIR).join("partial").join("rs.svg")
+    );
+    assert!(find_icon_pack(root.path(), "missing").is_builtin());
+}
@@ -0,0 +1,219 @@
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::RwLock;
+
+use super::IconKey;
+
+
+pub const BUILTIN_ICON_PACK: &str = "default";
+
+
+pub const BUILTIN_ICON_DIR: &str = "assets/icons";
+
+
+pub const ICON_PACKS_DIR: &str = "assets/icon-packs";
+
+pub const REQUIRED_ICONS: &[&str] = &[
+    "folder",
+    "folder-open",
+    "file",
+    "file-text",
+    "file-code",
+    "file-image",
+];
+
+static ICON_PACKS: RwLock<Option<IconPackRegistry>> = RwLock::new(None);
+
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IconPack {
+    name: String,
+    dir: PathBuf,
+    provided: Option<HashSet<String>>,
+}
+
+impl IconPack {
+    pub fn builtin() -> Self {
+        Self {
+            name: BUILTIN_ICON_PACK.to_string(),
+            dir: PathBuf::from(BUILTIN_ICON_DIR),
+            provided: None,
+        }
+    }
+
+
+    pub fn scan(asset_root: &Path, dir: PathBuf) -> Self {
+        let name = dir
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_else(|| BUILTIN_ICON_PACK.to_string());
+        let provided = fs::read_dir(asset_root.join(&dir))
+            .map(|entries| {
+                entries
+                    .flatten()
+                    .map(|entry| entry.path())
+                    .filter(|path| path.extension().is_some_and(|ext| ext == "svg"))
+                    .filter_map(|path| Some(path.file_stem()?.to_string_lossy().to_string()))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Self {
+            name,
+            dir,
+            provided: Some(provided),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    pub fn is_builtin(&self) -> bool {
+        self.provided.is_none()
+    }
+
+    pub fn provides(&self, icon: &str) -> bool {
+        self.provided
+            .as_ref()
+            .map_or(true, |provided| provided.contains(icon))
+    }
+
+    pub fn missing_required(&self) -> Vec<&'static str> {
+        REQUIRED_ICONS
+            .iter()
+            .copied()
+            .filter(|icon| !self.provides(icon))
+            .collect()
+    }
+
+
+    pub fn icon_path(&self, icon: &str) -> PathBuf {
+        let dir = if self.provides(icon) {
+            self.dir.as_path()
+        } else {
+            Path::new(BUILTIN_ICON_DIR)
+        };
+        dir.join(format!("{}.svg", icon))
+    }
+
+    pub fn resolve(&self, key: &IconKey) -> PathBuf {
+        match key {
+            IconKey::Directory => self.icon_path("folder"),
+            IconKey::GenericFile | IconKey::MimeType(_) => self.icon_path("file"),
+            IconKey::Extension(ext) if self.provides(ext) && !self.is_builtin() => {
+                self.icon_path(ext)
+            }
+            IconKey::Extension(_) => self.icon_path("file"),
+            IconKey::Custom(path) => path.clone(),
+        }
+    }
+}
+
+impl Default for IconPack {
+    fn default() -> Self {
+        Self::builtin()
+    }
+}
+
+
+pub fn discover_icon_packs(asset_root: &Path) -> Vec<IconPack> {
+    let mut packs: Vec<IconPack> = fs::read_dir(asset_root.join(ICON_PACKS_DIR))
+        .map(|entries| {
+            entries
+                .flatten()
+                .filter(|entry| entry.path().is_dir())
+                .map(|entry| {
+                    IconPack::scan(asset_root, Path::new(ICON_PACKS_DIR).join(entry.file_name()))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    packs.sort_by(|a, b| a.name.cmp(&b.name));
+    packs.insert(0, IconPack::builtin());
+    packs
+}
+
+
+pub fn find_icon_pack(asset_root: &Path, name: &str) -> IconPack {
+    discover_icon_packs(asset_root)
+        .into_iter()
+        .find(|pack| pack.name == name)
+        .unwrap_or_default()
+}
+
+
+#[derive(Debug)]
+struct IconPackRegistry {
+    asset_root: PathBuf,
+    current: IconPack,
+}
+
+
+pub fn init_icon_packs(asset_root: PathBuf, selected: &str) -> IconPack {
+    let current = find_icon_pack(&asset_root, selected);
+    if let Ok(mut registry) = ICON_PACKS.write() {
+        *registry = Some(IconPackRegistry {
+            asset_root,
+            current: current.clone(),
+        });
+    }
+    current
+}
+
+
+pub fn available_icon_packs() -> Vec<IconPack> {
+    ICON_PACKS
+        .read()
+        .ok()
+        .and_then(|registry| {
+            registry
+                .as_ref()
+                .map(|registry| discover_icon_packs(&registry.asset_root))
+        })
+        .unwrap_or_else(|| vec![IconPack::builtin()])
+}
+
+
+pub fn current_icon_pack() -> IconPack {
+    ICON_PACKS
+        .read()
+        .ok()
+        .and_then(|registry| registry.as_ref().map(|registry| registry.current.clone()))
+        .unwrap_or_default()
+}
+
+
+pub fn set_current_icon_pack(pack: IconPack) {
+    if let Ok(mut registry) = ICON_PACKS.write() {
+        match registry.as_mut() {
+            Some(registry) => registry.current = pack,
+            None => {
+                *registry = Some(IconPackRegistry {
+                    asset_root: PathBuf::from("."),
+                    current: pack,
+                })
+            }
+        }
+    }
+}
+
+
+pub fn icon_asset_path(icon: &str) -> String {
+    ICON_PACKS
+        .read()
+        .ok()
+        .and_then(|registry| {
+            registry
+                .as_ref()
+                .map(|registry| registry.current.icon_path(icon))
+        })
+        .unwrap_or_else(|| Path::new(BUILTIN_ICON_DIR).join(format!("{}.svg", icon)))
+        .to_string_lossy()
+        .into_owned()
+}
@@ -0,0 +1,171 @@
+use std::fs;
+use std::io::{self, Cursor};
+use std::path::{Path, PathBuf};
+
+use image::{DynamicImage, ImageFormat};
+use thiserror::Error;
+
+
+#[derive(Debug, Error)]
+pub enum ImageEditError {
+    #[error("Editing {0} images is not supported")]
+    UnsupportedFormat(String),
+
+    #[error("Image error: {0}")]
+    Image(#[from] image::ImageError),
+
+    #[error("IO error: {0}")]
+    Io(#[from] io::Error),
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ImageTransform {
+    quarter_turns: u8,
+    mirrored: bool,
+}
+
+impl ImageTransform {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn quarter_turns(&self) -> u8 {
+        self.quarter_turns
+    }
+
+    pub fn is_mirrored(&self) -> bool {
+        self.mirrored
+    }
+
+    pub fn is_identity(&self) -> bool {
+        self.quarter_turns == 0 && !self.mirrored
+    }
+
+    pub fn rotate_right(&mut self) {
+        self.quarter_turns = (self.quarter_turns + 1) % 4;
+    }
+
+    pub fn rotate_left(&mut self) {
+        self.quarter_turns = (self.quarter_turns + 3) % 4;
+    }
+
+
+    pub fn flip_horizontal(&mut self) {
+        self.quarter_turns = (4 - self.quarter_turns) % 4;
+        self.mirrored = !self.mirrored;
+    }
+
+
+    pub fn flip_vertical(&mut self) {
+        self.quarter_turns = (6 - self.quarter_turns) % 4;
+        self.mirrored = !self.mirrored;
+    }
+
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    pub fn transformed_dimensions(&self, (width, height): (u32, u32)) -> (u32, u32) {
+        if self.quarter_turns % 2 == 1 {
+            (height, width)
+        } else {
+            (width, height)
+        }
+    }
+
+
+    pub fn apply(&self, image: &DynamicImage) -> DynamicImage {
+        let image = if self.mirrored {
+            image.fliph()
+        } else {
+            image.clone()
+        };
+
+        match self.quarter_turns {
+            1 => image.rotate90(),
+            2 => image.rotate180(),
+            3 => image.rotate270(),
+            _ => image,
+        }
+    }
+}
+
+
+pub fn editable_image_format(path: &Path) -> Result<ImageFormat, ImageEditError> {
+    let extension = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(|ext| ext.to_lowercase())
+        .unwrap_or_default();
+
+    match extension.as_str() {
+        "png" => Ok(ImageFormat::Png),
+        "jpg" | "jpeg" => Ok(ImageFormat::Jpeg),
+        "" => Err(ImageEditError::UnsupportedFormat("extensionless".to_string())),
+        other => Err(ImageEditError::UnsupportedFormat(other.to_uppercase())),
+    }
+}
+
+
+pub fn render_transformed_png(
+    path: &Path,
+    transform: ImageTransform,
+) -> Result<Vec<u8>, ImageEditError> {
+    let image = image::open(path)?;
+    encode_image(&transform.apply(&image), ImageFormat::Png)
+}
+
+
+pub fn save_transformed_image(
+    source: &Path,
+    destination: &Path,
+    transform: ImageTransform,
+) -> Result<(), ImageEditError> {
+    let format = editable_image_format(destination)?;
+    let image = image::open(source)?;
+    let bytes = encode_image(&transform.apply(&image), format)?;
+
+    let temp_path = destination.with_extension("nexus-edit.tmp");
+    fs::write(&temp_path, bytes)?;
+    if let Err(e) = fs::rename(&temp_path, destination) {
+        let _ = fs::remove_file(&temp_path);
+        return Err(e.into());
+    }
+    Ok(())
+}
+
+
+pub fn edited_copy_path(path: &Path) -> PathBuf {
+    let stem = path.file_stem().and_then(|s| s.to_str()).unwrap_or("image");
+    let ext = path.extension().and_then(|e| e.to_str());
+    let parent = path.parent().unwrap_or(path);
+
+    let mut counter = 1;
+    loop {
+        let suffix = if counter == 1 {
+            " edited".to_string()
+        } else {
+            format!(" edited {}", counter)
+        };
+        let new_name = match ext {
+            Some(ext) => format!("{}{}.{}", stem, suffix, ext),
+            None => format!("{}{}", stem, suffix),
+        };
+        let new_path = parent.join(new_name);
+        if !new_path.exists() {
+            return new_path;
+        }
+        counter += 1;
+    }
+}
+
+fn encode_image(image: &DynamicImage, format: ImageFormat) -> Result<Vec<u8>, ImageEditError> {
+    let mut bytes = Vec::new();
+    if format == ImageFormat::Jpeg {
+        DynamicImage::ImageRgb8(image.to_rgb8()).write_to(&mut Cursor::new(&mut bytes), format)?;
+    } else {
+        image.write_to(&mut Cursor::new(&mut bytes), format)?;
+    }
+    Ok(bytes)
+}
@@ -0,0 +1,131 @@
+use super::image_edit::*;
+use image::{DynamicImage, GenericImageView, Rgba, RgbaImage};
+use std::path::Path;
+use tempfile::TempDir;
+
+
+const RED: Rgba<u8> = Rgba([255, 0, 0, 255]);
+const GREEN: Rgba<u8> = Rgba([0, 255, 0, 255]);
+const BLUE: Rgba<u8> = Rgba([0, 0, 255, 255]);
+const WHITE: Rgba<u8> = Rgba([255, 255, 255, 255]);
+
+
+fn sample_image() -> DynamicImage {
+    let mut buffer = RgbaImage::new(3, 2);
+    buffer.put_pixel(0, 0, RED);
+    buffer.put_pixel(1, 0, GREEN);
+    buffer.put_pixel(2, 0, BLUE);
+    buffer.put_pixel(0, 1, WHITE);
+    buffer.put_pixel(1, 1, WHITE);
+    buffer.put_pixel(2, 1, WHITE);
+    DynamicImage::ImageRgba8(buffer)
+}
+
+#[test]
+fn test_rotate_right_moves_top_row_to_right_column() {
+    let mut transform = ImageTransform::new();
+    transform.rotate_right();
+
+    let rotated = transform.apply(&sample_image());
+
+    assert_eq!(rotated.dimensions(), (2, 3));
+    assert_eq!(rotated.get_pixel(1, 0), RED);
+    assert_eq!(rotated.get_pixel(1, 1), GREEN);
+    assert_eq!(rotated.get_pixel(1, 2), BLUE);
+    assert_eq!(rotated.get_pixel(0, 0), WHITE);
+    assert_eq!(transform.transformed_dimensions((3, 2)), (2, 3));
+}
+
+#[test]
+fn test_rotate_left_moves_top_row_to_left_column() {
+    let mut transform = ImageTransform::new();
+    transform.rotate_left();
+
+    let rotated = transform.apply(&sample_image());
+
+    assert_eq!(rotated.dimensions(), (2, 3));
+    assert_eq!(rotated.get_pixel(0, 2), RED);
+    assert_eq!(rotated.get_pixel(0, 1), GREEN);
+    assert_eq!(rotated.get_pixel(0, 0), BLUE);
+}
+
+#[test]
+fn test_four_rotations_are_identity() {
+    let mut transform = ImageTransform::new();
+    for _ in 0..4 {
+        transform.rotate_right();
+    }
+
+    assert!(transform.is_identity());
+    assert_eq!(transform.apply(&sample_image()), sample_image());
+}
+
+#[test]
+fn test_combined_edits_match_sequential_operations() {
+    let image = sample_image();
+    let mut transform = ImageTransform::new();
+    transform.rotate_right();
+    transform.flip_horizontal();
+    transform.rotate_right();
+    transform.flip_vertical();
+
+    let expected = image.rotate90().fliph().rotate90().flipv();
+
+    assert_eq!(transform.apply(&image), expected);
+}
+
+#[test]
+fn test_double_flip_is_identity() {
+    let mut transform = ImageTransform::new();
+    transform.rotate_right();
+    transform.flip_vertical();
+    transform.flip_vertical();
+
+    assert_eq!(transform.quarter_turns(), 1);
+    assert!(!transform.is_mirrored());
+}
+
+#[test]
+fn test_editable_formats() {
+    assert!(editable_image_format(Path::new("photo.PNG")).is_ok());
+    assert!(editable_image_format(Path::new("photo.jpeg")).is_ok());
+
+    let err = editable_image_format(Path::new("anim.gif")).unwrap_err();
+    assert_eq!(err.to_string(), "Editing GIF images is not supported");
+}
+
+#[test]
+fn test_save_transformed_copy_leaves_original_untouched() {
+    let temp = TempDir::new().unwrap();
+    let original = temp.path().join("photo.png");
+    sample_image().save(&original).unwrap();
+
+    let mut transform = ImageTransform::new();
+    transform.rotate_right();
+    let copy = edited_copy_path(&original);
+    save_transformed_image(&original, &copy, transform).unwrap();
+
+    assert_eq!(copy.file_name().unwrap(), "photo edited.png");
+    assert_eq!(image::open(&original).unwrap().dimensions(), (3, 2));
+    assert_eq!(image::open(&copy).unwrap().dimensions(), (2, 3));
+    assert_eq!(
+        edited_copy_path(&original).file_name().unwrap(),
+        "photo edited 2.png"
+    );
+}
+
+#[test]
+fn test_save_transformed_overwrites_jpeg() {
+    let temp = TempDir::new().unwrap();
+    let original = temp.path().join("photo.jpg");
+    DynamicImage::ImageRgb8(sample_image().to_rgb8())
+        .save(&original)
+        .unwrap();
+
+    let mut transform = ImageTransform::new();
+    transform.rotate_left();
+    save_transformed_image(&original, &original, transform).unwrap();
+
+    assert_eq!(image::open(&original).unwrap().dimensions(), (2, 3));
+    assert_eq!(std::fs::read_dir(temp.path()).unwrap().count(), 1);
+}
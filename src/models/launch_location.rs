@@ -0,0 +1,76 @@
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LaunchLocation {
+    #[default]
+    Home,
+    LastVisited,
+    Pinned(PathBuf),
+}
+
+impl LaunchLocation {
+    pub fn label(&self) -> &'static str {
+        match self {
+            LaunchLocation::Home => "Home folder",
+            LaunchLocation::LastVisited => "Last visited folder",
+            LaunchLocation::Pinned(_) => "Pinned folder",
+        }
+    }
+}
+
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LaunchTarget {
+    pub path: PathBuf,
+    pub missing: Option<PathBuf>,
+}
+
+impl LaunchTarget {
+    pub fn fell_back(&self) -> bool {
+        self.missing.is_some()
+    }
+
+    pub fn fallback_message(&self) -> Option<String> {
+        self.missing.as_ref().map(|missing| {
+            format!(
+                "Couldn't open {}: folder not found. Showing your home folder instead.",
+                missing.display()
+            )
+        })
+    }
+}
+
+
+pub fn resolve_launch_path(
+    cli_path: Option<&Path>,
+    setting: &LaunchLocation,
+    last_visited: Option<&Path>,
+    home: &Path,
+) -> LaunchTarget {
+    let requested = match (cli_path, setting) {
+        (Some(path), _) if path.is_file() => path.parent().map(Path::to_path_buf),
+        (Some(path), _) => Some(path.to_path_buf()),
+        (None, LaunchLocation::Home) => None,
+        (None, LaunchLocation::LastVisited) => last_visited.map(Path::to_path_buf),
+        (None, LaunchLocation::Pinned(path)) => Some(path.clone()),
+    };
+
+    match requested {
+        Some(path) if path.is_dir() => LaunchTarget {
+            path,
+            missing: None,
+        },
+        Some(path) => LaunchTarget {
+            path: home.to_path_buf(),
+            missing: Some(path),
+        },
+        None => LaunchTarget {
+            path: home.to_path_buf(),
+            missing: None,
+        },
+    }
+}
@@ -0,0 +1,99 @@
+use super::launch_location::*;
+use std::fs;
+use std::path::PathBuf;
+use tempfile::TempDir;
+
+
+struct Dirs {
+    _root: TempDir,
+    home: PathBuf,
+    last: PathBuf,
+    pinned: PathBuf,
+    cli: PathBuf,
+}
+
+fn dirs() -> Dirs {
+    let root = TempDir::new().unwrap();
+    let make = |name: &str| {
+        let path = root.path().join(name);
+        fs::create_dir(&path).unwrap();
+        path
+    };
+    Dirs {
+        home: make("home"),
+        last: make("last"),
+        pinned: make("pinned"),
+        cli: make("cli"),
+        _root: root,
+    }
+}
+
+#[test]
+fn test_setting_picks_launch_directory() {
+    let d = dirs();
+    let resolve = |setting: &LaunchLocation| {
+        resolve_launch_path(None, setting, Some(&d.last), &d.home).path
+    };
+
+    assert_eq!(resolve(&LaunchLocation::Home), d.home);
+    assert_eq!(resolve(&LaunchLocation::LastVisited), d.last);
+    assert_eq!(resolve(&LaunchLocation::Pinned(d.pinned.clone())), d.pinned);
+
+    let never_visited = resolve_launch_path(None, &LaunchLocation::LastVisited, None, &d.home);
+    assert_eq!(never_visited.path, d.home);
+    assert!(!never_visited.fell_back());
+}
+
+#[test]
+fn test_cli_path_takes_precedence_over_setting() {
+    let d = dirs();
+    for setting in [
+        LaunchLocation::Home,
+        LaunchLocation::LastVisited,
+        LaunchLocation::Pinned(d.pinned.clone()),
+    ] {
+        let target = resolve_launch_path(Some(&d.cli), &setting, Some(&d.last), &d.home);
+        assert_eq!(target.path, d.cli);
+        assert_eq!(target.missing, None);
+    }
+
+    let file = d.cli.join("notes.txt");
+    fs::write(&file, b"notes").unwrap();
+    let target = resolve_launch_path(Some(&file), &LaunchLocation::Home, None, &d.home);
+    assert_eq!(target.path, d.cli);
+}
+
+#[test]
+fn test_missing_paths_fall_back_to_home() {
+    let d = dirs();
+    let gone = d.cli.join("gone");
+
+    let target = resolve_launch_path(Some(&gone), &LaunchLocation::LastVisited, Some(&d.last), &d.home);
+    assert_eq!(target.path, d.home);
+    assert_eq!(target.missing.as_deref(), Some(gone.as_path()));
+    assert!(target.fallback_message().unwrap().contains("gone"));
+
+    let pinned = resolve_launch_path(None, &LaunchLocation::Pinned(gone.clone()), None, &d.home);
+    assert_eq!(pinned.path, d.home);
+    assert!(pinned.fell_back());
+
+    let last = resolve_launch_path(None, &LaunchLocation::LastVisited, Some(&gone), &d.home);
+    assert_eq!(last.path, d.home);
+    assert!(last.fell_back());
+}
+
+#[test]
+fn test_launch_location_round_trips_through_json() {
+    for location in [
+        LaunchLocation::Home,
+        LaunchLocation::LastVisited,
+        LaunchLocation::Pinned("/data/projects".into()),
+    ] {
+        let json = serde_json::to_string(&location).unwrap();
+        assert_eq!(serde_json::from_str::<LaunchLocation>(&json).unwrap(), location);
+    }
+    assert_eq!(
+        serde_json::from_str::<LaunchLocation>("\"last_visited\"").unwrap(),
+        LaunchLocation::LastVisited
+    );
+}
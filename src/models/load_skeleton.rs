@@ -0,0 +1,106 @@
+use std::time::{Duration, Instant};
+
+
+pub const SKELETON_DELAY: Duration = Duration::from_millis(150);
+
+pub const MAX_SKELETON_ROWS: usize = 48;
+
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+enum Phase {
+    #[default]
+    Idle,
+    Waiting { request_id: usize, started: Instant },
+    Streaming { request_id: usize },
+    Showing { request_id: usize },
+}
+
+
+#[derive(Debug, Clone, Default)]
+pub struct LoadSkeleton {
+    phase: Phase,
+}
+
+impl LoadSkeleton {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn begin(&mut self, request_id: usize, now: Instant) {
+        self.phase = Phase::Waiting {
+            request_id,
+            started: now,
+        };
+    }
+
+    pub fn deadline(&self) -> Option<Instant> {
+        match self.phase {
+            Phase::Waiting { started, .. } => Some(started + SKELETON_DELAY),
+            _ => None,
+        }
+    }
+
+    pub fn tick(&mut self, now: Instant) -> bool {
+        match self.phase {
+            Phase::Waiting {
+                request_id,
+                started,
+            } if now.duration_since(started) >= SKELETON_DELAY => {
+                self.phase = Phase::Showing { request_id };
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub fn receive_batch(&mut self, request_id: usize) -> bool {
+        match self.phase {
+            Phase::Waiting { request_id: id, .. } | Phase::Showing { request_id: id }
+                if id == request_id =>
+            {
+                let was_showing = self.is_showing();
+                self.phase = Phase::Streaming { request_id };
+                was_showing
+            }
+            _ => false,
+        }
+    }
+
+    pub fn finish(&mut self, request_id: usize) -> bool {
+        if self.request_id() != Some(request_id) {
+            return false;
+        }
+        let was_showing = self.is_showing();
+        self.phase = Phase::Idle;
+        was_showing
+    }
+
+    pub fn is_showing(&self) -> bool {
+        matches!(self.phase, Phase::Showing { .. })
+    }
+
+    pub fn is_loading(&self, request_id: usize) -> bool {
+        self.request_id() == Some(request_id)
+    }
+
+    pub fn is_busy(&self) -> bool {
+        self.request_id().is_some()
+    }
+
+    fn request_id(&self) -> Option<usize> {
+        match self.phase {
+            Phase::Idle => None,
+            Phase::Waiting { request_id, .. }
+            | Phase::Streaming { request_id }
+            | Phase::Showing { request_id } => Some(request_id),
+        }
+    }
+}
+
+
+pub fn skeleton_row_count(viewport_height: f32, row_height: f32) -> usize {
+    if row_height <= 0.0 || viewport_height <= 0.0 {
+        return 1;
+    }
+    ((viewport_height / row_height).ceil() as usize).clamp(1, MAX_SKELETON_ROWS)
+}
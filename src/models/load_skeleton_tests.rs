@@ -0,0 +1,67 @@
+use super::load_skeleton::*;
+use std::time::{Duration, Instant};
+
+
+#[test]
+fn test_skeleton_shows_after_delay_and_clears_on_first_batch() {
+    let start = Instant::now();
+    let mut skeleton = LoadSkeleton::new();
+    skeleton.begin(7, start);
+    assert_eq!(skeleton.deadline(), Some(start + SKELETON_DELAY));
+
+    assert!(!skeleton.tick(start + SKELETON_DELAY / 2));
+    assert!(!skeleton.is_showing());
+
+    assert!(skeleton.tick(start + SKELETON_DELAY));
+    assert!(skeleton.is_showing());
+    assert!(!skeleton.tick(start + SKELETON_DELAY * 2));
+
+    assert!(skeleton.receive_batch(7));
+    assert!(!skeleton.is_showing());
+    assert!(skeleton.is_loading(7));
+
+    assert!(skeleton.is_busy());
+    assert!(!skeleton.finish(7));
+    assert!(!skeleton.is_loading(7));
+    assert!(!skeleton.is_busy());
+}
+
+#[test]
+fn test_fast_loads_never_show_skeleton() {
+    let start = Instant::now();
+    let mut skeleton = LoadSkeleton::new();
+    assert!(!skeleton.is_busy());
+    skeleton.begin(1, start);
+
+    assert!(!skeleton.receive_batch(1));
+    assert!(!skeleton.tick(start + SKELETON_DELAY * 4));
+    assert!(!skeleton.is_showing());
+
+    skeleton.begin(2, start);
+    assert!(!skeleton.finish(2));
+    assert!(!skeleton.tick(start + SKELETON_DELAY * 4));
+}
+
+#[test]
+fn test_stale_requests_do_not_clear_skeleton() {
+    let start = Instant::now();
+    let mut skeleton = LoadSkeleton::new();
+    skeleton.begin(3, start);
+    skeleton.begin(4, start + Duration::from_millis(10));
+    assert!(skeleton.tick(start + Duration::from_millis(10) + SKELETON_DELAY));
+
+    assert!(!skeleton.receive_batch(3));
+    assert!(!skeleton.finish(3));
+    assert!(skeleton.is_showing());
+
+    assert!(skeleton.finish(4));
+    assert!(!skeleton.is_showing());
+}
+
+#[test]
+fn test_skeleton_rows_fill_viewport() {
+    assert_eq!(skeleton_row_count(600.0, 28.0), 22);
+    assert_eq!(skeleton_row_count(560.0, 28.0), 20);
+    assert_eq!(skeleton_row_count(0.0, 28.0), 1);
+    assert_eq!(skeleton_row_count(10_000.0, 28.0), MAX_SKELETON_ROWS);
+}
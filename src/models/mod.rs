@@ -1,37 +1,86 @@
 mod ansi_parser;
+mod app_associations;
 mod archive_manager;
+mod automation;
+mod background_activity;
 mod batch_rename;
 mod bookmarks;
+mod checksum_manifest;
+mod click_behavior;
 mod clipboard;
 mod column_view;
+mod column_widths;
+mod conflict_strategy;
+mod context_menu_registry;
+mod date_format;
 mod default_app;
+mod delete_guard;
+mod details_columns;
 mod device_monitor;
+mod directory_summary;
 mod drag_drop;
 mod dual_pane;
+mod duplicate_finder;
 mod encrypted_volume;
+mod executable_launch;
 mod favorites;
 mod file_hash;
 mod file_operations;
 mod file_share;
 mod file_system;
+mod file_templates;
+mod flatten_view;
+mod focus_region;
+mod folder_sizes;
+mod folder_view;
+mod frequent_folders;
+mod git_repo;
 mod icon_cache;
+mod icon_pack;
+mod image_edit;
+mod launch_location;
+mod load_skeleton;
+mod motion;
+mod mounted_images;
+mod name_tint;
+mod navigation_history;
+mod network_mount;
 mod network_storage;
 pub mod open_with;
+mod operation_queue;
+mod pane_sizes;
+mod path_format;
 mod permissions;
 #[cfg(target_os = "windows")]
 mod permissions_windows;
 mod platform_adapter;
+mod preview_limits;
+mod preview_pane;
+mod preview_queue;
+mod preview_service;
 mod pty_service;
+mod quick_navigation;
+mod result_groups;
+mod scroll_memory;
 mod search_engine;
 mod settings;
+mod sheet_drag;
 mod smart_folders;
 mod tabs;
+mod tag_bulk;
 mod tags;
 mod terminal;
+mod terminal_palette;
+mod text_format;
 mod theme;
+mod toolbar_layout;
+mod transfer_rate;
 mod trash;
+#[cfg(target_os = "linux")]
+mod trash_volume;
 mod types;
 mod typography;
+mod volume_space;
 #[cfg(not(test))]
 mod window_manager;
 mod wsl;
@@ -50,65 +99,207 @@ mod windows_device_notifications;
 #[cfg(test)]
 mod ansi_parser_tests;
 #[cfg(test)]
+mod app_associations_tests;
+#[cfg(test)]
 mod archive_manager_tests;
 #[cfg(test)]
+mod automation_tests;
+#[cfg(test)]
+mod background_activity_tests;
+#[cfg(test)]
+mod checksum_manifest_tests;
+#[cfg(test)]
+mod click_behavior_tests;
+#[cfg(test)]
 mod clipboard_tests;
 #[cfg(test)]
 mod column_view_tests;
 #[cfg(test)]
+mod column_widths_tests;
+#[cfg(test)]
+mod conflict_strategy_tests;
+#[cfg(test)]
+mod context_menu_registry_tests;
+#[cfg(test)]
+mod date_format_tests;
+#[cfg(test)]
+mod delete_guard_tests;
+#[cfg(test)]
+mod details_columns_tests;
+#[cfg(test)]
 mod device_monitor_tests;
 #[cfg(test)]
+mod directory_summary_tests;
+#[cfg(test)]
 mod dual_pane_tests;
 #[cfg(test)]
+mod duplicate_finder_tests;
+#[cfg(test)]
 mod encrypted_volume_tests;
 #[cfg(test)]
+mod executable_launch_tests;
+#[cfg(test)]
 mod file_hash_tests;
 #[cfg(test)]
+mod file_templates_tests;
+#[cfg(test)]
+mod flatten_view_tests;
+#[cfg(test)]
+mod focus_region_tests;
+#[cfg(test)]
+mod folder_sizes_tests;
+#[cfg(test)]
+mod folder_view_tests;
+#[cfg(test)]
+mod frequent_folders_tests;
+#[cfg(test)]
+mod git_repo_tests;
+#[cfg(test)]
+mod image_edit_tests;
+#[cfg(test)]
+mod launch_location_tests;
+#[cfg(test)]
+mod load_skeleton_tests;
+#[cfg(test)]
+mod motion_tests;
+#[cfg(test)]
+mod mounted_images_tests;
+#[cfg(test)]
+mod name_tint_tests;
+#[cfg(test)]
+mod navigation_history_tests;
+#[cfg(test)]
+mod network_mount_tests;
+#[cfg(test)]
+mod open_with_tests;
+#[cfg(test)]
+mod operation_queue_tests;
+#[cfg(test)]
+mod pane_sizes_tests;
+#[cfg(test)]
+mod path_format_tests;
+#[cfg(test)]
 mod permissions_tests;
 #[cfg(test)]
+mod preview_limits_tests;
+#[cfg(test)]
+mod preview_pane_tests;
+#[cfg(test)]
+mod preview_queue_tests;
+#[cfg(test)]
+mod quick_navigation_tests;
+#[cfg(test)]
+mod result_groups_tests;
+#[cfg(test)]
+mod sheet_drag_tests;
+#[cfg(test)]
 mod smart_folders_tests;
 #[cfg(test)]
+mod tag_bulk_tests;
+#[cfg(test)]
 mod terminal_tests;
 #[cfg(test)]
+mod terminal_palette_tests;
+#[cfg(test)]
+mod text_format_tests;
+#[cfg(test)]
 mod wsl_tests;
 #[cfg(test)]
+mod toolbar_layout_tests;
+#[cfg(test)]
+mod transfer_rate_tests;
+#[cfg(test)]
 mod trash_tests;
 #[cfg(test)]
+#[cfg(target_os = "linux")]
+mod trash_volume_tests;
+#[cfg(test)]
+mod volume_space_tests;
+#[cfg(test)]
 #[cfg(target_os = "windows")]
 mod windows_device_tests;
 
 pub use ansi_parser::*;
+pub use app_associations::*;
 pub use archive_manager::*;
+pub use automation::*;
+pub use background_activity::*;
 pub use batch_rename::*;
 pub use bookmarks::*;
+pub use checksum_manifest::*;
+pub use click_behavior::*;
 pub use clipboard::*;
 pub use column_view::*;
+pub use column_widths::*;
+pub use conflict_strategy::*;
+pub use context_menu_registry::*;
+pub use date_format::*;
 pub use default_app::*;
+pub use delete_guard::*;
+pub use details_columns::*;
 pub use device_monitor::*;
+pub use directory_summary::*;
 pub use drag_drop::*;
 pub use dual_pane::*;
+pub use duplicate_finder::*;
 pub use encrypted_volume::*;
+pub use executable_launch::*;
 pub use favorites::*;
 pub use file_hash::*;
 pub use file_operations::*;
 pub use file_share::*;
 pub use file_system::*;
+pub use file_templates::*;
+pub use flatten_view::*;
+pub use focus_region::*;
+pub use folder_sizes::*;
+pub use folder_view::*;
+pub use frequent_folders::*;
+pub use git_repo::*;
 pub use icon_cache::*;
+pub use icon_pack::*;
+pub use image_edit::*;
+pub use launch_location::*;
+pub use load_skeleton::*;
+pub use motion::*;
+pub use mounted_images::*;
+pub use name_tint::*;
+pub use navigation_history::*;
+pub use network_mount::*;
 pub use network_storage::*;
 pub use open_with::*;
+pub use operation_queue::*;
+pub use pane_sizes::*;
+pub use path_format::*;
 pub use permissions::*;
 pub use platform_adapter::*;
+pub use preview_limits::*;
+pub use preview_pane::*;
+pub use preview_queue::*;
+pub use preview_service::*;
 pub use pty_service::*;
+pub use quick_navigation::*;
+pub use result_groups::*;
+pub use scroll_memory::*;
 pub use search_engine::*;
 pub use settings::*;
+pub use sheet_drag::*;
 pub use smart_folders::*;
 pub use tabs::*;
+pub use tag_bulk::*;
 pub use tags::*;
 pub use terminal::*;
+pub use terminal_palette::*;
+pub use text_format::*;
 pub use theme::*;
+pub use toolbar_layout::*;
+pub use transfer_rate::*;
 pub use trash::*;
+#[cfg(target_os = "linux")]
+pub use trash_volume::*;
 pub use types::*;
 pub use typography::*;
+pub use volume_space::*;
 #[cfg(not(test))]
 pub use window_manager::*;
 pub use wsl::*;
@@ -0,0 +1,276 @@
+use std::process::Command;
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU8, Ordering};
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+
+
+static REDUCED_MOTION: AtomicBool = AtomicBool::new(false);
+
+
+pub const ANIMATION_SPEED_MIN: f32 = 0.25;
+
+
+pub const ANIMATION_SPEED_MAX: f32 = 4.0;
+
+
+pub const ANIMATION_SPEED_DEFAULT: f32 = 1.0;
+
+
+pub const ANIMATION_SPEED_STEPS: [f32; 5] = [0.5, 0.75, 1.0, 1.5, 2.0];
+
+
+static ANIMATION_SPEED_PERCENT: AtomicU32 = AtomicU32::new(100);
+
+
+static EASING_PRESET: AtomicU8 = AtomicU8::new(0);
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum MotionPreference {
+    #[default]
+    System,
+    Reduced,
+    Full,
+}
+
+impl MotionPreference {
+    pub fn label(&self) -> &'static str {
+        match self {
+            MotionPreference::System => "Match system",
+            MotionPreference::Reduced => "Reduced",
+            MotionPreference::Full => "Full",
+        }
+    }
+
+
+    pub fn resolve(&self, system_prefers_reduced: bool) -> bool {
+        match self {
+            MotionPreference::System => system_prefers_reduced,
+            MotionPreference::Reduced => true,
+            MotionPreference::Full => false,
+        }
+    }
+}
+
+
+pub fn set_reduced_motion(reduced: bool) {
+    REDUCED_MOTION.store(reduced, Ordering::SeqCst);
+}
+
+
+pub fn reduced_motion() -> bool {
+    REDUCED_MOTION.load(Ordering::SeqCst)
+}
+
+
+pub fn apply_motion_preference(preference: MotionPreference) {
+    let system = preference == MotionPreference::System && system_prefers_reduced_motion();
+    set_reduced_motion(preference.resolve(system));
+}
+
+
+pub fn clamp_animation_speed(speed: f32) -> f32 {
+    if !speed.is_finite() {
+        return ANIMATION_SPEED_DEFAULT;
+    }
+    let percent = (speed * 100.0).round() / 100.0;
+    percent.clamp(ANIMATION_SPEED_MIN, ANIMATION_SPEED_MAX)
+}
+
+
+pub fn set_animation_speed(speed: f32) {
+    let percent = (clamp_animation_speed(speed) * 100.0).round() as u32;
+    ANIMATION_SPEED_PERCENT.store(percent, Ordering::SeqCst);
+}
+
+
+pub fn animation_speed() -> f32 {
+    ANIMATION_SPEED_PERCENT.load(Ordering::SeqCst) as f32 / 100.0
+}
+
+
+pub fn next_animation_speed(speed: f32) -> f32 {
+    ANIMATION_SPEED_STEPS
+        .into_iter()
+        .find(|step| *step > speed + f32::EPSILON)
+        .unwrap_or(ANIMATION_SPEED_STEPS[0])
+}
+
+
+pub fn animation_speed_label(speed: f32) -> String {
+    format!("{}%", (speed * 100.0).round() as u32)
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum EasingPreset {
+    #[default]
+    Standard,
+    Linear,
+    EaseInOut,
+    Spring,
+}
+
+impl EasingPreset {
+    pub const ALL: [EasingPreset; 4] = [
+        EasingPreset::Standard,
+        EasingPreset::Linear,
+        EasingPreset::EaseInOut,
+        EasingPreset::Spring,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            EasingPreset::Standard => "Standard",
+            EasingPreset::Linear => "Linear",
+            EasingPreset::EaseInOut => "Ease in-out",
+            EasingPreset::Spring => "Spring",
+        }
+    }
+
+    pub fn next(&self) -> Self {
+        let index = Self::ALL.iter().position(|preset| preset == self).unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+
+
+    pub fn apply(&self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            EasingPreset::Standard => 1.0 - (1.0 - t).powi(3),
+            EasingPreset::Linear => t,
+            EasingPreset::EaseInOut => {
+                if t < 0.5 {
+                    4.0 * t * t * t
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(3) / 2.0
+                }
+            }
+            EasingPreset::Spring => {
+                const OVERSHOOT: f32 = 1.70158;
+                let u = t - 1.0;
+                1.0 + (OVERSHOOT + 1.0) * u.powi(3) + OVERSHOOT * u.powi(2)
+            }
+        }
+    }
+}
+
+
+pub fn set_easing_preset(preset: EasingPreset) {
+    let index = EasingPreset::ALL.iter().position(|p| *p == preset).unwrap_or(0);
+    EASING_PRESET.store(index as u8, Ordering::SeqCst);
+}
+
+
+pub fn easing_preset() -> EasingPreset {
+    EasingPreset::ALL
+        .get(EASING_PRESET.load(Ordering::SeqCst) as usize)
+        .copied()
+        .unwrap_or_default()
+}
+
+
+pub fn scale_duration(duration: Duration, speed: f32) -> Duration {
+    let percent = (clamp_animation_speed(speed) * 100.0).round() as u32;
+    duration * 100 / percent
+}
+
+
+pub fn motion_duration(duration: Duration) -> Duration {
+    resolve_motion_duration(duration, reduced_motion(), animation_speed())
+}
+
+
+pub fn resolve_motion_duration(duration: Duration, reduced: bool, speed: f32) -> Duration {
+    if reduced {
+        Duration::ZERO
+    } else {
+        scale_duration(duration, speed)
+    }
+}
+
+
+#[derive(Debug, Clone, Copy)]
+pub struct Transition {
+    start_time: Instant,
+    duration: Duration,
+}
+
+impl Transition {
+    pub fn new(duration: Duration) -> Self {
+        Self::with_resolved_duration(motion_duration(duration))
+    }
+
+    pub fn with_resolved_duration(duration: Duration) -> Self {
+        Self {
+            start_time: Instant::now(),
+            duration,
+        }
+    }
+
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    pub fn progress(&self) -> f32 {
+        if self.duration.is_zero() {
+            return 1.0;
+        }
+        (self.start_time.elapsed().as_secs_f32() / self.duration.as_secs_f32()).min(1.0)
+    }
+
+    pub fn eased_progress(&self) -> f32 {
+        easing_preset().apply(self.progress())
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.progress() >= 1.0
+    }
+}
+
+
+#[cfg(target_os = "macos")]
+pub fn system_prefers_reduced_motion() -> bool {
+    Command::new("defaults")
+        .args(["read", "com.apple.universalaccess", "reduceMotion"])
+        .output()
+        .map(|output| String::from_utf8_lossy(&output.stdout).trim() == "1")
+        .unwrap_or(false)
+}
+
+
+#[cfg(target_os = "linux")]
+pub fn system_prefers_reduced_motion() -> bool {
+    Command::new("gsettings")
+        .args(["get", "org.gnome.desktop.interface", "enable-animations"])
+        .output()
+        .map(|output| String::from_utf8_lossy(&output.stdout).trim() == "false")
+        .unwrap_or(false)
+}
+
+
+#[cfg(target_os = "windows")]
+pub fn system_prefers_reduced_motion() -> bool {
+    Command::new("reg")
+        .args([
+            "query",
+            r"HKCU\Control Panel\Desktop\WindowMetrics",
+            "/v",
+            "MinAnimate",
+        ])
+        .output()
+        .map(|output| {
+            String::from_utf8_lossy(&output.stdout)
+                .split_whitespace()
+                .last()
+                .is_some_and(|value| value == "0")
+        })
+        .unwrap_or(false)
+}
+
+
+#[cfg(not(any(target_os = "macos", target_os = "linux", target_os = "windows")))]
+pub fn system_prefers_reduced_motion() -> bool {
+    false
+}
@@ -0,0 +1,87 @@
+use super::motion::*;
+use std::sync::{Mutex, MutexGuard, PoisonError};
+use std::time::Duration;
+
+
+static MOTION_GLOBALS: Mutex<()> = Mutex::new(());
+
+struct SpeedGuard {
+    previous: f32,
+    _lock: MutexGuard<'static, ()>,
+}
+
+impl SpeedGuard {
+    fn new() -> Self {
+        let lock = MOTION_GLOBALS.lock().unwrap_or_else(PoisonError::into_inner);
+        Self {
+            previous: animation_speed(),
+            _lock: lock,
+        }
+    }
+}
+
+impl Drop for SpeedGuard {
+    fn drop(&mut self) {
+        set_animation_speed(self.previous);
+    }
+}
+
+#[test]
+fn test_preference_resolution() {
+    assert!(MotionPreference::System.resolve(true));
+    assert!(!MotionPreference::System.resolve(false));
+    assert!(MotionPreference::Reduced.resolve(false));
+    assert!(!MotionPreference::Full.resolve(true));
+}
+
+#[test]
+fn test_reduced_motion_makes_transitions_instant() {
+    let duration = resolve_motion_duration(Duration::from_millis(250), true, 1.0);
+    let transition = Transition::with_resolved_duration(duration);
+    assert_eq!(transition.duration(), Duration::ZERO);
+    assert_eq!(transition.progress(), 1.0);
+    assert!(transition.is_complete());
+    assert_eq!(resolve_motion_duration(Duration::from_secs(1), true, 2.0), Duration::ZERO);
+
+    let duration = resolve_motion_duration(Duration::from_millis(250), false, 1.0);
+    let transition = Transition::with_resolved_duration(duration);
+    assert_eq!(transition.duration(), Duration::from_millis(250));
+    assert!(!transition.is_complete());
+}
+
+#[test]
+fn test_animation_speed_scales_transition_duration() {
+    let base = Duration::from_millis(300);
+    assert_eq!(resolve_motion_duration(base, false, 2.0), Duration::from_millis(150));
+    assert_eq!(resolve_motion_duration(base, false, 0.5), Duration::from_millis(600));
+    assert_eq!(resolve_motion_duration(base, true, 0.5), Duration::ZERO);
+    assert_eq!(resolve_motion_duration(base, false, ANIMATION_SPEED_DEFAULT), base);
+
+    assert_eq!(clamp_animation_speed(100.0), ANIMATION_SPEED_MAX);
+    assert_eq!(clamp_animation_speed(0.0), ANIMATION_SPEED_MIN);
+    assert_eq!(clamp_animation_speed(f32::NAN), ANIMATION_SPEED_DEFAULT);
+}
+
+#[test]
+fn test_animation_speed_setting_is_clamped() {
+    let _guard = SpeedGuard::new();
+    set_animation_speed(100.0);
+    assert_eq!(animation_speed(), ANIMATION_SPEED_MAX);
+    set_animation_speed(0.0);
+    assert_eq!(animation_speed(), ANIMATION_SPEED_MIN);
+}
+
+#[test]
+fn test_easing_presets_start_and_end_in_place() {
+    for preset in EasingPreset::ALL {
+        assert!(preset.apply(0.0).abs() < 1e-5, "{:?}", preset);
+        assert!((preset.apply(1.0) - 1.0).abs() < 1e-5, "{:?}", preset);
+    }
+    assert_eq!(EasingPreset::Linear.apply(0.25), 0.25);
+    assert!(EasingPreset::Standard.apply(0.25) > 0.25);
+    assert!(EasingPreset::EaseInOut.apply(0.25) < 0.25);
+    assert!(EasingPreset::Spring.apply(0.8) > 1.0);
+    assert_eq!(EasingPreset::Spring.next(), EasingPreset::Standard);
+    assert_eq!(next_animation_speed(1.0), 1.5);
+    assert_eq!(next_animation_speed(2.0), 0.5);
+}
@@ -0,0 +1,66 @@
+use std::path::{Path, PathBuf};
+
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MountedImage {
+    pub image: PathBuf,
+    pub mount_point: PathBuf,
+}
+
+impl MountedImage {
+    pub fn image_name(&self) -> String {
+        self.image
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_else(|| self.image.display().to_string())
+    }
+}
+
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MountedImages {
+    mounts: Vec<MountedImage>,
+}
+
+impl MountedImages {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record(&mut self, image: PathBuf, mount_point: PathBuf) {
+        self.mounts
+            .retain(|m| m.image != image && m.mount_point != mount_point);
+        self.mounts.push(MountedImage { image, mount_point });
+    }
+
+    pub fn forget(&mut self, mount_point: &Path) -> Option<MountedImage> {
+        let index = self.mounts.iter().position(|m| m.mount_point == mount_point)?;
+        Some(self.mounts.remove(index))
+    }
+
+    pub fn at_mount_point(&self, mount_point: &Path) -> Option<&MountedImage> {
+        self.mounts.iter().find(|m| m.mount_point == mount_point)
+    }
+
+    pub fn for_image(&self, image: &Path) -> Option<&MountedImage> {
+        self.mounts.iter().find(|m| m.image == image)
+    }
+
+    pub fn unmount_target(&self, path: &Path) -> PathBuf {
+        self.for_image(path)
+            .map(|m| m.mount_point.clone())
+            .unwrap_or_else(|| path.to_path_buf())
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &MountedImage> {
+        self.mounts.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.mounts.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.mounts.is_empty()
+    }
+}
@@ -0,0 +1,58 @@
+use super::mounted_images::*;
+use std::path::{Path, PathBuf};
+
+
+fn tracked() -> MountedImages {
+    let mut images = MountedImages::new();
+    images.record("/Users/me/Installer.dmg".into(), "/Volumes/Installer".into());
+    images.record("/home/me/disk.iso".into(), "/media/me/disk".into());
+    images
+}
+
+#[test]
+fn test_mount_point_maps_back_to_its_image() {
+    let images = tracked();
+    let mounted = images.at_mount_point(Path::new("/Volumes/Installer")).unwrap();
+    assert_eq!(mounted.image, PathBuf::from("/Users/me/Installer.dmg"));
+    assert_eq!(mounted.image_name(), "Installer.dmg");
+    assert!(images.at_mount_point(Path::new("/Volumes/Backup")).is_none());
+}
+
+#[test]
+fn test_unmount_targets_mount_point_for_image_or_mount_path() {
+    let images = tracked();
+    assert_eq!(
+        images.unmount_target(Path::new("/home/me/disk.iso")),
+        PathBuf::from("/media/me/disk")
+    );
+    assert_eq!(
+        images.unmount_target(Path::new("/media/me/disk")),
+        PathBuf::from("/media/me/disk")
+    );
+}
+
+#[test]
+fn test_remounting_replaces_previous_association() {
+    let mut images = tracked();
+    images.record("/home/me/disk.iso".into(), "/media/me/disk1".into());
+    assert_eq!(images.len(), 2);
+    assert!(images.at_mount_point(Path::new("/media/me/disk")).is_none());
+    assert_eq!(
+        images.for_image(Path::new("/home/me/disk.iso")).unwrap().mount_point,
+        PathBuf::from("/media/me/disk1")
+    );
+
+    images.record("/home/me/other.iso".into(), "/media/me/disk1".into());
+    assert_eq!(images.len(), 2);
+    assert!(images.for_image(Path::new("/home/me/disk.iso")).is_none());
+}
+
+#[test]
+fn test_unmount_drops_association() {
+    let mut images = tracked();
+    let forgotten = images.forget(Path::new("/Volumes/Installer")).unwrap();
+    assert_eq!(forgotten.image, PathBuf::from("/Users/me/Installer.dmg"));
+    assert!(images.forget(Path::new("/Volumes/Installer")).is_none());
+    assert!(images.for_image(Path::new("/Users/me/Installer.dmg")).is_none());
+    assert_eq!(images.len(), 1);
+}
@@ -0,0 +1,248 @@
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::time::{Duration, SystemTime};
+
+use gpui::Rgba;
+use serde::{Deserialize, Serialize};
+
+use crate::models::{FileEntry, IconKey, ThemeColors};
+
+
+pub const MIN_TINT_CONTRAST: f32 = 4.5;
+
+const DAY_SECS: u64 = 24 * 60 * 60;
+
+const ARCHIVE_EXTENSIONS: &[&str] = &[
+    "zip", "tar", "gz", "tgz", "bz2", "xz", "zst", "rar", "7z", "dmg", "iso", "deb", "rpm",
+];
+
+const IMAGE_EXTENSIONS: &[&str] = &[
+    "png", "jpg", "jpeg", "gif", "bmp", "webp", "svg", "ico", "heic", "heif", "tif", "tiff",
+    "raw", "psd",
+];
+
+const CODE_EXTENSIONS: &[&str] = &[
+    "rs", "c", "h", "cpp", "hpp", "cc", "go", "py", "rb", "js", "jsx", "mjs", "ts", "tsx",
+    "java", "kt", "scala", "swift", "cs", "php", "lua", "sh", "bash", "zsh", "html", "htm",
+    "css", "scss", "sass", "less", "json", "toml", "yaml", "yml", "xml", "sql", "zig",
+];
+
+const DOCUMENT_EXTENSIONS: &[&str] = &[
+    "pdf", "txt", "md", "markdown", "rtf", "doc", "docx", "odt", "xls", "xlsx", "ods", "ppt",
+    "pptx", "odp", "csv", "epub", "pages", "numbers", "key",
+];
+
+static NAME_TINT_MODE: AtomicU8 = AtomicU8::new(0);
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum NameTintMode {
+    #[default]
+    Off,
+    Category,
+    Age,
+}
+
+impl NameTintMode {
+    pub fn label(&self) -> &'static str {
+        match self {
+            NameTintMode::Off => "Off",
+            NameTintMode::Category => "By type",
+            NameTintMode::Age => "By age",
+        }
+    }
+
+    pub fn next(&self) -> Self {
+        match self {
+            NameTintMode::Off => NameTintMode::Category,
+            NameTintMode::Category => NameTintMode::Age,
+            NameTintMode::Age => NameTintMode::Off,
+        }
+    }
+}
+
+
+pub fn set_name_tint_mode(mode: NameTintMode) {
+    let value = match mode {
+        NameTintMode::Off => 0,
+        NameTintMode::Category => 1,
+        NameTintMode::Age => 2,
+    };
+    NAME_TINT_MODE.store(value, Ordering::SeqCst);
+}
+
+
+pub fn name_tint_mode() -> NameTintMode {
+    match NAME_TINT_MODE.load(Ordering::SeqCst) {
+        1 => NameTintMode::Category,
+        2 => NameTintMode::Age,
+        _ => NameTintMode::Off,
+    }
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FileCategory {
+    Archive,
+    Image,
+    Code,
+    Document,
+    Other,
+}
+
+impl FileCategory {
+    pub fn from_icon_key(key: &IconKey) -> Self {
+        match key {
+            IconKey::Extension(ext) => Self::from_extension(ext),
+            IconKey::MimeType(mime) => Self::from_mime_type(mime),
+            _ => FileCategory::Other,
+        }
+    }
+
+    pub fn from_extension(ext: &str) -> Self {
+        let ext = ext.to_lowercase();
+        let ext = ext.as_str();
+        if ARCHIVE_EXTENSIONS.contains(&ext) {
+            FileCategory::Archive
+        } else if IMAGE_EXTENSIONS.contains(&ext) {
+            FileCategory::Image
+        } else if CODE_EXTENSIONS.contains(&ext) {
+            FileCategory::Code
+        } else if DOCUMENT_EXTENSIONS.contains(&ext) {
+            FileCategory::Document
+        } else {
+            FileCategory::Other
+        }
+    }
+
+    fn from_mime_type(mime: &str) -> Self {
+        let mime = mime.to_lowercase();
+        if mime.starts_with("image/") {
+            FileCategory::Image
+        } else if mime.contains("zip") || mime.contains("tar") || mime.contains("compressed") {
+            FileCategory::Archive
+        } else if mime == "application/pdf" || mime.starts_with("text/plain") {
+            FileCategory::Document
+        } else if mime.starts_with("text/") {
+            FileCategory::Code
+        } else {
+            FileCategory::Other
+        }
+    }
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum AgeBucket {
+    Today,
+    ThisWeek,
+    ThisMonth,
+    ThisYear,
+    Older,
+}
+
+impl AgeBucket {
+    pub fn from_age(age: Duration) -> Self {
+        let days = age.as_secs() / DAY_SECS;
+        match days {
+            0 => AgeBucket::Today,
+            1..=6 => AgeBucket::ThisWeek,
+            7..=29 => AgeBucket::ThisMonth,
+            30..=364 => AgeBucket::ThisYear,
+            _ => AgeBucket::Older,
+        }
+    }
+
+    pub fn for_modified(modified: SystemTime, now: SystemTime) -> Self {
+        Self::from_age(now.duration_since(modified).unwrap_or(Duration::ZERO))
+    }
+
+
+    pub fn brightness(&self) -> f32 {
+        match self {
+            AgeBucket::Today => 1.0,
+            AgeBucket::ThisWeek => 0.75,
+            AgeBucket::ThisMonth => 0.5,
+            AgeBucket::ThisYear => 0.25,
+            AgeBucket::Older => 0.0,
+        }
+    }
+}
+
+
+pub fn category_tint(category: FileCategory, colors: &ThemeColors) -> Option<Rgba> {
+    match category {
+        FileCategory::Archive => Some(colors.file_archive),
+        FileCategory::Image => Some(colors.file_media),
+        FileCategory::Code => Some(colors.file_code),
+        FileCategory::Document => Some(colors.file_document),
+        FileCategory::Other => None,
+    }
+}
+
+
+pub fn age_tint(bucket: AgeBucket, colors: &ThemeColors) -> Rgba {
+    mix(colors.text_muted, colors.text_primary, bucket.brightness())
+}
+
+
+pub fn name_tint(
+    mode: NameTintMode,
+    entry: &FileEntry,
+    now: SystemTime,
+    colors: &ThemeColors,
+) -> Option<Rgba> {
+    let tint = match mode {
+        NameTintMode::Off => None,
+        NameTintMode::Category if entry.is_dir => None,
+        NameTintMode::Category => {
+            category_tint(FileCategory::from_icon_key(&entry.icon_key), colors)
+        }
+        NameTintMode::Age => Some(age_tint(
+            AgeBucket::for_modified(entry.modified, now),
+            colors,
+        )),
+    }?;
+    Some(ensure_contrast(tint, colors.bg_primary, colors.text_primary))
+}
+
+
+pub fn ensure_contrast(color: Rgba, background: Rgba, fallback: Rgba) -> Rgba {
+    const STEPS: u8 = 10;
+
+    for step in 0..STEPS {
+        let candidate = mix(color, fallback, step as f32 / STEPS as f32);
+        if contrast_ratio(candidate, background) >= MIN_TINT_CONTRAST {
+            return candidate;
+        }
+    }
+    fallback
+}
+
+
+pub fn contrast_ratio(a: Rgba, b: Rgba) -> f32 {
+    let (la, lb) = (relative_luminance(a), relative_luminance(b));
+    let (lighter, darker) = if la >= lb { (la, lb) } else { (lb, la) };
+    (lighter + 0.05) / (darker + 0.05)
+}
+
+fn relative_luminance(color: Rgba) -> f32 {
+    let channel = |value: f32| {
+        if value <= 0.039_28 {
+            value / 12.92
+        } else {
+            ((value + 0.055) / 1.055).powf(2.4)
+        }
+    };
+    0.2126 * channel(color.r) + 0.7152 * channel(color.g) + 0.0722 * channel(color.b)
+}
+
+pub(crate) fn mix(from: Rgba, to: Rgba, amount: f32) -> Rgba {
+    let amount = amount.clamp(0.0, 1.0);
+    let lerp = |a: f32, b: f32| a + (b - a) * amount;
+    Rgba {
+        r: lerp(from.r, to.r),
+        g: lerp(from.g, to.g),
+        b: lerp(from.b, to.b),
+        a: lerp(from.a, to.a),
+    }
+}
@@ -0,0 +1,111 @@
+use super::name_tint::*;
+use crate::models::{FileEntry, IconKey, Theme};
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime};
+
+const DAY: Duration = Duration::from_secs(24 * 60 * 60);
+
+
+fn entry(name: &str, is_dir: bool, modified: SystemTime) -> FileEntry {
+    FileEntry::new(
+        name.to_string(),
+        PathBuf::from("/data").join(name),
+        is_dir,
+        1024,
+        modified,
+    )
+}
+
+#[test]
+fn test_extensions_map_to_categories() {
+    assert_eq!(FileCategory::from_extension("zip"), FileCategory::Archive);
+    assert_eq!(FileCategory::from_extension("7z"), FileCategory::Archive);
+    assert_eq!(FileCategory::from_extension("PNG"), FileCategory::Image);
+    assert_eq!(FileCategory::from_extension("heic"), FileCategory::Image);
+    assert_eq!(FileCategory::from_extension("rs"), FileCategory::Code);
+    assert_eq!(FileCategory::from_extension("tsx"), FileCategory::Code);
+    assert_eq!(FileCategory::from_extension("pdf"), FileCategory::Document);
+    assert_eq!(FileCategory::from_extension("docx"), FileCategory::Document);
+    assert_eq!(FileCategory::from_extension("bin"), FileCategory::Other);
+}
+
+#[test]
+fn test_icon_keys_map_to_categories() {
+    assert_eq!(
+        FileCategory::from_icon_key(&IconKey::Extension("tar".to_string())),
+        FileCategory::Archive
+    );
+    assert_eq!(
+        FileCategory::from_icon_key(&IconKey::MimeType("image/jpeg".to_string())),
+        FileCategory::Image
+    );
+    assert_eq!(FileCategory::from_icon_key(&IconKey::Directory), FileCategory::Other);
+    assert_eq!(FileCategory::from_icon_key(&IconKey::GenericFile), FileCategory::Other);
+}
+
+#[test]
+fn test_ages_map_to_tint_buckets() {
+    let now = SystemTime::now();
+
+    assert_eq!(AgeBucket::for_modified(now, now), AgeBucket::Today);
+    assert_eq!(AgeBucket::for_modified(now + DAY, now), AgeBucket::Today);
+    assert_eq!(AgeBucket::for_modified(now - DAY * 3, now), AgeBucket::ThisWeek);
+    assert_eq!(AgeBucket::for_modified(now - DAY * 10, now), AgeBucket::ThisMonth);
+    assert_eq!(AgeBucket::for_modified(now - DAY * 200, now), AgeBucket::ThisYear);
+    assert_eq!(AgeBucket::for_modified(now - DAY * 400, now), AgeBucket::Older);
+
+    assert!(AgeBucket::Today.brightness() > AgeBucket::ThisWeek.brightness());
+    assert!(AgeBucket::ThisYear.brightness() > AgeBucket::Older.brightness());
+}
+
+#[test]
+fn test_tint_respects_mode() {
+    let colors = Theme::dark().colors;
+    let now = SystemTime::now();
+    let archive = entry("backup.zip", false, now);
+    let folder = entry("src", true, now);
+
+    assert_eq!(name_tint(NameTintMode::Off, &archive, now, &colors), None);
+    assert!(name_tint(NameTintMode::Category, &archive, now, &colors).is_some());
+    assert_eq!(name_tint(NameTintMode::Category, &folder, now, &colors), None);
+    assert!(name_tint(NameTintMode::Age, &folder, now, &colors).is_some());
+}
+
+#[test]
+fn test_tints_keep_contrast_in_every_theme() {
+    let now = SystemTime::now();
+    let samples = [
+        entry("a.zip", false, now),
+        entry("b.png", false, now),
+        entry("c.rs", false, now - DAY * 3),
+        entry("d.pdf", false, now - DAY * 40),
+        entry("e.log", false, now - DAY * 900),
+    ];
+
+    for theme in Theme::all_themes() {
+        let colors = &theme.colors;
+        let baseline = contrast_ratio(colors.text_primary, colors.bg_primary);
+        for mode in [NameTintMode::Category, NameTintMode::Age] {
+            for sample in &samples {
+                if let Some(tint) = name_tint(mode, sample, now, colors) {
+                    let ratio = contrast_ratio(tint, colors.bg_primary);
+                    assert!(
+                        ratio >= MIN_TINT_CONTRAST.min(baseline),
+                        "{:?} {:?} {} has contrast {}",
+                        theme.id,
+                        mode,
+                        sample.name,
+                        ratio
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_mode_cycles_through_all_states() {
+    assert_eq!(NameTintMode::Off.next(), NameTintMode::Category);
+    assert_eq!(NameTintMode::Category.next(), NameTintMode::Age);
+    assert_eq!(NameTintMode::Age.next(), NameTintMode::Off);
+}
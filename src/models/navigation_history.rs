@@ -0,0 +1,131 @@
+use std::path::{Path, PathBuf};
+
+use gpui::{MouseButton, NavigationDirection};
+
+
+pub const HISTORY_MENU_LIMIT: usize = 12;
+
+
+pub const DEFAULT_HISTORY_LIMIT: usize = 100;
+
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HistoryMenuEntry {
+    pub steps: usize,
+    pub path: PathBuf,
+}
+
+impl HistoryMenuEntry {
+    pub fn label(&self) -> String {
+        history_label(&self.path)
+    }
+}
+
+
+pub fn back_menu_entries(history: &[PathBuf], limit: usize) -> Vec<HistoryMenuEntry> {
+    history
+        .iter()
+        .rev()
+        .skip(1)
+        .take(limit)
+        .enumerate()
+        .map(|(index, path)| HistoryMenuEntry {
+            steps: index + 1,
+            path: path.clone(),
+        })
+        .collect()
+}
+
+
+pub fn forward_menu_entries(forward: &[PathBuf], limit: usize) -> Vec<HistoryMenuEntry> {
+    forward
+        .iter()
+        .rev()
+        .take(limit)
+        .enumerate()
+        .map(|(index, path)| HistoryMenuEntry {
+            steps: index + 1,
+            path: path.clone(),
+        })
+        .collect()
+}
+
+
+pub fn record_visit(
+    history: &mut Vec<PathBuf>,
+    forward: &mut Vec<PathBuf>,
+    path: PathBuf,
+    limit: usize,
+) -> bool {
+    if history.last() == Some(&path) {
+        return false;
+    }
+    history.push(path);
+    forward.clear();
+    trim_history(history, limit);
+    true
+}
+
+
+pub fn trim_history(history: &mut Vec<PathBuf>, limit: usize) {
+    let limit = limit.max(1);
+    if history.len() > limit {
+        history.drain(..history.len() - limit);
+    }
+}
+
+
+pub fn jump_back(
+    history: &mut Vec<PathBuf>,
+    forward: &mut Vec<PathBuf>,
+    steps: usize,
+) -> Option<PathBuf> {
+    if steps == 0 || steps >= history.len() {
+        return None;
+    }
+    for _ in 0..steps {
+        forward.extend(history.pop());
+    }
+    history.last().cloned()
+}
+
+
+pub fn jump_forward(
+    history: &mut Vec<PathBuf>,
+    forward: &mut Vec<PathBuf>,
+    steps: usize,
+) -> Option<PathBuf> {
+    if steps == 0 || steps > forward.len() {
+        return None;
+    }
+    for _ in 0..steps {
+        history.extend(forward.pop());
+    }
+    history.last().cloned()
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HistoryStep {
+    Back,
+    Forward,
+}
+
+
+pub fn mouse_history_step(button: MouseButton, text_input_focused: bool) -> Option<HistoryStep> {
+    if text_input_focused {
+        return None;
+    }
+    match button {
+        MouseButton::Navigate(NavigationDirection::Back) => Some(HistoryStep::Back),
+        MouseButton::Navigate(NavigationDirection::Forward) => Some(HistoryStep::Forward),
+        _ => None,
+    }
+}
+
+
+fn history_label(path: &Path) -> String {
+    path.file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_else(|| path.display().to_string())
+}
@@ -0,0 +1,139 @@
+use super::navigation_history::*;
+use gpui::{MouseButton, NavigationDirection};
+use std::path::PathBuf;
+
+
+fn paths(names: &[&str]) -> Vec<PathBuf> {
+    names.iter().map(PathBuf::from).collect()
+}
+
+#[test]
+fn test_back_menu_lists_most_recent_first_without_current() {
+    let history = paths(&["/", "/home", "/home/user", "/home/user/docs"]);
+    let entries = back_menu_entries(&history, HISTORY_MENU_LIMIT);
+
+    assert_eq!(
+        entries.iter().map(|e| e.path.clone()).collect::<Vec<_>>(),
+        paths(&["/home/user", "/home", "/"])
+    );
+    assert_eq!(entries[0].steps, 1);
+    assert_eq!(entries[2].steps, 3);
+    assert_eq!(entries[0].label(), "user");
+    assert_eq!(entries[2].label(), "/");
+}
+
+#[test]
+fn test_menu_length_is_capped() {
+    let history: Vec<PathBuf> = (0..40).map(|i| PathBuf::from(format!("/dir{}", i))).collect();
+    let entries = back_menu_entries(&history, HISTORY_MENU_LIMIT);
+
+    assert_eq!(entries.len(), HISTORY_MENU_LIMIT);
+    assert_eq!(entries[0].path, PathBuf::from("/dir38"));
+    assert_eq!(forward_menu_entries(&history, 5).len(), 5);
+}
+
+#[test]
+fn test_selecting_third_back_entry_trims_history() {
+    let mut history = paths(&["/a", "/b", "/c", "/d", "/e"]);
+    let mut forward = Vec::new();
+
+    let third = back_menu_entries(&history, HISTORY_MENU_LIMIT)[2].clone();
+    assert_eq!(third.path, PathBuf::from("/b"));
+
+    let current = jump_back(&mut history, &mut forward, third.steps);
+    assert_eq!(current, Some(PathBuf::from("/b")));
+    assert_eq!(history, paths(&["/a", "/b"]));
+    assert_eq!(forward, paths(&["/e", "/d", "/c"]));
+
+    let entries = forward_menu_entries(&forward, HISTORY_MENU_LIMIT);
+    assert_eq!(entries[0].path, PathBuf::from("/c"));
+    assert_eq!(entries[2].path, PathBuf::from("/e"));
+}
+
+#[test]
+fn test_jump_forward_restores_trimmed_entries() {
+    let mut history = paths(&["/a", "/b"]);
+    let mut forward = paths(&["/e", "/d", "/c"]);
+
+    assert_eq!(
+        jump_forward(&mut history, &mut forward, 2),
+        Some(PathBuf::from("/d"))
+    );
+    assert_eq!(history, paths(&["/a", "/b", "/c", "/d"]));
+    assert_eq!(forward, paths(&["/e"]));
+}
+
+#[test]
+fn test_out_of_range_jumps_are_ignored() {
+    let mut history = paths(&["/a", "/b"]);
+    let mut forward = Vec::new();
+
+    assert_eq!(jump_back(&mut history, &mut forward, 0), None);
+    assert_eq!(jump_back(&mut history, &mut forward, 2), None);
+    assert_eq!(jump_forward(&mut history, &mut forward, 1), None);
+    assert_eq!(history, paths(&["/a", "/b"]));
+    assert!(forward.is_empty());
+}
+
+#[test]
+fn test_refreshing_same_directory_does_not_grow_history() {
+    let mut history = paths(&["/a"]);
+    let mut forward = Vec::new();
+
+    assert!(record_visit(&mut history, &mut forward, PathBuf::from("/b"), DEFAULT_HISTORY_LIMIT));
+    for _ in 0..5 {
+        assert!(!record_visit(&mut history, &mut forward, PathBuf::from("/b"), DEFAULT_HISTORY_LIMIT));
+    }
+    assert_eq!(history, paths(&["/a", "/b"]));
+
+    jump_back(&mut history, &mut forward, 1);
+    assert!(!record_visit(&mut history, &mut forward, PathBuf::from("/a"), DEFAULT_HISTORY_LIMIT));
+    assert_eq!(forward, paths(&["/b"]));
+}
+
+#[test]
+fn test_history_cap_trims_oldest_entries() {
+    let mut history = Vec::new();
+    let mut forward = Vec::new();
+    for i in 0..10 {
+        record_visit(&mut history, &mut forward, PathBuf::from(format!("/dir{}", i)), 4);
+    }
+
+    assert_eq!(history, paths(&["/dir6", "/dir7", "/dir8", "/dir9"]));
+
+    assert_eq!(jump_back(&mut history, &mut forward, 3), Some(PathBuf::from("/dir6")));
+    assert_eq!(jump_back(&mut history, &mut forward, 1), None);
+    assert_eq!(jump_forward(&mut history, &mut forward, 3), Some(PathBuf::from("/dir9")));
+    assert_eq!(history.len(), 4);
+
+    trim_history(&mut history, 0);
+    assert_eq!(history, paths(&["/dir9"]));
+}
+
+#[test]
+fn test_mouse_navigation_buttons_step_through_history() {
+    let back = MouseButton::Navigate(NavigationDirection::Back);
+    let forward = MouseButton::Navigate(NavigationDirection::Forward);
+    assert_eq!(mouse_history_step(back, false), Some(HistoryStep::Back));
+    assert_eq!(mouse_history_step(forward, false), Some(HistoryStep::Forward));
+    assert_eq!(mouse_history_step(MouseButton::Left, false), None);
+    assert_eq!(mouse_history_step(MouseButton::Middle, false), None);
+
+    let mut history = paths(&["/", "/home", "/home/user"]);
+    let mut forward_stack = Vec::new();
+    let step = |button| match mouse_history_step(button, false) {
+        Some(HistoryStep::Back) => jump_back,
+        Some(HistoryStep::Forward) => jump_forward,
+        None => unreachable!(),
+    };
+    assert_eq!(step(back)(&mut history, &mut forward_stack, 1), Some(PathBuf::from("/home")));
+    assert_eq!(step(forward)(&mut history, &mut forward_stack, 1), Some(PathBuf::from("/home/user")));
+}
+
+#[test]
+fn test_mouse_navigation_ignored_while_typing() {
+    let back = MouseButton::Navigate(NavigationDirection::Back);
+    let forward = MouseButton::Navigate(NavigationDirection::Forward);
+    assert_eq!(mouse_history_step(back, true), None);
+    assert_eq!(mouse_history_step(forward, true), None);
+}
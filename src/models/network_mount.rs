@@ -0,0 +1,281 @@
+use std::io::{Read, Write};
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
+
+use crate::models::{AuthMethod, NetworkConnectionConfig, NetworkError, NetworkResult};
+
+
+pub const NETWORK_CONNECT_TIMEOUT: Duration = Duration::from_secs(30);
+
+
+pub fn mount_location(
+    config: &NetworkConnectionConfig,
+    timeout: Duration,
+) -> NetworkResult<Option<PathBuf>> {
+    let (command, stdin) = mount_command(config)?;
+    let output = run_with_timeout(command, stdin, timeout)?;
+
+    if !output.success {
+        let message = if output.stderr.trim().is_empty() {
+            output.stdout
+        } else {
+            output.stderr
+        };
+        return Err(classify_mount_error(config, &message));
+    }
+
+    Ok(find_mount_point(config))
+}
+
+
+pub fn classify_mount_error(config: &NetworkConnectionConfig, message: &str) -> NetworkError {
+    let lower = message.to_lowercase();
+    let detail = message.trim().to_string();
+
+    if lower.contains("permission denied")
+        || lower.contains("authentication")
+        || lower.contains("logon failure")
+        || lower.contains("access denied")
+        || lower.contains("password")
+        || lower.contains("system error 86")
+        || lower.contains("system error 1326")
+    {
+        NetworkError::AuthenticationFailed(config.host.clone())
+    } else if lower.contains("could not resolve")
+        || lower.contains("name or service not known")
+        || lower.contains("unknown host")
+        || lower.contains("no such host")
+        || lower.contains("system error 53")
+    {
+        NetworkError::HostNotFound(config.host.clone())
+    } else if lower.contains("network is unreachable")
+        || lower.contains("no route to host")
+        || lower.contains("connection refused")
+    {
+        NetworkError::NetworkUnreachable
+    } else if lower.contains("timed out") || lower.contains("timeout") {
+        NetworkError::Timeout
+    } else if lower.contains("not found")
+        || lower.contains("does not exist")
+        || lower.contains("no such file")
+        || lower.contains("bad network name")
+        || lower.contains("system error 67")
+    {
+        NetworkError::ShareNotFound(config.path.clone())
+    } else {
+        NetworkError::ConnectionFailed(detail)
+    }
+}
+
+
+struct MountOutput {
+    success: bool,
+    stdout: String,
+    stderr: String,
+}
+
+
+fn run_with_timeout(
+    mut command: Command,
+    stdin: Option<String>,
+    timeout: Duration,
+) -> NetworkResult<MountOutput> {
+    let mut child = command
+        .stdin(if stdin.is_some() {
+            Stdio::piped()
+        } else {
+            Stdio::null()
+        })
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+
+    if let (Some(input), Some(mut pipe)) = (stdin, child.stdin.take()) {
+        let _ = pipe.write_all(input.as_bytes());
+    }
+
+    let deadline = Instant::now() + timeout;
+    let status = loop {
+        if let Some(status) = child.try_wait()? {
+            break status;
+        }
+        if Instant::now() >= deadline {
+            let _ = child.kill();
+            let _ = child.wait();
+            return Err(NetworkError::Timeout);
+        }
+        std::thread::sleep(Duration::from_millis(100));
+    };
+
+    let mut stdout = String::new();
+    let mut stderr = String::new();
+    if let Some(mut pipe) = child.stdout.take() {
+        let _ = pipe.read_to_string(&mut stdout);
+    }
+    if let Some(mut pipe) = child.stderr.take() {
+        let _ = pipe.read_to_string(&mut stderr);
+    }
+
+    Ok(MountOutput {
+        success: status.success(),
+        stdout,
+        stderr,
+    })
+}
+
+
+fn credentials(config: &NetworkConnectionConfig) -> Option<(&str, &str)> {
+    match &config.auth {
+        AuthMethod::Anonymous => None,
+        AuthMethod::Password { username, password } => Some((username, password)),
+        AuthMethod::KeyFile { username, .. } => Some((username, "")),
+    }
+}
+
+
+#[cfg(target_os = "linux")]
+fn url_with_user(config: &NetworkConnectionConfig) -> String {
+    match credentials(config) {
+        Some((username, _)) if !username.is_empty() => {
+            let port = config.port.map(|p| format!(":{}", p)).unwrap_or_default();
+            format!(
+                "{}://{}@{}{}{}",
+                config.protocol.url_scheme(),
+                username,
+                config.host,
+                port,
+                config.path
+            )
+        }
+        _ => config.to_url(),
+    }
+}
+
+
+#[cfg(target_os = "linux")]
+pub(crate) fn mount_command(
+    config: &NetworkConnectionConfig,
+) -> NetworkResult<(Command, Option<String>)> {
+    if config.protocol == crate::models::NetworkProtocol::Nfs {
+        return Err(NetworkError::ProtocolNotSupported(
+            config.protocol.display_name().to_string(),
+        ));
+    }
+
+    let mut command = Command::new("gio");
+    command.args(["mount", &url_with_user(config)]);
+
+    let stdin = credentials(config).map(|(username, password)| {
+        if config.protocol == crate::models::NetworkProtocol::Smb {
+            format!("{}\n\n{}\n", username, password)
+        } else {
+            format!("{}\n", password)
+        }
+    });
+
+    Ok((command, stdin))
+}
+
+
+#[cfg(target_os = "macos")]
+pub(crate) fn mount_command(
+    config: &NetworkConnectionConfig,
+) -> NetworkResult<(Command, Option<String>)> {
+    let escape = |value: &str| value.replace('\\', "\\\\").replace('"', "\\\"");
+    let mut script = format!("mount volume \"{}\"", escape(&config.to_url()));
+    if let Some((username, password)) = credentials(config) {
+        script.push_str(&format!(" as user name \"{}\"", escape(username)));
+        if !password.is_empty() {
+            script.push_str(&format!(" with password \"{}\"", escape(password)));
+        }
+    }
+
+    let mut command = Command::new("osascript");
+    command.arg("-");
+    Ok((command, Some(format!("{}\n", script))))
+}
+
+
+#[cfg(target_os = "windows")]
+pub(crate) fn mount_command(
+    config: &NetworkConnectionConfig,
+) -> NetworkResult<(Command, Option<String>)> {
+    if config.protocol != crate::models::NetworkProtocol::Smb {
+        return Err(NetworkError::ProtocolNotSupported(
+            config.protocol.display_name().to_string(),
+        ));
+    }
+
+    let mut command = Command::new("net");
+    command.args(["use", &unc_path(config)]);
+    let stdin = credentials(config).map(|(username, password)| {
+        command.arg("*").arg(format!("/user:{}", username));
+        format!("{}\n", password)
+    });
+    Ok((command, stdin))
+}
+
+
+#[cfg(not(any(target_os = "linux", target_os = "macos", target_os = "windows")))]
+pub(crate) fn mount_command(
+    config: &NetworkConnectionConfig,
+) -> NetworkResult<(Command, Option<String>)> {
+    Err(NetworkError::ProtocolNotSupported(
+        config.protocol.display_name().to_string(),
+    ))
+}
+
+
+#[cfg(target_os = "windows")]
+fn unc_path(config: &NetworkConnectionConfig) -> String {
+    format!(
+        "\\\\{}{}",
+        config.host,
+        config.path.trim_end_matches('/').replace('/', "\\")
+    )
+}
+
+
+#[cfg(target_os = "linux")]
+fn find_mount_point(config: &NetworkConnectionConfig) -> Option<PathBuf> {
+    let runtime_dir = std::env::var_os("XDG_RUNTIME_DIR").map(PathBuf::from)?;
+    let host_marker = format!("host={}", config.host.to_lowercase());
+    let server_marker = format!("server={}", config.host.to_lowercase());
+
+    std::fs::read_dir(runtime_dir.join("gvfs"))
+        .ok()?
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| {
+            let name = entry.file_name().to_string_lossy().to_lowercase();
+            name.contains(&host_marker) || name.contains(&server_marker)
+        })
+        .max_by_key(|entry| entry.metadata().and_then(|m| m.modified()).ok())
+        .map(|entry| entry.path())
+}
+
+
+#[cfg(target_os = "macos")]
+fn find_mount_point(config: &NetworkConnectionConfig) -> Option<PathBuf> {
+    let volume = config
+        .path
+        .trim_matches('/')
+        .split('/')
+        .next()
+        .filter(|share| !share.is_empty())
+        .unwrap_or(&config.host);
+    let path = PathBuf::from("/Volumes").join(volume);
+    path.exists().then_some(path)
+}
+
+
+#[cfg(target_os = "windows")]
+fn find_mount_point(config: &NetworkConnectionConfig) -> Option<PathBuf> {
+    Some(PathBuf::from(unc_path(config)))
+}
+
+
+#[cfg(not(any(target_os = "linux", target_os = "macos", target_os = "windows")))]
+fn find_mount_point(_config: &NetworkConnectionConfig) -> Option<PathBuf> {
+    None
+}
@@ -0,0 +1,54 @@
+use super::network_mount::*;
+use crate::models::{AuthMethod, NetworkConnectionConfig, NetworkError, NetworkProtocol};
+
+
+fn config() -> NetworkConnectionConfig {
+    NetworkConnectionConfig::new(NetworkProtocol::Smb, "nas.local".to_string())
+        .with_path("/media".to_string())
+}
+
+#[test]
+fn test_classify_authentication_failure() {
+    let error = classify_mount_error(&config(), "gio: smb://nas.local/media: Permission denied");
+    assert!(matches!(error, NetworkError::AuthenticationFailed(host) if host == "nas.local"));
+
+    let error = classify_mount_error(&config(), "System error 1326 has occurred.");
+    assert!(matches!(error, NetworkError::AuthenticationFailed(_)));
+}
+
+#[test]
+fn test_classify_unreachable_host() {
+    let error = classify_mount_error(&config(), "Could not resolve hostname nas.local");
+    assert!(matches!(error, NetworkError::HostNotFound(_)));
+
+    let error = classify_mount_error(&config(), "connect: No route to host");
+    assert!(matches!(error, NetworkError::NetworkUnreachable));
+}
+
+#[test]
+fn test_classify_missing_share() {
+    let error = classify_mount_error(&config(), "The share \"media\" does not exist");
+    assert!(matches!(error, NetworkError::ShareNotFound(path) if path == "/media"));
+}
+
+#[test]
+fn test_classify_unknown_error_keeps_message() {
+    let error = classify_mount_error(&config(), "  something odd happened \n");
+    assert!(matches!(error, NetworkError::ConnectionFailed(msg) if msg == "something odd happened"));
+}
+
+#[cfg(any(target_os = "linux", target_os = "macos", target_os = "windows"))]
+#[test]
+fn test_mount_command_keeps_password_out_of_argv() {
+    let secret = "s3cret-pa55";
+    let config = config().with_auth(AuthMethod::Password {
+        username: "alice".to_string(),
+        password: secret.to_string(),
+    });
+
+    let (command, stdin) = mount_command(&config).unwrap();
+    assert!(command
+        .get_args()
+        .all(|arg| !arg.to_string_lossy().contains(secret)));
+    assert!(stdin.is_some_and(|input| input.contains(secret)));
+}
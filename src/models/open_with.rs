@@ -3,11 +3,14 @@
 
 
 use std::collections::{HashMap, HashSet};
+use std::ffi::OsString;
 use std::path::{Path, PathBuf};
 use std::process::Command;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, RwLock};
 
+use crate::models::AppAssociations;
+
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct AppInfo {
@@ -187,11 +190,97 @@ pub fn is_registry_loaded() -> bool {
 }
 
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LaunchPlatform {
+    MacOs,
+    Windows,
+    Linux,
+}
+
+impl LaunchPlatform {
+    pub fn current() -> Option<Self> {
+        if cfg!(target_os = "macos") {
+            Some(LaunchPlatform::MacOs)
+        } else if cfg!(target_os = "windows") {
+            Some(LaunchPlatform::Windows)
+        } else if cfg!(target_os = "linux") {
+            Some(LaunchPlatform::Linux)
+        } else {
+            None
+        }
+    }
+
+    pub fn accepts_multiple_files(self) -> bool {
+        self == LaunchPlatform::MacOs
+    }
+}
+
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AppLaunch {
+    pub program: PathBuf,
+    pub args: Vec<OsString>,
+    pub files: Vec<PathBuf>,
+}
+
+
+pub fn app_launches(files: &[PathBuf], app: &AppInfo, platform: LaunchPlatform) -> Vec<AppLaunch> {
+    if files.is_empty() {
+        return Vec::new();
+    }
+    match platform {
+        LaunchPlatform::MacOs => {
+            let mut args: Vec<OsString> = vec!["-a".into(), app.path.clone().into_os_string()];
+            args.extend(files.iter().map(|file| file.clone().into_os_string()));
+            vec![AppLaunch {
+                program: PathBuf::from("open"),
+                args,
+                files: files.to_vec(),
+            }]
+        }
+        LaunchPlatform::Windows | LaunchPlatform::Linux => files
+            .iter()
+            .map(|file| AppLaunch {
+                program: app.path.clone(),
+                args: vec![file.clone().into_os_string()],
+                files: vec![file.clone()],
+            })
+            .collect(),
+    }
+}
+
+
+pub fn open_files_with_app(files: &[PathBuf], app: &AppInfo) -> Vec<(PathBuf, String)> {
+    let Some(platform) = LaunchPlatform::current() else {
+        return files
+            .iter()
+            .map(|file| (file.clone(), "Platform not supported".to_string()))
+            .collect();
+    };
+
+    let mut failures = Vec::new();
+    for launch in app_launches(files, app, platform) {
+        if let Err(e) = Command::new(&launch.program).args(&launch.args).spawn() {
+            let message = format!("Failed to open: {}", e);
+            failures.extend(launch.files.into_iter().map(|file| (file, message.clone())));
+        }
+    }
+    failures
+}
+
+
 pub fn open_file_with_app(file_path: &Path, app: &AppInfo) -> Result<(), String> {
+    match open_files_with_app(&[file_path.to_path_buf()], app).pop() {
+        Some((_, error)) => Err(error),
+        None => Ok(()),
+    }
+}
+
+
+pub fn open_with_default_app(file_path: &Path) -> Result<(), String> {
     #[cfg(target_os = "macos")]
     {
         Command::new("open")
-            .args(["-a", app.path.to_str().unwrap_or("")])
             .arg(file_path)
             .spawn()
             .map_err(|e| format!("Failed to open: {}", e))?;
@@ -200,7 +289,8 @@ pub fn open_file_with_app(file_path: &Path, app: &AppInfo) -> Result<(), String>
 
     #[cfg(target_os = "windows")]
     {
-        Command::new(&app.path)
+        Command::new("cmd")
+            .args(["/C", "start", ""])
             .arg(file_path)
             .spawn()
             .map_err(|e| format!("Failed to open: {}", e))?;
@@ -209,7 +299,7 @@ pub fn open_file_with_app(file_path: &Path, app: &AppInfo) -> Result<(), String>
 
     #[cfg(target_os = "linux")]
     {
-        Command::new(&app.path)
+        Command::new("xdg-open")
             .arg(file_path)
             .spawn()
             .map_err(|e| format!("Failed to open: {}", e))?;
@@ -218,11 +308,60 @@ pub fn open_file_with_app(file_path: &Path, app: &AppInfo) -> Result<(), String>
 
     #[cfg(not(any(target_os = "macos", target_os = "windows", target_os = "linux")))]
     {
+        let _ = file_path;
         Err("Platform not supported".to_string())
     }
 }
 
 
+pub fn open_in_text_editor(file_path: &Path) -> Result<(), String> {
+    #[cfg(target_os = "macos")]
+    {
+        Command::new("open")
+            .arg("-t")
+            .arg(file_path)
+            .spawn()
+            .map_err(|e| format!("Failed to open: {}", e))?;
+        Ok(())
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        Command::new("notepad")
+            .arg(file_path)
+            .spawn()
+            .map_err(|e| format!("Failed to open: {}", e))?;
+        Ok(())
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        Command::new("xdg-open")
+            .arg(file_path)
+            .spawn()
+            .map_err(|e| format!("Failed to open: {}", e))?;
+        Ok(())
+    }
+
+    #[cfg(not(any(target_os = "macos", target_os = "windows", target_os = "linux")))]
+    {
+        let _ = file_path;
+        Err("Platform not supported".to_string())
+    }
+}
+
+
+pub fn open_with_preferred_app(file_path: &Path) -> Result<(), String> {
+    if file_path.is_dir() {
+        return open_with_default_app(file_path);
+    }
+    match AppAssociations::load().for_path(file_path) {
+        Some(association) => open_file_with_app(file_path, &association.to_app_info()),
+        None => open_with_default_app(file_path),
+    }
+}
+
+
 pub fn show_open_with_dialog(file_path: &Path) -> Result<(), String> {
     #[cfg(target_os = "macos")]
     {
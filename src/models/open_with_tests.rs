@@ -0,0 +1,52 @@
+use super::open_with::*;
+use std::ffi::OsString;
+use std::path::PathBuf;
+
+
+fn preview_app() -> AppInfo {
+    AppInfo::new("Preview".to_string(), PathBuf::from("/Applications/Preview.app"))
+}
+
+fn files() -> Vec<PathBuf> {
+    vec![PathBuf::from("/photos/a.png"), PathBuf::from("/photos/b c.png")]
+}
+
+#[test]
+fn test_multi_file_open_uses_single_launch_when_supported() {
+    let launches = app_launches(&files(), &preview_app(), LaunchPlatform::MacOs);
+
+    assert_eq!(launches.len(), 1);
+    assert_eq!(launches[0].program, PathBuf::from("open"));
+    assert_eq!(
+        launches[0].args,
+        vec![
+            OsString::from("-a"),
+            OsString::from("/Applications/Preview.app"),
+            OsString::from("/photos/a.png"),
+            OsString::from("/photos/b c.png"),
+        ]
+    );
+    assert_eq!(launches[0].files, files());
+}
+
+#[test]
+fn test_multi_file_open_falls_back_to_one_launch_per_file() {
+    let app = AppInfo::new("Viewer".to_string(), PathBuf::from("/usr/bin/viewer"));
+    for platform in [LaunchPlatform::Linux, LaunchPlatform::Windows] {
+        assert!(!platform.accepts_multiple_files());
+        let launches = app_launches(&files(), &app, platform);
+
+        assert_eq!(launches.len(), 2);
+        for (launch, file) in launches.iter().zip(files()) {
+            assert_eq!(launch.program, app.path);
+            assert_eq!(launch.args, vec![file.clone().into_os_string()]);
+            assert_eq!(launch.files, vec![file]);
+        }
+    }
+}
+
+#[test]
+fn test_empty_selection_launches_nothing() {
+    assert!(app_launches(&[], &preview_app(), LaunchPlatform::MacOs).is_empty());
+    assert!(open_files_with_app(&[], &preview_app()).is_empty());
+}
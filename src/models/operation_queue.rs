@@ -0,0 +1,163 @@
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use crate::models::{FileOperation, OperationId, OperationStatus, OperationType};
+
+
+pub const COMPLETED_OPERATION_TTL: Duration = Duration::from_secs(5);
+
+
+#[derive(Debug, Default)]
+pub struct OperationQueue {
+    operations: Vec<FileOperation>,
+    next_id: u64,
+}
+
+impl OperationQueue {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn enqueue(
+        &mut self,
+        op_type: OperationType,
+        sources: Vec<PathBuf>,
+        destination: Option<PathBuf>,
+    ) -> OperationId {
+        self.next_id += 1;
+        let id = OperationId::new(self.next_id);
+        self.operations
+            .push(FileOperation::new(id, op_type, sources, destination));
+        id
+    }
+
+
+    pub fn start_ready(&mut self) -> Vec<OperationId> {
+        let mut started = Vec::new();
+        for index in 0..self.operations.len() {
+            if self.operations[index].status != OperationStatus::Pending {
+                continue;
+            }
+            let blocked = self.operations[..index]
+                .iter()
+                .filter(|earlier| earlier.status.is_active())
+                .chain(
+                    self.operations[index + 1..]
+                        .iter()
+                        .filter(|later| later.status == OperationStatus::Running),
+                )
+                .any(|other| operations_conflict(other, &self.operations[index]));
+            if !blocked {
+                self.operations[index].start();
+                started.push(self.operations[index].id);
+            }
+        }
+        started
+    }
+
+    pub fn finish(&mut self, id: OperationId, result: Result<(), String>) -> bool {
+        let Some(operation) = self.get_mut(id) else {
+            return false;
+        };
+        match result {
+            Ok(()) => operation.complete(),
+            Err(message) => operation.fail(message),
+        }
+        true
+    }
+
+
+    pub fn cancel(&mut self, id: OperationId) -> bool {
+        match self.get_mut(id) {
+            Some(operation) if operation.status == OperationStatus::Pending => {
+                operation.cancel();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub fn move_up(&mut self, id: OperationId) -> bool {
+        let Some(index) = self.pending_index(id) else {
+            return false;
+        };
+        let Some(previous) = (0..index)
+            .rev()
+            .find(|&i| self.operations[i].status == OperationStatus::Pending)
+        else {
+            return false;
+        };
+        self.operations.swap(index, previous);
+        true
+    }
+
+    pub fn move_down(&mut self, id: OperationId) -> bool {
+        let Some(index) = self.pending_index(id) else {
+            return false;
+        };
+        let Some(next) = (index + 1..self.operations.len())
+            .find(|&i| self.operations[i].status == OperationStatus::Pending)
+        else {
+            return false;
+        };
+        self.operations.swap(index, next);
+        true
+    }
+
+    pub fn dismiss(&mut self, id: OperationId) -> bool {
+        let before = self.operations.len();
+        self.operations
+            .retain(|op| op.id != id || op.status.is_active());
+        self.operations.len() != before
+    }
+
+    pub fn dismiss_finished(&mut self) -> bool {
+        let before = self.operations.len();
+        self.operations.retain(|op| op.status.is_active());
+        self.operations.len() != before
+    }
+
+
+    pub fn prune_finished(&mut self, now: Instant) -> bool {
+        let before = self.operations.len();
+        self.operations.retain(|op| {
+            op.completed_at
+                .map_or(true, |at| now.duration_since(at) < COMPLETED_OPERATION_TTL)
+        });
+        self.operations.len() != before
+    }
+
+    pub fn get(&self, id: OperationId) -> Option<&FileOperation> {
+        self.operations.iter().find(|op| op.id == id)
+    }
+
+    pub fn get_mut(&mut self, id: OperationId) -> Option<&mut FileOperation> {
+        self.operations.iter_mut().find(|op| op.id == id)
+    }
+
+    pub fn operations(&self) -> &[FileOperation] {
+        &self.operations
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.operations.is_empty()
+    }
+
+    fn pending_index(&self, id: OperationId) -> Option<usize> {
+        self.operations
+            .iter()
+            .position(|op| op.id == id && op.status == OperationStatus::Pending)
+    }
+}
+
+
+fn operations_conflict(a: &FileOperation, b: &FileOperation) -> bool {
+    match (&a.destination, &b.destination) {
+        (Some(a), Some(b)) => paths_overlap(a, b),
+        _ => false,
+    }
+}
+
+fn paths_overlap(a: &Path, b: &Path) -> bool {
+    a.starts_with(b) || b.starts_with(a)
+}
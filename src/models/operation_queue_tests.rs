@@ -0,0 +1,99 @@
+use super::operation_queue::*;
+use crate::models::{OperationId, OperationStatus, OperationType};
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+
+fn copy_into(queue: &mut OperationQueue, source: &str, destination: &str) -> OperationId {
+    queue.enqueue(
+        OperationType::Copy,
+        vec![PathBuf::from(source)],
+        Some(PathBuf::from(destination)),
+    )
+}
+
+fn status(queue: &OperationQueue, id: OperationId) -> OperationStatus {
+    queue.get(id).unwrap().status.clone()
+}
+
+#[test]
+fn test_independent_operations_start_together_in_order() {
+    let mut queue = OperationQueue::new();
+    let first = copy_into(&mut queue, "/src/a.txt", "/dest/one");
+    let second = copy_into(&mut queue, "/src/b.txt", "/dest/two");
+
+    assert_eq!(queue.start_ready(), vec![first, second]);
+    assert!(queue.start_ready().is_empty());
+}
+
+#[test]
+fn test_same_destination_never_runs_concurrently() {
+    let mut queue = OperationQueue::new();
+    let first = copy_into(&mut queue, "/src/a.txt", "/dest");
+    let second = copy_into(&mut queue, "/src/b.txt", "/dest");
+    let nested = copy_into(&mut queue, "/src/c.txt", "/dest/sub");
+
+    assert_eq!(queue.start_ready(), vec![first]);
+    assert_eq!(status(&queue, second), OperationStatus::Pending);
+    assert_eq!(status(&queue, nested), OperationStatus::Pending);
+
+    queue.finish(first, Ok(()));
+    assert_eq!(queue.start_ready(), vec![second]);
+    assert_eq!(status(&queue, nested), OperationStatus::Pending);
+
+    queue.finish(second, Err("disk full".to_string()));
+    assert_eq!(queue.start_ready(), vec![nested]);
+    assert_eq!(
+        status(&queue, second),
+        OperationStatus::Failed("disk full".to_string())
+    );
+}
+
+#[test]
+fn test_reordering_changes_dequeue_order() {
+    let mut queue = OperationQueue::new();
+    let running = copy_into(&mut queue, "/src/a.txt", "/dest");
+    let second = copy_into(&mut queue, "/src/b.txt", "/dest");
+    let third = copy_into(&mut queue, "/src/c.txt", "/dest");
+    queue.start_ready();
+
+    assert!(queue.move_up(third));
+    assert!(!queue.move_up(third));
+    assert!(!queue.move_up(running));
+    assert!(!queue.move_down(second));
+
+    queue.finish(running, Ok(()));
+    assert_eq!(queue.start_ready(), vec![third]);
+    assert!(!queue.move_down(third));
+}
+
+#[test]
+fn test_cancelled_items_are_skipped() {
+    let mut queue = OperationQueue::new();
+    let running = copy_into(&mut queue, "/src/a.txt", "/dest");
+    let queued = copy_into(&mut queue, "/src/b.txt", "/dest");
+    queue.start_ready();
+
+    assert!(!queue.cancel(running));
+    assert!(queue.cancel(queued));
+    assert_eq!(status(&queue, queued), OperationStatus::Cancelled);
+
+    queue.finish(running, Ok(()));
+    assert!(queue.start_ready().is_empty());
+}
+
+#[test]
+fn test_finished_entries_clear_after_delay() {
+    let mut queue = OperationQueue::new();
+    let done = copy_into(&mut queue, "/src/a.txt", "/dest/one");
+    let active = copy_into(&mut queue, "/src/b.txt", "/dest/two");
+    queue.start_ready();
+    queue.finish(done, Ok(()));
+
+    let now = Instant::now();
+    assert!(!queue.prune_finished(now));
+    assert!(queue.prune_finished(now + COMPLETED_OPERATION_TTL + Duration::from_millis(1)));
+    assert!(queue.get(done).is_none());
+    assert!(queue.get(active).is_some());
+    assert!(!queue.dismiss(active));
+}
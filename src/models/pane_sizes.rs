@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::{preview, terminal_layout};
+
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct PaneSizes {
+    #[serde(default)]
+    pub preview_width: Option<f32>,
+    #[serde(default)]
+    pub terminal_height: Option<f32>,
+}
+
+impl PaneSizes {
+    pub fn new(preview_width: f32, terminal_height: f32) -> Self {
+        Self {
+            preview_width: Some(preview::clamp_width(preview_width)),
+            terminal_height: Some(terminal_layout::clamp_height(terminal_height)),
+        }
+    }
+
+    pub fn preview_width(&self) -> Option<f32> {
+        self.preview_width.map(preview::clamp_width)
+    }
+
+    pub fn terminal_height(&self) -> Option<f32> {
+        self.terminal_height.map(terminal_layout::clamp_height)
+    }
+}
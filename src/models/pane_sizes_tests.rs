@@ -0,0 +1,35 @@
+use super::pane_sizes::*;
+use crate::models::{preview, terminal_layout};
+
+
+#[test]
+fn test_pane_sizes_roundtrip_through_saved_state() {
+    let sizes = PaneSizes::new(412.0, 260.0);
+
+    let json = serde_json::to_string(&sizes).unwrap();
+    let restored: PaneSizes = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(restored, sizes);
+    assert_eq!(restored.preview_width(), Some(412.0));
+    assert_eq!(restored.terminal_height(), Some(260.0));
+}
+
+#[test]
+fn test_restored_pane_sizes_are_clamped() {
+    let restored: PaneSizes =
+        serde_json::from_str(r#"{"preview_width":12.0,"terminal_height":9000.0}"#).unwrap();
+
+    assert_eq!(restored.preview_width(), Some(preview::MIN_WIDTH));
+    assert_eq!(restored.terminal_height(), Some(terminal_layout::MAX_HEIGHT));
+    assert_eq!(preview::clamp_width(f32::NAN), preview::WIDTH);
+    assert_eq!(terminal_layout::clamp_height(f32::INFINITY), terminal_layout::DEFAULT_HEIGHT);
+}
+
+#[test]
+fn test_missing_pane_sizes_keep_defaults() {
+    let restored: PaneSizes = serde_json::from_str("{}").unwrap();
+
+    assert_eq!(restored, PaneSizes::default());
+    assert_eq!(restored.preview_width(), None);
+    assert_eq!(restored.terminal_height(), None);
+}
@@ -0,0 +1,105 @@
+use std::path::{Component, Path, PathBuf};
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PathFormat {
+    Posix,
+    FileUri,
+    Relative,
+    Windows,
+}
+
+impl PathFormat {
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            PathFormat::Posix => "Copy as POSIX Path",
+            PathFormat::FileUri => "Copy as File URI",
+            PathFormat::Relative => "Copy as Relative Path",
+            PathFormat::Windows => "Copy as Windows Path",
+        }
+    }
+
+
+    pub fn available() -> Vec<PathFormat> {
+        let mut formats = vec![PathFormat::Posix, PathFormat::FileUri, PathFormat::Relative];
+        if cfg!(target_os = "windows") {
+            formats.push(PathFormat::Windows);
+        }
+        formats
+    }
+}
+
+
+pub fn format_path(path: &Path, format: PathFormat, base: &Path) -> String {
+    match format {
+        PathFormat::Posix => to_posix(path),
+        PathFormat::FileUri => to_file_uri(path),
+        PathFormat::Relative => to_posix(&relative_path(path, base)),
+        PathFormat::Windows => to_posix(path).replace('/', "\\"),
+    }
+}
+
+
+pub fn format_paths(paths: &[PathBuf], format: PathFormat, base: &Path) -> String {
+    paths
+        .iter()
+        .map(|path| format_path(path, format, base))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+
+pub fn relative_path(path: &Path, base: &Path) -> PathBuf {
+    if let Ok(stripped) = path.strip_prefix(base) {
+        if stripped.as_os_str().is_empty() {
+            return PathBuf::from(".");
+        }
+        return stripped.to_path_buf();
+    }
+
+    let path_components: Vec<Component> = path.components().collect();
+    let base_components: Vec<Component> = base.components().collect();
+    let common = path_components
+        .iter()
+        .zip(base_components.iter())
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    if common == 0 {
+        return path.to_path_buf();
+    }
+
+    let mut result = PathBuf::new();
+    for _ in common..base_components.len() {
+        result.push("..");
+    }
+    for component in &path_components[common..] {
+        result.push(component.as_os_str());
+    }
+    result
+}
+
+
+fn to_posix(path: &Path) -> String {
+    path.to_string_lossy().replace('\\', "/")
+}
+
+
+fn to_file_uri(path: &Path) -> String {
+    let posix = to_posix(path);
+    let mut encoded = String::with_capacity(posix.len());
+    for byte in posix.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' | b'/' | b':' => {
+                encoded.push(byte as char)
+            }
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+
+    if encoded.starts_with('/') {
+        format!("file://{}", encoded)
+    } else {
+        format!("file:///{}", encoded)
+    }
+}
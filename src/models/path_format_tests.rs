@@ -0,0 +1,123 @@
+use super::path_format::*;
+use std::path::{Path, PathBuf};
+
+
+#[test]
+fn test_posix_path() {
+    let path = Path::new("/Users/me/Documents/report.pdf");
+    let base = Path::new("/Users/me");
+
+    assert_eq!(
+        format_path(path, PathFormat::Posix, base),
+        "/Users/me/Documents/report.pdf"
+    );
+}
+
+#[test]
+fn test_file_uri_encodes_special_characters() {
+    let path = Path::new("/Users/me/My Files/naïve #1.txt");
+    let base = Path::new("/Users/me");
+
+    assert_eq!(
+        format_path(path, PathFormat::FileUri, base),
+        "file:///Users/me/My%20Files/na%C3%AFve%20%231.txt"
+    );
+}
+
+#[test]
+fn test_file_uri_plain_path() {
+    let path = Path::new("/tmp/notes.md");
+
+    assert_eq!(
+        format_path(path, PathFormat::FileUri, Path::new("/")),
+        "file:///tmp/notes.md"
+    );
+}
+
+#[test]
+fn test_relative_path_inside_base() {
+    let path = Path::new("/Users/me/Documents/report.pdf");
+    let base = Path::new("/Users/me");
+
+    assert_eq!(
+        format_path(path, PathFormat::Relative, base),
+        "Documents/report.pdf"
+    );
+}
+
+#[test]
+fn test_relative_path_outside_base() {
+    let path = Path::new("/Users/me/Music/song.mp3");
+    let base = Path::new("/Users/me/Documents/work");
+
+    assert_eq!(
+        format_path(path, PathFormat::Relative, base),
+        "../../Music/song.mp3"
+    );
+}
+
+#[test]
+fn test_relative_path_of_base_itself() {
+    let base = Path::new("/Users/me");
+
+    assert_eq!(format_path(base, PathFormat::Relative, base), ".");
+}
+
+#[test]
+fn test_windows_path_uses_backslashes() {
+    let path = Path::new("/Users/me/Documents/report.pdf");
+
+    assert_eq!(
+        format_path(path, PathFormat::Windows, Path::new("/")),
+        "\\Users\\me\\Documents\\report.pdf"
+    );
+}
+
+#[test]
+fn test_multiple_paths_are_newline_joined() {
+    let paths = vec![
+        PathBuf::from("/Users/me/a.txt"),
+        PathBuf::from("/Users/me/sub/b.txt"),
+    ];
+    let base = Path::new("/Users/me");
+
+    assert_eq!(
+        format_paths(&paths, PathFormat::Posix, base),
+        "/Users/me/a.txt\n/Users/me/sub/b.txt"
+    );
+    assert_eq!(
+        format_paths(&paths, PathFormat::Relative, base),
+        "a.txt\nsub/b.txt"
+    );
+}
+
+#[test]
+fn test_copying_a_marked_item_copies_the_whole_selection() {
+    let a = PathBuf::from("/Users/me/a.txt");
+    let b = PathBuf::from("/Users/me/b.txt");
+    let c = PathBuf::from("/Users/me/c.txt");
+    let marked = vec![a.clone(), b.clone()];
+    let base = Path::new("/Users/me");
+
+    let selection = crate::models::context_selection(&marked, &b);
+    assert_eq!(
+        format_paths(&selection, PathFormat::Posix, base),
+        "/Users/me/a.txt\n/Users/me/b.txt"
+    );
+
+    let single = crate::models::context_selection(&marked, &c);
+    assert_eq!(format_paths(&single, PathFormat::Relative, base), "c.txt");
+}
+
+#[test]
+fn test_windows_format_only_offered_on_windows() {
+    let formats = PathFormat::available();
+
+    assert!(formats.contains(&PathFormat::Posix));
+    assert!(formats.contains(&PathFormat::FileUri));
+    assert!(formats.contains(&PathFormat::Relative));
+    assert_eq!(
+        formats.contains(&PathFormat::Windows),
+        cfg!(target_os = "windows")
+    );
+}
@@ -162,6 +162,132 @@ impl UnixPermissions {
 
 
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BitState {
+    Set,
+    Unset,
+    Varies,
+}
+
+impl BitState {
+    pub fn common(values: impl IntoIterator<Item = bool>) -> Option<Self> {
+        let mut state = None;
+        for value in values {
+            let current = if value { BitState::Set } else { BitState::Unset };
+            state = match state {
+                None => Some(current),
+                Some(previous) if previous == current => Some(previous),
+                Some(_) => return Some(BitState::Varies),
+            };
+        }
+        state
+    }
+
+    pub fn is_set(&self) -> bool {
+        *self == BitState::Set
+    }
+
+    pub fn is_varies(&self) -> bool {
+        *self == BitState::Varies
+    }
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CommonMode {
+    set: u32,
+    varies: u32,
+}
+
+impl CommonMode {
+    pub fn from_modes(modes: &[u32]) -> Option<Self> {
+        let (first, rest) = modes.split_first()?;
+        let first = first & 0o7777;
+        let varies = rest
+            .iter()
+            .fold(0, |varies, mode| varies | ((mode & 0o7777) ^ first));
+        Some(Self {
+            set: first & !varies,
+            varies,
+        })
+    }
+
+    pub fn state(&self, bit: u32) -> BitState {
+        if self.varies & bit != 0 {
+            BitState::Varies
+        } else if self.set & bit != 0 {
+            BitState::Set
+        } else {
+            BitState::Unset
+        }
+    }
+
+    pub fn set_bit(&mut self, bit: u32, value: bool) {
+        self.varies &= !bit;
+        if value {
+            self.set |= bit;
+        } else {
+            self.set &= !bit;
+        }
+    }
+
+    pub fn is_uniform(&self) -> bool {
+        self.varies == 0
+    }
+
+
+    pub fn apply_to(&self, mode: u32) -> u32 {
+        (mode & self.varies) | (self.set & !self.varies)
+    }
+
+
+    pub fn to_octal_string(&self) -> String {
+        (0..4)
+            .rev()
+            .map(|digit| {
+                let shift = digit * 3;
+                if (self.varies >> shift) & 0o7 != 0 {
+                    '?'
+                } else {
+                    char::from_digit((self.set >> shift) & 0o7, 8).unwrap_or('?')
+                }
+            })
+            .collect()
+    }
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct FileAttributeFlags {
+    pub read_only: bool,
+    pub hidden: bool,
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CommonAttributes {
+    pub read_only: BitState,
+    pub hidden: BitState,
+}
+
+impl CommonAttributes {
+    pub fn from_flags(flags: &[FileAttributeFlags]) -> Option<Self> {
+        Some(Self {
+            read_only: BitState::common(flags.iter().map(|f| f.read_only))?,
+            hidden: BitState::common(flags.iter().map(|f| f.hidden))?,
+        })
+    }
+}
+
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BulkItemResult {
+    pub path: std::path::PathBuf,
+    pub result: Result<(), String>,
+}
+
+
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum WindowsPermissionType {
     FullControl,
@@ -388,6 +514,39 @@ impl PermissionsManager {
         Ok(())
     }
 
+    pub fn read_attributes(path: &Path) -> Result<FileAttributeFlags, PermissionError> {
+        let metadata = std::fs::metadata(path)?;
+        #[cfg(windows)]
+        {
+            use std::os::windows::fs::MetadataExt;
+            let attributes = metadata.file_attributes();
+            Ok(FileAttributeFlags {
+                read_only: metadata.permissions().readonly(),
+                hidden: attributes & crate::models::WINDOWS_FILE_ATTRIBUTE_HIDDEN != 0,
+            })
+        }
+        #[cfg(not(windows))]
+        {
+            let hidden = path
+                .file_name()
+                .map(|name| name.to_string_lossy().starts_with('.'))
+                .unwrap_or(false);
+            Ok(FileAttributeFlags {
+                read_only: metadata.permissions().readonly(),
+                hidden,
+            })
+        }
+    }
+
+    #[cfg(windows)]
+    pub fn write_attributes(
+        path: &Path,
+        read_only: Option<bool>,
+        hidden: Option<bool>,
+    ) -> Result<(), PermissionError> {
+        super::permissions_windows::write_windows_attributes(path, read_only, hidden)
+    }
+
     #[cfg(windows)]
     fn read_windows_permissions(path: &Path) -> Result<FilePermissions, PermissionError> {
         let acl = super::permissions_windows::read_windows_acl(path)?;
@@ -463,8 +622,12 @@ impl PermissionsManager {
             for entry in std::fs::read_dir(path)? {
                 let entry = entry?;
                 let entry_path = entry.path();
+                let file_type = entry.file_type()?;
 
-                if entry_path.is_dir() {
+                if file_type.is_symlink() {
+                    continue;
+                }
+                if file_type.is_dir() {
                     if include_directories {
                         if std::fs::set_permissions(&entry_path, fs_permissions.clone()).is_err() {
                             failed_paths.push(entry_path.clone());
@@ -484,6 +647,99 @@ impl PermissionsManager {
     }
 
 
+    #[cfg(unix)]
+    pub fn apply_mode_to_selection(
+        paths: &[std::path::PathBuf],
+        mode: &CommonMode,
+        recursive: bool,
+    ) -> Vec<BulkItemResult> {
+        paths
+            .iter()
+            .map(|path| BulkItemResult {
+                path: path.clone(),
+                result: Self::apply_common_mode(path, mode, recursive),
+            })
+            .collect()
+    }
+
+    #[cfg(unix)]
+    fn apply_common_mode(path: &Path, mode: &CommonMode, recursive: bool) -> Result<(), String> {
+        use std::os::unix::fs::PermissionsExt;
+
+        let set_mode = |path: &Path| -> std::io::Result<()> {
+            let current = std::fs::symlink_metadata(path)?.permissions().mode();
+            let updated = mode.apply_to(current & 0o7777);
+            std::fs::set_permissions(path, std::fs::Permissions::from_mode(updated))
+        };
+
+        let file_type = std::fs::symlink_metadata(path)
+            .map_err(|e| e.to_string())?
+            .file_type();
+        if file_type.is_symlink() {
+            return Ok(());
+        }
+        if !recursive || !file_type.is_dir() {
+            return set_mode(path).map_err(|e| e.to_string());
+        }
+
+        let mut failed = 0usize;
+        let mut directories = Vec::new();
+        let mut pending = vec![path.to_path_buf()];
+        while let Some(dir) = pending.pop() {
+            match std::fs::read_dir(&dir) {
+                Ok(entries) => {
+                    for entry in entries.flatten() {
+                        let Ok(entry_type) = entry.file_type() else {
+                            failed += 1;
+                            continue;
+                        };
+                        if entry_type.is_symlink() {
+                            continue;
+                        }
+                        if entry_type.is_dir() {
+                            pending.push(entry.path());
+                        } else if set_mode(&entry.path()).is_err() {
+                            failed += 1;
+                        }
+                    }
+                }
+                Err(_) => failed += 1,
+            }
+            directories.push(dir);
+        }
+
+        let root = directories.remove(0);
+        for dir in directories.iter().rev() {
+            if set_mode(dir).is_err() {
+                failed += 1;
+            }
+        }
+        set_mode(&root).map_err(|e| e.to_string())?;
+
+        if failed == 0 {
+            Ok(())
+        } else {
+            Err(format!("{} items inside could not be changed", failed))
+        }
+    }
+
+
+    #[cfg(windows)]
+    pub fn apply_attributes_to_selection(
+        paths: &[std::path::PathBuf],
+        read_only: Option<bool>,
+        hidden: Option<bool>,
+    ) -> Vec<BulkItemResult> {
+        paths
+            .iter()
+            .map(|path| BulkItemResult {
+                path: path.clone(),
+                result: Self::write_attributes(path, read_only, hidden).map_err(|e| e.to_string()),
+            })
+            .collect()
+    }
+
+
     #[cfg(unix)]
     pub fn change_ownership(path: &Path, uid: Option<u32>, gid: Option<u32>) -> Result<(), PermissionError> {
         use std::os::unix::ffi::OsStrExt;
@@ -154,4 +154,114 @@ mod unit_tests {
         assert!(file_perms.is_writable());
         assert!(file_perms.is_executable());
     }
+
+    #[test]
+    fn test_common_mode_marks_differing_bits_as_varies() {
+        let mode = CommonMode::from_modes(&[0o644, 0o755, 0o600]).unwrap();
+
+        assert_eq!(mode.state(0o400), BitState::Set);
+        assert_eq!(mode.state(0o200), BitState::Set);
+        assert_eq!(mode.state(0o100), BitState::Varies);
+        assert_eq!(mode.state(0o040), BitState::Varies);
+        assert_eq!(mode.state(0o020), BitState::Unset);
+        assert_eq!(mode.state(0o002), BitState::Unset);
+        assert_eq!(mode.state(0o4000), BitState::Unset);
+        assert!(!mode.is_uniform());
+        assert_eq!(mode.to_octal_string(), "0???");
+
+        assert!(CommonMode::from_modes(&[]).is_none());
+        assert!(CommonMode::from_modes(&[0o755, 0o755]).unwrap().is_uniform());
+    }
+
+    #[test]
+    fn test_common_mode_keeps_untouched_varying_bits_per_item() {
+        let mut mode = CommonMode::from_modes(&[0o644, 0o755]).unwrap();
+        mode.set_bit(0o002, true);
+        mode.set_bit(0o040, false);
+
+        assert_eq!(mode.state(0o040), BitState::Unset);
+        assert_eq!(mode.apply_to(0o644), 0o606);
+        assert_eq!(mode.apply_to(0o755), 0o717);
+    }
+
+    #[test]
+    fn test_common_attributes_from_mixed_selection() {
+        let flags = [
+            FileAttributeFlags { read_only: true, hidden: false },
+            FileAttributeFlags { read_only: true, hidden: true },
+        ];
+        let common = CommonAttributes::from_flags(&flags).unwrap();
+
+        assert_eq!(common.read_only, BitState::Set);
+        assert_eq!(common.hidden, BitState::Varies);
+        assert!(CommonAttributes::from_flags(&[]).is_none());
+        assert_eq!(BitState::common([false, false]), Some(BitState::Unset));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_apply_mode_to_selection_reports_each_item() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let temp = tempfile::TempDir::new().unwrap();
+        let script = temp.path().join("run.sh");
+        let notes = temp.path().join("notes.txt");
+        let missing = temp.path().join("missing.txt");
+        std::fs::write(&script, "").unwrap();
+        std::fs::write(&notes, "").unwrap();
+        std::fs::set_permissions(&script, std::fs::Permissions::from_mode(0o755)).unwrap();
+        std::fs::set_permissions(&notes, std::fs::Permissions::from_mode(0o644)).unwrap();
+
+        let mut mode = CommonMode::from_modes(&[0o755, 0o644]).unwrap();
+        mode.set_bit(0o004, false);
+        let paths = vec![script.clone(), notes.clone(), missing.clone()];
+        let results = PermissionsManager::apply_mode_to_selection(&paths, &mode, false);
+
+        assert_eq!(results.len(), 3);
+        assert!(results[0].result.is_ok());
+        assert!(results[1].result.is_ok());
+        assert_eq!(results[2].path, missing);
+        assert!(results[2].result.is_err());
+
+        let mode_of = |path: &std::path::Path| {
+            std::fs::metadata(path).unwrap().permissions().mode() & 0o7777
+        };
+        assert_eq!(mode_of(&script), 0o751);
+        assert_eq!(mode_of(&notes), 0o640);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_recursive_mode_skips_symlinks_and_updates_directories_last() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let temp = tempfile::TempDir::new().unwrap();
+        let outside = temp.path().join("outside.txt");
+        let root = temp.path().join("root");
+        let nested = root.join("nested");
+        let inner = nested.join("inner.txt");
+        std::fs::create_dir_all(&nested).unwrap();
+        std::fs::write(&outside, "").unwrap();
+        std::fs::write(&inner, "").unwrap();
+        std::os::unix::fs::symlink(&outside, root.join("link.txt")).unwrap();
+        std::fs::set_permissions(&outside, std::fs::Permissions::from_mode(0o644)).unwrap();
+        std::fs::set_permissions(&inner, std::fs::Permissions::from_mode(0o644)).unwrap();
+
+        let mut mode = CommonMode::from_modes(&[0o755]).unwrap();
+        mode.set_bit(0o100, false);
+        mode.set_bit(0o004, false);
+        let results = PermissionsManager::apply_mode_to_selection(std::slice::from_ref(&root), &mode, true);
+
+        let mode_of = |path: &std::path::Path| {
+            std::fs::symlink_metadata(path).unwrap().permissions().mode() & 0o7777
+        };
+        assert!(results[0].result.is_ok());
+        assert_eq!(mode_of(&root), 0o651);
+        assert_eq!(mode_of(&nested), 0o651);
+        assert_eq!(mode_of(&outside), 0o644);
+
+        std::fs::set_permissions(&root, std::fs::Permissions::from_mode(0o755)).unwrap();
+        std::fs::set_permissions(&nested, std::fs::Permissions::from_mode(0o755)).unwrap();
+        assert_eq!(mode_of(&inner), 0o651);
+    }
 }
@@ -301,6 +301,53 @@ fn parse_dacl(dacl: *mut WIN_ACL, acl: &mut WindowsAcl) {
 }
 
 
+#[cfg(target_os = "windows")]
+pub fn write_windows_attributes(
+    path: &Path,
+    read_only: Option<bool>,
+    hidden: Option<bool>,
+) -> Result<(), PermissionError> {
+    use std::os::windows::ffi::OsStrExt;
+    use windows_sys::Win32::Storage::FileSystem::{
+        GetFileAttributesW, SetFileAttributesW, FILE_ATTRIBUTE_HIDDEN, FILE_ATTRIBUTE_NORMAL,
+        FILE_ATTRIBUTE_READONLY, INVALID_FILE_ATTRIBUTES,
+    };
+
+    let wide_path: Vec<u16> = path
+        .as_os_str()
+        .encode_wide()
+        .chain(std::iter::once(0))
+        .collect();
+
+    unsafe {
+        let current = GetFileAttributesW(wide_path.as_ptr());
+        if current == INVALID_FILE_ATTRIBUTES {
+            return Err(PermissionError::IoError(std::io::Error::last_os_error()));
+        }
+
+        let mut updated = current;
+        for (flag, value) in [(FILE_ATTRIBUTE_READONLY, read_only), (FILE_ATTRIBUTE_HIDDEN, hidden)] {
+            match value {
+                Some(true) => updated |= flag,
+                Some(false) => updated &= !flag,
+                None => {}
+            }
+        }
+        if updated == current {
+            return Ok(());
+        }
+        if updated == 0 {
+            updated = FILE_ATTRIBUTE_NORMAL;
+        }
+
+        if SetFileAttributesW(wide_path.as_ptr(), updated) == 0 {
+            return Err(PermissionError::IoError(std::io::Error::last_os_error()));
+        }
+    }
+    Ok(())
+}
+
+
 #[cfg(target_os = "windows")]
 #[repr(C)]
 struct AceHeader {
@@ -0,0 +1,107 @@
+use std::sync::RwLock;
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::PreviewKind;
+
+const MB: u64 = 1024 * 1024;
+
+static PREVIEW_LIMITS: RwLock<PreviewLimits> = RwLock::new(PreviewLimits::DEFAULT);
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PreviewContentType {
+    Text,
+    Image,
+    Pdf,
+}
+
+impl PreviewContentType {
+    pub fn label(&self) -> &'static str {
+        match self {
+            PreviewContentType::Text => "Text",
+            PreviewContentType::Image => "Image",
+            PreviewContentType::Pdf => "PDF",
+        }
+    }
+
+    pub fn for_kind(kind: PreviewKind) -> Self {
+        match kind {
+            PreviewKind::Thumbnail(_) | PreviewKind::FullImage(_) | PreviewKind::Svg(_) => {
+                PreviewContentType::Image
+            }
+            PreviewKind::PdfPage(_) => PreviewContentType::Pdf,
+            PreviewKind::Text => PreviewContentType::Text,
+        }
+    }
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PreviewLimits {
+    pub text_bytes: u64,
+    pub image_bytes: u64,
+    pub pdf_bytes: u64,
+}
+
+impl PreviewLimits {
+    pub const DEFAULT: Self = Self {
+        text_bytes: 10 * MB,
+        image_bytes: 64 * MB,
+        pdf_bytes: 200 * MB,
+    };
+
+    pub fn limit_for(&self, content_type: PreviewContentType) -> u64 {
+        match content_type {
+            PreviewContentType::Text => self.text_bytes,
+            PreviewContentType::Image => self.image_bytes,
+            PreviewContentType::Pdf => self.pdf_bytes,
+        }
+    }
+
+    pub fn check(&self, content_type: PreviewContentType, size: u64) -> PreviewDecision {
+        let limit = self.limit_for(content_type);
+        if size > limit {
+            PreviewDecision::TooLarge {
+                content_type,
+                size,
+                limit,
+            }
+        } else {
+            PreviewDecision::Render
+        }
+    }
+}
+
+impl Default for PreviewLimits {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PreviewDecision {
+    Render,
+    TooLarge {
+        content_type: PreviewContentType,
+        size: u64,
+        limit: u64,
+    },
+}
+
+
+pub fn set_preview_limits(limits: PreviewLimits) {
+    if let Ok(mut current) = PREVIEW_LIMITS.write() {
+        *current = limits;
+    }
+}
+
+
+pub fn preview_limits() -> PreviewLimits {
+    PREVIEW_LIMITS
+        .read()
+        .map(|limits| *limits)
+        .unwrap_or_default()
+}
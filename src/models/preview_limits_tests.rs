@@ -0,0 +1,76 @@
+use super::preview_limits::*;
+use super::preview_queue::*;
+use std::sync::atomic::AtomicBool;
+use tempfile::TempDir;
+
+const MB: u64 = 1024 * 1024;
+
+
+fn tiny_limits() -> PreviewLimits {
+    PreviewLimits {
+        text_bytes: 1024,
+        image_bytes: 1024,
+        pdf_bytes: 1024,
+    }
+}
+
+#[test]
+fn test_thresholds_apply_per_content_type() {
+    let limits = PreviewLimits::default();
+
+    assert_eq!(limits.check(PreviewContentType::Image, 20 * MB), PreviewDecision::Render);
+    assert_eq!(
+        limits.check(PreviewContentType::Text, 20 * MB),
+        PreviewDecision::TooLarge {
+            content_type: PreviewContentType::Text,
+            size: 20 * MB,
+            limit: limits.text_bytes,
+        }
+    );
+    assert_eq!(
+        limits.check(PreviewContentType::Pdf, limits.pdf_bytes),
+        PreviewDecision::Render
+    );
+    assert_eq!(
+        PreviewContentType::for_kind(PreviewKind::Thumbnail(THUMBNAIL_SIZE)),
+        PreviewContentType::Image
+    );
+}
+
+#[test]
+fn test_oversized_files_fall_back_without_decoding() {
+    let dir = TempDir::new().unwrap();
+    let image_path = dir.path().join("huge.png");
+    let text_path = dir.path().join("huge.log");
+    std::fs::write(&image_path, vec![0xAB; 4096]).unwrap();
+    std::fs::write(&text_path, "x".repeat(4096)).unwrap();
+    let cancel = AtomicBool::new(false);
+
+    let image = PreviewKey::for_path(&image_path, PreviewKind::Thumbnail(THUMBNAIL_SIZE));
+    assert!(matches!(
+        generate_preview_with_limits(&image, &cancel, &tiny_limits()),
+        Err(PreviewError::TooLarge {
+            size: 4096,
+            limit: 1024
+        })
+    ));
+    assert!(matches!(
+        generate_preview_with_limits(&image, &cancel, &PreviewLimits::default()),
+        Err(PreviewError::Image(_))
+    ));
+
+    let text = PreviewKey::for_path(&text_path, PreviewKind::Text);
+    assert!(matches!(
+        generate_preview_with_limits(&text, &cancel, &tiny_limits()),
+        Err(PreviewError::TooLarge { .. })
+    ));
+}
+
+#[test]
+fn test_missing_limits_fall_back_to_defaults() {
+    let limits: PreviewLimits = serde_json::from_str(r#"{"text_bytes": 2048}"#).unwrap();
+
+    assert_eq!(limits.text_bytes, 2048);
+    assert_eq!(limits.image_bytes, PreviewLimits::DEFAULT.image_bytes);
+    assert_eq!(limits.pdf_bytes, PreviewLimits::DEFAULT.pdf_bytes);
+}
@@ -0,0 +1,55 @@
+use serde::{Deserialize, Serialize};
+
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PreviewVisibility {
+    #[default]
+    Auto,
+    Shown,
+    Hidden,
+}
+
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PreviewPaneState {
+    visibility: PreviewVisibility,
+    file_selected: bool,
+}
+
+impl PreviewPaneState {
+    pub fn new(visibility: PreviewVisibility) -> Self {
+        Self {
+            visibility,
+            file_selected: false,
+        }
+    }
+
+    pub fn visibility(&self) -> PreviewVisibility {
+        self.visibility
+    }
+
+    pub fn is_visible(&self) -> bool {
+        match self.visibility {
+            PreviewVisibility::Auto => self.file_selected,
+            PreviewVisibility::Shown => true,
+            PreviewVisibility::Hidden => false,
+        }
+    }
+
+
+    pub fn update_selection(&mut self, file_selected: bool) -> Option<bool> {
+        let changed = self.file_selected != file_selected;
+        self.file_selected = file_selected;
+        (changed && self.visibility == PreviewVisibility::Auto).then_some(file_selected)
+    }
+
+
+    pub fn toggle(&mut self, currently_visible: bool) -> bool {
+        self.visibility = if currently_visible {
+            PreviewVisibility::Hidden
+        } else {
+            PreviewVisibility::Shown
+        };
+        !currently_visible
+    }
+}
@@ -0,0 +1,37 @@
+use super::preview_pane::*;
+
+
+#[test]
+fn test_auto_follows_file_selection() {
+    let mut state = PreviewPaneState::default();
+    assert!(!state.is_visible());
+
+    assert_eq!(state.update_selection(true), Some(true));
+    assert_eq!(state.update_selection(true), None);
+    assert!(state.is_visible());
+    assert_eq!(state.update_selection(false), Some(false));
+}
+
+#[test]
+fn test_toggle_overrides_selection() {
+    let mut state = PreviewPaneState::default();
+    state.update_selection(true);
+
+    assert!(!state.toggle(true));
+    assert_eq!(state.visibility(), PreviewVisibility::Hidden);
+    assert_eq!(state.update_selection(false), None);
+    assert_eq!(state.update_selection(true), None);
+    assert!(!state.is_visible());
+
+    assert!(state.toggle(false));
+    assert_eq!(state.visibility(), PreviewVisibility::Shown);
+    assert_eq!(state.update_selection(false), None);
+    assert!(state.is_visible());
+}
+
+#[test]
+fn test_visibility_round_trips_through_json() {
+    let json = serde_json::to_string(&PreviewVisibility::Hidden).unwrap();
+    let restored: PreviewVisibility = serde_json::from_str(&json).unwrap();
+    assert_eq!(restored, PreviewVisibility::Hidden);
+}
@@ -0,0 +1,551 @@
+use std::collections::{HashMap, HashSet};
+use std::fs::{self, File};
+use std::io::{self, Cursor, Read};
+use std::path::{Path, PathBuf};
+#[cfg(unix)]
+use std::process::Command;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::SystemTime;
+
+use image::imageops::FilterType;
+use image::ImageFormat;
+use thiserror::Error;
+
+use crate::models::{preview_limits, PreviewContentType, PreviewDecision, PreviewLimits};
+
+
+pub const DEFAULT_PREVIEW_CONCURRENCY: usize = 3;
+
+
+pub const THUMBNAIL_SIZE: u32 = 256;
+
+
+pub const DOCUMENT_PAGE_SIZE: u32 = 1024;
+
+
+pub const FULL_IMAGE_SIZE: u32 = 2048;
+
+
+pub const SVG_PREVIEW_SIZE: u32 = 512;
+
+const TEXT_PREVIEW_BYTES: u64 = 64 * 1024;
+
+const THUMBNAIL_EXTENSIONS: &[&str] = &[
+    "png", "jpg", "jpeg", "gif", "bmp", "webp", "ico", "tif", "tiff",
+];
+
+
+#[derive(Debug, Error)]
+pub enum PreviewError {
+    #[error("No preview available for {0}")]
+    Unsupported(String),
+
+    #[error("Preview was cancelled")]
+    Cancelled,
+
+    #[error("Image error: {0}")]
+    Image(#[from] image::ImageError),
+
+    #[error("IO error: {0}")]
+    Io(#[from] io::Error),
+
+    #[error("Renderer failed: {0}")]
+    Renderer(String),
+
+    #[error("Invalid SVG: {0}")]
+    InvalidSvg(String),
+
+    #[error("File is too large to preview ({size} bytes, limit {limit} bytes)")]
+    TooLarge { size: u64, limit: u64 },
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PreviewKind {
+    Thumbnail(u32),
+    FullImage(u32),
+    PdfPage(u32),
+    Svg(u32),
+    Text,
+}
+
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PreviewKey {
+    pub path: PathBuf,
+    pub modified: Option<SystemTime>,
+    pub kind: PreviewKind,
+}
+
+impl PreviewKey {
+    pub fn new(path: PathBuf, modified: Option<SystemTime>, kind: PreviewKind) -> Self {
+        Self {
+            path,
+            modified,
+            kind,
+        }
+    }
+
+    pub fn for_path(path: &Path, kind: PreviewKind) -> Self {
+        let modified = fs::metadata(path).and_then(|m| m.modified()).ok();
+        Self::new(path.to_path_buf(), modified, kind)
+    }
+
+
+    pub fn is_superseded_by(&self, other: &PreviewKey) -> bool {
+        self.path == other.path && self.kind == other.kind && self.modified != other.modified
+    }
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PreviewPriority {
+    Prefetch,
+    Visible,
+    Selected,
+}
+
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum PreviewOutput {
+    Png {
+        width: u32,
+        height: u32,
+        bytes: Vec<u8>,
+    },
+    Text {
+        content: String,
+        truncated: bool,
+    },
+}
+
+
+#[derive(Debug, Clone)]
+pub struct PreviewJob {
+    pub id: u64,
+    pub key: PreviewKey,
+    pub priority: PreviewPriority,
+    pub cancel: Arc<AtomicBool>,
+}
+
+
+#[derive(Debug, Clone)]
+struct PendingRequest {
+    key: PreviewKey,
+    priority: PreviewPriority,
+    seq: u64,
+}
+
+
+#[derive(Debug)]
+pub struct PreviewQueue {
+    pending: Vec<PendingRequest>,
+    in_flight: HashMap<PreviewKey, (u64, PreviewPriority, Arc<AtomicBool>)>,
+    max_concurrency: usize,
+    next_seq: u64,
+}
+
+impl Default for PreviewQueue {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PreviewQueue {
+    pub fn new() -> Self {
+        Self::with_concurrency(DEFAULT_PREVIEW_CONCURRENCY)
+    }
+
+    pub fn with_concurrency(max_concurrency: usize) -> Self {
+        Self {
+            pending: Vec::new(),
+            in_flight: HashMap::new(),
+            max_concurrency: max_concurrency.max(1),
+            next_seq: 0,
+        }
+    }
+
+    pub fn max_concurrency(&self) -> usize {
+        self.max_concurrency
+    }
+
+    pub fn pending_len(&self) -> usize {
+        self.pending.len()
+    }
+
+    pub fn in_flight_len(&self) -> usize {
+        self.in_flight.len()
+    }
+
+    pub fn is_queued(&self, key: &PreviewKey) -> bool {
+        self.in_flight.contains_key(key) || self.pending.iter().any(|r| &r.key == key)
+    }
+
+    pub fn queued_priority(&self, key: &PreviewKey) -> Option<PreviewPriority> {
+        self.in_flight
+            .get(key)
+            .map(|(_, priority, _)| *priority)
+            .or_else(|| {
+                self.pending
+                    .iter()
+                    .find(|r| &r.key == key)
+                    .map(|r| r.priority)
+            })
+    }
+
+    pub fn can_start(&self) -> bool {
+        !self.pending.is_empty() && self.in_flight.len() < self.max_concurrency
+    }
+
+
+    pub fn request(&mut self, key: PreviewKey, priority: PreviewPriority) -> bool {
+        self.cancel_where(|queued, _| queued.is_superseded_by(&key));
+
+        if let Some((_, queued, _)) = self.in_flight.get_mut(&key) {
+            *queued = (*queued).max(priority);
+            return false;
+        }
+        if let Some(existing) = self.pending.iter_mut().find(|r| r.key == key) {
+            existing.priority = existing.priority.max(priority);
+            return false;
+        }
+
+        let seq = self.next_seq;
+        self.next_seq += 1;
+        self.pending.push(PendingRequest { key, priority, seq });
+        true
+    }
+
+    pub fn cancel(&mut self, key: &PreviewKey) -> bool {
+        self.cancel_where(|queued, _| queued == key) > 0
+    }
+
+
+    pub fn retain_paths(&mut self, keep: &HashSet<PathBuf>) -> usize {
+        self.cancel_where(|queued, priority| {
+            priority < PreviewPriority::Selected && !keep.contains(&queued.path)
+        })
+    }
+
+
+    pub fn next_job(&mut self) -> Option<PreviewJob> {
+        if self.in_flight.len() >= self.max_concurrency {
+            return None;
+        }
+
+        let index = self
+            .pending
+            .iter()
+            .enumerate()
+            .max_by(|(_, a), (_, b)| a.priority.cmp(&b.priority).then(b.seq.cmp(&a.seq)))
+            .map(|(index, _)| index)?;
+        let request = self.pending.remove(index);
+
+        let id = self.next_seq;
+        self.next_seq += 1;
+        let cancel = Arc::new(AtomicBool::new(false));
+        self.in_flight
+            .insert(request.key.clone(), (id, request.priority, cancel.clone()));
+
+        Some(PreviewJob {
+            id,
+            key: request.key,
+            priority: request.priority,
+            cancel,
+        })
+    }
+
+
+    pub fn finish(&mut self, job: &PreviewJob) -> bool {
+        match self.in_flight.get(&job.key) {
+            Some((id, _, _)) if *id == job.id => {
+                self.in_flight.remove(&job.key);
+                !job.cancel.load(Ordering::Relaxed)
+            }
+            _ => false,
+        }
+    }
+
+    fn cancel_where(&mut self, matches: impl Fn(&PreviewKey, PreviewPriority) -> bool) -> usize {
+        let before = self.pending.len();
+        self.pending.retain(|r| !matches(&r.key, r.priority));
+        let mut cancelled = before - self.pending.len();
+
+        self.in_flight.retain(|key, (_, priority, flag)| {
+            if matches(key, *priority) {
+                flag.store(true, Ordering::Relaxed);
+                cancelled += 1;
+                false
+            } else {
+                true
+            }
+        });
+        cancelled
+    }
+}
+
+
+#[derive(Debug, Default)]
+pub struct PreviewSelection {
+    keys: Vec<PreviewKey>,
+}
+
+impl PreviewSelection {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn keys(&self) -> &[PreviewKey] {
+        &self.keys
+    }
+
+
+    pub fn select(&mut self, keys: Vec<PreviewKey>) -> Vec<PreviewKey> {
+        if keys == self.keys {
+            return Vec::new();
+        }
+        let previous = std::mem::replace(&mut self.keys, keys);
+        previous
+            .into_iter()
+            .filter(|key| !self.keys.contains(key))
+            .collect()
+    }
+}
+
+
+pub fn progressive_image_keys(path: &Path, modified: Option<SystemTime>) -> Vec<PreviewKey> {
+    vec![
+        PreviewKey::new(path.to_path_buf(), modified, PreviewKind::Thumbnail(THUMBNAIL_SIZE)),
+        PreviewKey::new(path.to_path_buf(), modified, PreviewKind::FullImage(FULL_IMAGE_SIZE)),
+    ]
+}
+
+
+pub fn supports_thumbnail(path: &Path) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .map(|ext| ext.to_lowercase())
+        .is_some_and(|ext| THUMBNAIL_EXTENSIONS.contains(&ext.as_str()))
+}
+
+
+pub fn is_svg(path: &Path) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("svg"))
+}
+
+
+pub fn rasterize_svg(data: &[u8], size: u32) -> Result<PreviewOutput, PreviewError> {
+    let tree = resvg::usvg::Tree::from_data(data, &resvg::usvg::Options::default())
+        .map_err(|e| PreviewError::InvalidSvg(e.to_string()))?;
+
+    let natural = tree.size();
+    let scale = size as f32 / natural.width().max(natural.height());
+    let width = ((natural.width() * scale).round() as u32).max(1);
+    let height = ((natural.height() * scale).round() as u32).max(1);
+    let mut pixmap = resvg::tiny_skia::Pixmap::new(width, height)
+        .ok_or_else(|| PreviewError::InvalidSvg(format!("cannot render at {}×{}", width, height)))?;
+    resvg::render(
+        &tree,
+        resvg::tiny_skia::Transform::from_scale(scale, scale),
+        &mut pixmap.as_mut(),
+    );
+
+    let bytes = pixmap
+        .encode_png()
+        .map_err(|e| PreviewError::Renderer(e.to_string()))?;
+    Ok(PreviewOutput::Png {
+        width,
+        height,
+        bytes,
+    })
+}
+
+
+pub fn generate_preview(
+    key: &PreviewKey,
+    cancel: &AtomicBool,
+) -> Result<PreviewOutput, PreviewError> {
+    generate_preview_with_limits(key, cancel, &preview_limits())
+}
+
+
+pub fn generate_preview_with_limits(
+    key: &PreviewKey,
+    cancel: &AtomicBool,
+    limits: &PreviewLimits,
+) -> Result<PreviewOutput, PreviewError> {
+    if cancel.load(Ordering::Relaxed) {
+        return Err(PreviewError::Cancelled);
+    }
+
+    let size = fs::metadata(&key.path)?.len();
+    if let PreviewDecision::TooLarge { size, limit, .. } =
+        limits.check(PreviewContentType::for_kind(key.kind), size)
+    {
+        return Err(PreviewError::TooLarge { size, limit });
+    }
+
+    match key.kind {
+        PreviewKind::Thumbnail(size) => {
+            let image = image::open(&key.path)?;
+            if cancel.load(Ordering::Relaxed) {
+                return Err(PreviewError::Cancelled);
+            }
+            let thumbnail = image.thumbnail(size, size);
+            let mut bytes = Vec::new();
+            thumbnail.write_to(&mut Cursor::new(&mut bytes), ImageFormat::Png)?;
+            Ok(PreviewOutput::Png {
+                width: thumbnail.width(),
+                height: thumbnail.height(),
+                bytes,
+            })
+        }
+        PreviewKind::FullImage(size) => {
+            let image = image::open(&key.path)?;
+            if cancel.load(Ordering::Relaxed) {
+                return Err(PreviewError::Cancelled);
+            }
+            let image = if image.width() > size || image.height() > size {
+                image.resize(size, size, FilterType::Triangle)
+            } else {
+                image
+            };
+            if cancel.load(Ordering::Relaxed) {
+                return Err(PreviewError::Cancelled);
+            }
+            let mut bytes = Vec::new();
+            image.write_to(&mut Cursor::new(&mut bytes), ImageFormat::Png)?;
+            Ok(PreviewOutput::Png {
+                width: image.width(),
+                height: image.height(),
+                bytes,
+            })
+        }
+        PreviewKind::PdfPage(page) => {
+            let bytes = render_pdf_page(&key.path, page.max(1), DOCUMENT_PAGE_SIZE)?;
+            if cancel.load(Ordering::Relaxed) {
+                return Err(PreviewError::Cancelled);
+            }
+            let page_image = image::load_from_memory_with_format(&bytes, ImageFormat::Png)?;
+            let (width, height) = (page_image.width(), page_image.height());
+            Ok(PreviewOutput::Png {
+                width,
+                height,
+                bytes,
+            })
+        }
+        PreviewKind::Svg(size) => {
+            let data = fs::read(&key.path)?;
+            if cancel.load(Ordering::Relaxed) {
+                return Err(PreviewError::Cancelled);
+            }
+            rasterize_svg(&data, size)
+        }
+        PreviewKind::Text => {
+            let total = size;
+            let mut buffer = Vec::new();
+            File::open(&key.path)?
+                .take(TEXT_PREVIEW_BYTES)
+                .read_to_end(&mut buffer)?;
+            Ok(PreviewOutput::Text {
+                content: String::from_utf8_lossy(&buffer).into_owned(),
+                truncated: total > TEXT_PREVIEW_BYTES,
+            })
+        }
+    }
+}
+
+
+fn render_pdf_page(path: &Path, page: u32, size: u32) -> Result<Vec<u8>, PreviewError> {
+    static NEXT_RENDER: AtomicU64 = AtomicU64::new(0);
+
+    let output_dir = std::env::temp_dir().join(format!(
+        "nexus-preview-{}-{}",
+        std::process::id(),
+        NEXT_RENDER.fetch_add(1, Ordering::Relaxed)
+    ));
+    fs::create_dir_all(&output_dir)?;
+    let result = run_pdf_renderer(path, page, size, &output_dir);
+    let _ = fs::remove_dir_all(&output_dir);
+    result
+}
+
+
+#[cfg(target_os = "macos")]
+fn run_pdf_renderer(
+    path: &Path,
+    page: u32,
+    size: u32,
+    output_dir: &Path,
+) -> Result<Vec<u8>, PreviewError> {
+    if page > 1 {
+        return Err(PreviewError::Unsupported(format!("page {}", page)));
+    }
+
+    let output = Command::new("qlmanage")
+        .arg("-t")
+        .arg("-s")
+        .arg(size.to_string())
+        .arg("-o")
+        .arg(output_dir)
+        .arg(path)
+        .output()?;
+    if !output.status.success() {
+        return Err(PreviewError::Renderer(
+            String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        ));
+    }
+
+    let file_name = path
+        .file_name()
+        .map(|name| format!("{}.png", name.to_string_lossy()))
+        .unwrap_or_default();
+    Ok(fs::read(output_dir.join(file_name))?)
+}
+
+
+#[cfg(all(unix, not(target_os = "macos")))]
+fn run_pdf_renderer(
+    path: &Path,
+    page: u32,
+    size: u32,
+    output_dir: &Path,
+) -> Result<Vec<u8>, PreviewError> {
+    let prefix = output_dir.join("page");
+    let output = Command::new("pdftoppm")
+        .args(["-png", "-singlefile"])
+        .arg("-f")
+        .arg(page.to_string())
+        .arg("-l")
+        .arg(page.to_string())
+        .arg("-scale-to")
+        .arg(size.to_string())
+        .arg(path)
+        .arg(&prefix)
+        .output()
+        .map_err(|e| match e.kind() {
+            io::ErrorKind::NotFound => PreviewError::Unsupported("PDF (pdftoppm not found)".into()),
+            _ => PreviewError::Io(e),
+        })?;
+    if !output.status.success() {
+        return Err(PreviewError::Renderer(
+            String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        ));
+    }
+
+    Ok(fs::read(prefix.with_extension("png"))?)
+}
+
+
+#[cfg(not(unix))]
+fn run_pdf_renderer(
+    _path: &Path,
+    _page: u32,
+    _size: u32,
+    _output_dir: &Path,
+) -> Result<Vec<u8>, PreviewError> {
+    Err(PreviewError::Unsupported("PDF".to_string()))
+}
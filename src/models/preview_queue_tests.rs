@@ -0,0 +1,247 @@
+use super::preview_queue::*;
+use std::collections::HashSet;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, SystemTime};
+use tempfile::TempDir;
+
+
+fn key(name: &str) -> PreviewKey {
+    PreviewKey::new(
+        PathBuf::from("/photos").join(name),
+        Some(SystemTime::UNIX_EPOCH),
+        PreviewKind::Thumbnail(THUMBNAIL_SIZE),
+    )
+}
+
+fn drain(queue: &mut PreviewQueue) -> Vec<PreviewKey> {
+    let mut order = Vec::new();
+    while let Some(job) = queue.next_job() {
+        assert!(queue.finish(&job));
+        order.push(job.key);
+    }
+    order
+}
+
+#[test]
+fn test_selected_runs_before_visible_before_prefetch() {
+    let mut queue = PreviewQueue::with_concurrency(1);
+    queue.request(key("prefetch.jpg"), PreviewPriority::Prefetch);
+    queue.request(key("visible-a.jpg"), PreviewPriority::Visible);
+    queue.request(key("selected.jpg"), PreviewPriority::Selected);
+    queue.request(key("visible-b.jpg"), PreviewPriority::Visible);
+
+    assert_eq!(
+        drain(&mut queue),
+        vec![
+            key("selected.jpg"),
+            key("visible-a.jpg"),
+            key("visible-b.jpg"),
+            key("prefetch.jpg"),
+        ]
+    );
+}
+
+#[test]
+fn test_rerequest_promotes_pending_item() {
+    let mut queue = PreviewQueue::with_concurrency(1);
+    queue.request(key("a.jpg"), PreviewPriority::Visible);
+    queue.request(key("b.jpg"), PreviewPriority::Visible);
+
+    assert!(!queue.request(key("b.jpg"), PreviewPriority::Selected));
+    assert_eq!(queue.pending_len(), 2);
+    assert_eq!(queue.queued_priority(&key("b.jpg")), Some(PreviewPriority::Selected));
+    assert_eq!(drain(&mut queue), vec![key("b.jpg"), key("a.jpg")]);
+}
+
+#[test]
+fn test_duplicate_requests_are_merged() {
+    let mut queue = PreviewQueue::new();
+    assert!(queue.request(key("a.jpg"), PreviewPriority::Visible));
+    assert!(!queue.request(key("a.jpg"), PreviewPriority::Visible));
+    assert_eq!(queue.pending_len(), 1);
+
+    let job = queue.next_job().unwrap();
+    assert!(!queue.request(key("a.jpg"), PreviewPriority::Selected));
+    assert_eq!(queue.pending_len(), 0);
+    assert_eq!(queue.in_flight_len(), 1);
+    assert!(queue.finish(&job));
+}
+
+#[test]
+fn test_concurrency_is_bounded() {
+    let mut queue = PreviewQueue::with_concurrency(2);
+    for name in ["a.jpg", "b.jpg", "c.jpg"] {
+        queue.request(key(name), PreviewPriority::Visible);
+    }
+
+    let first = queue.next_job().unwrap();
+    let _second = queue.next_job().unwrap();
+    assert!(queue.next_job().is_none());
+    assert!(!queue.can_start());
+
+    assert!(queue.finish(&first));
+    assert!(queue.next_job().is_some());
+}
+
+#[test]
+fn test_newer_mtime_supersedes_queued_request() {
+    let mut queue = PreviewQueue::with_concurrency(1);
+    let stale = key("a.jpg");
+    let fresh = PreviewKey {
+        modified: Some(SystemTime::UNIX_EPOCH + Duration::from_secs(60)),
+        ..stale.clone()
+    };
+
+    queue.request(stale.clone(), PreviewPriority::Visible);
+    let job = queue.next_job().unwrap();
+    assert!(queue.request(fresh.clone(), PreviewPriority::Visible));
+
+    assert!(job.cancel.load(Ordering::Relaxed));
+    assert!(!queue.finish(&job));
+    assert!(!queue.is_queued(&stale));
+    assert_eq!(drain(&mut queue), vec![fresh]);
+}
+
+#[test]
+fn test_retain_paths_cancels_items_out_of_view() {
+    let mut queue = PreviewQueue::with_concurrency(1);
+    queue.request(key("gone.jpg"), PreviewPriority::Visible);
+    queue.request(key("kept.jpg"), PreviewPriority::Visible);
+    queue.request(key("selected.jpg"), PreviewPriority::Selected);
+    queue.request(key("scrolled.jpg"), PreviewPriority::Prefetch);
+    let running = queue.next_job().unwrap();
+    assert_eq!(running.key, key("selected.jpg"));
+
+    let keep: HashSet<PathBuf> = [key("kept.jpg").path].into_iter().collect();
+    assert_eq!(queue.retain_paths(&keep), 2);
+
+    assert!(queue.finish(&running));
+    assert_eq!(drain(&mut queue), vec![key("kept.jpg")]);
+}
+
+#[test]
+fn test_explicit_cancel_stops_in_flight_job() {
+    let mut queue = PreviewQueue::new();
+    queue.request(key("a.jpg"), PreviewPriority::Selected);
+    let job = queue.next_job().unwrap();
+
+    assert!(queue.cancel(&key("a.jpg")));
+    assert!(!queue.cancel(&key("a.jpg")));
+    assert!(!queue.finish(&job));
+    assert!(matches!(
+        generate_preview(&job.key, &job.cancel),
+        Err(PreviewError::Cancelled)
+    ));
+}
+
+#[test]
+fn test_generate_thumbnail_and_text_previews() {
+    let dir = TempDir::new().unwrap();
+    let image_path = dir.path().join("large.png");
+    image::RgbaImage::new(600, 300).save(&image_path).unwrap();
+    let text_path = dir.path().join("notes.txt");
+    std::fs::write(&text_path, "hello preview").unwrap();
+    let cancel = AtomicBool::new(false);
+
+    let thumbnail = PreviewKey::for_path(&image_path, PreviewKind::Thumbnail(128));
+    match generate_preview(&thumbnail, &cancel).unwrap() {
+        PreviewOutput::Png { width, height, .. } => assert_eq!((width, height), (128, 64)),
+        other => panic!("expected image preview, got {:?}", other),
+    }
+
+    let text = PreviewKey::for_path(&text_path, PreviewKind::Text);
+    assert_eq!(
+        generate_preview(&text, &cancel).unwrap(),
+        PreviewOutput::Text {
+            content: "hello preview".to_string(),
+            truncated: false,
+        }
+    );
+}
+
+#[test]
+fn test_superseded_selection_cancels_in_flight_decodes() {
+    let mut queue = PreviewQueue::new();
+    let mut selection = PreviewSelection::new();
+    let modified = Some(SystemTime::UNIX_EPOCH);
+    let first = progressive_image_keys(&PathBuf::from("/photos/first.jpg"), modified);
+    let second = progressive_image_keys(&PathBuf::from("/photos/second.jpg"), modified);
+    assert_eq!(first[0].kind, PreviewKind::Thumbnail(THUMBNAIL_SIZE));
+    assert_eq!(first[1].kind, PreviewKind::FullImage(FULL_IMAGE_SIZE));
+
+    assert!(selection.select(first.clone()).is_empty());
+    for key in selection.keys() {
+        queue.request(key.clone(), PreviewPriority::Selected);
+    }
+    let draft = queue.next_job().unwrap();
+    let full = queue.next_job().unwrap();
+    assert_eq!((&draft.key, &full.key), (&first[0], &first[1]));
+    assert!(selection.select(first.clone()).is_empty());
+
+    let superseded = selection.select(second.clone());
+    assert_eq!(superseded, first);
+    for key in &superseded {
+        assert!(queue.cancel(key));
+    }
+    for key in selection.keys() {
+        queue.request(key.clone(), PreviewPriority::Selected);
+    }
+
+    for job in [&draft, &full] {
+        assert!(job.cancel.load(Ordering::Relaxed));
+        assert!(!queue.finish(job));
+        assert!(matches!(
+            generate_preview(&job.key, &job.cancel),
+            Err(PreviewError::Cancelled)
+        ));
+    }
+    assert_eq!(drain(&mut queue), second);
+}
+
+#[test]
+fn test_full_image_is_bounded_by_target_size() {
+    let dir = TempDir::new().unwrap();
+    let image_path = dir.path().join("wide.png");
+    image::RgbaImage::new(600, 300).save(&image_path).unwrap();
+    let cancel = AtomicBool::new(false);
+
+    let scaled = PreviewKey::for_path(&image_path, PreviewKind::FullImage(200));
+    match generate_preview(&scaled, &cancel).unwrap() {
+        PreviewOutput::Png { width, height, .. } => assert_eq!((width, height), (200, 100)),
+        other => panic!("expected image preview, got {:?}", other),
+    }
+
+    let native = PreviewKey::for_path(&image_path, PreviewKind::FullImage(FULL_IMAGE_SIZE));
+    match generate_preview(&native, &cancel).unwrap() {
+        PreviewOutput::Png { width, height, .. } => assert_eq!((width, height), (600, 300)),
+        other => panic!("expected image preview, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_svg_rasterizes_to_non_empty_bitmap() {
+    let dir = TempDir::new().unwrap();
+    let svg_path = dir.path().join("badge.svg");
+    std::fs::write(
+        &svg_path,
+        r##"<svg xmlns="http://www.w3.org/2000/svg" width="40" height="20"><rect width="40" height="20" fill="#3178c6"/></svg>"##,
+    )
+    .unwrap();
+    let cancel = AtomicBool::new(false);
+
+    let key = PreviewKey::for_path(&svg_path, PreviewKind::Svg(SVG_PREVIEW_SIZE));
+    match generate_preview(&key, &cancel).unwrap() {
+        PreviewOutput::Png { width, height, bytes } => {
+            assert_eq!((width, height), (SVG_PREVIEW_SIZE, SVG_PREVIEW_SIZE / 2));
+            let bitmap = image::load_from_memory(&bytes).unwrap().to_rgba8();
+            assert!(bitmap.pixels().any(|pixel| pixel[3] > 0));
+        }
+        other => panic!("expected image preview, got {:?}", other),
+    }
+
+    assert!(matches!(
+        rasterize_svg(b"<svg><unclosed", SVG_PREVIEW_SIZE),
+        Err(PreviewError::InvalidSvg(_))
+    ));
+}
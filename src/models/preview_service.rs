@@ -0,0 +1,163 @@
+use std::collections::HashSet;
+use std::num::NonZeroUsize;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use gpui::{App, Global, Image, ImageFormat, SharedString};
+use lru::LruCache;
+
+use crate::models::{
+    generate_preview, PreviewError, PreviewJob, PreviewKey, PreviewKind, PreviewOutput,
+    PreviewPriority, PreviewQueue,
+};
+
+
+const MAX_CACHED_PREVIEWS: usize = 512;
+
+
+const MAX_CACHED_FULL_IMAGES: usize = 8;
+
+
+#[derive(Clone)]
+pub enum PreviewResult {
+    Image(Arc<Image>),
+    Text {
+        content: SharedString,
+        truncated: bool,
+    },
+    Failed(SharedString),
+}
+
+
+pub struct PreviewService {
+    queue: PreviewQueue,
+    results: LruCache<PreviewKey, PreviewResult>,
+    full_images: LruCache<PreviewKey, PreviewResult>,
+}
+
+impl Default for PreviewService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Global for PreviewService {}
+
+impl PreviewService {
+    pub fn new() -> Self {
+        Self {
+            queue: PreviewQueue::new(),
+            results: LruCache::new(NonZeroUsize::new(MAX_CACHED_PREVIEWS).unwrap()),
+            full_images: LruCache::new(NonZeroUsize::new(MAX_CACHED_FULL_IMAGES).unwrap()),
+        }
+    }
+
+    pub fn queue(&self) -> &PreviewQueue {
+        &self.queue
+    }
+
+    pub fn result(&self, key: &PreviewKey) -> Option<&PreviewResult> {
+        self.cache(key).peek(key)
+    }
+
+    fn cache(&self, key: &PreviewKey) -> &LruCache<PreviewKey, PreviewResult> {
+        match key.kind {
+            PreviewKind::FullImage(_) => &self.full_images,
+            _ => &self.results,
+        }
+    }
+
+    fn cache_mut(&mut self, key: &PreviewKey) -> &mut LruCache<PreviewKey, PreviewResult> {
+        match key.kind {
+            PreviewKind::FullImage(_) => &mut self.full_images,
+            _ => &mut self.results,
+        }
+    }
+
+
+    pub fn request(key: PreviewKey, priority: PreviewPriority, cx: &mut App) -> Option<PreviewResult> {
+        let service = cx.try_global::<Self>()?;
+        if let Some(result) = service.result(&key) {
+            return Some(result.clone());
+        }
+        if service
+            .queue
+            .queued_priority(&key)
+            .is_some_and(|queued| queued >= priority)
+        {
+            return None;
+        }
+
+        cx.update_global::<Self, _>(|service, _| {
+            service.queue.request(key, priority);
+        });
+        Self::pump(cx);
+        None
+    }
+
+    pub fn cancel(key: &PreviewKey, cx: &mut App) {
+        let is_queued = cx
+            .try_global::<Self>()
+            .is_some_and(|service| service.queue.is_queued(key));
+        if is_queued {
+            cx.update_global::<Self, _>(|service, _| {
+                service.queue.cancel(key);
+            });
+            Self::pump(cx);
+        }
+    }
+
+
+    pub fn retain_paths(keep: &HashSet<PathBuf>, cx: &mut App) {
+        if !cx.has_global::<Self>() {
+            return;
+        }
+        cx.update_global::<Self, _>(|service, _| {
+            service.queue.retain_paths(keep);
+        });
+        Self::pump(cx);
+    }
+
+    fn pump(cx: &mut App) {
+        while cx
+            .try_global::<Self>()
+            .is_some_and(|service| service.queue.can_start())
+        {
+            let Some(job) = cx.update_global::<Self, _>(|service, _| service.queue.next_job()) else {
+                return;
+            };
+
+            cx.spawn(async move |cx| {
+                let task_job = job.clone();
+                let result = cx
+                    .background_executor()
+                    .spawn(async move { generate_preview(&task_job.key, &task_job.cancel) })
+                    .await;
+
+                let _ = cx.update(|cx| Self::complete(job, result, cx));
+            })
+            .detach();
+        }
+    }
+
+    fn complete(job: PreviewJob, result: Result<PreviewOutput, PreviewError>, cx: &mut App) {
+        cx.update_global::<Self, _>(|service, _| {
+            if !service.queue.finish(&job) {
+                return;
+            }
+            let preview = match result {
+                Ok(PreviewOutput::Png { bytes, .. }) => {
+                    PreviewResult::Image(Arc::new(Image::from_bytes(ImageFormat::Png, bytes)))
+                }
+                Ok(PreviewOutput::Text { content, truncated }) => PreviewResult::Text {
+                    content: content.into(),
+                    truncated,
+                },
+                Err(PreviewError::Cancelled) => return,
+                Err(e) => PreviewResult::Failed(e.to_string().into()),
+            };
+            service.cache_mut(&job.key).put(job.key, preview);
+        });
+        Self::pump(cx);
+    }
+}
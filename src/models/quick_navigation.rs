@@ -0,0 +1,20 @@
+use std::path::{Path, PathBuf};
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuickDestination {
+    Parent,
+    Home,
+    Root,
+}
+
+impl QuickDestination {
+    pub fn resolve(self, current: &Path, home: Option<&Path>) -> Option<PathBuf> {
+        let target = match self {
+            QuickDestination::Parent => current.parent()?,
+            QuickDestination::Home => home?,
+            QuickDestination::Root => current.ancestors().last()?,
+        };
+        (target != current && !target.as_os_str().is_empty()).then(|| target.to_path_buf())
+    }
+}
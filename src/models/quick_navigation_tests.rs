@@ -0,0 +1,37 @@
+use super::quick_navigation::*;
+use std::path::{Path, PathBuf};
+
+
+#[test]
+fn test_parent_moves_up_one_level() {
+    let current = Path::new("/home/user/docs");
+    assert_eq!(
+        QuickDestination::Parent.resolve(current, None),
+        Some(PathBuf::from("/home/user"))
+    );
+    assert_eq!(QuickDestination::Parent.resolve(Path::new("/"), None), None);
+}
+
+#[test]
+fn test_home_uses_home_directory() {
+    let home = Path::new("/home/user");
+    assert_eq!(
+        QuickDestination::Home.resolve(Path::new("/tmp/build"), Some(home)),
+        Some(home.to_path_buf())
+    );
+    assert_eq!(QuickDestination::Home.resolve(home, Some(home)), None);
+    assert_eq!(QuickDestination::Home.resolve(Path::new("/tmp"), None), None);
+}
+
+#[test]
+fn test_root_jumps_to_top_of_path() {
+    let root = QuickDestination::Root.resolve(Path::new("/home/user/docs"), None);
+    assert_eq!(root, Some(PathBuf::from("/")));
+    assert_eq!(QuickDestination::Root.resolve(Path::new("/"), None), None);
+}
+
+#[test]
+fn test_relative_paths_without_parent_do_not_navigate() {
+    assert_eq!(QuickDestination::Parent.resolve(Path::new("docs"), None), None);
+    assert_eq!(QuickDestination::Root.resolve(Path::new("docs"), None), None);
+}
@@ -0,0 +1,139 @@
+use std::collections::{BTreeMap, HashSet};
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::{FileEntry, SortState};
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ResultGrouping {
+    #[default]
+    Flat,
+    ByDirectory,
+}
+
+impl ResultGrouping {
+    pub fn label(&self) -> &'static str {
+        match self {
+            ResultGrouping::Flat => "Flat",
+            ResultGrouping::ByDirectory => "By folder",
+        }
+    }
+
+    pub fn toggle(&self) -> Self {
+        match self {
+            ResultGrouping::Flat => ResultGrouping::ByDirectory,
+            ResultGrouping::ByDirectory => ResultGrouping::Flat,
+        }
+    }
+}
+
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResultGroup {
+    pub directory: PathBuf,
+    pub entries: Vec<FileEntry>,
+}
+
+impl ResultGroup {
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+
+pub fn group_by_directory(entries: &[FileEntry], sort: &SortState) -> Vec<ResultGroup> {
+    let mut buckets: BTreeMap<PathBuf, Vec<FileEntry>> = BTreeMap::new();
+    for entry in entries {
+        let directory = entry
+            .path
+            .parent()
+            .map(Path::to_path_buf)
+            .unwrap_or_else(|| entry.path.clone());
+        buckets.entry(directory).or_default().push(entry.clone());
+    }
+
+    buckets
+        .into_iter()
+        .map(|(directory, mut entries)| {
+            sort.sort_entries(&mut entries);
+            ResultGroup { directory, entries }
+        })
+        .collect()
+}
+
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum GroupedRow {
+    Header {
+        directory: PathBuf,
+        count: usize,
+        collapsed: bool,
+    },
+    Entry(FileEntry),
+}
+
+
+#[derive(Debug, Clone, Default)]
+pub struct GroupedResults {
+    groups: Vec<ResultGroup>,
+    collapsed: HashSet<PathBuf>,
+    rows: Vec<GroupedRow>,
+}
+
+impl GroupedResults {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn set_entries(&mut self, entries: &[FileEntry], sort: &SortState) {
+        self.groups = group_by_directory(entries, sort);
+        self.rebuild_rows();
+    }
+
+    pub fn clear(&mut self) {
+        self.groups.clear();
+        self.collapsed.clear();
+        self.rows.clear();
+    }
+
+    pub fn toggle_collapsed(&mut self, directory: &Path) {
+        if !self.collapsed.remove(directory) {
+            self.collapsed.insert(directory.to_path_buf());
+        }
+        self.rebuild_rows();
+    }
+
+    pub fn is_collapsed(&self, directory: &Path) -> bool {
+        self.collapsed.contains(directory)
+    }
+
+    pub fn groups(&self) -> &[ResultGroup] {
+        &self.groups
+    }
+
+    pub fn rows(&self) -> &[GroupedRow] {
+        &self.rows
+    }
+
+    fn rebuild_rows(&mut self) {
+        self.rows.clear();
+        for group in &self.groups {
+            let collapsed = self.collapsed.contains(&group.directory);
+            self.rows.push(GroupedRow::Header {
+                directory: group.directory.clone(),
+                count: group.len(),
+                collapsed,
+            });
+            if !collapsed {
+                self.rows
+                    .extend(group.entries.iter().cloned().map(GroupedRow::Entry));
+            }
+        }
+    }
+}
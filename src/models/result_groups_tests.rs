@@ -0,0 +1,100 @@
+use super::result_groups::*;
+use crate::models::{FileEntry, SortColumn, SortDirection, SortState};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+
+fn file(path: &str, size: u64) -> FileEntry {
+    let path = PathBuf::from(path);
+    FileEntry::new(
+        path.file_name().unwrap().to_string_lossy().to_string(),
+        path,
+        false,
+        size,
+        SystemTime::UNIX_EPOCH + Duration::from_secs(size),
+    )
+}
+
+
+fn sample() -> Vec<FileEntry> {
+    vec![
+        file("/root/src/views/list.rs", 30),
+        file("/root/README.md", 10),
+        file("/root/src/main.rs", 50),
+        file("/root/src/lib.rs", 20),
+        file("/root/src/views/grid.rs", 40),
+    ]
+}
+
+
+fn names(group: &ResultGroup) -> Vec<&str> {
+    group.entries.iter().map(|e| e.name.as_str()).collect()
+}
+
+#[test]
+fn test_results_are_bucketed_by_parent_directory() {
+    let groups = group_by_directory(&sample(), &SortState::default());
+
+    let directories: Vec<&Path> = groups.iter().map(|g| g.directory.as_path()).collect();
+    assert_eq!(
+        directories,
+        vec![
+            Path::new("/root"),
+            Path::new("/root/src"),
+            Path::new("/root/src/views"),
+        ]
+    );
+    assert_eq!(groups.iter().map(ResultGroup::len).collect::<Vec<_>>(), vec![1, 2, 2]);
+    assert_eq!(names(&groups[1]), vec!["lib.rs", "main.rs"]);
+}
+
+#[test]
+fn test_sorting_applies_within_each_group() {
+    let sort = SortState {
+        column: SortColumn::Size,
+        direction: SortDirection::Descending,
+        ..SortState::default()
+    };
+    let groups = group_by_directory(&sample(), &sort);
+
+    assert_eq!(groups[0].directory, PathBuf::from("/root"));
+    assert_eq!(names(&groups[1]), vec!["main.rs", "lib.rs"]);
+    assert_eq!(names(&groups[2]), vec!["grid.rs", "list.rs"]);
+}
+
+#[test]
+fn test_empty_results_produce_no_groups() {
+    assert!(group_by_directory(&[], &SortState::default()).is_empty());
+}
+
+#[test]
+fn test_collapsed_groups_hide_their_entries() {
+    let mut results = GroupedResults::new();
+    results.set_entries(&sample(), &SortState::default());
+    assert_eq!(results.rows().len(), 8);
+
+    results.toggle_collapsed(Path::new("/root/src"));
+    assert!(results.is_collapsed(Path::new("/root/src")));
+    assert_eq!(results.rows().len(), 6);
+    assert_eq!(
+        results.rows()[2],
+        GroupedRow::Header {
+            directory: PathBuf::from("/root/src"),
+            count: 2,
+            collapsed: true,
+        }
+    );
+
+    results.set_entries(&sample(), &SortState::default());
+    assert_eq!(results.rows().len(), 6);
+
+    results.toggle_collapsed(Path::new("/root/src"));
+    assert_eq!(results.rows().len(), 8);
+}
+
+#[test]
+fn test_grouping_toggles_between_flat_and_by_folder() {
+    assert_eq!(ResultGrouping::default(), ResultGrouping::Flat);
+    assert_eq!(ResultGrouping::Flat.toggle(), ResultGrouping::ByDirectory);
+    assert_eq!(ResultGrouping::ByDirectory.toggle(), ResultGrouping::Flat);
+}
@@ -0,0 +1,177 @@
+use std::collections::{HashMap, VecDeque};
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+
+use crate::models::{FileEntry, ViewMode};
+
+
+pub const SCROLL_MEMORY_CAPACITY: usize = 64;
+
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScrollGeometry {
+    pub item_extent: f32,
+    pub columns: usize,
+    pub viewport: f32,
+}
+
+impl ScrollGeometry {
+    pub fn list(row_height: f32, viewport: f32) -> Self {
+        Self {
+            item_extent: row_height,
+            columns: 1,
+            viewport,
+        }
+    }
+
+    pub fn grid(item_extent: f32, columns: usize, viewport: f32) -> Self {
+        Self {
+            item_extent,
+            columns,
+            viewport,
+        }
+    }
+
+    fn row_top(&self, index: usize) -> f32 {
+        (index / self.columns.max(1)) as f32 * self.item_extent
+    }
+
+    pub fn first_visible_index(&self, offset: f32) -> usize {
+        if self.item_extent <= 0.0 {
+            return 0;
+        }
+        let row = (offset.max(0.0) / self.item_extent).floor() as usize;
+        row * self.columns.max(1)
+    }
+
+    pub fn visible_range(&self, offset: f32, len: usize) -> Range<usize> {
+        if self.item_extent <= 0.0 || self.viewport <= 0.0 {
+            return 0..0;
+        }
+        let columns = self.columns.max(1);
+        let offset = offset.max(0.0);
+        let first_row = (offset / self.item_extent).floor() as usize;
+        let end_row = ((offset + self.viewport) / self.item_extent).ceil() as usize;
+        (first_row * columns).min(len)..(end_row * columns).min(len)
+    }
+
+    pub fn is_visible(&self, index: usize, offset: f32) -> bool {
+        let top = self.row_top(index);
+        top >= offset && top + self.item_extent <= offset + self.viewport
+    }
+
+    pub fn offset_revealing(&self, index: usize, offset: f32) -> f32 {
+        let top = self.row_top(index);
+        let offset = offset.max(0.0);
+        if self.viewport <= 0.0 || top < offset {
+            top
+        } else if top + self.item_extent > offset + self.viewport {
+            (top + self.item_extent - self.viewport).max(0.0)
+        } else {
+            offset
+        }
+    }
+}
+
+
+pub fn restore_scroll_offset(
+    saved: Option<f32>,
+    anchor: Option<usize>,
+    geometry: &ScrollGeometry,
+) -> f32 {
+    match (saved, anchor) {
+        (Some(offset), Some(index)) => geometry.offset_revealing(index, offset),
+        (Some(offset), None) => offset.max(0.0),
+        (None, Some(index)) => geometry.row_top(index),
+        (None, None) => 0.0,
+    }
+}
+
+
+#[derive(Debug, Clone, Default, PartialEq)]
+struct DirectoryScroll {
+    list: Option<f32>,
+    grid: Option<f32>,
+    selected: Option<PathBuf>,
+}
+
+impl DirectoryScroll {
+    fn slot(&mut self, mode: ViewMode) -> &mut Option<f32> {
+        match mode {
+            ViewMode::List | ViewMode::Details => &mut self.list,
+            ViewMode::Grid => &mut self.grid,
+        }
+    }
+
+    fn get(&self, mode: ViewMode) -> Option<f32> {
+        match mode {
+            ViewMode::List | ViewMode::Details => self.list,
+            ViewMode::Grid => self.grid,
+        }
+    }
+}
+
+
+#[derive(Debug, Clone, Default)]
+pub struct ScrollMemory {
+    directories: HashMap<PathBuf, DirectoryScroll>,
+    order: VecDeque<PathBuf>,
+}
+
+impl ScrollMemory {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn remember(&mut self, directory: &Path, mode: ViewMode, offset: f32) {
+        *self.touch(directory).slot(mode) = Some(offset.max(0.0));
+    }
+
+    pub fn remember_selection(&mut self, directory: &Path, selected: Option<PathBuf>) {
+        self.touch(directory).selected = selected;
+    }
+
+    pub fn selection(&self, directory: &Path) -> Option<&Path> {
+        self.directories
+            .get(directory)
+            .and_then(|scroll| scroll.selected.as_deref())
+    }
+
+    pub fn recall_selection(&mut self, directory: &Path, entries: &[FileEntry]) -> Option<usize> {
+        let scroll = self.directories.get_mut(directory)?;
+        let selected = scroll.selected.as_ref()?;
+        let index = entries.iter().position(|entry| &entry.path == selected);
+        if index.is_none() {
+            scroll.selected = None;
+        }
+        index
+    }
+
+    fn touch(&mut self, directory: &Path) -> &mut DirectoryScroll {
+        if let Some(position) = self.order.iter().position(|p| p == directory) {
+            self.order.remove(position);
+        }
+        self.order.push_back(directory.to_path_buf());
+
+        while self.order.len() > SCROLL_MEMORY_CAPACITY {
+            if let Some(oldest) = self.order.pop_front() {
+                self.directories.remove(&oldest);
+            }
+        }
+        self.directories.entry(directory.to_path_buf()).or_default()
+    }
+
+    pub fn offset(&self, directory: &Path, mode: ViewMode) -> Option<f32> {
+        self.directories
+            .get(directory)
+            .and_then(|scroll| scroll.get(mode))
+    }
+
+    pub fn len(&self) -> usize {
+        self.directories.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.directories.is_empty()
+    }
+}
@@ -0,0 +1,124 @@
+use super::scroll_memory::*;
+use crate::models::{FileEntry, ViewMode};
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+
+const LIST: ScrollGeometry = ScrollGeometry {
+    item_extent: 40.0,
+    columns: 1,
+    viewport: 400.0,
+};
+
+const GRID: ScrollGeometry = ScrollGeometry {
+    item_extent: 116.0,
+    columns: 5,
+    viewport: 400.0,
+};
+
+#[test]
+fn test_toggling_view_modes_keeps_selection_visible() {
+    let dir = Path::new("/projects");
+    let mut memory = ScrollMemory::new();
+    let selected = 50;
+
+    let list_offset = 1800.0;
+    assert!(LIST.is_visible(selected, list_offset));
+    memory.remember(dir, ViewMode::List, list_offset);
+
+    let grid_offset = restore_scroll_offset(
+        memory.offset(dir, ViewMode::Grid),
+        Some(selected),
+        &GRID,
+    );
+    assert!(GRID.is_visible(selected, grid_offset));
+    memory.remember(dir, ViewMode::Grid, grid_offset);
+
+    let back_to_list = restore_scroll_offset(
+        memory.offset(dir, ViewMode::List),
+        Some(selected),
+        &LIST,
+    );
+    assert_eq!(back_to_list, list_offset);
+    assert!(LIST.is_visible(selected, back_to_list));
+}
+
+#[test]
+fn test_saved_offset_adjusts_when_selection_moved_offscreen() {
+    let offset = restore_scroll_offset(Some(1800.0), Some(2), &LIST);
+    assert!(LIST.is_visible(2, offset));
+    assert_eq!(offset, 80.0);
+
+    let offset = restore_scroll_offset(Some(0.0), Some(30), &LIST);
+    assert!(LIST.is_visible(30, offset));
+    assert_eq!(offset, 840.0);
+}
+
+#[test]
+fn test_first_visible_item_maps_across_views_without_selection() {
+    let first = LIST.first_visible_index(1800.0);
+    assert_eq!(first, 45);
+
+    let grid_offset = restore_scroll_offset(None, Some(first), &GRID);
+    assert!(GRID.is_visible(first, grid_offset));
+    assert_eq!(GRID.first_visible_index(grid_offset), first);
+}
+
+#[test]
+fn test_visible_range_follows_scroll_offset() {
+    assert_eq!(GRID.visible_range(0.0, 200), 0..20);
+    assert_eq!(GRID.visible_range(1000.0, 200), 40..65);
+    assert_eq!(GRID.visible_range(1000.0, 50), 40..50);
+    assert_eq!(LIST.visible_range(1800.0, 1000), 45..55);
+
+    let unmeasured = ScrollGeometry::grid(116.0, 5, 0.0);
+    assert!(unmeasured.visible_range(0.0, 200).is_empty());
+}
+
+#[test]
+fn test_list_and_details_share_scroll_per_directory() {
+    let mut memory = ScrollMemory::new();
+    memory.remember(Path::new("/a"), ViewMode::Details, 120.0);
+    memory.remember(Path::new("/a"), ViewMode::Grid, 40.0);
+
+    assert_eq!(memory.offset(Path::new("/a"), ViewMode::List), Some(120.0));
+    assert_eq!(memory.offset(Path::new("/a"), ViewMode::Grid), Some(40.0));
+    assert_eq!(memory.offset(Path::new("/b"), ViewMode::List), None);
+}
+
+#[test]
+fn test_memory_forgets_least_recent_directories() {
+    let mut memory = ScrollMemory::new();
+    for i in 0..SCROLL_MEMORY_CAPACITY + 3 {
+        memory.remember(&PathBuf::from(format!("/dir{}", i)), ViewMode::List, i as f32);
+    }
+
+    assert_eq!(memory.len(), SCROLL_MEMORY_CAPACITY);
+    assert_eq!(memory.offset(Path::new("/dir0"), ViewMode::List), None);
+    assert_eq!(memory.offset(Path::new("/dir3"), ViewMode::List), Some(3.0));
+}
+
+#[test]
+fn test_navigating_out_and_back_reselects_prior_entry() {
+    let parent = Path::new("/projects");
+    let entry = |name: &str| {
+        FileEntry::new(name.to_string(), parent.join(name), false, 0, SystemTime::UNIX_EPOCH)
+    };
+    let mut memory = ScrollMemory::new();
+
+    memory.remember(parent, ViewMode::List, 80.0);
+    memory.remember_selection(parent, Some(parent.join("beta")));
+    memory.remember_selection(&parent.join("alpha"), None);
+
+    let reloaded = vec![entry("alpha"), entry("aardvark"), entry("beta"), entry("gamma")];
+    let index = memory.recall_selection(parent, &reloaded).unwrap();
+    assert_eq!(reloaded[index].path, parent.join("beta"));
+    let offset = restore_scroll_offset(memory.offset(parent, ViewMode::List), Some(index), &LIST);
+    assert!(LIST.is_visible(index, offset));
+    assert_eq!(memory.recall_selection(&parent.join("alpha"), &reloaded), None);
+
+    let without_beta = vec![entry("alpha"), entry("gamma")];
+    assert_eq!(memory.recall_selection(parent, &without_beta), None);
+    assert_eq!(memory.selection(parent), None);
+    assert_eq!(memory.offset(parent, ViewMode::List), Some(80.0));
+}
@@ -1,10 +1,15 @@
-use nucleo::{Config, Injector, Matcher, Nucleo};
-use std::path::PathBuf;
+use nucleo::{Config, Matcher, Nucleo};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
+use super::FsEvent;
+
 
 pub struct SearchEngine {
     nucleo: Nucleo<PathBuf>,
+    injected: HashSet<PathBuf>,
+    stale: bool,
     pattern: String,
     active: bool,
 }
@@ -25,6 +30,36 @@ pub struct MatchedItem {
     pub positions: Vec<usize>,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SearchSortMode {
+    #[default]
+    Relevance,
+    Name,
+    DateModified,
+    PathDepth,
+}
+
+impl SearchSortMode {
+    pub fn all() -> &'static [SearchSortMode] {
+        &[
+            SearchSortMode::Relevance,
+            SearchSortMode::Name,
+            SearchSortMode::DateModified,
+            SearchSortMode::PathDepth,
+        ]
+    }
+
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            SearchSortMode::Relevance => "Relevance",
+            SearchSortMode::Name => "Name",
+            SearchSortMode::DateModified => "Date",
+            SearchSortMode::PathDepth => "Depth",
+        }
+    }
+}
+
 impl SearchEngine {
 
     pub fn new() -> Self {
@@ -33,18 +68,22 @@ impl SearchEngine {
 
         Self {
             nucleo,
+            injected: HashSet::new(),
+            stale: false,
             pattern: String::new(),
             active: false,
         }
     }
 
 
-    pub fn injector(&self) -> Injector<PathBuf> {
-        self.nucleo.injector()
+    pub fn inject(&mut self, path: PathBuf) {
+        if !self.injected.insert(path.clone()) {
+            return;
+        }
+        self.push(path);
     }
 
-
-    pub fn inject(&self, path: PathBuf) {
+    fn push(&self, path: PathBuf) {
         let injector = self.nucleo.injector();
         let path_string = path.to_string_lossy().to_string();
         injector.push(path, move |_p, cols| {
@@ -53,6 +92,50 @@ impl SearchEngine {
     }
 
 
+    pub fn remove(&mut self, path: &Path) -> bool {
+        let removed = self.injected.remove(path);
+        self.stale |= removed;
+        removed
+    }
+
+    fn rebuild_if_stale(&mut self) {
+        if !self.stale {
+            return;
+        }
+        self.stale = false;
+        self.nucleo.restart(true);
+        for path in &self.injected {
+            self.push(path.clone());
+        }
+    }
+
+
+    pub fn contains(&self, path: &Path) -> bool {
+        self.injected.contains(path)
+    }
+
+
+    pub fn apply_events(&mut self, events: &[FsEvent]) -> bool {
+        let mut changed = false;
+        for event in events {
+            match event {
+                FsEvent::Created(path) => {
+                    changed |= !self.contains(path);
+                    self.inject(path.clone());
+                }
+                FsEvent::Deleted(path) => changed |= self.remove(path),
+                FsEvent::Renamed { from, to } => {
+                    changed |= self.remove(from);
+                    changed |= !self.contains(to);
+                    self.inject(to.clone());
+                }
+                FsEvent::Modified(_) => {}
+            }
+        }
+        changed
+    }
+
+
     pub fn set_pattern(&mut self, pattern: &str) {
         self.pattern = pattern.to_string();
         self.active = !pattern.is_empty();
@@ -78,10 +161,11 @@ impl SearchEngine {
 
 
     pub fn snapshot(&mut self) -> SearchSnapshot {
+        self.rebuild_if_stale();
         self.nucleo.tick(10);
 
         let snapshot = self.nucleo.snapshot();
-        let total_items = snapshot.item_count() as usize;
+        let total_items = self.injected.len();
 
         let mut matcher = Matcher::new(Config::DEFAULT);
 
@@ -118,6 +202,8 @@ impl SearchEngine {
     pub fn clear(&mut self) {
         self.pattern.clear();
         self.active = false;
+        self.injected.clear();
+        self.stale = false;
 
         let config = Config::DEFAULT.match_paths();
         self.nucleo = Nucleo::new(config, Arc::new(|| {}), None, 1);
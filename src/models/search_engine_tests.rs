@@ -175,6 +175,58 @@ mod unit_tests {
         );
     }
 
+    fn settled_snapshot(engine: &mut SearchEngine) -> SearchSnapshot {
+        engine.rebuild_if_stale();
+        while engine.nucleo.tick(10).running {}
+        engine.snapshot()
+    }
+
+    #[test]
+    fn test_watch_create_event_appears_in_active_search() {
+        use crate::models::FsEvent;
+
+        let mut engine = SearchEngine::new();
+        engine.inject(PathBuf::from("/home/user/documents/notes.txt"));
+        engine.set_pattern("report");
+
+        let created = PathBuf::from("/home/user/documents/report.txt");
+        let has_created = |snapshot: &SearchSnapshot| snapshot.matches.iter().any(|m| m.path == created);
+        assert!(engine.apply_events(&[FsEvent::Created(created.clone())]));
+        assert!(!engine.apply_events(&[FsEvent::Created(created.clone())]));
+        assert!(has_created(&settled_snapshot(&mut engine)));
+
+        assert!(engine.apply_events(&[FsEvent::Deleted(created.clone())]));
+        assert!(!has_created(&settled_snapshot(&mut engine)));
+        assert!(!engine.contains(&created));
+
+        engine.apply_events(&[FsEvent::Created(created.clone())]);
+        let snapshot = settled_snapshot(&mut engine);
+        assert_eq!(snapshot.matches.iter().filter(|m| m.path == created).count(), 1);
+        assert_eq!(snapshot.total_items, 2);
+    }
+
+    #[test]
+    fn test_deleted_paths_leave_the_ranked_window() {
+        use crate::models::FsEvent;
+
+        let mut engine = SearchEngine::new();
+        let paths: Vec<PathBuf> = (0..1500)
+            .map(|i| PathBuf::from(format!("/home/user/report-{i}.txt")))
+            .collect();
+        for path in &paths {
+            engine.inject(path.clone());
+        }
+        engine.set_pattern("report");
+
+        let deleted: Vec<FsEvent> = paths[..500].iter().cloned().map(FsEvent::Deleted).collect();
+        assert!(engine.apply_events(&deleted));
+
+        let snapshot = settled_snapshot(&mut engine);
+        assert_eq!(snapshot.total_items, 1000);
+        assert_eq!(snapshot.matches.len(), 1000);
+        assert!(snapshot.matches.iter().all(|m| engine.contains(&m.path)));
+    }
+
     #[test]
     fn test_matched_item_is_match_position() {
         let item = MatchedItem {
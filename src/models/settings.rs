@@ -2,7 +2,10 @@ use gpui::Global;
 use serde::{Deserialize, Serialize};
 
 use crate::io::{SortKey, SortOrder};
-use crate::models::ThemeId;
+use crate::models::{
+    ArchiveFormat, ClickBehavior, ColumnWidthRules, CompressOptions, ConflictStrategy, DateFormat, EasingPreset, ExecutableOpenBehavior, FolderViewRules, LaunchLocation, MotionPreference, NameTintMode, PreviewLimits, ResultGrouping,
+    TerminalPaletteMode, ThemeId, ToolbarLayout, TrashLocation, VisibleColumns,
+};
 
 
 
@@ -44,6 +47,136 @@ pub struct GlobalSettings {
 
     #[serde(default)]
     pub restore_windows: bool,
+
+
+    #[serde(default)]
+    pub launch_location: LaunchLocation,
+
+
+    #[serde(default)]
+    pub auto_reveal_in_sidebar: bool,
+
+
+    #[serde(default = "default_ui_scale")]
+    pub ui_scale: f32,
+
+
+    #[serde(default)]
+    pub preview_tab: PreviewTab,
+
+    #[serde(default)]
+    pub sidebar_sections: SidebarSections,
+
+    #[serde(default)]
+    pub folder_view_rules: FolderViewRules,
+
+    #[serde(default)]
+    pub column_widths: ColumnWidthRules,
+
+    #[serde(default)]
+    pub visible_columns: VisibleColumns,
+
+    #[serde(default)]
+    pub toolbar_layout: ToolbarLayout,
+
+    #[serde(default)]
+    pub pinned_tabs: Vec<std::path::PathBuf>,
+
+    #[serde(default)]
+    pub motion_preference: MotionPreference,
+
+    #[serde(default = "default_animation_speed")]
+    pub animation_speed: f32,
+
+    #[serde(default)]
+    pub archive_format: ArchiveFormat,
+
+    #[serde(default = "default_compression_level")]
+    pub compression_level: u8,
+
+    #[serde(default)]
+    pub easing_preset: EasingPreset,
+
+    #[serde(default)]
+    pub name_tint: NameTintMode,
+
+    #[serde(default)]
+    pub result_grouping: ResultGrouping,
+
+    #[serde(default)]
+    pub trash_location: TrashLocation,
+
+    #[serde(default)]
+    pub terminal_palette: TerminalPaletteMode,
+
+    #[serde(default)]
+    pub click_behavior: ClickBehavior,
+
+    #[serde(default)]
+    pub date_format: DateFormat,
+
+    #[serde(default)]
+    pub preview_limits: PreviewLimits,
+
+    #[serde(default = "default_duplicate_on_same_directory_paste")]
+    pub duplicate_on_same_directory_paste: bool,
+
+    #[serde(default)]
+    pub conflict_strategy: ConflictStrategy,
+
+    #[serde(default = "default_io_batch_size")]
+    pub io_batch_size: usize,
+
+    #[serde(default = "default_io_flush_interval_ms")]
+    pub io_flush_interval_ms: u64,
+
+    #[serde(default = "default_icon_pack")]
+    pub icon_pack: String,
+
+    #[serde(default = "default_history_limit")]
+    pub history_limit: usize,
+
+    #[serde(default)]
+    pub accent_hue: Option<f32>,
+
+    #[serde(default)]
+    pub show_folder_sizes: bool,
+
+    #[serde(default)]
+    pub executable_open: ExecutableOpenBehavior,
+}
+
+
+fn default_ui_scale() -> f32 {
+    crate::models::UI_SCALE_DEFAULT
+}
+
+fn default_animation_speed() -> f32 {
+    crate::models::ANIMATION_SPEED_DEFAULT
+}
+
+fn default_compression_level() -> u8 {
+    crate::models::DEFAULT_COMPRESSION_LEVEL
+}
+
+fn default_duplicate_on_same_directory_paste() -> bool {
+    true
+}
+
+fn default_io_batch_size() -> usize {
+    crate::io::DEFAULT_BATCH_SIZE
+}
+
+fn default_io_flush_interval_ms() -> u64 {
+    crate::io::DEFAULT_FLUSH_INTERVAL.as_millis() as u64
+}
+
+fn default_icon_pack() -> String {
+    crate::models::BUILTIN_ICON_PACK.to_string()
+}
+
+fn default_history_limit() -> usize {
+    crate::models::DEFAULT_HISTORY_LIMIT
 }
 
 
@@ -64,6 +197,86 @@ pub enum ViewMode {
 }
 
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum PreviewTab {
+    #[default]
+    Preview,
+    Info,
+    Permissions,
+}
+
+impl PreviewTab {
+    pub fn all() -> &'static [PreviewTab] {
+        &[PreviewTab::Preview, PreviewTab::Info, PreviewTab::Permissions]
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            PreviewTab::Preview => "Preview",
+            PreviewTab::Info => "Info",
+            PreviewTab::Permissions => "Permissions",
+        }
+    }
+
+    pub fn next(&self) -> PreviewTab {
+        match self {
+            PreviewTab::Preview => PreviewTab::Info,
+            PreviewTab::Info => PreviewTab::Permissions,
+            PreviewTab::Permissions => PreviewTab::Preview,
+        }
+    }
+
+    pub fn previous(&self) -> PreviewTab {
+        match self {
+            PreviewTab::Preview => PreviewTab::Permissions,
+            PreviewTab::Info => PreviewTab::Preview,
+            PreviewTab::Permissions => PreviewTab::Info,
+        }
+    }
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SidebarSections {
+    pub tools: bool,
+    pub workspace: bool,
+    pub devices: bool,
+    pub network: bool,
+    pub smart_folders: bool,
+    pub tags: bool,
+    pub bookmarks: bool,
+    pub frequent: bool,
+    pub favorites: bool,
+}
+
+impl Default for SidebarSections {
+    fn default() -> Self {
+        Self::all(true)
+    }
+}
+
+impl SidebarSections {
+    pub fn all(expanded: bool) -> Self {
+        Self {
+            tools: expanded,
+            workspace: expanded,
+            devices: expanded,
+            network: expanded,
+            smart_folders: expanded,
+            tags: expanded,
+            bookmarks: expanded,
+            frequent: expanded,
+            favorites: expanded,
+        }
+    }
+
+    pub fn any_expanded(&self) -> bool {
+        *self != Self::all(false)
+    }
+}
+
+
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct GridConfig {
 
@@ -257,14 +470,91 @@ impl GlobalSettings {
     }
 
 
-    pub fn save(&self) -> std::io::Result<()> {
-        let config_dir = dirs::config_dir()
+    pub fn launch_location(&self) -> &LaunchLocation {
+        &self.launch_location
+    }
+
+
+    pub fn set_launch_location(&mut self, location: LaunchLocation) {
+        self.launch_location = location;
+    }
+
+
+    pub fn auto_reveal_in_sidebar(&self) -> bool {
+        self.auto_reveal_in_sidebar
+    }
+
+
+    pub fn set_auto_reveal_in_sidebar(&mut self, enabled: bool) {
+        self.auto_reveal_in_sidebar = enabled;
+    }
+
+
+    pub fn ui_scale(&self) -> f32 {
+        crate::models::clamp_ui_scale(self.ui_scale)
+    }
+
+
+    pub fn set_ui_scale(&mut self, scale: f32) {
+        self.ui_scale = crate::models::clamp_ui_scale(scale);
+    }
+
+
+    pub fn animation_speed(&self) -> f32 {
+        crate::models::clamp_animation_speed(self.animation_speed)
+    }
+
+
+    pub fn set_animation_speed(&mut self, speed: f32) {
+        self.animation_speed = crate::models::clamp_animation_speed(speed);
+    }
+
+
+    pub fn archive_format(&self) -> ArchiveFormat {
+        if crate::models::COMPRESS_FORMATS.contains(&self.archive_format) {
+            self.archive_format
+        } else {
+            ArchiveFormat::default()
+        }
+    }
+
+
+    pub fn compress_options(&self) -> CompressOptions {
+        self.compress_options_for(self.archive_format())
+    }
+
+
+    pub fn compress_options_for(&self, format: ArchiveFormat) -> CompressOptions {
+        CompressOptions::new(format, self.compression_level)
+    }
+
+
+    pub fn batch_config(&self) -> crate::io::BatchConfig {
+        crate::io::BatchConfig::new(
+            self.io_batch_size,
+            std::time::Duration::from_millis(self.io_flush_interval_ms),
+        )
+    }
+
+
+    pub fn path() -> std::path::PathBuf {
+        dirs::config_dir()
             .unwrap_or_else(|| std::path::PathBuf::from("."))
-            .join("nexus-explorer");
+            .join("nexus-explorer")
+            .join("settings.json")
+    }
+
+
+    pub fn save(&self) -> std::io::Result<()> {
+        self.save_to(&Self::path())
+    }
 
-        std::fs::create_dir_all(&config_dir)?;
 
-        let config_path = config_dir.join("settings.json");
+    pub fn save_to(&self, config_path: &std::path::Path) -> std::io::Result<()> {
+        if let Some(config_dir) = config_path.parent() {
+            std::fs::create_dir_all(config_dir)?;
+        }
+
         let json = serde_json::to_string_pretty(self)
             .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
 
@@ -273,13 +563,13 @@ impl GlobalSettings {
 
 
     pub fn load() -> Self {
-        let config_path = dirs::config_dir()
-            .unwrap_or_else(|| std::path::PathBuf::from("."))
-            .join("nexus-explorer")
-            .join("settings.json");
+        Self::load_from(&Self::path())
+    }
+
 
+    pub fn load_from(config_path: &std::path::Path) -> Self {
         if config_path.exists() {
-            if let Ok(json) = std::fs::read_to_string(&config_path) {
+            if let Ok(json) = std::fs::read_to_string(config_path) {
                 if let Ok(settings) = serde_json::from_str::<GlobalSettings>(&json) {
                     return settings;
                 }
@@ -304,6 +594,37 @@ impl Default for GlobalSettings {
             grid_config: GridConfig::default(),
             theme_id: ThemeId::default(),
             restore_windows: false,
+            launch_location: LaunchLocation::default(),
+            auto_reveal_in_sidebar: false,
+            ui_scale: default_ui_scale(),
+            preview_tab: PreviewTab::default(),
+            sidebar_sections: SidebarSections::default(),
+            folder_view_rules: FolderViewRules::default(),
+            column_widths: ColumnWidthRules::default(),
+            visible_columns: VisibleColumns::default(),
+            toolbar_layout: ToolbarLayout::default(),
+            pinned_tabs: Vec::new(),
+            motion_preference: MotionPreference::default(),
+            animation_speed: default_animation_speed(),
+            archive_format: ArchiveFormat::default(),
+            compression_level: default_compression_level(),
+            easing_preset: EasingPreset::default(),
+            name_tint: NameTintMode::default(),
+            result_grouping: ResultGrouping::default(),
+            trash_location: TrashLocation::default(),
+            terminal_palette: TerminalPaletteMode::default(),
+            click_behavior: ClickBehavior::default(),
+            date_format: DateFormat::default(),
+            preview_limits: PreviewLimits::default(),
+            duplicate_on_same_directory_paste: default_duplicate_on_same_directory_paste(),
+            conflict_strategy: ConflictStrategy::default(),
+            io_batch_size: default_io_batch_size(),
+            io_flush_interval_ms: default_io_flush_interval_ms(),
+            icon_pack: default_icon_pack(),
+            history_limit: default_history_limit(),
+            accent_hue: None,
+            show_folder_sizes: false,
+            executable_open: ExecutableOpenBehavior::default(),
         }
     }
 }
@@ -428,6 +749,94 @@ mod tests {
         assert_eq!(config.content_height(0, 400.0), 0.0);
     }
 
+    #[test]
+    fn test_ui_scale_persisted_and_clamped() {
+        let mut settings = GlobalSettings::default();
+        assert_eq!(settings.ui_scale(), 1.0);
+
+        settings.set_ui_scale(1.2);
+        let json = serde_json::to_string(&settings).unwrap();
+        let restored: GlobalSettings = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.ui_scale(), 1.2);
+
+        settings.set_ui_scale(3.0);
+        assert_eq!(settings.ui_scale(), 1.5);
+    }
+
+    #[test]
+    fn test_animation_settings_persisted_and_clamped() {
+        let mut settings = GlobalSettings::default();
+        assert_eq!(settings.animation_speed(), 1.0);
+        assert_eq!(settings.easing_preset, EasingPreset::Standard);
+
+        settings.set_animation_speed(1.5);
+        settings.easing_preset = EasingPreset::Spring;
+        let json = serde_json::to_string(&settings).unwrap();
+        let restored: GlobalSettings = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.animation_speed(), 1.5);
+        assert_eq!(restored.easing_preset, EasingPreset::Spring);
+
+        settings.set_animation_speed(10.0);
+        assert_eq!(settings.animation_speed(), 4.0);
+    }
+
+    #[test]
+    fn test_folder_sizes_opt_in_and_persisted() {
+        let mut settings = GlobalSettings::default();
+        assert!(!settings.show_folder_sizes);
+
+        settings.show_folder_sizes = true;
+        let json = serde_json::to_string(&settings).unwrap();
+        let restored: GlobalSettings = serde_json::from_str(&json).unwrap();
+        assert!(restored.show_folder_sizes);
+    }
+
+    #[test]
+    fn test_preview_tab_persisted_and_cycles() {
+        let mut settings = GlobalSettings::default();
+        assert_eq!(settings.preview_tab, PreviewTab::Preview);
+
+        settings.preview_tab = PreviewTab::Permissions;
+        let json = serde_json::to_string(&settings).unwrap();
+        let restored: GlobalSettings = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.preview_tab, PreviewTab::Permissions);
+
+        assert_eq!(PreviewTab::Permissions.next(), PreviewTab::Preview);
+        assert_eq!(PreviewTab::Preview.previous(), PreviewTab::Permissions);
+        for tab in PreviewTab::all() {
+            assert_eq!(tab.next().previous(), *tab);
+        }
+    }
+
+    #[test]
+    fn test_sidebar_sections_restored_after_reload() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let config_path = temp.path().join("nexus-explorer").join("settings.json");
+
+        let mut settings = GlobalSettings::load_from(&config_path);
+        assert_eq!(settings.sidebar_sections, SidebarSections::all(true));
+
+        settings.sidebar_sections.network = false;
+        settings.sidebar_sections.favorites = false;
+        settings.save_to(&config_path).unwrap();
+        let restored = GlobalSettings::load_from(&config_path);
+
+        assert_eq!(
+            restored.sidebar_sections,
+            SidebarSections {
+                network: false,
+                favorites: false,
+                ..SidebarSections::all(true)
+            }
+        );
+        assert!(restored.sidebar_sections.any_expanded());
+        assert!(!SidebarSections::all(false).any_expanded());
+
+        let partial: SidebarSections = serde_json::from_str(r#"{"tags": false}"#).unwrap();
+        assert!(!partial.tags);
+        assert!(partial.bookmarks);
+    }
+
     fn arb_grid_config() -> impl Strategy<Value = GridConfig> {
         (
             16.0f32..128.0,
@@ -0,0 +1,132 @@
+use std::time::Duration;
+
+use crate::models::Transition;
+
+
+pub const SHEET_DISMISS_FRACTION: f32 = 0.35;
+
+
+pub const SHEET_SETTLE_DURATION: Duration = Duration::from_millis(200);
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SheetSide {
+    Top,
+    Bottom,
+    Left,
+    Right,
+}
+
+impl SheetSide {
+    pub fn dismiss_distance(&self, delta_x: f32, delta_y: f32) -> f32 {
+        match self {
+            SheetSide::Top => -delta_y,
+            SheetSide::Bottom => delta_y,
+            SheetSide::Left => -delta_x,
+            SheetSide::Right => delta_x,
+        }
+    }
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SheetRelease {
+    Dismiss,
+    SnapBack,
+}
+
+
+pub fn resolve_sheet_release(side: SheetSide, delta_x: f32, delta_y: f32, extent: f32) -> SheetRelease {
+    release_for_distance(side.dismiss_distance(delta_x, delta_y), extent)
+}
+
+fn release_for_distance(distance: f32, extent: f32) -> SheetRelease {
+    if extent > 0.0 && distance >= extent * SHEET_DISMISS_FRACTION {
+        SheetRelease::Dismiss
+    } else {
+        SheetRelease::SnapBack
+    }
+}
+
+
+#[derive(Debug, Clone, Copy)]
+struct SheetSettle {
+    from: f32,
+    to: f32,
+    timing: Transition,
+}
+
+
+#[derive(Debug, Clone, Copy)]
+pub struct SheetDrag {
+    side: SheetSide,
+    extent: f32,
+    offset: f32,
+    settle: Option<SheetSettle>,
+    release: Option<SheetRelease>,
+}
+
+impl SheetDrag {
+    pub fn new(side: SheetSide, extent: f32) -> Self {
+        Self {
+            side,
+            extent: extent.max(0.0),
+            offset: 0.0,
+            settle: None,
+            release: None,
+        }
+    }
+
+    pub fn side(&self) -> SheetSide {
+        self.side
+    }
+
+
+    pub fn drag_to(&mut self, delta_x: f32, delta_y: f32) {
+        if self.release.is_some() {
+            return;
+        }
+        self.offset = self
+            .side
+            .dismiss_distance(delta_x, delta_y)
+            .clamp(0.0, self.extent);
+    }
+
+
+    pub fn release(&mut self) -> SheetRelease {
+        if let Some(release) = self.release {
+            return release;
+        }
+        let release = release_for_distance(self.offset, self.extent);
+        let to = match release {
+            SheetRelease::Dismiss => self.extent,
+            SheetRelease::SnapBack => 0.0,
+        };
+        self.settle = Some(SheetSettle {
+            from: self.offset,
+            to,
+            timing: Transition::new(SHEET_SETTLE_DURATION),
+        });
+        self.release = Some(release);
+        release
+    }
+
+
+    pub fn offset(&self) -> f32 {
+        match self.settle {
+            Some(settle) => {
+                let eased = settle.timing.eased_progress();
+                settle.from + (settle.to - settle.from) * eased
+            }
+            None => self.offset,
+        }
+    }
+
+    pub fn is_settled(&self) -> bool {
+        self.settle.is_some_and(|settle| settle.timing.is_complete())
+    }
+
+    pub fn should_close(&self) -> bool {
+        self.release == Some(SheetRelease::Dismiss) && self.is_settled()
+    }
+}
@@ -0,0 +1,68 @@
+use super::sheet_drag::*;
+
+
+#[test]
+fn test_release_past_threshold_dismisses_toward_origin_edge() {
+    assert_eq!(
+        resolve_sheet_release(SheetSide::Bottom, 0.0, 120.0, 300.0),
+        SheetRelease::Dismiss
+    );
+    assert_eq!(
+        resolve_sheet_release(SheetSide::Top, 0.0, -120.0, 300.0),
+        SheetRelease::Dismiss
+    );
+    assert_eq!(
+        resolve_sheet_release(SheetSide::Left, -150.0, 0.0, 400.0),
+        SheetRelease::Dismiss
+    );
+    assert_eq!(
+        resolve_sheet_release(SheetSide::Right, 150.0, 0.0, 400.0),
+        SheetRelease::Dismiss
+    );
+}
+
+#[test]
+fn test_release_before_threshold_snaps_back() {
+    assert_eq!(
+        resolve_sheet_release(SheetSide::Bottom, 0.0, 100.0, 300.0),
+        SheetRelease::SnapBack
+    );
+    assert_eq!(
+        resolve_sheet_release(SheetSide::Right, 139.0, 0.0, 400.0),
+        SheetRelease::SnapBack
+    );
+    assert_eq!(
+        resolve_sheet_release(SheetSide::Bottom, 0.0, 100.0, 0.0),
+        SheetRelease::SnapBack
+    );
+}
+
+#[test]
+fn test_dragging_away_from_origin_edge_never_dismisses() {
+    assert_eq!(
+        resolve_sheet_release(SheetSide::Bottom, 0.0, -500.0, 300.0),
+        SheetRelease::SnapBack
+    );
+    assert_eq!(
+        resolve_sheet_release(SheetSide::Left, 500.0, 0.0, 300.0),
+        SheetRelease::SnapBack
+    );
+    assert_eq!(
+        resolve_sheet_release(SheetSide::Top, 300.0, 0.0, 300.0),
+        SheetRelease::SnapBack
+    );
+}
+
+#[test]
+fn test_drag_offset_is_clamped_to_sheet_extent() {
+    let mut drag = SheetDrag::new(SheetSide::Bottom, 300.0);
+    drag.drag_to(0.0, -40.0);
+    assert_eq!(drag.offset(), 0.0);
+
+    drag.drag_to(0.0, 900.0);
+    assert_eq!(drag.offset(), 300.0);
+
+    assert_eq!(drag.release(), SheetRelease::Dismiss);
+    drag.drag_to(0.0, 0.0);
+    assert_eq!(drag.release(), SheetRelease::Dismiss);
+}
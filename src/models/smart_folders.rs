@@ -582,7 +582,7 @@ impl SmartFolderManager {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::models::{FileType, IconKey};
+    use crate::models::{FileType, HiddenKind, IconKey};
 
     fn create_test_entry(name: &str, is_dir: bool, size: u64) -> FileEntry {
         let file_type = if is_dir {
@@ -609,6 +609,9 @@ mod tests {
             symlink_target: None,
             is_broken_symlink: false,
             is_shared: false,
+            hidden_kind: HiddenKind::Visible,
+            created: None,
+            unix_mode: None,
         }
     }
 
@@ -1,6 +1,6 @@
 use crate::models::{
-    CloudSyncStatus, DateFilter, FileEntry, FileType, IconKey, SearchQuery, SizeFilter,
-    SmartFolder, SmartFolderId, SmartFolderManager, TagId,
+    CloudSyncStatus, DateFilter, FileEntry, FileType, HiddenKind, IconKey, SearchQuery,
+    SizeFilter, SmartFolder, SmartFolderId, SmartFolderManager, TagId,
 };
 use proptest::prelude::*;
 use std::collections::HashSet;
@@ -32,6 +32,9 @@ fn create_test_entry(name: &str, is_dir: bool, size: u64) -> FileEntry {
         symlink_target: None,
         is_broken_symlink: false,
         is_shared: false,
+        hidden_kind: HiddenKind::Visible,
+        created: None,
+        unix_mode: None,
     }
 }
 
@@ -53,6 +56,9 @@ fn create_entry_with_extension(name: &str, ext: &str, size: u64) -> FileEntry {
         symlink_target: None,
         is_broken_symlink: false,
         is_shared: false,
+        hidden_kind: HiddenKind::Visible,
+        created: None,
+        unix_mode: None,
     }
 }
 
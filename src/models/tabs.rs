@@ -1,6 +1,7 @@
 use crate::models::ViewMode;
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -44,6 +45,8 @@ pub struct Tab {
     pub view_state: TabViewState,
     pub is_loading: bool,
     pub pinned: bool,
+    #[serde(default)]
+    pub search_query: String,
 }
 
 impl Tab {
@@ -59,6 +62,7 @@ impl Tab {
             view_state: TabViewState::default(),
             is_loading: false,
             pinned: false,
+            search_query: String::new(),
         }
     }
 
@@ -85,6 +89,7 @@ impl Tab {
         self.set_path(path);
         self.view_state.scroll_position = 0.0;
         self.view_state.selection = None;
+        self.search_query.clear();
     }
 
     pub fn can_go_back(&self) -> bool {
@@ -150,15 +155,34 @@ impl TabState {
     }
 
 
+    pub fn with_pinned(initial_path: PathBuf, pinned_paths: &[PathBuf]) -> Self {
+        let mut state = Self::new(initial_path);
+        for path in pinned_paths.iter().rev() {
+            let id = TabId::new(state.next_id);
+            state.next_id += 1;
+            let mut tab = Tab::new(id, path.clone());
+            tab.pinned = true;
+            state.tabs.insert(0, tab);
+        }
+        state.active_index = state.tabs.len() - 1;
+        state
+    }
+
+
     pub fn open_tab(&mut self, path: PathBuf) -> TabId {
+        let id = self.open_background_tab(path);
+        self.active_index = self.tabs.len() - 1;
+        id
+    }
+
+
+    pub fn open_background_tab(&mut self, path: PathBuf) -> TabId {
         let id = TabId::new(self.next_id);
         self.next_id += 1;
 
         let tab = Tab::new(id, path);
         self.tabs.push(tab);
 
-        self.active_index = self.tabs.len() - 1;
-
         id
     }
 
@@ -166,6 +190,9 @@ impl TabState {
 
     pub fn close_tab(&mut self, id: TabId) -> bool {
         if let Some(index) = self.tabs.iter().position(|t| t.id == id) {
+            if self.tabs[index].pinned {
+                return false;
+            }
             if self.tabs.len() == 1 {
                 let home = dirs::home_dir().unwrap_or_else(|| PathBuf::from("/"));
                 self.tabs[0].set_path(home);
@@ -281,6 +308,15 @@ impl TabState {
     }
 
 
+    pub fn active_search_query(&self) -> &str {
+        &self.tabs[self.active_index].search_query
+    }
+
+    pub fn set_active_search_query(&mut self, query: &str) {
+        self.tabs[self.active_index].search_query = query.to_string();
+    }
+
+
     pub fn go_back(&mut self) -> Option<PathBuf> {
         self.tabs[self.active_index].go_back()
     }
@@ -323,17 +359,157 @@ impl TabState {
     }
 
 
-    pub fn close_other_tabs(&mut self) {
-        let active_tab = self.tabs.remove(self.active_index);
-        self.tabs.clear();
-        self.tabs.push(active_tab);
-        self.active_index = 0;
+    pub fn move_tab_to_slot(&mut self, id: TabId, slot: usize) -> bool {
+        let Some(from_index) = self.tabs.iter().position(|t| t.id == id) else {
+            return false;
+        };
+        let pinned_count = self.pinned_count();
+        let slot = if self.tabs[from_index].pinned {
+            slot.min(pinned_count)
+        } else {
+            slot.clamp(pinned_count, self.tabs.len())
+        };
+        let to_index = if slot > from_index { slot - 1 } else { slot };
+        if to_index == from_index {
+            return false;
+        }
+
+        let active_id = self.active_tab_id();
+        let tab = self.tabs.remove(from_index);
+        self.tabs.insert(to_index, tab);
+        self.restore_active(active_id);
+        true
+    }
+
+
+    pub fn pinned_count(&self) -> usize {
+        self.tabs.iter().filter(|t| t.pinned).count()
     }
 
 
-    pub fn close_tabs_to_right(&mut self) {
-        self.tabs.truncate(self.active_index + 1);
+    pub fn pinned_paths(&self) -> Vec<PathBuf> {
+        self.tabs
+            .iter()
+            .filter(|t| t.pinned)
+            .map(|t| t.path.clone())
+            .collect()
     }
+
+
+    pub fn set_pinned(&mut self, id: TabId, pinned: bool) -> bool {
+        let Some(index) = self.tabs.iter().position(|t| t.id == id) else {
+            return false;
+        };
+        if self.tabs[index].pinned == pinned {
+            return false;
+        }
+
+        let active_id = self.active_tab_id();
+        let mut tab = self.tabs.remove(index);
+        tab.pinned = pinned;
+        let pinned_count = self.pinned_count();
+        self.tabs.insert(pinned_count, tab);
+        self.restore_active(active_id);
+        true
+    }
+
+
+    pub fn close_other_tabs(&mut self, keep: TabId) -> Vec<TabId> {
+        if self.get_tab(keep).is_none() {
+            return Vec::new();
+        }
+        self.switch_to(keep);
+        self.close_where(|tab| tab.id != keep)
+    }
+
+
+    pub fn close_tabs_to_right(&mut self, anchor: TabId) -> Vec<TabId> {
+        let Some(index) = self.tabs.iter().position(|t| t.id == anchor) else {
+            return Vec::new();
+        };
+        let right: Vec<TabId> = self.tabs[index + 1..].iter().map(|t| t.id).collect();
+        self.close_where(|tab| right.contains(&tab.id))
+    }
+
+
+    pub fn close_unpinned_tabs(&mut self) -> Vec<TabId> {
+        let keep_active = self.pinned_count() == 0;
+        let active_id = self.active_tab_id();
+        self.close_where(|tab| !(keep_active && tab.id == active_id))
+    }
+
+
+    pub fn retain_open<T>(&self, per_tab: &mut HashMap<TabId, T>) {
+        per_tab.retain(|id, _| self.get_tab(*id).is_some());
+    }
+
+    fn close_where(&mut self, should_close: impl Fn(&Tab) -> bool) -> Vec<TabId> {
+        let active_id = self.active_tab_id();
+        let closed: Vec<TabId> = self
+            .tabs
+            .iter()
+            .filter(|tab| !tab.pinned && should_close(tab))
+            .map(|tab| tab.id)
+            .collect();
+        let survivors_before_active = self.tabs[..self.active_index]
+            .iter()
+            .filter(|tab| !closed.contains(&tab.id))
+            .count();
+        self.tabs.retain(|tab| !closed.contains(&tab.id));
+
+        if closed.contains(&active_id) {
+            self.active_index = survivors_before_active.saturating_sub(1);
+        } else {
+            self.restore_active(active_id);
+        }
+        closed
+    }
+
+    fn restore_active(&mut self, active_id: TabId) {
+        self.active_index = self
+            .tabs
+            .iter()
+            .position(|t| t.id == active_id)
+            .unwrap_or(0);
+    }
+}
+
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TabDropTarget {
+    Tab(TabId),
+    NewTab,
+}
+
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TabDropAction {
+    Navigate {
+        tab: TabId,
+        path: PathBuf,
+        select: Option<PathBuf>,
+    },
+    OpenTab {
+        path: PathBuf,
+        select: Option<PathBuf>,
+    },
+}
+
+
+pub fn tab_drop_action(target: TabDropTarget, dropped: &Path) -> Option<TabDropAction> {
+    let (path, select) = if dropped.is_dir() {
+        (dropped.to_path_buf(), None)
+    } else if dropped.exists() {
+        (dropped.parent()?.to_path_buf(), Some(dropped.to_path_buf()))
+    } else {
+        return None;
+    };
+
+    Some(match target {
+        TabDropTarget::Tab(tab) => TabDropAction::Navigate { tab, path, select },
+        TabDropTarget::NewTab => TabDropAction::OpenTab { path, select },
+    })
 }
 
 impl Default for TabState {
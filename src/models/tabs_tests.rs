@@ -1,5 +1,6 @@
 use super::*;
 use proptest::prelude::*;
+use std::collections::HashMap;
 use std::path::PathBuf;
 
 #[test]
@@ -65,6 +66,18 @@ fn test_tab_state_open_tab() {
     assert_eq!(state.active_tab().path, PathBuf::from("/home/user"));
 }
 
+#[test]
+fn test_background_tab_keeps_current_tab_active() {
+    let mut state = TabState::new(PathBuf::from("/home"));
+    let active = state.active_tab_id();
+
+    let new_id = state.open_background_tab(PathBuf::from("/home/projects"));
+
+    assert_eq!(state.tab_count(), 2);
+    assert_eq!(state.active_tab_id(), active);
+    assert_eq!(state.get_tab(new_id).unwrap().path, PathBuf::from("/home/projects"));
+}
+
 #[test]
 fn test_tab_state_close_tab() {
     let mut state = TabState::new(PathBuf::from("/home"));
@@ -174,6 +187,244 @@ fn test_tab_state_get_tab() {
     assert!(state.get_tab(TabId::new(999)).is_none());
 }
 
+fn tab_order(state: &TabState) -> Vec<TabId> {
+    state.tabs().iter().map(|t| t.id).collect()
+}
+
+#[test]
+fn test_move_tab_to_slot_reorders_and_keeps_active() {
+    let mut state = TabState::new(PathBuf::from("/a"));
+    let b = state.open_tab(PathBuf::from("/b"));
+    let c = state.open_tab(PathBuf::from("/c"));
+    let a = TabId::new(0);
+    state.switch_to(b);
+
+    assert!(state.move_tab_to_slot(a, 2));
+    assert_eq!(tab_order(&state), vec![b, a, c]);
+    assert_eq!(state.active_tab_id(), b);
+    assert_eq!(state.active_index(), 0);
+
+    assert!(state.move_tab_to_slot(c, 0));
+    assert_eq!(tab_order(&state), vec![c, b, a]);
+    assert_eq!(state.active_tab_id(), b);
+    assert_eq!(state.active_index(), 1);
+}
+
+#[test]
+fn test_move_tab_to_slot_clamps_past_the_ends() {
+    let mut state = TabState::new(PathBuf::from("/a"));
+    let b = state.open_tab(PathBuf::from("/b"));
+    let c = state.open_tab(PathBuf::from("/c"));
+    let a = TabId::new(0);
+
+    assert!(state.move_tab_to_slot(a, 99));
+    assert_eq!(tab_order(&state), vec![b, c, a]);
+    assert_eq!(state.active_tab_id(), c);
+}
+
+#[test]
+fn test_move_tab_to_own_slot_is_a_no_op() {
+    let mut state = TabState::new(PathBuf::from("/a"));
+    let b = state.open_tab(PathBuf::from("/b"));
+    let a = TabId::new(0);
+
+    assert!(!state.move_tab_to_slot(b, 1));
+    assert!(!state.move_tab_to_slot(b, 2));
+    assert!(!state.move_tab_to_slot(TabId::new(42), 0));
+    assert_eq!(tab_order(&state), vec![a, b]);
+}
+
+#[test]
+fn test_pinned_tabs_survive_close_unpinned() {
+    let mut state = TabState::new(PathBuf::from("/a"));
+    let b = state.open_tab(PathBuf::from("/b"));
+    let c = state.open_tab(PathBuf::from("/c"));
+    let a = TabId::new(0);
+    assert!(state.set_pinned(c, true));
+
+    let closed = state.close_unpinned_tabs();
+    assert_eq!(closed, vec![a, b]);
+    assert_eq!(tab_order(&state), vec![c]);
+    assert_eq!(state.active_tab_id(), c);
+}
+
+#[test]
+fn test_close_others_and_explicit_close_skip_pinned_tabs() {
+    let mut state = TabState::new(PathBuf::from("/a"));
+    let b = state.open_tab(PathBuf::from("/b"));
+    let c = state.open_tab(PathBuf::from("/c"));
+    let a = TabId::new(0);
+    state.set_pinned(a, true);
+
+    assert!(!state.close_tab(a));
+    state.switch_to(a);
+    assert!(!state.close_active_tab());
+
+    assert_eq!(state.close_other_tabs(c), vec![b]);
+    assert_eq!(tab_order(&state), vec![a, c]);
+    assert_eq!(state.active_tab_id(), c);
+}
+
+#[test]
+fn test_pinning_moves_tab_into_pinned_block() {
+    let mut state = TabState::new(PathBuf::from("/a"));
+    let b = state.open_tab(PathBuf::from("/b"));
+    let c = state.open_tab(PathBuf::from("/c"));
+    let a = TabId::new(0);
+
+    state.set_pinned(c, true);
+    state.set_pinned(b, true);
+    assert_eq!(tab_order(&state), vec![c, b, a]);
+    assert_eq!(state.pinned_count(), 2);
+    assert!(!state.move_tab_to_slot(a, 0));
+
+    state.set_pinned(c, false);
+    assert_eq!(tab_order(&state), vec![b, c, a]);
+    assert_eq!(state.pinned_paths(), vec![PathBuf::from("/b")]);
+}
+
+#[test]
+fn test_reordered_pinned_tabs_restore_in_new_order() {
+    let mut state = TabState::new(PathBuf::from("/a"));
+    let b = state.open_tab(PathBuf::from("/b"));
+    let c = state.open_tab(PathBuf::from("/c"));
+    state.set_pinned(b, true);
+    state.set_pinned(c, true);
+    assert_eq!(tab_order(&state)[..2], [b, c]);
+
+    assert!(state.move_tab_to_slot(c, 0));
+    let saved = state.pinned_paths();
+    assert_eq!(saved, vec![PathBuf::from("/c"), PathBuf::from("/b")]);
+
+    let restored = TabState::with_pinned(PathBuf::from("/a"), &saved);
+    assert_eq!(restored.pinned_paths(), saved);
+}
+
+#[test]
+fn test_tabs_keep_separate_search_queries() {
+    let mut state = TabState::new(PathBuf::from("/a"));
+    let a = TabId::new(0);
+    let b = state.open_tab(PathBuf::from("/b"));
+
+    state.set_active_search_query("report");
+    state.switch_to(a);
+    assert_eq!(state.active_search_query(), "");
+    state.set_active_search_query("invoice");
+
+    state.switch_to(b);
+    assert_eq!(state.active_search_query(), "report");
+    state.switch_to(a);
+    assert_eq!(state.active_search_query(), "invoice");
+
+    state.navigate_active_to(PathBuf::from("/a/sub"));
+    assert_eq!(state.active_search_query(), "");
+    state.switch_to(b);
+    assert_eq!(state.active_search_query(), "report");
+}
+
+fn open_terminals(state: &TabState) -> HashMap<TabId, PathBuf> {
+    state.tabs().iter().map(|t| (t.id, t.path.clone())).collect()
+}
+
+fn surviving_terminals(
+    saved: &HashMap<TabId, PathBuf>,
+    state: &TabState,
+) -> HashMap<TabId, PathBuf> {
+    let mut terminals = saved.clone();
+    state.retain_open(&mut terminals);
+    terminals
+}
+
+fn expected_terminals(saved: &HashMap<TabId, PathBuf>, kept: &[TabId]) -> HashMap<TabId, PathBuf> {
+    kept.iter().map(|id| (*id, saved[id].clone())).collect()
+}
+
+#[test]
+fn test_close_others_keeps_target_and_pinned_tabs() {
+    let mut state = TabState::new(PathBuf::from("/a"));
+    let b = state.open_tab(PathBuf::from("/b"));
+    let c = state.open_tab(PathBuf::from("/c"));
+    let d = state.open_tab(PathBuf::from("/d"));
+    let a = TabId::new(0);
+    state.set_pinned(d, true);
+    let saved = open_terminals(&state);
+
+    assert_eq!(state.close_other_tabs(b), vec![a, c]);
+
+    assert_eq!(tab_order(&state), vec![d, b]);
+    assert_eq!(state.active_tab_id(), b);
+    let terminals = surviving_terminals(&saved, &state);
+    assert_eq!(terminals, expected_terminals(&saved, &[b, d]));
+    assert_eq!(terminals[&d], PathBuf::from("/d"));
+}
+
+#[test]
+fn test_close_to_right_activates_anchor_when_active_closes() {
+    let mut state = TabState::new(PathBuf::from("/a"));
+    let b = state.open_tab(PathBuf::from("/b"));
+    let c = state.open_tab(PathBuf::from("/c"));
+    let d = state.open_tab(PathBuf::from("/d"));
+    let a = TabId::new(0);
+    let saved = open_terminals(&state);
+
+    assert_eq!(state.close_tabs_to_right(b), vec![c, d]);
+
+    assert_eq!(tab_order(&state), vec![a, b]);
+    assert_eq!(state.active_tab_id(), b);
+    let terminals = surviving_terminals(&saved, &state);
+    assert_eq!(terminals, expected_terminals(&saved, &[a, b]));
+
+    state.switch_to(a);
+    assert!(state.close_tabs_to_right(b).is_empty());
+    assert_eq!(state.active_tab_id(), a);
+    assert_eq!(surviving_terminals(&terminals, &state), terminals);
+}
+
+#[test]
+fn test_close_all_keeps_pinned_or_active_tab() {
+    let mut state = TabState::new(PathBuf::from("/a"));
+    let b = state.open_tab(PathBuf::from("/b"));
+    let c = state.open_tab(PathBuf::from("/c"));
+    let a = TabId::new(0);
+    state.switch_to(b);
+    let saved = open_terminals(&state);
+
+    assert_eq!(state.close_unpinned_tabs(), vec![a, c]);
+    assert_eq!(tab_order(&state), vec![b]);
+    assert_eq!(state.active_tab_id(), b);
+    let terminals = surviving_terminals(&saved, &state);
+    assert_eq!(terminals, expected_terminals(&saved, &[b]));
+
+    let d = state.open_tab(PathBuf::from("/d"));
+    state.set_pinned(b, true);
+    let saved = open_terminals(&state);
+    assert_eq!(state.close_unpinned_tabs(), vec![d]);
+    assert_eq!(tab_order(&state), vec![b]);
+    let terminals = surviving_terminals(&saved, &state);
+    assert_eq!(terminals, expected_terminals(&saved, &[b]));
+    assert_eq!(terminals[&b], PathBuf::from("/b"));
+}
+
+#[test]
+fn test_with_pinned_restores_pinned_tabs_first() {
+    let state = TabState::with_pinned(
+        PathBuf::from("/home"),
+        &[PathBuf::from("/projects"), PathBuf::from("/notes")],
+    );
+
+    let paths: Vec<PathBuf> = state.tabs().iter().map(|t| t.path.clone()).collect();
+    assert_eq!(
+        paths,
+        vec![
+            PathBuf::from("/projects"),
+            PathBuf::from("/notes"),
+            PathBuf::from("/home")
+        ]
+    );
+    assert_eq!(state.pinned_count(), 2);
+    assert_eq!(state.active_tab().path, PathBuf::from("/home"));
+}
+
 fn arb_path() -> impl Strategy<Value = PathBuf> {
     prop::collection::vec("[a-z]{1,10}", 1..5).prop_map(|parts| {
         let mut path = PathBuf::from("/");
@@ -329,6 +580,49 @@ proptest! {
     }
 }
 
+#[test]
+fn test_dropped_folder_navigates_target_tab() {
+    let temp = tempfile::TempDir::new().unwrap();
+    let folder = temp.path().join("photos");
+    std::fs::create_dir(&folder).unwrap();
+
+    assert_eq!(
+        tab_drop_action(TabDropTarget::Tab(TabId::new(3)), &folder),
+        Some(TabDropAction::Navigate {
+            tab: TabId::new(3),
+            path: folder.clone(),
+            select: None,
+        })
+    );
+    assert_eq!(
+        tab_drop_action(TabDropTarget::NewTab, &folder),
+        Some(TabDropAction::OpenTab {
+            path: folder,
+            select: None,
+        })
+    );
+}
+
+#[test]
+fn test_dropped_file_opens_parent_and_selects_it() {
+    let temp = tempfile::TempDir::new().unwrap();
+    let file = temp.path().join("notes.txt");
+    std::fs::write(&file, "").unwrap();
+
+    assert_eq!(
+        tab_drop_action(TabDropTarget::Tab(TabId::new(0)), &file),
+        Some(TabDropAction::Navigate {
+            tab: TabId::new(0),
+            path: temp.path().to_path_buf(),
+            select: Some(file.clone()),
+        })
+    );
+    assert_eq!(
+        tab_drop_action(TabDropTarget::NewTab, &temp.path().join("missing.txt")),
+        None
+    );
+}
+
 #[derive(Debug, Clone)]
 enum TabOp {
     Open(PathBuf),
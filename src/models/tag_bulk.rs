@@ -0,0 +1,151 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use crate::models::{TagId, TagManager};
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TagCheckState {
+    Checked,
+    Mixed,
+    Unchecked,
+}
+
+
+pub fn tag_check_states(manager: &TagManager, paths: &[PathBuf]) -> HashMap<TagId, TagCheckState> {
+    manager
+        .all_tags()
+        .into_iter()
+        .map(|tag| {
+            let tagged = paths
+                .iter()
+                .filter(|path| manager.has_tag(path, tag.id))
+                .count();
+            let state = if paths.is_empty() || tagged == 0 {
+                TagCheckState::Unchecked
+            } else if tagged == paths.len() {
+                TagCheckState::Checked
+            } else {
+                TagCheckState::Mixed
+            };
+            (tag.id, state)
+        })
+        .collect()
+}
+
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TagDiff {
+    pub add: Vec<TagId>,
+    pub remove: Vec<TagId>,
+}
+
+impl TagDiff {
+    pub fn is_empty(&self) -> bool {
+        self.add.is_empty() && self.remove.is_empty()
+    }
+}
+
+
+#[derive(Debug, Clone)]
+pub struct BulkTagEdit {
+    paths: Vec<PathBuf>,
+    initial: HashMap<TagId, TagCheckState>,
+    current: HashMap<TagId, TagCheckState>,
+}
+
+impl BulkTagEdit {
+    pub fn new(manager: &TagManager, paths: Vec<PathBuf>) -> Self {
+        let initial = tag_check_states(manager, &paths);
+        Self {
+            current: initial.clone(),
+            initial,
+            paths,
+        }
+    }
+
+    pub fn paths(&self) -> &[PathBuf] {
+        &self.paths
+    }
+
+    pub fn state(&self, tag: TagId) -> TagCheckState {
+        self.current
+            .get(&tag)
+            .copied()
+            .unwrap_or(TagCheckState::Unchecked)
+    }
+
+    pub fn toggle(&mut self, tag: TagId) {
+        let was_mixed = self.initial.get(&tag) == Some(&TagCheckState::Mixed);
+        let next = match self.state(tag) {
+            TagCheckState::Mixed => TagCheckState::Checked,
+            TagCheckState::Checked => TagCheckState::Unchecked,
+            TagCheckState::Unchecked if was_mixed => TagCheckState::Mixed,
+            TagCheckState::Unchecked => TagCheckState::Checked,
+        };
+        self.current.insert(tag, next);
+    }
+
+    pub fn diff(&self) -> TagDiff {
+        let mut diff = TagDiff::default();
+        for (tag, state) in &self.current {
+            if self.initial.get(tag) == Some(state) {
+                continue;
+            }
+            match state {
+                TagCheckState::Checked => diff.add.push(*tag),
+                TagCheckState::Unchecked => diff.remove.push(*tag),
+                TagCheckState::Mixed => {}
+            }
+        }
+        diff.add.sort_by_key(|tag| tag.0);
+        diff.remove.sort_by_key(|tag| tag.0);
+        diff
+    }
+
+    pub fn is_dirty(&self) -> bool {
+        !self.diff().is_empty()
+    }
+}
+
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BulkTagReport {
+    pub updated: usize,
+    pub failures: Vec<(PathBuf, String)>,
+}
+
+impl BulkTagReport {
+    pub fn is_partial(&self) -> bool {
+        self.updated > 0 && !self.failures.is_empty()
+    }
+}
+
+
+pub fn apply_tag_diff(manager: &mut TagManager, paths: &[PathBuf], diff: &TagDiff) -> BulkTagReport {
+    let mut report = BulkTagReport::default();
+    for path in paths {
+        match apply_to_path(manager, path, diff) {
+            Ok(()) => report.updated += 1,
+            Err(message) => report.failures.push((path.clone(), message)),
+        }
+    }
+    report
+}
+
+fn apply_to_path(manager: &mut TagManager, path: &Path, diff: &TagDiff) -> Result<(), String> {
+    if std::fs::symlink_metadata(path).is_err() {
+        return Err("File no longer exists".to_string());
+    }
+    for tag in &diff.add {
+        manager
+            .apply_tag_with_xattr(path, *tag)
+            .map_err(|e| e.to_string())?;
+    }
+    for tag in &diff.remove {
+        manager
+            .remove_tag_with_xattr(path, *tag)
+            .map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
@@ -0,0 +1,129 @@
+use super::tag_bulk::*;
+use crate::models::{TagColor, TagId, TagManager};
+use std::fs;
+use std::path::PathBuf;
+use tempfile::TempDir;
+
+
+struct Fixture {
+    _dir: TempDir,
+    manager: TagManager,
+    paths: Vec<PathBuf>,
+    work: TagId,
+    home: TagId,
+    urgent: TagId,
+}
+
+fn fixture() -> Fixture {
+    let dir = TempDir::new().unwrap();
+    let paths: Vec<PathBuf> = ["a.txt", "b.txt", "c.txt"]
+        .iter()
+        .map(|name| {
+            let path = dir.path().join(name);
+            fs::write(&path, b"x").unwrap();
+            path
+        })
+        .collect();
+
+    let mut manager = TagManager::empty();
+    let work = manager.create_tag("Work".to_string(), TagColor::Blue).unwrap();
+    let home = manager.create_tag("Home".to_string(), TagColor::Green).unwrap();
+    let urgent = manager.create_tag("Urgent".to_string(), TagColor::Red).unwrap();
+    for path in &paths {
+        manager.apply_tag(path, work).unwrap();
+    }
+    manager.apply_tag(&paths[0], home).unwrap();
+
+    Fixture {
+        _dir: dir,
+        manager,
+        paths,
+        work,
+        home,
+        urgent,
+    }
+}
+
+#[test]
+fn test_tri_state_reflects_heterogeneous_selection() {
+    let f = fixture();
+    let states = tag_check_states(&f.manager, &f.paths);
+
+    assert_eq!(states[&f.work], TagCheckState::Checked);
+    assert_eq!(states[&f.home], TagCheckState::Mixed);
+    assert_eq!(states[&f.urgent], TagCheckState::Unchecked);
+
+    let empty = tag_check_states(&f.manager, &[]);
+    assert!(empty.values().all(|state| *state == TagCheckState::Unchecked));
+}
+
+#[test]
+fn test_toggling_cycles_and_produces_diff() {
+    let f = fixture();
+    let mut edit = BulkTagEdit::new(&f.manager, f.paths.clone());
+    assert!(!edit.is_dirty());
+
+    edit.toggle(f.home);
+    assert_eq!(edit.state(f.home), TagCheckState::Checked);
+    edit.toggle(f.home);
+    assert_eq!(edit.state(f.home), TagCheckState::Unchecked);
+    edit.toggle(f.home);
+    assert_eq!(edit.state(f.home), TagCheckState::Mixed);
+    assert!(!edit.is_dirty());
+
+    edit.toggle(f.home);
+    edit.toggle(f.work);
+    edit.toggle(f.urgent);
+    assert_eq!(
+        edit.diff(),
+        TagDiff {
+            add: vec![f.home, f.urgent],
+            remove: vec![f.work],
+        }
+    );
+}
+
+#[test]
+fn test_applying_diff_updates_every_selected_file() {
+    let mut f = fixture();
+    let diff = TagDiff {
+        add: vec![f.urgent],
+        remove: vec![f.home],
+    };
+
+    let report = apply_tag_diff(&mut f.manager, &f.paths, &diff);
+
+    assert_eq!(report.updated, 3);
+    assert!(report.failures.is_empty());
+    let states = tag_check_states(&f.manager, &f.paths);
+    assert_eq!(states[&f.work], TagCheckState::Checked);
+    assert_eq!(states[&f.home], TagCheckState::Unchecked);
+    assert_eq!(states[&f.urgent], TagCheckState::Checked);
+}
+
+#[test]
+fn test_partial_failures_are_reported_per_file() {
+    let mut f = fixture();
+    fs::remove_file(&f.paths[1]).unwrap();
+    let diff = TagDiff {
+        add: vec![f.urgent],
+        remove: Vec::new(),
+    };
+
+    let report = apply_tag_diff(&mut f.manager, &f.paths, &diff);
+
+    assert!(report.is_partial());
+    assert_eq!(report.updated, 2);
+    assert_eq!(report.failures.len(), 1);
+    assert_eq!(report.failures[0].0, f.paths[1]);
+    assert!(!f.manager.has_tag(&f.paths[1], f.urgent));
+
+    let missing_tag = TagDiff {
+        add: vec![TagId::new(999)],
+        remove: Vec::new(),
+    };
+    let report = apply_tag_diff(&mut f.manager, &f.paths[..1], &missing_tag);
+    assert_eq!(report.updated, 0);
+    assert!(!report.is_partial());
+    assert_eq!(report.failures.len(), 1);
+}
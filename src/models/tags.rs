@@ -124,6 +124,8 @@ impl Default for TagManager {
     }
 }
 
+impl gpui::Global for TagManager {}
+
 impl TagManager {
 
     pub fn new() -> Self {
@@ -224,6 +226,24 @@ impl TagManager {
     }
 
 
+    pub fn tag_counts(&self) -> Vec<(Tag, usize)> {
+        let mut counts: HashMap<TagId, usize> = HashMap::new();
+        for tag_ids in self.file_tags.values() {
+            for id in tag_ids {
+                *counts.entry(*id).or_default() += 1;
+            }
+        }
+
+        let mut result: Vec<(Tag, usize)> = self
+            .tags
+            .values()
+            .map(|tag| (tag.clone(), counts.get(&tag.id).copied().unwrap_or(0)))
+            .collect();
+        result.sort_by_key(|(tag, _)| tag.id.0);
+        result
+    }
+
+
     pub fn all_tags(&self) -> Vec<&Tag> {
         self.tags.values().collect()
     }
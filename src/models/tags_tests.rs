@@ -197,6 +197,58 @@ fn test_update_file_path() {
     assert!(manager.has_tag(&new_path, id));
 }
 
+#[test]
+fn test_tag_counts_aggregate_files_per_tag() {
+    let mut manager = TagManager::empty();
+
+    let work = manager
+        .create_tag("Work".to_string(), TagColor::Blue)
+        .unwrap();
+    let urgent = manager
+        .create_tag("Urgent".to_string(), TagColor::Red)
+        .unwrap();
+    let unused = manager
+        .create_tag("Unused".to_string(), TagColor::Gray)
+        .unwrap();
+
+    manager.apply_tag(&PathBuf::from("/test/a.txt"), work).unwrap();
+    manager.apply_tag(&PathBuf::from("/test/b.txt"), work).unwrap();
+    manager.apply_tag(&PathBuf::from("/test/b.txt"), urgent).unwrap();
+
+    let counts: Vec<(TagId, usize)> = manager
+        .tag_counts()
+        .into_iter()
+        .map(|(tag, count)| (tag.id, count))
+        .collect();
+    assert_eq!(counts, vec![(work, 2), (urgent, 1), (unused, 0)]);
+}
+
+#[test]
+fn test_tag_counts_follow_delete_and_rename() {
+    let mut manager = TagManager::empty();
+
+    let work = manager
+        .create_tag("Work".to_string(), TagColor::Blue)
+        .unwrap();
+    let home = manager
+        .create_tag("Home".to_string(), TagColor::Green)
+        .unwrap();
+    let path = PathBuf::from("/test/file.txt");
+    manager.apply_tag(&path, work).unwrap();
+    manager.apply_tag(&path, home).unwrap();
+
+    manager.rename_tag(work, "Office".to_string()).unwrap();
+    manager.set_tag_color(work, TagColor::Purple).unwrap();
+    manager.delete_tag(home).unwrap();
+
+    let counts = manager.tag_counts();
+    assert_eq!(counts.len(), 1);
+    assert_eq!(counts[0].0.name, "Office");
+    assert_eq!(counts[0].0.color, TagColor::Purple);
+    assert_eq!(counts[0].1, 1);
+    assert_eq!(manager.tags_for_file(&path)[0].name, "Office");
+}
+
 #[test]
 fn test_default_manager_has_color_tags() {
     let manager = TagManager::new();
@@ -0,0 +1,149 @@
+use std::sync::atomic::{AtomicU8, Ordering};
+
+use gpui::Rgba;
+use serde::{Deserialize, Serialize};
+
+use crate::models::name_tint::mix;
+use crate::models::{color_from_256, theme_colors, ThemeColors, ANSI_COLORS, DEFAULT_FG};
+
+
+const BRIGHT_MIX: f32 = 0.25;
+
+const BRIGHT_BLACK_MIX: f32 = 0.35;
+
+const WHITE: Rgba = Rgba {
+    r: 1.0,
+    g: 1.0,
+    b: 1.0,
+    a: 1.0,
+};
+
+const CLASSIC_BG: Rgba = Rgba {
+    r: 0.0,
+    g: 0.0,
+    b: 0.0,
+    a: 1.0,
+};
+
+static TERMINAL_PALETTE_MODE: AtomicU8 = AtomicU8::new(0);
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum TerminalPaletteMode {
+    #[default]
+    Theme,
+    Classic,
+}
+
+impl TerminalPaletteMode {
+    pub fn label(&self) -> &'static str {
+        match self {
+            TerminalPaletteMode::Theme => "Match theme",
+            TerminalPaletteMode::Classic => "Classic",
+        }
+    }
+
+    pub fn toggle(&self) -> Self {
+        match self {
+            TerminalPaletteMode::Theme => TerminalPaletteMode::Classic,
+            TerminalPaletteMode::Classic => TerminalPaletteMode::Theme,
+        }
+    }
+}
+
+
+pub fn set_terminal_palette_mode(mode: TerminalPaletteMode) {
+    let value = match mode {
+        TerminalPaletteMode::Theme => 0,
+        TerminalPaletteMode::Classic => 1,
+    };
+    TERMINAL_PALETTE_MODE.store(value, Ordering::SeqCst);
+}
+
+
+pub fn terminal_palette_mode() -> TerminalPaletteMode {
+    match TERMINAL_PALETTE_MODE.load(Ordering::SeqCst) {
+        1 => TerminalPaletteMode::Classic,
+        _ => TerminalPaletteMode::Theme,
+    }
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TerminalPalette {
+    pub ansi: [Rgba; 16],
+    pub foreground: Rgba,
+    pub background: Rgba,
+    pub cursor: Rgba,
+    pub selection: Rgba,
+}
+
+impl Default for TerminalPalette {
+    fn default() -> Self {
+        Self::classic()
+    }
+}
+
+impl TerminalPalette {
+    pub fn classic() -> Self {
+        Self {
+            ansi: ANSI_COLORS,
+            foreground: DEFAULT_FG,
+            background: CLASSIC_BG,
+            cursor: DEFAULT_FG,
+            selection: Rgba {
+                r: 0.23,
+                g: 0.51,
+                b: 0.96,
+                a: 0.3,
+            },
+        }
+    }
+
+    pub fn from_theme(colors: &ThemeColors) -> Self {
+        let normal = [
+            colors.terminal_black,
+            colors.terminal_red,
+            colors.terminal_green,
+            colors.terminal_yellow,
+            colors.terminal_blue,
+            colors.terminal_magenta,
+            colors.terminal_cyan,
+            colors.terminal_white,
+        ];
+
+        let mut ansi = [WHITE; 16];
+        for (index, color) in normal.iter().enumerate() {
+            ansi[index] = *color;
+            ansi[index + 8] = mix(*color, WHITE, BRIGHT_MIX);
+        }
+        ansi[8] = mix(colors.terminal_black, colors.terminal_fg, BRIGHT_BLACK_MIX);
+
+        Self {
+            ansi,
+            foreground: colors.terminal_fg,
+            background: colors.terminal_bg,
+            cursor: colors.terminal_cursor,
+            selection: colors.terminal_selection,
+        }
+    }
+
+    pub fn resolve(mode: TerminalPaletteMode, colors: &ThemeColors) -> Self {
+        match mode {
+            TerminalPaletteMode::Theme => Self::from_theme(colors),
+            TerminalPaletteMode::Classic => Self::classic(),
+        }
+    }
+
+    pub fn color(&self, index: usize) -> Rgba {
+        match self.ansi.get(index) {
+            Some(color) => *color,
+            None => color_from_256(index),
+        }
+    }
+}
+
+
+pub fn terminal_palette() -> TerminalPalette {
+    TerminalPalette::resolve(terminal_palette_mode(), &theme_colors())
+}
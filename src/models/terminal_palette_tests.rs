@@ -0,0 +1,56 @@
+use super::terminal_palette::*;
+use crate::models::{AnsiParser, Theme, ThemeId, ANSI_COLORS, DEFAULT_FG};
+
+
+fn palette_for(id: ThemeId) -> TerminalPalette {
+    TerminalPalette::resolve(TerminalPaletteMode::Theme, &Theme::from_id(id).colors)
+}
+
+#[test]
+fn test_theme_palette_uses_theme_terminal_colors() {
+    let colors = Theme::from_id(ThemeId::DragonForge).colors;
+    let palette = palette_for(ThemeId::DragonForge);
+
+    assert_eq!(palette.foreground, colors.terminal_fg);
+    assert_eq!(palette.background, colors.terminal_bg);
+    assert_eq!(palette.color(1), colors.terminal_red);
+    assert_eq!(palette.color(4), colors.terminal_blue);
+    assert_ne!(palette.color(9), colors.terminal_red);
+    assert!(palette.color(9).r >= colors.terminal_red.r);
+}
+
+#[test]
+fn test_switching_themes_updates_default_colors() {
+    let light = palette_for(ThemeId::Light);
+    let dark = palette_for(ThemeId::Dark);
+    assert_ne!(light.foreground, dark.foreground);
+
+    let mut parser = AnsiParser::with_palette(&light);
+    assert_eq!(parser.default_colors(), (light.foreground, light.background));
+    assert_eq!(parser.current_style().foreground, light.foreground);
+
+    parser.set_palette(&dark);
+    assert_eq!(parser.default_colors(), (dark.foreground, dark.background));
+    assert_eq!(parser.current_style().foreground, dark.foreground);
+
+    parser.parse(b"\x1b[31m");
+    assert_eq!(parser.current_style().foreground, dark.color(1));
+    parser.parse(b"\x1b[38;5;12m");
+    assert_eq!(parser.current_style().foreground, dark.color(12));
+    parser.parse(b"\x1b[39m");
+    assert_eq!(parser.current_style().foreground, dark.foreground);
+}
+
+#[test]
+fn test_classic_palette_ignores_theme() {
+    let classic = TerminalPalette::resolve(
+        TerminalPaletteMode::Classic,
+        &Theme::from_id(ThemeId::Light).colors,
+    );
+
+    assert_eq!(classic, TerminalPalette::classic());
+    assert_eq!(classic.ansi, ANSI_COLORS);
+    assert_eq!(classic.foreground, DEFAULT_FG);
+    assert_eq!(TerminalPaletteMode::default(), TerminalPaletteMode::Theme);
+    assert_eq!(TerminalPaletteMode::Theme.toggle(), TerminalPaletteMode::Classic);
+}
@@ -0,0 +1,286 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+
+
+const UTF8_BOM: &[u8] = &[0xEF, 0xBB, 0xBF];
+const UTF16_LE_BOM: &[u8] = &[0xFF, 0xFE];
+const UTF16_BE_BOM: &[u8] = &[0xFE, 0xFF];
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextEncoding {
+    Ascii,
+    Utf8,
+    Utf8Bom,
+    Utf16Le,
+    Utf16Be,
+    Latin1,
+}
+
+impl TextEncoding {
+    pub fn label(&self) -> &'static str {
+        match self {
+            TextEncoding::Ascii => "ASCII",
+            TextEncoding::Utf8 => "UTF-8",
+            TextEncoding::Utf8Bom => "UTF-8 with BOM",
+            TextEncoding::Utf16Le => "UTF-16 LE",
+            TextEncoding::Utf16Be => "UTF-16 BE",
+            TextEncoding::Latin1 => "ISO-8859-1",
+        }
+    }
+
+    fn bom(&self) -> &'static [u8] {
+        match self {
+            TextEncoding::Utf8Bom => UTF8_BOM,
+            TextEncoding::Utf16Le => UTF16_LE_BOM,
+            TextEncoding::Utf16Be => UTF16_BE_BOM,
+            _ => &[],
+        }
+    }
+}
+
+
+pub fn has_bom(bytes: &[u8]) -> bool {
+    bytes.starts_with(UTF8_BOM) || bytes.starts_with(UTF16_LE_BOM) || bytes.starts_with(UTF16_BE_BOM)
+}
+
+
+pub fn detect_encoding(bytes: &[u8]) -> TextEncoding {
+    if bytes.starts_with(UTF8_BOM) {
+        TextEncoding::Utf8Bom
+    } else if bytes.starts_with(UTF16_LE_BOM) {
+        TextEncoding::Utf16Le
+    } else if bytes.starts_with(UTF16_BE_BOM) {
+        TextEncoding::Utf16Be
+    } else if bytes.is_ascii() {
+        TextEncoding::Ascii
+    } else if std::str::from_utf8(bytes).is_ok() {
+        TextEncoding::Utf8
+    } else {
+        TextEncoding::Latin1
+    }
+}
+
+
+pub fn decode_text(bytes: &[u8], encoding: TextEncoding) -> Option<String> {
+    let body = &bytes[encoding.bom().len()..];
+    match encoding {
+        TextEncoding::Ascii | TextEncoding::Utf8 | TextEncoding::Utf8Bom => {
+            String::from_utf8(body.to_vec()).ok()
+        }
+        TextEncoding::Utf16Le | TextEncoding::Utf16Be => {
+            let pairs = body.chunks_exact(2);
+            if !pairs.remainder().is_empty() {
+                return None;
+            }
+            let units: Vec<u16> = pairs
+                .map(|pair| match encoding {
+                    TextEncoding::Utf16Le => u16::from_le_bytes([pair[0], pair[1]]),
+                    _ => u16::from_be_bytes([pair[0], pair[1]]),
+                })
+                .collect();
+            String::from_utf16(&units).ok()
+        }
+        TextEncoding::Latin1 => Some(body.iter().map(|&b| b as char).collect()),
+    }
+}
+
+
+pub fn encode_text(text: &str, encoding: TextEncoding) -> Option<Vec<u8>> {
+    let mut bytes = encoding.bom().to_vec();
+    match encoding {
+        TextEncoding::Ascii | TextEncoding::Utf8 | TextEncoding::Utf8Bom => {
+            bytes.extend_from_slice(text.as_bytes());
+        }
+        TextEncoding::Utf16Le => bytes.extend(text.encode_utf16().flat_map(u16::to_le_bytes)),
+        TextEncoding::Utf16Be => bytes.extend(text.encode_utf16().flat_map(u16::to_be_bytes)),
+        TextEncoding::Latin1 => {
+            for c in text.chars() {
+                bytes.push(u8::try_from(u32::from(c)).ok()?);
+            }
+        }
+    }
+    Some(bytes)
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LineEnding {
+    None,
+    Lf,
+    Crlf,
+    Cr,
+    Mixed,
+}
+
+impl LineEnding {
+    pub fn label(&self) -> &'static str {
+        match self {
+            LineEnding::None => "No line breaks",
+            LineEnding::Lf => "LF",
+            LineEnding::Crlf => "CRLF",
+            LineEnding::Cr => "CR",
+            LineEnding::Mixed => "Mixed",
+        }
+    }
+
+    fn separator(&self) -> Option<&'static str> {
+        match self {
+            LineEnding::Lf => Some("\n"),
+            LineEnding::Crlf => Some("\r\n"),
+            LineEnding::Cr => Some("\r"),
+            LineEnding::None | LineEnding::Mixed => None,
+        }
+    }
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct LineEndingCounts {
+    pub lf: usize,
+    pub crlf: usize,
+    pub cr: usize,
+}
+
+impl LineEndingCounts {
+    pub fn count(text: &str) -> Self {
+        let mut counts = Self::default();
+        let mut bytes = text.bytes().peekable();
+        while let Some(byte) = bytes.next() {
+            match byte {
+                b'\r' if bytes.peek() == Some(&b'\n') => {
+                    bytes.next();
+                    counts.crlf += 1;
+                }
+                b'\r' => counts.cr += 1,
+                b'\n' => counts.lf += 1,
+                _ => {}
+            }
+        }
+        counts
+    }
+
+    pub fn style(&self) -> LineEnding {
+        match (self.lf > 0, self.crlf > 0, self.cr > 0) {
+            (false, false, false) => LineEnding::None,
+            (true, false, false) => LineEnding::Lf,
+            (false, true, false) => LineEnding::Crlf,
+            (false, false, true) => LineEnding::Cr,
+            _ => LineEnding::Mixed,
+        }
+    }
+
+    pub fn summary(&self) -> String {
+        [("LF", self.lf), ("CRLF", self.crlf), ("CR", self.cr)]
+            .into_iter()
+            .filter(|(_, count)| *count > 0)
+            .map(|(label, count)| format!("{} {}", count, label))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TextFormat {
+    pub encoding: TextEncoding,
+    pub line_endings: LineEndingCounts,
+}
+
+impl TextFormat {
+    pub fn of(text: &str, encoding: TextEncoding) -> Self {
+        Self {
+            encoding,
+            line_endings: LineEndingCounts::count(text),
+        }
+    }
+
+    pub fn detect(bytes: &[u8]) -> Option<Self> {
+        let encoding = detect_encoding(bytes);
+        decode_text(bytes, encoding).map(|text| Self::of(&text, encoding))
+    }
+
+    pub fn line_ending(&self) -> LineEnding {
+        self.line_endings.style()
+    }
+
+    pub fn has_mixed_line_endings(&self) -> bool {
+        self.line_ending() == LineEnding::Mixed
+    }
+
+    pub fn label(&self) -> String {
+        match self.line_ending() {
+            LineEnding::None => self.encoding.label().to_string(),
+            style => format!("{} · {}", self.encoding.label(), style.label()),
+        }
+    }
+}
+
+
+pub fn normalize_line_endings(text: &str, target: LineEnding) -> String {
+    let Some(separator) = target.separator() else {
+        return text.to_string();
+    };
+    let mut normalized = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\r' => {
+                if chars.peek() == Some(&'\n') {
+                    chars.next();
+                }
+                normalized.push_str(separator);
+            }
+            '\n' => normalized.push_str(separator),
+            c => normalized.push(c),
+        }
+    }
+    normalized
+}
+
+
+pub fn convert_line_endings(
+    path: &Path,
+    target: LineEnding,
+    expected: TextEncoding,
+) -> io::Result<TextFormat> {
+    let invalid = |message: &str| io::Error::new(io::ErrorKind::InvalidData, message.to_string());
+    if target.separator().is_none() {
+        return Err(invalid("Line endings can only be converted to LF, CRLF or CR"));
+    }
+
+    let bytes = fs::read(path)?;
+    let encoding = detect_encoding(&bytes);
+    if encoding != expected {
+        return Err(invalid("File changed since it was previewed"));
+    }
+    let text = decode_text(&bytes, encoding).ok_or_else(|| invalid("File is not valid text"))?;
+    let converted = normalize_line_endings(&text, target);
+    if converted != text {
+        let encoded = encode_text(&converted, encoding)
+            .ok_or_else(|| invalid("Converted text cannot be written in the original encoding"))?;
+        replace_file_contents(path, &encoded)?;
+    }
+    Ok(TextFormat::of(&converted, encoding))
+}
+
+
+fn replace_file_contents(path: &Path, contents: &[u8]) -> io::Result<()> {
+    let name = path
+        .file_name()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "Path has no file name"))?;
+    let temp = path.with_file_name(format!(
+        ".{}.{}.tmp",
+        name.to_string_lossy(),
+        std::process::id()
+    ));
+
+    let result = fs::write(&temp, contents)
+        .and_then(|_| fs::set_permissions(&temp, fs::metadata(path)?.permissions()))
+        .and_then(|_| fs::rename(&temp, path));
+    if result.is_err() {
+        let _ = fs::remove_file(&temp);
+    }
+    result
+}
@@ -0,0 +1,74 @@
+use super::text_format::*;
+use std::fs;
+
+
+#[test]
+fn test_encoding_detected_from_bom_and_content() {
+    assert_eq!(detect_encoding(b"\xEF\xBB\xBFhello"), TextEncoding::Utf8Bom);
+    assert_eq!(detect_encoding(b"\xFF\xFEh\x00i\x00"), TextEncoding::Utf16Le);
+    assert_eq!(detect_encoding(b"\xFE\xFF\x00h\x00i"), TextEncoding::Utf16Be);
+    assert_eq!(detect_encoding(b"plain text"), TextEncoding::Ascii);
+    assert_eq!(detect_encoding("caf\u{e9}".as_bytes()), TextEncoding::Utf8);
+    assert_eq!(detect_encoding(b"caf\xE9"), TextEncoding::Latin1);
+    assert_eq!(detect_encoding(b""), TextEncoding::Ascii);
+
+    let utf16 = TextFormat::detect(b"\xFF\xFEa\x00\r\x00\n\x00b\x00").unwrap();
+    assert_eq!(utf16.encoding, TextEncoding::Utf16Le);
+    assert_eq!(utf16.line_ending(), LineEnding::Crlf);
+    assert_eq!(decode_text(b"\xEF\xBB\xBFhi", TextEncoding::Utf8Bom).as_deref(), Some("hi"));
+    assert_eq!(decode_text(b"caf\xE9", TextEncoding::Latin1).as_deref(), Some("caf\u{e9}"));
+    assert_eq!(TextFormat::detect(b"\xFF\xFEa"), None);
+}
+
+#[test]
+fn test_line_endings_detected_and_mixed_flagged() {
+    let detect = |bytes: &[u8]| TextFormat::detect(bytes).unwrap();
+
+    assert_eq!(detect(b"a\nb\n").line_ending(), LineEnding::Lf);
+    assert_eq!(detect(b"a\r\nb\r\n").line_ending(), LineEnding::Crlf);
+    assert_eq!(detect(b"a\rb\r").line_ending(), LineEnding::Cr);
+    assert_eq!(detect(b"single line").line_ending(), LineEnding::None);
+
+    let mixed = detect(b"a\r\nb\nc\r\nd");
+    assert!(mixed.has_mixed_line_endings());
+    assert_eq!(mixed.line_endings, LineEndingCounts { lf: 1, crlf: 2, cr: 0 });
+    assert_eq!(mixed.line_endings.summary(), "1 LF, 2 CRLF");
+    assert_eq!(detect(b"a\r\nb\r\n").label(), "ASCII · CRLF");
+}
+
+#[test]
+fn test_convert_line_endings_preserves_encoding() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("notes.txt");
+    fs::write(&path, b"\xEF\xBB\xBFone\r\ntwo\nthree\r").unwrap();
+
+    let format = convert_line_endings(&path, LineEnding::Lf, TextEncoding::Utf8Bom).unwrap();
+    assert_eq!(format.line_ending(), LineEnding::Lf);
+    assert_eq!(fs::read(&path).unwrap(), b"\xEF\xBB\xBFone\ntwo\nthree\n");
+
+    let utf16 = dir.path().join("wide.txt");
+    fs::write(&utf16, encode_text("a\nb", TextEncoding::Utf16Le).unwrap()).unwrap();
+    convert_line_endings(&utf16, LineEnding::Crlf, TextEncoding::Utf16Le).unwrap();
+    let written = fs::read(&utf16).unwrap();
+    assert_eq!(detect_encoding(&written), TextEncoding::Utf16Le);
+    assert_eq!(decode_text(&written, TextEncoding::Utf16Le).as_deref(), Some("a\r\nb"));
+
+    assert!(convert_line_endings(&path, LineEnding::Mixed, TextEncoding::Utf8Bom).is_err());
+}
+
+#[test]
+fn test_convert_line_endings_replaces_file_and_refuses_unexpected_encodings() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("notes.txt");
+    fs::write(&path, b"one\r\ntwo\r\n").unwrap();
+
+    convert_line_endings(&path, LineEnding::Lf, TextEncoding::Ascii).unwrap();
+    assert_eq!(fs::read(&path).unwrap(), b"one\ntwo\n");
+    assert_eq!(fs::read_dir(dir.path()).unwrap().count(), 1);
+
+    let binary = dir.path().join("blob.bin");
+    fs::write(&binary, b"\x00\x9C\r\n\x01").unwrap();
+    let before = fs::read(&binary).unwrap();
+    assert!(convert_line_endings(&binary, LineEnding::Lf, TextEncoding::Ascii).is_err());
+    assert_eq!(fs::read(&binary).unwrap(), before);
+}
@@ -1,4 +1,4 @@
-use gpui::{Global, Rgba};
+use gpui::{hsla, Global, Hsla, Rgba};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -294,6 +294,26 @@ impl ThemeColors {
             && self.terminal_bg.a > 0.0
             && self.terminal_fg.a > 0.0
     }
+
+
+    pub fn with_accent_hue(mut self, hue: f32) -> Self {
+        let hue = hue.rem_euclid(360.0) / 360.0;
+        let primary = Hsla::from(self.accent_primary);
+        let saturation = primary.s.max(ACCENT_MIN_SATURATION);
+
+        self.accent_primary = Rgba::from(hsla(hue, saturation, primary.l, primary.a));
+        self.accent_secondary = Rgba::from(hsla(
+            hue,
+            saturation,
+            (primary.l + ACCENT_SECONDARY_LIGHTEN).min(0.85),
+            primary.a,
+        ));
+        self.accent_glow = Rgba {
+            a: self.accent_glow.a,
+            ..self.accent_primary
+        };
+        self
+    }
 }
 
 impl Theme {
@@ -1207,6 +1227,24 @@ mod tests {
         assert_eq!(themes.len(), 7);
     }
 
+    #[test]
+    fn test_accent_override_only_replaces_accent_tokens() {
+        let base = Theme::dark().colors;
+        let overridden = base.clone().with_accent_hue(120.0);
+
+        let primary = Hsla::from(overridden.accent_primary);
+        assert!((primary.h * 360.0 - 120.0).abs() < 1.0);
+        assert!((Hsla::from(overridden.accent_secondary).h * 360.0 - 120.0).abs() < 1.0);
+        assert_ne!(overridden.accent_primary, base.accent_primary);
+        assert_eq!(overridden.accent_glow.a, base.accent_glow.a);
+
+        let mut restored = overridden.clone();
+        restored.accent_primary = base.accent_primary;
+        restored.accent_secondary = base.accent_secondary;
+        restored.accent_glow = base.accent_glow;
+        assert_eq!(format!("{:?}", restored), format!("{:?}", base));
+    }
+
     #[test]
     fn test_dragon_forge_colors_complete() {
         let theme = Theme::dragon_forge();
@@ -1992,12 +2030,24 @@ impl ThemeColors {
     }
 }
 
-use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::atomic::{AtomicU32, AtomicU8, Ordering};
 
 
 static CURRENT_THEME_ID: AtomicU8 = AtomicU8::new(1);
 
 
+static ACCENT_HUE_BITS: AtomicU32 = AtomicU32::new(NO_ACCENT_OVERRIDE);
+
+const NO_ACCENT_OVERRIDE: u32 = u32::MAX;
+
+pub const ACCENT_HUE_PRESETS: [f32; 10] =
+    [0.0, 24.0, 45.0, 90.0, 150.0, 180.0, 210.0, 240.0, 275.0, 320.0];
+
+const ACCENT_MIN_SATURATION: f32 = 0.45;
+
+const ACCENT_SECONDARY_LIGHTEN: f32 = 0.12;
+
+
 fn theme_id_to_u8(id: ThemeId) -> u8 {
     match id {
         ThemeId::Light => 0,
@@ -2035,8 +2085,28 @@ pub fn current_theme_id() -> ThemeId {
 }
 
 
+pub fn set_accent_override(hue: Option<f32>) {
+    let bits = hue
+        .filter(|hue| hue.is_finite())
+        .map_or(NO_ACCENT_OVERRIDE, |hue| hue.rem_euclid(360.0).to_bits());
+    ACCENT_HUE_BITS.store(bits, Ordering::SeqCst);
+}
+
+
+pub fn accent_override() -> Option<f32> {
+    match ACCENT_HUE_BITS.load(Ordering::SeqCst) {
+        NO_ACCENT_OVERRIDE => None,
+        bits => Some(f32::from_bits(bits)),
+    }
+}
+
+
 pub fn current_theme() -> Theme {
-    Theme::from_id(current_theme_id())
+    let mut theme = Theme::from_id(current_theme_id());
+    if let Some(hue) = accent_override() {
+        theme.colors = theme.colors.with_accent_hue(hue);
+    }
+    theme
 }
 
 
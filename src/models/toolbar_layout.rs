@@ -0,0 +1,193 @@
+use serde::{Deserialize, Serialize};
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ToolbarButton {
+    Back,
+    Forward,
+    GoToParent,
+    PathBar,
+    NewFolder,
+    Refresh,
+    ToggleHidden,
+    Terminal,
+    Copy,
+    Trash,
+    Flatten,
+    ViewToggle,
+}
+
+impl ToolbarButton {
+    pub const ALL: [ToolbarButton; 12] = [
+        ToolbarButton::Back,
+        ToolbarButton::Forward,
+        ToolbarButton::GoToParent,
+        ToolbarButton::PathBar,
+        ToolbarButton::NewFolder,
+        ToolbarButton::Refresh,
+        ToolbarButton::ToggleHidden,
+        ToolbarButton::Terminal,
+        ToolbarButton::Copy,
+        ToolbarButton::Trash,
+        ToolbarButton::Flatten,
+        ToolbarButton::ViewToggle,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            ToolbarButton::Back => "Back",
+            ToolbarButton::Forward => "Forward",
+            ToolbarButton::GoToParent => "Go to Parent",
+            ToolbarButton::PathBar => "Path Bar",
+            ToolbarButton::NewFolder => "New Folder",
+            ToolbarButton::Refresh => "Refresh",
+            ToolbarButton::ToggleHidden => "Toggle Hidden Files",
+            ToolbarButton::Terminal => "Terminal",
+            ToolbarButton::Copy => "Copy",
+            ToolbarButton::Trash => "Move to Trash",
+            ToolbarButton::Flatten => "Flatten View",
+            ToolbarButton::ViewToggle => "View Mode",
+        }
+    }
+
+    pub fn icon(self) -> &'static str {
+        match self {
+            ToolbarButton::Back => "arrow-left",
+            ToolbarButton::Forward => "arrow-right",
+            ToolbarButton::GoToParent => "arrow-up",
+            ToolbarButton::PathBar => "folder",
+            ToolbarButton::NewFolder => "folder-plus",
+            ToolbarButton::Refresh => "refresh-cw",
+            ToolbarButton::ToggleHidden => "eye",
+            ToolbarButton::Terminal => "terminal",
+            ToolbarButton::Copy => "copy",
+            ToolbarButton::Trash => "trash-2",
+            ToolbarButton::Flatten => "files",
+            ToolbarButton::ViewToggle => "grid-2x2",
+        }
+    }
+
+    pub fn is_removable(self) -> bool {
+        self != ToolbarButton::PathBar
+    }
+}
+
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "Vec<ToolbarButton>", into = "Vec<ToolbarButton>")]
+pub struct ToolbarLayout {
+    buttons: Vec<ToolbarButton>,
+}
+
+impl Default for ToolbarLayout {
+    fn default() -> Self {
+        Self {
+            buttons: vec![
+                ToolbarButton::Back,
+                ToolbarButton::Forward,
+                ToolbarButton::PathBar,
+                ToolbarButton::Terminal,
+                ToolbarButton::Copy,
+                ToolbarButton::Trash,
+                ToolbarButton::Flatten,
+                ToolbarButton::ViewToggle,
+            ],
+        }
+    }
+}
+
+impl From<Vec<ToolbarButton>> for ToolbarLayout {
+    fn from(buttons: Vec<ToolbarButton>) -> Self {
+        let mut layout = Self {
+            buttons: Vec::with_capacity(buttons.len()),
+        };
+        for button in buttons {
+            if !layout.contains(button) {
+                layout.buttons.push(button);
+            }
+        }
+        if !layout.contains(ToolbarButton::PathBar) {
+            layout.buttons.insert(0, ToolbarButton::PathBar);
+        }
+        layout
+    }
+}
+
+impl From<ToolbarLayout> for Vec<ToolbarButton> {
+    fn from(layout: ToolbarLayout) -> Self {
+        layout.buttons
+    }
+}
+
+impl ToolbarLayout {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn buttons(&self) -> &[ToolbarButton] {
+        &self.buttons
+    }
+
+    pub fn contains(&self, button: ToolbarButton) -> bool {
+        self.buttons.contains(&button)
+    }
+
+    fn path_bar_index(&self) -> usize {
+        self.buttons
+            .iter()
+            .position(|b| *b == ToolbarButton::PathBar)
+            .unwrap_or(0)
+    }
+
+    pub fn leading(&self) -> &[ToolbarButton] {
+        &self.buttons[..self.path_bar_index()]
+    }
+
+    pub fn trailing(&self) -> &[ToolbarButton] {
+        &self.buttons[self.path_bar_index() + 1..]
+    }
+
+    pub fn available(&self) -> Vec<ToolbarButton> {
+        ToolbarButton::ALL
+            .into_iter()
+            .filter(|button| !self.contains(*button))
+            .collect()
+    }
+
+    pub fn add(&mut self, button: ToolbarButton) -> bool {
+        if self.contains(button) {
+            return false;
+        }
+        self.buttons.push(button);
+        true
+    }
+
+    pub fn remove(&mut self, button: ToolbarButton) -> bool {
+        if !button.is_removable() || !self.contains(button) {
+            return false;
+        }
+        self.buttons.retain(|b| *b != button);
+        true
+    }
+
+    pub fn move_by(&mut self, button: ToolbarButton, offset: isize) -> bool {
+        let Some(index) = self.buttons.iter().position(|b| *b == button) else {
+            return false;
+        };
+        let target = index as isize + offset;
+        if offset == 0 || target < 0 || target as usize >= self.buttons.len() {
+            return false;
+        }
+        let item = self.buttons.remove(index);
+        self.buttons.insert(target as usize, item);
+        true
+    }
+
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
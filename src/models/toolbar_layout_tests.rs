@@ -0,0 +1,80 @@
+use super::toolbar_layout::*;
+
+
+#[test]
+fn test_default_layout_splits_around_path_bar() {
+    let layout = ToolbarLayout::new();
+    assert_eq!(layout.leading(), &[ToolbarButton::Back, ToolbarButton::Forward]);
+    assert_eq!(
+        layout.trailing(),
+        &[
+            ToolbarButton::Terminal,
+            ToolbarButton::Copy,
+            ToolbarButton::Trash,
+            ToolbarButton::Flatten,
+            ToolbarButton::ViewToggle,
+        ]
+    );
+    assert_eq!(
+        layout.available(),
+        vec![
+            ToolbarButton::GoToParent,
+            ToolbarButton::NewFolder,
+            ToolbarButton::Refresh,
+            ToolbarButton::ToggleHidden,
+        ]
+    );
+}
+
+#[test]
+fn test_configured_buttons_render_in_order() {
+    let mut layout = ToolbarLayout::new();
+    assert!(layout.add(ToolbarButton::Refresh));
+    assert!(!layout.add(ToolbarButton::Refresh));
+    assert!(layout.remove(ToolbarButton::Copy));
+    assert!(layout.remove(ToolbarButton::Flatten));
+    assert!(!layout.remove(ToolbarButton::PathBar));
+    assert!(layout.move_by(ToolbarButton::Refresh, -3));
+    assert!(layout.move_by(ToolbarButton::Terminal, -3));
+
+    assert_eq!(
+        layout.leading(),
+        &[ToolbarButton::Back, ToolbarButton::Terminal, ToolbarButton::Forward]
+    );
+    assert_eq!(
+        layout.trailing(),
+        &[ToolbarButton::Refresh, ToolbarButton::Trash, ToolbarButton::ViewToggle]
+    );
+    assert!(!layout.move_by(ToolbarButton::Back, -1));
+    assert!(!layout.move_by(ToolbarButton::NewFolder, 1));
+
+    layout.reset();
+    assert!(layout.is_default());
+}
+
+#[test]
+fn test_layout_serializes_as_ordered_list() {
+    let mut layout = ToolbarLayout::new();
+    layout.remove(ToolbarButton::Back);
+    layout.remove(ToolbarButton::Forward);
+    layout.add(ToolbarButton::NewFolder);
+
+    let json = serde_json::to_string(&layout).unwrap();
+    assert_eq!(
+        json,
+        r#"["PathBar","Terminal","Copy","Trash","Flatten","ViewToggle","NewFolder"]"#
+    );
+    let restored: ToolbarLayout = serde_json::from_str(&json).unwrap();
+    assert_eq!(restored, layout);
+    assert!(restored.leading().is_empty());
+}
+
+#[test]
+fn test_malformed_layout_is_repaired_on_load() {
+    let restored: ToolbarLayout =
+        serde_json::from_str(r#"["Refresh","Refresh","Trash"]"#).unwrap();
+    assert_eq!(
+        restored.buttons(),
+        &[ToolbarButton::PathBar, ToolbarButton::Refresh, ToolbarButton::Trash]
+    );
+}
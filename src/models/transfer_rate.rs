@@ -0,0 +1,62 @@
+use std::collections::VecDeque;
+use std::time::Duration;
+
+
+pub const TRANSFER_RATE_WINDOW: Duration = Duration::from_secs(3);
+
+const MIN_RATE_SAMPLES: usize = 3;
+const MIN_RATE_SPAN: Duration = Duration::from_millis(500);
+
+
+#[derive(Debug, Clone, Default)]
+pub struct TransferRate {
+    samples: VecDeque<(Duration, u64)>,
+}
+
+impl TransferRate {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record(&mut self, elapsed: Duration, transferred_bytes: u64) {
+        if self.samples.back().is_some_and(|&(last, _)| elapsed < last) {
+            self.samples.clear();
+        }
+        self.samples.push_back((elapsed, transferred_bytes));
+
+        while self.samples.len() > MIN_RATE_SAMPLES
+            && elapsed.saturating_sub(self.samples[1].0) >= TRANSFER_RATE_WINDOW
+        {
+            self.samples.pop_front();
+        }
+    }
+
+
+    pub fn bytes_per_sec(&self) -> Option<u64> {
+        if self.samples.len() < MIN_RATE_SAMPLES {
+            return None;
+        }
+        let (start, start_bytes) = *self.samples.front()?;
+        let (end, end_bytes) = *self.samples.back()?;
+        let span = end.saturating_sub(start);
+        if span < MIN_RATE_SPAN {
+            return None;
+        }
+        Some((end_bytes.saturating_sub(start_bytes) as f64 / span.as_secs_f64()) as u64)
+    }
+
+    pub fn estimate_remaining(&self, remaining_bytes: u64) -> Option<Duration> {
+        match self.bytes_per_sec()? {
+            0 => None,
+            rate => Some(Duration::from_secs_f64(remaining_bytes as f64 / rate as f64)),
+        }
+    }
+
+    pub fn is_calculating(&self) -> bool {
+        self.bytes_per_sec().is_none()
+    }
+
+    pub fn reset(&mut self) {
+        self.samples.clear();
+    }
+}
@@ -0,0 +1,69 @@
+use super::file_operations::*;
+use super::transfer_rate::*;
+use std::time::Duration;
+
+const MB: u64 = 1024 * 1024;
+
+
+fn ms(millis: u64) -> Duration {
+    Duration::from_millis(millis)
+}
+
+#[test]
+fn test_rate_is_calculating_until_enough_samples() {
+    let mut rate = TransferRate::new();
+    assert!(rate.is_calculating());
+
+    rate.record(ms(100), MB);
+    rate.record(ms(200), 2 * MB);
+    assert!(rate.is_calculating());
+    assert_eq!(rate.estimate_remaining(10 * MB), None);
+
+    rate.record(ms(300), 3 * MB);
+    assert!(rate.is_calculating(), "samples must span enough time");
+
+    rate.record(ms(1100), 11 * MB);
+    assert_eq!(rate.bytes_per_sec(), Some(10 * MB));
+}
+
+#[test]
+fn test_rate_is_smoothed_over_recent_window() {
+    let mut rate = TransferRate::new();
+    let mut transferred = 0;
+    for tick in 1..=20u64 {
+        transferred += if tick % 2 == 0 { 15 * MB } else { 5 * MB };
+        rate.record(ms(tick * 500), transferred);
+    }
+
+    let speed = rate.bytes_per_sec().unwrap();
+    assert!((19 * MB..=21 * MB).contains(&speed), "jittery series should average out, got {}", speed);
+
+    for tick in 21..=30u64 {
+        transferred += 2 * MB;
+        rate.record(ms(tick * 500), transferred);
+    }
+    assert_eq!(rate.bytes_per_sec(), Some(4 * MB), "old samples should fall out of the window");
+}
+
+#[test]
+fn test_eta_follows_remaining_bytes() {
+    let mut progress = OperationProgress::new(1, 100 * MB);
+    for second in 1..=4u64 {
+        progress.record_transfer(second * 10 * MB, Duration::from_secs(second));
+    }
+
+    assert!(!progress.is_calculating_rate());
+    assert_eq!(progress.speed_bytes_per_sec, 10 * MB);
+    assert_eq!(progress.estimated_remaining, Duration::from_secs(6));
+}
+
+#[test]
+fn test_stalled_transfer_has_no_eta() {
+    let mut rate = TransferRate::new();
+    for second in 1..=4u64 {
+        rate.record(Duration::from_secs(second), 5 * MB);
+    }
+
+    assert_eq!(rate.bytes_per_sec(), Some(0));
+    assert_eq!(rate.estimate_remaining(MB), None);
+}
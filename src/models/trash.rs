@@ -1,11 +1,36 @@
-use crate::models::{CloudSyncStatus, FileEntry, FileType, IconKey};
-use std::path::PathBuf;
+use crate::models::{CloudSyncStatus, FileEntry, FileType, HiddenKind, IconKey};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
 use std::time::SystemTime;
 
 #[cfg(target_os = "macos")]
 use std::process::Command;
 
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum TrashLocation {
+    #[default]
+    PerVolume,
+    Home,
+}
+
+impl TrashLocation {
+    pub fn label(&self) -> &'static str {
+        match self {
+            TrashLocation::PerVolume => "Trash on each volume",
+            TrashLocation::Home => "Home trash only",
+        }
+    }
+
+    pub fn toggle(&self) -> Self {
+        match self {
+            TrashLocation::PerVolume => TrashLocation::Home,
+            TrashLocation::Home => TrashLocation::PerVolume,
+        }
+    }
+}
+
+
 #[derive(Debug, Clone)]
 pub struct TrashEntry {
 
@@ -143,6 +168,22 @@ impl Default for TrashManager {
 }
 
 
+pub fn move_path_to_trash(path: &Path, location: TrashLocation) -> Result<(), TrashError> {
+    #[cfg(target_os = "linux")]
+    {
+        let home_trash = super::home_trash_dir();
+        let trash_root = super::trash_dir_for(path, location, &home_trash, super::current_uid());
+        if super::move_to_trash_dir(path, &trash_root).is_ok() {
+            return Ok(());
+        }
+    }
+    #[cfg(not(target_os = "linux"))]
+    let _ = location;
+
+    trash::delete(path).map_err(|e| TrashError::IoError(e.to_string()))
+}
+
+
 pub fn list_trash_entries() -> Vec<TrashEntry> {
     #[cfg(target_os = "macos")]
     {
@@ -225,7 +266,8 @@ fn list_trash_entries_linux() -> Vec<TrashEntry> {
         for item in items {
             let name = item.name.to_string_lossy().to_string();
             let original_path = item.original_parent.join(&item.name);
-            let item_path = PathBuf::from(&item.id);
+            let info_path = PathBuf::from(&item.id);
+            let item_path = super::trashed_path_for_info(&info_path).unwrap_or(info_path);
             
             let is_dir = std::fs::metadata(&item_path)
                 .map(|m| m.is_dir())
@@ -323,29 +365,31 @@ pub fn calculate_dir_size(path: &PathBuf) -> u64 {
 
 
 pub fn restore_from_trash(entry: &TrashEntry) -> Result<PathBuf, TrashError> {
-    if let Some(parent) = entry.original_path.parent() {
+    let original_path = recorded_original_path(entry);
+    if let Some(parent) = original_path.parent() {
         if !parent.exists() {
             std::fs::create_dir_all(parent)
                 .map_err(|e| TrashError::IoError(format!("Failed to create directory: {}", e)))?;
         }
     }
     
-    if entry.original_path.exists() {
+    if original_path.exists() {
         return Err(TrashError::IoError(format!(
             "File already exists at: {}",
-            entry.original_path.display()
+            original_path.display()
         )));
     }
     
     match &entry.trash_id {
         TrashId::Path(trash_path) => {
-            std::fs::rename(trash_path, &entry.original_path)
+            std::fs::rename(trash_path, &original_path)
                 .map_err(|e| TrashError::IoError(format!("Failed to restore: {}", e)))?;
+            remove_trash_info(trash_path);
         }
         #[cfg(target_os = "windows")]
         TrashId::Windows(_id) => {
             if let TrashId::Path(trash_path) = &entry.trash_id {
-                std::fs::rename(trash_path, &entry.original_path)
+                std::fs::rename(trash_path, &original_path)
                     .map_err(|e| TrashError::IoError(format!("Failed to restore: {}", e)))?;
             } else {
                 return Err(TrashError::PlatformError(
@@ -355,7 +399,31 @@ pub fn restore_from_trash(entry: &TrashEntry) -> Result<PathBuf, TrashError> {
         }
     }
     
-    Ok(entry.original_path.clone())
+    Ok(original_path)
+}
+
+
+fn recorded_original_path(entry: &TrashEntry) -> PathBuf {
+    #[cfg(target_os = "linux")]
+    {
+        let TrashId::Path(trash_path) = &entry.trash_id;
+        if let Some(info) = super::trash_info_path(trash_path)
+            .and_then(|info_path| super::read_trash_info(&info_path).ok())
+        {
+            return info.original_path;
+        }
+    }
+    entry.original_path.clone()
+}
+
+
+fn remove_trash_info(trash_path: &Path) {
+    #[cfg(target_os = "linux")]
+    if let Some(info_path) = super::trash_info_path(trash_path) {
+        let _ = std::fs::remove_file(info_path);
+    }
+    #[cfg(not(target_os = "linux"))]
+    let _ = trash_path;
 }
 
 
@@ -369,6 +437,7 @@ fn delete_from_trash(entry: &TrashEntry) -> Result<(), TrashError> {
                 std::fs::remove_file(trash_path)
                     .map_err(|e| TrashError::IoError(format!("Failed to delete: {}", e)))?;
             }
+            remove_trash_info(trash_path);
         }
         #[cfg(target_os = "windows")]
         TrashId::Windows(id) => {
@@ -538,7 +607,8 @@ fn list_trash_linux() -> Vec<FileEntry> {
         for item in items {
             let name = item.name.to_string_lossy().to_string();
             let path = item.original_parent.join(&item.name);
-            let is_dir = std::fs::metadata(&item.id)
+            let info_path = PathBuf::from(&item.id);
+            let is_dir = std::fs::metadata(super::trashed_path_for_info(&info_path).unwrap_or(info_path))
                 .map(|m| m.is_dir())
                 .unwrap_or(false);
 
@@ -578,6 +648,9 @@ fn list_trash_linux() -> Vec<FileEntry> {
                 symlink_target: None,
                 is_broken_symlink: false,
                 is_shared: false,
+                hidden_kind: HiddenKind::Visible,
+                created: None,
+                unix_mode: None,
             });
         }
     }
@@ -641,6 +714,9 @@ fn list_trash_windows() -> Vec<FileEntry> {
                 symlink_target: None,
                 is_broken_symlink: false,
                 is_shared: false,
+                hidden_kind: HiddenKind::Visible,
+                created: None,
+                unix_mode: None,
             });
         }
     }
@@ -693,6 +769,9 @@ fn create_entry_from_path(path: &PathBuf) -> Option<FileEntry> {
         symlink_target: None,
         is_broken_symlink: false,
         is_shared: false,
+        hidden_kind: HiddenKind::Visible,
+        created: None,
+        unix_mode: None,
     })
 }
 
@@ -0,0 +1,302 @@
+use std::ffi::{CString, OsStr, OsString};
+use std::fs::{self, DirBuilder, OpenOptions};
+use std::io::{ErrorKind, Write};
+use std::os::unix::ffi::OsStrExt;
+use std::os::unix::fs::{DirBuilderExt, MetadataExt, PermissionsExt};
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use chrono::{DateTime, Local, NaiveDateTime, TimeZone};
+
+use crate::models::{TrashError, TrashLocation};
+
+const STICKY_BIT: u32 = 0o1000;
+const TRASH_INFO_EXTENSION: &str = "trashinfo";
+const TRASH_INFO_DATE_FORMAT: &str = "%Y-%m-%dT%H:%M:%S";
+
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrashInfo {
+    pub original_path: PathBuf,
+    pub deletion_date: Option<SystemTime>,
+}
+
+
+pub fn home_trash_dir() -> PathBuf {
+    dirs::data_local_dir()
+        .map(|d| d.join("Trash"))
+        .unwrap_or_else(|| {
+            dirs::home_dir()
+                .map(|h| h.join(".local/share/Trash"))
+                .unwrap_or_else(|| PathBuf::from("/tmp/Trash"))
+        })
+}
+
+
+pub fn current_uid() -> u32 {
+    unsafe { libc::getuid() }
+}
+
+
+pub fn trash_dir_for(
+    path: &Path,
+    location: TrashLocation,
+    home_trash: &Path,
+    uid: u32,
+) -> PathBuf {
+    if location == TrashLocation::Home || same_device(path, home_trash) {
+        return home_trash.to_path_buf();
+    }
+    mount_point(path)
+        .and_then(|topdir| volume_trash_dir(&topdir, uid))
+        .unwrap_or_else(|| home_trash.to_path_buf())
+}
+
+
+pub fn volume_trash_dir(topdir: &Path, uid: u32) -> Option<PathBuf> {
+    let shared = topdir.join(".Trash");
+    if let Ok(metadata) = fs::symlink_metadata(&shared) {
+        let usable = metadata.is_dir()
+            && !metadata.file_type().is_symlink()
+            && metadata.permissions().mode() & STICKY_BIT != 0;
+        if usable {
+            let dir = shared.join(uid.to_string());
+            if prepare_trash_dir(&dir) {
+                return Some(dir);
+            }
+        }
+    }
+
+    let dir = topdir.join(format!(".Trash-{}", uid));
+    prepare_trash_dir(&dir).then_some(dir)
+}
+
+
+pub fn mount_point(path: &Path) -> Option<PathBuf> {
+    let mut current = existing_ancestor(path)?;
+    let device = device_of(&current)?;
+    while let Some(parent) = current.parent() {
+        if device_of(parent) != Some(device) {
+            break;
+        }
+        current = parent.to_path_buf();
+    }
+    Some(current)
+}
+
+
+pub fn same_device(a: &Path, b: &Path) -> bool {
+    let device = |path: &Path| existing_ancestor(path).and_then(|p| device_of(&p));
+    match (device(a), device(b)) {
+        (Some(a), Some(b)) => a == b,
+        _ => false,
+    }
+}
+
+
+pub fn move_to_trash_dir(path: &Path, trash_root: &Path) -> Result<PathBuf, TrashError> {
+    let name = path
+        .file_name()
+        .ok_or_else(|| TrashError::NotFound(path.display().to_string()))?;
+    let files_dir = trash_root.join("files");
+    let info_dir = trash_root.join("info");
+    let contents = format_trash_info(path, Local::now());
+
+    let mut attempt = 0u32;
+    loop {
+        attempt += 1;
+        let mut candidate = OsString::from(name);
+        if attempt > 1 {
+            candidate.push(format!(".{}", attempt));
+        }
+        let target = files_dir.join(&candidate);
+        let mut info_name = candidate.clone();
+        info_name.push(format!(".{}", TRASH_INFO_EXTENSION));
+        let info_path = info_dir.join(info_name);
+
+        let mut file = match OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&info_path)
+        {
+            Ok(file) => file,
+            Err(e) if e.kind() == ErrorKind::AlreadyExists => continue,
+            Err(e) => return Err(io_error(e)),
+        };
+        if fs::symlink_metadata(&target).is_ok() {
+            let _ = fs::remove_file(&info_path);
+            continue;
+        }
+
+        let moved = file
+            .write_all(contents.as_bytes())
+            .and_then(|_| fs::rename(path, &target));
+        if let Err(e) = moved {
+            let _ = fs::remove_file(&info_path);
+            return Err(io_error(e));
+        }
+        return Ok(target);
+    }
+}
+
+
+pub fn trash_info_path(trashed: &Path) -> Option<PathBuf> {
+    let files_dir = trashed.parent()?;
+    if files_dir.file_name()? != "files" {
+        return None;
+    }
+    let mut info_name = trashed.file_name()?.to_os_string();
+    info_name.push(format!(".{}", TRASH_INFO_EXTENSION));
+    Some(files_dir.parent()?.join("info").join(info_name))
+}
+
+
+pub fn trashed_path_for_info(info_path: &Path) -> Option<PathBuf> {
+    let info_dir = info_path.parent()?;
+    if info_dir.file_name()? != "info" || info_path.extension()? != TRASH_INFO_EXTENSION {
+        return None;
+    }
+    let name = info_path.file_stem()?;
+    Some(info_dir.parent()?.join("files").join(name))
+}
+
+
+pub fn read_trash_info(info_path: &Path) -> Result<TrashInfo, TrashError> {
+    let contents = fs::read_to_string(info_path).map_err(io_error)?;
+    let topdir = info_path
+        .parent()
+        .and_then(Path::parent)
+        .and_then(trash_topdir);
+    parse_trash_info(&contents, topdir.as_deref()).ok_or_else(|| {
+        TrashError::PlatformError(format!("Invalid trash info: {}", info_path.display()))
+    })
+}
+
+
+pub fn format_trash_info(original: &Path, deleted: DateTime<Local>) -> String {
+    format!(
+        "[Trash Info]\nPath={}\nDeletionDate={}\n",
+        encode_trash_path(original),
+        deleted.format(TRASH_INFO_DATE_FORMAT)
+    )
+}
+
+
+pub fn parse_trash_info(contents: &str, topdir: Option<&Path>) -> Option<TrashInfo> {
+    let mut lines = contents.lines().map(str::trim).skip_while(|l| l.is_empty());
+    if lines.next()? != "[Trash Info]" {
+        return None;
+    }
+
+    let mut original_path = None;
+    let mut deletion_date = None;
+    for line in lines {
+        if line.starts_with('[') {
+            break;
+        }
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+        match key.trim() {
+            "Path" => {
+                let path = PathBuf::from(OsStr::from_bytes(&decode_trash_path(value.trim())?));
+                original_path = Some(match topdir {
+                    Some(topdir) if path.is_relative() => topdir.join(path),
+                    _ => path,
+                });
+            }
+            "DeletionDate" => {
+                deletion_date = NaiveDateTime::parse_from_str(value.trim(), TRASH_INFO_DATE_FORMAT)
+                    .ok()
+                    .and_then(|date| Local.from_local_datetime(&date).earliest())
+                    .map(SystemTime::from);
+            }
+            _ => {}
+        }
+    }
+
+    Some(TrashInfo {
+        original_path: original_path?,
+        deletion_date,
+    })
+}
+
+
+fn trash_topdir(trash_root: &Path) -> Option<PathBuf> {
+    let parent = trash_root.parent()?;
+    let name = trash_root.file_name()?.to_string_lossy();
+    if parent.file_name().is_some_and(|n| n == ".Trash") {
+        parent.parent().map(Path::to_path_buf)
+    } else if name.starts_with(".Trash-") {
+        Some(parent.to_path_buf())
+    } else {
+        None
+    }
+}
+
+fn encode_trash_path(path: &Path) -> String {
+    let mut encoded = String::new();
+    for &byte in path.as_os_str().as_bytes() {
+        if byte.is_ascii_alphanumeric() || b"/-_.~".contains(&byte) {
+            encoded.push(byte as char);
+        } else {
+            encoded.push_str(&format!("%{:02X}", byte));
+        }
+    }
+    encoded
+}
+
+fn decode_trash_path(value: &str) -> Option<Vec<u8>> {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+    while index < bytes.len() {
+        if bytes[index] == b'%' {
+            let hex = std::str::from_utf8(bytes.get(index + 1..index + 3)?).ok()?;
+            decoded.push(u8::from_str_radix(hex, 16).ok()?);
+            index += 3;
+        } else {
+            decoded.push(bytes[index]);
+            index += 1;
+        }
+    }
+    Some(decoded)
+}
+
+fn prepare_trash_dir(root: &Path) -> bool {
+    let dirs = [root.to_path_buf(), root.join("files"), root.join("info")];
+    let created = dirs.iter().all(|dir| {
+        dir.is_dir()
+            || DirBuilder::new()
+                .recursive(true)
+                .mode(0o700)
+                .create(dir)
+                .is_ok()
+    });
+    created && dirs[1..].iter().all(|dir| is_writable(dir))
+}
+
+fn is_writable(path: &Path) -> bool {
+    let Ok(c_path) = CString::new(path.as_os_str().as_bytes()) else {
+        return false;
+    };
+    unsafe { libc::access(c_path.as_ptr(), libc::W_OK) == 0 }
+}
+
+fn existing_ancestor(path: &Path) -> Option<PathBuf> {
+    path.ancestors()
+        .find(|p| fs::symlink_metadata(p).is_ok())
+        .map(Path::to_path_buf)
+}
+
+fn device_of(path: &Path) -> Option<u64> {
+    fs::symlink_metadata(path).ok().map(|m| m.dev())
+}
+
+fn io_error(e: std::io::Error) -> TrashError {
+    match e.kind() {
+        ErrorKind::NotFound => TrashError::NotFound(e.to_string()),
+        ErrorKind::PermissionDenied => TrashError::PermissionDenied(e.to_string()),
+        _ => TrashError::IoError(e.to_string()),
+    }
+}
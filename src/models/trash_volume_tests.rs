@@ -0,0 +1,135 @@
+use super::trash::*;
+use super::trash_volume::*;
+use chrono::{Local, TimeZone};
+use std::fs;
+use std::os::unix::fs::{symlink, PermissionsExt};
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+use tempfile::TempDir;
+
+const UID: u32 = 1000;
+
+
+fn make_dir(path: &Path, mode: u32) {
+    fs::create_dir_all(path).unwrap();
+    fs::set_permissions(path, fs::Permissions::from_mode(mode)).unwrap();
+}
+
+#[test]
+fn test_sticky_shared_trash_uses_per_user_subdirectory() {
+    let volume = TempDir::new().unwrap();
+    make_dir(&volume.path().join(".Trash"), 0o1777);
+
+    let trash = volume_trash_dir(volume.path(), UID).unwrap();
+
+    assert_eq!(trash, volume.path().join(".Trash/1000"));
+    assert!(trash.join("files").is_dir());
+    assert!(trash.join("info").is_dir());
+}
+
+#[test]
+fn test_shared_trash_without_sticky_bit_is_skipped() {
+    let volume = TempDir::new().unwrap();
+    make_dir(&volume.path().join(".Trash"), 0o777);
+
+    let trash = volume_trash_dir(volume.path(), UID).unwrap();
+
+    assert_eq!(trash, volume.path().join(".Trash-1000"));
+    assert!(!volume.path().join(".Trash/1000").exists());
+}
+
+#[test]
+fn test_symlinked_shared_trash_is_skipped() {
+    let volume = TempDir::new().unwrap();
+    let elsewhere = volume.path().join("elsewhere");
+    make_dir(&elsewhere, 0o1777);
+    symlink(&elsewhere, volume.path().join(".Trash")).unwrap();
+
+    let trash = volume_trash_dir(volume.path(), UID).unwrap();
+
+    assert_eq!(trash, volume.path().join(".Trash-1000"));
+    assert!(!elsewhere.join("1000").exists());
+}
+
+#[test]
+fn test_same_volume_and_home_setting_use_home_trash() {
+    let root = TempDir::new().unwrap();
+    let home_trash = root.path().join("home/.local/share/Trash");
+    let file = root.path().join("data/notes.txt");
+    fs::create_dir_all(file.parent().unwrap()).unwrap();
+    fs::write(&file, b"notes").unwrap();
+
+    assert!(same_device(&file, &home_trash));
+    assert!(file.starts_with(mount_point(&file).unwrap()));
+    assert_eq!(
+        trash_dir_for(&file, TrashLocation::PerVolume, &home_trash, UID),
+        home_trash
+    );
+    assert_eq!(
+        trash_dir_for(&file, TrashLocation::Home, &home_trash, UID),
+        home_trash
+    );
+}
+
+#[test]
+fn test_trash_info_escapes_and_restores_original_path() {
+    let original = PathBuf::from("/data/My Files/résumé 100%.txt");
+    let deleted = Local.with_ymd_and_hms(2024, 3, 9, 14, 5, 30).unwrap();
+
+    let contents = format_trash_info(&original, deleted);
+    assert!(contents.starts_with("[Trash Info]\n"));
+    assert!(contents.contains("Path=/data/My%20Files/r%C3%A9sum%C3%A9%20100%25.txt\n"));
+    assert!(contents.contains("DeletionDate=2024-03-09T14:05:30\n"));
+
+    let info = parse_trash_info(&contents, None).unwrap();
+    assert_eq!(info.original_path, original);
+    assert_eq!(info.deletion_date, Some(SystemTime::from(deleted)));
+}
+
+#[test]
+fn test_relative_trash_info_paths_resolve_against_volume() {
+    let contents = "[Trash Info]\nPath=photos/beach.png\nDeletionDate=2024-01-01T00:00:00\n";
+
+    let info = parse_trash_info(contents, Some(Path::new("/media/usb"))).unwrap();
+    assert_eq!(info.original_path, PathBuf::from("/media/usb/photos/beach.png"));
+
+    assert!(parse_trash_info("Path=/tmp/a", None).is_none());
+    assert!(parse_trash_info("[Trash Info]\nDeletionDate=2024-01-01T00:00:00\n", None).is_none());
+}
+
+#[test]
+fn test_trashed_item_restores_to_recorded_location() {
+    let volume = TempDir::new().unwrap();
+    let trash = volume_trash_dir(volume.path(), UID).unwrap();
+    let original = volume.path().join("docs/report.txt");
+    fs::create_dir_all(original.parent().unwrap()).unwrap();
+    fs::write(&original, b"first").unwrap();
+
+    let trashed = move_to_trash_dir(&original, &trash).unwrap();
+    assert_eq!(trashed, trash.join("files/report.txt"));
+    assert!(!original.exists());
+
+    fs::write(&original, b"second").unwrap();
+    let second = move_to_trash_dir(&original, &trash).unwrap();
+    assert_eq!(second, trash.join("files/report.txt.2"));
+
+    let info_path = trash_info_path(&trashed).unwrap();
+    assert_eq!(info_path, trash.join("info/report.txt.trashinfo"));
+    assert_eq!(trashed_path_for_info(&info_path), Some(trashed.clone()));
+    assert_eq!(read_trash_info(&info_path).unwrap().original_path, original);
+
+    fs::remove_dir_all(original.parent().unwrap()).unwrap();
+    let entry = TrashEntry {
+        name: "report.txt".to_string(),
+        original_path: PathBuf::from("/nonexistent/report.txt"),
+        deletion_date: SystemTime::now(),
+        size: 5,
+        is_dir: false,
+        trash_id: TrashId::Path(trashed.clone()),
+    };
+
+    assert_eq!(restore_from_trash(&entry).unwrap(), original);
+    assert_eq!(fs::read(&original).unwrap(), b"first");
+    assert!(!trashed.exists());
+    assert!(!info_path.exists());
+}
@@ -84,6 +84,112 @@ pub struct FileEntry {
 
     #[serde(default)]
     pub is_shared: bool,
+
+    #[serde(default)]
+    pub hidden_kind: HiddenKind,
+
+    #[serde(default)]
+    pub created: Option<SystemTime>,
+
+    #[serde(default)]
+    pub unix_mode: Option<u32>,
+}
+
+
+pub const WINDOWS_FILE_ATTRIBUTE_HIDDEN: u32 = 0x2;
+
+pub const WINDOWS_FILE_ATTRIBUTE_SYSTEM: u32 = 0x4;
+
+pub const MACOS_UF_HIDDEN: u32 = 0x8000;
+
+pub const HIDDEN_ENTRY_OPACITY: f32 = 0.5;
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum HiddenKind {
+    #[default]
+    Visible,
+    Dotfile,
+    Hidden,
+    System,
+}
+
+impl HiddenKind {
+    pub fn is_dimmed(&self) -> bool {
+        !matches!(self, HiddenKind::Visible)
+    }
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct HiddenAttributes {
+    pub hidden: bool,
+    pub system: bool,
+}
+
+impl HiddenAttributes {
+    pub fn from_windows_attributes(attributes: u32) -> Self {
+        Self {
+            hidden: attributes & WINDOWS_FILE_ATTRIBUTE_HIDDEN != 0,
+            system: attributes & WINDOWS_FILE_ATTRIBUTE_SYSTEM != 0,
+        }
+    }
+
+    pub fn from_bsd_flags(flags: u32) -> Self {
+        Self {
+            hidden: flags & MACOS_UF_HIDDEN != 0,
+            system: false,
+        }
+    }
+
+    #[cfg(windows)]
+    pub fn from_metadata(metadata: &std::fs::Metadata) -> Self {
+        use std::os::windows::fs::MetadataExt;
+        Self::from_windows_attributes(metadata.file_attributes())
+    }
+
+    #[cfg(target_os = "macos")]
+    pub fn from_metadata(metadata: &std::fs::Metadata) -> Self {
+        use std::os::macos::fs::MetadataExt;
+        Self::from_bsd_flags(metadata.st_flags())
+    }
+
+    #[cfg(not(any(windows, target_os = "macos")))]
+    pub fn from_metadata(_metadata: &std::fs::Metadata) -> Self {
+        Self::default()
+    }
+}
+
+
+pub fn classify_hidden(name: &str, attributes: HiddenAttributes) -> HiddenKind {
+    if attributes.system {
+        HiddenKind::System
+    } else if attributes.hidden {
+        HiddenKind::Hidden
+    } else if name.starts_with('.') {
+        HiddenKind::Dotfile
+    } else {
+        HiddenKind::Visible
+    }
+}
+
+
+pub fn format_permission_bits(mode: u32) -> String {
+    let mut result = String::with_capacity(9);
+
+    result.push(if mode & 0o400 != 0 { 'r' } else { '-' });
+    result.push(if mode & 0o200 != 0 { 'w' } else { '-' });
+    result.push(if mode & 0o100 != 0 { 'x' } else { '-' });
+
+    result.push(if mode & 0o040 != 0 { 'r' } else { '-' });
+    result.push(if mode & 0o020 != 0 { 'w' } else { '-' });
+    result.push(if mode & 0o010 != 0 { 'x' } else { '-' });
+
+    result.push(if mode & 0o004 != 0 { 'r' } else { '-' });
+    result.push(if mode & 0o002 != 0 { 'w' } else { '-' });
+    result.push(if mode & 0o001 != 0 { 'x' } else { '-' });
+
+    result
 }
 
 
@@ -104,21 +210,7 @@ impl LinuxFilePermissions {
 
 
     pub fn format_mode(&self) -> String {
-        let mut result = String::with_capacity(9);
-
-        result.push(if self.mode & 0o400 != 0 { 'r' } else { '-' });
-        result.push(if self.mode & 0o200 != 0 { 'w' } else { '-' });
-        result.push(if self.mode & 0o100 != 0 { 'x' } else { '-' });
-
-        result.push(if self.mode & 0o040 != 0 { 'r' } else { '-' });
-        result.push(if self.mode & 0o020 != 0 { 'w' } else { '-' });
-        result.push(if self.mode & 0o010 != 0 { 'x' } else { '-' });
-
-        result.push(if self.mode & 0o004 != 0 { 'r' } else { '-' });
-        result.push(if self.mode & 0o002 != 0 { 'w' } else { '-' });
-        result.push(if self.mode & 0o001 != 0 { 'x' } else { '-' });
-
-        result
+        format_permission_bits(self.mode)
     }
 
 
@@ -241,6 +333,8 @@ impl FileEntry {
                 .unwrap_or(IconKey::GenericFile)
         };
 
+        let hidden_kind = classify_hidden(&name, HiddenAttributes::default());
+
         Self {
             name,
             path,
@@ -255,6 +349,9 @@ impl FileEntry {
             symlink_target: None,
             is_broken_symlink: false,
             is_shared: false,
+            hidden_kind,
+            created: None,
+            unix_mode: None,
         }
     }
 
@@ -283,6 +380,25 @@ impl FileEntry {
     }
 
 
+    pub fn with_metadata_details(mut self, metadata: &std::fs::Metadata) -> Self {
+        self.created = metadata.created().ok();
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            self.unix_mode = Some(metadata.permissions().mode() & 0o777);
+        }
+        self
+    }
+
+
+    pub fn permissions_label(&self) -> Option<String> {
+        self.linux_permissions
+            .as_ref()
+            .map(|p| p.format_mode())
+            .or_else(|| self.unix_mode.map(format_permission_bits))
+    }
+
+
     pub fn with_linux_permissions(mut self, permissions: LinuxFilePermissions) -> Self {
         self.linux_permissions = Some(permissions);
         self
@@ -345,7 +461,10 @@ impl FileEntry {
             let is_dir = symlink_metadata.is_dir();
             let size = if is_dir { 0 } else { symlink_metadata.len() };
             let modified = symlink_metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH);
-            Some(Self::new(name, path.to_path_buf(), is_dir, size, modified))
+            Some(
+                Self::new(name, path.to_path_buf(), is_dir, size, modified)
+                    .with_metadata_details(&symlink_metadata),
+            )
         }
     }
 
@@ -386,6 +505,18 @@ pub enum SortColumn {
     Size,
 }
 
+impl SortColumn {
+    pub fn default_direction(&self) -> SortDirection {
+        match self {
+            SortColumn::Name | SortColumn::Type => SortDirection::Ascending,
+            SortColumn::Date | SortColumn::Size => SortDirection::Descending,
+        }
+    }
+}
+
+
+pub const MAX_SORT_TIEBREAKERS: usize = 3;
+
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub enum SortDirection {
@@ -413,6 +544,8 @@ pub struct SortState {
     pub column: SortColumn,
     pub direction: SortDirection,
     pub directories_first: bool,
+    #[serde(default)]
+    pub tiebreakers: [Option<(SortColumn, SortDirection)>; MAX_SORT_TIEBREAKERS],
 }
 
 impl Default for SortState {
@@ -421,6 +554,7 @@ impl Default for SortState {
             column: SortColumn::Name,
             direction: SortDirection::Ascending,
             directories_first: true,
+            tiebreakers: [None; MAX_SORT_TIEBREAKERS],
         }
     }
 }
@@ -436,52 +570,134 @@ impl SortState {
             self.direction = self.direction.toggle();
         } else {
             self.column = column;
-            self.direction = match column {
-                SortColumn::Name => SortDirection::Ascending,
-                SortColumn::Date => SortDirection::Descending,
-                SortColumn::Type => SortDirection::Ascending,
-                SortColumn::Size => SortDirection::Descending,
-            };
+            self.direction = column.default_direction();
+            self.tiebreakers = [None; MAX_SORT_TIEBREAKERS];
         }
     }
 
 
-    pub fn sort_entries(&self, entries: &mut [FileEntry]) {
-        if self.directories_first {
-            let (mut dirs, mut files): (Vec<_>, Vec<_>) =
-                entries.iter().cloned().partition(|e| e.is_dir);
+    pub fn add_tiebreaker(&mut self, column: SortColumn) {
+        if self.column == column {
+            self.direction = self.direction.toggle();
+            return;
+        }
+        let existing = self
+            .tiebreakers
+            .iter_mut()
+            .flatten()
+            .find(|(key, _)| *key == column);
+        if let Some((_, direction)) = existing {
+            *direction = direction.toggle();
+            return;
+        }
+        if let Some(slot) = self.tiebreakers.iter_mut().find(|slot| slot.is_none()) {
+            *slot = Some((column, column.default_direction()));
+        }
+    }
 
-            self.sort_by_column(&mut dirs);
-            self.sort_by_column(&mut files);
 
-            let combined: Vec<_> = dirs.into_iter().chain(files).collect();
-            entries.clone_from_slice(&combined);
-        } else {
-            self.sort_by_column(entries);
+    pub fn keys(&self) -> Vec<(SortColumn, SortDirection)> {
+        let mut keys = vec![(self.column, self.direction)];
+        for &(column, direction) in self.tiebreakers.iter().flatten() {
+            if !keys.iter().any(|(key, _)| *key == column) {
+                keys.push((column, direction));
+            }
         }
+        keys
     }
 
-    fn sort_by_column(&self, entries: &mut [FileEntry]) {
-        let direction = self.direction;
+    pub fn is_multi_key(&self) -> bool {
+        self.keys().len() > 1
+    }
 
-        entries.sort_by(|a, b| {
-            let ordering = match self.column {
-                SortColumn::Name => compare_names(&a.name, &b.name),
-                SortColumn::Date => a.modified.cmp(&b.modified),
-                SortColumn::Type => compare_types(a, b),
-                SortColumn::Size => a.size.cmp(&b.size),
-            };
 
+    pub fn position(&self, column: SortColumn) -> Option<(usize, SortDirection)> {
+        self.keys()
+            .into_iter()
+            .enumerate()
+            .find(|(_, (key, _))| *key == column)
+            .map(|(index, (_, direction))| (index, direction))
+    }
+
+
+    pub fn sort_entries(&self, entries: &mut [FileEntry]) {
+        sort_entries_by(entries, &self.keys(), self.directories_first);
+    }
+
+    pub fn comparator(&self) -> impl Fn(&FileEntry, &FileEntry) -> Ordering {
+        let keys = self.keys();
+        let directories_first = self.directories_first;
+        move |a, b| compare_entries(a, b, &keys, directories_first)
+    }
+
+    pub fn set_directories_first(&mut self, value: bool) {
+        self.directories_first = value;
+    }
+}
+
+
+pub fn sort_entries_by(
+    entries: &mut [FileEntry],
+    keys: &[(SortColumn, SortDirection)],
+    directories_first: bool,
+) {
+    entries.sort_by(|a, b| compare_entries(a, b, keys, directories_first));
+}
+
+
+pub fn merge_sorted_entries(
+    entries: &mut Vec<FileEntry>,
+    mut batch: Vec<FileEntry>,
+    compare: impl Fn(&FileEntry, &FileEntry) -> Ordering,
+) {
+    batch.sort_by(&compare);
+    let existing = std::mem::take(entries);
+    entries.reserve(existing.len() + batch.len());
+    let mut existing = existing.into_iter().peekable();
+    let mut batch = batch.into_iter().peekable();
+    loop {
+        let from_batch = match (existing.peek(), batch.peek()) {
+            (Some(current), Some(incoming)) => compare(incoming, current) == Ordering::Less,
+            (Some(_), None) => false,
+            (None, Some(_)) => true,
+            (None, None) => break,
+        };
+        let next = if from_batch { batch.next() } else { existing.next() };
+        entries.extend(next);
+    }
+}
+
+
+fn compare_entries(
+    a: &FileEntry,
+    b: &FileEntry,
+    keys: &[(SortColumn, SortDirection)],
+    directories_first: bool,
+) -> Ordering {
+    let group = if directories_first {
+        b.is_dir.cmp(&a.is_dir)
+    } else {
+        Ordering::Equal
+    };
+    keys.iter().fold(group, |ordering, &(column, direction)| {
+        ordering.then_with(|| {
+            let ordering = compare_column(a, b, column);
             if direction == SortDirection::Descending {
                 ordering.reverse()
             } else {
                 ordering
             }
-        });
-    }
+        })
+    })
+}
 
-    pub fn set_directories_first(&mut self, value: bool) {
-        self.directories_first = value;
+
+fn compare_column(a: &FileEntry, b: &FileEntry, column: SortColumn) -> Ordering {
+    match column {
+        SortColumn::Name => compare_names(&a.name, &b.name),
+        SortColumn::Date => a.modified.cmp(&b.modified),
+        SortColumn::Type => compare_types(a, b),
+        SortColumn::Size => a.size.cmp(&b.size),
     }
 }
 
@@ -1,6 +1,10 @@
 
 
-use super::{CloudSyncStatus, FileEntry, FileType, IconKey, SortColumn, SortDirection, SortState};
+use super::{
+    classify_hidden, merge_sorted_entries, CloudSyncStatus, FileEntry, FileType, HiddenAttributes,
+    HiddenKind, IconKey, sort_entries_by, SortColumn, SortDirection, SortState, WINDOWS_FILE_ATTRIBUTE_HIDDEN,
+    WINDOWS_FILE_ATTRIBUTE_SYSTEM,
+};
 use proptest::prelude::*;
 use std::path::PathBuf;
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
@@ -57,10 +61,153 @@ fn arb_file_entry() -> impl Strategy<Value = FileEntry> {
                 symlink_target,
                 is_broken_symlink,
                 is_shared: false,
+                hidden_kind: HiddenKind::Visible,
+                created: None,
+                unix_mode: None,
             },
         )
 }
 
+#[test]
+fn test_platform_attributes_map_to_hidden_kind() {
+    let none = HiddenAttributes::default();
+    assert_eq!(classify_hidden("notes.txt", none), HiddenKind::Visible);
+    assert_eq!(classify_hidden(".bashrc", none), HiddenKind::Dotfile);
+
+    let hidden = HiddenAttributes::from_windows_attributes(WINDOWS_FILE_ATTRIBUTE_HIDDEN);
+    assert_eq!(classify_hidden("desktop.ini", hidden), HiddenKind::Hidden);
+
+    let system = HiddenAttributes::from_windows_attributes(
+        WINDOWS_FILE_ATTRIBUTE_HIDDEN | WINDOWS_FILE_ATTRIBUTE_SYSTEM,
+    );
+    assert_eq!(classify_hidden("pagefile.sys", system), HiddenKind::System);
+
+    let flagged = HiddenAttributes::from_bsd_flags(super::MACOS_UF_HIDDEN);
+    assert_eq!(classify_hidden("Library", flagged), HiddenKind::Hidden);
+    assert_eq!(HiddenAttributes::from_bsd_flags(0), none);
+
+    assert!(!HiddenKind::Visible.is_dimmed());
+    assert!(HiddenKind::Dotfile.is_dimmed());
+    assert!(HiddenKind::System.is_dimmed());
+}
+
+#[test]
+fn test_file_entry_new_marks_dotfiles() {
+    let entry = FileEntry::new(
+        ".env".to_string(),
+        PathBuf::from("/project/.env"),
+        false,
+        12,
+        SystemTime::UNIX_EPOCH,
+    );
+    assert_eq!(entry.hidden_kind, HiddenKind::Dotfile);
+}
+
+fn entry(name: &str, size: u64) -> FileEntry {
+    FileEntry::new(
+        name.to_string(),
+        PathBuf::from("/data").join(name),
+        false,
+        size,
+        SystemTime::UNIX_EPOCH,
+    )
+}
+
+fn names(entries: &[FileEntry]) -> Vec<&str> {
+    entries.iter().map(|e| e.name.as_str()).collect()
+}
+
+#[test]
+fn test_two_key_sort_orders_by_kind_then_name() {
+    let mut entries = vec![
+        entry("b.txt", 1),
+        entry("c.rs", 1),
+        entry("a.txt", 1),
+        entry("a.rs", 1),
+    ];
+    sort_entries_by(
+        &mut entries,
+        &[
+            (SortColumn::Type, SortDirection::Ascending),
+            (SortColumn::Name, SortDirection::Ascending),
+        ],
+        true,
+    );
+    assert_eq!(names(&entries), vec!["a.rs", "c.rs", "a.txt", "b.txt"]);
+
+    let mut sort_state = SortState::new();
+    sort_state.toggle_column(SortColumn::Type);
+    sort_state.add_tiebreaker(SortColumn::Size);
+    assert_eq!(
+        sort_state.keys(),
+        vec![
+            (SortColumn::Type, SortDirection::Ascending),
+            (SortColumn::Size, SortDirection::Descending),
+        ]
+    );
+    assert_eq!(sort_state.position(SortColumn::Size), Some((1, SortDirection::Descending)));
+}
+
+#[test]
+fn test_ties_fall_through_to_secondary_key() {
+    let mut entries = vec![entry("small.txt", 10), entry("big.txt", 900), entry("mid.txt", 10)];
+
+    let mut sort_state = SortState::new();
+    sort_state.toggle_column(SortColumn::Size);
+    sort_state.add_tiebreaker(SortColumn::Name);
+    sort_state.sort_entries(&mut entries);
+    assert_eq!(names(&entries), vec!["big.txt", "mid.txt", "small.txt"]);
+
+    sort_state.add_tiebreaker(SortColumn::Name);
+    sort_state.sort_entries(&mut entries);
+    assert_eq!(names(&entries), vec!["big.txt", "small.txt", "mid.txt"]);
+
+    sort_state.toggle_column(SortColumn::Name);
+    assert!(!sort_state.is_multi_key());
+}
+
+#[test]
+fn test_sort_state_without_tiebreakers_still_deserializes() {
+    let json = r#"{"column":"Size","direction":"Descending","directories_first":true}"#;
+    let sort_state: SortState = serde_json::from_str(json).unwrap();
+    assert_eq!(sort_state.keys(), vec![(SortColumn::Size, SortDirection::Descending)]);
+
+    let mut multi = sort_state;
+    multi.add_tiebreaker(SortColumn::Name);
+    let round_trip: SortState = serde_json::from_str(&serde_json::to_string(&multi).unwrap()).unwrap();
+    assert_eq!(round_trip, multi);
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(100))]
+
+    #[test]
+    fn prop_merged_batches_match_full_sort(
+        batches in prop::collection::vec(prop::collection::vec(arb_file_entry(), 0..20), 1..6),
+        column in prop_oneof![
+            Just(SortColumn::Name),
+            Just(SortColumn::Date),
+            Just(SortColumn::Type),
+            Just(SortColumn::Size),
+        ],
+        directories_first in proptest::bool::ANY,
+    ) {
+        let mut sort_state = SortState::new();
+        sort_state.toggle_column(column);
+        sort_state.directories_first = directories_first;
+
+        let mut merged = Vec::new();
+        for batch in batches.clone() {
+            merge_sorted_entries(&mut merged, batch, sort_state.comparator());
+        }
+
+        let mut expected: Vec<FileEntry> = batches.into_iter().flatten().collect();
+        sort_state.sort_entries(&mut expected);
+        let paths = |entries: &[FileEntry]| entries.iter().map(|e| e.path.clone()).collect::<Vec<_>>();
+        prop_assert_eq!(paths(&merged), paths(&expected));
+    }
+}
+
 proptest! {
     #![proptest_config(ProptestConfig::with_cases(100))]
 
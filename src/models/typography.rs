@@ -15,6 +15,7 @@
 //! - Generous spacing for readability and RPG aesthetic
 
 use gpui::SharedString;
+use std::sync::atomic::{AtomicU32, Ordering};
 
 
 
@@ -152,6 +153,20 @@ pub mod sidebar {
     pub const WIDTH: f32 = 280.0;
 
 
+    pub const MIN_WIDTH: f32 = 200.0;
+
+
+    pub const MAX_WIDTH: f32 = 480.0;
+
+
+    pub fn clamp_width(width: f32) -> f32 {
+        if !width.is_finite() {
+            return WIDTH;
+        }
+        width.clamp(MIN_WIDTH, MAX_WIDTH)
+    }
+
+
     pub const ITEM_HEIGHT: f32 = 36.0;
 
 
@@ -224,12 +239,23 @@ pub mod toolbar {
 
 pub mod terminal_layout {
 
-    pub const MIN_HEIGHT: f32 = 200.0;
+    pub const MIN_HEIGHT: f32 = 150.0;
+
+
+    pub const MAX_HEIGHT: f32 = 600.0;
 
 
     pub const DEFAULT_HEIGHT: f32 = 300.0;
 
 
+    pub fn clamp_height(height: f32) -> f32 {
+        if !height.is_finite() {
+            return DEFAULT_HEIGHT;
+        }
+        height.clamp(MIN_HEIGHT, MAX_HEIGHT)
+    }
+
+
     pub const LINE_HEIGHT: f32 = 1.5;
 
 
@@ -242,7 +268,21 @@ pub mod terminal_layout {
 
 pub mod preview {
 
-    pub const WIDTH: f32 = 360.0;
+    pub const WIDTH: f32 = 320.0;
+
+
+    pub const MIN_WIDTH: f32 = 200.0;
+
+
+    pub const MAX_WIDTH: f32 = 600.0;
+
+
+    pub fn clamp_width(width: f32) -> f32 {
+        if !width.is_finite() {
+            return WIDTH;
+        }
+        width.clamp(MIN_WIDTH, MAX_WIDTH)
+    }
 
 
     pub const HEADER_HEIGHT: f32 = 64.0;
@@ -317,6 +357,64 @@ pub const RADIUS_XL: f32 = 24.0;
 
 
 
+pub const UI_SCALE_MIN: f32 = 0.9;
+
+
+pub const UI_SCALE_MAX: f32 = 1.5;
+
+
+pub const UI_SCALE_STEP: f32 = 0.1;
+
+
+pub const UI_SCALE_DEFAULT: f32 = 1.0;
+
+
+pub const BASE_REM_SIZE: f32 = 16.0;
+
+
+static UI_SCALE_PERCENT: AtomicU32 = AtomicU32::new(100);
+
+
+pub fn clamp_ui_scale(scale: f32) -> f32 {
+    if !scale.is_finite() {
+        return UI_SCALE_DEFAULT;
+    }
+    let percent = (scale * 100.0).round() / 100.0;
+    percent.clamp(UI_SCALE_MIN, UI_SCALE_MAX)
+}
+
+
+pub fn set_ui_scale(scale: f32) {
+    let percent = (clamp_ui_scale(scale) * 100.0).round() as u32;
+    UI_SCALE_PERCENT.store(percent, Ordering::SeqCst);
+}
+
+
+pub fn ui_scale() -> f32 {
+    UI_SCALE_PERCENT.load(Ordering::SeqCst) as f32 / 100.0
+}
+
+
+pub fn scaled(value: f32) -> f32 {
+    scaled_by(value, ui_scale())
+}
+
+
+pub fn scaled_by(value: f32, scale: f32) -> f32 {
+    value * scale
+}
+
+
+pub fn scale_up(scale: f32) -> f32 {
+    clamp_ui_scale(scale + UI_SCALE_STEP)
+}
+
+
+pub fn scale_down(scale: f32) -> f32 {
+    clamp_ui_scale(scale - UI_SCALE_STEP)
+}
+
+
 pub fn display_font() -> SharedString {
     SharedString::from(FONT_DISPLAY)
 }
@@ -537,4 +635,45 @@ mod tests {
         let result = tracking_to_px(TRACKING_WIDE, SIZE_2XL);
         assert!((result - 1.8).abs() < 0.01);
     }
+
+    #[test]
+    fn test_clamp_ui_scale() {
+        assert_eq!(clamp_ui_scale(0.5), UI_SCALE_MIN);
+        assert_eq!(clamp_ui_scale(2.0), UI_SCALE_MAX);
+        assert_eq!(clamp_ui_scale(1.25), 1.25);
+        assert_eq!(clamp_ui_scale(f32::NAN), UI_SCALE_DEFAULT);
+    }
+
+    #[test]
+    fn test_clamp_sidebar_width() {
+        assert_eq!(sidebar::clamp_width(120.0), sidebar::MIN_WIDTH);
+        assert_eq!(sidebar::clamp_width(900.0), sidebar::MAX_WIDTH);
+        assert_eq!(sidebar::clamp_width(320.0), 320.0);
+        assert_eq!(sidebar::clamp_width(f32::INFINITY), sidebar::WIDTH);
+        assert!(sidebar::MIN_WIDTH <= sidebar::WIDTH && sidebar::WIDTH <= sidebar::MAX_WIDTH);
+    }
+
+    #[test]
+    fn test_scale_steps_stay_in_range() {
+        let mut scale = UI_SCALE_DEFAULT;
+        for _ in 0..20 {
+            scale = scale_up(scale);
+        }
+        assert_eq!(scale, UI_SCALE_MAX);
+
+        for _ in 0..20 {
+            scale = scale_down(scale);
+        }
+        assert_eq!(scale, UI_SCALE_MIN);
+
+        assert!((scale_up(1.0) - 1.1).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_scaled_layout_constants() {
+        assert_eq!(scaled_by(sidebar::WIDTH, UI_SCALE_MAX), 420.0);
+        assert_eq!(scaled_by(file_list::ROW_HEIGHT, UI_SCALE_MAX), 60.0);
+        assert_eq!(scaled_by(toolbar::HEIGHT, UI_SCALE_MAX), 78.0);
+        assert_eq!(scaled_by(sidebar::WIDTH, UI_SCALE_DEFAULT), sidebar::WIDTH);
+    }
 }
@@ -0,0 +1,88 @@
+use std::path::Path;
+use std::time::Duration;
+
+use gpui::Rgba;
+
+use crate::models::ThemeColors;
+use crate::utils::{format_size, format_space_tooltip, is_space_critical, is_space_very_low, usage_percentage};
+
+
+pub const VOLUME_SPACE_REFRESH_INTERVAL: Duration = Duration::from_secs(60);
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct VolumeId(u64);
+
+
+pub fn volume_id(path: &Path) -> Option<VolumeId> {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::MetadataExt;
+        std::fs::metadata(path).ok().map(|meta| VolumeId(meta.dev()))
+    }
+
+    #[cfg(not(unix))]
+    {
+        use std::hash::{Hash, Hasher};
+        let root = path.components().next()?;
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        root.as_os_str().to_string_lossy().to_lowercase().hash(&mut hasher);
+        Some(VolumeId(hasher.finish()))
+    }
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpaceLevel {
+    Normal,
+    Low,
+    Critical,
+}
+
+impl SpaceLevel {
+    pub fn color(self, theme: &ThemeColors) -> Rgba {
+        match self {
+            SpaceLevel::Normal => theme.text_muted,
+            SpaceLevel::Low => theme.warning,
+            SpaceLevel::Critical => theme.error,
+        }
+    }
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VolumeSpace {
+    pub total: u64,
+    pub free: u64,
+}
+
+impl VolumeSpace {
+    pub fn new(total: u64, free: u64) -> Self {
+        Self {
+            total,
+            free: free.min(total),
+        }
+    }
+
+    pub fn level(&self) -> SpaceLevel {
+        if is_space_very_low(self.total, self.free) {
+            SpaceLevel::Critical
+        } else if is_space_critical(self.total, self.free) {
+            SpaceLevel::Low
+        } else {
+            SpaceLevel::Normal
+        }
+    }
+
+    pub fn usage_percentage(&self) -> f64 {
+        usage_percentage(self.total, self.free)
+    }
+
+    pub fn label(&self) -> String {
+        format!("{} free", format_size(self.free))
+    }
+
+    pub fn tooltip(&self) -> String {
+        format_space_tooltip(self.total, self.free)
+    }
+}
@@ -0,0 +1,47 @@
+use super::volume_space::*;
+use crate::models::theme_colors;
+
+const GB: u64 = 1024 * 1024 * 1024;
+
+
+#[test]
+fn test_space_level_follows_usage() {
+    assert_eq!(VolumeSpace::new(100 * GB, 50 * GB).level(), SpaceLevel::Normal);
+    assert_eq!(VolumeSpace::new(100 * GB, 11 * GB).level(), SpaceLevel::Normal);
+    assert_eq!(VolumeSpace::new(100 * GB, 10 * GB).level(), SpaceLevel::Low);
+    assert_eq!(VolumeSpace::new(100 * GB, 6 * GB).level(), SpaceLevel::Low);
+    assert_eq!(VolumeSpace::new(100 * GB, 5 * GB).level(), SpaceLevel::Critical);
+    assert_eq!(VolumeSpace::new(100 * GB, 0).level(), SpaceLevel::Critical);
+    assert_eq!(VolumeSpace::new(0, 0).level(), SpaceLevel::Normal);
+}
+
+#[test]
+fn test_space_level_colors() {
+    let theme = theme_colors();
+    assert_eq!(SpaceLevel::Normal.color(&theme), theme.text_muted);
+    assert_eq!(SpaceLevel::Low.color(&theme), theme.warning);
+    assert_eq!(SpaceLevel::Critical.color(&theme), theme.error);
+    assert_eq!(
+        VolumeSpace::new(100 * GB, 2 * GB).level().color(&theme),
+        theme.error
+    );
+}
+
+#[test]
+fn test_free_space_is_capped_by_total() {
+    let space = VolumeSpace::new(10 * GB, 20 * GB);
+    assert_eq!(space.free, space.total);
+    assert_eq!(space.usage_percentage(), 0.0);
+    assert!(space.label().ends_with(" free"));
+    assert!(space.tooltip().contains("Free:"));
+}
+
+#[test]
+fn test_volume_id_matches_within_volume() {
+    let dir = tempfile::tempdir().unwrap();
+    let nested = dir.path().join("nested");
+    std::fs::create_dir(&nested).unwrap();
+
+    assert!(volume_id(dir.path()).is_some());
+    assert_eq!(volume_id(dir.path()), volume_id(&nested));
+}
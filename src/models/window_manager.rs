@@ -2,11 +2,14 @@ use std::collections::HashMap;
 use std::path::PathBuf;
 use std::sync::atomic::{AtomicU64, Ordering};
 
-use gpui::{px, size, App, Bounds, Global, Pixels, WindowBounds, WindowHandle, WindowOptions};
+use gpui::{
+    px, size, App, Bounds, Global, Pixels, WindowBounds, WindowHandle, WindowId, WindowOptions,
+};
 use serde::{Deserialize, Serialize};
 
 #[cfg(not(test))]
 use crate::app::Workspace;
+use crate::models::{DualPaneLayout, PaneSizes, PreviewVisibility};
 
 static NEXT_WINDOW_ID: AtomicU64 = AtomicU64::new(1);
 
@@ -33,6 +36,14 @@ pub struct WindowState {
     pub path: PathBuf,
     pub bounds: Option<WindowBoundsState>,
     pub is_active: bool,
+    #[serde(default)]
+    pub dual_pane: Option<DualPaneLayout>,
+    #[serde(default)]
+    pub preview: PreviewVisibility,
+    #[serde(default)]
+    pub sidebar_width: Option<f32>,
+    #[serde(default, flatten)]
+    pub panes: PaneSizes,
 }
 
 
@@ -183,6 +194,10 @@ impl WindowManager {
                     path,
                     bounds: Some(WindowBoundsState::from_bounds(&cascaded_bounds)),
                     is_active: true,
+                    dual_pane: None,
+                    preview: PreviewVisibility::default(),
+                    sidebar_width: None,
+                    panes: PaneSizes::default(),
                 };
 
                 self.windows.insert(id, handle);
@@ -229,6 +244,10 @@ impl WindowManager {
             path,
             bounds: None,
             is_active: true,
+            dual_pane: None,
+            preview: PreviewVisibility::default(),
+            sidebar_width: None,
+            panes: PaneSizes::default(),
         };
 
         self.windows.insert(id, handle);
@@ -251,6 +270,49 @@ impl WindowManager {
             state.path = path;
         }
     }
+
+
+    pub fn update_dual_pane_layout(&mut self, id: AppWindowId, layout: DualPaneLayout) {
+        if let Some(state) = self.window_states.get_mut(&id) {
+            state.dual_pane = Some(layout);
+        }
+    }
+
+
+    pub fn update_preview_visibility(&mut self, id: AppWindowId, preview: PreviewVisibility) {
+        if let Some(state) = self.window_states.get_mut(&id) {
+            state.preview = preview;
+        }
+    }
+
+
+    pub fn update_sidebar_width(&mut self, id: AppWindowId, width: f32) {
+        if let Some(state) = self.window_states.get_mut(&id) {
+            state.sidebar_width = Some(width);
+        }
+    }
+
+
+    pub fn update_pane_sizes(&mut self, id: AppWindowId, panes: PaneSizes) {
+        if let Some(state) = self.window_states.get_mut(&id) {
+            state.panes = panes;
+        }
+    }
+
+
+    pub fn find_window(&self, window_id: WindowId) -> Option<AppWindowId> {
+        self.windows
+            .iter()
+            .find(|(_, handle)| handle.window_id() == window_id)
+            .map(|(id, _)| *id)
+    }
+
+
+    pub fn dual_pane_layout(&self, id: AppWindowId) -> Option<&DualPaneLayout> {
+        self.window_states
+            .get(&id)
+            .and_then(|state| state.dual_pane.as_ref())
+    }
 }
 
 impl Default for WindowManager {
@@ -350,8 +412,32 @@ impl WindowManager {
                         path,
                         bounds: window_state.bounds,
                         is_active: false,
+                        dual_pane: window_state.dual_pane,
+                        preview: window_state.preview,
+                        sidebar_width: window_state.sidebar_width,
+                        panes: window_state.panes,
                     };
 
+                    let preview = window_state.preview;
+                    let sidebar_width = window_state.sidebar_width;
+                    let panes = window_state.panes;
+                    let dual_pane = new_state.dual_pane.clone();
+                    let _ = handle.update(cx, |workspace, _window, cx| {
+                        workspace.set_preview_visibility(preview, cx);
+                        if let Some(layout) = dual_pane {
+                            workspace.restore_dual_pane(layout, cx);
+                        }
+                        if let Some(width) = sidebar_width {
+                            workspace.set_sidebar_width(width, cx);
+                        }
+                        if let Some(width) = panes.preview_width() {
+                            workspace.set_preview_width(width, cx);
+                        }
+                        if let Some(height) = panes.terminal_height() {
+                            workspace.set_terminal_height(height, cx);
+                        }
+                    });
+
                     self.windows.insert(id, handle);
                     self.window_states.insert(id, new_state);
                 }
@@ -436,6 +522,10 @@ mod tests {
                 height: 600.0,
             }),
             is_active: true,
+            dual_pane: None,
+            preview: PreviewVisibility::default(),
+            sidebar_width: Some(320.0),
+            panes: PaneSizes::default(),
         };
 
         let json = serde_json::to_string(&state).expect("Failed to serialize");
@@ -444,6 +534,7 @@ mod tests {
         assert_eq!(state.id, restored.id);
         assert_eq!(state.path, restored.path);
         assert_eq!(state.is_active, restored.is_active);
+        assert_eq!(restored.sidebar_width, Some(320.0));
 
         let bounds = state.bounds.unwrap();
         let restored_bounds = restored.bounds.unwrap();
@@ -462,6 +553,10 @@ mod tests {
                     path: PathBuf::from("/home/user"),
                     bounds: None,
                     is_active: true,
+                    dual_pane: None,
+                    preview: PreviewVisibility::default(),
+                    sidebar_width: None,
+                    panes: PaneSizes::default(),
                 },
                 WindowState {
                     id: AppWindowId(2),
@@ -473,6 +568,10 @@ mod tests {
                         height: 700.0,
                     }),
                     is_active: false,
+                    dual_pane: None,
+                    preview: PreviewVisibility::default(),
+                    sidebar_width: None,
+                    panes: PaneSizes::default(),
                 },
             ],
             active_window_index: Some(0),
@@ -117,6 +117,35 @@ pub fn format_space_tooltip(total: u64, free: u64) -> String {
     )
 }
 
+pub const ELLIPSIS: char = '\u{2026}';
+
+
+pub fn middle_ellipsis(name: &str, max_chars: usize) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    if chars.len() <= max_chars {
+        return name.to_string();
+    }
+    if max_chars == 0 {
+        return String::new();
+    }
+
+    let keep = max_chars - 1;
+    let extension_len = name
+        .rfind('.')
+        .filter(|&dot| dot > 0)
+        .map(|dot| name[dot..].chars().count())
+        .filter(|&len| len <= keep / 2)
+        .unwrap_or(0);
+    let tail = extension_len.max(keep / 3).min(keep);
+    let head = keep - tail;
+
+    chars[..head]
+        .iter()
+        .chain(std::iter::once(&ELLIPSIS))
+        .chain(&chars[chars.len() - tail..])
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -338,6 +367,37 @@ mod tests {
         assert!(tooltip.contains("Free: 1.0 GB"));
     }
 
+    #[test]
+    fn test_middle_ellipsis_preserves_extension() {
+        let name = "quarterly_financial_report_final_revision_v12.pdf";
+        let short = middle_ellipsis(name, 24);
+        assert_eq!(short.chars().count(), 24);
+        assert!(short.ends_with(".pdf"));
+        assert!(short.starts_with("quarterly_financ"));
+        assert!(short.contains(ELLIPSIS));
+
+        assert_eq!(middle_ellipsis("notes.txt", 24), "notes.txt");
+        assert_eq!(middle_ellipsis("archive.tar.gz", 14), "archive.tar.gz");
+    }
+
+    #[test]
+    fn test_middle_ellipsis_is_bounded() {
+        let names = [
+            "a_very_long_folder_name_without_any_extension_at_all",
+            ".a_dotfile_that_goes_on_and_on_forever",
+            "name.with_an_extension_longer_than_half_the_budget",
+            "\u{65e5}\u{672c}\u{8a9e}\u{306e}\u{9577}\u{3044}\u{30d5}\u{30a1}\u{30a4}\u{30eb}\u{540d}.txt",
+        ];
+        for max_chars in 0..20 {
+            for name in names {
+                let short = middle_ellipsis(name, max_chars);
+                assert!(short.chars().count() <= max_chars, "{:?} at {}", short, max_chars);
+            }
+        }
+        assert_eq!(middle_ellipsis("abcdef.txt", 1), ELLIPSIS.to_string());
+        assert_eq!(middle_ellipsis(".bashrc_extended", 7), ".bas\u{2026}ed");
+    }
+
     #[test]
     fn test_format_size_precision() {
         assert_eq!(format_size(1536), "1.5 KB");
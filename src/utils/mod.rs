@@ -5,7 +5,7 @@ mod icons;
 pub use cache::*;
 pub use format::{
     format_size, format_size_for_list, format_space_tooltip, is_space_critical, is_space_very_low,
-    parse_size, usage_percentage,
+    middle_ellipsis, parse_size, usage_percentage, ELLIPSIS,
 };
 pub use icons::{
     bgra_to_rgba, bgra_to_rgba_inplace, rgba_to_bgra, rgba_to_bgra_inplace, rgba_to_bgra_pixel,
@@ -6,6 +6,11 @@ use gpui::{
 };
 
 use crate::models::{theme_colors, toolbar as toolbar_spacing};
+use crate::utils::middle_ellipsis;
+use crate::views::TooltipLabel;
+
+
+const SEGMENT_MAX_CHARS: usize = 32;
 
 
 #[derive(Debug, Clone, PartialEq)]
@@ -261,6 +266,7 @@ impl Render for BreadcrumbView {
                 let path_for_context = segment.path.clone();
                 let is_first = i == 0;
                 let show_ellipsis = needs_truncation && i == 1;
+                let segment_truncated = segment.name.chars().count() > SEGMENT_MAX_CHARS;
 
                 div()
                     .flex()
@@ -335,7 +341,7 @@ impl Render for BreadcrumbView {
                                                             view.breadcrumb.show_ellipsis_menu = false;
                                                         }),
                                                     )
-                                                    .child(seg.name.clone())
+                                                    .child(middle_ellipsis(&seg.name, SEGMENT_MAX_CHARS))
                                             }))
                                     )
                                 })
@@ -344,6 +350,7 @@ impl Render for BreadcrumbView {
                     .child(
                         div()
                             .id(SharedString::from(format!("segment-{}", i)))
+                            .when(segment_truncated, |s| s.tooltip_label(segment.name.clone()))
                             .px(segment_padding)
                             .py_0p5()
                             .rounded_sm()
@@ -362,7 +369,7 @@ impl Render for BreadcrumbView {
                                     view.handle_context_menu(path_for_context.clone(), window, cx);
                                 }),
                             )
-                            .child(segment.name.clone())
+                            .child(middle_ellipsis(&segment.name, SEGMENT_MAX_CHARS))
                     )
             }))
             .when(self.context_menu_path.is_some(), |s| {
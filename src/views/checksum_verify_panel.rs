@@ -0,0 +1,341 @@
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use gpui::{
+    div, prelude::*, px, svg, App, Context, FocusHandle, Focusable, InteractiveElement,
+    IntoElement, KeyDownEvent, ParentElement, Render, SharedString, Styled, Window,
+};
+
+use crate::models::{theme_colors, ChecksumManifest, VerifyStatus, VerifySummary};
+use crate::views::{focus_ring, FocusTrap, TrapKey};
+
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ChecksumVerifyAction {
+    Close,
+}
+
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum VerifyPhase {
+    Running,
+    Finished,
+    Cancelled,
+}
+
+
+pub struct ChecksumVerifyPanel {
+    manifest_path: PathBuf,
+    manifest: ChecksumManifest,
+    statuses: Vec<Option<VerifyStatus>>,
+    summary: VerifySummary,
+    phase: VerifyPhase,
+    cancel: Arc<AtomicBool>,
+    focus_handle: FocusHandle,
+    focus_trap: FocusTrap,
+    pending_action: Option<ChecksumVerifyAction>,
+}
+
+
+const PRIMARY_FOCUS: usize = 0;
+
+impl ChecksumVerifyPanel {
+    pub fn new(manifest_path: PathBuf, manifest: ChecksumManifest, cx: &mut Context<Self>) -> Self {
+        let statuses = vec![None; manifest.entries.len()];
+        Self {
+            manifest_path,
+            manifest,
+            statuses,
+            summary: VerifySummary::default(),
+            phase: VerifyPhase::Running,
+            cancel: Arc::new(AtomicBool::new(false)),
+            focus_handle: cx.focus_handle(),
+            focus_trap: FocusTrap::new(vec![cx.focus_handle()]),
+            pending_action: None,
+        }
+    }
+
+    pub fn cancel_handle(&self) -> Arc<AtomicBool> {
+        self.cancel.clone()
+    }
+
+    pub fn summary(&self) -> &VerifySummary {
+        &self.summary
+    }
+
+    pub fn record(&mut self, index: usize, status: VerifyStatus, cx: &mut Context<Self>) {
+        if let Some(slot) = self.statuses.get_mut(index) {
+            self.summary.record(&status);
+            *slot = Some(status);
+            cx.notify();
+        }
+    }
+
+    pub fn finish(&mut self, completed: bool, cx: &mut Context<Self>) {
+        self.phase = if completed {
+            VerifyPhase::Finished
+        } else {
+            VerifyPhase::Cancelled
+        };
+        cx.notify();
+    }
+
+    pub fn take_pending_action(&mut self) -> Option<ChecksumVerifyAction> {
+        self.pending_action.take()
+    }
+
+
+    pub fn take_restore_focus(&mut self) -> Option<FocusHandle> {
+        self.focus_trap.take_restore_target()
+    }
+
+    fn handle_key_down(&mut self, event: &KeyDownEvent, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(key) = TrapKey::from_keystroke(&event.keystroke) else {
+            return;
+        };
+        match key {
+            TrapKey::Next | TrapKey::Previous => {
+                self.focus_trap.cycle(key == TrapKey::Previous, window);
+            }
+            TrapKey::Dismiss => self.close(cx),
+            TrapKey::Confirm => self.primary(cx),
+        }
+        cx.stop_propagation();
+    }
+
+    fn primary(&mut self, cx: &mut Context<Self>) {
+        if self.phase == VerifyPhase::Running {
+            self.cancel.store(true, Ordering::Relaxed);
+            cx.notify();
+        } else {
+            self.close(cx);
+        }
+    }
+
+    fn close(&mut self, cx: &mut Context<Self>) {
+        self.cancel.store(true, Ordering::Relaxed);
+        self.pending_action = Some(ChecksumVerifyAction::Close);
+        cx.notify();
+    }
+
+    fn status_line(&self) -> String {
+        let total = self.manifest.entries.len();
+        let checked = self.summary.checked();
+        let counts = format!(
+            "{} OK, {} mismatched, {} missing",
+            self.summary.matched, self.summary.mismatched, self.summary.missing
+        );
+        let counts = if self.summary.unreadable > 0 {
+            format!("{}, {} unreadable", counts, self.summary.unreadable)
+        } else {
+            counts
+        };
+        match self.phase {
+            VerifyPhase::Running if self.cancel.load(Ordering::Relaxed) => "Cancelling…".to_string(),
+            VerifyPhase::Running => format!("Checked {} of {} files", checked, total),
+            VerifyPhase::Finished if self.summary.all_matched() => {
+                format!("All {} files verified", total)
+            }
+            VerifyPhase::Finished => counts,
+            VerifyPhase::Cancelled => format!("Cancelled after {} of {} files — {}", checked, total, counts),
+        }
+    }
+}
+
+impl Focusable for ChecksumVerifyPanel {
+    fn focus_handle(&self, _cx: &App) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for ChecksumVerifyPanel {
+    fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        self.focus_trap.activate(window, cx);
+        let primary_focused = self.focus_trap.is_focused(PRIMARY_FOCUS, window);
+
+        let colors = theme_colors();
+        let bg_primary = colors.bg_primary;
+        let bg_secondary = colors.bg_secondary;
+        let border_color = colors.border_default;
+        let text_primary = colors.text_primary;
+        let text_secondary = colors.text_secondary;
+        let text_muted = colors.text_muted;
+        let accent_primary = colors.accent_primary;
+        let hover_bg = colors.bg_hover;
+
+        let manifest_name = self
+            .manifest_path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let total = self.manifest.entries.len().max(1);
+        let fraction = self.summary.checked() as f32 / total as f32;
+        let is_running = self.phase == VerifyPhase::Running;
+        let status_line = self.status_line();
+
+        let rows: Vec<_> = self
+            .manifest
+            .entries
+            .iter()
+            .zip(&self.statuses)
+            .enumerate()
+            .map(|(index, (entry, status))| {
+                let (icon, color, label) = match status {
+                    Some(VerifyStatus::Match) => ("check", colors.success, "OK".to_string()),
+                    Some(status @ VerifyStatus::Mismatch { .. }) => {
+                        ("x", colors.error, status.label().to_string())
+                    }
+                    Some(VerifyStatus::Missing) => ("triangle-alert", colors.warning, "Missing".to_string()),
+                    Some(VerifyStatus::Unreadable(reason)) => ("triangle-alert", colors.error, reason.clone()),
+                    None => ("file", text_muted, "Pending".to_string()),
+                };
+                div()
+                    .id(SharedString::from(format!("checksum-row-{}", index)))
+                    .px_3()
+                    .py_1()
+                    .flex()
+                    .items_center()
+                    .gap_2()
+                    .child(
+                        svg()
+                            .path(SharedString::from(format!("assets/icons/{}.svg", icon)))
+                            .size(px(14.0))
+                            .flex_shrink_0()
+                            .text_color(color),
+                    )
+                    .child(
+                        div()
+                            .flex_1()
+                            .overflow_hidden()
+                            .text_ellipsis()
+                            .text_sm()
+                            .text_color(text_primary)
+                            .child(entry.file_name.clone()),
+                    )
+                    .child(div().text_xs().text_color(color).child(label))
+            })
+            .collect();
+
+        div()
+            .id("checksum-verify-panel")
+            .track_focus(&self.focus_handle)
+            .on_key_down(cx.listener(Self::handle_key_down))
+            .w(px(520.0))
+            .bg(bg_primary)
+            .border_1()
+            .border_color(border_color)
+            .rounded_lg()
+            .shadow_xl()
+            .flex()
+            .flex_col()
+            .overflow_hidden()
+            .child(
+                div()
+                    .px_4()
+                    .py_3()
+                    .bg(bg_secondary)
+                    .border_b_1()
+                    .border_color(border_color)
+                    .flex()
+                    .items_center()
+                    .gap_2()
+                    .child(
+                        svg()
+                            .path("assets/icons/shield-check.svg")
+                            .size(px(18.0))
+                            .text_color(accent_primary),
+                    )
+                    .child(
+                        div()
+                            .flex()
+                            .flex_col()
+                            .child(
+                                div()
+                                    .text_sm()
+                                    .font_weight(gpui::FontWeight::SEMIBOLD)
+                                    .text_color(text_primary)
+                                    .child("Verify Checksums"),
+                            )
+                            .child(
+                                div()
+                                    .text_xs()
+                                    .text_color(text_secondary)
+                                    .child(format!(
+                                        "{} · {}",
+                                        manifest_name,
+                                        self.manifest.algorithm.display_name()
+                                    )),
+                            ),
+                    ),
+            )
+            .child(
+                div()
+                    .px_4()
+                    .pt_3()
+                    .flex()
+                    .flex_col()
+                    .gap_2()
+                    .child(
+                        div()
+                            .w_full()
+                            .h(px(4.0))
+                            .bg(border_color)
+                            .rounded_full()
+                            .overflow_hidden()
+                            .child(
+                                div()
+                                    .h_full()
+                                    .w(gpui::relative(fraction))
+                                    .bg(accent_primary)
+                                    .rounded_full(),
+                            ),
+                    )
+                    .child(
+                        div()
+                            .text_xs()
+                            .text_color(text_secondary)
+                            .child(status_line),
+                    ),
+            )
+            .child(
+                div()
+                    .id("checksum-verify-results")
+                    .p_2()
+                    .max_h(px(320.0))
+                    .overflow_y_scroll()
+                    .flex()
+                    .flex_col()
+                    .children(rows),
+            )
+            .child(
+                div()
+                    .px_4()
+                    .py_3()
+                    .bg(bg_secondary)
+                    .border_t_1()
+                    .border_color(border_color)
+                    .flex()
+                    .justify_end()
+                    .child(
+                        div()
+                            .id("checksum-verify-primary-btn")
+                            .track_focus(self.focus_trap.handle(PRIMARY_FOCUS))
+                            .px_4()
+                            .py_2()
+                            .rounded_md()
+                            .border_1()
+                            .border_color(border_color)
+                            .text_sm()
+                            .text_color(text_primary)
+                            .cursor_pointer()
+                            .hover(|s| s.bg(hover_bg))
+                            .on_click(cx.listener(|panel, _, _, cx| {
+                                panel.primary(cx);
+                            }))
+                            .map(|el| focus_ring(el, primary_focused))
+                            .child(if is_running { "Cancel" } else { "Close" }),
+                    ),
+            )
+    }
+}
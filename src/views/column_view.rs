@@ -1,4 +1,5 @@
 use std::path::PathBuf;
+use std::rc::Rc;
 
 use gpui::{
     actions, div, prelude::*, px, svg, App, Context, FocusHandle, Focusable, InteractiveElement,
@@ -7,7 +8,11 @@ use gpui::{
 };
 
 use super::file_list::{get_file_icon, get_file_icon_color};
-use crate::models::{Column, ColumnView, FileEntry};
+use super::tag_ui::{render_tag_dot_buttons, tags_for_entries, TagSelectHandler};
+use crate::models::{
+    click_behavior, click_intent, icon_asset_path, ClickIntent, Column, ColumnView, FileEntry, Tag,
+    TagManager,
+};
 
 actions!(
     column_view,
@@ -39,21 +44,17 @@ pub struct ColumnViewComponent {
     context_menu_position: Option<Point<Pixels>>,
     context_menu_column: Option<usize>,
     context_menu_entry: Option<usize>,
+    pending_tag_filter: Option<Tag>,
 }
 
 impl ColumnViewComponent {
     pub fn new(root: PathBuf, cx: &mut Context<Self>) -> Self {
-        Self {
-            column_view: ColumnView::new(root),
-            focus_handle: cx.focus_handle(),
-            pending_navigation: None,
-            context_menu_position: None,
-            context_menu_column: None,
-            context_menu_entry: None,
-        }
+        Self::with_column_view(ColumnView::new(root), cx)
     }
 
     pub fn with_column_view(column_view: ColumnView, cx: &mut Context<Self>) -> Self {
+        cx.observe_global::<TagManager>(|_, cx| cx.notify()).detach();
+
         Self {
             column_view,
             focus_handle: cx.focus_handle(),
@@ -61,6 +62,7 @@ impl ColumnViewComponent {
             context_menu_position: None,
             context_menu_column: None,
             context_menu_entry: None,
+            pending_tag_filter: None,
         }
     }
 
@@ -76,6 +78,10 @@ impl ColumnViewComponent {
         self.pending_navigation.take()
     }
 
+    pub fn take_pending_tag_filter(&mut self) -> Option<Tag> {
+        self.pending_tag_filter.take()
+    }
+
     pub fn close_context_menu(&mut self) {
         self.context_menu_position = None;
         self.context_menu_column = None;
@@ -246,6 +252,19 @@ impl Render for ColumnViewComponent {
         let menu_bg = gpui::rgb(0x161b22);
 
         let entity = cx.entity().clone();
+        let column_tags: Vec<Vec<Vec<Tag>>> = columns
+            .iter()
+            .map(|column| tags_for_entries(cx, &column.entries))
+            .collect();
+        let on_tag_select: TagSelectHandler = {
+            let entity = entity.clone();
+            Rc::new(move |tag: &Tag, _window: &mut Window, cx: &mut App| {
+                entity.update(cx, |view, cx| {
+                    view.pending_tag_filter = Some(tag.clone());
+                    cx.notify();
+                });
+            })
+        };
 
         div()
             .id("column-view")
@@ -290,6 +309,8 @@ impl Render for ColumnViewComponent {
                             render_column(
                                 col_idx,
                                 column,
+                                &column_tags[col_idx],
+                                on_tag_select.clone(),
                                 column_width,
                                 entity.clone(),
                                 bg_dark,
@@ -444,6 +465,8 @@ impl Render for ColumnViewComponent {
 fn render_column(
     col_idx: usize,
     column: &Column,
+    entry_tags: &[Vec<Tag>],
+    on_tag_select: TagSelectHandler,
     column_width: f32,
     entity: gpui::Entity<ColumnViewComponent>,
     bg_dark: gpui::Rgba,
@@ -537,7 +560,12 @@ fn render_column(
                                 move |event, _window, cx| {
                                     entity.update(cx, |view, cx| {
                                         view.close_context_menu();
-                                        if event.click_count() == 2 && is_dir {
+                                        let intent = click_intent(
+                                            click_behavior(),
+                                            event.click_count(),
+                                            &event.modifiers(),
+                                        );
+                                        if intent == ClickIntent::Open && is_dir {
                                             view.pending_navigation = Some(entry_path.clone());
                                         } else {
                                             view.column_view.select(col_idx, entry_idx);
@@ -560,10 +588,7 @@ fn render_column(
                             })
                             .child(
                                 svg()
-                                    .path(SharedString::from(format!(
-                                        "assets/icons/{}.svg",
-                                        icon_name
-                                    )))
+                                    .path(SharedString::from(icon_asset_path(icon_name)))
                                     .size(px(16.0))
                                     .text_color(icon_color),
                             )
@@ -579,6 +604,16 @@ fn render_column(
                                     .truncate()
                                     .child(name),
                             )
+                            .when_some(
+                                entry_tags.get(entry_idx).filter(|tags| !tags.is_empty()),
+                                |this, tags| {
+                                    this.child(render_tag_dot_buttons(
+                                        &format!("col-{}-entry-{}", col_idx, entry_idx),
+                                        tags,
+                                        on_tag_select.clone(),
+                                    ))
+                                },
+                            )
                             .when(is_dir, |this| {
                                 this.child(
                                     svg()
@@ -0,0 +1,466 @@
+use gpui::{
+    div, prelude::*, px, svg, App, Context, Entity, FocusHandle, Focusable, InteractiveElement,
+    IntoElement, KeyDownEvent, MouseButton, ParentElement, Render, SharedString, Styled, Window,
+};
+
+use crate::models::{theme_colors, ViewMode};
+use adabraka_ui::components::input::{InputEvent, InputState};
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PaletteCommand {
+    NewFolder,
+    NewFolderWithSelection,
+    NewFile,
+    ToggleTerminal,
+    ToggleHiddenFiles,
+    SwitchView(ViewMode),
+    GoToHome,
+    GoToDocuments,
+    GoToDownloads,
+    OpenSettings,
+    EmptyTrash,
+    Refresh,
+    FindDuplicates,
+    ApplyColumnWidthsToAll,
+    CustomizeToolbar,
+    CycleAnimationSpeed,
+    CycleAnimationEasing,
+    ToggleFolderSizes,
+    CycleScriptOpenAction,
+    CycleProgramOpenAction,
+    CompressSelection,
+    RenameSelection,
+    CopySelectionPath,
+    MoveSelectionToTrash,
+}
+
+impl PaletteCommand {
+    pub const ALL: &'static [PaletteCommand] = &[
+        PaletteCommand::NewFolder,
+        PaletteCommand::NewFolderWithSelection,
+        PaletteCommand::NewFile,
+        PaletteCommand::ToggleTerminal,
+        PaletteCommand::ToggleHiddenFiles,
+        PaletteCommand::SwitchView(ViewMode::List),
+        PaletteCommand::SwitchView(ViewMode::Grid),
+        PaletteCommand::SwitchView(ViewMode::Details),
+        PaletteCommand::GoToHome,
+        PaletteCommand::GoToDocuments,
+        PaletteCommand::GoToDownloads,
+        PaletteCommand::OpenSettings,
+        PaletteCommand::EmptyTrash,
+        PaletteCommand::Refresh,
+        PaletteCommand::FindDuplicates,
+        PaletteCommand::ApplyColumnWidthsToAll,
+        PaletteCommand::CustomizeToolbar,
+        PaletteCommand::CycleAnimationSpeed,
+        PaletteCommand::CycleAnimationEasing,
+        PaletteCommand::ToggleFolderSizes,
+        PaletteCommand::CycleScriptOpenAction,
+        PaletteCommand::CycleProgramOpenAction,
+        PaletteCommand::CompressSelection,
+        PaletteCommand::RenameSelection,
+        PaletteCommand::CopySelectionPath,
+        PaletteCommand::MoveSelectionToTrash,
+    ];
+
+    pub fn title(&self) -> &'static str {
+        match self {
+            PaletteCommand::NewFolder => "New Folder",
+            PaletteCommand::NewFolderWithSelection => "New Folder with Selection",
+            PaletteCommand::NewFile => "New File",
+            PaletteCommand::ToggleTerminal => "Toggle Terminal",
+            PaletteCommand::ToggleHiddenFiles => "Toggle Hidden Files",
+            PaletteCommand::SwitchView(ViewMode::List) => "Switch to List View",
+            PaletteCommand::SwitchView(ViewMode::Grid) => "Switch to Grid View",
+            PaletteCommand::SwitchView(ViewMode::Details) => "Switch to Details View",
+            PaletteCommand::GoToHome => "Go to Home",
+            PaletteCommand::GoToDocuments => "Go to Documents",
+            PaletteCommand::GoToDownloads => "Go to Downloads",
+            PaletteCommand::OpenSettings => "Open Settings",
+            PaletteCommand::EmptyTrash => "Empty Trash",
+            PaletteCommand::Refresh => "Refresh",
+            PaletteCommand::FindDuplicates => "Find Duplicates",
+            PaletteCommand::ApplyColumnWidthsToAll => "Apply Column Widths to All Folders",
+            PaletteCommand::CustomizeToolbar => "Customize Toolbar",
+            PaletteCommand::CycleAnimationSpeed => "Cycle Animation Speed",
+            PaletteCommand::CycleAnimationEasing => "Cycle Animation Easing",
+            PaletteCommand::ToggleFolderSizes => "Toggle Folder Sizes",
+            PaletteCommand::CycleScriptOpenAction => "Cycle Script Open Action",
+            PaletteCommand::CycleProgramOpenAction => "Cycle Program Open Action",
+            PaletteCommand::CompressSelection => "Compress Selection",
+            PaletteCommand::RenameSelection => "Rename Selection",
+            PaletteCommand::CopySelectionPath => "Copy Path of Selection",
+            PaletteCommand::MoveSelectionToTrash => "Move Selection to Trash",
+        }
+    }
+
+    pub fn icon(&self) -> &'static str {
+        match self {
+            PaletteCommand::NewFolder | PaletteCommand::NewFolderWithSelection => "folder-plus",
+            PaletteCommand::NewFile => "file-plus",
+            PaletteCommand::ToggleTerminal => "terminal",
+            PaletteCommand::ToggleHiddenFiles => "eye",
+            PaletteCommand::SwitchView(ViewMode::List) => "list",
+            PaletteCommand::SwitchView(ViewMode::Grid) => "grid-2x2",
+            PaletteCommand::SwitchView(ViewMode::Details) => "columns-3",
+            PaletteCommand::GoToHome => "house",
+            PaletteCommand::GoToDocuments => "file-text",
+            PaletteCommand::GoToDownloads => "folder-output",
+            PaletteCommand::OpenSettings => "file-cog",
+            PaletteCommand::EmptyTrash | PaletteCommand::MoveSelectionToTrash => "trash-2",
+            PaletteCommand::Refresh => "refresh-cw",
+            PaletteCommand::FindDuplicates => "files",
+            PaletteCommand::ApplyColumnWidthsToAll => "columns-3",
+            PaletteCommand::CustomizeToolbar => "layout-grid",
+            PaletteCommand::CycleAnimationSpeed => "refresh-cw",
+            PaletteCommand::CycleAnimationEasing => "sparkles",
+            PaletteCommand::ToggleFolderSizes => "hard-drive",
+            PaletteCommand::CycleScriptOpenAction => "file-code",
+            PaletteCommand::CycleProgramOpenAction => "file-terminal",
+            PaletteCommand::CompressSelection => "archive",
+            PaletteCommand::RenameSelection => "pen",
+            PaletteCommand::CopySelectionPath => "copy",
+        }
+    }
+
+    pub fn requires_selection(&self) -> bool {
+        matches!(
+            self,
+            PaletteCommand::NewFolderWithSelection
+                | PaletteCommand::CompressSelection
+                | PaletteCommand::RenameSelection
+                | PaletteCommand::CopySelectionPath
+                | PaletteCommand::MoveSelectionToTrash
+        )
+    }
+
+    fn matches_query(&self, query: &str) -> bool {
+        let title = self.title().to_lowercase();
+        let mut chars = title.chars();
+        query
+            .to_lowercase()
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .all(|c| chars.any(|t| t == c))
+    }
+}
+
+
+#[derive(Debug, Default)]
+pub struct CommandPalette {
+    query: String,
+    has_selection: bool,
+    highlighted: usize,
+    pending_command: Option<PaletteCommand>,
+}
+
+impl CommandPalette {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn query(&self) -> &str {
+        &self.query
+    }
+
+    pub fn set_query(&mut self, query: impl Into<String>) {
+        self.query = query.into();
+        self.highlighted = 0;
+    }
+
+    pub fn set_has_selection(&mut self, has_selection: bool) {
+        self.has_selection = has_selection;
+        self.highlighted = 0;
+    }
+
+
+    pub fn commands(&self) -> Vec<PaletteCommand> {
+        PaletteCommand::ALL
+            .iter()
+            .copied()
+            .filter(|command| self.has_selection || !command.requires_selection())
+            .filter(|command| command.matches_query(&self.query))
+            .collect()
+    }
+
+    pub fn highlighted(&self) -> Option<PaletteCommand> {
+        self.commands().get(self.highlighted).copied()
+    }
+
+    pub fn highlighted_index(&self) -> usize {
+        self.highlighted
+    }
+
+    pub fn move_highlight(&mut self, delta: isize) {
+        let count = self.commands().len();
+        if count == 0 {
+            self.highlighted = 0;
+            return;
+        }
+        self.highlighted = (self.highlighted as isize + delta).rem_euclid(count as isize) as usize;
+    }
+
+
+    pub fn select(&mut self, command: PaletteCommand) -> bool {
+        if command.requires_selection() && !self.has_selection {
+            return false;
+        }
+        self.pending_command = Some(command);
+        true
+    }
+
+    pub fn confirm(&mut self) -> bool {
+        match self.highlighted() {
+            Some(command) => self.select(command),
+            None => false,
+        }
+    }
+
+    pub fn take_pending_command(&mut self) -> Option<PaletteCommand> {
+        self.pending_command.take()
+    }
+}
+
+
+pub struct CommandPaletteView {
+    palette: CommandPalette,
+    query_input: Entity<InputState>,
+    focus_handle: FocusHandle,
+    is_visible: bool,
+    should_focus_input: bool,
+}
+
+impl CommandPaletteView {
+    pub fn new(cx: &mut Context<Self>) -> Self {
+        let query_input = cx.new(InputState::new);
+
+        cx.subscribe(&query_input, |view: &mut Self, input, event: &InputEvent, cx| {
+            match event {
+                InputEvent::Change => {
+                    let query = input.read(cx).content.to_string();
+                    view.palette.set_query(query);
+                    cx.notify();
+                }
+                InputEvent::Enter => view.confirm(cx),
+                _ => {}
+            }
+        })
+        .detach();
+
+        Self {
+            palette: CommandPalette::new(),
+            query_input,
+            focus_handle: cx.focus_handle(),
+            is_visible: false,
+            should_focus_input: false,
+        }
+    }
+
+    pub fn show(&mut self, has_selection: bool, cx: &mut Context<Self>) {
+        self.palette.set_query("");
+        self.palette.set_has_selection(has_selection);
+        self.query_input.update(cx, |input, _| {
+            input.content = "".into();
+        });
+        self.is_visible = true;
+        self.should_focus_input = true;
+        cx.notify();
+    }
+
+    pub fn hide(&mut self, cx: &mut Context<Self>) {
+        self.is_visible = false;
+        cx.notify();
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.is_visible
+    }
+
+    pub fn take_pending_command(&mut self) -> Option<PaletteCommand> {
+        self.palette.take_pending_command()
+    }
+
+    fn select(&mut self, command: PaletteCommand, cx: &mut Context<Self>) {
+        if self.palette.select(command) {
+            self.hide(cx);
+        }
+    }
+
+    fn confirm(&mut self, cx: &mut Context<Self>) {
+        if self.palette.confirm() {
+            self.hide(cx);
+        }
+    }
+
+    fn handle_key_down(&mut self, event: &KeyDownEvent, _window: &mut Window, cx: &mut Context<Self>) {
+        match event.keystroke.key.as_str() {
+            "up" => self.palette.move_highlight(-1),
+            "down" => self.palette.move_highlight(1),
+            "escape" => self.hide(cx),
+            _ => return,
+        }
+        cx.notify();
+        cx.stop_propagation();
+    }
+}
+
+impl Focusable for CommandPaletteView {
+    fn focus_handle(&self, _cx: &App) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for CommandPaletteView {
+    fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        if !self.is_visible {
+            return div().into_any_element();
+        }
+
+        if self.should_focus_input {
+            window.focus(&self.query_input.read(cx).focus_handle(cx));
+            self.should_focus_input = false;
+        }
+
+        let theme = theme_colors();
+        let commands = self.palette.commands();
+        let highlighted = self.palette.highlighted_index();
+
+        div()
+            .id("command-palette-overlay")
+            .absolute()
+            .inset_0()
+            .bg(gpui::rgba(0x00000080))
+            .flex()
+            .flex_col()
+            .items_center()
+            .pt(px(80.0))
+            .on_mouse_down(
+                MouseButton::Left,
+                cx.listener(|view, _event, _window, cx| {
+                    view.hide(cx);
+                }),
+            )
+            .child(
+                div()
+                    .id("command-palette")
+                    .track_focus(&self.focus_handle)
+                    .on_key_down(cx.listener(Self::handle_key_down))
+                    .w(px(520.0))
+                    .max_h(px(420.0))
+                    .bg(theme.bg_secondary)
+                    .border_1()
+                    .border_color(theme.border_default)
+                    .rounded_lg()
+                    .shadow_lg()
+                    .flex()
+                    .flex_col()
+                    .overflow_hidden()
+                    .on_mouse_down(MouseButton::Left, |_event, _window, cx| {
+                        cx.stop_propagation();
+                    })
+                    .child(
+                        div()
+                            .px_3()
+                            .py_2()
+                            .border_b_1()
+                            .border_color(theme.border_subtle)
+                            .child(self.query_input.clone()),
+                    )
+                    .child(
+                        div()
+                            .id("command-palette-list")
+                            .flex_1()
+                            .overflow_y_scroll()
+                            .p_1()
+                            .when(commands.is_empty(), |s| {
+                                s.child(
+                                    div()
+                                        .px_3()
+                                        .py_2()
+                                        .text_sm()
+                                        .text_color(theme.text_muted)
+                                        .child("No matching commands"),
+                                )
+                            })
+                            .children(commands.into_iter().enumerate().map(|(ix, command)| {
+                                let is_highlighted = ix == highlighted;
+                                div()
+                                    .id(SharedString::from(format!("palette-command-{}", ix)))
+                                    .flex()
+                                    .items_center()
+                                    .gap_2()
+                                    .px_3()
+                                    .py_1p5()
+                                    .rounded_md()
+                                    .cursor_pointer()
+                                    .text_sm()
+                                    .text_color(theme.text_primary)
+                                    .when(is_highlighted, |s| s.bg(theme.bg_selected))
+                                    .when(!is_highlighted, |s| s.hover(|h| h.bg(theme.bg_hover)))
+                                    .on_mouse_down(
+                                        MouseButton::Left,
+                                        cx.listener(move |view, _event, _window, cx| {
+                                            view.select(command, cx);
+                                        }),
+                                    )
+                                    .child(
+                                        svg()
+                                            .path(SharedString::from(format!(
+                                                "assets/icons/{}.svg",
+                                                command.icon()
+                                            )))
+                                            .size(px(14.0))
+                                            .text_color(theme.text_secondary),
+                                    )
+                                    .child(command.title())
+                            })),
+                    ),
+            )
+            .into_any_element()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_selecting_command_enqueues_it() {
+        let mut palette = CommandPalette::new();
+        assert!(palette.select(PaletteCommand::NewFolder));
+        assert_eq!(palette.take_pending_command(), Some(PaletteCommand::NewFolder));
+        assert_eq!(palette.take_pending_command(), None);
+    }
+
+    #[test]
+    fn test_confirm_enqueues_highlighted_match() {
+        let mut palette = CommandPalette::new();
+        palette.set_query("grid");
+        assert_eq!(palette.commands(), vec![PaletteCommand::SwitchView(ViewMode::Grid)]);
+
+        assert!(palette.confirm());
+        assert_eq!(
+            palette.take_pending_command(),
+            Some(PaletteCommand::SwitchView(ViewMode::Grid))
+        );
+
+        palette.set_query("go dl");
+        assert!(palette.confirm());
+        assert_eq!(palette.take_pending_command(), Some(PaletteCommand::GoToDownloads));
+    }
+
+    #[test]
+    fn test_selection_commands_hidden_without_selection() {
+        let mut palette = CommandPalette::new();
+        assert!(!palette.commands().contains(&PaletteCommand::CompressSelection));
+        assert!(!palette.select(PaletteCommand::CompressSelection));
+        assert_eq!(palette.take_pending_command(), None);
+
+        palette.set_has_selection(true);
+        assert!(palette.commands().contains(&PaletteCommand::CompressSelection));
+        assert!(palette.select(PaletteCommand::CompressSelection));
+        assert_eq!(palette.take_pending_command(), Some(PaletteCommand::CompressSelection));
+    }
+}
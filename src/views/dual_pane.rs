@@ -5,7 +5,7 @@ use gpui::{
     InteractiveElement, IntoElement, KeyBinding, ParentElement, Render, Styled, Window,
 };
 
-use crate::models::{theme_colors, DragPayload, DualPane, FileEntry, PaneSide};
+use crate::models::{theme_colors, DragPayload, DualPane, DualPaneLayout, FileEntry, PaneSide};
 use crate::views::FileListView;
 
 actions!(
@@ -86,6 +86,7 @@ pub struct DualPaneView {
     right_drop_hover: bool,
 
     dragging_from: Option<PaneSide>,
+    layout_changed: bool,
 }
 
 impl DualPaneView {
@@ -104,11 +105,21 @@ impl DualPaneView {
             left_drop_hover: false,
             right_drop_hover: false,
             dragging_from: None,
+            layout_changed: false,
         }
     }
 
     pub fn with_paths(left_path: PathBuf, right_path: PathBuf, cx: &mut Context<Self>) -> Self {
-        let dual_pane = DualPane::with_paths(left_path, right_path);
+        Self::with_dual_pane(DualPane::with_paths(left_path, right_path), cx)
+    }
+
+
+    pub fn from_layout(layout: DualPaneLayout, cx: &mut Context<Self>) -> Self {
+        let home = dirs::home_dir().unwrap_or_else(|| PathBuf::from("/"));
+        Self::with_dual_pane(DualPane::from_layout(layout, &home), cx)
+    }
+
+    fn with_dual_pane(dual_pane: DualPane, cx: &mut Context<Self>) -> Self {
 
         let left_file_list = cx.new(|cx| FileListView::new(cx));
         let right_file_list = cx.new(|cx| FileListView::new(cx));
@@ -122,6 +133,7 @@ impl DualPaneView {
             left_drop_hover: false,
             right_drop_hover: false,
             dragging_from: None,
+            layout_changed: false,
         }
     }
 
@@ -143,18 +155,21 @@ impl DualPaneView {
 
     pub fn enable(&mut self, cx: &mut Context<Self>) {
         self.dual_pane.enable();
+        self.layout_changed = true;
         cx.notify();
     }
 
 
     pub fn disable(&mut self, cx: &mut Context<Self>) {
         self.dual_pane.disable();
+        self.layout_changed = true;
         cx.notify();
     }
 
 
     pub fn toggle(&mut self, cx: &mut Context<Self>) {
         self.dual_pane.toggle();
+        self.layout_changed = true;
         cx.notify();
     }
 
@@ -166,16 +181,42 @@ impl DualPaneView {
 
     pub fn switch_active(&mut self, cx: &mut Context<Self>) {
         self.dual_pane.switch_active();
+        self.layout_changed = true;
         cx.notify();
     }
 
 
     pub fn set_active(&mut self, side: PaneSide, cx: &mut Context<Self>) {
-        self.dual_pane.set_active(side);
+        if self.dual_pane.active_side() != side {
+            self.dual_pane.set_active(side);
+            self.layout_changed = true;
+        }
+        cx.notify();
+    }
+
+
+    pub fn split_ratio(&self) -> f32 {
+        self.dual_pane.split_ratio()
+    }
+
+
+    pub fn set_split_ratio(&mut self, ratio: f32, cx: &mut Context<Self>) {
+        self.dual_pane.set_split_ratio(ratio);
+        self.layout_changed = true;
         cx.notify();
     }
 
 
+    pub fn layout(&self) -> DualPaneLayout {
+        self.dual_pane.layout()
+    }
+
+
+    pub fn take_layout_change(&mut self) -> Option<DualPaneLayout> {
+        std::mem::take(&mut self.layout_changed).then(|| self.dual_pane.layout())
+    }
+
+
     pub fn inner(&self) -> &DualPane {
         &self.dual_pane
     }
@@ -220,6 +261,7 @@ impl DualPaneView {
     pub fn navigate_left(&mut self, path: PathBuf, cx: &mut Context<Self>) {
         self.dual_pane.left_pane_mut().navigate_to(path.clone());
         self.pending_action = Some(DualPaneAction::NavigateLeft(path));
+        self.layout_changed = true;
         cx.notify();
     }
 
@@ -227,6 +269,7 @@ impl DualPaneView {
     pub fn navigate_right(&mut self, path: PathBuf, cx: &mut Context<Self>) {
         self.dual_pane.right_pane_mut().navigate_to(path.clone());
         self.pending_action = Some(DualPaneAction::NavigateRight(path));
+        self.layout_changed = true;
         cx.notify();
     }
 
@@ -437,6 +480,7 @@ impl Render for DualPaneView {
         let theme = theme_colors();
         let is_enabled = self.dual_pane.is_enabled();
         let active_side = self.dual_pane.active_side();
+        let split_ratio = self.dual_pane.split_ratio();
 
         let left_path = self.dual_pane.left_pane().path.clone();
         let right_path = self.dual_pane.right_pane().path.clone();
@@ -476,7 +520,8 @@ impl Render for DualPaneView {
                 this.child(
                     div()
                         .id("left-pane")
-                        .flex_1()
+                        .flex_none()
+                        .w(gpui::relative(split_ratio))
                         .flex()
                         .flex_col()
                         .border_r_1()
@@ -0,0 +1,488 @@
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use gpui::{
+    div, prelude::*, px, svg, App, Context, FocusHandle, Focusable, InteractiveElement,
+    IntoElement, KeyDownEvent, ParentElement, Render, SharedString, Styled, Window,
+};
+
+use crate::models::{theme_colors, DuplicateGroup, DuplicateScanProgress};
+use crate::utils::format_size;
+use crate::views::{focus_ring, FocusTrap, TrapKey};
+
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum DuplicateFinderAction {
+    Close,
+    Rescan { recursive: bool },
+    Trash(Vec<PathBuf>),
+}
+
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum ScanPhase {
+    Scanning,
+    Finished,
+    Cancelled,
+}
+
+
+pub struct DuplicateFinderPanel {
+    root: PathBuf,
+    recursive: bool,
+    progress: Option<DuplicateScanProgress>,
+    groups: Vec<DuplicateGroup>,
+    keep: Vec<usize>,
+    phase: ScanPhase,
+    cancel: Arc<AtomicBool>,
+    focus_handle: FocusHandle,
+    focus_trap: FocusTrap,
+    pending_action: Option<DuplicateFinderAction>,
+}
+
+
+const PRIMARY_FOCUS: usize = 0;
+
+impl DuplicateFinderPanel {
+    pub fn new(root: PathBuf, recursive: bool, cx: &mut Context<Self>) -> Self {
+        Self {
+            root,
+            recursive,
+            progress: None,
+            groups: Vec::new(),
+            keep: Vec::new(),
+            phase: ScanPhase::Scanning,
+            cancel: Arc::new(AtomicBool::new(false)),
+            focus_handle: cx.focus_handle(),
+            focus_trap: FocusTrap::new(vec![cx.focus_handle()]),
+            pending_action: None,
+        }
+    }
+
+    pub fn cancel_handle(&self) -> Arc<AtomicBool> {
+        self.cancel.clone()
+    }
+
+    pub fn record_progress(&mut self, progress: DuplicateScanProgress, cx: &mut Context<Self>) {
+        self.progress = Some(progress);
+        cx.notify();
+    }
+
+    pub fn finish(&mut self, groups: Option<Vec<DuplicateGroup>>, cx: &mut Context<Self>) {
+        match groups {
+            Some(groups) => {
+                self.keep = vec![0; groups.len()];
+                self.groups = groups;
+                self.phase = ScanPhase::Finished;
+            }
+            None => self.phase = ScanPhase::Cancelled,
+        }
+        cx.notify();
+    }
+
+    pub fn remove_paths(&mut self, removed: &[PathBuf], cx: &mut Context<Self>) {
+        let mut groups = Vec::new();
+        let mut keep = Vec::new();
+        for (mut group, kept) in self.groups.drain(..).zip(self.keep.drain(..)) {
+            let kept_path = group.paths.get(kept).cloned();
+            group.paths.retain(|path| !removed.contains(path));
+            if group.paths.len() > 1 {
+                let kept = kept_path
+                    .and_then(|kept| group.paths.iter().position(|path| *path == kept))
+                    .unwrap_or(0);
+                keep.push(kept);
+                groups.push(group);
+            }
+        }
+        self.groups = groups;
+        self.keep = keep;
+        cx.notify();
+    }
+
+    pub fn take_pending_action(&mut self) -> Option<DuplicateFinderAction> {
+        self.pending_action.take()
+    }
+
+
+    pub fn take_restore_focus(&mut self) -> Option<FocusHandle> {
+        self.focus_trap.take_restore_target()
+    }
+
+    fn handle_key_down(&mut self, event: &KeyDownEvent, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(key) = TrapKey::from_keystroke(&event.keystroke) else {
+            return;
+        };
+        match key {
+            TrapKey::Next | TrapKey::Previous => {
+                self.focus_trap.cycle(key == TrapKey::Previous, window);
+            }
+            TrapKey::Dismiss => self.close(cx),
+            TrapKey::Confirm => self.primary(cx),
+        }
+        cx.stop_propagation();
+    }
+
+    fn primary(&mut self, cx: &mut Context<Self>) {
+        if self.phase == ScanPhase::Scanning {
+            self.cancel.store(true, Ordering::Relaxed);
+            cx.notify();
+        } else {
+            self.close(cx);
+        }
+    }
+
+    fn close(&mut self, cx: &mut Context<Self>) {
+        self.cancel.store(true, Ordering::Relaxed);
+        self.pending_action = Some(DuplicateFinderAction::Close);
+        cx.notify();
+    }
+
+    fn toggle_recursive(&mut self, cx: &mut Context<Self>) {
+        self.cancel.store(true, Ordering::Relaxed);
+        self.pending_action = Some(DuplicateFinderAction::Rescan {
+            recursive: !self.recursive,
+        });
+        cx.notify();
+    }
+
+    fn set_keep(&mut self, group: usize, path: usize, cx: &mut Context<Self>) {
+        if let Some(slot) = self.keep.get_mut(group) {
+            *slot = path;
+            cx.notify();
+        }
+    }
+
+    fn trash_extras(&mut self, group: Option<usize>, cx: &mut Context<Self>) {
+        let paths: Vec<PathBuf> = self
+            .groups
+            .iter()
+            .zip(&self.keep)
+            .enumerate()
+            .filter(|(index, _)| group.map_or(true, |group| group == *index))
+            .flat_map(|(_, (group, keep))| group.extras(*keep))
+            .collect();
+        if !paths.is_empty() {
+            self.pending_action = Some(DuplicateFinderAction::Trash(paths));
+            cx.notify();
+        }
+    }
+
+    fn status_line(&self) -> String {
+        let reclaimable: u64 = self.groups.iter().map(|g| g.reclaimable_bytes()).sum();
+        match self.phase {
+            ScanPhase::Scanning if self.cancel.load(Ordering::Relaxed) => "Cancelling…".to_string(),
+            ScanPhase::Scanning => match self.progress {
+                Some(progress) => format!(
+                    "Comparing {} of {} same-size files",
+                    progress.hashed, progress.to_hash
+                ),
+                None => "Scanning for files…".to_string(),
+            },
+            ScanPhase::Finished if self.groups.is_empty() => "No duplicates found".to_string(),
+            ScanPhase::Finished => format!(
+                "{} duplicate groups · {} reclaimable",
+                self.groups.len(),
+                format_size(reclaimable)
+            ),
+            ScanPhase::Cancelled => "Scan cancelled".to_string(),
+        }
+    }
+}
+
+impl Focusable for DuplicateFinderPanel {
+    fn focus_handle(&self, _cx: &App) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for DuplicateFinderPanel {
+    fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        self.focus_trap.activate(window, cx);
+        let primary_focused = self.focus_trap.is_focused(PRIMARY_FOCUS, window);
+
+        let colors = theme_colors();
+        let bg_primary = colors.bg_primary;
+        let bg_secondary = colors.bg_secondary;
+        let border_color = colors.border_default;
+        let text_primary = colors.text_primary;
+        let text_secondary = colors.text_secondary;
+        let text_muted = colors.text_muted;
+        let accent_primary = colors.accent_primary;
+        let hover_bg = colors.bg_hover;
+        let error = colors.error;
+
+        let root_name = self
+            .root
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_else(|| self.root.display().to_string());
+        let fraction = match (self.phase, self.progress) {
+            (ScanPhase::Scanning, Some(progress)) if progress.to_hash > 0 => {
+                progress.hashed as f32 / progress.to_hash as f32
+            }
+            (ScanPhase::Scanning, _) => 0.0,
+            _ => 1.0,
+        };
+        let is_scanning = self.phase == ScanPhase::Scanning;
+        let has_groups = !self.groups.is_empty();
+        let recursive = self.recursive;
+        let status_line = self.status_line();
+
+        let groups: Vec<_> = self
+            .groups
+            .iter()
+            .zip(&self.keep)
+            .enumerate()
+            .map(|(group_index, (group, keep))| {
+                let rows = group.paths.iter().enumerate().map(|(path_index, path)| {
+                    let is_kept = path_index == *keep;
+                    let display = path
+                        .strip_prefix(&self.root)
+                        .unwrap_or(path)
+                        .display()
+                        .to_string();
+                    div()
+                        .id(SharedString::from(format!("duplicate-{}-{}", group_index, path_index)))
+                        .px_2()
+                        .py_1()
+                        .rounded_md()
+                        .flex()
+                        .items_center()
+                        .gap_2()
+                        .cursor_pointer()
+                        .hover(|s| s.bg(hover_bg))
+                        .on_click(cx.listener(move |panel, _, _, cx| {
+                            panel.set_keep(group_index, path_index, cx);
+                        }))
+                        .child(
+                            svg()
+                                .path(if is_kept { "assets/icons/check.svg" } else { "assets/icons/file.svg" })
+                                .size(px(14.0))
+                                .flex_shrink_0()
+                                .text_color(if is_kept { accent_primary } else { text_muted }),
+                        )
+                        .child(
+                            div()
+                                .flex_1()
+                                .overflow_hidden()
+                                .text_ellipsis()
+                                .text_sm()
+                                .text_color(if is_kept { text_primary } else { text_secondary })
+                                .child(display),
+                        )
+                        .child(
+                            div()
+                                .text_xs()
+                                .text_color(text_muted)
+                                .child(if is_kept { "Keep" } else { "" }),
+                        )
+                });
+
+                div()
+                    .flex()
+                    .flex_col()
+                    .p_1()
+                    .border_b_1()
+                    .border_color(border_color)
+                    .child(
+                        div()
+                            .px_2()
+                            .py_1()
+                            .flex()
+                            .items_center()
+                            .justify_between()
+                            .child(
+                                div()
+                                    .text_xs()
+                                    .text_color(text_muted)
+                                    .child(format!(
+                                        "{} copies · {} each",
+                                        group.paths.len(),
+                                        format_size(group.size)
+                                    )),
+                            )
+                            .child(
+                                div()
+                                    .id(SharedString::from(format!("duplicate-trash-{}", group_index)))
+                                    .px_2()
+                                    .rounded_md()
+                                    .text_xs()
+                                    .text_color(error)
+                                    .cursor_pointer()
+                                    .hover(|s| s.bg(hover_bg))
+                                    .on_click(cx.listener(move |panel, _, _, cx| {
+                                        panel.trash_extras(Some(group_index), cx);
+                                    }))
+                                    .child("Trash Others"),
+                            ),
+                    )
+                    .children(rows)
+            })
+            .collect();
+
+        div()
+            .id("duplicate-finder-panel")
+            .track_focus(&self.focus_handle)
+            .on_key_down(cx.listener(Self::handle_key_down))
+            .w(px(560.0))
+            .bg(bg_primary)
+            .border_1()
+            .border_color(border_color)
+            .rounded_lg()
+            .shadow_xl()
+            .flex()
+            .flex_col()
+            .overflow_hidden()
+            .child(
+                div()
+                    .px_4()
+                    .py_3()
+                    .bg(bg_secondary)
+                    .border_b_1()
+                    .border_color(border_color)
+                    .flex()
+                    .items_center()
+                    .gap_2()
+                    .child(
+                        svg()
+                            .path("assets/icons/files.svg")
+                            .size(px(18.0))
+                            .text_color(accent_primary),
+                    )
+                    .child(
+                        div()
+                            .flex()
+                            .flex_col()
+                            .child(
+                                div()
+                                    .text_sm()
+                                    .font_weight(gpui::FontWeight::SEMIBOLD)
+                                    .text_color(text_primary)
+                                    .child("Find Duplicates"),
+                            )
+                            .child(
+                                div()
+                                    .text_xs()
+                                    .text_color(text_secondary)
+                                    .child(root_name),
+                            ),
+                    ),
+            )
+            .child(
+                div()
+                    .px_4()
+                    .pt_3()
+                    .flex()
+                    .flex_col()
+                    .gap_2()
+                    .child(
+                        div()
+                            .w_full()
+                            .h(px(4.0))
+                            .bg(border_color)
+                            .rounded_full()
+                            .overflow_hidden()
+                            .child(
+                                div()
+                                    .h_full()
+                                    .w(gpui::relative(fraction))
+                                    .bg(accent_primary)
+                                    .rounded_full(),
+                            ),
+                    )
+                    .child(
+                        div()
+                            .text_xs()
+                            .text_color(text_secondary)
+                            .child(status_line),
+                    ),
+            )
+            .child(
+                div()
+                    .id("duplicate-finder-results")
+                    .p_2()
+                    .max_h(px(360.0))
+                    .overflow_y_scroll()
+                    .flex()
+                    .flex_col()
+                    .children(groups),
+            )
+            .child(
+                div()
+                    .px_4()
+                    .py_3()
+                    .bg(bg_secondary)
+                    .border_t_1()
+                    .border_color(border_color)
+                    .flex()
+                    .items_center()
+                    .justify_between()
+                    .child(
+                        div()
+                            .id("duplicate-finder-recursive")
+                            .flex()
+                            .items_center()
+                            .gap_2()
+                            .text_sm()
+                            .text_color(text_secondary)
+                            .cursor_pointer()
+                            .on_click(cx.listener(|panel, _, _, cx| {
+                                panel.toggle_recursive(cx);
+                            }))
+                            .child(
+                                div()
+                                    .size(px(14.0))
+                                    .rounded_sm()
+                                    .border_1()
+                                    .border_color(if recursive { accent_primary } else { border_color })
+                                    .when(recursive, |el| el.bg(accent_primary)),
+                            )
+                            .child("Include subfolders"),
+                    )
+                    .child(
+                        div()
+                            .flex()
+                            .gap_2()
+                            .when(has_groups && !is_scanning, |el| {
+                                el.child(
+                                    div()
+                                        .id("duplicate-finder-trash-all")
+                                        .px_4()
+                                        .py_2()
+                                        .rounded_md()
+                                        .border_1()
+                                        .border_color(error)
+                                        .text_sm()
+                                        .text_color(error)
+                                        .cursor_pointer()
+                                        .hover(|s| s.bg(hover_bg))
+                                        .on_click(cx.listener(|panel, _, _, cx| {
+                                            panel.trash_extras(None, cx);
+                                        }))
+                                        .child("Trash All Duplicates"),
+                                )
+                            })
+                            .child(
+                                div()
+                                    .id("duplicate-finder-primary-btn")
+                                    .track_focus(self.focus_trap.handle(PRIMARY_FOCUS))
+                                    .px_4()
+                                    .py_2()
+                                    .rounded_md()
+                                    .border_1()
+                                    .border_color(border_color)
+                                    .text_sm()
+                                    .text_color(text_primary)
+                                    .cursor_pointer()
+                                    .hover(|s| s.bg(hover_bg))
+                                    .on_click(cx.listener(|panel, _, _, cx| {
+                                        panel.primary(cx);
+                                    }))
+                                    .map(|el| focus_ring(el, primary_focused))
+                                    .child(if is_scanning { "Cancel" } else { "Close" }),
+                            ),
+                    ),
+            )
+    }
+}
@@ -1,18 +1,23 @@
-use std::path::PathBuf;
-use std::time::SystemTime;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
 
 use gpui::{
-    actions, anchored, div, prelude::*, px, svg, uniform_list, App, Context, Corner, FocusHandle,
-    Focusable, InteractiveElement, IntoElement, KeyBinding, MouseButton, MouseDownEvent,
-    ParentElement, Pixels, Point, Render, ScrollStrategy, SharedString, Styled,
+    actions, anchored, div, prelude::*, pulsating_between, px, svg, uniform_list, Animation,
+    AnimationExt, App, Context, Corner, FocusHandle,
+    Focusable, InteractiveElement, IntoElement, KeyBinding, Modifiers, MouseButton, MouseDownEvent,
+    MouseMoveEvent, ParentElement, Pixels, Point, Render, ScrollStrategy, SharedString, Styled,
     UniformListScrollHandle, Window,
 };
 
 use crate::models::{
-    file_list as file_list_spacing, theme_colors, CloudSyncStatus, FileEntry, IconKey, SortColumn,
-    SortDirection, SortState,
+    apply_pins, click_behavior, click_intent, context_selection, file_list as file_list_spacing, folder_size, icon_asset_path, is_checksum_manifest, load_templates, merge_sorted_entries, middle_click_intent, motion_duration, name_tint, name_tint_mode, pin_rank, reduced_motion, scaled, show_folder_sizes, skeleton_row_count,
+    templates_dir, theme_colors, toggle_marked, ArchiveJobKind, ArchivePlaceholder, ClickIntent, CloudSyncStatus, ColumnWidths, ContextMenuEntryId, ContextMenuRegistry, DetailsColumn, FileEntry, FileTemplate, FolderSize, FolderSizes, GitStatusBadge, IconKey, MiddleClickIntent, PathFormat, ScrollGeometry, SearchSortMode,
+    SortColumn, SortDirection, SortState, Tag, TagManager, ViewMode, VisibleColumns, FOLDER_SIZE_PLACEHOLDER, HIDDEN_ENTRY_OPACITY,
 };
+use crate::utils::middle_ellipsis;
 use crate::views::sidebar::{DraggedFolder, DraggedFolderView};
+use crate::views::{render_tag_dots, TooltipLabel};
 
 
 #[derive(Clone, Debug, PartialEq)]
@@ -20,13 +25,14 @@ pub enum ContextMenuAction {
     Open(PathBuf),
     OpenWith(PathBuf),
     OpenWithApp {
-        file_path: PathBuf,
+        paths: Vec<PathBuf>,
         app_path: PathBuf,
         app_name: String,
     },
     OpenWithOther(PathBuf),
     OpenInNewWindow(PathBuf),
     OpenInNewTab(PathBuf),
+    OpenInBackgroundTab(PathBuf),
     GetInfo(PathBuf),
     Rename(PathBuf),
     Copy(PathBuf),
@@ -35,6 +41,7 @@ pub enum ContextMenuAction {
     Duplicate(PathBuf),
     MoveToTrash(PathBuf),
     Compress(PathBuf),
+    CompressItems(Vec<PathBuf>),
     CompressAs {
         path: PathBuf,
         format: crate::models::ArchiveFormat,
@@ -43,7 +50,13 @@ pub enum ContextMenuAction {
     ExtractToFolder(PathBuf),
     Share(PathBuf),
     CopyPath(PathBuf),
+    CopyPathAs {
+        paths: Vec<PathBuf>,
+        format: PathFormat,
+    },
     ShowInFinder(PathBuf),
+    RevealInNewWindow(PathBuf),
+    TogglePin(PathBuf),
     QuickLook(PathBuf),
     AddToFavorites(PathBuf),
     NewFolder,
@@ -53,6 +66,21 @@ pub enum ContextMenuAction {
     MountImage(PathBuf),
     UnmountImage(PathBuf),
     CreateBootableUSB(PathBuf),
+    SetFolderViewMode {
+        path: PathBuf,
+        mode: Option<ViewMode>,
+    },
+    SetFolderHiddenFiles {
+        path: PathBuf,
+        show: Option<bool>,
+    },
+    EditTags(Vec<PathBuf>),
+    NewFromTemplate(FileTemplate),
+    VerifyChecksums(PathBuf),
+    Custom {
+        id: ContextMenuEntryId,
+        paths: Vec<PathBuf>,
+    },
 }
 
 actions!(
@@ -73,6 +101,8 @@ const ICON_GAP: f32 = file_list_spacing::ICON_GAP;
 const ROW_PADDING_X: f32 = file_list_spacing::ROW_PADDING_X;
 const HEADER_HEIGHT: f32 = file_list_spacing::HEADER_HEIGHT;
 const FOOTER_HEIGHT: f32 = file_list_spacing::FOOTER_HEIGHT;
+const NAME_MAX_CHARS: usize = 72;
+const DEFAULT_LIST_WIDTH: f32 = 800.0;
 
 pub struct FileList {
     entries: Vec<FileEntry>,
@@ -85,6 +115,14 @@ pub struct FileList {
     selected_index: Option<usize>,
     search_query: String,
     sort_state: SortState,
+    search_sort: SearchSortMode,
+    pinned: Vec<PathBuf>,
+    placeholders: Vec<ArchivePlaceholder>,
+    git_badges: HashMap<PathBuf, GitStatusBadge>,
+    marked: Vec<PathBuf>,
+    column_widths: ColumnWidths,
+    visible_columns: VisibleColumns,
+    skeleton_rows: usize,
 }
 
 
@@ -106,10 +144,18 @@ pub struct FileListView {
     context_menu_index: Option<usize>,
     pending_context_action: Option<ContextMenuAction>,
     show_open_with_submenu: bool,
+    show_copy_path_submenu: bool,
+    show_template_submenu: bool,
+    resizing_column: Option<(SortColumn, f32)>,
+    column_widths_changed: bool,
+    header_menu_position: Option<Point<Pixels>>,
+    visible_columns_changed: bool,
+    folder_sizes: FolderSizes,
 }
 
 impl FileListView {
     pub fn new(cx: &mut Context<Self>) -> Self {
+        cx.observe_global::<TagManager>(|_, cx| cx.notify()).detach();
         Self {
             file_list: FileList::new(),
             focus_handle: cx.focus_handle(),
@@ -120,10 +166,18 @@ impl FileListView {
             context_menu_index: None,
             pending_context_action: None,
             show_open_with_submenu: false,
+            show_copy_path_submenu: false,
+            show_template_submenu: false,
+            resizing_column: None,
+            column_widths_changed: false,
+            header_menu_position: None,
+            visible_columns_changed: false,
+            folder_sizes: FolderSizes::new(),
         }
     }
 
     pub fn with_file_list(file_list: FileList, cx: &mut Context<Self>) -> Self {
+        cx.observe_global::<TagManager>(|_, cx| cx.notify()).detach();
         Self {
             file_list,
             focus_handle: cx.focus_handle(),
@@ -134,6 +188,44 @@ impl FileListView {
             context_menu_index: None,
             pending_context_action: None,
             show_open_with_submenu: false,
+            show_copy_path_submenu: false,
+            show_template_submenu: false,
+            resizing_column: None,
+            column_widths_changed: false,
+            header_menu_position: None,
+            visible_columns_changed: false,
+            folder_sizes: FolderSizes::new(),
+        }
+    }
+
+    fn request_folder_sizes(&mut self, visible: Vec<(PathBuf, SystemTime)>, cx: &mut Context<Self>) {
+        let visible_paths: Vec<PathBuf> = visible.iter().map(|(path, _)| path.clone()).collect();
+        self.folder_sizes.cancel_hidden(&visible_paths);
+
+        for (path, mtime) in visible {
+            self.folder_sizes.request(&path, mtime);
+        }
+        self.start_folder_size_jobs(cx);
+    }
+
+    fn start_folder_size_jobs(&mut self, cx: &mut Context<Self>) {
+        while let Some(job) = self.folder_sizes.next_job() {
+            cx.spawn(async move |this, cx| {
+                let task_path = job.path.clone();
+                let task_cancelled = job.cancelled.clone();
+                let result = cx
+                    .background_executor()
+                    .spawn(async move { folder_size(&task_path, &task_cancelled) })
+                    .await;
+
+                let _ = this.update(cx, |view, cx| {
+                    if view.folder_sizes.complete(&job, result) {
+                        cx.notify();
+                    }
+                    view.start_folder_size_jobs(cx);
+                });
+            })
+            .detach();
         }
     }
 
@@ -141,6 +233,9 @@ impl FileListView {
         self.context_menu_position = None;
         self.context_menu_index = None;
         self.show_open_with_submenu = false;
+        self.show_copy_path_submenu = false;
+        self.show_template_submenu = false;
+        self.header_menu_position = None;
     }
 
     pub fn take_pending_context_action(&mut self) -> Option<ContextMenuAction> {
@@ -155,6 +250,28 @@ impl FileListView {
         &mut self.file_list
     }
 
+    pub fn take_column_widths_change(&mut self) -> Option<ColumnWidths> {
+        std::mem::take(&mut self.column_widths_changed).then_some(self.file_list.column_widths)
+    }
+
+    pub fn take_visible_columns_change(&mut self) -> Option<VisibleColumns> {
+        std::mem::take(&mut self.visible_columns_changed)
+            .then(|| self.file_list.visible_columns.clone())
+    }
+
+    fn toggle_visible_column(&mut self, column: DetailsColumn, cx: &mut Context<Self>) {
+        self.file_list.toggle_column_visibility(column);
+        self.visible_columns_changed = true;
+        cx.notify();
+    }
+
+    fn reset_visible_columns(&mut self, cx: &mut Context<Self>) {
+        self.file_list.set_visible_columns(VisibleColumns::default());
+        self.header_menu_position = None;
+        self.visible_columns_changed = true;
+        cx.notify();
+    }
+
     pub fn take_pending_navigation(&mut self) -> Option<PathBuf> {
         self.pending_navigation.take()
     }
@@ -272,12 +389,81 @@ impl FileListView {
     }
 
 
+    pub fn scroll_offset(&self) -> f32 {
+        -f32::from(self.scroll_handle.0.borrow().base_handle.offset().y)
+    }
+
+    pub fn set_scroll_offset(&self, offset: f32) {
+        let state = self.scroll_handle.0.borrow();
+        let mut position = state.base_handle.offset();
+        position.y = px(-offset.max(0.0));
+        state.base_handle.set_offset(position);
+    }
+
+    fn list_width(&self) -> f32 {
+        let width = f32::from(self.scroll_handle.0.borrow().base_handle.bounds().size.width);
+        if width > 0.0 {
+            width
+        } else {
+            DEFAULT_LIST_WIDTH
+        }
+    }
+
+    fn start_column_resize(&mut self, column: SortColumn, x: f32, cx: &mut Context<Self>) {
+        self.resizing_column = Some((column, x));
+        cx.notify();
+    }
+
+    fn handle_column_resize_move(&mut self, event: &MouseMoveEvent, cx: &mut Context<Self>) {
+        let Some((column, last_x)) = self.resizing_column else {
+            return;
+        };
+        if event.pressed_button != Some(MouseButton::Left) {
+            self.finish_column_resize(cx);
+            return;
+        }
+        let x = f32::from(event.position.x);
+        let total = self.list_width();
+        if self.file_list.resize_column(column, x - last_x, total) {
+            self.resizing_column = Some((column, x));
+            cx.notify();
+        }
+    }
+
+    fn finish_column_resize(&mut self, cx: &mut Context<Self>) {
+        if self.resizing_column.take().is_some() {
+            self.column_widths_changed = true;
+            cx.notify();
+        }
+    }
+
+    pub fn scroll_geometry(&self) -> ScrollGeometry {
+        let viewport = f32::from(self.scroll_handle.0.borrow().base_handle.bounds().size.height);
+        ScrollGeometry::list(scaled(self.file_list.row_height()), viewport)
+    }
+
     fn scroll_to_index(&self, index: usize) {
         self.scroll_handle
             .scroll_to_item(index, ScrollStrategy::Center);
     }
 
 
+    pub fn reveal_entry_named(&mut self, name: &str, cx: &mut Context<Self>) {
+        if let Some(index) = self.file_list.select_entry_named(name) {
+            self.scroll_to_index(index);
+            cx.notify();
+        }
+    }
+
+
+    pub fn reveal_entry_at_path(&mut self, path: &Path, cx: &mut Context<Self>) {
+        if let Some(index) = self.file_list.select_entry_at_path(path) {
+            self.scroll_to_index(index);
+            cx.notify();
+        }
+    }
+
+
     pub fn move_selection_up(&mut self) {
         let item_count = self.file_list.item_count();
         if item_count == 0 {
@@ -320,10 +506,21 @@ impl Focusable for FileListView {
 impl Render for FileListView {
     fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
         let total_items = self.file_list.item_count();
-        let row_height = self.file_list.row_height();
+        let row_height = scaled(self.file_list.row_height());
         let selected_index = self.file_list.selected_index;
+        let placeholders = self.file_list.visible_placeholders();
         let context_menu_pos = self.context_menu_position;
         let context_menu_idx = self.context_menu_index;
+        let list_width = self.list_width();
+        let visible_columns = self.file_list.visible_columns.clone();
+        let header_columns: Vec<(DetailsColumn, f32)> = visible_columns
+            .columns()
+            .into_iter()
+            .map(|column| (column, column.width(&self.file_list.column_widths, list_width)))
+            .collect();
+        let header_menu_pos = self.header_menu_position;
+        let resizing_column = self.resizing_column.map(|(column, _)| column);
+        let skeleton_rows = self.file_list.skeleton_rows;
 
         let colors = theme_colors();
 
@@ -331,6 +528,7 @@ impl Render for FileListView {
         let bg_dark = colors.bg_primary;
         let border_color = colors.border_default;
         let border_subtle = colors.border_subtle;
+        let border_emphasis = colors.border_emphasis;
         let text_gray = colors.text_secondary;
         let text_light = colors.text_primary;
         let hover_bg = colors.bg_hover;
@@ -361,23 +559,31 @@ impl Render for FileListView {
                 window.focus(&view.focus_handle);
                 cx.notify();
             }))
+            .on_mouse_move(cx.listener(|view, event: &MouseMoveEvent, _window, cx| {
+                view.handle_column_resize_move(event, cx);
+            }))
+            .on_mouse_up(MouseButton::Left, cx.listener(|view, _event, _window, cx| {
+                view.finish_column_resize(cx);
+            }))
             .child({
-                let sort_column = self.file_list.sort_state.column;
-                let sort_direction = self.file_list.sort_state.direction;
+                let sort_state = self.file_list.sort_state;
                 let entity = cx.entity().clone();
-                let entity_date = entity.clone();
-                let entity_type = entity.clone();
-                let entity_size = entity.clone();
+                let entity_name = entity.clone();
 
                 div()
                     .flex()
-                    .h(px(HEADER_HEIGHT))
+                    .h(px(scaled(HEADER_HEIGHT)))
                     .bg(bg_dark)
                     .border_b_1()
                     .border_color(border_color)
                     .text_xs()
                     .font_weight(gpui::FontWeight::SEMIBOLD)
                     .text_color(text_gray)
+                    .on_mouse_down(MouseButton::Right, cx.listener(|view, event: &MouseDownEvent, _window, cx| {
+                        view.close_context_menu();
+                        view.header_menu_position = Some(event.position);
+                        cx.notify();
+                    }))
                     .child(
                         div()
                             .id("header-name")
@@ -388,88 +594,99 @@ impl Render for FileListView {
                             .gap_1()
                             .cursor_pointer()
                             .hover(|s| s.bg(hover_bg).text_color(text_light))
-                            .when(sort_column == SortColumn::Name, |s| s.text_color(text_light))
-                            .on_click(move |_event, _window, cx| {
-                                entity.update(cx, |view, cx| {
-                                    view.file_list.toggle_sort_column(SortColumn::Name);
+                            .when(sort_state.position(SortColumn::Name).is_some(), |s| s.text_color(text_light))
+                            .on_click(move |event, _window, cx| {
+                                entity_name.update(cx, |view, cx| {
+                                    if event.modifiers().shift {
+                                        view.file_list.add_sort_tiebreaker(SortColumn::Name);
+                                    } else {
+                                        view.file_list.toggle_sort_column(SortColumn::Name);
+                                    }
                                     cx.notify();
                                 });
                             })
                             .child("NAME")
-                            .child(render_sort_indicator(SortColumn::Name, sort_column, sort_direction, text_gray, text_light)),
+                            .child(render_sort_indicator(SortColumn::Name, &sort_state, text_gray, text_light)),
+                    )
+                    .children(header_columns.iter().flat_map(|&(column, width)| {
+                        let divider = visible_columns.resize_divider(column).map(|divider| {
+                            render_column_divider(
+                                divider,
+                                resizing_column == Some(divider),
+                                border_emphasis,
+                                cx,
+                            )
+                            .into_any_element()
+                        });
+                        divider.into_iter().chain(std::iter::once(
+                            render_header_cell(
+                                column,
+                                width,
+                                &sort_state,
+                                border_subtle,
+                                hover_bg,
+                                text_gray,
+                                text_light,
+                                entity.clone(),
+                            )
+                            .into_any_element(),
+                        ))
+                    }))
+            })
+            .children(placeholders.into_iter().map(|placeholder| {
+                let percentage = (placeholder.percentage / 100.0) as f32;
+                div()
+                    .id(SharedString::from(format!("archive-placeholder-{}", placeholder.id)))
+                    .relative()
+                    .h(px(row_height))
+                    .px(px(scaled(ROW_PADDING_X)))
+                    .flex()
+                    .items_center()
+                    .gap(px(scaled(ICON_GAP)))
+                    .border_b_1()
+                    .border_color(border_subtle)
+                    .text_sm()
+                    .child(
+                        svg()
+                            .path("assets/icons/file-archive.svg")
+                            .size(px(scaled(ICON_SIZE)))
+                            .text_color(accent_primary),
                     )
                     .child(
                         div()
-                            .id("header-date")
-                            .w(px(120.0))
-                            .px_4()
-                            .flex()
-                            .items_center()
-                            .gap_1()
-                            .border_l_1()
-                            .border_color(border_subtle)
-                            .cursor_pointer()
-                            .hover(|s| s.bg(hover_bg).text_color(text_light))
-                            .when(sort_column == SortColumn::Date, |s| s.text_color(text_light))
-                            .on_click(move |_event, _window, cx| {
-                                entity_date.update(cx, |view, cx| {
-                                    view.file_list.toggle_sort_column(SortColumn::Date);
-                                    cx.notify();
-                                });
-                            })
-                            .child("DATE")
-                            .child(render_sort_indicator(SortColumn::Date, sort_column, sort_direction, text_gray, text_light)),
+                            .flex_1()
+                            .overflow_hidden()
+                            .text_ellipsis()
+                            .whitespace_nowrap()
+                            .text_color(text_gray)
+                            .child(placeholder.name.clone()),
                     )
                     .child(
                         div()
-                            .id("header-type")
-                            .w(px(100.0))
-                            .px_4()
-                            .flex()
-                            .items_center()
-                            .gap_1()
-                            .border_l_1()
-                            .border_color(border_subtle)
-                            .cursor_pointer()
-                            .hover(|s| s.bg(hover_bg).text_color(text_light))
-                            .when(sort_column == SortColumn::Type, |s| s.text_color(text_light))
-                            .on_click(move |_event, _window, cx| {
-                                entity_type.update(cx, |view, cx| {
-                                    view.file_list.toggle_sort_column(SortColumn::Type);
-                                    cx.notify();
-                                });
-                            })
-                            .child("TYPE")
-                            .child(render_sort_indicator(SortColumn::Type, sort_column, sort_direction, text_gray, text_light)),
+                            .text_xs()
+                            .text_color(text_gray)
+                            .child(placeholder.status_label()),
                     )
                     .child(
                         div()
-                            .id("header-size")
-                            .w(px(80.0))
-                            .px_4()
-                            .flex()
-                            .items_center()
-                            .gap_1()
-                            .border_l_1()
-                            .border_color(border_subtle)
-                            .cursor_pointer()
-                            .hover(|s| s.bg(hover_bg).text_color(text_light))
-                            .when(sort_column == SortColumn::Size, |s| s.text_color(text_light))
-                            .on_click(move |_event, _window, cx| {
-                                entity_size.update(cx, |view, cx| {
-                                    view.file_list.toggle_sort_column(SortColumn::Size);
-                                    cx.notify();
-                                });
-                            })
-                            .child("SIZE")
-                            .child(render_sort_indicator(SortColumn::Size, sort_column, sort_direction, text_gray, text_light)),
+                            .absolute()
+                            .left_0()
+                            .bottom_0()
+                            .h(px(2.0))
+                            .w(gpui::relative(percentage))
+                            .bg(accent_primary),
                     )
-            })
+            }))
             .child(
                 div()
                     .flex_1()
                     .overflow_hidden()
-                    .when(total_items == 0, |this| {
+                    .when(total_items == 0 && skeleton_rows > 0, |this| {
+                        this.flex().flex_col().children((0..skeleton_rows).map(|ix| {
+                            render_skeleton_row(ix, row_height, &header_columns, border_subtle)
+                        }))
+                    })
+                    .when(total_items == 0 && skeleton_rows == 0, |this| {
                         this.flex()
                             .items_center()
                             .justify_center()
@@ -491,12 +708,19 @@ impl Render for FileListView {
                     })
                     .when(total_items > 0, |this| {
                         let entity = cx.entity().clone();
+                        let tint_mode = name_tint_mode();
+                        let tint_colors = colors.clone();
+                        let now = SystemTime::now();
+                        let row_columns = header_columns.clone();
+                        let show_tags = visible_columns.is_visible(DetailsColumn::Tags);
+                        let show_sizes = show_folder_sizes() && visible_columns.is_visible(DetailsColumn::Size);
                         this.child(
                             uniform_list(
                                 "file-list-items",
                                 total_items,
-                                cx.processor(move |view, range, _window, _cx| {
+                                cx.processor(move |view, range, _window, cx| {
                                     let mut items = Vec::new();
+                                    let mut visible_folders = Vec::new();
                                     for ix in range {
                                         let (entry, match_positions) = if let Some(filtered) = view.file_list.get_filtered_entry(ix) {
                                             (filtered.entry.clone(), Some(filtered.match_positions.clone()))
@@ -506,15 +730,22 @@ impl Render for FileListView {
                                             continue;
                                         };
 
-                                        let is_selected = selected_index == Some(ix);
+                                        let is_selected = selected_index == Some(ix)
+                                            || view.file_list.is_marked(&entry.path);
                                         let is_dir = entry.is_dir;
+                                        if is_dir && show_sizes {
+                                            visible_folders.push((entry.path.clone(), entry.modified));
+                                        }
+                                        let size_label = size_cell_label(&entry, show_sizes.then_some(&view.folder_sizes));
                                         let name = entry.name.clone();
-                                        let size = format_size(entry.size, entry.is_dir);
-                                        let date = format_date(entry.modified);
-                                        let file_type = if is_dir {
-                                            "Folder".to_string()
-                                        } else {
-                                            get_file_type(&name)
+                                        let name_truncated = name.chars().count() > NAME_MAX_CHARS;
+                                        let entry_tags: Vec<Tag> = match cx.try_global::<TagManager>() {
+                                            Some(manager) if show_tags => manager
+                                                .tags_for_file(&entry.path)
+                                                .into_iter()
+                                                .cloned()
+                                                .collect(),
+                                            _ => Vec::new(),
                                         };
                                         let icon_name = get_file_icon(&name, is_dir);
                                         let icon_color = if is_dir {
@@ -522,10 +753,15 @@ impl Render for FileListView {
                                         } else {
                                             get_file_icon_color(&name)
                                         };
+                                        let name_color = name_tint(tint_mode, &entry, now, &tint_colors)
+                                            .unwrap_or(text_light);
                                         let sync_status = entry.sync_status;
                                         let is_symlink = entry.is_symlink;
                                         let is_broken_symlink = entry.is_broken_symlink;
                                         let is_shared = entry.is_shared;
+                                        let is_dimmed = entry.hidden_kind.is_dimmed();
+                                        let is_pinned = self.file_list.is_pinned(&entry.path);
+                                        let git_badge = self.file_list.git_badge(&entry.path);
                                         let entry_path = entry.path.clone();
                                         let entity = entity.clone();
                                         let entity_for_ctx = entity.clone();
@@ -533,6 +769,7 @@ impl Render for FileListView {
                                         items.push(
                                                 div()
                                                     .id(SharedString::from(format!("file-{}", ix)))
+                                                    .when(name_truncated, |s| s.tooltip_label(name.clone()))
                                                     .h(px(row_height))
                                                     .w_full()
                                                     .flex()
@@ -549,21 +786,48 @@ impl Render for FileListView {
                                                     .when(!is_selected, |s| s.hover(|h| h
                                                         .bg(hover_bg)
                                                     ))
+                                                    .when(is_dimmed, |s| s.opacity(HIDDEN_ENTRY_OPACITY))
                                                     .on_click({
                                                         let entry_path = entry_path.clone();
                                                         let entity = entity.clone();
                                                         move |event, _window, cx| {
                                                             entity.update(cx, |view, cx| {
                                                                 view.close_context_menu();
-                                                                if event.click_count() == 2 && is_dir {
+                                                                let intent = click_intent(
+                                                                    click_behavior(),
+                                                                    event.click_count(),
+                                                                    &event.modifiers(),
+                                                                );
+                                                                if intent == ClickIntent::Open && is_dir {
                                                                     view.pending_navigation = Some(entry_path.clone());
                                                                 } else {
+                                                                    if event.modifiers().secondary() {
+                                                                        view.file_list.toggle_marked(entry_path.clone());
+                                                                    } else {
+                                                                        view.file_list.clear_marked();
+                                                                    }
                                                                     view.file_list.selected_index = Some(ix);
                                                                 }
                                                                 cx.notify();
                                                             });
                                                         }
                                                     })
+                                                    .on_mouse_down(MouseButton::Middle, {
+                                                        let entry_path = entry_path.clone();
+                                                        let entity = entity.clone();
+                                                        move |event: &MouseDownEvent, _window, cx| {
+                                                            cx.stop_propagation();
+                                                            entity.update(cx, |view, cx| {
+                                                                view.close_context_menu();
+                                                                view.pending_context_action = Some(middle_click_action(
+                                                                    entry_path.clone(),
+                                                                    is_dir,
+                                                                    &event.modifiers,
+                                                                ));
+                                                                cx.notify();
+                                                            });
+                                                        }
+                                                    })
                                                     .on_mouse_down(MouseButton::Right, {
                                                         let entity = entity_for_ctx.clone();
                                                         move |event: &MouseDownEvent, _window, cx| {
@@ -585,7 +849,7 @@ impl Render for FileListView {
                                                     .child(
                                                         div()
                                                             .flex_1()
-                                                            .px(px(ROW_PADDING_X))
+                                                            .px(px(scaled(ROW_PADDING_X)))
                                                             .flex()
                                                             .items_center()
                                                             .overflow_hidden()
@@ -593,15 +857,15 @@ impl Render for FileListView {
                                                                 div()
                                                                     .flex()
                                                                     .items_center()
-                                                                    .gap(px(ICON_GAP))
+                                                                    .gap(px(scaled(ICON_GAP)))
                                                                     .child(
                                                                         div()
                                                                             .relative()
                                                                             .flex_shrink_0()
                                                                             .child(
                                                                                 svg()
-                                                                                    .path(SharedString::from(format!("assets/icons/{}.svg", icon_name)))
-                                                                                    .size(px(ICON_SIZE))
+                                                                                    .path(SharedString::from(icon_asset_path(icon_name)))
+                                                                                    .size(px(scaled(ICON_SIZE)))
                                                                                     .text_color(icon_color),
                                                                             )
                                                                             .when(is_symlink, |s| {
@@ -652,7 +916,7 @@ impl Render for FileListView {
                                                                             &name,
                                                                             match_positions.as_ref(),
                                                                             is_selected,
-                                                                            text_light,
+                                                                            name_color,
                                                                             accent_primary,
                                                                         ),
                                                                     )
@@ -670,6 +934,30 @@ impl Render for FileListView {
                                                                                 )
                                                                         )
                                                                     })
+                                                                    .when(is_pinned, |s| {
+                                                                        s.child(
+                                                                            div()
+                                                                                .ml_2()
+                                                                                .flex()
+                                                                                .items_center()
+                                                                                .child(
+                                                                                    svg()
+                                                                                        .path("assets/icons/pin.svg")
+                                                                                        .size(px(12.0))
+                                                                                        .text_color(accent_primary)
+                                                                                )
+                                                                        )
+                                                                    })
+                                                                    .when_some(git_badge, |s, badge| {
+                                                                        s.child(
+                                                                            div()
+                                                                                .ml_2()
+                                                                                .text_xs()
+                                                                                .font_weight(gpui::FontWeight::BOLD)
+                                                                                .text_color(gpui::rgb(badge.color()))
+                                                                                .child(badge.label())
+                                                                        )
+                                                                    })
                                                                     .when(sync_status.icon_name().is_some(), |s| {
                                                                         let icon = sync_status.icon_name().unwrap_or("check");
                                                                         let color = sync_status.color().unwrap_or(0x8b949e);
@@ -688,36 +976,12 @@ impl Render for FileListView {
                                                                     }),
                                                             ),
                                                     )
-                                                    .child(
-                                                        div()
-                                                            .w(px(120.0))
-                                                            .px_4()
-                                                            .text_xs()
-                                                            .text_color(text_gray)
-                                                            .truncate()
-                                                            .child(date),
-                                                    )
-                                                    .child(
-                                                        div()
-                                                            .w(px(100.0))
-                                                            .px_4()
-                                                            .text_xs()
-                                                            .text_color(text_gray)
-                                                            .truncate()
-                                                            .child(file_type),
-                                                    )
-                                                    .child(
-                                                        div()
-                                                            .w(px(80.0))
-                                                            .px_4()
-                                                            .text_xs()
-                                                            .text_color(text_gray)
-                                                            .font_family("Mono")
-                                                            .truncate()
-                                                            .child(size),
-                                                    ),
+                                                    .children(row_columns.iter().map(|&(column, width)| {
+                                                        render_detail_cell(column, &entry, width, &entry_tags, &size_label, text_gray)
+                                                    })),
                                             );
                                     }
+                                    view.request_folder_sizes(visible_folders, cx);
                                     items
                                 }),
                             )
@@ -728,14 +992,14 @@ impl Render for FileListView {
             )
             .child(
                 div()
-                    .h(px(FOOTER_HEIGHT))
+                    .h(px(scaled(FOOTER_HEIGHT)))
                     .bg(bg_dark)
                     .border_t_1()
                     .border_color(border_color)
                     .flex()
                     .items_center()
                     .justify_between()
-                    .px(px(ROW_PADDING_X))
+                    .px(px(scaled(ROW_PADDING_X)))
                     .text_xs()
                     .text_color(text_gray)
                     .child(
@@ -751,6 +1015,34 @@ impl Render for FileListView {
                             )
                             .child(format!("{} items", total_items)),
                     )
+                    .when(self.file_list.is_filtered(), |this| {
+                        let current_sort = self.file_list.search_sort();
+                        this.child(
+                            div()
+                                .id("search-sort-options")
+                                .flex()
+                                .items_center()
+                                .gap_1()
+                                .child("Sort:")
+                                .children(SearchSortMode::all().iter().map(|mode| {
+                                    let mode = *mode;
+                                    let is_active = mode == current_sort;
+                                    div()
+                                        .id(SharedString::from(format!("search-sort-{}", mode.label())))
+                                        .px_2()
+                                        .py(px(2.0))
+                                        .rounded_sm()
+                                        .cursor_pointer()
+                                        .when(is_active, |chip| chip.bg(selected_bg).text_color(text_light))
+                                        .when(!is_active, |chip| chip.hover(|h| h.bg(hover_bg)))
+                                        .on_mouse_down(MouseButton::Left, cx.listener(move |view, _event, _window, cx| {
+                                            view.file_list.set_search_sort(mode);
+                                            cx.notify();
+                                        }))
+                                        .child(mode.label())
+                                })),
+                        )
+                    })
                     .child(
                         div()
                             .flex()
@@ -766,13 +1058,54 @@ impl Render for FileListView {
                             .child("List View"),
                     ),
             )
+            .when_some(header_menu_pos, |this, pos| {
+                this.child(
+                    anchored()
+                        .snap_to_window_with_margin(px(8.0))
+                        .anchor(Corner::TopLeft)
+                        .position(pos)
+                        .child(
+                            div()
+                                .id("file-list-column-menu")
+                                .occlude()
+                                .w(px(200.0))
+                                .bg(menu_bg)
+                                .border_1()
+                                .border_color(border_color)
+                                .rounded_lg()
+                                .shadow_lg()
+                                .py_1()
+                                .on_mouse_down_out(cx.listener(|view, _, _, cx| {
+                                    view.header_menu_position = None;
+                                    cx.notify();
+                                }))
+                                .child(render_column_menu(
+                                    &visible_columns,
+                                    text_light,
+                                    accent_primary,
+                                    hover_bg,
+                                    border_subtle,
+                                    cx,
+                                )),
+                        ),
+                )
+            })
             .when_some(context_menu_pos, |this, pos| {
                 let entity = cx.entity().clone();
                 let selected_entry = context_menu_idx.and_then(|idx| self.file_list.entries.get(idx).cloned());
+                let is_pinned = selected_entry.as_ref().is_some_and(|e| self.file_list.is_pinned(&e.path));
+                let selection = selected_entry
+                    .as_ref()
+                    .map(|e| context_selection(self.file_list.marked(), &e.path))
+                    .unwrap_or_default();
+                let is_multi = selection.len() > 1;
                 let is_dir = selected_entry.as_ref().map(|e| e.is_dir).unwrap_or(false);
                 let is_archive = selected_entry.as_ref()
                     .map(|e| crate::models::ArchiveManager::new().is_archive(&e.path))
                     .unwrap_or(false);
+                let is_manifest = selected_entry
+                    .as_ref()
+                    .is_some_and(|e| !e.is_dir && is_checksum_manifest(&e.path));
                 let is_disk_image = selected_entry.as_ref()
                     .map(|e| is_disk_image_file(&e.path))
                     .unwrap_or(false);
@@ -784,6 +1117,10 @@ impl Render for FileListView {
                             .unwrap_or(false)
                     })
                     .unwrap_or(false);
+                let custom_entries = selected_entry
+                    .as_ref()
+                    .map(|e| custom_context_entries(&e.path, e.is_dir, cx))
+                    .unwrap_or_default();
 
                 this.child(
                     anchored()
@@ -822,6 +1159,7 @@ impl Render for FileListView {
                                 }))
                                 .child(render_open_with_submenu(
                                     selected_entry.clone(),
+                                    selection.clone(),
                                     self.show_open_with_submenu,
                                     text_light,
                                     hover_bg,
@@ -858,6 +1196,60 @@ impl Render for FileListView {
                                         }
                                     }))
                                 })
+                                .when(is_dir, |this| {
+                                    let rules = [
+                                        ("layout-grid", "Always Use Grid View", Some(ViewMode::Grid)),
+                                        ("list", "Always Use List View", Some(ViewMode::List)),
+                                        ("folder", "Use Default View", None),
+                                    ];
+                                    this.child(render_context_menu_divider(border_subtle)).children(
+                                        rules.into_iter().map(|(icon, label, mode)| {
+                                            let entity = entity.clone();
+                                            let entry = selected_entry.clone();
+                                            render_context_menu_item(icon, label, text_light, hover_bg, {
+                                                move |_window, cx| {
+                                                    if let Some(ref e) = entry {
+                                                        entity.update(cx, |view, cx| {
+                                                            view.pending_context_action =
+                                                                Some(ContextMenuAction::SetFolderViewMode {
+                                                                    path: e.path.clone(),
+                                                                    mode,
+                                                                });
+                                                            view.close_context_menu();
+                                                            cx.notify();
+                                                        });
+                                                    }
+                                                }
+                                            })
+                                        }),
+                                    )
+                                })
+                                .when(is_dir, |this| {
+                                    let rules = [
+                                        ("eye", "Always Show Hidden Files", Some(true)),
+                                        ("eye-off", "Always Hide Hidden Files", Some(false)),
+                                        ("folder", "Use Default Hidden Files", None),
+                                    ];
+                                    this.children(rules.into_iter().map(|(icon, label, show)| {
+                                        let entity = entity.clone();
+                                        let entry = selected_entry.clone();
+                                        render_context_menu_item(icon, label, text_light, hover_bg, {
+                                            move |_window, cx| {
+                                                if let Some(ref e) = entry {
+                                                    entity.update(cx, |view, cx| {
+                                                        view.pending_context_action =
+                                                            Some(ContextMenuAction::SetFolderHiddenFiles {
+                                                                path: e.path.clone(),
+                                                                show,
+                                                            });
+                                                        view.close_context_menu();
+                                                        cx.notify();
+                                                    });
+                                                }
+                                            }
+                                        })
+                                    }))
+                                })
                                 .child(render_context_menu_divider(border_subtle))
                                 .child(render_context_menu_item("eye", "Quick Look", text_light, hover_bg, {
                                     let entity = entity.clone();
@@ -885,6 +1277,19 @@ impl Render for FileListView {
                                         }
                                     }
                                 }))
+                                .child(render_context_menu_item("tag", "Edit Tags…", text_light, hover_bg, {
+                                    let entity = entity.clone();
+                                    let paths = selection.clone();
+                                    move |_window, cx| {
+                                        if !paths.is_empty() {
+                                            entity.update(cx, |view, cx| {
+                                                view.pending_context_action = Some(ContextMenuAction::EditTags(paths.clone()));
+                                                view.close_context_menu();
+                                                cx.notify();
+                                            });
+                                        }
+                                    }
+                                }))
                                 .child(render_context_menu_divider(border_subtle))
                                 .child(render_context_menu_item("pen", "Rename", text_light, hover_bg, {
                                     let entity = entity.clone();
@@ -949,7 +1354,21 @@ impl Render for FileListView {
                                     }
                                 }))
                                 .child(render_context_menu_divider(border_subtle))
-                                .when(!is_archive, |this| {
+                                .when(is_multi, |this| {
+                                    let entity = entity.clone();
+                                    let paths = selection.clone();
+                                    let label = format!("Compress {} Items…", paths.len());
+                                    this.child(render_context_menu_item("archive", label, text_light, hover_bg, {
+                                        move |_window, cx| {
+                                            entity.update(cx, |view, cx| {
+                                                view.pending_context_action = Some(ContextMenuAction::CompressItems(paths.clone()));
+                                                view.close_context_menu();
+                                                cx.notify();
+                                            });
+                                        }
+                                    }))
+                                })
+                                .when(!is_archive && !is_multi, |this| {
                                     let entity = entity.clone();
                                     let entry = selected_entry.clone();
                                     this.child(render_context_menu_item("archive", "Compress", text_light, hover_bg, {
@@ -992,6 +1411,21 @@ impl Render for FileListView {
                                         }
                                     }))
                                 })
+                                .when(is_manifest, |this| {
+                                    let entity = entity.clone();
+                                    let entry = selected_entry.clone();
+                                    this.child(render_context_menu_item("shield-check", "Verify Checksums", text_light, hover_bg, {
+                                        move |_window, cx| {
+                                            if let Some(ref e) = entry {
+                                                entity.update(cx, |view, cx| {
+                                                    view.pending_context_action = Some(ContextMenuAction::VerifyChecksums(e.path.clone()));
+                                                    view.close_context_menu();
+                                                    cx.notify();
+                                                });
+                                            }
+                                        }
+                                    }))
+                                })
                                 .when(is_disk_image, |this| {
                                     let entity = entity.clone();
                                     let entry = selected_entry.clone();
@@ -1036,32 +1470,66 @@ impl Render for FileListView {
                                     }
                                 }))
                                 .child(render_context_menu_divider(border_subtle))
-                                .child(render_context_menu_item("link", "Copy Path", text_light, hover_bg, {
+                                .child(render_copy_path_submenu(
+                                    selection.clone(),
+                                    self.show_copy_path_submenu,
+                                    text_light,
+                                    hover_bg,
+                                    border_color,
+                                    entity.clone(),
+                                ))
+                                .child(render_template_submenu(
+                                    self.show_template_submenu,
+                                    text_light,
+                                    hover_bg,
+                                    border_color,
+                                    entity.clone(),
+                                ))
+                                .child(render_context_menu_item("folder-search", "Show in Finder", text_light, hover_bg, {
                                     let entity = entity.clone();
                                     let entry = selected_entry.clone();
                                     move |_window, cx| {
                                         if let Some(ref e) = entry {
                                             entity.update(cx, |view, cx| {
-                                                view.pending_context_action = Some(ContextMenuAction::CopyPath(e.path.clone()));
+                                                view.pending_context_action = Some(ContextMenuAction::ShowInFinder(e.path.clone()));
                                                 view.close_context_menu();
                                                 cx.notify();
                                             });
                                         }
                                     }
                                 }))
-                                .child(render_context_menu_item("folder-search", "Show in Finder", text_light, hover_bg, {
+                                .child(render_context_menu_item("app-window", "Reveal in New Window", text_light, hover_bg, {
                                     let entity = entity.clone();
                                     let entry = selected_entry.clone();
                                     move |_window, cx| {
                                         if let Some(ref e) = entry {
                                             entity.update(cx, |view, cx| {
-                                                view.pending_context_action = Some(ContextMenuAction::ShowInFinder(e.path.clone()));
+                                                view.pending_context_action = Some(ContextMenuAction::RevealInNewWindow(e.path.clone()));
                                                 view.close_context_menu();
                                                 cx.notify();
                                             });
                                         }
                                     }
                                 }))
+                                .child(render_context_menu_item(
+                                    if is_pinned { "pin-off" } else { "pin" },
+                                    if is_pinned { "Unpin" } else { "Pin to Top" },
+                                    text_light,
+                                    hover_bg,
+                                    {
+                                        let entity = entity.clone();
+                                        let entry = selected_entry.clone();
+                                        move |_window, cx| {
+                                            if let Some(ref e) = entry {
+                                                entity.update(cx, |view, cx| {
+                                                    view.pending_context_action = Some(ContextMenuAction::TogglePin(e.path.clone()));
+                                                    view.close_context_menu();
+                                                    cx.notify();
+                                                });
+                                            }
+                                        }
+                                    },
+                                ))
                                 .child(render_context_menu_item("star", "Add to Favorites", text_light, hover_bg, {
                                     let entity = entity.clone();
                                     let entry = selected_entry.clone();
@@ -1101,6 +1569,24 @@ impl Render for FileListView {
                                         }
                                     }))
                                 })
+                                .when(!custom_entries.is_empty(), |this| {
+                                    this.child(render_context_menu_divider(border_subtle)).children(
+                                        custom_entries.into_iter().map(|(id, icon, label)| {
+                                            let entity = entity.clone();
+                                            let paths = selection.clone();
+                                            render_context_menu_item(&icon, label, text_light, hover_bg, move |_window, cx| {
+                                                entity.update(cx, |view, cx| {
+                                                    view.pending_context_action = Some(ContextMenuAction::Custom {
+                                                        id,
+                                                        paths: paths.clone(),
+                                                    });
+                                                    view.close_context_menu();
+                                                    cx.notify();
+                                                });
+                                            })
+                                        }),
+                                    )
+                                })
                                 .child(render_context_menu_divider(border_subtle))
                                 .child(render_context_menu_item("trash-2", "Move to Trash", gpui::rgb(0xf85149), hover_bg, {
                                     let entity = entity.clone();
@@ -1123,43 +1609,204 @@ impl Render for FileListView {
 
 fn render_sort_indicator(
     column: SortColumn,
-    active_column: SortColumn,
-    direction: SortDirection,
+    sort_state: &SortState,
     inactive_color: gpui::Rgba,
     active_color: gpui::Rgba,
 ) -> impl IntoElement {
-    let is_active = column == active_column;
-    let icon_name = if is_active {
-        match direction {
-            SortDirection::Ascending => "chevron-up",
-            SortDirection::Descending => "chevron-down",
-        }
-    } else {
-        "arrow-down-up"
+    let position = sort_state.position(column);
+    let is_active = position.is_some();
+    let icon_name = match position {
+        Some((_, SortDirection::Ascending)) => "chevron-up",
+        Some((_, SortDirection::Descending)) => "chevron-down",
+        None => "arrow-down-up",
     };
+    let ordinal = position
+        .filter(|_| sort_state.is_multi_key())
+        .map(|(index, _)| index + 1);
 
-    svg()
-        .path(SharedString::from(format!(
-            "assets/icons/{}.svg",
-            icon_name
-        )))
-        .size(px(12.0))
-        .text_color(if is_active {
-            active_color
-        } else {
-            inactive_color
-        })
-        .when(!is_active, |s| s.opacity(0.5))
-}
-
-fn render_highlighted_name(
-    name: &str,
-    match_positions: Option<&Vec<usize>>,
-    is_selected: bool,
-    text_light: gpui::Rgba,
-    accent_color: gpui::Rgba,
-) -> impl IntoElement {
-    let highlight_color = accent_color;
+    div()
+        .flex()
+        .items_center()
+        .child(
+            svg()
+                .path(SharedString::from(format!(
+                    "assets/icons/{}.svg",
+                    icon_name
+                )))
+                .size(px(12.0))
+                .text_color(if is_active {
+                    active_color
+                } else {
+                    inactive_color
+                })
+                .when(!is_active, |s| s.opacity(0.5)),
+        )
+        .when_some(ordinal, |s, ordinal| {
+            s.child(
+                div()
+                    .text_size(px(9.0))
+                    .text_color(active_color)
+                    .child(ordinal.to_string()),
+            )
+        })
+}
+
+fn render_header_cell(
+    column: DetailsColumn,
+    width: f32,
+    sort_state: &SortState,
+    border_color: gpui::Rgba,
+    hover_bg: gpui::Rgba,
+    text_gray: gpui::Rgba,
+    text_light: gpui::Rgba,
+    entity: gpui::Entity<FileListView>,
+) -> impl IntoElement {
+    let sort_column = column.sort_column();
+    let is_sorted = sort_column.is_some_and(|c| sort_state.position(c).is_some());
+
+    div()
+        .id(SharedString::from(format!("header-{:?}", column)))
+        .w(px(width))
+        .px_4()
+        .flex()
+        .items_center()
+        .gap_1()
+        .border_l_1()
+        .border_color(border_color)
+        .overflow_hidden()
+        .when(is_sorted, |s| s.text_color(text_light))
+        .when_some(sort_column, |s, sort_column| {
+            s.cursor_pointer()
+                .hover(|s| s.bg(hover_bg).text_color(text_light))
+                .on_click(move |event, _window, cx| {
+                    entity.update(cx, |view, cx| {
+                        if event.modifiers().shift {
+                            view.file_list.add_sort_tiebreaker(sort_column);
+                        } else {
+                            view.file_list.toggle_sort_column(sort_column);
+                        }
+                        cx.notify();
+                    });
+                })
+        })
+        .child(column.header())
+        .when_some(sort_column, |s, sort_column| {
+            s.child(render_sort_indicator(sort_column, sort_state, text_gray, text_light))
+        })
+}
+
+fn render_detail_cell(
+    column: DetailsColumn,
+    entry: &FileEntry,
+    width: f32,
+    tags: &[Tag],
+    size_label: &str,
+    text_color: gpui::Rgba,
+) -> impl IntoElement {
+    let cell = div()
+        .w(px(width))
+        .px_4()
+        .text_xs()
+        .text_color(text_color)
+        .truncate();
+
+    match column {
+        DetailsColumn::Modified => cell.child(format_date(entry.modified)),
+        DetailsColumn::Kind => cell.child(if entry.is_dir {
+            "Folder".to_string()
+        } else {
+            get_file_type(&entry.name)
+        }),
+        DetailsColumn::Size => cell
+            .font_family("Mono")
+            .child(size_label.to_string()),
+        DetailsColumn::Created => cell.child(
+            entry
+                .created
+                .map(format_date)
+                .unwrap_or_else(|| "--".to_string()),
+        ),
+        DetailsColumn::Permissions => cell
+            .font_family("Mono")
+            .child(entry.permissions_label().unwrap_or_else(|| "--".to_string())),
+        DetailsColumn::Tags => {
+            let tags: Vec<&Tag> = tags.iter().collect();
+            cell.flex().items_center().child(render_tag_dots(&tags))
+        }
+    }
+}
+
+fn render_column_menu(
+    visible_columns: &VisibleColumns,
+    text_color: gpui::Rgba,
+    check_color: gpui::Rgba,
+    hover_bg: gpui::Rgba,
+    divider_color: gpui::Rgba,
+    cx: &mut Context<FileListView>,
+) -> impl IntoElement {
+    let row = |id: SharedString, label: &'static str, checked: bool| {
+        div()
+            .id(id)
+            .flex()
+            .items_center()
+            .gap_2()
+            .px_3()
+            .py_1p5()
+            .mx_1()
+            .rounded_md()
+            .cursor_pointer()
+            .text_sm()
+            .text_color(text_color)
+            .hover(|s| s.bg(hover_bg))
+            .child(div().w(px(14.0)).when(checked, |el| {
+                el.child(
+                    svg()
+                        .path("assets/icons/check.svg")
+                        .size(px(14.0))
+                        .text_color(check_color),
+                )
+            }))
+            .child(label)
+    };
+
+    div()
+        .flex()
+        .flex_col()
+        .child(row("column-menu-name".into(), "Name", true).opacity(0.5))
+        .children(DetailsColumn::ALL.map(|column| {
+            row(
+                SharedString::from(format!("column-menu-{:?}", column)),
+                column.label(),
+                visible_columns.is_visible(column),
+            )
+            .on_mouse_down(
+                MouseButton::Left,
+                cx.listener(move |view, _event, _window, cx| {
+                    view.toggle_visible_column(column, cx);
+                    cx.stop_propagation();
+                }),
+            )
+        }))
+        .child(render_context_menu_divider(divider_color))
+        .child(
+            row("column-menu-reset".into(), "Reset to Defaults", false).on_mouse_down(
+                MouseButton::Left,
+                cx.listener(|view, _event, _window, cx| {
+                    view.reset_visible_columns(cx);
+                    cx.stop_propagation();
+                }),
+            ),
+        )
+}
+
+fn render_highlighted_name(
+    name: &str,
+    match_positions: Option<&Vec<usize>>,
+    is_selected: bool,
+    text_light: gpui::Rgba,
+    accent_color: gpui::Rgba,
+) -> impl IntoElement {
+    let highlight_color = accent_color;
     let text_color = if is_selected {
         gpui::rgb(0xffffff)
     } else {
@@ -1238,15 +1885,39 @@ fn render_highlighted_name(
                 .text_color(text_color)
                 .font_weight(font_weight)
                 .truncate()
-                .child(name.to_string())
+                .child(middle_ellipsis(name, NAME_MAX_CHARS))
                 .into_any_element()
         }
     }
 }
 
+pub(crate) fn middle_click_action(path: PathBuf, is_dir: bool, modifiers: &Modifiers) -> ContextMenuAction {
+    match middle_click_intent(is_dir, modifiers) {
+        MiddleClickIntent::OpenInBackgroundTab => ContextMenuAction::OpenInBackgroundTab(path),
+        MiddleClickIntent::OpenInNewTab => ContextMenuAction::OpenInNewTab(path),
+        MiddleClickIntent::OpenExternally => ContextMenuAction::Open(path),
+    }
+}
+
+pub(crate) fn custom_context_entries(
+    path: &Path,
+    is_dir: bool,
+    cx: &App,
+) -> Vec<(ContextMenuEntryId, String, String)> {
+    cx.try_global::<ContextMenuRegistry>()
+        .map(|registry| {
+            registry
+                .entries_for(path, is_dir)
+                .into_iter()
+                .map(|(id, entry)| (id, entry.icon.clone(), entry.label.clone()))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
 fn render_context_menu_item<F>(
-    icon_name: &'static str,
-    label: &'static str,
+    icon_name: &str,
+    label: impl Into<SharedString>,
     text_color: gpui::Rgba,
     hover_bg: gpui::Rgba,
     on_click: F,
@@ -1254,6 +1925,7 @@ fn render_context_menu_item<F>(
 where
     F: Fn(&mut Window, &mut App) + 'static,
 {
+    let label = label.into();
     div()
         .id(SharedString::from(format!("ctx-{}", label)))
         .flex()
@@ -1282,12 +1954,84 @@ where
         .child(label)
 }
 
+fn render_skeleton_row(
+    index: usize,
+    row_height: f32,
+    columns: &[(DetailsColumn, f32)],
+    color: gpui::Rgba,
+) -> impl IntoElement {
+    let name_width = 120.0 + ((index * 37) % 5) as f32 * 28.0;
+    let bar = |width: f32| div().h(px(8.0)).w(px(width)).rounded_sm().bg(color);
+
+    let row = div()
+        .h(px(row_height))
+        .px(px(scaled(ROW_PADDING_X)))
+        .flex()
+        .items_center()
+        .child(
+            div()
+                .flex_1()
+                .flex()
+                .items_center()
+                .gap(px(scaled(ICON_GAP)))
+                .child(div().size(px(scaled(ICON_SIZE))).rounded_sm().bg(color))
+                .child(bar(name_width)),
+        )
+        .children(columns.iter().map(|&(_, width)| {
+            div()
+                .w(px(width))
+                .px_4()
+                .child(bar((width - 32.0).clamp(16.0, 72.0)))
+        }));
+
+    if reduced_motion() {
+        return row.opacity(0.6).into_any_element();
+    }
+    row.with_animation(
+        ("file-list-skeleton", index),
+        Animation::new(motion_duration(Duration::from_millis(1400)))
+            .repeat()
+            .with_easing(pulsating_between(0.35, 0.85)),
+        |row, delta| row.opacity(delta),
+    )
+    .into_any_element()
+}
+
+fn render_column_divider(
+    column: SortColumn,
+    is_active: bool,
+    color: gpui::Rgba,
+    cx: &mut Context<FileListView>,
+) -> impl IntoElement {
+    div().relative().w(px(0.0)).child(
+        div()
+            .id(SharedString::from(format!("column-divider-{:?}", column)))
+            .absolute()
+            .top_0()
+            .bottom_0()
+            .left(px(-3.0))
+            .w(px(6.0))
+            .occlude()
+            .cursor_col_resize()
+            .when(is_active, |s| s.bg(color))
+            .hover(|s| s.bg(color))
+            .on_mouse_down(
+                MouseButton::Left,
+                cx.listener(move |view, event: &MouseDownEvent, _window, cx| {
+                    view.start_column_resize(column, f32::from(event.position.x), cx);
+                    cx.stop_propagation();
+                }),
+            ),
+    )
+}
+
 fn render_context_menu_divider(color: gpui::Rgba) -> impl IntoElement {
     div().h(px(1.0)).mx_2().my_1().bg(color)
 }
 
 fn render_open_with_submenu(
     selected_entry: Option<FileEntry>,
+    selection: Vec<PathBuf>,
     show_submenu: bool,
     text_color: gpui::Rgba,
     hover_bg: gpui::Rgba,
@@ -1369,7 +2113,7 @@ fn render_open_with_submenu(
                         for app in apps.iter().take(10) {
                             let app_name = app.name.clone();
                             let app_path = app.path.clone();
-                            let file_path = selected_entry.as_ref().map(|e| e.path.clone());
+                            let paths = selection.clone();
                             let entity = entity.clone();
 
                             submenu = submenu.child(
@@ -1389,11 +2133,11 @@ fn render_open_with_submenu(
                                         let app_name = app_name.clone();
                                         let app_path = app_path.clone();
                                         move |_event, _window, cx| {
-                                            if let Some(ref fp) = file_path {
+                                            if !paths.is_empty() {
                                                 entity.update(cx, |view, cx| {
                                                     view.pending_context_action =
                                                         Some(ContextMenuAction::OpenWithApp {
-                                                            file_path: fp.clone(),
+                                                            paths: paths.clone(),
                                                             app_path: app_path.clone(),
                                                             app_name: app_name.clone(),
                                                         });
@@ -1450,6 +2194,219 @@ fn render_open_with_submenu(
         })
 }
 
+fn render_template_submenu(
+    show_submenu: bool,
+    text_color: gpui::Rgba,
+    hover_bg: gpui::Rgba,
+    border_color: gpui::Rgba,
+    entity: gpui::Entity<FileListView>,
+) -> impl IntoElement {
+    let entity_for_toggle = entity.clone();
+
+    div()
+        .id("template-menu-wrapper")
+        .flex()
+        .flex_col()
+        .child(
+            div()
+                .id("template-trigger")
+                .flex()
+                .items_center()
+                .justify_between()
+                .gap_3()
+                .px_3()
+                .py_1p5()
+                .mx_1()
+                .rounded_md()
+                .cursor_pointer()
+                .text_sm()
+                .text_color(text_color)
+                .hover(|s| s.bg(hover_bg))
+                .on_mouse_down(MouseButton::Left, move |_event, _window, cx| {
+                    entity_for_toggle.update(cx, |view, cx| {
+                        view.show_template_submenu = !view.show_template_submenu;
+                        cx.notify();
+                    });
+                })
+                .child(
+                    div()
+                        .flex()
+                        .items_center()
+                        .gap_3()
+                        .child(
+                            svg()
+                                .path("assets/icons/file-plus.svg")
+                                .size(px(14.0))
+                                .text_color(text_color),
+                        )
+                        .child("New from Template"),
+                )
+                .child(
+                    svg()
+                        .path(if show_submenu {
+                            "assets/icons/chevron-down.svg"
+                        } else {
+                            "assets/icons/chevron-right.svg"
+                        })
+                        .size(px(12.0))
+                        .text_color(text_color),
+                ),
+        )
+        .when(show_submenu, move |this| {
+            let mut submenu = div()
+                .id("template-inline-list")
+                .flex()
+                .flex_col()
+                .pl_4()
+                .border_l_1()
+                .border_color(border_color)
+                .ml_4();
+
+            for template in load_templates(&templates_dir()) {
+                let entity = entity.clone();
+                let icon = template.icon;
+                let label = template.label.clone();
+
+                submenu = submenu.child(
+                    div()
+                        .id(SharedString::from(format!("template-{}", template.file_name)))
+                        .flex()
+                        .items_center()
+                        .gap_2()
+                        .px_3()
+                        .py_1p5()
+                        .rounded_md()
+                        .cursor_pointer()
+                        .text_sm()
+                        .text_color(text_color)
+                        .hover(|s| s.bg(hover_bg))
+                        .on_mouse_down(MouseButton::Left, move |_event, _window, cx| {
+                            entity.update(cx, |view, cx| {
+                                view.pending_context_action =
+                                    Some(ContextMenuAction::NewFromTemplate(template.clone()));
+                                view.close_context_menu();
+                                cx.notify();
+                            });
+                        })
+                        .child(
+                            svg()
+                                .path(SharedString::from(format!("assets/icons/{}.svg", icon)))
+                                .size(px(14.0))
+                                .text_color(text_color),
+                        )
+                        .child(label),
+                );
+            }
+            this.child(submenu)
+        })
+}
+
+fn render_copy_path_submenu(
+    selection: Vec<PathBuf>,
+    show_submenu: bool,
+    text_color: gpui::Rgba,
+    hover_bg: gpui::Rgba,
+    border_color: gpui::Rgba,
+    entity: gpui::Entity<FileListView>,
+) -> impl IntoElement {
+    let entity_for_toggle = entity.clone();
+
+    div()
+        .id("copy-path-menu-wrapper")
+        .flex()
+        .flex_col()
+        .child(
+            div()
+                .id("copy-path-trigger")
+                .flex()
+                .items_center()
+                .justify_between()
+                .gap_3()
+                .px_3()
+                .py_1p5()
+                .mx_1()
+                .rounded_md()
+                .cursor_pointer()
+                .text_sm()
+                .text_color(text_color)
+                .hover(|s| s.bg(hover_bg))
+                .on_mouse_down(MouseButton::Left, move |_event, _window, cx| {
+                    entity_for_toggle.update(cx, |view, cx| {
+                        view.show_copy_path_submenu = !view.show_copy_path_submenu;
+                        cx.notify();
+                    });
+                })
+                .child(
+                    div()
+                        .flex()
+                        .items_center()
+                        .gap_3()
+                        .child(
+                            svg()
+                                .path("assets/icons/link.svg")
+                                .size(px(14.0))
+                                .text_color(text_color),
+                        )
+                        .child("Copy Path"),
+                )
+                .child(
+                    svg()
+                        .path(if show_submenu {
+                            "assets/icons/chevron-down.svg"
+                        } else {
+                            "assets/icons/chevron-right.svg"
+                        })
+                        .size(px(12.0))
+                        .text_color(text_color),
+                ),
+        )
+        .when(show_submenu, move |this| {
+            let mut submenu = div()
+                .id("copy-path-inline-list")
+                .flex()
+                .flex_col()
+                .pl_4()
+                .border_l_1()
+                .border_color(border_color)
+                .ml_4();
+
+            for format in PathFormat::available() {
+                let entity = entity.clone();
+                let paths = selection.clone();
+
+                submenu = submenu.child(
+                    div()
+                        .id(SharedString::from(format!("copy-path-{:?}", format)))
+                        .flex()
+                        .items_center()
+                        .gap_2()
+                        .px_3()
+                        .py_1p5()
+                        .rounded_md()
+                        .cursor_pointer()
+                        .text_sm()
+                        .text_color(text_color)
+                        .hover(|s| s.bg(hover_bg))
+                        .on_mouse_down(MouseButton::Left, move |_event, _window, cx| {
+                            if !paths.is_empty() {
+                                entity.update(cx, |view, cx| {
+                                    view.pending_context_action = Some(ContextMenuAction::CopyPathAs {
+                                        paths: paths.clone(),
+                                        format,
+                                    });
+                                    view.close_context_menu();
+                                    cx.notify();
+                                });
+                            }
+                        })
+                        .child(format.display_name()),
+                );
+            }
+
+            this.child(submenu)
+        })
+}
+
 fn get_file_type(name: &str) -> String {
     if let Some(ext) = name.rsplit('.').next() {
         if ext != name {
@@ -1557,6 +2514,14 @@ impl FileList {
             selected_index: None,
             search_query: String::new(),
             sort_state: SortState::new(),
+            search_sort: SearchSortMode::default(),
+            pinned: Vec::new(),
+            placeholders: Vec::new(),
+            git_badges: HashMap::new(),
+            marked: Vec::new(),
+            column_widths: ColumnWidths::default(),
+            visible_columns: VisibleColumns::default(),
+            skeleton_rows: 0,
         }
     }
 
@@ -1572,6 +2537,14 @@ impl FileList {
             selected_index: None,
             search_query: String::new(),
             sort_state: SortState::new(),
+            search_sort: SearchSortMode::default(),
+            pinned: Vec::new(),
+            placeholders: Vec::new(),
+            git_badges: HashMap::new(),
+            marked: Vec::new(),
+            column_widths: ColumnWidths::default(),
+            visible_columns: VisibleColumns::default(),
+            skeleton_rows: 0,
         }
     }
 
@@ -1608,14 +2581,51 @@ impl FileList {
     }
 
     pub fn set_entries(&mut self, entries: Vec<FileEntry>) {
+        self.skeleton_rows = 0;
         self.entries = entries;
-        self.sort_state.sort_entries(&mut self.entries);
+        self.sort_entries();
         self.filtered_entries = None;
         self.highlight_positions = None;
         self.selected_index = None;
+        self.marked.clear();
         self.search_query.clear();
     }
 
+    pub fn refresh_entries(&mut self, entries: Vec<FileEntry>) {
+        let selected_path = self
+            .selected_index
+            .and_then(|i| self.get_display_entry(i))
+            .map(|e| e.path.clone());
+
+        self.entries = entries;
+        self.sort_entries();
+        self.filtered_entries = None;
+        self.highlight_positions = None;
+        let entries = &self.entries;
+        self.marked.retain(|path| entries.iter().any(|e| &e.path == path));
+        self.selected_index =
+            selected_path.and_then(|path| self.entries.iter().position(|e| e.path == path));
+    }
+
+    pub fn append_entries(&mut self, entries: Vec<FileEntry>) {
+        let selected_path = self
+            .selected_index
+            .and_then(|i| self.entries.get(i))
+            .map(|e| e.path.clone());
+
+        let compare = self.sort_state.comparator();
+        let pinned = &self.pinned;
+        merge_sorted_entries(&mut self.entries, entries, |a, b| {
+            pin_rank(pinned, a)
+                .cmp(&pin_rank(pinned, b))
+                .then_with(|| compare(a, b))
+        });
+
+        if let Some(path) = selected_path {
+            self.selected_index = self.entries.iter().position(|e| e.path == path);
+        }
+    }
+
     pub fn sort_state(&self) -> &SortState {
         &self.sort_state
     }
@@ -1626,11 +2636,134 @@ impl FileList {
 
     pub fn toggle_sort_column(&mut self, column: SortColumn) {
         self.sort_state.toggle_column(column);
-        self.sort_state.sort_entries(&mut self.entries);
+        self.sort_entries();
+    }
+
+    pub fn add_sort_tiebreaker(&mut self, column: SortColumn) {
+        self.sort_state.add_tiebreaker(column);
+        self.sort_entries();
     }
 
     pub fn apply_sort(&mut self) {
+        self.sort_entries();
+    }
+
+    pub fn pinned(&self) -> &[PathBuf] {
+        &self.pinned
+    }
+
+    pub fn is_pinned(&self, path: &Path) -> bool {
+        self.pinned.iter().any(|pinned| pinned == path)
+    }
+
+    pub fn set_pinned(&mut self, pinned: Vec<PathBuf>) {
+        let selected_path = self
+            .selected_index
+            .and_then(|i| self.entries.get(i))
+            .map(|e| e.path.clone());
+
+        self.pinned = pinned;
+        self.sort_entries();
+
+        if let Some(path) = selected_path {
+            self.selected_index = self.entries.iter().position(|e| e.path == path);
+        }
+    }
+
+    pub fn is_marked(&self, path: &Path) -> bool {
+        self.marked.iter().any(|marked| marked == path)
+    }
+
+    pub fn marked(&self) -> &[PathBuf] {
+        &self.marked
+    }
+
+    pub fn toggle_marked(&mut self, path: PathBuf) {
+        let current = self
+            .selected_index
+            .and_then(|index| self.get_display_entry(index))
+            .map(|entry| entry.path.clone());
+        toggle_marked(&mut self.marked, current.as_deref(), path);
+    }
+
+    pub fn clear_marked(&mut self) {
+        self.marked.clear();
+    }
+
+    pub fn column_widths(&self) -> &ColumnWidths {
+        &self.column_widths
+    }
+
+    pub fn set_column_widths(&mut self, widths: ColumnWidths) {
+        self.column_widths = widths;
+    }
+
+    pub fn resize_column(&mut self, divider: SortColumn, delta: f32, total: f32) -> bool {
+        self.column_widths.resize(divider, delta, total)
+    }
+
+    pub fn visible_columns(&self) -> &VisibleColumns {
+        &self.visible_columns
+    }
+
+    pub fn set_visible_columns(&mut self, columns: VisibleColumns) {
+        self.visible_columns = columns;
+        let sort = self.visible_columns.restrict_sort(self.sort_state);
+        if sort != self.sort_state {
+            self.sort_state = sort;
+            self.apply_sort();
+        }
+    }
+
+    pub fn toggle_column_visibility(&mut self, column: DetailsColumn) -> bool {
+        let mut columns = self.visible_columns.clone();
+        let visible = columns.toggle(column);
+        self.set_visible_columns(columns);
+        visible
+    }
+
+    pub fn skeleton_rows(&self) -> usize {
+        self.skeleton_rows
+    }
+
+    pub fn show_skeleton(&mut self, viewport_height: f32) {
+        self.entries.clear();
+        self.filtered_entries = None;
+        self.selected_index = None;
+        self.skeleton_rows = skeleton_row_count(viewport_height, self.row_height);
+    }
+
+    pub fn git_badge(&self, path: &Path) -> Option<GitStatusBadge> {
+        self.git_badges.get(path).copied()
+    }
+
+    pub fn set_git_badges(&mut self, badges: HashMap<PathBuf, GitStatusBadge>) {
+        self.git_badges = badges;
+    }
+
+    pub fn placeholders(&self) -> &[ArchivePlaceholder] {
+        &self.placeholders
+    }
+
+
+    pub fn set_placeholders(&mut self, placeholders: Vec<ArchivePlaceholder>) {
+        self.placeholders = placeholders;
+    }
+
+    pub fn visible_placeholders(&self) -> Vec<ArchivePlaceholder> {
+        self.placeholders
+            .iter()
+            .filter(|placeholder| {
+                placeholder.kind == ArchiveJobKind::Extract
+                    || !self.entries.iter().any(|e| e.path == placeholder.output)
+            })
+            .cloned()
+            .collect()
+    }
+
+    fn sort_entries(&mut self) {
         self.sort_state.sort_entries(&mut self.entries);
+        apply_pins(&mut self.entries, &self.pinned);
     }
 
     pub fn entries(&self) -> &[FileEntry] {
@@ -1648,6 +2781,26 @@ impl FileList {
     }
 
 
+    pub fn select_entry_named(&mut self, name: &str) -> Option<usize> {
+        let index = self
+            .visible_entries()
+            .iter()
+            .position(|entry| entry.name == name)?;
+        self.selected_index = Some(index);
+        Some(index)
+    }
+
+
+    pub fn select_entry_at_path(&mut self, path: &Path) -> Option<usize> {
+        let index = self
+            .visible_entries()
+            .iter()
+            .position(|entry| entry.path == path)?;
+        self.selected_index = Some(index);
+        Some(index)
+    }
+
+
     pub fn visible_entries(&self) -> Vec<&FileEntry> {
         if let Some(filtered) = &self.filtered_entries {
             filtered.iter().map(|f| &f.entry).collect()
@@ -1692,11 +2845,51 @@ impl FileList {
             .collect();
 
         self.filtered_entries = Some(filtered);
+        self.sort_search_results();
         self.selected_index = None;
         self.scroll_offset = 0.0;
     }
 
 
+    pub fn search_sort(&self) -> SearchSortMode {
+        self.search_sort
+    }
+
+
+    pub fn set_search_sort(&mut self, mode: SearchSortMode) {
+        self.search_sort = mode;
+        self.sort_search_results();
+        self.selected_index = None;
+    }
+
+    fn sort_search_results(&mut self) {
+        let Some(filtered) = self.filtered_entries.as_mut() else {
+            return;
+        };
+
+        match self.search_sort {
+            SearchSortMode::Relevance => filtered.sort_by(|a, b| b.score.cmp(&a.score)),
+            SearchSortMode::Name => filtered.sort_by(|a, b| {
+                a.entry
+                    .name
+                    .to_lowercase()
+                    .cmp(&b.entry.name.to_lowercase())
+            }),
+            SearchSortMode::DateModified => {
+                filtered.sort_by(|a, b| b.entry.modified.cmp(&a.entry.modified))
+            }
+            SearchSortMode::PathDepth => filtered.sort_by(|a, b| {
+                a.entry
+                    .path
+                    .components()
+                    .count()
+                    .cmp(&b.entry.path.components().count())
+                    .then_with(|| a.entry.name.to_lowercase().cmp(&b.entry.name.to_lowercase()))
+            }),
+        }
+    }
+
+
     pub fn clear_search_filter(&mut self) {
         self.filtered_entries = None;
         self.search_query.clear();
@@ -1812,6 +3005,17 @@ impl RenderedEntry {
     }
 }
 
+pub(crate) fn size_cell_label(entry: &FileEntry, folder_sizes: Option<&FolderSizes>) -> String {
+    match folder_sizes {
+        Some(sizes) if entry.is_dir => match sizes.get(&entry.path, entry.modified) {
+            FolderSize::Pending => FOLDER_SIZE_PLACEHOLDER.to_string(),
+            FolderSize::Ready(size) => format_size(size, false),
+            FolderSize::Unavailable => format_size(0, true),
+        },
+        _ => format_size(entry.size, entry.is_dir),
+    }
+}
+
 pub fn format_size(size: u64, is_dir: bool) -> String {
     if is_dir {
         return "--".to_string();
@@ -1836,19 +3040,7 @@ pub fn format_size(size: u64, is_dir: bool) -> String {
 }
 
 pub fn format_date(time: SystemTime) -> String {
-    match time.duration_since(SystemTime::UNIX_EPOCH) {
-        Ok(duration) => {
-            let secs = duration.as_secs();
-            let days = secs / 86400;
-            let years = 1970 + (days / 365);
-            let remaining_days = days % 365;
-            let month = (remaining_days / 30) + 1;
-            let day = (remaining_days % 30) + 1;
-
-            format!("{:04}-{:02}-{:02}", years, month.min(12), day.min(31))
-        }
-        Err(_) => "Unknown".to_string(),
-    }
+    crate::models::format_file_date(time)
 }
 
 #[cfg(test)]
@@ -1059,3 +1059,220 @@ fn test_parent_navigation_preserves_prefix() {
         current = parent.to_path_buf();
     }
 }
+
+#[test]
+fn test_append_entries_keeps_sorted_and_selection() {
+    let mut list = FileList::new();
+    list.set_entries(vec![
+        create_test_entry("src/b.rs", false, 10),
+        create_test_entry("src/d.rs", false, 10),
+    ]);
+    list.set_selected_index(Some(1));
+
+    list.append_entries(vec![
+        create_test_entry("src/a.rs", false, 10),
+        create_test_entry("src/c.rs", false, 10),
+    ]);
+
+    let names: Vec<&str> = list.entries().iter().map(|e| e.name.as_str()).collect();
+    assert_eq!(names, vec!["src/a.rs", "src/b.rs", "src/c.rs", "src/d.rs"]);
+    assert_eq!(list.selected_index(), Some(3));
+}
+
+fn create_dated_entry(name: &str, path: &str, age_secs: u64) -> FileEntry {
+    FileEntry::new(
+        name.to_string(),
+        PathBuf::from(path),
+        false,
+        100,
+        SystemTime::now() - std::time::Duration::from_secs(age_secs),
+    )
+}
+
+fn display_names(list: &FileList) -> Vec<String> {
+    (0..list.item_count())
+        .filter_map(|i| list.get_display_entry(i).map(|e| e.name.clone()))
+        .collect()
+}
+
+#[test]
+fn test_search_results_default_to_relevance() {
+    let mut list = FileList::new();
+    list.set_entries(vec![
+        create_test_entry("zeta_report.txt", false, 100),
+        create_test_entry("report.txt", false, 100),
+        create_test_entry("alpha_report.txt", false, 100),
+    ]);
+
+    let matches = vec![(0, vec![5], 40), (1, vec![0], 120), (2, vec![6], 80)];
+    list.apply_search_filter("report", matches);
+
+    assert_eq!(list.search_sort(), SearchSortMode::Relevance);
+    assert_eq!(
+        display_names(&list),
+        vec!["report.txt", "alpha_report.txt", "zeta_report.txt"]
+    );
+}
+
+#[test]
+fn test_search_sort_by_name_keeps_highlights() {
+    let mut list = FileList::new();
+    list.set_entries(vec![
+        create_test_entry("zeta_report.txt", false, 100),
+        create_test_entry("report.txt", false, 100),
+        create_test_entry("Alpha_report.txt", false, 100),
+    ]);
+
+    let matches = vec![
+        (0, vec![5, 6], 40),
+        (1, vec![0, 1], 120),
+        (2, vec![6, 7], 80),
+    ];
+    list.apply_search_filter("re", matches);
+    list.set_search_sort(SearchSortMode::Name);
+
+    assert_eq!(
+        display_names(&list),
+        vec!["Alpha_report.txt", "report.txt", "zeta_report.txt"]
+    );
+    assert_eq!(list.get_match_positions(0).unwrap(), &[6, 7]);
+    assert_eq!(list.get_match_positions(1).unwrap(), &[0, 1]);
+    assert_eq!(list.get_match_positions(2).unwrap(), &[5, 6]);
+}
+
+#[test]
+fn test_search_sort_by_date_and_depth() {
+    let mut list = FileList::new();
+    list.set_entries(vec![
+        create_dated_entry("old.rs", "/repo/old.rs", 3600),
+        create_dated_entry("deep.rs", "/repo/src/a/b/deep.rs", 60),
+        create_dated_entry("mid.rs", "/repo/src/mid.rs", 600),
+    ]);
+
+    let matches = vec![(0, vec![], 100), (1, vec![], 90), (2, vec![], 80)];
+    list.apply_search_filter("rs", matches);
+
+    list.set_search_sort(SearchSortMode::DateModified);
+    assert_eq!(display_names(&list), vec!["deep.rs", "mid.rs", "old.rs"]);
+
+    list.set_search_sort(SearchSortMode::PathDepth);
+    assert_eq!(display_names(&list), vec!["old.rs", "mid.rs", "deep.rs"]);
+
+    list.set_search_sort(SearchSortMode::Relevance);
+    assert_eq!(display_names(&list), vec!["old.rs", "deep.rs", "mid.rs"]);
+}
+
+#[test]
+fn test_search_sort_persists_across_new_queries() {
+    let mut list = FileList::new();
+    list.set_entries(vec![
+        create_test_entry("beta.txt", false, 100),
+        create_test_entry("alpha.txt", false, 100),
+    ]);
+
+    list.set_search_sort(SearchSortMode::Name);
+    list.apply_search_filter("a", vec![(0, vec![1], 100), (1, vec![0], 50)]);
+
+    assert_eq!(display_names(&list), vec!["alpha.txt", "beta.txt"]);
+}
+
+#[test]
+fn test_select_entry_at_path_after_load() {
+    let mut list = FileList::new();
+    list.set_entries(vec![
+        create_test_entry("zeta.txt", false, 10),
+        create_test_entry("alpha", true, 0),
+        create_test_entry("report.pdf", false, 2048),
+    ]);
+
+    let index = list
+        .select_entry_at_path(&PathBuf::from("/test/report.pdf"))
+        .unwrap();
+    assert_eq!(list.selected_index(), Some(index));
+    assert_eq!(list.get_display_entry(index).unwrap().name, "report.pdf");
+
+    assert_eq!(list.select_entry_at_path(&PathBuf::from("/other/report.pdf")), None);
+    assert_eq!(list.selected_index(), Some(index));
+}
+
+#[test]
+fn test_select_entry_named_targets_created_folder() {
+    let mut list = FileList::new();
+    list.set_entries(vec![
+        create_test_entry("zeta.txt", false, 10),
+        create_test_entry("alpha", true, 0),
+        create_test_entry("src", true, 0),
+    ]);
+
+    let index = list.select_entry_named("src").unwrap();
+    assert_eq!(list.selected_index(), Some(index));
+    assert_eq!(list.get_display_entry(index).unwrap().name, "src");
+
+    assert_eq!(list.select_entry_named("does-not-exist"), None);
+    assert_eq!(list.selected_index(), Some(index));
+}
+
+#[test]
+fn test_middle_click_on_directory_opens_new_tab() {
+    let folder = PathBuf::from("/home/user/projects");
+    let file = PathBuf::from("/home/user/notes.txt");
+
+    assert_eq!(
+        middle_click_action(folder.clone(), true, &Modifiers::default()),
+        ContextMenuAction::OpenInBackgroundTab(folder.clone())
+    );
+    assert_eq!(
+        middle_click_action(folder.clone(), true, &Modifiers::shift()),
+        ContextMenuAction::OpenInNewTab(folder)
+    );
+    assert_eq!(
+        middle_click_action(file.clone(), false, &Modifiers::default()),
+        ContextMenuAction::Open(file)
+    );
+}
+
+#[test]
+fn test_folder_size_cell_shows_placeholder_until_computed() {
+    let folder = create_test_entry("projects", true, 0);
+    let file = create_test_entry("notes.txt", false, 2048);
+    let mut sizes = FolderSizes::new();
+
+    assert_eq!(size_cell_label(&folder, None), "--");
+    assert_eq!(size_cell_label(&folder, Some(&sizes)), FOLDER_SIZE_PLACEHOLDER);
+    assert_eq!(size_cell_label(&file, Some(&sizes)), "2.0 KB");
+
+    let cancelled = sizes.request(&folder.path, folder.modified).unwrap();
+    assert_eq!(size_cell_label(&folder, Some(&sizes)), FOLDER_SIZE_PLACEHOLDER);
+    assert!(sizes.complete(&folder.path, folder.modified, &cancelled, Ok(1536)));
+    assert_eq!(size_cell_label(&folder, Some(&sizes)), "1.5 KB");
+
+    let revisited = FileEntry::new(
+        folder.name.clone(),
+        folder.path.clone(),
+        true,
+        0,
+        folder.modified,
+    );
+    assert!(sizes.request(&revisited.path, revisited.modified).is_none());
+    assert_eq!(size_cell_label(&revisited, Some(&sizes)), "1.5 KB");
+}
+
+#[test]
+fn test_refresh_entries_keeps_selection_and_marks() {
+    let mut list = FileList::new();
+    list.set_entries(vec![
+        create_test_entry("beta.txt", false, 10),
+        create_test_entry("gamma.txt", false, 20),
+    ]);
+    list.select_entry_at_path(&PathBuf::from("/test/gamma.txt"));
+    list.toggle_marked(PathBuf::from("/test/beta.txt"));
+
+    list.refresh_entries(vec![
+        create_test_entry("alpha.txt", false, 5),
+        create_test_entry("gamma.txt", false, 20),
+    ]);
+
+    let selected = list.selected_index().and_then(|i| list.get_display_entry(i));
+    assert_eq!(selected.unwrap().name, "gamma.txt");
+    assert_eq!(list.marked(), &[PathBuf::from("/test/gamma.txt")]);
+}
@@ -0,0 +1,182 @@
+use gpui::{prelude::*, App, FocusHandle, Keystroke, Window};
+
+use crate::models::theme_colors;
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrapKey {
+    Next,
+    Previous,
+    Dismiss,
+    Confirm,
+}
+
+impl TrapKey {
+    pub fn from_keystroke(keystroke: &Keystroke) -> Option<Self> {
+        match keystroke.key.as_str() {
+            "tab" if keystroke.modifiers.shift => Some(TrapKey::Previous),
+            "tab" => Some(TrapKey::Next),
+            "escape" => Some(TrapKey::Dismiss),
+            "enter" => Some(TrapKey::Confirm),
+            _ => None,
+        }
+    }
+}
+
+
+pub fn cycle_focus_index(current: Option<usize>, len: usize, backwards: bool) -> Option<usize> {
+    if len == 0 {
+        return None;
+    }
+    Some(match (current, backwards) {
+        (None, false) => 0,
+        (None, true) => len - 1,
+        (Some(index), false) => (index + 1) % len,
+        (Some(index), true) => (index + len - 1) % len,
+    })
+}
+
+
+pub struct FocusTrap {
+    handles: Vec<FocusHandle>,
+    initial: usize,
+    restore_to: Option<FocusHandle>,
+    activated: bool,
+}
+
+impl FocusTrap {
+    pub fn new(handles: Vec<FocusHandle>) -> Self {
+        Self {
+            handles,
+            initial: 0,
+            restore_to: None,
+            activated: false,
+        }
+    }
+
+    pub fn with_initial(mut self, index: usize) -> Self {
+        self.initial = index.min(self.handles.len().saturating_sub(1));
+        self
+    }
+
+
+    pub fn restoring(mut self, handle: Option<FocusHandle>) -> Self {
+        self.restore_to = handle;
+        self
+    }
+
+    pub fn len(&self) -> usize {
+        self.handles.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.handles.is_empty()
+    }
+
+    pub fn handle(&self, index: usize) -> &FocusHandle {
+        &self.handles[index]
+    }
+
+    pub fn focused_index(&self, window: &Window) -> Option<usize> {
+        self.handles
+            .iter()
+            .position(|handle| handle.is_focused(window))
+    }
+
+    pub fn is_focused(&self, index: usize, window: &Window) -> bool {
+        self.handles
+            .get(index)
+            .is_some_and(|handle| handle.is_focused(window))
+    }
+
+
+    pub fn activate(&mut self, window: &mut Window, cx: &mut App) {
+        if self.activated || self.handles.is_empty() {
+            return;
+        }
+        self.activated = true;
+        if self.restore_to.is_none() {
+            self.restore_to = window.focused(cx);
+        }
+        let handle = self.handles[self.initial].clone();
+        window.defer(cx, move |window, _| handle.focus(window));
+    }
+
+    pub fn cycle(&self, backwards: bool, window: &mut Window) {
+        let current = self.focused_index(window);
+        if let Some(index) = cycle_focus_index(current, self.handles.len(), backwards) {
+            self.handles[index].focus(window);
+        }
+    }
+
+    pub fn take_restore_target(&mut self) -> Option<FocusHandle> {
+        self.restore_to.take()
+    }
+
+
+    pub fn release(mut self, cx: &mut App) {
+        restore_focus(self.restore_to.take(), cx);
+    }
+}
+
+
+pub fn restore_focus(handle: Option<FocusHandle>, cx: &mut App) {
+    let Some(handle) = handle else {
+        return;
+    };
+    let Some(window) = cx.active_window() else {
+        return;
+    };
+    cx.defer(move |cx| {
+        let _ = window.update(cx, |_, window, _| handle.focus(window));
+    });
+}
+
+
+pub fn focus_ring<E: Styled>(element: E, focused: bool) -> E {
+    if focused {
+        element.border_2().border_color(theme_colors().accent_primary)
+    } else {
+        element
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tab_cycles_forward_and_wraps() {
+        let order: Vec<usize> = std::iter::successors(Some(0), |&i| cycle_focus_index(Some(i), 4, false))
+            .take(6)
+            .collect();
+        assert_eq!(order, vec![0, 1, 2, 3, 0, 1]);
+    }
+
+    #[test]
+    fn test_shift_tab_cycles_backward_and_wraps() {
+        let order: Vec<usize> = std::iter::successors(Some(1), |&i| cycle_focus_index(Some(i), 4, true))
+            .take(6)
+            .collect();
+        assert_eq!(order, vec![1, 0, 3, 2, 1, 0]);
+    }
+
+    #[test]
+    fn test_cycle_enters_trap_from_outside() {
+        assert_eq!(cycle_focus_index(None, 3, false), Some(0));
+        assert_eq!(cycle_focus_index(None, 3, true), Some(2));
+        assert_eq!(cycle_focus_index(None, 0, false), None);
+        assert_eq!(cycle_focus_index(Some(0), 1, true), Some(0));
+    }
+
+    #[test]
+    fn test_trap_keys() {
+        let key = |s: &str| TrapKey::from_keystroke(&Keystroke::parse(s).unwrap());
+        assert_eq!(key("tab"), Some(TrapKey::Next));
+        assert_eq!(key("shift-tab"), Some(TrapKey::Previous));
+        assert_eq!(key("escape"), Some(TrapKey::Dismiss));
+        assert_eq!(key("enter"), Some(TrapKey::Confirm));
+        assert_eq!(key("a"), None);
+    }
+}
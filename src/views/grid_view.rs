@@ -1,29 +1,72 @@
-use std::path::PathBuf;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+use std::sync::Arc;
 
 use gpui::{
-    anchored, div, prelude::*, px, svg, App, Context, Corner, FocusHandle, Focusable,
-    InteractiveElement, IntoElement, MouseButton, MouseDownEvent, ParentElement, Pixels, Point,
-    Render, SharedString, Styled, Window,
+    actions, anchored, div, img, prelude::*, px, svg, App, Context, Corner, FocusHandle, Focusable, Image,
+    InteractiveElement, IntoElement, KeyBinding, MouseButton, MouseDownEvent, ParentElement, Pixels, Point,
+    Render, ScrollHandle, SharedString, Styled, Window,
 };
 
-use super::file_list::{get_file_icon, get_file_icon_color, ContextMenuAction};
-use crate::models::{theme_colors, FileEntry, GridConfig};
+use super::file_list::{
+    custom_context_entries, get_file_icon, get_file_icon_color, middle_click_action, ContextMenuAction,
+};
+use super::tag_ui::{render_tag_dot_buttons, tags_for_entries, TagSelectHandler};
+use super::TooltipLabel;
+use crate::utils::middle_ellipsis;
+use crate::models::{
+    apply_pins, click_behavior, click_intent, context_selection, icon_asset_path, is_checksum_manifest, load_templates, merge_sorted_entries, pin_rank, supports_thumbnail, templates_dir, theme_colors, toggle_marked, ClickIntent, FileEntry, GitStatusBadge, GridConfig, PreviewKey, PreviewKind,
+    PreviewPriority, PreviewResult, PreviewService, ScrollGeometry, Tag, TagManager, ViewMode,
+    HIDDEN_ENTRY_OPACITY, THUMBNAIL_SIZE,
+};
+
+
+actions!(
+    grid_view,
+    [
+        GridSelectLeft,
+        GridSelectRight,
+        GridSelectUp,
+        GridSelectDown,
+        GridOpenSelected,
+    ]
+);
+
+
+const THUMBNAIL_WINDOW: usize = 60;
+
+
+const GRID_PADDING: f32 = 16.0;
+
+
+const THUMBNAIL_PREFETCH: usize = 20;
+
+
+const NAME_MAX_CHARS: usize = 14;
 
 pub struct GridView {
     entries: Vec<FileEntry>,
     config: GridConfig,
     selected_index: Option<usize>,
     viewport_width: f32,
+    pinned: Vec<PathBuf>,
+    git_badges: HashMap<PathBuf, GitStatusBadge>,
+    marked: Vec<PathBuf>,
 }
 
 pub struct GridViewComponent {
     grid_view: GridView,
     focus_handle: FocusHandle,
+    scroll_handle: ScrollHandle,
     pending_navigation: Option<PathBuf>,
     context_menu_position: Option<Point<Pixels>>,
     context_menu_index: Option<usize>,
     pending_context_action: Option<ContextMenuAction>,
     show_open_with_submenu: bool,
+    show_template_submenu: bool,
+    pending_tag_filter: Option<Tag>,
+    thumbnail_paths: HashSet<PathBuf>,
 }
 
 impl GridView {
@@ -33,6 +76,9 @@ impl GridView {
             config: GridConfig::default(),
             selected_index: None,
             viewport_width: 800.0,
+            pinned: Vec::new(),
+            git_badges: HashMap::new(),
+            marked: Vec::new(),
         }
     }
 
@@ -42,12 +88,84 @@ impl GridView {
             config,
             selected_index: None,
             viewport_width: 800.0,
+            pinned: Vec::new(),
+            git_badges: HashMap::new(),
+            marked: Vec::new(),
         }
     }
 
     pub fn set_entries(&mut self, entries: Vec<FileEntry>) {
         self.entries = entries;
+        apply_pins(&mut self.entries, &self.pinned);
         self.selected_index = None;
+        self.marked.clear();
+    }
+
+    pub fn append_entries(&mut self, entries: Vec<FileEntry>) {
+        let selected_path = self
+            .selected_index
+            .and_then(|i| self.entries.get(i))
+            .map(|e| e.path.clone());
+
+        let pinned = &self.pinned;
+        merge_sorted_entries(&mut self.entries, entries, |a, b| {
+            pin_rank(pinned, a).cmp(&pin_rank(pinned, b))
+        });
+
+        if let Some(path) = selected_path {
+            self.selected_index = self.entries.iter().position(|e| e.path == path);
+        }
+    }
+
+    pub fn refresh_entries(&mut self, entries: Vec<FileEntry>) {
+        let selected_path = self
+            .selected_index
+            .and_then(|i| self.entries.get(i))
+            .map(|e| e.path.clone());
+
+        self.entries = entries;
+        apply_pins(&mut self.entries, &self.pinned);
+        let entries = &self.entries;
+        self.marked.retain(|path| entries.iter().any(|e| &e.path == path));
+        self.selected_index =
+            selected_path.and_then(|path| self.entries.iter().position(|e| e.path == path));
+    }
+
+    pub fn is_pinned(&self, path: &Path) -> bool {
+        self.pinned.iter().any(|pinned| pinned == path)
+    }
+
+    pub fn set_pinned(&mut self, pinned: Vec<PathBuf>) {
+        self.pinned = pinned;
+        apply_pins(&mut self.entries, &self.pinned);
+    }
+
+    pub fn is_marked(&self, path: &Path) -> bool {
+        self.marked.iter().any(|marked| marked == path)
+    }
+
+    pub fn marked(&self) -> &[PathBuf] {
+        &self.marked
+    }
+
+    pub fn toggle_marked(&mut self, path: PathBuf) {
+        let current = self
+            .selected_index
+            .and_then(|index| self.entries.get(index))
+            .map(|entry| entry.path.clone());
+        toggle_marked(&mut self.marked, current.as_deref(), path);
+    }
+
+    pub fn clear_marked(&mut self) {
+        self.marked.clear();
+    }
+
+    pub fn git_badge(&self, path: &Path) -> Option<GitStatusBadge> {
+        self.git_badges.get(path).copied()
+    }
+
+    pub fn set_git_badges(&mut self, badges: HashMap<PathBuf, GitStatusBadge>) {
+        self.git_badges = badges;
     }
 
     pub fn entries(&self) -> &[FileEntry] {
@@ -74,6 +192,18 @@ impl GridView {
         self.selected_index = index;
     }
 
+    pub fn select_entry_named(&mut self, name: &str) -> Option<usize> {
+        let index = self.entries.iter().position(|entry| entry.name == name)?;
+        self.selected_index = Some(index);
+        Some(index)
+    }
+
+    pub fn select_entry_at_path(&mut self, path: &Path) -> Option<usize> {
+        let index = self.entries.iter().position(|entry| entry.path == path)?;
+        self.selected_index = Some(index);
+        Some(index)
+    }
+
     pub fn set_viewport_width(&mut self, width: f32) {
         self.viewport_width = width;
     }
@@ -105,26 +235,25 @@ impl Default for GridView {
 
 impl GridViewComponent {
     pub fn new(cx: &mut Context<Self>) -> Self {
-        Self {
-            grid_view: GridView::new(),
-            focus_handle: cx.focus_handle(),
-            pending_navigation: None,
-            context_menu_position: None,
-            context_menu_index: None,
-            pending_context_action: None,
-            show_open_with_submenu: false,
-        }
+        Self::with_grid_view(GridView::new(), cx)
     }
 
     pub fn with_grid_view(grid_view: GridView, cx: &mut Context<Self>) -> Self {
+        cx.observe_global::<TagManager>(|_, cx| cx.notify()).detach();
+        cx.observe_global::<PreviewService>(|_, cx| cx.notify()).detach();
+
         Self {
             grid_view,
             focus_handle: cx.focus_handle(),
+            scroll_handle: ScrollHandle::new(),
             pending_navigation: None,
             context_menu_position: None,
             context_menu_index: None,
             pending_context_action: None,
             show_open_with_submenu: false,
+            show_template_submenu: false,
+            pending_tag_filter: None,
+            thumbnail_paths: HashSet::new(),
         }
     }
 
@@ -140,16 +269,138 @@ impl GridViewComponent {
         self.pending_navigation.take()
     }
 
+    pub fn scroll_offset(&self) -> f32 {
+        -f32::from(self.scroll_handle.offset().y)
+    }
+
+    pub fn set_scroll_offset(&self, offset: f32) {
+        let mut position = self.scroll_handle.offset();
+        position.y = px(-offset.max(0.0));
+        self.scroll_handle.set_offset(position);
+    }
+
+    pub fn scroll_geometry(&self) -> ScrollGeometry {
+        let config = self.grid_view.config;
+        let viewport = f32::from(self.scroll_handle.bounds().size.height);
+        ScrollGeometry::grid(
+            config.item_height + config.gap,
+            self.grid_view.columns(),
+            viewport,
+        )
+    }
+
     pub fn close_context_menu(&mut self) {
         self.context_menu_position = None;
         self.context_menu_index = None;
         self.show_open_with_submenu = false;
+        self.show_template_submenu = false;
     }
 
     pub fn take_pending_context_action(&mut self) -> Option<ContextMenuAction> {
         self.pending_context_action.take()
     }
 
+    pub fn take_pending_tag_filter(&mut self) -> Option<Tag> {
+        self.pending_tag_filter.take()
+    }
+
+    fn request_thumbnails(&mut self, cx: &mut Context<Self>) -> HashMap<usize, Arc<Image>> {
+        let selected = self.grid_view.selected_index;
+        let len = self.grid_view.entries.len();
+        let mut visible = self
+            .scroll_geometry()
+            .visible_range(self.scroll_offset(), len);
+        if visible.is_empty() {
+            visible = 0..THUMBNAIL_WINDOW.min(len);
+        }
+        let start = visible.start.saturating_sub(THUMBNAIL_PREFETCH);
+        let end = (visible.end + THUMBNAIL_PREFETCH).min(len);
+        let candidates: Vec<(usize, PreviewKey)> = self
+            .grid_view
+            .entries
+            .iter()
+            .enumerate()
+            .take(end)
+            .skip(start)
+            .filter(|(_, entry)| !entry.is_dir && supports_thumbnail(&entry.path))
+            .map(|(ix, entry)| {
+                let key = PreviewKey::new(
+                    entry.path.clone(),
+                    Some(entry.modified),
+                    PreviewKind::Thumbnail(THUMBNAIL_SIZE),
+                );
+                (ix, key)
+            })
+            .collect();
+
+        let paths: HashSet<PathBuf> = candidates.iter().map(|(_, key)| key.path.clone()).collect();
+        if paths != self.thumbnail_paths {
+            PreviewService::retain_paths(&paths, cx);
+            self.thumbnail_paths = paths;
+        }
+
+        let mut thumbnails = HashMap::new();
+        for (ix, key) in candidates {
+            let priority = if selected == Some(ix) {
+                PreviewPriority::Selected
+            } else if visible.contains(&ix) {
+                PreviewPriority::Visible
+            } else {
+                PreviewPriority::Prefetch
+            };
+            if let Some(PreviewResult::Image(image)) = PreviewService::request(key, priority, cx) {
+                thumbnails.insert(ix, image);
+            }
+        }
+        thumbnails
+    }
+
+    pub fn register_key_bindings(cx: &mut App) {
+        cx.bind_keys([
+            KeyBinding::new("left", GridSelectLeft, Some("GridView")),
+            KeyBinding::new("right", GridSelectRight, Some("GridView")),
+            KeyBinding::new("up", GridSelectUp, Some("GridView")),
+            KeyBinding::new("down", GridSelectDown, Some("GridView")),
+            KeyBinding::new("enter", GridOpenSelected, Some("GridView")),
+        ]);
+    }
+
+    fn step_selection(&mut self, offset: isize, cx: &mut Context<Self>) {
+        let Some(last) = self.grid_view.item_count().checked_sub(1) else {
+            return;
+        };
+        let index = match self.grid_view.selected_index {
+            Some(current) => current.saturating_add_signed(offset).min(last),
+            None => 0,
+        };
+        self.grid_view.selected_index = Some(index);
+        cx.notify();
+    }
+
+    fn handle_select_left(&mut self, _: &GridSelectLeft, _window: &mut Window, cx: &mut Context<Self>) {
+        self.step_selection(-1, cx);
+    }
+
+    fn handle_select_right(&mut self, _: &GridSelectRight, _window: &mut Window, cx: &mut Context<Self>) {
+        self.step_selection(1, cx);
+    }
+
+    fn handle_select_up(&mut self, _: &GridSelectUp, _window: &mut Window, cx: &mut Context<Self>) {
+        let columns = self.grid_view.columns().max(1) as isize;
+        self.step_selection(-columns, cx);
+    }
+
+    fn handle_select_down(&mut self, _: &GridSelectDown, _window: &mut Window, cx: &mut Context<Self>) {
+        let columns = self.grid_view.columns().max(1) as isize;
+        self.step_selection(columns, cx);
+    }
+
+    fn handle_open_selected(&mut self, _: &GridOpenSelected, _window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(index) = self.grid_view.selected_index {
+            self.open_item(index, cx);
+        }
+    }
+
     pub fn select_item(&mut self, index: usize, cx: &mut Context<Self>) {
         self.grid_view.selected_index = Some(index);
         cx.notify();
@@ -178,6 +429,11 @@ impl Render for GridViewComponent {
         let selected_index = self.grid_view.selected_index;
         let context_menu_pos = self.context_menu_position;
         let _context_menu_idx = self.context_menu_index;
+        let thumbnails = self.request_thumbnails(cx);
+        let grid_width = f32::from(self.scroll_handle.bounds().size.width) - GRID_PADDING * 2.0;
+        if grid_width > 0.0 {
+            self.grid_view.set_viewport_width(grid_width);
+        }
 
         let theme = theme_colors();
         let bg_darker = theme.bg_void;
@@ -194,6 +450,13 @@ impl Render for GridViewComponent {
 
         div()
             .id("grid-view")
+            .key_context("GridView")
+            .track_focus(&self.focus_handle)
+            .on_action(cx.listener(Self::handle_select_left))
+            .on_action(cx.listener(Self::handle_select_right))
+            .on_action(cx.listener(Self::handle_select_up))
+            .on_action(cx.listener(Self::handle_select_down))
+            .on_action(cx.listener(Self::handle_open_selected))
             .size_full()
             .bg(bg_darker)
             .flex()
@@ -201,8 +464,9 @@ impl Render for GridViewComponent {
             .relative()
             .on_mouse_down(
                 MouseButton::Left,
-                cx.listener(|view, _event, _window, cx| {
+                cx.listener(|view, _event, window, cx| {
                     view.close_context_menu();
+                    window.focus(&view.focus_handle);
                     cx.notify();
                 }),
             )
@@ -222,9 +486,11 @@ impl Render for GridViewComponent {
             )
             .child(
                 div()
+                    .id("grid-view-scroll")
                     .flex_1()
-                    .overflow_hidden()
-                    .p_4()
+                    .overflow_y_scroll()
+                    .track_scroll(&self.scroll_handle)
+                    .p(px(GRID_PADDING))
                     .when(total_items == 0, |this| {
                         this.flex().items_center().justify_center().child(
                             div()
@@ -245,13 +511,28 @@ impl Render for GridViewComponent {
                     .when(total_items > 0, |this| {
                         let entity = cx.entity().clone();
                         let entries = self.grid_view.entries.clone();
+                        let entry_tags = tags_for_entries(cx, &entries);
+                        let on_tag_select: TagSelectHandler = {
+                            let entity = entity.clone();
+                            Rc::new(move |tag: &Tag, _window: &mut Window, cx: &mut App| {
+                                entity.update(cx, |view, cx| {
+                                    view.pending_tag_filter = Some(tag.clone());
+                                    cx.notify();
+                                });
+                            })
+                        };
 
                         this.child(div().flex().flex_wrap().gap(px(config.gap)).children(
                             entries.iter().enumerate().map(|(ix, entry)| {
-                                let is_selected = selected_index == Some(ix);
+                                let is_selected = selected_index == Some(ix)
+                                    || self.grid_view.is_marked(&entry.path);
                                 let is_dir = entry.is_dir;
                                 let is_shared = entry.is_shared;
+                                let is_dimmed = entry.hidden_kind.is_dimmed();
+                                let is_pinned = self.grid_view.is_pinned(&entry.path);
+                                let git_badge = self.grid_view.git_badge(&entry.path);
                                 let name = entry.name.clone();
+                                let name_truncated = name.chars().count() > NAME_MAX_CHARS;
                                 let icon_name = get_file_icon(&name, is_dir);
                                 let icon_color = if is_dir {
                                     if is_selected {
@@ -265,9 +546,12 @@ impl Render for GridViewComponent {
                                 let entry_path = entry.path.clone();
                                 let entity = entity.clone();
                                 let entity_for_ctx = entity.clone();
+                                let tags = &entry_tags[ix];
+                                let thumbnail = thumbnails.get(&ix).cloned();
 
                                 div()
                                     .id(SharedString::from(format!("grid-item-{}", ix)))
+                                    .when(name_truncated, |s| s.tooltip_label(name.clone()))
                                     .w(px(config.item_width))
                                     .h(px(config.item_height))
                                     .flex()
@@ -280,22 +564,48 @@ impl Render for GridViewComponent {
                                     .cursor_pointer()
                                     .when(is_selected, |s| s.bg(selected_bg))
                                     .when(!is_selected, |s| s.hover(|h| h.bg(hover_bg)))
+                                    .when(is_dimmed, |s| s.opacity(HIDDEN_ENTRY_OPACITY))
                                     .on_click({
                                         let entry_path = entry_path.clone();
                                         let entity = entity.clone();
                                         move |event, _window, cx| {
                                             entity.update(cx, |view, cx| {
                                                 view.close_context_menu();
-                                                if event.click_count() == 2 && is_dir {
-                                                    view.pending_navigation =
-                                                        Some(entry_path.clone());
+                                                let intent = click_intent(
+                                                    click_behavior(),
+                                                    event.click_count(),
+                                                    &event.modifiers(),
+                                                );
+                                                if intent == ClickIntent::Open && is_dir {
+                                                    view.pending_navigation = Some(entry_path.clone());
                                                 } else {
+                                                    if event.modifiers().secondary() {
+                                                        view.grid_view.toggle_marked(entry_path.clone());
+                                                    } else {
+                                                        view.grid_view.clear_marked();
+                                                    }
                                                     view.grid_view.selected_index = Some(ix);
                                                 }
                                                 cx.notify();
                                             });
                                         }
                                     })
+                                    .on_mouse_down(MouseButton::Middle, {
+                                        let entry_path = entry_path.clone();
+                                        let entity = entity.clone();
+                                        move |event: &MouseDownEvent, _window, cx| {
+                                            cx.stop_propagation();
+                                            entity.update(cx, |view, cx| {
+                                                view.close_context_menu();
+                                                view.pending_context_action = Some(middle_click_action(
+                                                    entry_path.clone(),
+                                                    is_dir,
+                                                    &event.modifiers,
+                                                ));
+                                                cx.notify();
+                                            });
+                                        }
+                                    })
                                     .on_mouse_down(MouseButton::Right, {
                                         let entity = entity_for_ctx.clone();
                                         move |event: &MouseDownEvent, _window, cx| {
@@ -310,15 +620,20 @@ impl Render for GridViewComponent {
                                     .child(
                                         div()
                                             .relative()
-                                            .child(
-                                                svg()
-                                                    .path(SharedString::from(format!(
-                                                        "assets/icons/{}.svg",
-                                                        icon_name
+                                            .child(match thumbnail {
+                                                Some(image) => img(image)
+                                                    .size(px(config.icon_size))
+                                                    .rounded_sm()
+                                                    .object_fit(gpui::ObjectFit::Contain)
+                                                    .into_any_element(),
+                                                None => svg()
+                                                    .path(SharedString::from(icon_asset_path(
+                                                        icon_name,
                                                     )))
                                                     .size(px(config.icon_size))
-                                                    .text_color(icon_color),
-                                            )
+                                                    .text_color(icon_color)
+                                                    .into_any_element(),
+                                            })
                                             .when(is_shared && is_dir, |s| {
                                                 s.child(
                                                     div()
@@ -334,6 +649,37 @@ impl Render for GridViewComponent {
                                                                 .text_color(gpui::rgb(0x3fb950))
                                                         )
                                                 )
+                                            })
+                                            .when(is_pinned, |s| {
+                                                s.child(
+                                                    div()
+                                                        .absolute()
+                                                        .top_0()
+                                                        .left_0()
+                                                        .bg(gpui::rgb(0x0d1117))
+                                                        .rounded_sm()
+                                                        .child(
+                                                            svg()
+                                                                .path("assets/icons/pin.svg")
+                                                                .size(px(12.0))
+                                                                .text_color(theme.accent_primary),
+                                                        ),
+                                                )
+                                            })
+                                            .when_some(git_badge, |s, badge| {
+                                                s.child(
+                                                    div()
+                                                        .absolute()
+                                                        .bottom_0()
+                                                        .right_0()
+                                                        .px_1()
+                                                        .bg(gpui::rgb(0x0d1117))
+                                                        .rounded_sm()
+                                                        .text_xs()
+                                                        .font_weight(gpui::FontWeight::BOLD)
+                                                        .text_color(gpui::rgb(badge.color()))
+                                                        .child(badge.label()),
+                                                )
                                             }),
                                     )
                                     .child(
@@ -347,8 +693,15 @@ impl Render for GridViewComponent {
                                                 text_light
                                             })
                                             .truncate()
-                                            .child(truncate_name(&name, 14)),
+                                            .child(middle_ellipsis(&name, NAME_MAX_CHARS)),
                                     )
+                                    .when(!tags.is_empty(), |s| {
+                                        s.child(render_tag_dot_buttons(
+                                            &format!("grid-item-{}", ix),
+                                            tags,
+                                            on_tag_select.clone(),
+                                        ))
+                                    })
                             }),
                         ))
                     }),
@@ -385,10 +738,24 @@ impl Render for GridViewComponent {
                 let selected_entry = self
                     .context_menu_index
                     .and_then(|idx| self.grid_view.entries.get(idx).cloned());
+                let is_pinned = selected_entry
+                    .as_ref()
+                    .is_some_and(|e| self.grid_view.is_pinned(&e.path));
+                let tag_selection = selected_entry
+                    .as_ref()
+                    .map(|e| context_selection(self.grid_view.marked(), &e.path))
+                    .unwrap_or_default();
                 let is_dir = selected_entry.as_ref().map(|e| e.is_dir).unwrap_or(false);
                 let is_archive = selected_entry.as_ref()
                     .map(|e| crate::models::ArchiveManager::new().is_archive(&e.path))
                     .unwrap_or(false);
+                let is_manifest = selected_entry
+                    .as_ref()
+                    .is_some_and(|e| !e.is_dir && is_checksum_manifest(&e.path));
+                let custom_entries = selected_entry
+                    .as_ref()
+                    .map(|e| custom_context_entries(&e.path, e.is_dir, cx))
+                    .unwrap_or_default();
 
                 this.child(
                     anchored()
@@ -435,6 +802,7 @@ impl Render for GridViewComponent {
                                 ))
                                 .child(render_grid_open_with_submenu(
                                     selected_entry.clone(),
+                                    tag_selection.clone(),
                                     self.show_open_with_submenu,
                                     text_light,
                                     hover_bg,
@@ -492,6 +860,60 @@ impl Render for GridViewComponent {
                                         ),
                                     )
                                 })
+                                .when(is_dir, |this| {
+                                    let rules = [
+                                        ("layout-grid", "Always Use Grid View", Some(ViewMode::Grid)),
+                                        ("list", "Always Use List View", Some(ViewMode::List)),
+                                        ("folder", "Use Default View", None),
+                                    ];
+                                    this.child(render_context_menu_divider(border_subtle)).children(
+                                        rules.into_iter().map(|(icon, label, mode)| {
+                                            let entity = entity.clone();
+                                            let entry = selected_entry.clone();
+                                            render_context_menu_item(icon, label, text_light, hover_bg, {
+                                                move |_window, cx| {
+                                                    if let Some(ref e) = entry {
+                                                        entity.update(cx, |view, cx| {
+                                                            view.pending_context_action =
+                                                                Some(ContextMenuAction::SetFolderViewMode {
+                                                                    path: e.path.clone(),
+                                                                    mode,
+                                                                });
+                                                            view.close_context_menu();
+                                                            cx.notify();
+                                                        });
+                                                    }
+                                                }
+                                            })
+                                        }),
+                                    )
+                                })
+                                .when(is_dir, |this| {
+                                    let rules = [
+                                        ("eye", "Always Show Hidden Files", Some(true)),
+                                        ("eye-off", "Always Hide Hidden Files", Some(false)),
+                                        ("folder", "Use Default Hidden Files", None),
+                                    ];
+                                    this.children(rules.into_iter().map(|(icon, label, show)| {
+                                        let entity = entity.clone();
+                                        let entry = selected_entry.clone();
+                                        render_context_menu_item(icon, label, text_light, hover_bg, {
+                                            move |_window, cx| {
+                                                if let Some(ref e) = entry {
+                                                    entity.update(cx, |view, cx| {
+                                                        view.pending_context_action =
+                                                            Some(ContextMenuAction::SetFolderHiddenFiles {
+                                                                path: e.path.clone(),
+                                                                show,
+                                                            });
+                                                        view.close_context_menu();
+                                                        cx.notify();
+                                                    });
+                                                }
+                                            }
+                                        })
+                                    }))
+                                })
                                 .child(render_context_menu_divider(border_subtle))
                                 .child(render_context_menu_item(
                                     "eye",
@@ -536,6 +958,26 @@ impl Render for GridViewComponent {
                                         }
                                     },
                                 ))
+                                .child(render_context_menu_item(
+                                    "tag",
+                                    "Edit Tags…",
+                                    text_light,
+                                    hover_bg,
+                                    {
+                                        let entity = entity.clone();
+                                        let paths = tag_selection.clone();
+                                        move |_window, cx| {
+                                            if !paths.is_empty() {
+                                                entity.update(cx, |view, cx| {
+                                                    view.pending_context_action =
+                                                        Some(ContextMenuAction::EditTags(paths.clone()));
+                                                    view.close_context_menu();
+                                                    cx.notify();
+                                                });
+                                            }
+                                        }
+                                    },
+                                ))
                                 .child(render_context_menu_divider(border_subtle))
                                 .child(render_context_menu_item(
                                     "pen",
@@ -663,6 +1105,31 @@ impl Render for GridViewComponent {
                                         },
                                     ))
                                 })
+                                .when(is_manifest, |this| {
+                                    let entity = entity.clone();
+                                    let entry = selected_entry.clone();
+                                    this.child(render_context_menu_item(
+                                        "shield-check",
+                                        "Verify Checksums",
+                                        text_light,
+                                        hover_bg,
+                                        {
+                                            move |_window, cx| {
+                                                if let Some(ref e) = entry {
+                                                    entity.update(cx, |view, cx| {
+                                                        view.pending_context_action = Some(
+                                                            ContextMenuAction::VerifyChecksums(
+                                                                e.path.clone(),
+                                                            ),
+                                                        );
+                                                        view.close_context_menu();
+                                                        cx.notify();
+                                                    });
+                                                }
+                                            }
+                                        },
+                                    ))
+                                })
                                 .when(is_archive, |this| {
                                     let entity1 = entity.clone();
                                     let entity2 = entity.clone();
@@ -750,6 +1217,13 @@ impl Render for GridViewComponent {
                                         }
                                     },
                                 ))
+                                .child(render_grid_template_submenu(
+                                    self.show_template_submenu,
+                                    text_light,
+                                    hover_bg,
+                                    border_color,
+                                    entity.clone(),
+                                ))
                                 .child(render_context_menu_item(
                                     "folder-search",
                                     "Show in Finder",
@@ -772,6 +1246,50 @@ impl Render for GridViewComponent {
                                         }
                                     },
                                 ))
+                                .child(render_context_menu_item(
+                                    "app-window",
+                                    "Reveal in New Window",
+                                    text_light,
+                                    hover_bg,
+                                    {
+                                        let entity = entity.clone();
+                                        let entry = selected_entry.clone();
+                                        move |_window, cx| {
+                                            if let Some(ref e) = entry {
+                                                entity.update(cx, |view, cx| {
+                                                    view.pending_context_action =
+                                                        Some(ContextMenuAction::RevealInNewWindow(
+                                                            e.path.clone(),
+                                                        ));
+                                                    view.close_context_menu();
+                                                    cx.notify();
+                                                });
+                                            }
+                                        }
+                                    },
+                                ))
+                                .child(render_context_menu_item(
+                                    if is_pinned { "pin-off" } else { "pin" },
+                                    if is_pinned { "Unpin" } else { "Pin to Top" },
+                                    text_light,
+                                    hover_bg,
+                                    {
+                                        let entity = entity.clone();
+                                        let entry = selected_entry.clone();
+                                        move |_window, cx| {
+                                            if let Some(ref e) = entry {
+                                                entity.update(cx, |view, cx| {
+                                                    view.pending_context_action =
+                                                        Some(ContextMenuAction::TogglePin(
+                                                            e.path.clone(),
+                                                        ));
+                                                    view.close_context_menu();
+                                                    cx.notify();
+                                                });
+                                            }
+                                        }
+                                    },
+                                ))
                                 .child(render_context_menu_item(
                                     "star",
                                     "Add to Favorites",
@@ -794,6 +1312,32 @@ impl Render for GridViewComponent {
                                         }
                                     },
                                 ))
+                                .when(!custom_entries.is_empty(), |this| {
+                                    this.child(render_context_menu_divider(border_subtle))
+                                        .children(custom_entries.into_iter().map(
+                                            |(id, icon, label)| {
+                                                let entity = entity.clone();
+                                                let paths = tag_selection.clone();
+                                                render_context_menu_item(
+                                                    &icon,
+                                                    label,
+                                                    text_light,
+                                                    hover_bg,
+                                                    move |_window, cx| {
+                                                        entity.update(cx, |view, cx| {
+                                                            view.pending_context_action =
+                                                                Some(ContextMenuAction::Custom {
+                                                                    id,
+                                                                    paths: paths.clone(),
+                                                                });
+                                                            view.close_context_menu();
+                                                            cx.notify();
+                                                        });
+                                                    },
+                                                )
+                                            },
+                                        ))
+                                })
                                 .child(render_context_menu_divider(border_subtle))
                                 .child(render_context_menu_item(
                                     "trash-2",
@@ -823,17 +1367,9 @@ impl Render for GridViewComponent {
     }
 }
 
-fn truncate_name(name: &str, max_len: usize) -> String {
-    if name.len() <= max_len {
-        name.to_string()
-    } else {
-        format!("{}...", &name[..max_len.saturating_sub(3)])
-    }
-}
-
 fn render_context_menu_item<F>(
-    icon_name: &'static str,
-    label: &'static str,
+    icon_name: &str,
+    label: impl Into<SharedString>,
     text_color: gpui::Rgba,
     hover_bg: gpui::Rgba,
     on_click: F,
@@ -841,6 +1377,7 @@ fn render_context_menu_item<F>(
 where
     F: Fn(&mut Window, &mut App) + 'static,
 {
+    let label = label.into();
     div()
         .id(SharedString::from(format!("grid-ctx-{}", label)))
         .flex()
@@ -873,8 +1410,116 @@ fn render_context_menu_divider(color: gpui::Rgba) -> impl IntoElement {
     div().h(px(1.0)).mx_2().my_1().bg(color)
 }
 
+fn render_grid_template_submenu(
+    show_submenu: bool,
+    text_color: gpui::Rgba,
+    hover_bg: gpui::Rgba,
+    border_color: gpui::Rgba,
+    entity: gpui::Entity<GridViewComponent>,
+) -> impl IntoElement {
+    let entity_for_toggle = entity.clone();
+
+    div()
+        .id("grid-template-menu-wrapper")
+        .flex()
+        .flex_col()
+        .child(
+            div()
+                .id("grid-template-trigger")
+                .flex()
+                .items_center()
+                .justify_between()
+                .gap_3()
+                .px_3()
+                .py_1p5()
+                .mx_1()
+                .rounded_md()
+                .cursor_pointer()
+                .text_sm()
+                .text_color(text_color)
+                .hover(|s| s.bg(hover_bg))
+                .on_mouse_down(MouseButton::Left, move |_event, _window, cx| {
+                    entity_for_toggle.update(cx, |view, cx| {
+                        view.show_template_submenu = !view.show_template_submenu;
+                        cx.notify();
+                    });
+                })
+                .child(
+                    div()
+                        .flex()
+                        .items_center()
+                        .gap_3()
+                        .child(
+                            svg()
+                                .path("assets/icons/file-plus.svg")
+                                .size(px(14.0))
+                                .text_color(text_color),
+                        )
+                        .child("New from Template"),
+                )
+                .child(
+                    svg()
+                        .path(if show_submenu {
+                            "assets/icons/chevron-down.svg"
+                        } else {
+                            "assets/icons/chevron-right.svg"
+                        })
+                        .size(px(12.0))
+                        .text_color(text_color),
+                ),
+        )
+        .when(show_submenu, move |this| {
+            let mut submenu = div()
+                .id("grid-template-inline-list")
+                .flex()
+                .flex_col()
+                .pl_4()
+                .border_l_1()
+                .border_color(border_color)
+                .ml_4();
+
+            for template in load_templates(&templates_dir()) {
+                let entity = entity.clone();
+                let icon = template.icon;
+                let label = template.label.clone();
+
+                submenu = submenu.child(
+                    div()
+                        .id(SharedString::from(format!("grid-template-{}", template.file_name)))
+                        .flex()
+                        .items_center()
+                        .gap_2()
+                        .px_3()
+                        .py_1p5()
+                        .rounded_md()
+                        .cursor_pointer()
+                        .text_sm()
+                        .text_color(text_color)
+                        .hover(|s| s.bg(hover_bg))
+                        .on_mouse_down(MouseButton::Left, move |_event, _window, cx| {
+                            entity.update(cx, |view, cx| {
+                                view.pending_context_action =
+                                    Some(ContextMenuAction::NewFromTemplate(template.clone()));
+                                view.close_context_menu();
+                                cx.notify();
+                            });
+                        })
+                        .child(
+                            svg()
+                                .path(SharedString::from(format!("assets/icons/{}.svg", icon)))
+                                .size(px(14.0))
+                                .text_color(text_color),
+                        )
+                        .child(label),
+                );
+            }
+            this.child(submenu)
+        })
+}
+
 fn render_grid_open_with_submenu(
     selected_entry: Option<FileEntry>,
+    selection: Vec<PathBuf>,
     show_submenu: bool,
     text_color: gpui::Rgba,
     hover_bg: gpui::Rgba,
@@ -955,7 +1600,7 @@ fn render_grid_open_with_submenu(
                         for app in apps.iter().take(10) {
                             let app_name = app.name.clone();
                             let app_path = app.path.clone();
-                            let file_path = selected_entry.as_ref().map(|e| e.path.clone());
+                            let paths = selection.clone();
                             let entity = entity.clone();
 
                             submenu = submenu.child(
@@ -975,11 +1620,11 @@ fn render_grid_open_with_submenu(
                                         let app_name = app_name.clone();
                                         let app_path = app_path.clone();
                                         move |_event, _window, cx| {
-                                            if let Some(ref fp) = file_path {
+                                            if !paths.is_empty() {
                                                 entity.update(cx, |view, cx| {
                                                     view.pending_context_action =
                                                         Some(ContextMenuAction::OpenWithApp {
-                                                            file_path: fp.clone(),
+                                                            paths: paths.clone(),
                                                             app_path: app_path.clone(),
                                                             app_name: app_name.clone(),
                                                         });
@@ -1088,6 +1733,22 @@ mod tests {
         assert!(grid.selected_index().is_none());
     }
 
+    #[test]
+    fn test_grid_view_select_entry_named() {
+        let mut grid = GridView::new();
+        grid.set_entries(vec![
+            create_test_entry("docs", true),
+            create_test_entry("New Project", true),
+            create_test_entry("notes.txt", false),
+        ]);
+
+        assert_eq!(grid.select_entry_named("New Project"), Some(1));
+        assert_eq!(grid.selected_index(), Some(1));
+
+        assert_eq!(grid.select_entry_named("missing"), None);
+        assert_eq!(grid.selected_index(), Some(1));
+    }
+
     #[test]
     fn test_grid_view_columns_and_rows() {
         let mut grid = GridView::new();
@@ -1106,12 +1767,12 @@ mod tests {
     }
 
     #[test]
-    fn test_truncate_name() {
-        assert_eq!(truncate_name("short.txt", 14), "short.txt");
+    fn test_grid_names_truncate_in_the_middle() {
+        assert_eq!(middle_ellipsis("short.txt", NAME_MAX_CHARS), "short.txt");
         assert_eq!(
-            truncate_name("very_long_filename.txt", 14),
-            "very_long_f..."
+            middle_ellipsis("very_long_filename.txt", NAME_MAX_CHARS),
+            "very_long\u{2026}.txt"
         );
-        assert_eq!(truncate_name("a", 14), "a");
+        assert_eq!(middle_ellipsis("a", NAME_MAX_CHARS), "a");
     }
 }
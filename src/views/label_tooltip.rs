@@ -0,0 +1,146 @@
+use gpui::{
+    div, prelude::*, px, App, Context, IntoElement, ParentElement, Render, SharedString,
+    StatefulInteractiveElement, Styled, Window,
+};
+
+use crate::models::theme_colors;
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IconControl {
+    Back,
+    Forward,
+    Terminal { open: bool },
+    Copy,
+    Trash,
+    Flatten { active: bool },
+    GridView { active: bool },
+    ListView { active: bool },
+    EmptyTrash,
+    GoToParent,
+    NewFolder,
+    Refresh,
+    ToggleHidden { shown: bool },
+}
+
+impl IconControl {
+    pub fn label(&self) -> &'static str {
+        match self {
+            IconControl::Back => "Go back",
+            IconControl::Forward => "Go forward",
+            IconControl::Terminal { open: false } => "Open terminal",
+            IconControl::Terminal { open: true } => "Close terminal",
+            IconControl::Copy => "Copy selection",
+            IconControl::Trash => "Move to Trash",
+            IconControl::Flatten { active: false } => "Show all files in subfolders",
+            IconControl::Flatten { active: true } => "Exit flattened view",
+            IconControl::GridView { active: false } => "Switch to grid view",
+            IconControl::GridView { active: true } => "Grid view (current)",
+            IconControl::ListView { active: false } => "Switch to list view",
+            IconControl::ListView { active: true } => "List view (current)",
+            IconControl::EmptyTrash => "Empty Trash",
+            IconControl::GoToParent => "Go to enclosing folder",
+            IconControl::NewFolder => "New folder",
+            IconControl::Refresh => "Refresh",
+            IconControl::ToggleHidden { shown: false } => "Show hidden files",
+            IconControl::ToggleHidden { shown: true } => "Hide hidden files",
+        }
+    }
+
+    pub fn all_states() -> Vec<IconControl> {
+        let mut controls = vec![
+            IconControl::Back,
+            IconControl::Forward,
+            IconControl::Copy,
+            IconControl::Trash,
+            IconControl::EmptyTrash,
+            IconControl::GoToParent,
+            IconControl::NewFolder,
+            IconControl::Refresh,
+        ];
+        for state in [false, true] {
+            controls.push(IconControl::Terminal { open: state });
+            controls.push(IconControl::Flatten { active: state });
+            controls.push(IconControl::GridView { active: state });
+            controls.push(IconControl::ListView { active: state });
+            controls.push(IconControl::ToggleHidden { shown: state });
+        }
+        controls
+    }
+}
+
+
+pub struct LabelTooltip {
+    label: SharedString,
+}
+
+impl LabelTooltip {
+    pub fn new(label: impl Into<SharedString>) -> Self {
+        Self {
+            label: label.into(),
+        }
+    }
+}
+
+impl Render for LabelTooltip {
+    fn render(&mut self, _window: &mut Window, _cx: &mut Context<Self>) -> impl IntoElement {
+        let theme = theme_colors();
+
+        div()
+            .px_2()
+            .py_1()
+            .bg(theme.bg_tertiary)
+            .border_1()
+            .border_color(theme.border_default)
+            .rounded_md()
+            .shadow_md()
+            .text_xs()
+            .text_color(theme.text_primary)
+            .max_w(px(240.0))
+            .child(self.label.clone())
+    }
+}
+
+
+pub trait TooltipLabel: StatefulInteractiveElement + Sized {
+    fn tooltip_label(self, label: impl Into<SharedString>) -> Self {
+        let label = label.into();
+        self.tooltip(move |_window, cx: &mut App| {
+            let label = label.clone();
+            cx.new(|_| LabelTooltip::new(label)).into()
+        })
+    }
+
+    fn icon_control(self, control: IconControl) -> Self {
+        self.tooltip_label(control.label())
+    }
+}
+
+impl<E: StatefulInteractiveElement> TooltipLabel for E {}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_icon_controls_expose_non_empty_names() {
+        for control in IconControl::all_states() {
+            assert!(
+                !control.label().trim().is_empty(),
+                "{:?} has no label",
+                control
+            );
+        }
+    }
+
+    #[test]
+    fn test_toggle_labels_follow_state() {
+        assert_eq!(IconControl::Terminal { open: false }.label(), "Open terminal");
+        assert_eq!(IconControl::Terminal { open: true }.label(), "Close terminal");
+        assert_ne!(
+            IconControl::Flatten { active: false }.label(),
+            IconControl::Flatten { active: true }.label()
+        );
+    }
+}
@@ -1,12 +1,17 @@
 mod batch_rename_dialog;
 mod checksum_dialog;
+mod checksum_verify_panel;
 mod column_view;
+mod command_palette;
 mod conflict_dialog;
 mod dual_pane;
+mod duplicate_finder_panel;
 mod file_list;
+mod focus_trap;
 mod format_dialog;
 mod go_to_folder;
 mod grid_view;
+mod label_tooltip;
 mod network_dialog;
 mod permissions_dialog;
 mod preview;
@@ -19,6 +24,7 @@ mod smart_folder_dialog;
 mod status_bar;
 mod symlink_dialog;
 mod tab_bar;
+mod tag_dialog;
 mod tag_ui;
 mod terminal;
 mod theme_picker;
@@ -39,14 +45,17 @@ pub use file_list::{
     format_date, format_size, get_file_icon, get_file_icon_color, ContextMenuAction, FileList,
     FileListView, RenderedEntry, VisibleRange, DEFAULT_BUFFER_SIZE, DEFAULT_ROW_HEIGHT,
 };
+pub use command_palette::{CommandPalette, CommandPaletteView, PaletteCommand};
+pub use focus_trap::{cycle_focus_index, focus_ring, restore_focus, FocusTrap, TrapKey};
 pub use go_to_folder::GoToFolderView;
 pub use grid_view::{GridView, GridViewComponent};
+pub use label_tooltip::{IconControl, LabelTooltip, TooltipLabel};
 pub use network_dialog::{NetworkConnectionDialog, NetworkDialogAction};
 pub use preview::{
     calculate_directory_stats, format_date as preview_format_date, format_hex_dump,
     format_size as preview_format_size, FileMetadata, Preview, PreviewContent, PreviewView,
 };
-pub use progress_panel::{ProgressPanelAction, ProgressPanelView};
+pub use progress_panel::{transfer_summary, ProgressPanelAction, ProgressPanelView};
 pub use quick_look::{
     CloseQuickLook, QuickLook, QuickLookContent, QuickLookNext, QuickLookPrevious, QuickLookView,
     ToggleQuickLook,
@@ -56,19 +65,23 @@ pub use sidebar::{Sidebar, SidebarItem, SidebarView, ToolAction};
 pub use smart_folder_dialog::{QueryBuilderState, SmartFolderDialog, SmartFolderDialogAction};
 pub use status_bar::{
     detect_git_branch, format_size as status_bar_format_size, StatusBarAction, StatusBarState,
-    StatusBarView,
+    StatusBarView, UnreadableItem,
 };
 pub use tab_bar::TabBarView;
+pub use tag_dialog::{BulkTagDialog, BulkTagDialogAction};
 pub use tag_ui::{
     parse_tag_query, render_file_tag_dots, render_tag_context_menu, render_tag_dot,
-    render_tag_dots, render_tag_filter_item,
+    render_tag_dot_buttons, render_tag_dots, render_tag_filter_item, tag_dot_layout,
+    tag_filter_query, tags_for_entries, TagSelectHandler, MAX_VISIBLE_TAG_DOTS,
 };
 pub use terminal::TerminalView;
 pub use theme_picker::{ThemePicker, ThemePickerView};
 pub use toast::{Toast, ToastManager, ToastVariant};
 pub use batch_rename_dialog::{BatchRenameDialog, BatchRenameDialogAction, RenameMode};
 pub use checksum_dialog::{ChecksumDialog, ChecksumDialogAction};
+pub use checksum_verify_panel::{ChecksumVerifyAction, ChecksumVerifyPanel};
 pub use conflict_dialog::{ConflictDialog, ConflictDialogAction, ConflictInfo};
+pub use duplicate_finder_panel::{DuplicateFinderAction, DuplicateFinderPanel};
 pub use format_dialog::{FormatDialog, FormatDialogAction};
 pub use permissions_dialog::{PermissionsDialog, PermissionsDialogAction, PermissionsDialogView};
 pub use share_dialog::{ShareDialog, ShareDialogAction};
@@ -18,6 +18,8 @@ pub enum NetworkDialogAction {
 
 
 pub struct NetworkConnectionDialog {
+    url: String,
+    url_error: Option<String>,
     protocol: NetworkProtocol,
     host: String,
     port: String,
@@ -35,6 +37,8 @@ pub struct NetworkConnectionDialog {
 impl NetworkConnectionDialog {
     pub fn new() -> Self {
         Self {
+            url: String::new(),
+            url_error: None,
             protocol: NetworkProtocol::Smb,
             host: String::new(),
             port: String::new(),
@@ -142,6 +146,47 @@ impl NetworkConnectionDialog {
     }
 
 
+    pub fn set_url(&mut self, url: String) {
+        self.url = url;
+        self.error_message = None;
+
+        if self.url.trim().is_empty() {
+            self.url_error = None;
+            return;
+        }
+
+        match NetworkConnectionConfig::parse_url(&self.url) {
+            Ok(config) => {
+                self.url_error = None;
+                self.apply_config(config);
+            }
+            Err(e) => self.url_error = Some(e.to_string()),
+        }
+    }
+
+
+    fn apply_config(&mut self, config: NetworkConnectionConfig) {
+        self.protocol = config.protocol;
+        self.host = config.host;
+        self.port = config.port.map(|p| p.to_string()).unwrap_or_default();
+        self.path = config.path;
+        match config.auth {
+            AuthMethod::Anonymous => self.use_anonymous = true,
+            AuthMethod::Password { username, password } => {
+                self.use_anonymous = false;
+                self.username = username;
+                if !password.is_empty() {
+                    self.password = password;
+                }
+            }
+            AuthMethod::KeyFile { username, .. } => {
+                self.use_anonymous = false;
+                self.username = username;
+            }
+        }
+    }
+
+
     pub fn set_protocol(&mut self, protocol: NetworkProtocol) {
         self.protocol = protocol;
         if self.port.is_empty() {
@@ -188,6 +233,16 @@ impl NetworkConnectionDialog {
     }
 
 
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+
+    pub fn url_error(&self) -> Option<&str> {
+        self.url_error.as_deref()
+    }
+
+
     pub fn protocol(&self) -> NetworkProtocol {
         self.protocol
     }
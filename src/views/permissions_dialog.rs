@@ -1,6 +1,6 @@
 use crate::models::{
-    AclEntryType, FilePermissions, PermissionError, PermissionsManager,
-    UnixPermissions, WindowsAcl,
+    AclEntryType, BitState, BulkItemResult, CommonAttributes, CommonMode, FilePermissions,
+    PermissionError, PermissionsManager, UnixPermissions, WindowsAcl,
 };
 use gpui::prelude::FluentBuilder;
 use gpui::*;
@@ -24,11 +24,80 @@ pub enum PermissionsDialogAction {
     SetuidChanged(bool),
     SetgidChanged(bool),
     StickyChanged(bool),
+    ReadOnlyChanged(bool),
+    HiddenChanged(bool),
+    RecursiveChanged(bool),
+}
+
+impl PermissionsDialogAction {
+    fn mode_bit(&self) -> Option<(u32, bool)> {
+        match *self {
+            Self::OwnerReadChanged(v) => Some((0o400, v)),
+            Self::OwnerWriteChanged(v) => Some((0o200, v)),
+            Self::OwnerExecuteChanged(v) => Some((0o100, v)),
+            Self::GroupReadChanged(v) => Some((0o040, v)),
+            Self::GroupWriteChanged(v) => Some((0o020, v)),
+            Self::GroupExecuteChanged(v) => Some((0o010, v)),
+            Self::OthersReadChanged(v) => Some((0o004, v)),
+            Self::OthersWriteChanged(v) => Some((0o002, v)),
+            Self::OthersExecuteChanged(v) => Some((0o001, v)),
+            Self::SetuidChanged(v) => Some((0o4000, v)),
+            Self::SetgidChanged(v) => Some((0o2000, v)),
+            Self::StickyChanged(v) => Some((0o1000, v)),
+            _ => None,
+        }
+    }
+}
+
+
+pub(crate) struct SelectionApply {
+    paths: Vec<PathBuf>,
+    #[cfg(unix)]
+    mode: Option<CommonMode>,
+    #[cfg(unix)]
+    recursive: bool,
+    #[cfg(windows)]
+    attributes: Option<CommonAttributes>,
+}
+
+impl SelectionApply {
+    pub(crate) fn run(self) -> Vec<BulkItemResult> {
+        #[cfg(unix)]
+        let results = match &self.mode {
+            Some(mode) => {
+                PermissionsManager::apply_mode_to_selection(&self.paths, mode, self.recursive)
+            }
+            None => Vec::new(),
+        };
+        #[cfg(windows)]
+        let results = match &self.attributes {
+            Some(attributes) => {
+                let requested = |state: BitState| (!state.is_varies()).then_some(state.is_set());
+                PermissionsManager::apply_attributes_to_selection(
+                    &self.paths,
+                    requested(attributes.read_only),
+                    requested(attributes.hidden),
+                )
+            }
+            None => Vec::new(),
+        };
+        #[cfg(not(any(unix, windows)))]
+        let results: Vec<BulkItemResult> = Vec::new();
+
+        results
+    }
 }
 
 
 pub struct PermissionsDialog {
     path: PathBuf,
+    selection: Vec<PathBuf>,
+    original_mode: Option<CommonMode>,
+    common_mode: Option<CommonMode>,
+    original_attributes: Option<CommonAttributes>,
+    common_attributes: Option<CommonAttributes>,
+    apply_recursively: bool,
+    item_results: Vec<BulkItemResult>,
     original_permissions: Option<FilePermissions>,
     modified_permissions: Option<FilePermissions>,
     error_message: Option<String>,
@@ -48,7 +117,14 @@ impl PermissionsDialog {
         let modified_permissions = original_permissions.clone();
 
         Self {
+            selection: vec![path.clone()],
             path,
+            original_mode: None,
+            common_mode: None,
+            original_attributes: None,
+            common_attributes: None,
+            apply_recursively: false,
+            item_results: Vec::new(),
             original_permissions,
             modified_permissions,
             error_message: None,
@@ -61,6 +137,47 @@ impl PermissionsDialog {
         }
     }
 
+
+    pub fn for_selection(paths: Vec<PathBuf>) -> Self {
+        if paths.len() == 1 {
+            return Self::new(paths[0].clone());
+        }
+
+        let mut dialog = Self::new(paths.first().cloned().unwrap_or_default());
+        dialog.original_permissions = None;
+        dialog.modified_permissions = None;
+        dialog.is_directory = paths.iter().any(|path| path.is_dir());
+        dialog.requires_elevation = paths
+            .iter()
+            .any(|path| PermissionsManager::requires_elevation(path));
+
+        let modes: Vec<u32> = paths
+            .iter()
+            .filter_map(|path| match PermissionsManager::read_permissions(path) {
+                Ok(FilePermissions::Unix(perms)) => Some(perms.to_mode()),
+                _ => None,
+            })
+            .collect();
+        if modes.len() == paths.len() {
+            dialog.original_mode = CommonMode::from_modes(&modes);
+        }
+
+        if cfg!(windows) {
+            let flags: Vec<_> = paths
+                .iter()
+                .filter_map(|path| PermissionsManager::read_attributes(path).ok())
+                .collect();
+            if flags.len() == paths.len() {
+                dialog.original_attributes = CommonAttributes::from_flags(&flags);
+            }
+        }
+
+        dialog.common_mode = dialog.original_mode;
+        dialog.common_attributes = dialog.original_attributes;
+        dialog.selection = paths;
+        dialog
+    }
+
     pub fn with_on_close<F>(mut self, callback: F) -> Self
     where
         F: Fn() + Send + Sync + 'static,
@@ -81,6 +198,30 @@ impl PermissionsDialog {
         &self.path
     }
 
+    pub fn selection(&self) -> &[PathBuf] {
+        &self.selection
+    }
+
+    pub fn is_bulk(&self) -> bool {
+        self.selection.len() > 1
+    }
+
+    pub fn common_mode(&self) -> Option<&CommonMode> {
+        self.common_mode.as_ref()
+    }
+
+    pub fn common_attributes(&self) -> Option<&CommonAttributes> {
+        self.common_attributes.as_ref()
+    }
+
+    pub fn applies_recursively(&self) -> bool {
+        self.apply_recursively
+    }
+
+    pub fn item_results(&self) -> &[BulkItemResult] {
+        &self.item_results
+    }
+
     pub fn is_directory(&self) -> bool {
         self.is_directory
     }
@@ -108,6 +249,10 @@ impl PermissionsDialog {
 
 
     pub fn has_changes(&self) -> bool {
+        if self.is_bulk() {
+            return self.common_mode != self.original_mode
+                || self.common_attributes != self.original_attributes;
+        }
         match (&self.original_permissions, &self.modified_permissions) {
             (Some(FilePermissions::Unix(orig)), Some(FilePermissions::Unix(modified))) => {
                 orig.to_mode() != modified.to_mode()
@@ -118,6 +263,9 @@ impl PermissionsDialog {
 
 
     pub fn apply(&mut self) -> Result<(), PermissionError> {
+        if self.is_bulk() {
+            return self.apply_to_selection();
+        }
         if let Some(perms) = &self.modified_permissions {
             self.is_applying = true;
             self.error_message = None;
@@ -189,6 +337,56 @@ impl PermissionsDialog {
     }
 
 
+    fn apply_to_selection(&mut self) -> Result<(), PermissionError> {
+        let job = self.begin_apply_to_selection();
+        self.finish_apply_to_selection(job.run())
+    }
+
+
+    pub(crate) fn begin_apply_to_selection(&mut self) -> SelectionApply {
+        self.is_applying = true;
+        self.error_message = None;
+        self.success_message = None;
+        SelectionApply {
+            paths: self.selection.clone(),
+            #[cfg(unix)]
+            mode: self.common_mode,
+            #[cfg(unix)]
+            recursive: self.apply_recursively,
+            #[cfg(windows)]
+            attributes: self.common_attributes,
+        }
+    }
+
+
+    pub(crate) fn finish_apply_to_selection(
+        &mut self,
+        results: Vec<BulkItemResult>,
+    ) -> Result<(), PermissionError> {
+        self.is_applying = false;
+        if results.is_empty() {
+            return Err(PermissionError::PlatformNotSupported(
+                "No permissions to apply".to_string(),
+            ));
+        }
+
+        let failed = results.iter().filter(|item| item.result.is_err()).count();
+        if failed == 0 {
+            self.original_mode = self.common_mode;
+            self.original_attributes = self.common_attributes;
+            self.success_message = Some(format!("Applied to {} items", results.len()));
+        } else {
+            self.error_message = Some(format!(
+                "Failed on {} of {} items",
+                failed,
+                results.len()
+            ));
+        }
+        self.item_results = results;
+        Ok(())
+    }
+
+
     pub fn close(&self) {
         if let Some(callback) = &self.on_close {
             callback();
@@ -207,6 +405,12 @@ impl PermissionsDialog {
 
 
     pub fn handle_action(&mut self, action: PermissionsDialogAction) {
+        if self.is_bulk() {
+            if let (Some((bit, value)), Some(mode)) = (action.mode_bit(), self.common_mode.as_mut()) {
+                mode.set_bit(bit, value);
+                return;
+            }
+        }
         match action {
             PermissionsDialogAction::Close => self.close(),
             PermissionsDialogAction::Apply => {
@@ -254,6 +458,19 @@ impl PermissionsDialog {
             PermissionsDialogAction::StickyChanged(v) => {
                 self.update_unix_bit(|p| p.special.sticky = v);
             }
+            PermissionsDialogAction::ReadOnlyChanged(v) => {
+                if let Some(attributes) = self.common_attributes.as_mut() {
+                    attributes.read_only = if v { BitState::Set } else { BitState::Unset };
+                }
+            }
+            PermissionsDialogAction::HiddenChanged(v) => {
+                if let Some(attributes) = self.common_attributes.as_mut() {
+                    attributes.hidden = if v { BitState::Set } else { BitState::Unset };
+                }
+            }
+            PermissionsDialogAction::RecursiveChanged(v) => {
+                self.apply_recursively = v;
+            }
         }
     }
 
@@ -293,10 +510,30 @@ impl PermissionsDialog {
         checked: bool,
         on_change: impl Fn(bool) -> PermissionsDialogAction + 'static,
     ) -> impl IntoElement {
-        let checkbox_bg = if checked {
-            rgb(0x3B82F6)
+        let state = if checked { BitState::Set } else { BitState::Unset };
+        Self::render_state_checkbox(label, state, on_change)
+    }
+
+
+    fn render_state_checkbox(
+        label: &str,
+        state: BitState,
+        _on_change: impl Fn(bool) -> PermissionsDialogAction + 'static,
+    ) -> impl IntoElement {
+        let checkbox_bg = match state {
+            BitState::Set => rgb(0x3B82F6),
+            BitState::Varies => rgb(0x1E3A8A),
+            BitState::Unset => rgb(0x374151),
+        };
+        let mark = match state {
+            BitState::Set => Some("✓"),
+            BitState::Varies => Some("–"),
+            BitState::Unset => None,
+        };
+        let label = if state.is_varies() {
+            format!("{} (varies)", label)
         } else {
-            rgb(0x374151)
+            label.to_string()
         };
 
         div()
@@ -315,12 +552,12 @@ impl PermissionsDialog {
                     .flex()
                     .items_center()
                     .justify_center()
-                    .when(checked, |el| {
+                    .when_some(mark, |el, mark| {
                         el.child(
                             div()
                                 .text_color(rgb(0xFFFFFF))
                                 .text_size(px(12.0))
-                                .child("✓"),
+                                .child(mark),
                         )
                     }),
             )
@@ -328,12 +565,162 @@ impl PermissionsDialog {
                 div()
                     .text_color(rgb(0xE5E7EB))
                     .text_size(px(13.0))
-                    .child(label.to_string()),
+                    .child(label),
+            )
+    }
+
+
+    fn render_mode_row(
+        title: &str,
+        mode: &CommonMode,
+        bits: [(&str, u32, fn(bool) -> PermissionsDialogAction); 3],
+    ) -> impl IntoElement {
+        div()
+            .flex()
+            .flex_col()
+            .gap_2()
+            .child(
+                div()
+                    .text_color(rgb(0x9CA3AF))
+                    .text_size(px(12.0))
+                    .font_weight(FontWeight::SEMIBOLD)
+                    .child(title.to_string()),
+            )
+            .child(
+                div()
+                    .flex()
+                    .gap_4()
+                    .children(bits.into_iter().map(|(label, bit, action)| {
+                        Self::render_state_checkbox(label, mode.state(bit), action)
+                    })),
             )
     }
 
 
-    fn render_unix_permissions(&self, perms: &UnixPermissions) -> impl IntoElement {
+    pub(crate) fn render_common_mode(&self, mode: &CommonMode) -> impl IntoElement {
+        use PermissionsDialogAction as Action;
+
+        div()
+            .flex()
+            .flex_col()
+            .gap_4()
+            .p_4()
+            .child(Self::render_mode_row(
+                "Owner",
+                mode,
+                [
+                    ("Read", 0o400, Action::OwnerReadChanged),
+                    ("Write", 0o200, Action::OwnerWriteChanged),
+                    ("Execute", 0o100, Action::OwnerExecuteChanged),
+                ],
+            ))
+            .child(Self::render_mode_row(
+                "Group",
+                mode,
+                [
+                    ("Read", 0o040, Action::GroupReadChanged),
+                    ("Write", 0o020, Action::GroupWriteChanged),
+                    ("Execute", 0o010, Action::GroupExecuteChanged),
+                ],
+            ))
+            .child(Self::render_mode_row(
+                "Others",
+                mode,
+                [
+                    ("Read", 0o004, Action::OthersReadChanged),
+                    ("Write", 0o002, Action::OthersWriteChanged),
+                    ("Execute", 0o001, Action::OthersExecuteChanged),
+                ],
+            ))
+            .child(Self::render_mode_row(
+                "Special Permissions",
+                mode,
+                [
+                    ("Set User ID", 0o4000, Action::SetuidChanged),
+                    ("Set Group ID", 0o2000, Action::SetgidChanged),
+                    ("Sticky Bit", 0o1000, Action::StickyChanged),
+                ],
+            ))
+            .child(
+                div()
+                    .flex()
+                    .gap_2()
+                    .mt_2()
+                    .pt_2()
+                    .border_t_1()
+                    .border_color(rgb(0x374151))
+                    .child(
+                        div()
+                            .text_color(rgb(0x9CA3AF))
+                            .text_size(px(12.0))
+                            .child("Octal:"),
+                    )
+                    .child(
+                        div()
+                            .text_color(rgb(0xE5E7EB))
+                            .text_size(px(12.0))
+                            .font_family("monospace")
+                            .child(mode.to_octal_string()),
+                    ),
+            )
+            .when(self.is_directory, |el| {
+                el.child(Self::render_permission_checkbox(
+                    "Apply to folder contents",
+                    self.apply_recursively,
+                    PermissionsDialogAction::RecursiveChanged,
+                ))
+            })
+    }
+
+
+    pub(crate) fn render_common_attributes(&self, attributes: &CommonAttributes) -> impl IntoElement {
+        div()
+            .flex()
+            .gap_4()
+            .p_4()
+            .child(Self::render_state_checkbox(
+                "Read-only",
+                attributes.read_only,
+                PermissionsDialogAction::ReadOnlyChanged,
+            ))
+            .child(Self::render_state_checkbox(
+                "Hidden",
+                attributes.hidden,
+                PermissionsDialogAction::HiddenChanged,
+            ))
+    }
+
+
+    pub(crate) fn render_item_results(&self) -> impl IntoElement {
+        div()
+            .flex()
+            .flex_col()
+            .gap_1()
+            .px_4()
+            .py_2()
+            .children(self.item_results.iter().map(|item| {
+                let name = item
+                    .path
+                    .file_name()
+                    .map(|n| n.to_string_lossy().to_string())
+                    .unwrap_or_else(|| item.path.display().to_string());
+                let (status, color) = match &item.result {
+                    Ok(()) => ("Updated".to_string(), rgb(0x6EE7B7)),
+                    Err(error) => (error.clone(), rgb(0xFCA5A5)),
+                };
+
+                div()
+                    .flex()
+                    .justify_between()
+                    .gap_2()
+                    .text_size(px(11.0))
+                    .child(div().text_color(rgb(0xE5E7EB)).truncate().child(name))
+                    .child(div().text_color(color).child(status))
+            }))
+    }
+
+
+    pub(crate) fn render_unix_permissions(&self, perms: &UnixPermissions) -> impl IntoElement {
         div()
             .flex()
             .flex_col()
@@ -539,7 +926,7 @@ impl PermissionsDialog {
     }
 
 
-    fn render_windows_acl(&self, acl: &WindowsAcl) -> impl IntoElement {
+    pub(crate) fn render_windows_acl(&self, acl: &WindowsAcl) -> impl IntoElement {
         div()
             .flex()
             .flex_col()
@@ -656,6 +1043,13 @@ impl PermissionsDialogView {
         }
     }
 
+    pub fn for_selection(paths: Vec<PathBuf>, cx: &mut Context<Self>) -> Self {
+        Self {
+            dialog: PermissionsDialog::for_selection(paths),
+            focus_handle: cx.focus_handle(),
+        }
+    }
+
     pub fn dialog(&self) -> &PermissionsDialog {
         &self.dialog
     }
@@ -663,6 +1057,28 @@ impl PermissionsDialogView {
     pub fn dialog_mut(&mut self) -> &mut PermissionsDialog {
         &mut self.dialog
     }
+
+    pub fn apply(&mut self, cx: &mut Context<Self>) {
+        if self.dialog.is_applying() {
+            return;
+        }
+        if !self.dialog.is_bulk() {
+            let _ = self.dialog.apply();
+            cx.notify();
+            return;
+        }
+
+        let job = self.dialog.begin_apply_to_selection();
+        cx.notify();
+        cx.spawn(async move |this, cx| {
+            let results = cx.background_executor().spawn(async move { job.run() }).await;
+            let _ = this.update(cx, |view, cx| {
+                let _ = view.dialog.finish_apply_to_selection(results);
+                cx.notify();
+            });
+        })
+        .detach();
+    }
 }
 
 impl Focusable for PermissionsDialogView {
@@ -672,14 +1088,20 @@ impl Focusable for PermissionsDialogView {
 }
 
 impl Render for PermissionsDialogView {
-    fn render(&mut self, _window: &mut Window, _cx: &mut Context<Self>) -> impl IntoElement {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
         let path_display = self.dialog.path().display().to_string();
-        let file_name = self
-            .dialog
-            .path()
-            .file_name()
-            .map(|n| n.to_string_lossy().to_string())
-            .unwrap_or_else(|| path_display.clone());
+        let file_name = if self.dialog.is_bulk() {
+            format!("{} items", self.dialog.selection().len())
+        } else {
+            self.dialog
+                .path()
+                .file_name()
+                .map(|n| n.to_string_lossy().to_string())
+                .unwrap_or_else(|| path_display.clone())
+        };
+        let is_bulk = self.dialog.is_bulk();
+        let has_bulk_state =
+            self.dialog.common_mode().is_some() || self.dialog.common_attributes().is_some();
 
         div()
             .flex()
@@ -729,7 +1151,25 @@ impl Render for PermissionsDialogView {
                     .when_some(self.dialog.windows_acl(), |el, acl| {
                         el.child(self.dialog.render_windows_acl(acl))
                     })
-                    .when(self.dialog.current_permissions().is_none(), |el| {
+                    .when_some(self.dialog.common_mode(), |el, mode| {
+                        el.child(self.dialog.render_common_mode(mode))
+                    })
+                    .when_some(self.dialog.common_attributes(), |el, attributes| {
+                        el.child(self.dialog.render_common_attributes(attributes))
+                    })
+                    .when(!self.dialog.item_results().is_empty(), |el| {
+                        el.child(self.dialog.render_item_results())
+                    })
+                    .when(is_bulk && !has_bulk_state, |el| {
+                        el.child(
+                            div()
+                                .p_4()
+                                .text_color(rgb(0xEF4444))
+                                .text_size(px(13.0))
+                                .child("Unable to read permissions for every selected item."),
+                        )
+                    })
+                    .when(!is_bulk && self.dialog.current_permissions().is_none(), |el| {
                         el.child(
                             div()
                                 .p_4()
@@ -782,7 +1222,7 @@ impl Render for PermissionsDialogView {
                     .py_3()
                     .border_t_1()
                     .border_color(rgb(0x374151))
-                    .when(self.dialog.is_directory(), |el| {
+                    .when(!is_bulk && self.dialog.is_directory(), |el| {
                         el.child(
                             div()
                                 .px_3()
@@ -818,10 +1258,14 @@ impl Render for PermissionsDialogView {
                             .text_size(px(13.0))
                             .cursor_pointer()
                             .hover(|s| s.bg(rgb(0x2563EB)))
-                            .when(!self.dialog.has_changes(), |el| {
+                            .when(!self.dialog.has_changes() || self.dialog.is_applying(), |el| {
                                 el.opacity(0.5).cursor_default()
                             })
-                            .child("Apply"),
+                            .on_mouse_down(
+                                MouseButton::Left,
+                                cx.listener(|view, _, _window, cx| view.apply(cx)),
+                            )
+                            .child(if self.dialog.is_applying() { "Applying…" } else { "Apply" }),
                     ),
             )
     }
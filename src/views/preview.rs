@@ -1,12 +1,25 @@
 use gpui::{
-    div, prelude::*, px, svg, App, Context, FocusHandle, Focusable, InteractiveElement,
-    IntoElement, ParentElement, Render, Styled, Window,
+    actions, div, img, prelude::*, px, svg, App, Context, FocusHandle, Focusable,
+    InteractiveElement, IntoElement, KeyBinding, MouseButton, ParentElement, Render, SharedString,
+    Styled, Window,
 };
 use std::fs;
+use std::io::{self, Read};
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
 use std::time::SystemTime;
 
-use crate::models::theme_colors;
+pub use crate::models::calculate_directory_stats;
+use crate::models::{
+    association_key, calculate_file_hash, convert_line_endings, decode_text, detect_encoding,
+    get_apps_for_file, has_bom, is_registry_loaded, is_svg, preview_limits,
+    progressive_image_keys, summarize_directory, supports_thumbnail, theme_colors,
+    AppAssociations, AppInfo, DirectorySummary, GlobalSettings, HashAlgorithm, LineEnding,
+    PreviewContentType, PreviewDecision, PreviewKey, PreviewKind, PreviewPriority, PreviewResult,
+    PreviewSelection, PreviewService, PreviewTab, TextEncoding, TextFormat, SVG_PREVIEW_SIZE,
+};
+use crate::views::PermissionsDialog;
 
 
 #[derive(Debug, Clone, PartialEq)]
@@ -15,12 +28,22 @@ pub enum PreviewContent {
         content: String,
         language: Option<String>,
         line_count: usize,
+        format: TextFormat,
     },
     Image {
         path: PathBuf,
         dimensions: Option<(u32, u32)>,
         format: String,
     },
+    Document {
+        path: PathBuf,
+        format: String,
+    },
+    Svg {
+        path: PathBuf,
+        source: String,
+        show_source: bool,
+    },
     HexDump {
         bytes: Vec<u8>,
         total_size: u64,
@@ -31,6 +54,12 @@ pub enum PreviewContent {
         subdir_count: usize,
         file_count: usize,
     },
+    TooLarge {
+        path: PathBuf,
+        content_type: PreviewContentType,
+        size: u64,
+        limit: u64,
+    },
     Error {
         message: String,
     },
@@ -137,6 +166,8 @@ pub struct Preview {
     metadata: Option<FileMetadata>,
     current_path: Option<PathBuf>,
     scroll_offset: f32,
+    directory_summary: Option<DirectorySummary>,
+    active_tab: PreviewTab,
 }
 
 impl Default for Preview {
@@ -152,6 +183,8 @@ impl Preview {
             metadata: None,
             current_path: None,
             scroll_offset: 0.0,
+            directory_summary: None,
+            active_tab: PreviewTab::default(),
         }
     }
 
@@ -167,42 +200,86 @@ impl Preview {
         self.current_path.as_ref()
     }
 
+    pub fn directory_summary(&self) -> Option<&DirectorySummary> {
+        self.directory_summary.as_ref()
+    }
+
+    pub fn set_directory_summary(&mut self, path: &Path, summary: DirectorySummary) -> bool {
+        if self.current_path.as_deref() != Some(path) {
+            return false;
+        }
+        self.directory_summary = Some(summary);
+        true
+    }
+
+    pub fn active_tab(&self) -> PreviewTab {
+        self.active_tab
+    }
+
+    pub fn set_active_tab(&mut self, tab: PreviewTab) {
+        self.active_tab = tab;
+    }
+
+    pub fn toggle_svg_source(&mut self) -> bool {
+        match &mut self.content {
+            PreviewContent::Svg { show_source, .. } => {
+                *show_source = !*show_source;
+                true
+            }
+            _ => false,
+        }
+    }
+
     pub fn clear(&mut self) {
         self.content = PreviewContent::None;
         self.metadata = None;
         self.current_path = None;
         self.scroll_offset = 0.0;
+        self.directory_summary = None;
     }
 
     pub fn load_file(&mut self, path: &Path) {
+        if self.begin_load(path) {
+            self.set_directory_stats(path, calculate_directory_stats(path));
+        }
+    }
+
+    pub fn begin_load(&mut self, path: &Path) -> bool {
         self.current_path = Some(path.to_path_buf());
         self.scroll_offset = 0.0;
+        self.directory_summary = None;
 
         self.metadata = FileMetadata::from_path(path);
 
         if path.is_dir() {
-            self.load_directory_content(path);
+            self.content = PreviewContent::Loading;
+            true
         } else {
             self.load_file_content(path);
+            false
         }
     }
 
-    fn load_directory_content(&mut self, path: &Path) {
-        match calculate_directory_stats(path) {
-            Ok((item_count, total_size, subdir_count, file_count)) => {
-                self.content = PreviewContent::Directory {
-                    item_count,
-                    total_size,
-                    subdir_count,
-                    file_count,
-                };
-            }
-            Err(e) => {
-                self.content = PreviewContent::Error {
-                    message: e.to_string(),
-                };
-            }
+    pub fn set_directory_stats(
+        &mut self,
+        path: &Path,
+        stats: io::Result<(usize, u64, usize, usize)>,
+    ) -> bool {
+        if self.current_path.as_deref() != Some(path) {
+            return false;
         }
+        self.content = match stats {
+            Ok((item_count, total_size, subdir_count, file_count)) => PreviewContent::Directory {
+                item_count,
+                total_size,
+                subdir_count,
+                file_count,
+            },
+            Err(e) => PreviewContent::Error {
+                message: e.to_string(),
+            },
+        };
+        true
     }
 
     fn load_file_content(&mut self, path: &Path) {
@@ -211,44 +288,113 @@ impl Preview {
             .and_then(|ext| ext.to_str())
             .map(|s| s.to_lowercase());
 
-        if is_image_extension(extension.as_deref()) {
-            self.load_image_content(path, extension.as_deref());
-            return;
-        }
+        let content_type = if is_image_extension(extension.as_deref()) {
+            Some(PreviewContentType::Image)
+        } else if extension.as_deref() == Some("pdf") {
+            Some(PreviewContentType::Pdf)
+        } else if is_text_extension(extension.as_deref()) || is_likely_text_file(path) {
+            Some(PreviewContentType::Text)
+        } else {
+            None
+        };
 
-        if is_text_extension(extension.as_deref()) || is_likely_text_file(path) {
-            self.load_text_content(path, extension);
-            return;
+        if let Some(content_type) = content_type {
+            let size = fs::metadata(path).map(|m| m.len()).unwrap_or(0);
+            if let PreviewDecision::TooLarge {
+                content_type,
+                size,
+                limit,
+            } = preview_limits().check(content_type, size)
+            {
+                self.content = PreviewContent::TooLarge {
+                    path: path.to_path_buf(),
+                    content_type,
+                    size,
+                    limit,
+                };
+                return;
+            }
         }
 
-        self.load_hex_dump(path);
+        match content_type {
+            Some(PreviewContentType::Image) if is_svg(path) => self.load_svg_content(path),
+            Some(PreviewContentType::Image) => {
+                self.load_image_content(path, extension.as_deref());
+            }
+            Some(PreviewContentType::Pdf) => {
+                self.content = PreviewContent::Document {
+                    path: path.to_path_buf(),
+                    format: "PDF".to_string(),
+                };
+            }
+            Some(PreviewContentType::Text) => self.load_text_content(path, extension),
+            None => self.load_hex_dump(path),
+        }
     }
 
-    fn load_text_content(&mut self, path: &Path, extension: Option<String>) {
-        match fs::read_to_string(path) {
-            Ok(content) => {
-                let line_count = content.lines().count();
-                let language = extension.and_then(|ext| detect_language(&ext));
-
-                let preview_content = if content.len() > 50000 {
-                    format!(
-                        "{}...\n\n[Content truncated - file too large]",
-                        &content[..50000]
-                    )
-                } else {
-                    content
-                };
 
-                self.content = PreviewContent::Text {
-                    content: preview_content,
-                    language,
-                    line_count,
-                };
-            }
-            Err(_) => {
-                self.load_hex_dump(path);
+    pub fn rendered_preview_keys(&self) -> Vec<PreviewKey> {
+        let modified = self.metadata.as_ref().and_then(|m| m.modified);
+        match &self.content {
+            PreviewContent::Image { path, .. } if supports_thumbnail(path) => {
+                progressive_image_keys(path, modified)
             }
+            PreviewContent::Document { path, .. } => vec![PreviewKey::new(
+                path.clone(),
+                modified,
+                PreviewKind::PdfPage(1),
+            )],
+            PreviewContent::Svg {
+                path,
+                show_source: false,
+                ..
+            } => vec![PreviewKey::new(
+                path.clone(),
+                modified,
+                PreviewKind::Svg(SVG_PREVIEW_SIZE),
+            )],
+            _ => Vec::new(),
+        }
+    }
+
+    fn load_text_content(&mut self, path: &Path, extension: Option<String>) {
+        let Ok(bytes) = fs::read(path) else {
+            self.load_hex_dump(path);
+            return;
+        };
+        let encoding = detect_encoding(&bytes);
+        if encoding == TextEncoding::Latin1 && !is_text_extension(extension.as_deref()) {
+            self.load_hex_dump(path);
+            return;
         }
+        let Some(content) = decode_text(&bytes, encoding) else {
+            self.load_hex_dump(path);
+            return;
+        };
+
+        let line_count = content.lines().count();
+        let language = extension.and_then(|ext| detect_language(&ext));
+        let format = TextFormat::of(&content, encoding);
+
+        let preview_content = if content.len() > 50000 {
+            let end = (0..=50000)
+                .rev()
+                .find(|&i| content.is_char_boundary(i))
+                .unwrap_or(0);
+            format!(
+                "{}...\n\n[Content truncated - file too large]",
+                &content[..end]
+            )
+        } else {
+            content
+        };
+
+        self.content = PreviewContent::Text {
+            content: preview_content,
+            language,
+            line_count,
+            format,
+        };
     }
 
     fn load_image_content(&mut self, path: &Path, extension: Option<&str>) {
@@ -265,13 +411,14 @@ impl Preview {
         };
     }
 
-    fn load_hex_dump(&mut self, path: &Path) {
-        let total_size = fs::metadata(path).map(|m| m.len()).unwrap_or(0);
-
+    fn load_svg_content(&mut self, path: &Path) {
         match fs::read(path) {
-            Ok(data) => {
-                let bytes: Vec<u8> = data.into_iter().take(256).collect();
-                self.content = PreviewContent::HexDump { bytes, total_size };
+            Ok(bytes) => {
+                self.content = PreviewContent::Svg {
+                    path: path.to_path_buf(),
+                    source: String::from_utf8_lossy(&bytes).into_owned(),
+                    show_source: false,
+                };
             }
             Err(e) => {
                 self.content = PreviewContent::Error {
@@ -280,29 +427,22 @@ impl Preview {
             }
         }
     }
-}
-
 
-pub fn calculate_directory_stats(path: &Path) -> std::io::Result<(usize, u64, usize, usize)> {
-    let mut item_count = 0;
-    let mut total_size = 0u64;
-    let mut subdir_count = 0;
-    let mut file_count = 0;
-
-    for entry in fs::read_dir(path)? {
-        let entry = entry?;
-        let metadata = entry.metadata()?;
-        item_count += 1;
+    fn load_hex_dump(&mut self, path: &Path) {
+        let total_size = fs::metadata(path).map(|m| m.len()).unwrap_or(0);
 
-        if metadata.is_dir() {
-            subdir_count += 1;
-        } else {
-            file_count += 1;
-            total_size += metadata.len();
+        let mut bytes = Vec::with_capacity(256);
+        match fs::File::open(path).and_then(|file| file.take(256).read_to_end(&mut bytes)) {
+            Ok(_) => {
+                self.content = PreviewContent::HexDump { bytes, total_size };
+            }
+            Err(e) => {
+                self.content = PreviewContent::Error {
+                    message: format!("Cannot read file: {}", e),
+                };
+            }
         }
     }
-
-    Ok((item_count, total_size, subdir_count, file_count))
 }
 
 
@@ -406,8 +546,14 @@ fn is_text_extension(ext: Option<&str>) -> bool {
 
 
 fn is_likely_text_file(path: &Path) -> bool {
-    if let Ok(data) = fs::read(path) {
-        let sample: Vec<u8> = data.into_iter().take(512).collect();
+    let mut sample = Vec::with_capacity(512);
+    if fs::File::open(path)
+        .and_then(|file| file.take(512).read_to_end(&mut sample))
+        .is_ok()
+    {
+        if has_bom(&sample) {
+            return true;
+        }
         !sample.contains(&0)
             && sample
                 .iter()
@@ -498,18 +644,7 @@ pub fn format_size(size: u64) -> String {
 
 
 pub fn format_date(time: SystemTime) -> String {
-    use std::time::UNIX_EPOCH;
-
-    let duration = time.duration_since(UNIX_EPOCH).unwrap_or_default();
-    let secs = duration.as_secs();
-
-    let days_since_epoch = secs / 86400;
-    let years = 1970 + (days_since_epoch / 365);
-    let remaining_days = days_since_epoch % 365;
-    let month = (remaining_days / 30) + 1;
-    let day = (remaining_days % 30) + 1;
-
-    format!("{:04}-{:02}-{:02}", years, month.min(12), day.min(31))
+    crate::models::format_file_date(time)
 }
 
 
@@ -545,19 +680,138 @@ pub fn format_hex_dump(bytes: &[u8]) -> Vec<(String, String, String)> {
 }
 
 
+fn render_rendered_frame(
+    rendered: Option<PreviewResult>,
+    placeholder_icon: &'static str,
+    size: gpui::Pixels,
+    text_gray: gpui::Rgba,
+) -> gpui::Div {
+    let frame = div()
+        .w(size)
+        .h(size)
+        .bg(gpui::rgb(0x21262d))
+        .rounded_lg()
+        .overflow_hidden()
+        .flex()
+        .items_center()
+        .justify_center();
+
+    match rendered {
+        Some(PreviewResult::Image(image)) => frame.child(
+            img(image)
+                .size_full()
+                .object_fit(gpui::ObjectFit::Contain),
+        ),
+        _ => frame.child(
+            svg()
+                .path(placeholder_icon)
+                .size(px(64.0))
+                .text_color(text_gray),
+        ),
+    }
+}
+
+
+fn render_numbered_lines(
+    content: &str,
+    line_count: usize,
+    text_light: gpui::Rgba,
+    text_gray: gpui::Rgba,
+) -> gpui::Div {
+    let lines: Vec<String> = content.lines().map(|s| s.to_string()).collect();
+    let line_number_width = line_count.to_string().len().max(3);
+
+    div()
+        .flex()
+        .child(
+            div()
+                .flex()
+                .flex_col()
+                .text_color(text_gray)
+                .pr_3()
+                .border_r_1()
+                .border_color(gpui::rgb(0x30363d))
+                .mr_3()
+                .children((0..lines.len()).map(|i| {
+                    div()
+                        .text_right()
+                        .min_w(px((line_number_width * 8) as f32))
+                        .child(format!("{}", i + 1))
+                })),
+        )
+        .child(div().flex().flex_col().text_color(text_light).children(
+            lines.into_iter().map(|line| {
+                div().whitespace_nowrap().child(if line.is_empty() {
+                    " ".to_string()
+                } else {
+                    line
+                })
+            }),
+        ))
+}
+
+
+fn render_app_icon(icon_path: Option<PathBuf>, color: gpui::Rgba) -> gpui::AnyElement {
+    match icon_path {
+        Some(path) => img(path).size(px(16.0)).flex_shrink_0().into_any_element(),
+        None => svg()
+            .path("assets/icons/app-window.svg")
+            .size(px(16.0))
+            .flex_shrink_0()
+            .text_color(color)
+            .into_any_element(),
+    }
+}
+
+
+actions!(preview, [NextPreviewTab, PreviousPreviewTab]);
+
+
 pub struct PreviewView {
     preview: Preview,
     focus_handle: FocusHandle,
+    permissions: Option<PermissionsDialog>,
+    checksum: Option<Result<String, String>>,
+    is_hashing: bool,
+    rendered: PreviewSelection,
+    associations: AppAssociations,
+    show_app_picker: bool,
+    conversion_error: Option<String>,
+    pending_open: Option<PathBuf>,
+    summary_cancel: Option<Arc<AtomicBool>>,
 }
 
 impl PreviewView {
     pub fn new(cx: &mut Context<Self>) -> Self {
+        let mut preview = Preview::new();
+        preview.set_active_tab(GlobalSettings::load().preview_tab);
+
+        cx.observe_global::<PreviewService>(|_, cx| cx.notify()).detach();
+
         Self {
-            preview: Preview::new(),
+            preview,
             focus_handle: cx.focus_handle(),
+            permissions: None,
+            checksum: None,
+            is_hashing: false,
+            rendered: PreviewSelection::new(),
+            associations: AppAssociations::load(),
+            show_app_picker: false,
+            conversion_error: None,
+            pending_open: None,
+            summary_cancel: None,
         }
     }
 
+    pub fn register_key_bindings(cx: &mut App) {
+        cx.bind_keys([
+            KeyBinding::new("ctrl-tab", NextPreviewTab, Some("Preview")),
+            KeyBinding::new("ctrl-shift-tab", PreviousPreviewTab, Some("Preview")),
+            KeyBinding::new("right", NextPreviewTab, Some("Preview")),
+            KeyBinding::new("left", PreviousPreviewTab, Some("Preview")),
+        ]);
+    }
+
     pub fn preview(&self) -> &Preview {
         &self.preview
     }
@@ -566,12 +820,216 @@ impl PreviewView {
         &mut self.preview
     }
 
-    pub fn load_file(&mut self, path: &Path) {
-        self.preview.load_file(path);
+    pub fn take_pending_open(&mut self) -> Option<PathBuf> {
+        self.pending_open.take()
+    }
+
+    pub fn load_file(&mut self, path: &Path, cx: &mut Context<Self>) {
+        if self.preview.current_path().map(|p| p.as_path()) != Some(path) {
+            self.checksum = None;
+            self.is_hashing = false;
+            self.show_app_picker = false;
+            self.conversion_error = None;
+        }
+        self.cancel_summary();
+        let is_dir = self.preview.begin_load(path);
+        self.permissions = Some(PermissionsDialog::new(path.to_path_buf()));
+        if is_dir {
+            self.load_directory_stats(path.to_path_buf(), cx);
+            self.load_directory_summary(path.to_path_buf(), cx);
+        }
+    }
+
+    fn load_directory_stats(&mut self, path: PathBuf, cx: &mut Context<Self>) {
+        cx.spawn(async move |this, cx| {
+            let task_path = path.clone();
+            let stats = cx
+                .background_executor()
+                .spawn(async move { calculate_directory_stats(&task_path) })
+                .await;
+
+            let _ = this.update(cx, |view, cx| {
+                if view.preview.set_directory_stats(&path, stats) {
+                    cx.notify();
+                }
+            });
+        })
+        .detach();
+    }
+
+    fn cancel_summary(&mut self) {
+        if let Some(cancelled) = self.summary_cancel.take() {
+            cancelled.store(true, Ordering::SeqCst);
+        }
+    }
+
+    fn load_directory_summary(&mut self, path: PathBuf, cx: &mut Context<Self>) {
+        let cancelled = Arc::new(AtomicBool::new(false));
+        self.summary_cancel = Some(cancelled.clone());
+
+        cx.spawn(async move |this, cx| {
+            let task_path = path.clone();
+            let task_cancelled = cancelled.clone();
+            let result = cx
+                .background_executor()
+                .spawn(async move { summarize_directory(&task_path, &task_cancelled) })
+                .await;
+
+            if cancelled.load(Ordering::SeqCst) {
+                return;
+            }
+
+            let _ = this.update(cx, |view, cx| {
+                view.summary_cancel = None;
+                if let Ok(summary) = result {
+                    if view.preview.set_directory_summary(&path, summary) {
+                        cx.notify();
+                    }
+                }
+            });
+        })
+        .detach();
     }
 
     pub fn clear(&mut self) {
+        self.cancel_summary();
         self.preview.clear();
+        self.permissions = None;
+        self.checksum = None;
+        self.is_hashing = false;
+        self.conversion_error = None;
+    }
+
+
+    pub fn select_tab(&mut self, tab: PreviewTab, cx: &mut Context<Self>) {
+        if self.preview.active_tab() == tab {
+            return;
+        }
+        self.preview.set_active_tab(tab);
+
+        let mut settings = GlobalSettings::load();
+        settings.preview_tab = tab;
+        let _ = settings.save();
+        cx.notify();
+    }
+
+    fn handle_next_tab(&mut self, _: &NextPreviewTab, _window: &mut Window, cx: &mut Context<Self>) {
+        self.select_tab(self.preview.active_tab().next(), cx);
+    }
+
+    fn handle_previous_tab(
+        &mut self,
+        _: &PreviousPreviewTab,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.select_tab(self.preview.active_tab().previous(), cx);
+    }
+
+    fn request_rendered_preview(&mut self, cx: &mut Context<Self>) -> Option<PreviewResult> {
+        for superseded in self.rendered.select(self.preview.rendered_preview_keys()) {
+            PreviewService::cancel(&superseded, cx);
+        }
+
+        let mut best = None;
+        for key in self.rendered.keys().to_vec() {
+            match PreviewService::request(key, PreviewPriority::Selected, cx) {
+                Some(result @ PreviewResult::Image(_)) => best = Some(result),
+                Some(result) if best.is_none() => best = Some(result),
+                _ => {}
+            }
+        }
+        best
+    }
+
+    fn set_association(&mut self, app: &AppInfo, cx: &mut Context<Self>) {
+        let Some(extension) = self.preview.current_path().and_then(|p| association_key(p)) else {
+            return;
+        };
+        self.associations.set(&extension, app);
+        let _ = self.associations.save();
+        self.show_app_picker = false;
+        cx.notify();
+    }
+
+    fn reset_association(&mut self, cx: &mut Context<Self>) {
+        let Some(extension) = self.preview.current_path().and_then(|p| association_key(p)) else {
+            return;
+        };
+        if self.associations.reset(&extension) {
+            let _ = self.associations.save();
+        }
+        self.show_app_picker = false;
+        cx.notify();
+    }
+
+    fn convert_line_endings(&mut self, target: LineEnding, cx: &mut Context<Self>) {
+        let Some(path) = self.preview.current_path().cloned() else {
+            return;
+        };
+        let PreviewContent::Text { format, .. } = self.preview.content() else {
+            return;
+        };
+        let encoding = format.encoding;
+
+        cx.spawn(async move |this, cx| {
+            let task_path = path.clone();
+            let result = cx
+                .background_executor()
+                .spawn(async move { convert_line_endings(&task_path, target, encoding) })
+                .await;
+
+            let _ = this.update(cx, |view, cx| {
+                if view.preview.current_path() != Some(&path) {
+                    return;
+                }
+                match result {
+                    Ok(_) => {
+                        view.conversion_error = None;
+                        view.preview.load_file(&path);
+                    }
+                    Err(e) => {
+                        view.conversion_error = Some(format!(
+                            "Could not convert to {}: {}",
+                            target.label(),
+                            e
+                        ));
+                    }
+                }
+                cx.notify();
+            });
+        })
+        .detach();
+    }
+
+    fn calculate_checksum(&mut self, cx: &mut Context<Self>) {
+        let Some(path) = self.preview.current_path().cloned() else {
+            return;
+        };
+        if self.is_hashing || path.is_dir() {
+            return;
+        }
+        self.is_hashing = true;
+        self.checksum = None;
+        cx.notify();
+
+        cx.spawn(async move |this, cx| {
+            let task_path = path.clone();
+            let result = cx
+                .background_executor()
+                .spawn(async move { calculate_file_hash(&task_path, HashAlgorithm::Sha256) })
+                .await;
+
+            let _ = this.update(cx, |view, cx| {
+                if view.preview.current_path() != Some(&path) {
+                    return;
+                }
+                view.is_hashing = false;
+                view.checksum = Some(result.map_err(|e| e.to_string()));
+                cx.notify();
+            });
+        })
+        .detach();
     }
 }
 
@@ -582,7 +1040,7 @@ impl Focusable for PreviewView {
 }
 
 impl Render for PreviewView {
-    fn render(&mut self, _window: &mut Window, _cx: &mut Context<Self>) -> impl IntoElement {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
         let theme = theme_colors();
         let bg_dark = theme.bg_secondary;
         let bg_header = theme.bg_tertiary;
@@ -590,53 +1048,87 @@ impl Render for PreviewView {
         let text_gray = theme.text_muted;
         let text_light = theme.text_primary;
         let accent = theme.accent_primary;
+        let active_tab = self.preview.active_tab();
+        let rendered = if active_tab == PreviewTab::Preview {
+            self.request_rendered_preview(cx)
+        } else {
+            None
+        };
 
         div()
             .id("preview-content")
+            .key_context("Preview")
+            .track_focus(&self.focus_handle)
+            .on_action(cx.listener(Self::handle_next_tab))
+            .on_action(cx.listener(Self::handle_previous_tab))
             .size_full()
             .bg(bg_dark)
             .flex()
             .flex_col()
             .child(
                 div()
-                    .h(px(52.0))
+                    .h(px(40.0))
                     .bg(bg_dark)
                     .border_b_1()
                     .border_color(border_color)
                     .flex()
-                    .items_center()
-                    .justify_between()
-                    .px_4()
-                    .child(
+                    .items_end()
+                    .gap_1()
+                    .px_3()
+                    .children(PreviewTab::all().iter().map(|tab| {
+                        let tab = *tab;
+                        let is_active = tab == active_tab;
                         div()
+                            .id(SharedString::from(format!("preview-tab-{}", tab.label())))
+                            .px_3()
+                            .py_2()
                             .text_xs()
                             .font_weight(gpui::FontWeight::SEMIBOLD)
-                            .text_color(text_gray)
-                            .child("PREVIEW"),
-                    )
-                    .child(
-                        div().flex().gap_2().child(
-                            div()
-                                .w(px(24.0))
-                                .h(px(24.0))
-                                .rounded_md()
-                                .bg(theme.bg_hover)
-                                .flex()
-                                .items_center()
-                                .justify_center()
-                                .cursor_pointer()
-                                .child(div().text_xs().text_color(text_gray).child("×")),
-                        ),
-                    ),
+                            .cursor_pointer()
+                            .border_b_2()
+                            .when(is_active, |this| {
+                                this.border_color(accent).text_color(text_light)
+                            })
+                            .when(!is_active, |this| {
+                                this.border_color(gpui::transparent_black())
+                                    .text_color(text_gray)
+                                    .hover(|h| h.text_color(text_light))
+                            })
+                            .on_mouse_down(
+                                MouseButton::Left,
+                                cx.listener(move |view, _event, window, cx| {
+                                    window.focus(&view.focus_handle);
+                                    view.select_tab(tab, cx);
+                                }),
+                            )
+                            .child(tab.label())
+                    })),
             )
-            .child(self.render_metadata_header(
-                bg_header,
-                border_color,
-                text_light,
-                text_gray,
-                accent,
-            ))
-            .child(self.render_content(bg_dark, text_light, text_gray, accent))
+            .map(|this| match active_tab {
+                PreviewTab::Preview => {
+                    this.child(self.render_content(
+                        rendered,
+                        bg_dark,
+                        text_light,
+                        text_gray,
+                        accent,
+                        cx,
+                    ))
+                }
+                PreviewTab::Info => this
+                    .child(self.render_metadata_header(
+                        bg_header,
+                        border_color,
+                        text_light,
+                        text_gray,
+                        accent,
+                    ))
+                    .child(self.render_checksum(text_light, text_gray, cx))
+                    .child(self.render_open_with(text_light, text_gray, accent, cx)),
+                PreviewTab::Permissions => {
+                    this.child(self.render_permissions(text_light, text_gray))
+                }
+            })
             .child(self.render_info_bar(bg_dark, border_color, text_gray))
     }
 }
@@ -766,31 +1258,56 @@ impl PreviewView {
 
     fn render_content(
         &self,
+        rendered: Option<PreviewResult>,
         bg_dark: gpui::Rgba,
         text_light: gpui::Rgba,
         text_gray: gpui::Rgba,
         accent: gpui::Rgba,
+        cx: &mut Context<Self>,
     ) -> impl IntoElement {
         let content_element: gpui::Div = match self.preview.content() {
             PreviewContent::None => self.render_empty_state(text_gray),
             PreviewContent::Loading => self.render_loading_state(text_gray),
             PreviewContent::Error { message } => self.render_error_state(message, text_gray),
+            PreviewContent::TooLarge {
+                path,
+                content_type,
+                size,
+                limit,
+            } => self.render_too_large_state(path, *content_type, *size, *limit, text_gray, accent, cx),
             PreviewContent::Text {
                 content,
                 language,
                 line_count,
+                format,
             } => self.render_text_content(
                 content,
                 language.as_deref(),
                 *line_count,
+                format,
                 text_light,
                 text_gray,
+                cx,
             ),
             PreviewContent::Image {
                 path,
                 dimensions,
                 format,
-            } => self.render_image_content(path, dimensions.as_ref(), format, text_gray),
+            } => self.render_image_content(
+                path,
+                dimensions.as_ref(),
+                format,
+                rendered,
+                text_gray,
+            ),
+            PreviewContent::Document { format, .. } => {
+                self.render_document_content(format, rendered, text_gray)
+            }
+            PreviewContent::Svg {
+                source,
+                show_source,
+                ..
+            } => self.render_svg_content(source, *show_source, rendered, text_light, text_gray, cx),
             PreviewContent::HexDump { bytes, total_size } => {
                 self.render_hex_dump(bytes, *total_size, text_light, text_gray)
             }
@@ -869,16 +1386,77 @@ impl PreviewView {
             )
     }
 
+    fn render_too_large_state(
+        &self,
+        path: &Path,
+        content_type: PreviewContentType,
+        size: u64,
+        limit: u64,
+        text_gray: gpui::Rgba,
+        accent: gpui::Rgba,
+        cx: &mut Context<Self>,
+    ) -> gpui::Div {
+        let path = path.to_path_buf();
+
+        div()
+            .flex()
+            .flex_col()
+            .items_center()
+            .justify_center()
+            .gap_2()
+            .h_full()
+            .text_color(text_gray)
+            .child(
+                svg()
+                    .path("assets/icons/file.svg")
+                    .size(px(48.0))
+                    .text_color(text_gray)
+                    .mb_2(),
+            )
+            .child(
+                div()
+                    .text_sm()
+                    .font_weight(gpui::FontWeight::MEDIUM)
+                    .child("Too large to preview"),
+            )
+            .child(div().text_xs().child(format!(
+                "{} is over the {} limit for {} previews",
+                format_size(size),
+                format_size(limit),
+                content_type.label().to_lowercase()
+            )))
+            .child(
+                div()
+                    .mt_2()
+                    .px_3()
+                    .py_1()
+                    .rounded_md()
+                    .border_1()
+                    .border_color(accent)
+                    .text_xs()
+                    .text_color(accent)
+                    .cursor_pointer()
+                    .on_mouse_down(
+                        MouseButton::Left,
+                        cx.listener(move |view, _, _, cx| {
+                            view.pending_open = Some(path.clone());
+                            cx.notify();
+                        }),
+                    )
+                    .child("Open externally"),
+            )
+    }
+
     fn render_text_content(
         &self,
         content: &str,
         language: Option<&str>,
         line_count: usize,
+        format: &TextFormat,
         text_light: gpui::Rgba,
         text_gray: gpui::Rgba,
+        cx: &mut Context<Self>,
     ) -> gpui::Div {
-        let lines: Vec<String> = content.lines().map(|s| s.to_string()).collect();
-        let line_number_width = line_count.to_string().len().max(3);
         let lang_str = language.map(|s| s.to_string());
 
         div()
@@ -894,35 +1472,143 @@ impl PreviewView {
                         .child(format!("Language: {}", lang)),
                 )
             })
-            .child(
-                div()
-                    .flex()
-                    .child(
+            .child(self.render_line_endings(format, text_light, text_gray, cx))
+            .child(render_numbered_lines(content, line_count, text_light, text_gray))
+    }
+
+    fn render_svg_content(
+        &self,
+        source: &str,
+        show_source: bool,
+        rendered: Option<PreviewResult>,
+        text_light: gpui::Rgba,
+        text_gray: gpui::Rgba,
+        cx: &mut Context<Self>,
+    ) -> gpui::Div {
+        let theme = theme_colors();
+        let error = match &rendered {
+            Some(PreviewResult::Failed(message)) if !show_source => Some(message.clone()),
+            _ => None,
+        };
+        let toggle_label = if show_source { "Show Image" } else { "Show Source" };
+
+        let toolbar = div()
+            .flex()
+            .items_center()
+            .justify_between()
+            .mb_2()
+            .text_xs()
+            .text_color(text_gray)
+            .child("Format: SVG")
+            .when(error.is_none(), |this| {
+                this.child(
+                    div()
+                        .id("svg-source-toggle")
+                        .px_2()
+                        .rounded_md()
+                        .bg(gpui::rgb(0x21262d))
+                        .text_color(text_light)
+                        .cursor_pointer()
+                        .hover(|h| h.bg(gpui::rgb(0x30363d)))
+                        .on_mouse_down(
+                            MouseButton::Left,
+                            cx.listener(|view, _event, _window, cx| {
+                                view.preview.toggle_svg_source();
+                                cx.notify();
+                            }),
+                        )
+                        .child(toggle_label),
+                )
+            });
+
+        let body = if show_source || error.is_some() {
+            div()
+                .flex()
+                .flex_col()
+                .font_family("JetBrains Mono")
+                .text_xs()
+                .when_some(error, |this, message| {
+                    this.child(
                         div()
-                            .flex()
-                            .flex_col()
-                            .text_color(text_gray)
-                            .pr_3()
-                            .border_r_1()
-                            .border_color(gpui::rgb(0x30363d))
-                            .mr_3()
-                            .children((0..lines.len()).map(|i| {
-                                div()
-                                    .text_right()
-                                    .min_w(px((line_number_width * 8) as f32))
-                                    .child(format!("{}", i + 1))
-                            })),
+                            .mb_2()
+                            .text_color(theme.warning)
+                            .child(format!("Could not render SVG: {}", message)),
                     )
-                    .child(div().flex().flex_col().text_color(text_light).children(
-                        lines.into_iter().map(|line| {
-                            div().whitespace_nowrap().child(if line.is_empty() {
-                                " ".to_string()
-                            } else {
-                                line
-                            })
+                })
+                .child(render_numbered_lines(
+                    source,
+                    source.lines().count(),
+                    text_light,
+                    text_gray,
+                ))
+        } else {
+            div().flex().justify_center().child(render_rendered_frame(
+                rendered,
+                "assets/icons/file-image.svg",
+                px(260.0),
+                text_gray,
+            ))
+        };
+
+        div().flex().flex_col().child(toolbar).child(body)
+    }
+
+    fn render_line_endings(
+        &self,
+        format: &TextFormat,
+        text_light: gpui::Rgba,
+        text_gray: gpui::Rgba,
+        cx: &mut Context<Self>,
+    ) -> gpui::Div {
+        let theme = theme_colors();
+        let current = format.line_ending();
+        let targets: Vec<LineEnding> = if current == LineEnding::None {
+            Vec::new()
+        } else {
+            [LineEnding::Lf, LineEnding::Crlf]
+                .into_iter()
+                .filter(|target| *target != current)
+                .collect()
+        };
+
+        div()
+            .flex()
+            .flex_wrap()
+            .items_center()
+            .gap_2()
+            .mb_2()
+            .text_color(text_gray)
+            .child(format.label())
+            .when(format.has_mixed_line_endings(), |this| {
+                this.child(
+                    div()
+                        .text_color(theme.warning)
+                        .child(format!(
+                            "Mixed line endings ({})",
+                            format.line_endings.summary()
+                        )),
+                )
+            })
+            .children(targets.into_iter().map(|target| {
+                div()
+                    .id(SharedString::from(format!("convert-line-endings-{}", target.label())))
+                    .px_2()
+                    .rounded_md()
+                    .bg(gpui::rgb(0x21262d))
+                    .text_color(text_light)
+                    .cursor_pointer()
+                    .hover(|h| h.bg(gpui::rgb(0x30363d)))
+                    .on_mouse_down(
+                        MouseButton::Left,
+                        cx.listener(move |view, _event, _window, cx| {
+                            view.convert_line_endings(target, cx);
                         }),
-                    )),
-            )
+                    )
+                    .child(format!("Convert to {}", target.label()))
+            }))
+            .when_some(self.conversion_error.clone(), |this, error| {
+                this.child(div().text_color(theme.error).child(error))
+            })
     }
 
     fn render_image_content(
@@ -930,6 +1616,7 @@ impl PreviewView {
         _path: &Path,
         dimensions: Option<&(u32, u32)>,
         format: &str,
+        rendered: Option<PreviewResult>,
         text_gray: gpui::Rgba,
     ) -> gpui::Div {
         let dims = dimensions.copied();
@@ -940,22 +1627,12 @@ impl PreviewView {
             .flex_col()
             .items_center()
             .gap_4()
-            .child(
-                div()
-                    .w(px(200.0))
-                    .h(px(200.0))
-                    .bg(gpui::rgb(0x21262d))
-                    .rounded_lg()
-                    .flex()
-                    .items_center()
-                    .justify_center()
-                    .child(
-                        svg()
-                            .path("assets/icons/file-image.svg")
-                            .size(px(64.0))
-                            .text_color(text_gray),
-                    ),
-            )
+            .child(render_rendered_frame(
+                rendered,
+                "assets/icons/file-image.svg",
+                px(200.0),
+                text_gray,
+            ))
             .child(
                 div()
                     .text_xs()
@@ -968,6 +1645,40 @@ impl PreviewView {
             )
     }
 
+    fn render_document_content(
+        &self,
+        format: &str,
+        rendered: Option<PreviewResult>,
+        text_gray: gpui::Rgba,
+    ) -> gpui::Div {
+        let error = match &rendered {
+            Some(PreviewResult::Failed(message)) => Some(message.clone()),
+            _ => None,
+        };
+
+        div()
+            .flex()
+            .flex_col()
+            .items_center()
+            .gap_4()
+            .child(render_rendered_frame(
+                rendered,
+                "assets/icons/file-text.svg",
+                px(260.0),
+                text_gray,
+            ))
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(text_gray)
+                    .text_center()
+                    .child(format!("Format: {}", format))
+                    .when_some(error, |this, message| {
+                        this.child(div().mt_1().child(message))
+                    }),
+            )
+    }
+
     fn render_hex_dump(
         &self,
         bytes: &[u8],
@@ -1073,6 +1784,306 @@ impl PreviewView {
                             .child(div().text_color(text_light).child(format_size(total_size))),
                     ),
             )
+            .when_some(self.preview.directory_summary(), |this, summary| {
+                let newest = summary.newest.as_ref().map(|f| f.name.clone());
+                let oldest = summary.oldest.as_ref().map(|f| f.name.clone());
+                this.child(
+                    div()
+                        .flex()
+                        .flex_col()
+                        .gap_2()
+                        .text_sm()
+                        .when_some(newest, |this, name| {
+                            this.child(
+                                div()
+                                    .flex()
+                                    .justify_between()
+                                    .child(div().text_color(text_gray).child("Newest"))
+                                    .child(div().text_color(text_light).truncate().child(name)),
+                            )
+                        })
+                        .when_some(oldest, |this, name| {
+                            this.child(
+                                div()
+                                    .flex()
+                                    .justify_between()
+                                    .child(div().text_color(text_gray).child("Oldest"))
+                                    .child(div().text_color(text_light).truncate().child(name)),
+                            )
+                        }),
+                )
+                .child(div().flex().flex_wrap().gap_2().children(summary.kinds.iter().map(
+                    |(kind, count)| {
+                        div()
+                            .px_2()
+                            .py_1()
+                            .rounded_md()
+                            .bg(gpui::rgb(0x21262d))
+                            .text_xs()
+                            .text_color(text_gray)
+                            .child(format!("{} {}", count, kind.display_name()))
+                    },
+                )))
+            })
+    }
+
+    fn render_checksum(
+        &self,
+        text_light: gpui::Rgba,
+        text_gray: gpui::Rgba,
+        cx: &mut Context<Self>,
+    ) -> impl IntoElement {
+        let is_dir = self.preview.metadata().map(|m| m.is_dir).unwrap_or(false);
+        let path = self
+            .preview
+            .current_path()
+            .map(|p| p.display().to_string())
+            .unwrap_or_default();
+
+        div()
+            .flex()
+            .flex_col()
+            .gap_2()
+            .p_4()
+            .text_xs()
+            .text_color(text_gray)
+            .child(div().child("Location"))
+            .child(div().text_color(text_light).child(path))
+            .when(!is_dir, |this| {
+                this.child(div().mt_2().child("SHA-256"))
+                    .map(|this| match &self.checksum {
+                        Some(Ok(hash)) => this.child(
+                            div()
+                                .font_family("JetBrains Mono")
+                                .text_color(text_light)
+                                .child(hash.clone()),
+                        ),
+                        Some(Err(e)) => this.child(
+                            div()
+                                .text_color(gpui::rgb(0xf85149))
+                                .child(format!("Checksum failed: {}", e)),
+                        ),
+                        None if self.is_hashing => this.child(div().child("Calculating...")),
+                        None => this.child(
+                            div()
+                                .id("calculate-checksum")
+                                .px_3()
+                                .py_1()
+                                .rounded_md()
+                                .bg(gpui::rgb(0x21262d))
+                                .text_color(text_light)
+                                .cursor_pointer()
+                                .hover(|h| h.bg(gpui::rgb(0x30363d)))
+                                .on_mouse_down(
+                                    MouseButton::Left,
+                                    cx.listener(|view, _event, _window, cx| {
+                                        view.calculate_checksum(cx);
+                                    }),
+                                )
+                                .child("Calculate Checksum"),
+                        ),
+                    })
+            })
+    }
+
+    fn render_open_with(
+        &self,
+        text_light: gpui::Rgba,
+        text_gray: gpui::Rgba,
+        accent: gpui::Rgba,
+        cx: &mut Context<Self>,
+    ) -> impl IntoElement {
+        let theme = theme_colors();
+        let path = self.preview.current_path().cloned();
+        let is_dir = self.preview.metadata().map(|m| m.is_dir).unwrap_or(false);
+        let extension = path.as_deref().and_then(association_key);
+        let association = path
+            .as_deref()
+            .and_then(|p| self.associations.for_path(p))
+            .cloned();
+        let apps = match &path {
+            Some(path) if !is_dir => get_apps_for_file(path),
+            _ => Vec::new(),
+        };
+        let current_icon = association.as_ref().and_then(|current| {
+            apps.iter()
+                .find(|app| app.path == current.path)
+                .and_then(|app| app.icon_path.clone())
+        });
+        let registry_loaded = is_registry_loaded();
+
+        div()
+            .when(!is_dir && extension.is_some(), |this| {
+                this.flex()
+                    .flex_col()
+                    .gap_2()
+                    .px_4()
+                    .pb_4()
+                    .text_xs()
+                    .text_color(text_gray)
+                    .child(div().child(format!(
+                        "Open .{} files with",
+                        extension.clone().unwrap_or_default()
+                    )))
+                    .child(
+                        div()
+                            .flex()
+                            .items_center()
+                            .gap_2()
+                            .child(render_app_icon(current_icon, text_light))
+                            .child(
+                                div()
+                                    .flex_1()
+                                    .text_color(text_light)
+                                    .child(match &association {
+                                        Some(app) => app.name.clone(),
+                                        None => "System Default".to_string(),
+                                    }),
+                            )
+                            .child(
+                                div()
+                                    .id("change-open-with")
+                                    .px_2()
+                                    .py_0p5()
+                                    .rounded_md()
+                                    .border_1()
+                                    .border_color(theme.border_default)
+                                    .text_color(text_light)
+                                    .cursor_pointer()
+                                    .hover(|h| h.bg(theme.bg_hover))
+                                    .on_mouse_down(
+                                        MouseButton::Left,
+                                        cx.listener(|view, _event, _window, cx| {
+                                            view.show_app_picker = !view.show_app_picker;
+                                            if view.show_app_picker {
+                                                view.associations = AppAssociations::load();
+                                            }
+                                            cx.notify();
+                                        }),
+                                    )
+                                    .child("Change…"),
+                            ),
+                    )
+                    .when(self.show_app_picker, |this| {
+                        this.child(
+                            div()
+                                .id("open-with-apps")
+                                .flex()
+                                .flex_col()
+                                .max_h(px(200.0))
+                                .overflow_y_scroll()
+                                .rounded_md()
+                                .border_1()
+                                .border_color(theme.border_default)
+                                .when(apps.is_empty(), |list| {
+                                    list.child(div().px_3().py_2().child(if registry_loaded {
+                                        "No applications found for this file type"
+                                    } else {
+                                        "Loading applications…"
+                                    }))
+                                })
+                                .children(apps.into_iter().enumerate().map(|(ix, app)| {
+                                    let is_current = association
+                                        .as_ref()
+                                        .is_some_and(|current| current.path == app.path);
+                                    let icon_path = app.icon_path.clone();
+                                    let name = app.name.clone();
+                                    div()
+                                        .id(SharedString::from(format!("open-with-app-{}", ix)))
+                                        .flex()
+                                        .items_center()
+                                        .gap_2()
+                                        .px_3()
+                                        .py_1p5()
+                                        .cursor_pointer()
+                                        .text_color(if is_current { accent } else { text_light })
+                                        .hover(|h| h.bg(theme.bg_hover))
+                                        .on_mouse_down(
+                                            MouseButton::Left,
+                                            cx.listener(move |view, _event, _window, cx| {
+                                                view.set_association(&app, cx);
+                                            }),
+                                        )
+                                        .child(render_app_icon(icon_path, text_light))
+                                        .child(name)
+                                }))
+                                .when(association.is_some(), |list| {
+                                    list.child(
+                                        div()
+                                            .id("reset-open-with")
+                                            .flex()
+                                            .items_center()
+                                            .gap_2()
+                                            .px_3()
+                                            .py_1p5()
+                                            .border_t_1()
+                                            .border_color(theme.border_default)
+                                            .cursor_pointer()
+                                            .text_color(text_gray)
+                                            .hover(|h| h.bg(theme.bg_hover).text_color(text_light))
+                                            .on_mouse_down(
+                                                MouseButton::Left,
+                                                cx.listener(|view, _event, _window, cx| {
+                                                    view.reset_association(cx);
+                                                }),
+                                            )
+                                            .child("Reset to System Default"),
+                                    )
+                                }),
+                        )
+                    })
+            })
+    }
+
+    fn render_permissions(&self, text_light: gpui::Rgba, text_gray: gpui::Rgba) -> gpui::Div {
+        let Some(dialog) = &self.permissions else {
+            return self.render_empty_state(text_gray);
+        };
+
+        div()
+            .flex_1()
+            .overflow_hidden()
+            .flex()
+            .flex_col()
+            .when_some(dialog.symbolic_mode(), |this, symbolic| {
+                this.child(
+                    div()
+                        .px_4()
+                        .pt_4()
+                        .text_xs()
+                        .text_color(text_gray)
+                        .child(format!(
+                            "{} ({})",
+                            symbolic,
+                            dialog.octal_mode().unwrap_or_default()
+                        )),
+                )
+            })
+            .when_some(dialog.unix_permissions(), |this, perms| {
+                this.child(dialog.render_unix_permissions(perms))
+            })
+            .when_some(dialog.windows_acl(), |this, acl| {
+                this.child(dialog.render_windows_acl(acl))
+            })
+            .when(dialog.current_permissions().is_none(), |this| {
+                this.child(
+                    div()
+                        .p_4()
+                        .text_xs()
+                        .text_color(text_light)
+                        .child("Unable to read permissions for this item."),
+                )
+            })
+            .when(dialog.requires_elevation(), |this| {
+                this.child(
+                    div()
+                        .px_4()
+                        .py_2()
+                        .text_xs()
+                        .text_color(gpui::rgb(0xfcd34d))
+                        .child("Modifying permissions may require administrator privileges"),
+                )
+            })
     }
 
     fn render_info_bar(
@@ -1087,6 +2098,11 @@ impl PreviewView {
             .and_then(|m| m.modified)
             .map(format_date)
             .unwrap_or_default();
+        let text_format = match self.preview.content() {
+            PreviewContent::Text { format, .. } => Some(*format),
+            _ => None,
+        };
+        let warning = theme_colors().warning;
 
         div()
             .h(px(28.0))
@@ -1099,7 +2115,15 @@ impl PreviewView {
             .px_3()
             .text_xs()
             .text_color(text_gray)
-            .child("UTF-8")
+            .child(
+                div()
+                    .when_some(text_format, |this, format| {
+                        this.when(format.has_mixed_line_endings(), |this| {
+                            this.text_color(warning)
+                        })
+                        .child(format.label())
+                    }),
+            )
             .child(modified)
             .child(file_type)
     }
@@ -290,3 +290,121 @@ fn test_file_metadata_has_all_fields() {
     let empty_meta = FileMetadata::default();
     assert!(!empty_meta.has_all_fields());
 }
+
+#[test]
+fn test_directory_preview_includes_summary() {
+    let temp_dir = create_test_dir();
+    create_test_file(&temp_dir, "notes.md", b"notes");
+    create_test_file(&temp_dir, "main.rs", b"fn main() {}");
+    create_test_subdir(&temp_dir, "src");
+
+    let mut preview = Preview::new();
+    preview.load_file(temp_dir.path());
+    assert!(preview.directory_summary().is_none());
+
+    let summary = summarize_directory(temp_dir.path(), &AtomicBool::new(false)).unwrap();
+    assert!(preview.set_directory_summary(temp_dir.path(), summary.clone()));
+    let shown = preview.directory_summary().expect("directory summary");
+    assert_eq!(shown.item_count, 3);
+    assert_eq!(shown.subdir_count, 1);
+
+    let file_path = create_test_file(&temp_dir, "other.txt", b"x");
+    preview.load_file(&file_path);
+    assert!(preview.directory_summary().is_none());
+    assert!(!preview.set_directory_summary(temp_dir.path(), summary));
+    assert!(preview.directory_summary().is_none());
+}
+
+#[test]
+fn test_directory_stats_arrive_after_loading_placeholder() {
+    let temp_dir = create_test_dir();
+    let file_path = create_test_file(&temp_dir, "notes.md", b"notes");
+    create_test_subdir(&temp_dir, "src");
+
+    let mut preview = Preview::new();
+    assert!(preview.begin_load(temp_dir.path()));
+    assert!(matches!(preview.content(), PreviewContent::Loading));
+
+    let stats = calculate_directory_stats(temp_dir.path());
+    assert!(preview.set_directory_stats(temp_dir.path(), stats));
+    assert!(matches!(
+        preview.content(),
+        PreviewContent::Directory {
+            item_count: 2,
+            subdir_count: 1,
+            file_count: 1,
+            ..
+        }
+    ));
+
+    assert!(!preview.begin_load(&file_path));
+    let stale = calculate_directory_stats(temp_dir.path());
+    assert!(!preview.set_directory_stats(temp_dir.path(), stale));
+    assert!(!matches!(preview.content(), PreviewContent::Directory { .. }));
+}
+
+#[test]
+fn test_active_tab_survives_loading_files() {
+    let temp_dir = create_test_dir();
+    let file_path = create_test_file(&temp_dir, "test.txt", b"content");
+
+    let mut preview = Preview::new();
+    assert_eq!(preview.active_tab(), PreviewTab::Preview);
+
+    preview.set_active_tab(PreviewTab::Permissions);
+    preview.load_file(&file_path);
+    preview.clear();
+
+    assert_eq!(preview.active_tab(), PreviewTab::Permissions);
+}
+
+#[test]
+fn test_text_preview_reports_encoding_and_line_endings() {
+    let temp_dir = create_test_dir();
+    let file_path = create_test_file(
+        &temp_dir,
+        "windows.txt",
+        b"\xFF\xFEo\x00n\x00e\x00\r\x00\n\x00t\x00w\x00o\x00\n\x00",
+    );
+
+    let mut preview = Preview::new();
+    preview.load_file(&file_path);
+
+    match preview.content() {
+        PreviewContent::Text { content, format, .. } => {
+            assert_eq!(content, "one\r\ntwo\n");
+            assert_eq!(format.encoding, TextEncoding::Utf16Le);
+            assert!(format.has_mixed_line_endings());
+        }
+        other => panic!("Expected Text content, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_svg_preview_renders_image_and_toggles_source() {
+    let temp_dir = create_test_dir();
+    let source = r#"<svg xmlns="http://www.w3.org/2000/svg" width="8" height="8"><circle cx="4" cy="4" r="4"/></svg>"#;
+    let file_path = create_test_file(&temp_dir, "dot.svg", source.as_bytes());
+
+    let mut preview = Preview::new();
+    preview.load_file(&file_path);
+
+    assert!(matches!(
+        preview.content(),
+        PreviewContent::Svg { show_source: false, .. }
+    ));
+    let keys = preview.rendered_preview_keys();
+    assert_eq!(keys.len(), 1);
+    assert_eq!(keys[0].kind, PreviewKind::Svg(SVG_PREVIEW_SIZE));
+
+    assert!(preview.toggle_svg_source());
+    match preview.content() {
+        PreviewContent::Svg {
+            source: shown,
+            show_source: true,
+            ..
+        } => assert_eq!(shown, source),
+        other => panic!("Expected SVG source, got {:?}", other),
+    }
+    assert!(preview.rendered_preview_keys().is_empty());
+}
@@ -5,7 +5,9 @@ use gpui::{
     IntoElement, MouseButton, ParentElement, Render, Rgba, SharedString, Styled, Window,
 };
 
-use crate::models::{theme_colors, FileOperation, OperationId, OperationStatus, OperationType};
+use crate::models::{
+    theme_colors, FileOperation, OperationId, OperationProgress, OperationStatus, OperationType,
+};
 
 
 fn with_alpha(color: Rgba, alpha: f32) -> Rgba {
@@ -67,13 +69,31 @@ fn format_size(size: u64) -> String {
 }
 
 
+pub fn transfer_summary(progress: &OperationProgress) -> Option<String> {
+    if progress.total_bytes == 0 {
+        return None;
+    }
+    if progress.is_calculating_rate() {
+        return Some("Calculating…".to_string());
+    }
+    let mut summary = format_speed(progress.speed_bytes_per_sec);
+    if progress.estimated_remaining.as_secs() > 0 {
+        summary.push_str(&format!(
+            " · ~{} remaining",
+            format_duration(progress.estimated_remaining)
+        ));
+    }
+    Some(summary)
+}
+
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum ProgressPanelAction {
     Cancel(OperationId),
-    Skip(OperationId),
-    Retry(OperationId),
     Dismiss(OperationId),
     DismissAll,
+    MoveUp(OperationId),
+    MoveDown(OperationId),
 }
 
 
@@ -101,6 +121,14 @@ impl ProgressPanelView {
     }
 
 
+    pub fn update_operation(&mut self, operation: FileOperation, cx: &mut Context<Self>) {
+        if let Some(existing) = self.operations.iter_mut().find(|op| op.id == operation.id) {
+            *existing = operation;
+            cx.notify();
+        }
+    }
+
+
     pub fn take_pending_action(&mut self) -> Option<ProgressPanelAction> {
         self.pending_action.take()
     }
@@ -121,6 +149,13 @@ impl ProgressPanelView {
         cx.notify();
     }
 
+    pub fn set_expanded(&mut self, expanded: bool, cx: &mut Context<Self>) {
+        if self.is_expanded != expanded {
+            self.is_expanded = expanded;
+            cx.notify();
+        }
+    }
+
     fn render_operation(&self, op: &FileOperation, cx: &mut Context<Self>) -> impl IntoElement {
         let theme = theme_colors();
         let bg_color = theme.bg_tertiary;
@@ -147,6 +182,23 @@ impl ProgressPanelView {
         let is_failed = matches!(status, OperationStatus::Failed(_));
         let is_cancelled = matches!(status, OperationStatus::Cancelled);
         let is_paused = matches!(status, OperationStatus::Paused);
+        let is_pending = matches!(status, OperationStatus::Pending);
+        let reorder_button = |id: &str, icon: &'static str, action: ProgressPanelAction| {
+            div()
+                .id(SharedString::from(format!("{}-{}", id, op_id.0)))
+                .p_1()
+                .rounded_sm()
+                .cursor_pointer()
+                .hover(|s| s.bg(hover_bg))
+                .on_mouse_down(
+                    MouseButton::Left,
+                    cx.listener(move |view, _event, _window, cx| {
+                        view.pending_action = Some(action.clone());
+                        cx.notify();
+                    }),
+                )
+                .child(svg().path(icon).size(px(12.0)).text_color(text_muted))
+        };
 
         div()
             .id(SharedString::from(format!("operation-{}", op_id.0)))
@@ -175,6 +227,8 @@ impl ProgressPanelView {
                                         OperationType::Copy => "assets/icons/copy.svg",
                                         OperationType::Move => "assets/icons/arrow-right.svg",
                                         OperationType::Delete => "assets/icons/trash-2.svg",
+                                        OperationType::Compress => "assets/icons/file-archive.svg",
+                                        OperationType::Extract => "assets/icons/folder-archive.svg",
                                     })
                                     .size(px(16.0))
                                     .text_color(if is_completed {
@@ -221,7 +275,7 @@ impl ProgressPanelView {
                                         _ => text_muted,
                                     })
                                     .child(match &status {
-                                        OperationStatus::Pending => "Pending".to_string(),
+                                        OperationStatus::Pending => "Queued".to_string(),
                                         OperationStatus::Running => format!("{:.0}%", percentage),
                                         OperationStatus::Paused => {
                                             "Error - Action Required".to_string()
@@ -231,6 +285,18 @@ impl ProgressPanelView {
                                         OperationStatus::Cancelled => "Cancelled".to_string(),
                                     }),
                             )
+                            .when(is_pending, |el| {
+                                el.child(reorder_button(
+                                    "move-up",
+                                    "assets/icons/arrow-up.svg",
+                                    ProgressPanelAction::MoveUp(op_id),
+                                ))
+                                .child(reorder_button(
+                                    "move-down",
+                                    "assets/icons/arrow-down.svg",
+                                    ProgressPanelAction::MoveDown(op_id),
+                                ))
+                            })
                             .when(is_active, |el| {
                                 el.child(
                                     div()
@@ -308,21 +374,9 @@ impl ProgressPanelView {
                                 .truncate()
                                 .child(current_file.unwrap_or_else(|| "Preparing...".to_string())),
                         )
-                        .child(
-                            div()
-                                .flex()
-                                .items_center()
-                                .gap_2()
-                                .when(progress.speed_bytes_per_sec > 0, |el| {
-                                    el.child(format_speed(progress.speed_bytes_per_sec))
-                                })
-                                .when(progress.estimated_remaining.as_secs() > 0, |el| {
-                                    el.child(format!(
-                                        "~{} remaining",
-                                        format_duration(progress.estimated_remaining)
-                                    ))
-                                }),
-                        ),
+                        .when_some(transfer_summary(&progress), |el, summary| {
+                            el.child(div().flex().items_center().gap_2().child(summary))
+                        }),
                 )
             })
             .when(progress.total_bytes > 0 && is_active, |el| {
@@ -350,11 +404,6 @@ impl ProgressPanelView {
                 )
             })
             .when(has_error, |el| {
-                let is_recoverable = op
-                    .current_error
-                    .as_ref()
-                    .map(|e| e.is_recoverable)
-                    .unwrap_or(false);
                 let user_msg = op
                     .current_error
                     .as_ref()
@@ -396,52 +445,6 @@ impl ProgressPanelView {
                                 .items_center()
                                 .gap_2()
                                 .justify_end()
-                                .child(
-                                    div()
-                                        .id(SharedString::from(format!("skip-{}", op_id.0)))
-                                        .px_3()
-                                        .py_1()
-                                        .rounded_sm()
-                                        .cursor_pointer()
-                                        .bg(hover_bg)
-                                        .hover(|s| s.bg(border_color))
-                                        .text_xs()
-                                        .font_weight(gpui::FontWeight::MEDIUM)
-                                        .text_color(text_primary)
-                                        .on_mouse_down(
-                                            MouseButton::Left,
-                                            cx.listener(move |view, _event, _window, cx| {
-                                                view.pending_action =
-                                                    Some(ProgressPanelAction::Skip(op_id));
-                                                cx.notify();
-                                            }),
-                                        )
-                                        .child("Skip"),
-                                )
-                                .when(is_recoverable, |el| {
-                                    el.child(
-                                        div()
-                                            .id(SharedString::from(format!("retry-{}", op_id.0)))
-                                            .px_3()
-                                            .py_1()
-                                            .rounded_sm()
-                                            .cursor_pointer()
-                                            .bg(with_alpha(accent, 0.2))
-                                            .hover(|s| s.bg(with_alpha(accent, 0.3)))
-                                            .text_xs()
-                                            .font_weight(gpui::FontWeight::MEDIUM)
-                                            .text_color(accent)
-                                            .on_mouse_down(
-                                                MouseButton::Left,
-                                                cx.listener(move |view, _event, _window, cx| {
-                                                    view.pending_action =
-                                                        Some(ProgressPanelAction::Retry(op_id));
-                                                    cx.notify();
-                                                }),
-                                            )
-                                            .child("Retry"),
-                                    )
-                                })
                                 .child(
                                     div()
                                         .id(SharedString::from(format!("cancel-err-{}", op_id.0)))
@@ -619,6 +622,21 @@ mod tests {
         assert_eq!(format_speed(1073741824), "1.0 GB/s");
     }
 
+    #[test]
+    fn test_transfer_summary_waits_for_rate() {
+        let mut progress = OperationProgress::new(1, 100 * 1048576);
+        assert_eq!(transfer_summary(&progress).as_deref(), Some("Calculating…"));
+
+        for second in 1..=4u64 {
+            progress.record_transfer(second * 10 * 1048576, Duration::from_secs(second));
+        }
+        assert_eq!(
+            transfer_summary(&progress).as_deref(),
+            Some("10.0 MB/s · ~6s remaining")
+        );
+        assert_eq!(transfer_summary(&OperationProgress::new(1, 0)), None);
+    }
+
     #[test]
     fn test_format_size() {
         assert_eq!(format_size(500), "500 B");
@@ -1,13 +1,21 @@
 use gpui::{
     actions, div, img, prelude::*, px, svg, App, Context, FocusHandle, Focusable,
-    InteractiveElement, IntoElement, KeyBinding, ParentElement, Render, SharedString, Styled,
-    Window,
+    InteractiveElement, IntoElement, KeyBinding, MouseButton, ParentElement, Render, SharedString,
+    Styled, Window,
 };
 use std::fs;
+use std::io::Read;
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
 use std::time::SystemTime;
 
-use crate::models::{theme_colors, FileEntry};
+use crate::models::{
+    editable_image_format, edited_copy_path, preview_limits,
+    render_transformed_png, save_transformed_image, summarize_directory, theme_colors,
+    DirectorySummary, DirectorySummaryCache, FileEntry, ImageTransform, PreviewContentType,
+    PreviewDecision, PreviewKey, PreviewKind, PreviewPriority, PreviewResult, PreviewService,
+};
 use crate::views::preview::{format_date, format_size};
 
 
@@ -27,9 +35,21 @@ pub enum QuickLookContent {
         content: String,
         file_type: String,
     },
+    Pdf {
+        path: PathBuf,
+    },
     Unsupported {
         file_type: String,
     },
+    TooLarge {
+        path: PathBuf,
+        content_type: PreviewContentType,
+        size: u64,
+        limit: u64,
+    },
+    Directory {
+        summary: DirectorySummary,
+    },
     Loading,
     None,
 }
@@ -44,6 +64,7 @@ pub struct QuickLook {
     file_name: String,
     file_size: u64,
     modified: Option<SystemTime>,
+    image_transform: ImageTransform,
 }
 
 impl Default for QuickLook {
@@ -62,6 +83,7 @@ impl QuickLook {
             file_name: String::new(),
             file_size: 0,
             modified: None,
+            image_transform: ImageTransform::new(),
         }
     }
 
@@ -93,11 +115,16 @@ impl QuickLook {
         self.modified
     }
 
+    pub fn image_transform(&self) -> ImageTransform {
+        self.image_transform
+    }
+
 
     pub fn show(&mut self, path: PathBuf) {
         self.current_path = Some(path.clone());
         self.is_visible = true;
         self.zoom_level = 1.0;
+        self.image_transform.reset();
         self.load_content(&path);
     }
 
@@ -116,53 +143,28 @@ impl QuickLook {
     }
 
 
-    pub fn next(&mut self, entries: &[FileEntry], current_index: usize) {
+    pub fn next(&mut self, entries: &[FileEntry], current_index: usize) -> Option<usize> {
         if entries.is_empty() {
-            return;
+            return None;
         }
 
-        let mut next_idx = (current_index + 1) % entries.len();
-        let start_idx = next_idx;
-
-        loop {
-            if !entries[next_idx].is_dir {
-                self.show(entries[next_idx].path.clone());
-                return;
-            }
-            next_idx = (next_idx + 1) % entries.len();
-            if next_idx == start_idx {
-                break;
-            }
-        }
+        let next_idx = (current_index + 1) % entries.len();
+        self.show(entries[next_idx].path.clone());
+        Some(next_idx)
     }
 
 
-    pub fn previous(&mut self, entries: &[FileEntry], current_index: usize) {
+    pub fn previous(&mut self, entries: &[FileEntry], current_index: usize) -> Option<usize> {
         if entries.is_empty() {
-            return;
+            return None;
         }
 
-        let mut prev_idx = if current_index == 0 {
-            entries.len() - 1
-        } else {
-            current_index - 1
+        let prev_idx = match current_index.min(entries.len()) {
+            0 => entries.len() - 1,
+            idx => idx - 1,
         };
-        let start_idx = prev_idx;
-
-        loop {
-            if !entries[prev_idx].is_dir {
-                self.show(entries[prev_idx].path.clone());
-                return;
-            }
-            prev_idx = if prev_idx == 0 {
-                entries.len() - 1
-            } else {
-                prev_idx - 1
-            };
-            if prev_idx == start_idx {
-                break;
-            }
-        }
+        self.show(entries[prev_idx].path.clone());
+        Some(prev_idx)
     }
 
 
@@ -180,6 +182,59 @@ impl QuickLook {
         self.zoom_level = 1.0;
     }
 
+
+    pub fn can_edit_image(&self) -> bool {
+        matches!(self.content, QuickLookContent::Image { .. })
+            && self
+                .current_path
+                .as_ref()
+                .is_some_and(|p| editable_image_format(p).is_ok())
+    }
+
+
+    pub fn rotate_image_left(&mut self) {
+        if self.can_edit_image() {
+            self.image_transform.rotate_left();
+        }
+    }
+
+
+    pub fn rotate_image_right(&mut self) {
+        if self.can_edit_image() {
+            self.image_transform.rotate_right();
+        }
+    }
+
+
+    pub fn flip_image_horizontal(&mut self) {
+        if self.can_edit_image() {
+            self.image_transform.flip_horizontal();
+        }
+    }
+
+
+    pub fn flip_image_vertical(&mut self) {
+        if self.can_edit_image() {
+            self.image_transform.flip_vertical();
+        }
+    }
+
+
+    pub fn discard_image_edits(&mut self) {
+        self.image_transform.reset();
+    }
+
+
+    pub fn set_directory_summary(&mut self, summary: DirectorySummary) {
+        self.file_size = summary.total_size;
+        self.content = QuickLookContent::Directory { summary };
+    }
+
+
+    pub fn is_directory(&self) -> bool {
+        self.current_path.as_ref().is_some_and(|p| p.is_dir())
+    }
+
     fn load_content(&mut self, path: &Path) {
         if let Ok(metadata) = fs::metadata(path) {
             self.file_size = metadata.len();
@@ -192,24 +247,60 @@ impl QuickLook {
             .unwrap_or("Unknown")
             .to_string();
 
+        if path.is_dir() {
+            self.file_size = 0;
+            self.content = QuickLookContent::Loading;
+            return;
+        }
+
         let extension = path
             .extension()
             .and_then(|ext| ext.to_str())
             .map(|s| s.to_lowercase());
 
-        if is_image_extension(extension.as_deref()) {
-            self.load_image_content(path, extension.as_deref());
-            return;
-        }
+        let content_type = if is_image_extension(extension.as_deref()) {
+            Some(PreviewContentType::Image)
+        } else if extension.as_deref() == Some("pdf") {
+            Some(PreviewContentType::Pdf)
+        } else if is_text_extension(extension.as_deref()) || is_likely_text_file(path) {
+            Some(PreviewContentType::Text)
+        } else {
+            None
+        };
 
-        if is_text_extension(extension.as_deref()) || is_likely_text_file(path) {
-            self.load_text_content(path, extension);
-            return;
+        if let Some(content_type) = content_type {
+            if let PreviewDecision::TooLarge {
+                content_type,
+                size,
+                limit,
+            } = preview_limits().check(content_type, self.file_size)
+            {
+                self.content = QuickLookContent::TooLarge {
+                    path: path.to_path_buf(),
+                    content_type,
+                    size,
+                    limit,
+                };
+                return;
+            }
         }
 
-        self.content = QuickLookContent::Unsupported {
-            file_type: extension.unwrap_or_else(|| "Unknown".to_string()),
-        };
+        match content_type {
+            Some(PreviewContentType::Image) => {
+                self.load_image_content(path, extension.as_deref());
+            }
+            Some(PreviewContentType::Pdf) => {
+                self.content = QuickLookContent::Pdf {
+                    path: path.to_path_buf(),
+                };
+            }
+            Some(PreviewContentType::Text) => self.load_text_content(path, extension),
+            None => {
+                self.content = QuickLookContent::Unsupported {
+                    file_type: extension.unwrap_or_else(|| "Unknown".to_string()),
+                };
+            }
+        }
     }
 
     fn load_image_content(&mut self, path: &Path, extension: Option<&str>) {
@@ -357,8 +448,11 @@ fn is_text_extension(ext: Option<&str>) -> bool {
 
 
 fn is_likely_text_file(path: &Path) -> bool {
-    if let Ok(data) = fs::read(path) {
-        let sample: Vec<u8> = data.into_iter().take(512).collect();
+    let mut sample = Vec::with_capacity(512);
+    if fs::File::open(path)
+        .and_then(|file| file.take(512).read_to_end(&mut sample))
+        .is_ok()
+    {
         !sample.contains(&0)
             && sample
                 .iter()
@@ -453,15 +547,31 @@ pub struct QuickLookView {
     focus_handle: FocusHandle,
     entries: Vec<FileEntry>,
     current_index: Option<usize>,
+    summary_cache: DirectorySummaryCache,
+    summary_cancel: Option<Arc<AtomicBool>>,
+    edited_preview: Option<(PathBuf, ImageTransform, Arc<gpui::Image>)>,
+    pending_overwrite: Option<PathBuf>,
+    image_edit_message: Option<(PathBuf, String)>,
+    rendered_key: Option<PreviewKey>,
+    pending_open: Option<PathBuf>,
 }
 
 impl QuickLookView {
     pub fn new(cx: &mut Context<Self>) -> Self {
+        cx.observe_global::<PreviewService>(|_, cx| cx.notify()).detach();
+
         Self {
             quick_look: QuickLook::new(),
             focus_handle: cx.focus_handle(),
             entries: Vec::new(),
             current_index: None,
+            summary_cache: DirectorySummaryCache::new(),
+            summary_cancel: None,
+            edited_preview: None,
+            pending_overwrite: None,
+            image_edit_message: None,
+            rendered_key: None,
+            pending_open: None,
         }
     }
 
@@ -481,56 +591,269 @@ impl QuickLookView {
         self.quick_look.is_visible()
     }
 
-    pub fn show(&mut self, path: PathBuf, entries: Vec<FileEntry>, index: usize) {
+    pub fn take_pending_open(&mut self) -> Option<PathBuf> {
+        self.pending_open.take()
+    }
+
+    pub fn show(
+        &mut self,
+        path: PathBuf,
+        entries: Vec<FileEntry>,
+        index: usize,
+        cx: &mut Context<Self>,
+    ) {
+        self.cancel_summary();
         self.entries = entries;
         self.current_index = Some(index);
-        self.quick_look.show(path);
+        self.quick_look.show(path.clone());
+        if path.is_dir() {
+            self.load_directory_summary(path, cx);
+        }
     }
 
     pub fn hide(&mut self) {
+        self.cancel_summary();
+        self.pending_overwrite = None;
         self.quick_look.hide();
     }
 
-    pub fn toggle(&mut self, path: PathBuf, entries: Vec<FileEntry>, index: usize) {
+    pub fn toggle(
+        &mut self,
+        path: PathBuf,
+        entries: Vec<FileEntry>,
+        index: usize,
+        cx: &mut Context<Self>,
+    ) {
         if self.quick_look.is_visible() && self.quick_look.current_path() == Some(&path) {
             self.hide();
         } else {
-            self.show(path, entries, index);
+            self.show(path, entries, index, cx);
         }
     }
 
-    pub fn next(&mut self) {
-        if let Some(idx) = self.current_index {
-            let next_idx = (idx + 1) % self.entries.len().max(1);
-            if !self.entries.is_empty() && !self.entries[next_idx].is_dir {
-                self.current_index = Some(next_idx);
-                self.quick_look.show(self.entries[next_idx].path.clone());
-            } else {
-                self.quick_look.next(&self.entries, idx);
-                if let Some(path) = self.quick_look.current_path() {
-                    self.current_index = self.entries.iter().position(|e| &e.path == path);
-                }
+    fn request_rendered_page(&mut self, cx: &mut Context<Self>) -> Option<PreviewResult> {
+        let key = match self.quick_look.content() {
+            QuickLookContent::Pdf { path } if self.quick_look.is_visible() => Some(PreviewKey::new(
+                path.clone(),
+                self.quick_look.modified(),
+                PreviewKind::PdfPage(1),
+            )),
+            _ => None,
+        };
+        if key != self.rendered_key {
+            if let Some(previous) = self.rendered_key.take() {
+                PreviewService::cancel(&previous, cx);
             }
+            self.rendered_key = key.clone();
         }
+        PreviewService::request(key?, PreviewPriority::Selected, cx)
     }
 
-    pub fn previous(&mut self) {
-        if let Some(idx) = self.current_index {
-            let prev_idx = if idx == 0 {
-                self.entries.len().saturating_sub(1)
-            } else {
-                idx - 1
-            };
-            if !self.entries.is_empty() && !self.entries[prev_idx].is_dir {
-                self.current_index = Some(prev_idx);
-                self.quick_look.show(self.entries[prev_idx].path.clone());
-            } else {
-                self.quick_look.previous(&self.entries, idx);
-                if let Some(path) = self.quick_look.current_path() {
-                    self.current_index = self.entries.iter().position(|e| &e.path == path);
-                }
+    fn cancel_summary(&mut self) {
+        if let Some(cancelled) = self.summary_cancel.take() {
+            cancelled.store(true, Ordering::SeqCst);
+        }
+    }
+
+    fn load_directory_summary(&mut self, path: PathBuf, cx: &mut Context<Self>) {
+        let mtime = fs::metadata(&path).and_then(|m| m.modified()).ok();
+        if let Some(summary) = mtime.and_then(|mtime| self.summary_cache.get(&path, mtime)) {
+            let summary = summary.clone();
+            self.quick_look.set_directory_summary(summary);
+            return;
+        }
+
+        let cancelled = Arc::new(AtomicBool::new(false));
+        self.summary_cancel = Some(cancelled.clone());
+
+        cx.spawn(async move |this, cx| {
+            let task_path = path.clone();
+            let task_cancelled = cancelled.clone();
+            let result = cx
+                .background_executor()
+                .spawn(async move { summarize_directory(&task_path, &task_cancelled) })
+                .await;
+
+            if cancelled.load(Ordering::SeqCst) {
+                return;
             }
+
+            let _ = this.update(cx, |view, cx| {
+                if cancelled.load(Ordering::SeqCst) {
+                    return;
+                }
+                view.summary_cancel = None;
+                match result {
+                    Ok(summary) => {
+                        if let Some(mtime) = mtime {
+                            view.summary_cache.insert(path.clone(), mtime, summary.clone());
+                        }
+                        if view.quick_look.current_path() == Some(&path) {
+                            view.quick_look.set_directory_summary(summary);
+                        }
+                    }
+                    Err(e) => {
+                        if view.quick_look.current_path() == Some(&path) {
+                            view.quick_look.content = QuickLookContent::Unsupported {
+                                file_type: format!("folder ({})", e),
+                            };
+                        }
+                    }
+                }
+                cx.notify();
+            });
+        })
+        .detach();
+    }
+
+    fn apply_image_edit(&mut self, edit: fn(&mut QuickLook), cx: &mut Context<Self>) {
+        edit(&mut self.quick_look);
+        self.pending_overwrite = None;
+        self.image_edit_message = None;
+        self.refresh_edited_preview(cx);
+        cx.notify();
+    }
+
+    fn discard_image_edits(&mut self, cx: &mut Context<Self>) {
+        self.quick_look.discard_image_edits();
+        self.pending_overwrite = None;
+        self.image_edit_message = None;
+        cx.notify();
+    }
+
+    fn edited_preview_for_current(&self) -> Option<Arc<gpui::Image>> {
+        let path = self.quick_look.current_path()?;
+        let transform = self.quick_look.image_transform();
+        self.edited_preview
+            .as_ref()
+            .filter(|(p, t, _)| p == path && *t == transform)
+            .map(|(_, _, image)| image.clone())
+    }
+
+    fn refresh_edited_preview(&mut self, cx: &mut Context<Self>) {
+        let Some(path) = self.quick_look.current_path().cloned() else {
+            return;
+        };
+        let transform = self.quick_look.image_transform();
+        if transform.is_identity() || self.edited_preview_for_current().is_some() {
+            return;
+        }
+
+        cx.spawn(async move |this, cx| {
+            let task_path = path.clone();
+            let result = cx
+                .background_executor()
+                .spawn(async move { render_transformed_png(&task_path, transform) })
+                .await;
+
+            let _ = this.update(cx, |view, cx| {
+                match result {
+                    Ok(bytes) => {
+                        let image = gpui::Image::from_bytes(gpui::ImageFormat::Png, bytes);
+                        view.edited_preview = Some((path, transform, Arc::new(image)));
+                    }
+                    Err(e) => {
+                        view.image_edit_message = Some((path, e.to_string()));
+                    }
+                }
+                cx.notify();
+            });
+        })
+        .detach();
+    }
+
+    fn request_save_image(&mut self, cx: &mut Context<Self>) {
+        self.pending_overwrite = self.quick_look.current_path().cloned();
+        cx.notify();
+    }
+
+    fn save_image_edits(&mut self, overwrite: bool, cx: &mut Context<Self>) {
+        let Some(source) = self.quick_look.current_path().cloned() else {
+            return;
+        };
+        let transform = self.quick_look.image_transform();
+        let destination = if overwrite {
+            source.clone()
+        } else {
+            edited_copy_path(&source)
+        };
+        self.pending_overwrite = None;
+
+        cx.spawn(async move |this, cx| {
+            let task_source = source.clone();
+            let task_destination = destination.clone();
+            let result = cx
+                .background_executor()
+                .spawn(async move {
+                    save_transformed_image(&task_source, &task_destination, transform).and_then(
+                        |_| {
+                            if overwrite {
+                                render_transformed_png(&task_destination, ImageTransform::new())
+                                    .map(Some)
+                            } else {
+                                Ok(None)
+                            }
+                        },
+                    )
+                })
+                .await;
+
+            let _ = this.update(cx, |view, cx| {
+                if view.quick_look.current_path() != Some(&source) {
+                    return;
+                }
+                match result {
+                    Ok(saved_preview) => {
+                        let name = destination
+                            .file_name()
+                            .map(|n| n.to_string_lossy().to_string())
+                            .unwrap_or_default();
+                        if let Some(bytes) = saved_preview {
+                            view.quick_look.show(source.clone());
+                            let image = gpui::Image::from_bytes(gpui::ImageFormat::Png, bytes);
+                            view.edited_preview =
+                                Some((source.clone(), ImageTransform::new(), Arc::new(image)));
+                        }
+                        view.image_edit_message = Some((source, format!("Saved {}", name)));
+                    }
+                    Err(e) => {
+                        view.image_edit_message = Some((source, format!("Save failed: {}", e)));
+                    }
+                }
+                cx.notify();
+            });
+        })
+        .detach();
+    }
+
+    pub fn next(&mut self, cx: &mut Context<Self>) {
+        let Some(idx) = self.current_index else {
+            return;
+        };
+        self.cancel_summary();
+        let next_idx = self.quick_look.next(&self.entries, idx);
+        self.show_entry_at(next_idx, cx);
+    }
+
+    pub fn previous(&mut self, cx: &mut Context<Self>) {
+        let Some(idx) = self.current_index else {
+            return;
+        };
+        self.cancel_summary();
+        let prev_idx = self.quick_look.previous(&self.entries, idx);
+        self.show_entry_at(prev_idx, cx);
+    }
+
+    fn show_entry_at(&mut self, index: Option<usize>, cx: &mut Context<Self>) {
+        let Some(index) = index else {
+            return;
+        };
+        self.current_index = Some(index);
+        let path = self.entries[index].path.clone();
+        if path.is_dir() {
+            self.load_directory_summary(path, cx);
         }
+        cx.notify();
     }
 
     pub fn zoom_in(&mut self) {
@@ -558,6 +881,7 @@ impl Focusable for QuickLookView {
 
 impl Render for QuickLookView {
     fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let rendered_page = self.request_rendered_page(cx);
         if !self.quick_look.is_visible() {
             return div().id("quick-look-hidden");
         }
@@ -571,6 +895,7 @@ impl Render for QuickLookView {
 
         div()
             .id("quick-look-overlay")
+            .key_context("QuickLook")
             .track_focus(&self.focus_handle)
             .absolute()
             .inset_0()
@@ -588,11 +913,11 @@ impl Render for QuickLookView {
             .on_action(cx.listener(|view, _: &CloseQuickLook, _window, _cx| {
                 view.hide();
             }))
-            .on_action(cx.listener(|view, _: &QuickLookNext, _window, _cx| {
-                view.next();
+            .on_action(cx.listener(|view, _: &QuickLookNext, _window, cx| {
+                view.next(cx);
             }))
-            .on_action(cx.listener(|view, _: &QuickLookPrevious, _window, _cx| {
-                view.previous();
+            .on_action(cx.listener(|view, _: &QuickLookPrevious, _window, cx| {
+                view.previous(cx);
             }))
             .on_action(cx.listener(|view, _: &QuickLookZoomIn, _window, _cx| {
                 view.zoom_in();
@@ -604,7 +929,14 @@ impl Render for QuickLookView {
                 view.reset_zoom();
             }))
             .child(self.render_header(text_primary, text_muted))
-            .child(self.render_content(panel_bg, border_color, text_primary, text_muted))
+            .child(self.render_content(
+                rendered_page,
+                panel_bg,
+                border_color,
+                text_primary,
+                text_muted,
+                cx,
+            ))
             .child(self.render_footer(text_muted))
     }
 }
@@ -658,17 +990,19 @@ impl QuickLookView {
 
     fn render_content(
         &self,
+        rendered_page: Option<PreviewResult>,
         panel_bg: gpui::Rgba,
         border_color: gpui::Rgba,
         text_primary: gpui::Rgba,
         text_muted: gpui::Rgba,
+        cx: &mut Context<Self>,
     ) -> impl IntoElement {
         let content = match self.quick_look.content() {
             QuickLookContent::Image {
                 path,
                 dimensions,
                 format,
-            } => self.render_image_content(path, dimensions.as_ref(), format, text_muted),
+            } => self.render_image_content(path, dimensions.as_ref(), format, text_muted, cx),
             QuickLookContent::Text {
                 content,
                 language,
@@ -684,9 +1018,19 @@ impl QuickLookView {
             QuickLookContent::Document { content, file_type } => {
                 self.render_document_content(content, file_type, panel_bg, text_primary, text_muted)
             }
+            QuickLookContent::Pdf { .. } => self.render_pdf_content(rendered_page, text_muted),
             QuickLookContent::Unsupported { file_type } => {
                 self.render_unsupported_content(file_type, text_muted)
             }
+            QuickLookContent::TooLarge {
+                path,
+                content_type,
+                size,
+                limit,
+            } => self.render_too_large_content(path, *content_type, *size, *limit, text_muted, cx),
+            QuickLookContent::Directory { summary } => {
+                self.render_directory_content(summary, text_primary, text_muted)
+            }
             QuickLookContent::Loading => self.render_loading_content(text_muted),
             QuickLookContent::None => div(),
         };
@@ -712,10 +1056,16 @@ impl QuickLookView {
         dimensions: Option<&(u32, u32)>,
         format: &str,
         text_muted: gpui::Rgba,
+        cx: &mut Context<Self>,
     ) -> gpui::Div {
         let zoom = self.quick_look.zoom_level();
-        let path_str = path.to_string_lossy().to_string();
+        let transform = self.quick_look.image_transform();
+        let source: gpui::ImageSource = match self.edited_preview_for_current() {
+            Some(image) => image.into(),
+            None => SharedString::from(path.to_string_lossy().to_string()).into(),
+        };
         let dims_str = dimensions
+            .map(|dims| transform.transformed_dimensions(*dims))
             .map(|(w, h)| format!("{}×{}", w, h))
             .unwrap_or_else(|| "Unknown".to_string());
         let format_str = format.to_string();
@@ -735,7 +1085,7 @@ impl QuickLookView {
                     .justify_center()
                     .overflow_hidden()
                     .child(
-                        img(SharedString::from(path_str))
+                        img(source)
                             .w(px(600.0 * zoom))
                             .h(px(400.0 * zoom))
                             .object_fit(gpui::ObjectFit::Contain),
@@ -753,6 +1103,138 @@ impl QuickLookView {
                     .child(format!("Zoom: {}%", (zoom * 100.0) as i32)),
             )
             .child(self.render_zoom_controls(text_muted))
+            .child(self.render_image_edit_controls(path, text_muted, cx))
+    }
+
+    fn render_image_edit_controls(
+        &self,
+        path: &Path,
+        text_muted: gpui::Rgba,
+        cx: &mut Context<Self>,
+    ) -> impl IntoElement {
+        let message = self
+            .image_edit_message
+            .as_ref()
+            .filter(|(p, _)| p == path)
+            .map(|(_, message)| message.clone());
+
+        if let Err(e) = editable_image_format(path) {
+            return div()
+                .mt_2()
+                .text_xs()
+                .text_color(text_muted)
+                .child(e.to_string());
+        }
+
+        let has_edits = !self.quick_look.image_transform().is_identity();
+        let confirm_overwrite = has_edits && self.pending_overwrite.as_deref() == Some(path);
+
+        let button = |id: &'static str, label: &'static str| {
+            div()
+                .id(id)
+                .px_3()
+                .py_1()
+                .rounded_md()
+                .cursor_pointer()
+                .bg(gpui::rgba(0xffffff11))
+                .hover(|h| h.bg(gpui::rgba(0xffffff22)))
+                .text_xs()
+                .text_color(text_muted)
+                .child(label)
+        };
+
+        div()
+            .mt_2()
+            .flex()
+            .flex_col()
+            .items_center()
+            .gap_2()
+            .child(
+                div()
+                    .flex()
+                    .items_center()
+                    .gap_2()
+                    .child(button("rotate-left-btn", "⟲ Rotate Left").on_mouse_down(
+                        gpui::MouseButton::Left,
+                        cx.listener(|view, _event, _window, cx| {
+                            cx.stop_propagation();
+                            view.apply_image_edit(QuickLook::rotate_image_left, cx);
+                        }),
+                    ))
+                    .child(button("rotate-right-btn", "⟳ Rotate Right").on_mouse_down(
+                        gpui::MouseButton::Left,
+                        cx.listener(|view, _event, _window, cx| {
+                            cx.stop_propagation();
+                            view.apply_image_edit(QuickLook::rotate_image_right, cx);
+                        }),
+                    ))
+                    .child(button("flip-horizontal-btn", "⇋ Flip H").on_mouse_down(
+                        gpui::MouseButton::Left,
+                        cx.listener(|view, _event, _window, cx| {
+                            cx.stop_propagation();
+                            view.apply_image_edit(QuickLook::flip_image_horizontal, cx);
+                        }),
+                    ))
+                    .child(button("flip-vertical-btn", "⇵ Flip V").on_mouse_down(
+                        gpui::MouseButton::Left,
+                        cx.listener(|view, _event, _window, cx| {
+                            cx.stop_propagation();
+                            view.apply_image_edit(QuickLook::flip_image_vertical, cx);
+                        }),
+                    ))
+                    .when(has_edits && !confirm_overwrite, |this| {
+                        this.child(button("save-image-btn", "Save").on_mouse_down(
+                            gpui::MouseButton::Left,
+                            cx.listener(|view, _event, _window, cx| {
+                                cx.stop_propagation();
+                                view.request_save_image(cx);
+                            }),
+                        ))
+                        .child(button("save-image-copy-btn", "Save Copy").on_mouse_down(
+                            gpui::MouseButton::Left,
+                            cx.listener(|view, _event, _window, cx| {
+                                cx.stop_propagation();
+                                view.save_image_edits(false, cx);
+                            }),
+                        ))
+                        .child(button("discard-image-edits-btn", "Revert").on_mouse_down(
+                            gpui::MouseButton::Left,
+                            cx.listener(|view, _event, _window, cx| {
+                                cx.stop_propagation();
+                                view.discard_image_edits(cx);
+                            }),
+                        ))
+                    }),
+            )
+            .when(confirm_overwrite, |this| {
+                this.child(
+                    div()
+                        .flex()
+                        .items_center()
+                        .gap_2()
+                        .text_xs()
+                        .text_color(gpui::rgb(0xf0b429))
+                        .child("Overwrite the original file? This cannot be undone.")
+                        .child(button("confirm-overwrite-btn", "Overwrite").on_mouse_down(
+                            gpui::MouseButton::Left,
+                            cx.listener(|view, _event, _window, cx| {
+                                cx.stop_propagation();
+                                view.save_image_edits(true, cx);
+                            }),
+                        ))
+                        .child(button("cancel-overwrite-btn", "Cancel").on_mouse_down(
+                            gpui::MouseButton::Left,
+                            cx.listener(|view, _event, _window, cx| {
+                                cx.stop_propagation();
+                                view.pending_overwrite = None;
+                                cx.notify();
+                            }),
+                        )),
+                )
+            })
+            .when_some(message, |this, message| {
+                this.child(div().text_xs().text_color(text_muted).child(message))
+            })
     }
 
     fn render_zoom_controls(&self, text_muted: gpui::Rgba) -> impl IntoElement {
@@ -917,6 +1399,40 @@ impl QuickLookView {
             )
     }
 
+    fn render_pdf_content(
+        &self,
+        rendered_page: Option<PreviewResult>,
+        text_muted: gpui::Rgba,
+    ) -> gpui::Div {
+        let zoom = self.quick_look.zoom_level();
+        let container = div()
+            .size_full()
+            .flex()
+            .flex_col()
+            .items_center()
+            .justify_center()
+            .p_4();
+
+        match rendered_page {
+            Some(PreviewResult::Image(image)) => container.child(
+                img(image)
+                    .w(px(600.0 * zoom))
+                    .h(px(600.0 * zoom))
+                    .object_fit(gpui::ObjectFit::Contain),
+            ),
+            Some(PreviewResult::Failed(message)) => container
+                .gap_4()
+                .child(
+                    svg()
+                        .path("assets/icons/file-text.svg")
+                        .size(px(64.0))
+                        .text_color(text_muted),
+                )
+                .child(div().text_sm().text_color(text_muted).child(message)),
+            _ => container.child(self.render_loading_content(text_muted)),
+        }
+    }
+
     fn render_unsupported_content(&self, file_type: &str, text_muted: gpui::Rgba) -> gpui::Div {
         let file_type_str = file_type.to_string();
 
@@ -941,6 +1457,140 @@ impl QuickLookView {
             )
     }
 
+    fn render_too_large_content(
+        &self,
+        path: &Path,
+        content_type: PreviewContentType,
+        size: u64,
+        limit: u64,
+        text_muted: gpui::Rgba,
+        cx: &mut Context<Self>,
+    ) -> gpui::Div {
+        let theme = theme_colors();
+        let path = path.to_path_buf();
+
+        div()
+            .size_full()
+            .flex()
+            .flex_col()
+            .items_center()
+            .justify_center()
+            .gap_4()
+            .child(
+                svg()
+                    .path("assets/icons/file.svg")
+                    .size(px(64.0))
+                    .text_color(text_muted),
+            )
+            .child(div().text_sm().text_color(text_muted).child(format!(
+                "{} is over the {} limit for {} previews",
+                format_size(size),
+                format_size(limit),
+                content_type.label().to_lowercase()
+            )))
+            .child(
+                div()
+                    .id("quick-look-open-externally")
+                    .px_4()
+                    .py_2()
+                    .rounded_md()
+                    .bg(theme.accent_primary)
+                    .text_sm()
+                    .text_color(theme.text_inverse)
+                    .cursor_pointer()
+                    .on_mouse_down(
+                        MouseButton::Left,
+                        cx.listener(move |view, _, _, cx| {
+                            view.pending_open = Some(path.clone());
+                            cx.notify();
+                        }),
+                    )
+                    .child("Open externally"),
+            )
+    }
+
+    fn render_directory_content(
+        &self,
+        summary: &DirectorySummary,
+        text_primary: gpui::Rgba,
+        text_muted: gpui::Rgba,
+    ) -> gpui::Div {
+        let stat_row = |label: &str, value: String| {
+            div()
+                .flex()
+                .items_center()
+                .justify_between()
+                .py_1()
+                .child(div().text_xs().text_color(text_muted).child(label.to_string()))
+                .child(div().text_sm().text_color(text_primary).child(value))
+        };
+
+        let newest = summary
+            .newest
+            .as_ref()
+            .map(|f| format!("{} ({})", f.name, format_date(f.modified)))
+            .unwrap_or_else(|| "—".to_string());
+        let oldest = summary
+            .oldest
+            .as_ref()
+            .map(|f| format!("{} ({})", f.name, format_date(f.modified)))
+            .unwrap_or_else(|| "—".to_string());
+        let kinds = summary.kinds.clone();
+
+        div()
+            .size_full()
+            .flex()
+            .flex_col()
+            .items_center()
+            .justify_center()
+            .gap_4()
+            .p_6()
+            .child(
+                svg()
+                    .path("assets/icons/folder.svg")
+                    .size(px(64.0))
+                    .text_color(text_muted),
+            )
+            .child(
+                div()
+                    .w(px(420.0))
+                    .flex()
+                    .flex_col()
+                    .child(stat_row(
+                        "Items",
+                        format!(
+                            "{} ({} folders, {} files)",
+                            summary.item_count, summary.subdir_count, summary.file_count
+                        ),
+                    ))
+                    .child(stat_row("Total size", format_size(summary.total_size)))
+                    .child(stat_row("Newest file", newest))
+                    .child(stat_row("Oldest file", oldest)),
+            )
+            .when(!kinds.is_empty(), |this| {
+                this.child(
+                    div()
+                        .w(px(420.0))
+                        .pt_2()
+                        .border_t_1()
+                        .border_color(gpui::rgb(0x30363d))
+                        .flex()
+                        .flex_wrap()
+                        .gap_2()
+                        .children(kinds.into_iter().map(|(kind, count)| {
+                            div()
+                                .px_2()
+                                .py_1()
+                                .rounded_md()
+                                .bg(gpui::rgba(0xffffff11))
+                                .text_xs()
+                                .text_color(text_muted)
+                                .child(format!("{} {}", count, kind.display_name()))
+                        })),
+                )
+            })
+    }
+
     fn render_loading_content(&self, text_muted: gpui::Rgba) -> gpui::Div {
         div()
             .size_full()
@@ -1057,4 +1707,68 @@ mod tests {
         }
         assert!(ql.zoom_level() >= 0.25);
     }
+
+    #[test]
+    fn test_quick_look_directory_shows_summary() {
+        let temp = tempfile::TempDir::new().unwrap();
+        std::fs::write(temp.path().join("a.txt"), "hello").unwrap();
+        let mut ql = QuickLook::new();
+
+        ql.show(temp.path().to_path_buf());
+        assert!(ql.is_directory());
+        assert_eq!(ql.content(), &QuickLookContent::Loading);
+
+        let cancelled = AtomicBool::new(false);
+        let summary = summarize_directory(temp.path(), &cancelled).unwrap();
+        ql.set_directory_summary(summary.clone());
+        assert_eq!(ql.file_size(), 5);
+        assert_eq!(ql.content(), &QuickLookContent::Directory { summary });
+    }
+
+    #[test]
+    fn test_quick_look_navigation_includes_directories() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let file = temp.path().join("a.txt");
+        let folder = temp.path().join("folder");
+        std::fs::write(&file, "hello").unwrap();
+        std::fs::create_dir(&folder).unwrap();
+        let entries = vec![
+            FileEntry::new("a.txt".to_string(), file.clone(), false, 5, SystemTime::now()),
+            FileEntry::new("folder".to_string(), folder.clone(), true, 0, SystemTime::now()),
+        ];
+        let mut ql = QuickLook::new();
+
+        assert_eq!(ql.next(&entries, 0), Some(1));
+        assert_eq!(ql.current_path(), Some(&folder));
+        assert_eq!(ql.content(), &QuickLookContent::Loading);
+
+        assert_eq!(ql.next(&entries, 1), Some(0));
+        assert_eq!(ql.current_path(), Some(&file));
+
+        assert_eq!(ql.previous(&entries, 0), Some(1));
+        assert_eq!(ql.current_path(), Some(&folder));
+        assert_eq!(ql.next(&[], 0), None);
+    }
+
+    #[test]
+    fn test_quick_look_image_edits_reset_on_navigation() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let png = temp.path().join("photo.png");
+        image::RgbaImage::new(4, 2).save(&png).unwrap();
+        let mut ql = QuickLook::new();
+
+        ql.show(png.clone());
+        assert!(ql.can_edit_image());
+        ql.rotate_image_right();
+        ql.flip_image_horizontal();
+        assert!(!ql.image_transform().is_identity());
+
+        ql.show(png);
+        assert!(ql.image_transform().is_identity());
+
+        ql.show(temp.path().join("anim.gif"));
+        assert!(!ql.can_edit_image());
+        ql.rotate_image_left();
+        assert!(ql.image_transform().is_identity());
+    }
 }
@@ -1,18 +1,27 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
 
+use adabraka_ui::components::input::{InputEvent, InputState};
 use gpui::{
-    div, prelude::*, px, svg, App, Context, DragMoveEvent, ExternalPaths, FocusHandle, Focusable,
-    InteractiveElement, IntoElement, MouseButton, ParentElement, Render, SharedString, Styled,
-    Window,
+    actions, div, percentage, prelude::*, px, svg, Animation, AnimationExt, App, Context, Div, DragMoveEvent,
+    Entity, ExternalPaths, FocusHandle, Focusable, InteractiveElement, IntoElement, KeyBinding, MouseButton,
+    ParentElement, Render, ScrollHandle, SharedString, Stateful, Styled, Transformation, Window,
 };
 
+use super::tag_ui::render_tag_dot;
+use super::TooltipLabel;
+use crate::utils::middle_ellipsis;
 use crate::models::{
-    sidebar as sidebar_spacing, theme_colors, Bookmark, BookmarkId, BookmarkManager,
-    CloudStorageManager, Device, DeviceId, DeviceMonitor, DeviceType, Favorite, Favorites, NetworkLocationId,
-    NetworkSidebarState, NetworkStorageManager, SearchQuery, SmartFolder, SmartFolderId,
-    SmartFolderManager, TrashManager, WslDistribution,
+    sidebar as sidebar_spacing, theme_colors, Bookmark, BookmarkId,
+    BookmarkCheck, BookmarkManager, BOOKMARK_CHECK_POLL_INTERVAL, BOOKMARK_CHECK_TIMEOUT, mount_location, CloudStorageManager, Device, DeviceAction, DeviceId, DeviceMonitor, Favorite, FavoriteGroup, FavoriteGroupId,
+    Favorites, FrequentFolders, MAX_FREQUENT_FOLDERS, MountedImages, NetworkError, NetworkLocationId, NetworkSidebarState, NETWORK_CONNECT_TIMEOUT, NetworkStorageManager, SearchQuery, SmartFolder, SmartFolderId,
+    SidebarSections, SmartFolderManager, Tag, TagColor, TagId, TagManager, TagResult, TrashManager,
+    WslDistribution,
 };
 
+
+const SIDEBAR_NAME_MAX_CHARS: usize = 28;
+
 #[derive(Clone)]
 pub struct SidebarItem {
     pub name: String,
@@ -20,6 +29,7 @@ pub struct SidebarItem {
     pub is_dir: bool,
     pub depth: usize,
     pub is_expanded: bool,
+    pub is_loaded: bool,
     pub children: Vec<SidebarItem>,
 }
 
@@ -31,12 +41,100 @@ impl SidebarItem {
             is_dir,
             depth,
             is_expanded: false,
+            is_loaded: false,
             children: Vec::new(),
         }
     }
+
+    pub fn load_children(&mut self, show_hidden: bool) {
+        if self.is_loaded || !self.is_dir {
+            return;
+        }
+
+        let mut children: Vec<SidebarItem> = std::fs::read_dir(&self.path)
+            .map(|read_dir| {
+                read_dir
+                    .flatten()
+                    .filter_map(|entry| {
+                        let path = entry.path();
+                        if !path.is_dir() {
+                            return None;
+                        }
+                        let name = entry.file_name().to_string_lossy().to_string();
+                        if !show_hidden && name.starts_with('.') {
+                            return None;
+                        }
+                        Some(SidebarItem::new(name, path, true, self.depth + 1))
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        children.sort_by(|a, b| a.name.to_lowercase().cmp(&b.name.to_lowercase()));
+        self.children = children;
+        self.is_loaded = true;
+    }
+
+    pub fn reveal(&mut self, target: &Path, show_hidden: bool) -> bool {
+        if target == self.path {
+            return true;
+        }
+        if !self.is_dir || !target.starts_with(&self.path) {
+            return false;
+        }
+
+        self.load_children(show_hidden);
+        self.is_expanded = true;
+
+        self.children
+            .iter_mut()
+            .find(|child| target.starts_with(&child.path))
+            .map(|child| child.reveal(target, show_hidden))
+            .unwrap_or(false)
+    }
+
+    pub fn toggle_expanded(&mut self, target: &Path, show_hidden: bool) -> bool {
+        if target == self.path {
+            if !self.is_expanded {
+                self.load_children(show_hidden);
+            }
+            self.is_expanded = !self.is_expanded;
+            return true;
+        }
+
+        self.children
+            .iter_mut()
+            .filter(|child| target.starts_with(&child.path))
+            .any(|child| child.toggle_expanded(target, show_hidden))
+    }
+
+    pub fn invalidate(&mut self) {
+        self.is_loaded = false;
+        self.children.clear();
+        self.is_expanded = false;
+    }
+
+    pub fn visible_items(&self) -> Vec<&SidebarItem> {
+        let mut items = vec![self];
+        if self.is_expanded {
+            for child in &self.children {
+                items.extend(child.visible_items());
+            }
+        }
+        items
+    }
+
+    pub fn visible_index_of(&self, target: &Path) -> Option<usize> {
+        self.visible_items()
+            .iter()
+            .position(|item| item.path.as_path() == target)
+    }
 }
 
 
+actions!(sidebar, [SelectPreviousItem, SelectNextItem, OpenSelectedItem]);
+
+
 #[derive(Clone)]
 pub struct DraggedFolder {
     pub path: PathBuf,
@@ -76,20 +174,20 @@ pub enum ToolAction {
     Move,
     Paste,
     Delete,
+    FindDuplicates,
 }
 
 pub struct Sidebar {
     favorites: Favorites,
     bookmarks: BookmarkManager,
+    frequent: FrequentFolders,
     smart_folders: SmartFolderManager,
     workspace_root: Option<SidebarItem>,
+    auto_reveal: bool,
+    revealed_path: Option<PathBuf>,
     selected_path: Option<PathBuf>,
     is_drop_target: bool,
-    is_tools_expanded: bool,
-    is_bookmarks_expanded: bool,
-    is_network_expanded: bool,
-    is_devices_expanded: bool,
-    is_smart_folders_expanded: bool,
+    sections: SidebarSections,
     show_hidden_files: bool,
     current_directory: Option<PathBuf>,
     network_manager: NetworkStorageManager,
@@ -112,6 +210,8 @@ impl Sidebar {
 
         let bookmarks = BookmarkManager::load().unwrap_or_else(|_| BookmarkManager::new());
 
+        let frequent = FrequentFolders::load();
+
         let network_manager = NetworkStorageManager::load();
 
         let mut cloud_manager = CloudStorageManager::new();
@@ -126,18 +226,20 @@ impl Sidebar {
         let mut trash_manager = TrashManager::new();
         trash_manager.refresh();
 
+        let settings = crate::models::GlobalSettings::load();
+        let auto_reveal = settings.auto_reveal_in_sidebar;
+
         Self {
             favorites,
             bookmarks,
+            frequent,
             smart_folders,
             workspace_root: None,
+            auto_reveal,
+            revealed_path: None,
             selected_path: None,
             is_drop_target: false,
-            is_tools_expanded: true,
-            is_bookmarks_expanded: true,
-            is_network_expanded: true,
-            is_devices_expanded: true,
-            is_smart_folders_expanded: true,
+            sections: settings.sidebar_sections,
             show_hidden_files: false,
             current_directory: None,
             network_manager,
@@ -148,19 +250,64 @@ impl Sidebar {
     }
 
     pub fn is_tools_expanded(&self) -> bool {
-        self.is_tools_expanded
+        self.sections.tools
     }
 
     pub fn toggle_tools_expanded(&mut self) {
-        self.is_tools_expanded = !self.is_tools_expanded;
+        self.sections.tools = !self.sections.tools;
+        self.save_sections();
     }
 
     pub fn is_bookmarks_expanded(&self) -> bool {
-        self.is_bookmarks_expanded
+        self.sections.bookmarks
     }
 
     pub fn toggle_bookmarks_expanded(&mut self) {
-        self.is_bookmarks_expanded = !self.is_bookmarks_expanded;
+        self.sections.bookmarks = !self.sections.bookmarks;
+        self.save_sections();
+    }
+
+    pub fn is_frequent_expanded(&self) -> bool {
+        self.sections.frequent
+    }
+
+    pub fn toggle_frequent_expanded(&mut self) {
+        self.sections.frequent = !self.sections.frequent;
+        self.save_sections();
+    }
+
+    pub fn is_workspace_expanded(&self) -> bool {
+        self.sections.workspace
+    }
+
+    pub fn set_workspace_expanded(&mut self, expanded: bool) {
+        self.sections.workspace = expanded;
+        self.save_sections();
+    }
+
+    pub fn is_favorites_expanded(&self) -> bool {
+        self.sections.favorites
+    }
+
+    pub fn toggle_favorites_expanded(&mut self) {
+        self.sections.favorites = !self.sections.favorites;
+        self.save_sections();
+    }
+
+    pub fn sections(&self) -> SidebarSections {
+        self.sections
+    }
+
+
+    pub fn set_all_sections_expanded(&mut self, expanded: bool) {
+        self.sections = SidebarSections::all(expanded);
+        self.save_sections();
+    }
+
+    fn save_sections(&self) {
+        let mut settings = crate::models::GlobalSettings::load();
+        settings.sidebar_sections = self.sections;
+        let _ = settings.save();
     }
 
     pub fn bookmarks(&self) -> &BookmarkManager {
@@ -182,6 +329,41 @@ impl Sidebar {
         result
     }
 
+    pub fn repoint_bookmark(
+        &mut self,
+        id: BookmarkId,
+        path: PathBuf,
+    ) -> Result<(), crate::models::BookmarkError> {
+        let result = self.bookmarks.repoint(id, path);
+        if result.is_ok() {
+            let _ = self.bookmarks.save();
+        }
+        result
+    }
+
+    pub fn apply_bookmark_validity(&mut self, results: &[(BookmarkId, bool)]) -> bool {
+        let changed = self.bookmarks.apply_validity(results);
+        if changed {
+            let _ = self.bookmarks.save();
+        }
+        changed
+    }
+
+    pub fn frequent_folders(&self) -> Vec<PathBuf> {
+        self.frequent.ranked(SystemTime::now(), MAX_FREQUENT_FOLDERS)
+    }
+
+    pub fn record_folder_visit(&mut self, path: &Path) {
+        if self.frequent.record_visit(path, SystemTime::now()) {
+            let _ = self.frequent.save();
+        }
+    }
+
+    pub fn clear_frequent_folders(&mut self) {
+        self.frequent.clear();
+        let _ = self.frequent.save();
+    }
+
     pub fn remove_bookmark(
         &mut self,
         id: BookmarkId,
@@ -215,6 +397,14 @@ impl Sidebar {
 
     pub fn toggle_hidden_files(&mut self) {
         self.show_hidden_files = !self.show_hidden_files;
+        if let Some(root) = self.workspace_root.as_mut() {
+            root.invalidate();
+            root.load_children(self.show_hidden_files);
+            root.is_expanded = true;
+        }
+        if self.auto_reveal {
+            self.reveal_current_directory();
+        }
     }
 
     pub fn current_directory(&self) -> Option<&PathBuf> {
@@ -223,6 +413,9 @@ impl Sidebar {
 
     pub fn set_current_directory(&mut self, path: PathBuf) {
         self.current_directory = Some(path);
+        if self.auto_reveal {
+            self.reveal_current_directory();
+        }
     }
 
     pub fn set_workspace_root(&mut self, path: PathBuf) {
@@ -231,13 +424,92 @@ impl Sidebar {
             .and_then(|n| n.to_str())
             .unwrap_or("Root")
             .to_string();
-        self.workspace_root = Some(SidebarItem::new(name, path, true, 0));
+        let mut root = SidebarItem::new(name, path, true, 0);
+        root.load_children(self.show_hidden_files);
+        root.is_expanded = true;
+        self.workspace_root = Some(root);
+        self.revealed_path = None;
+    }
+
+    pub fn workspace_root(&self) -> Option<&SidebarItem> {
+        self.workspace_root.as_ref()
+    }
+
+    pub fn is_auto_reveal(&self) -> bool {
+        self.auto_reveal
+    }
+
+    pub fn set_auto_reveal(&mut self, enabled: bool) {
+        self.auto_reveal = enabled;
+        if enabled {
+            self.reveal_current_directory();
+        }
+    }
+
+    pub fn toggle_auto_reveal(&mut self) {
+        self.set_auto_reveal(!self.auto_reveal);
+    }
+
+    pub fn revealed_path(&self) -> Option<&PathBuf> {
+        self.revealed_path.as_ref()
+    }
+
+    pub fn reveal_current_directory(&mut self) -> Option<usize> {
+        let target = self.current_directory.clone()?;
+        let show_hidden = self.show_hidden_files;
+        let root = self.workspace_root.as_mut()?;
+
+        if root.reveal(&target, show_hidden) {
+            self.revealed_path = Some(target.clone());
+            root.visible_index_of(&target)
+        } else {
+            self.revealed_path = None;
+            None
+        }
+    }
+
+    pub fn toggle_tree_item(&mut self, path: &Path) {
+        let show_hidden = self.show_hidden_files;
+        if let Some(root) = self.workspace_root.as_mut() {
+            root.toggle_expanded(path, show_hidden);
+        }
     }
 
     pub fn set_selected_path(&mut self, path: PathBuf) {
         self.selected_path = Some(path);
     }
 
+
+    pub fn keyboard_targets(&self) -> Vec<PathBuf> {
+        let mut targets = Vec::new();
+        if self.is_workspace_expanded() {
+            if let Some(root) = self.workspace_root() {
+                targets.extend(root.visible_items().into_iter().map(|item| item.path.clone()));
+            }
+        }
+        if self.is_favorites_expanded() {
+            targets.extend(self.favorites.items().iter().map(|favorite| favorite.path.clone()));
+        }
+        targets
+    }
+
+    pub fn move_keyboard_selection(&mut self, backwards: bool) -> Option<&PathBuf> {
+        let targets = self.keyboard_targets();
+        let last = targets.len().checked_sub(1)?;
+        let current = self
+            .selected_path
+            .as_ref()
+            .and_then(|path| targets.iter().position(|target| target == path));
+        let index = match (current, backwards) {
+            (None, false) => 0,
+            (None, true) => last,
+            (Some(index), false) => (index + 1).min(last),
+            (Some(index), true) => index.saturating_sub(1),
+        };
+        self.selected_path = targets.get(index).cloned();
+        self.selected_path.as_ref()
+    }
+
     pub fn favorites(&self) -> &Favorites {
         &self.favorites
     }
@@ -277,16 +549,71 @@ impl Sidebar {
         result
     }
 
+    pub fn move_favorite(&mut self, from: usize, to: usize) -> Result<(), crate::models::FavoritesError> {
+        let result = self.favorites.move_favorite(from, to);
+        if result.is_ok() {
+            let _ = self.favorites.save();
+        }
+        result
+    }
+
+    pub fn move_favorite_to_group(
+        &mut self,
+        index: usize,
+        group: Option<FavoriteGroupId>,
+    ) -> Result<(), crate::models::FavoritesError> {
+        let result = self.favorites.move_to_group(index, group);
+        if result.is_ok() {
+            let _ = self.favorites.save();
+        }
+        result
+    }
+
+    pub fn add_favorite_group(&mut self, name: &str) -> Result<FavoriteGroupId, crate::models::FavoritesError> {
+        let result = self.favorites.add_group(name);
+        if result.is_ok() {
+            let _ = self.favorites.save();
+        }
+        result
+    }
+
+    pub fn rename_favorite_group(
+        &mut self,
+        id: FavoriteGroupId,
+        name: &str,
+    ) -> Result<(), crate::models::FavoritesError> {
+        let result = self.favorites.rename_group(id, name);
+        if result.is_ok() {
+            let _ = self.favorites.save();
+        }
+        result
+    }
+
+    pub fn remove_favorite_group(&mut self, id: FavoriteGroupId) -> Result<(), crate::models::FavoritesError> {
+        let result = self.favorites.remove_group(id).map(|_| ());
+        if result.is_ok() {
+            let _ = self.favorites.save();
+        }
+        result
+    }
+
+    pub fn toggle_favorite_group(&mut self, id: FavoriteGroupId) {
+        if self.favorites.toggle_group_collapsed(id).is_ok() {
+            let _ = self.favorites.save();
+        }
+    }
+
     pub fn set_drop_target(&mut self, is_target: bool) {
         self.is_drop_target = is_target;
     }
 
     pub fn is_network_expanded(&self) -> bool {
-        self.is_network_expanded
+        self.sections.network
     }
 
     pub fn toggle_network_expanded(&mut self) {
-        self.is_network_expanded = !self.is_network_expanded;
+        self.sections.network = !self.sections.network;
+        self.save_sections();
     }
 
     pub fn network_manager(&self) -> &NetworkStorageManager {
@@ -314,11 +641,12 @@ impl Sidebar {
     }
 
     pub fn is_devices_expanded(&self) -> bool {
-        self.is_devices_expanded
+        self.sections.devices
     }
 
     pub fn toggle_devices_expanded(&mut self) {
-        self.is_devices_expanded = !self.is_devices_expanded;
+        self.sections.devices = !self.sections.devices;
+        self.save_sections();
     }
 
     pub fn device_monitor(&self) -> &DeviceMonitor {
@@ -355,11 +683,21 @@ impl Sidebar {
     }
 
     pub fn is_smart_folders_expanded(&self) -> bool {
-        self.is_smart_folders_expanded
+        self.sections.smart_folders
     }
 
     pub fn toggle_smart_folders_expanded(&mut self) {
-        self.is_smart_folders_expanded = !self.is_smart_folders_expanded;
+        self.sections.smart_folders = !self.sections.smart_folders;
+        self.save_sections();
+    }
+
+    pub fn is_tags_expanded(&self) -> bool {
+        self.sections.tags
+    }
+
+    pub fn toggle_tags_expanded(&mut self) {
+        self.sections.tags = !self.sections.tags;
+        self.save_sections();
     }
 
     pub fn smart_folders(&self) -> &SmartFolderManager {
@@ -409,8 +747,10 @@ impl Sidebar {
 pub struct SidebarView {
     sidebar: Sidebar,
     focus_handle: FocusHandle,
+    tree_scroll_handle: ScrollHandle,
     dragging_favorite_index: Option<usize>,
     drop_target_index: Option<usize>,
+    favorite_group_rename: Option<(FavoriteGroupId, Entity<InputState>)>,
     pending_navigation: Option<PathBuf>,
     pending_action: Option<ToolAction>,
     selected_file_count: usize,
@@ -423,15 +763,27 @@ pub struct SidebarView {
     pending_eject_device: Option<DeviceId>,
     pending_mount_device: Option<PathBuf>,
     pending_format_device: Option<Device>,
+    pending_tag_filter: Option<Tag>,
+    pending_network_error: Option<String>,
+    pending_bookmark_error: Option<String>,
+    bookmark_check_running: bool,
+    bookmark_check_queued: bool,
+    tag_menu: Option<TagId>,
+    tag_rename: Option<(TagId, Entity<InputState>)>,
+    mounted_images: MountedImages,
 }
 
 impl SidebarView {
     pub fn new(cx: &mut Context<Self>) -> Self {
+        cx.observe_global::<TagManager>(|_, cx| cx.notify()).detach();
+
         Self {
             sidebar: Sidebar::new(),
             focus_handle: cx.focus_handle(),
+            tree_scroll_handle: ScrollHandle::new(),
             dragging_favorite_index: None,
             drop_target_index: None,
+            favorite_group_rename: None,
             pending_navigation: None,
             pending_action: None,
             selected_file_count: 0,
@@ -444,6 +796,14 @@ impl SidebarView {
             pending_eject_device: None,
             pending_mount_device: None,
             pending_format_device: None,
+            pending_tag_filter: None,
+            pending_network_error: None,
+            pending_bookmark_error: None,
+            bookmark_check_running: false,
+            bookmark_check_queued: false,
+            tag_menu: None,
+            tag_rename: None,
+            mounted_images: MountedImages::new(),
         }
     }
 
@@ -493,6 +853,7 @@ impl SidebarView {
     pub fn add_bookmark_for_current(&mut self, cx: &mut Context<Self>) {
         if let Some(path) = self.sidebar.current_directory.clone() {
             let _ = self.sidebar.add_bookmark(path);
+            self.refresh_bookmark_validity(cx);
             cx.notify();
         }
     }
@@ -512,16 +873,96 @@ impl SidebarView {
 
     fn handle_bookmark_remove(&mut self, id: BookmarkId, cx: &mut Context<Self>) {
         let _ = self.sidebar.remove_bookmark(id);
+        self.context_menu_bookmark_id = None;
+        cx.notify();
+    }
+
+
+    fn toggle_bookmark_fix_menu(&mut self, id: BookmarkId, cx: &mut Context<Self>) {
+        self.context_menu_bookmark_id = if self.context_menu_bookmark_id == Some(id) {
+            None
+        } else {
+            Some(id)
+        };
+        cx.notify();
+    }
+
+
+    fn repoint_bookmark_to_current(&mut self, id: BookmarkId, cx: &mut Context<Self>) {
+        let Some(path) = self.sidebar.current_directory.clone() else {
+            return;
+        };
+        if let Err(e) = self.sidebar.repoint_bookmark(id, path) {
+            self.pending_bookmark_error = Some(format!("Couldn't update bookmark: {}", e));
+        }
+        self.refresh_bookmark_validity(cx);
+        self.context_menu_bookmark_id = None;
         cx.notify();
     }
 
 
+    pub fn refresh_bookmark_validity(&mut self, cx: &mut Context<Self>) {
+        let paths = self.sidebar.bookmarks.paths_to_check();
+        if paths.is_empty() {
+            return;
+        }
+
+        if self.bookmark_check_running {
+            self.bookmark_check_queued = true;
+            return;
+        }
+        self.bookmark_check_running = true;
+
+        let mut check = BookmarkCheck::start(paths, BOOKMARK_CHECK_TIMEOUT);
+        cx.spawn(async move |this, cx| {
+            while !check.poll() {
+                cx.background_executor().timer(BOOKMARK_CHECK_POLL_INTERVAL).await;
+            }
+            let results = check.into_results();
+
+            let _ = this.update(cx, |view, cx| {
+                view.bookmark_check_running = false;
+                if view.sidebar.apply_bookmark_validity(&results) {
+                    cx.notify();
+                }
+                if std::mem::take(&mut view.bookmark_check_queued) {
+                    view.refresh_bookmark_validity(cx);
+                }
+            });
+        })
+        .detach();
+    }
+
+
+    pub fn take_pending_bookmark_error(&mut self) -> Option<String> {
+        self.pending_bookmark_error.take()
+    }
+
+
     fn toggle_bookmarks_section(&mut self, cx: &mut Context<Self>) {
         self.sidebar.toggle_bookmarks_expanded();
         cx.notify();
     }
 
 
+    fn toggle_frequent_section(&mut self, cx: &mut Context<Self>) {
+        self.sidebar.toggle_frequent_expanded();
+        cx.notify();
+    }
+
+
+    pub fn record_folder_visit(&mut self, path: &Path, cx: &mut Context<Self>) {
+        self.sidebar.record_folder_visit(path);
+        cx.notify();
+    }
+
+
+    pub fn clear_frequent_folders(&mut self, cx: &mut Context<Self>) {
+        self.sidebar.clear_frequent_folders();
+        cx.notify();
+    }
+
+
     pub fn take_pending_navigation(&mut self) -> Option<PathBuf> {
         self.pending_navigation.take()
     }
@@ -534,6 +975,117 @@ impl SidebarView {
 
     pub fn set_current_directory(&mut self, path: PathBuf) {
         self.sidebar.set_current_directory(path);
+        if self.sidebar.is_auto_reveal() {
+            self.scroll_to_revealed();
+        }
+    }
+
+
+    pub fn reveal_current_directory(&mut self, cx: &mut Context<Self>) {
+        if !self.sidebar.is_workspace_expanded() {
+            self.sidebar.set_workspace_expanded(true);
+        }
+        self.sidebar.reveal_current_directory();
+        self.scroll_to_revealed();
+        cx.notify();
+    }
+
+
+    pub fn toggle_auto_reveal(&mut self, cx: &mut Context<Self>) {
+        self.sidebar.toggle_auto_reveal();
+        let mut settings = crate::models::GlobalSettings::load();
+        settings.auto_reveal_in_sidebar = self.sidebar.is_auto_reveal();
+        let _ = settings.save();
+        self.scroll_to_revealed();
+        cx.notify();
+    }
+
+
+    pub fn is_auto_reveal(&self) -> bool {
+        self.sidebar.is_auto_reveal()
+    }
+
+
+    fn scroll_to_revealed(&self) {
+        let index = self.sidebar.revealed_path().and_then(|path| {
+            self.sidebar
+                .workspace_root()
+                .and_then(|root| root.visible_index_of(path))
+        });
+        if let Some(index) = index {
+            self.tree_scroll_handle.scroll_to_item(index);
+        }
+    }
+
+
+    fn handle_tree_item_click(&mut self, path: PathBuf, cx: &mut Context<Self>) {
+        self.sidebar.selected_path = Some(path.clone());
+        self.pending_navigation = Some(path);
+        cx.notify();
+    }
+
+
+    pub fn register_key_bindings(cx: &mut App) {
+        cx.bind_keys([
+            KeyBinding::new("up", SelectPreviousItem, Some("Sidebar")),
+            KeyBinding::new("down", SelectNextItem, Some("Sidebar")),
+            KeyBinding::new("enter", OpenSelectedItem, Some("Sidebar")),
+        ]);
+    }
+
+    fn handle_select_previous(&mut self, _: &SelectPreviousItem, _window: &mut Window, cx: &mut Context<Self>) {
+        self.move_keyboard_selection(true, cx);
+    }
+
+    fn handle_select_next(&mut self, _: &SelectNextItem, _window: &mut Window, cx: &mut Context<Self>) {
+        self.move_keyboard_selection(false, cx);
+    }
+
+    fn move_keyboard_selection(&mut self, backwards: bool, cx: &mut Context<Self>) {
+        let Some(path) = self.sidebar.move_keyboard_selection(backwards).cloned() else {
+            return;
+        };
+        let index = self
+            .sidebar
+            .workspace_root()
+            .and_then(|root| root.visible_index_of(&path));
+        if let Some(index) = index {
+            self.tree_scroll_handle.scroll_to_item(index);
+        }
+        cx.notify();
+    }
+
+    fn handle_open_selected(&mut self, _: &OpenSelectedItem, _window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(path) = self.sidebar.selected_path.clone() {
+            self.pending_navigation = Some(path);
+            cx.notify();
+        }
+    }
+
+
+    fn handle_tree_item_toggle(&mut self, path: PathBuf, cx: &mut Context<Self>) {
+        self.sidebar.toggle_tree_item(&path);
+        cx.notify();
+    }
+
+
+    fn toggle_tree_section(&mut self, cx: &mut Context<Self>) {
+        let expanded = self.sidebar.is_workspace_expanded();
+        self.sidebar.set_workspace_expanded(!expanded);
+        cx.notify();
+    }
+
+
+    fn toggle_favorites_section(&mut self, cx: &mut Context<Self>) {
+        self.sidebar.toggle_favorites_expanded();
+        cx.notify();
+    }
+
+
+    pub fn toggle_all_sections(&mut self, cx: &mut Context<Self>) {
+        let expand = !self.sidebar.sections().any_expanded();
+        self.sidebar.set_all_sections_expanded(expand);
+        cx.notify();
     }
 
 
@@ -547,6 +1099,14 @@ impl SidebarView {
     }
 
 
+    pub fn set_show_hidden_files(&mut self, show: bool, cx: &mut Context<Self>) {
+        if self.sidebar.show_hidden_files() != show {
+            self.sidebar.toggle_hidden_files();
+            cx.notify();
+        }
+    }
+
+
     pub fn toggle_hidden_files(&mut self, cx: &mut Context<Self>) {
         self.sidebar.toggle_hidden_files();
         self.pending_action = Some(ToolAction::ToggleHiddenFiles);
@@ -585,22 +1145,75 @@ impl SidebarView {
         _window: &mut Window,
         cx: &mut Context<Self>,
     ) {
-        let _ = self.sidebar.network_manager_mut().connect(id);
-
-        let mount_point = self
+        let mounted = self
             .sidebar
             .network_manager()
             .get_location(id)
+            .filter(|loc| loc.is_connected())
             .and_then(|loc| loc.mount_point.clone());
+        if let Some(path) = mounted {
+            self.navigate_to_mount(path, cx);
+            return;
+        }
 
-        if let Some(path) = mount_point {
-            self.sidebar.selected_path = Some(path.clone());
-            self.pending_navigation = Some(path);
-        }
+        let config = match self.sidebar.network_manager_mut().begin_connect(id) {
+            Ok(config) => config,
+            Err(NetworkError::ConnectionInProgress) => return,
+            Err(e) => {
+                self.pending_network_error = Some(e.to_string());
+                cx.notify();
+                return;
+            }
+        };
+        cx.notify();
+
+        cx.spawn(async move |this, cx| {
+            let mount_config = config.clone();
+            let result = cx
+                .background_executor()
+                .spawn(async move { mount_location(&mount_config, NETWORK_CONNECT_TIMEOUT) })
+                .await;
+
+            let _ = this.update(cx, |view, cx| {
+                match view.sidebar.network_manager_mut().finish_connect(id, result) {
+                    Ok(()) => {
+                        let _ = view.sidebar.network_manager().save();
+                        let mount_point = view
+                            .sidebar
+                            .network_manager()
+                            .get_location(id)
+                            .and_then(|loc| loc.mount_point.clone());
+                        if let Some(path) = mount_point {
+                            view.navigate_to_mount(path, cx);
+                        }
+                    }
+                    Err(e) => {
+                        view.pending_network_error = Some(format!(
+                            "Couldn't connect to {}: {}",
+                            config.display_name(),
+                            e
+                        ));
+                    }
+                }
+                cx.notify();
+            });
+        })
+        .detach();
+    }
+
+
+    fn navigate_to_mount(&mut self, path: PathBuf, cx: &mut Context<Self>) {
+        self.sidebar.selected_path = Some(path.clone());
+        self.pending_navigation = Some(path);
         cx.notify();
     }
 
 
+    pub fn take_pending_network_error(&mut self) -> Option<String> {
+        self.pending_network_error.take()
+    }
+
+
     pub fn show_network_dialog(&mut self, cx: &mut Context<Self>) {
         self.show_network_dialog = true;
         cx.notify();
@@ -678,6 +1291,114 @@ impl SidebarView {
     }
 
 
+    fn toggle_tags_section(&mut self, cx: &mut Context<Self>) {
+        self.sidebar.toggle_tags_expanded();
+        cx.notify();
+    }
+
+
+    fn handle_tag_click(&mut self, tag: Tag, cx: &mut Context<Self>) {
+        self.tag_menu = None;
+        self.pending_tag_filter = Some(tag);
+        cx.notify();
+    }
+
+
+    pub fn take_pending_tag_filter(&mut self) -> Option<Tag> {
+        self.pending_tag_filter.take()
+    }
+
+
+    fn toggle_tag_menu(&mut self, id: TagId, cx: &mut Context<Self>) {
+        self.tag_rename = None;
+        self.tag_menu = if self.tag_menu == Some(id) {
+            None
+        } else {
+            Some(id)
+        };
+        cx.notify();
+    }
+
+
+    pub fn is_renaming_tag(&self, window: &Window, cx: &App) -> bool {
+        self.tag_rename
+            .as_ref()
+            .is_some_and(|(_, input)| input.read(cx).focus_handle(cx).is_focused(window))
+    }
+
+
+    fn start_tag_rename(&mut self, tag: Tag, cx: &mut Context<Self>) {
+        let input = cx.new(|cx| {
+            let mut state = InputState::new(cx);
+            state.content = tag.name.clone().into();
+            state.select_on_focus = true;
+            state
+        });
+
+        cx.subscribe(&input, |view: &mut Self, _, event: &InputEvent, cx| {
+            if let InputEvent::Enter = event {
+                view.commit_tag_rename(cx);
+            }
+        })
+        .detach();
+
+        self.tag_menu = None;
+        self.tag_rename = Some((tag.id, input));
+        cx.notify();
+    }
+
+
+    fn commit_tag_rename(&mut self, cx: &mut Context<Self>) {
+        let Some((id, input)) = self.tag_rename.as_ref() else {
+            return;
+        };
+        let id = *id;
+        let name = input.read(cx).content.trim().to_string();
+
+        if name.is_empty() || Self::update_tags(cx, |tags| tags.rename_tag(id, name)) {
+            self.tag_rename = None;
+        }
+        cx.notify();
+    }
+
+
+    fn cancel_tag_rename(&mut self, cx: &mut Context<Self>) {
+        self.tag_rename = None;
+        cx.notify();
+    }
+
+
+    fn recolor_tag(&mut self, id: TagId, color: TagColor, cx: &mut Context<Self>) {
+        Self::update_tags(cx, |tags| tags.set_tag_color(id, color));
+        self.tag_menu = None;
+        cx.notify();
+    }
+
+
+    fn delete_tag(&mut self, id: TagId, cx: &mut Context<Self>) {
+        Self::update_tags(cx, |tags| tags.delete_tag(id));
+        self.tag_menu = None;
+        cx.notify();
+    }
+
+
+    fn update_tags(
+        cx: &mut Context<Self>,
+        update: impl FnOnce(&mut TagManager) -> TagResult<()>,
+    ) -> bool {
+        if !cx.has_global::<TagManager>() {
+            return false;
+        }
+        cx.update_global::<TagManager, _>(|tags, _| {
+            let result = update(tags);
+            if result.is_ok() {
+                let _ = tags.save();
+            }
+            result.is_ok()
+        })
+    }
+
+
     pub fn create_smart_folder(
         &mut self,
         name: String,
@@ -725,16 +1446,24 @@ impl SidebarView {
 
 
     fn handle_device_click(&mut self, path: PathBuf, _window: &mut Window, cx: &mut Context<Self>) {
-        if path.starts_with("/dev/") {
-            self.pending_mount_device = Some(path);
-        } else {
-            self.sidebar.selected_path = Some(path.clone());
-            self.pending_navigation = Some(path);
-        }
+        self.sidebar.selected_path = Some(path.clone());
+        self.pending_navigation = Some(path);
         cx.notify();
     }
 
 
+    fn handle_device_action(&mut self, device: &Device, action: DeviceAction, window: &mut Window, cx: &mut Context<Self>) {
+        match action {
+            DeviceAction::Open => self.handle_device_click(device.path.clone(), window, cx),
+            DeviceAction::Mount => {
+                self.pending_mount_device = Some(device.path.clone());
+                cx.notify();
+            }
+            DeviceAction::Eject => self.handle_device_eject(device.id, cx),
+        }
+    }
+
+
     pub fn refresh_devices(&mut self, cx: &mut Context<Self>) {
         self.sidebar.refresh_devices();
         cx.notify();
@@ -746,6 +1475,12 @@ impl SidebarView {
     }
 
 
+    pub fn set_mounted_images(&mut self, images: MountedImages, cx: &mut Context<Self>) {
+        self.mounted_images = images;
+        cx.notify();
+    }
+
+
     pub fn wsl_distributions(&self) -> &[WslDistribution] {
         self.sidebar.wsl_distributions()
     }
@@ -839,12 +1574,81 @@ impl SidebarView {
     }
 
     fn handle_reorder_drop(&mut self, from: usize, to: usize, cx: &mut Context<Self>) {
-        let _ = self.sidebar.reorder_favorites(from, to);
+        let _ = self.sidebar.move_favorite(from, to);
+        self.dragging_favorite_index = None;
+        self.drop_target_index = None;
+        cx.notify();
+    }
+
+    fn handle_group_drop(&mut self, group: FavoriteGroupId, path: PathBuf, cx: &mut Context<Self>) {
+        if self.sidebar.favorites.find_index(&path).is_none() && path.is_dir() {
+            let _ = self.sidebar.add_favorite(path.clone());
+        }
+        if let Some(index) = self.sidebar.favorites.find_index(&path) {
+            let _ = self.sidebar.move_favorite_to_group(index, Some(group));
+        }
+        self.sidebar.set_drop_target(false);
         self.dragging_favorite_index = None;
         self.drop_target_index = None;
         cx.notify();
     }
 
+    fn add_favorite_group(&mut self, cx: &mut Context<Self>) {
+        let name = format!("Group {}", self.sidebar.favorites.groups().len() + 1);
+        if let Ok(id) = self.sidebar.add_favorite_group(&name) {
+            self.start_favorite_group_rename(id, name, cx);
+        }
+    }
+
+    pub fn is_renaming_favorite_group(&self, window: &Window, cx: &App) -> bool {
+        self.favorite_group_rename
+            .as_ref()
+            .is_some_and(|(_, input)| input.read(cx).focus_handle(cx).is_focused(window))
+    }
+
+    fn start_favorite_group_rename(&mut self, id: FavoriteGroupId, name: String, cx: &mut Context<Self>) {
+        let input = cx.new(|cx| {
+            let mut state = InputState::new(cx);
+            state.content = name.into();
+            state.select_on_focus = true;
+            state
+        });
+
+        cx.subscribe(&input, |view: &mut Self, _, event: &InputEvent, cx| {
+            if let InputEvent::Enter = event {
+                view.commit_favorite_group_rename(cx);
+            }
+        })
+        .detach();
+
+        self.favorite_group_rename = Some((id, input));
+        cx.notify();
+    }
+
+    fn commit_favorite_group_rename(&mut self, cx: &mut Context<Self>) {
+        let Some((id, input)) = self.favorite_group_rename.as_ref() else {
+            return;
+        };
+        let id = *id;
+        let name = input.read(cx).content.trim().to_string();
+
+        if name.is_empty() || self.sidebar.rename_favorite_group(id, &name).is_ok() {
+            self.favorite_group_rename = None;
+        }
+        cx.notify();
+    }
+
+    fn cancel_favorite_group_rename(&mut self, cx: &mut Context<Self>) {
+        self.favorite_group_rename = None;
+        cx.notify();
+    }
+
+    fn remove_favorite_group(&mut self, id: FavoriteGroupId, cx: &mut Context<Self>) {
+        let _ = self.sidebar.remove_favorite_group(id);
+        self.favorite_group_rename = None;
+        cx.notify();
+    }
+
     fn get_icon_for_favorite(&self, index: usize, path: &PathBuf) -> &'static str {
         if let Some(home) = dirs::home_dir() {
             if path == &home {
@@ -906,6 +1710,7 @@ impl Render for SidebarView {
         let show_hidden = self.sidebar.show_hidden_files();
         let has_selection = self.selected_file_count > 0;
         let has_clipboard = self.has_clipboard;
+        let all_collapsed = !self.sidebar.sections().any_expanded();
 
         let section_gap = px(sidebar_spacing::SECTION_GAP);
         let item_padding_x = px(sidebar_spacing::ITEM_PADDING_X);
@@ -914,6 +1719,11 @@ impl Render for SidebarView {
 
         div()
             .id("sidebar-content")
+            .key_context("Sidebar")
+            .track_focus(&self.focus_handle)
+            .on_action(cx.listener(Self::handle_select_previous))
+            .on_action(cx.listener(Self::handle_select_next))
+            .on_action(cx.listener(Self::handle_open_selected))
             .size_full()
             .bg(bg_dark)
             .flex()
@@ -926,6 +1736,32 @@ impl Render for SidebarView {
                     .flex_col()
                     .flex_shrink_0()
                     .min_h_full()
+                    .child(
+                        div()
+                            .flex()
+                            .justify_end()
+                            .mb_1()
+                            .child(
+                                div()
+                                    .id("sidebar-toggle-all-sections")
+                                    .px_2()
+                                    .text_xs()
+                                    .text_color(label_color)
+                                    .cursor_pointer()
+                                    .hover(|h| h.text_color(text_light))
+                                    .on_mouse_down(
+                                        MouseButton::Left,
+                                        cx.listener(|view, _event, _window, cx| {
+                                            view.toggle_all_sections(cx);
+                                        }),
+                                    )
+                                    .child(if all_collapsed {
+                                        "Expand All"
+                                    } else {
+                                        "Collapse All"
+                                    }),
+                            ),
+                    )
                     .child(self.render_tools_section(
                         label_color,
                         text_gray,
@@ -934,6 +1770,15 @@ impl Render for SidebarView {
                         icon_blue,
                         cx,
                     ))
+                    .child(self.render_workspace_tree_section(
+                        label_color,
+                        text_gray,
+                        text_light,
+                        hover_bg,
+                        selected_bg,
+                        icon_blue,
+                        cx,
+                    ))
                     .child(self.render_devices_section(
                         label_color,
                         text_gray,
@@ -962,6 +1807,14 @@ impl Render for SidebarView {
                         icon_blue,
                         cx,
                     ))
+                    .child(self.render_tags_section(
+                        label_color,
+                        text_gray,
+                        text_light,
+                        hover_bg,
+                        selected_bg,
+                        cx,
+                    ))
                     .child(self.render_bookmarks_section(
                         label_color,
                         text_gray,
@@ -972,6 +1825,15 @@ impl Render for SidebarView {
                         warning_color,
                         cx,
                     ))
+                    .child(self.render_frequent_section(
+                        label_color,
+                        text_gray,
+                        text_light,
+                        hover_bg,
+                        selected_bg,
+                        icon_blue,
+                        cx,
+                    ))
                     .child(self.render_favorites_section(
                         label_color,
                         text_gray,
@@ -1036,6 +1898,202 @@ impl SidebarView {
         }
     }
 
+    fn render_workspace_tree_section(
+        &self,
+        label_color: gpui::Rgba,
+        text_gray: gpui::Rgba,
+        text_light: gpui::Rgba,
+        hover_bg: gpui::Rgba,
+        selected_bg: gpui::Rgba,
+        icon_blue: gpui::Rgba,
+        cx: &mut Context<Self>,
+    ) -> impl IntoElement {
+        let is_expanded = self.sidebar.is_workspace_expanded();
+        let auto_reveal = self.sidebar.is_auto_reveal();
+        let revealed_path = self.sidebar.revealed_path().cloned();
+        let selected_path = self.sidebar.selected_path.clone();
+        let items: Vec<SidebarItem> = self
+            .sidebar
+            .workspace_root()
+            .map(|root| root.visible_items().into_iter().cloned().collect())
+            .unwrap_or_default();
+
+        div()
+            .mb_4()
+            .child(
+                div()
+                    .text_xs()
+                    .font_weight(gpui::FontWeight::BOLD)
+                    .text_color(label_color)
+                    .mb_2()
+                    .px_2()
+                    .flex()
+                    .items_center()
+                    .justify_between()
+                    .child(
+                        div()
+                            .id("workspace-tree-header")
+                            .flex_1()
+                            .cursor_pointer()
+                            .on_mouse_down(
+                                MouseButton::Left,
+                                cx.listener(|view, _event, _window, cx| {
+                                    view.toggle_tree_section(cx);
+                                }),
+                            )
+                            .child("FOLDERS"),
+                    )
+                    .child(
+                        div()
+                            .flex()
+                            .items_center()
+                            .gap_2()
+                            .child(
+                                div()
+                                    .id("reveal-current-btn")
+                                    .cursor_pointer()
+                                    .on_mouse_down(
+                                        MouseButton::Left,
+                                        cx.listener(|view, _event, _window, cx| {
+                                            view.reveal_current_directory(cx);
+                                        }),
+                                    )
+                                    .child(
+                                        svg()
+                                            .path("assets/icons/folder-search.svg")
+                                            .size(px(12.0))
+                                            .text_color(label_color),
+                                    ),
+                            )
+                            .child(
+                                div()
+                                    .id("auto-reveal-toggle")
+                                    .cursor_pointer()
+                                    .on_mouse_down(
+                                        MouseButton::Left,
+                                        cx.listener(|view, _event, _window, cx| {
+                                            view.toggle_auto_reveal(cx);
+                                        }),
+                                    )
+                                    .child(
+                                        svg()
+                                            .path(if auto_reveal {
+                                                "assets/icons/eye.svg"
+                                            } else {
+                                                "assets/icons/eye-off.svg"
+                                            })
+                                            .size(px(12.0))
+                                            .text_color(if auto_reveal {
+                                                icon_blue
+                                            } else {
+                                                label_color
+                                            }),
+                                    ),
+                            )
+                            .child(
+                                svg()
+                                    .path(if is_expanded {
+                                        "assets/icons/chevron-down.svg"
+                                    } else {
+                                        "assets/icons/chevron-right.svg"
+                                    })
+                                    .size(px(12.0))
+                                    .text_color(label_color),
+                            ),
+                    ),
+            )
+            .when(is_expanded, |s| {
+                s.child(
+                    div()
+                        .id("workspace-tree-items")
+                        .flex()
+                        .flex_col()
+                        .gap_0p5()
+                        .p_1()
+                        .max_h(px(240.0))
+                        .overflow_y_scroll()
+                        .track_scroll(&self.tree_scroll_handle)
+                        .children(items.into_iter().enumerate().map(|(ix, item)| {
+                            let is_active = revealed_path.as_ref() == Some(&item.path)
+                                || selected_path.as_ref() == Some(&item.path);
+                            let has_children = !item.is_loaded || !item.children.is_empty();
+                            let click_path = item.path.clone();
+                            let toggle_path = item.path.clone();
+                            let name_truncated = item.name.chars().count() > SIDEBAR_NAME_MAX_CHARS;
+
+                            div()
+                                .id(SharedString::from(format!("tree-item-{}", ix)))
+                                .when(name_truncated, |s| s.tooltip_label(item.name.clone()))
+                                .flex()
+                                .items_center()
+                                .gap_1()
+                                .pl(px(8.0 + item.depth as f32 * 12.0))
+                                .pr_2()
+                                .py_1()
+                                .rounded_md()
+                                .cursor_pointer()
+                                .text_sm()
+                                .when(is_active, |s| s.bg(selected_bg).text_color(text_light))
+                                .when(!is_active, |s| {
+                                    s.text_color(text_gray)
+                                        .hover(|h| h.bg(hover_bg).text_color(text_light))
+                                })
+                                .on_mouse_down(
+                                    MouseButton::Left,
+                                    cx.listener(move |view, _event, _window, cx| {
+                                        view.handle_tree_item_click(click_path.clone(), cx);
+                                    }),
+                                )
+                                .child(
+                                    div()
+                                        .id(SharedString::from(format!("tree-toggle-{}", ix)))
+                                        .size(px(12.0))
+                                        .flex_shrink_0()
+                                        .when(has_children, |d| {
+                                            d.on_mouse_down(
+                                                MouseButton::Left,
+                                                cx.listener(move |view, _event, _window, cx| {
+                                                    cx.stop_propagation();
+                                                    view.handle_tree_item_toggle(
+                                                        toggle_path.clone(),
+                                                        cx,
+                                                    );
+                                                }),
+                                            )
+                                            .child(
+                                                svg()
+                                                    .path(if item.is_expanded {
+                                                        "assets/icons/chevron-down.svg"
+                                                    } else {
+                                                        "assets/icons/chevron-right.svg"
+                                                    })
+                                                    .size(px(12.0))
+                                                    .text_color(text_gray),
+                                            )
+                                        }),
+                                )
+                                .child(
+                                    svg()
+                                        .path(if item.is_expanded {
+                                            "assets/icons/folder-open.svg"
+                                        } else {
+                                            "assets/icons/folder.svg"
+                                        })
+                                        .size(px(14.0))
+                                        .text_color(if is_active { text_light } else { icon_blue }),
+                                )
+                                .child(
+                                    div()
+                                        .flex_1()
+                                        .overflow_hidden()
+                                        .truncate()
+                                        .child(middle_ellipsis(&item.name, SIDEBAR_NAME_MAX_CHARS)),
+                                )
+                        })),
+                )
+            })
+    }
+
     fn render_smart_folders_section(
         &self,
         label_color: gpui::Rgba,
@@ -1195,7 +2253,474 @@ impl SidebarView {
             })
     }
 
-    fn render_bookmarks_section(
+    fn render_tags_section(
+        &self,
+        label_color: gpui::Rgba,
+        text_gray: gpui::Rgba,
+        text_light: gpui::Rgba,
+        hover_bg: gpui::Rgba,
+        selected_bg: gpui::Rgba,
+        cx: &mut Context<Self>,
+    ) -> impl IntoElement {
+        let is_expanded = self.sidebar.is_tags_expanded();
+        let tag_counts = cx
+            .try_global::<TagManager>()
+            .map(|tags| tags.tag_counts())
+            .unwrap_or_default();
+        let tag_menu = self.tag_menu;
+        let tag_rename = self.tag_rename.clone();
+
+        div()
+            .mb_4()
+            .child(
+                div()
+                    .id("tags-header")
+                    .text_xs()
+                    .font_weight(gpui::FontWeight::BOLD)
+                    .text_color(label_color)
+                    .mb_2()
+                    .px_2()
+                    .flex()
+                    .items_center()
+                    .justify_between()
+                    .cursor_pointer()
+                    .on_mouse_down(
+                        MouseButton::Left,
+                        cx.listener(|view, _event, _window, cx| {
+                            view.toggle_tags_section(cx);
+                        }),
+                    )
+                    .child("TAGS")
+                    .child(
+                        svg()
+                            .path(if is_expanded {
+                                "assets/icons/chevron-down.svg"
+                            } else {
+                                "assets/icons/chevron-right.svg"
+                            })
+                            .size(px(12.0))
+                            .text_color(label_color),
+                    ),
+            )
+            .when(is_expanded, |s| {
+                s.child(
+                    div()
+                        .flex()
+                        .flex_col()
+                        .gap_0p5()
+                        .p_1()
+                        .when(tag_counts.is_empty(), |s| {
+                            s.child(
+                                div()
+                                    .px_2()
+                                    .py_1p5()
+                                    .text_sm()
+                                    .text_color(text_gray)
+                                    .opacity(0.7)
+                                    .child("No tags yet"),
+                            )
+                        })
+                        .children(tag_counts.into_iter().map(|(tag, count)| {
+                            let tag_id = tag.id;
+                            let is_menu_open = tag_menu == Some(tag_id);
+                            let rename_input = tag_rename
+                                .as_ref()
+                                .filter(|(id, _)| *id == tag_id)
+                                .map(|(_, input)| input.clone());
+                            let is_renaming = rename_input.is_some();
+                            let click_tag = tag.clone();
+
+                            div()
+                                .flex()
+                                .flex_col()
+                                .child(
+                                    div()
+                                        .id(SharedString::from(format!("sidebar-tag-{}", tag_id.0)))
+                                        .flex()
+                                        .items_center()
+                                        .gap_3()
+                                        .px_2()
+                                        .py_1p5()
+                                        .rounded_md()
+                                        .cursor_pointer()
+                                        .text_sm()
+                                        .when(is_menu_open, |s| {
+                                            s.bg(selected_bg).text_color(text_light)
+                                        })
+                                        .when(!is_menu_open, |s| {
+                                            s.text_color(text_gray)
+                                                .hover(|h| h.bg(hover_bg).text_color(text_light))
+                                        })
+                                        .when(!is_renaming, |s| {
+                                            s.on_mouse_down(
+                                                MouseButton::Left,
+                                                cx.listener(move |view, _event, _window, cx| {
+                                                    view.handle_tag_click(click_tag.clone(), cx);
+                                                }),
+                                            )
+                                        })
+                                        .on_mouse_down(
+                                            MouseButton::Right,
+                                            cx.listener(move |view, _event, _window, cx| {
+                                                view.toggle_tag_menu(tag_id, cx);
+                                            }),
+                                        )
+                                        .child(render_tag_dot(tag.color))
+                                        .child(match rename_input {
+                                            Some(input) => div()
+                                                .flex_1()
+                                                .flex()
+                                                .items_center()
+                                                .gap_1()
+                                                .child(div().flex_1().child(input))
+                                                .child(
+                                                    div()
+                                                        .id(SharedString::from(format!(
+                                                            "sidebar-tag-rename-cancel-{}",
+                                                            tag_id.0
+                                                        )))
+                                                        .px_1()
+                                                        .text_xs()
+                                                        .cursor_pointer()
+                                                        .hover(|h| h.text_color(text_light))
+                                                        .on_mouse_down(
+                                                            MouseButton::Left,
+                                                            cx.listener(|view, _event, _window, cx| {
+                                                                view.cancel_tag_rename(cx);
+                                                            }),
+                                                        )
+                                                        .child("✕"),
+                                                ),
+                                            None => div()
+                                                .flex_1()
+                                                .overflow_hidden()
+                                                .child(tag.name.clone()),
+                                        })
+                                        .child(
+                                            div()
+                                                .text_xs()
+                                                .text_color(text_gray)
+                                                .opacity(0.6)
+                                                .child(count.to_string()),
+                                        ),
+                                )
+                                .when(is_menu_open, |s| {
+                                    s.child(self.render_tag_menu(
+                                        tag.clone(),
+                                        text_gray,
+                                        text_light,
+                                        hover_bg,
+                                        cx,
+                                    ))
+                                })
+                        })),
+                )
+            })
+    }
+
+    fn render_tag_menu(
+        &self,
+        tag: Tag,
+        text_gray: gpui::Rgba,
+        text_light: gpui::Rgba,
+        hover_bg: gpui::Rgba,
+        cx: &mut Context<Self>,
+    ) -> impl IntoElement {
+        let tag_id = tag.id;
+        let current_color = tag.color;
+        let menu_item = |id: &str| {
+            div()
+                .id(SharedString::from(format!("{}-{}", id, tag_id.0)))
+                .px_2()
+                .py_1()
+                .rounded_md()
+                .cursor_pointer()
+                .text_xs()
+                .text_color(text_gray)
+                .hover(|h| h.bg(hover_bg).text_color(text_light))
+        };
+
+        div()
+            .ml(px(26.0))
+            .mb_1()
+            .flex()
+            .flex_col()
+            .gap_0p5()
+            .child(
+                div()
+                    .flex()
+                    .items_center()
+                    .gap_1()
+                    .px_2()
+                    .py_1()
+                    .children(TagColor::all().iter().map(|color| {
+                        let color = *color;
+                        let is_current = color == current_color;
+
+                        div()
+                            .id(SharedString::from(format!(
+                                "sidebar-tag-color-{}-{}",
+                                tag_id.0,
+                                color.display_name()
+                            )))
+                            .p_0p5()
+                            .rounded_full()
+                            .cursor_pointer()
+                            .when(is_current, |s| s.border_1().border_color(text_light))
+                            .on_mouse_down(
+                                MouseButton::Left,
+                                cx.listener(move |view, _event, _window, cx| {
+                                    view.recolor_tag(tag_id, color, cx);
+                                }),
+                            )
+                            .child(render_tag_dot(color))
+                    })),
+            )
+            .child(
+                menu_item("sidebar-tag-rename")
+                    .on_mouse_down(
+                        MouseButton::Left,
+                        cx.listener(move |view, _event, _window, cx| {
+                            view.start_tag_rename(tag.clone(), cx);
+                        }),
+                    )
+                    .child("Rename..."),
+            )
+            .child(
+                menu_item("sidebar-tag-delete")
+                    .on_mouse_down(
+                        MouseButton::Left,
+                        cx.listener(move |view, _event, _window, cx| {
+                            view.delete_tag(tag_id, cx);
+                        }),
+                    )
+                    .child("Delete Tag"),
+            )
+    }
+
+    fn render_bookmarks_section(
+        &self,
+        label_color: gpui::Rgba,
+        text_gray: gpui::Rgba,
+        text_light: gpui::Rgba,
+        hover_bg: gpui::Rgba,
+        selected_bg: gpui::Rgba,
+        icon_blue: gpui::Rgba,
+        warning_color: gpui::Rgba,
+        cx: &mut Context<Self>,
+    ) -> impl IntoElement {
+        let is_expanded = self.sidebar.is_bookmarks_expanded();
+        let bookmarks = self.sidebar.bookmarks.bookmarks().to_vec();
+        let selected_path = self.sidebar.selected_path.clone();
+        let fix_menu = self.context_menu_bookmark_id;
+
+        div()
+            .mb_4()
+            .child(
+                div()
+                    .id("bookmarks-header")
+                    .text_xs()
+                    .font_weight(gpui::FontWeight::BOLD)
+                    .text_color(label_color)
+                    .mb_2()
+                    .px_2()
+                    .flex()
+                    .items_center()
+                    .justify_between()
+                    .cursor_pointer()
+                    .on_mouse_down(
+                        MouseButton::Left,
+                        cx.listener(|view, _event, _window, cx| {
+                            view.toggle_bookmarks_section(cx);
+                        }),
+                    )
+                    .child("BOOKMARKS")
+                    .child(
+                        svg()
+                            .path(if is_expanded {
+                                "assets/icons/chevron-down.svg"
+                            } else {
+                                "assets/icons/chevron-right.svg"
+                            })
+                            .size(px(12.0))
+                            .text_color(label_color),
+                    ),
+            )
+            .when(is_expanded, |s| {
+                s.child(
+                    div()
+                        .flex()
+                        .flex_col()
+                        .gap_0p5()
+                        .p_1()
+                        .when(bookmarks.is_empty(), |s| {
+                            s.child(
+                                div()
+                                    .px_2()
+                                    .py_1p5()
+                                    .text_sm()
+                                    .text_color(text_gray)
+                                    .opacity(0.7)
+                                    .child("No bookmarks yet")
+                                    .child(
+                                        div()
+                                            .text_xs()
+                                            .text_color(text_gray)
+                                            .opacity(0.5)
+                                            .mt_1()
+                                            .child("Press ⌘D to bookmark current folder"),
+                                    ),
+                            )
+                        })
+                        .children(bookmarks.into_iter().map(|bookmark| {
+                            let is_selected = selected_path.as_ref() == Some(&bookmark.path);
+                            let path_clone = bookmark.path.clone();
+                            let is_valid = bookmark.is_valid;
+                            let bookmark_id = bookmark.id;
+                            let is_menu_open = !is_valid && fix_menu == Some(bookmark_id);
+                            let shortcut_display = bookmark.shortcut.as_ref().map(|s| s.display());
+
+                            div()
+                                .flex()
+                                .flex_col()
+                                .child(
+                                    div()
+                                        .id(SharedString::from(format!("bookmark-{}", bookmark.id.0)))
+                                        .when(bookmark.name.chars().count() > SIDEBAR_NAME_MAX_CHARS, |s| {
+                                            s.tooltip_label(bookmark.name.clone())
+                                        })
+                                        .flex()
+                                        .items_center()
+                                        .gap_3()
+                                        .px_2()
+                                        .py_1p5()
+                                        .rounded_md()
+                                        .cursor_pointer()
+                                        .text_sm()
+                                        .when(is_selected, |s| s.bg(selected_bg).text_color(text_light))
+                                        .when(!is_selected && is_valid, |s| {
+                                            s.text_color(text_gray)
+                                                .hover(|h| h.bg(hover_bg).text_color(text_light))
+                                        })
+                                        .when(!is_valid, |s| s.text_color(warning_color).opacity(0.7))
+                                        .on_mouse_down(
+                                            MouseButton::Left,
+                                            cx.listener(move |view, _event, window, cx| {
+                                                if is_valid {
+                                                    view.handle_bookmark_click(path_clone.clone(), window, cx);
+                                                } else {
+                                                    view.toggle_bookmark_fix_menu(bookmark_id, cx);
+                                                }
+                                            }),
+                                        )
+                                        .on_mouse_down(
+                                            MouseButton::Right,
+                                            cx.listener(move |view, _event, _window, cx| {
+                                                view.handle_bookmark_remove(bookmark_id, cx);
+                                            }),
+                                        )
+                                        .child(
+                                            svg()
+                                                .path("assets/icons/folder-heart.svg")
+                                                .size(px(14.0))
+                                                .text_color(if !is_valid {
+                                                    warning_color
+                                                } else if is_selected {
+                                                    text_light
+                                                } else {
+                                                    icon_blue
+                                                }),
+                                        )
+                                        .child(
+                                            div()
+                                                .flex_1()
+                                                .overflow_hidden()
+                                                .child(middle_ellipsis(&bookmark.name, SIDEBAR_NAME_MAX_CHARS)),
+                                        )
+                                        .when(shortcut_display.is_some(), |s| {
+                                            s.child(
+                                                div()
+                                                    .text_xs()
+                                                    .text_color(text_gray)
+                                                    .opacity(0.6)
+                                                    .child(shortcut_display.unwrap_or_default()),
+                                            )
+                                        })
+                                        .when(!is_valid, |s| {
+                                            s.child(
+                                                svg()
+                                                    .path("assets/icons/triangle-alert.svg")
+                                                    .size(px(12.0))
+                                                    .text_color(warning_color),
+                                            )
+                                        }),
+                                )
+                                .when(is_menu_open, |s| {
+                                    s.child(self.render_bookmark_fix_menu(
+                                        bookmark_id,
+                                        text_gray,
+                                        text_light,
+                                        hover_bg,
+                                        cx,
+                                    ))
+                                })
+                        })),
+                )
+            })
+    }
+
+    fn render_bookmark_fix_menu(
+        &self,
+        id: BookmarkId,
+        text_gray: gpui::Rgba,
+        text_light: gpui::Rgba,
+        hover_bg: gpui::Rgba,
+        cx: &mut Context<Self>,
+    ) -> impl IntoElement {
+        let can_repoint = self.sidebar.current_directory.is_some();
+        let menu_item = |name: &str| {
+            div()
+                .id(SharedString::from(format!("{}-{}", name, id.0)))
+                .px_2()
+                .py_1()
+                .rounded_md()
+                .cursor_pointer()
+                .text_xs()
+                .text_color(text_gray)
+                .hover(|h| h.bg(hover_bg).text_color(text_light))
+        };
+
+        div()
+            .ml(px(26.0))
+            .mb_1()
+            .flex()
+            .flex_col()
+            .gap_0p5()
+            .when(can_repoint, |s| {
+                s.child(
+                    menu_item("bookmark-repoint")
+                        .on_mouse_down(
+                            MouseButton::Left,
+                            cx.listener(move |view, _event, _window, cx| {
+                                view.repoint_bookmark_to_current(id, cx);
+                            }),
+                        )
+                        .child("Point to Current Folder"),
+                )
+            })
+            .child(
+                menu_item("bookmark-remove")
+                    .on_mouse_down(
+                        MouseButton::Left,
+                        cx.listener(move |view, _event, _window, cx| {
+                            view.handle_bookmark_remove(id, cx);
+                        }),
+                    )
+                    .child("Remove Bookmark"),
+            )
+    }
+
+    fn render_frequent_section(
         &self,
         label_color: gpui::Rgba,
         text_gray: gpui::Rgba,
@@ -1203,18 +2728,17 @@ impl SidebarView {
         hover_bg: gpui::Rgba,
         selected_bg: gpui::Rgba,
         icon_blue: gpui::Rgba,
-        warning_color: gpui::Rgba,
         cx: &mut Context<Self>,
     ) -> impl IntoElement {
-        let is_expanded = self.sidebar.is_bookmarks_expanded();
-        let bookmarks = self.sidebar.bookmarks.bookmarks().to_vec();
+        let is_expanded = self.sidebar.is_frequent_expanded();
+        let folders = self.sidebar.frequent_folders();
         let selected_path = self.sidebar.selected_path.clone();
 
         div()
             .mb_4()
             .child(
                 div()
-                    .id("bookmarks-header")
+                    .id("frequent-header")
                     .text_xs()
                     .font_weight(gpui::FontWeight::BOLD)
                     .text_color(label_color)
@@ -1227,19 +2751,41 @@ impl SidebarView {
                     .on_mouse_down(
                         MouseButton::Left,
                         cx.listener(|view, _event, _window, cx| {
-                            view.toggle_bookmarks_section(cx);
+                            view.toggle_frequent_section(cx);
                         }),
                     )
-                    .child("BOOKMARKS")
+                    .child("FREQUENT")
                     .child(
-                        svg()
-                            .path(if is_expanded {
-                                "assets/icons/chevron-down.svg"
-                            } else {
-                                "assets/icons/chevron-right.svg"
+                        div()
+                            .flex()
+                            .items_center()
+                            .gap_2()
+                            .when(is_expanded && !folders.is_empty(), |s| {
+                                s.child(
+                                    div()
+                                        .id("frequent-clear")
+                                        .font_weight(gpui::FontWeight::NORMAL)
+                                        .hover(|h| h.text_color(text_light))
+                                        .on_mouse_down(
+                                            MouseButton::Left,
+                                            cx.listener(|view, _event, _window, cx| {
+                                                view.clear_frequent_folders(cx);
+                                                cx.stop_propagation();
+                                            }),
+                                        )
+                                        .child("Clear"),
+                                )
                             })
-                            .size(px(12.0))
-                            .text_color(label_color),
+                            .child(
+                                svg()
+                                    .path(if is_expanded {
+                                        "assets/icons/chevron-down.svg"
+                                    } else {
+                                        "assets/icons/chevron-right.svg"
+                                    })
+                                    .size(px(12.0))
+                                    .text_color(label_color),
+                            ),
                     ),
             )
             .when(is_expanded, |s| {
@@ -1249,7 +2795,7 @@ impl SidebarView {
                         .flex_col()
                         .gap_0p5()
                         .p_1()
-                        .when(bookmarks.is_empty(), |s| {
+                        .when(folders.is_empty(), |s| {
                             s.child(
                                 div()
                                     .px_2()
@@ -1257,26 +2803,21 @@ impl SidebarView {
                                     .text_sm()
                                     .text_color(text_gray)
                                     .opacity(0.7)
-                                    .child("No bookmarks yet")
-                                    .child(
-                                        div()
-                                            .text_xs()
-                                            .text_color(text_gray)
-                                            .opacity(0.5)
-                                            .mt_1()
-                                            .child("Press ⌘D to bookmark current folder"),
-                                    ),
+                                    .child("Folders you visit often appear here"),
                             )
                         })
-                        .children(bookmarks.into_iter().map(|bookmark| {
-                            let is_selected = selected_path.as_ref() == Some(&bookmark.path);
-                            let path_clone = bookmark.path.clone();
-                            let is_valid = bookmark.is_valid;
-                            let bookmark_id = bookmark.id;
-                            let shortcut_display = bookmark.shortcut.as_ref().map(|s| s.display());
+                        .children(folders.into_iter().enumerate().map(|(ix, path)| {
+                            let is_selected = selected_path.as_ref() == Some(&path);
+                            let name = path
+                                .file_name()
+                                .map(|n| n.to_string_lossy().to_string())
+                                .unwrap_or_else(|| path.to_string_lossy().to_string());
 
                             div()
-                                .id(SharedString::from(format!("bookmark-{}", bookmark.id.0)))
+                                .id(SharedString::from(format!("frequent-{}", ix)))
+                                .when(name.chars().count() > SIDEBAR_NAME_MAX_CHARS, |s| {
+                                    s.tooltip_label(name.clone())
+                                })
                                 .flex()
                                 .items_center()
                                 .gap_3()
@@ -1286,58 +2827,28 @@ impl SidebarView {
                                 .cursor_pointer()
                                 .text_sm()
                                 .when(is_selected, |s| s.bg(selected_bg).text_color(text_light))
-                                .when(!is_selected && is_valid, |s| {
+                                .when(!is_selected, |s| {
                                     s.text_color(text_gray)
                                         .hover(|h| h.bg(hover_bg).text_color(text_light))
                                 })
-                                .when(!is_valid, |s| s.text_color(warning_color).opacity(0.7))
                                 .on_mouse_down(
                                     MouseButton::Left,
                                     cx.listener(move |view, _event, window, cx| {
-                                        view.handle_bookmark_click(path_clone.clone(), window, cx);
-                                    }),
-                                )
-                                .on_mouse_down(
-                                    MouseButton::Right,
-                                    cx.listener(move |view, _event, _window, cx| {
-                                        view.handle_bookmark_remove(bookmark_id, cx);
+                                        view.handle_bookmark_click(path.clone(), window, cx);
                                     }),
                                 )
                                 .child(
                                     svg()
-                                        .path("assets/icons/folder-heart.svg")
+                                        .path("assets/icons/folder.svg")
                                         .size(px(14.0))
-                                        .text_color(if !is_valid {
-                                            warning_color
-                                        } else if is_selected {
-                                            text_light
-                                        } else {
-                                            icon_blue
-                                        }),
+                                        .text_color(if is_selected { text_light } else { icon_blue }),
                                 )
                                 .child(
                                     div()
                                         .flex_1()
                                         .overflow_hidden()
-                                        .child(bookmark.name.clone()),
+                                        .child(middle_ellipsis(&name, SIDEBAR_NAME_MAX_CHARS)),
                                 )
-                                .when(shortcut_display.is_some(), |s| {
-                                    s.child(
-                                        div()
-                                            .text_xs()
-                                            .text_color(text_gray)
-                                            .opacity(0.6)
-                                            .child(shortcut_display.unwrap_or_default()),
-                                    )
-                                })
-                                .when(!is_valid, |s| {
-                                    s.child(
-                                        svg()
-                                            .path("assets/icons/triangle-alert.svg")
-                                            .size(px(12.0))
-                                            .text_color(warning_color),
-                                    )
-                                })
                         })),
                 )
             })
@@ -1355,6 +2866,7 @@ impl SidebarView {
     ) -> impl IntoElement {
         let is_expanded = self.sidebar.is_network_expanded();
         let network_state = self.network_state();
+        let warning_color = theme_colors().warning;
         let selected_path = self.sidebar.selected_path.clone();
 
         div()
@@ -1471,6 +2983,9 @@ impl SidebarView {
                                 .iter()
                                 .map(|network| {
                                     let is_connected = network.is_connected;
+                                    let is_connecting = network.is_connecting;
+                                    let animate_spinner = !crate::models::reduced_motion();
+                                    let has_error = network.last_error.is_some();
                                     let display_name = network.name.clone();
                                     let protocol_icon = network.protocol.icon_name();
                                     let latency = network.latency_ms;
@@ -1504,6 +3019,49 @@ impl SidebarView {
                                                 .text_color(icon_blue),
                                         )
                                         .child(div().flex_1().overflow_hidden().child(display_name))
+                                        .when(is_connecting && !animate_spinner, |s| {
+                                            s.child(
+                                                svg()
+                                                    .path("assets/icons/refresh-cw.svg")
+                                                    .size(px(12.0))
+                                                    .text_color(text_gray),
+                                            )
+                                        })
+                                        .when(is_connecting && animate_spinner, |s| {
+                                            s.child(
+                                                svg()
+                                                    .path("assets/icons/refresh-cw.svg")
+                                                    .size(px(12.0))
+                                                    .text_color(text_gray)
+                                                    .with_animation(
+                                                        SharedString::from(format!(
+                                                            "network-connecting-{}",
+                                                            network_id.0
+                                                        )),
+                                                        Animation::new(
+                                                            crate::models::motion_duration(
+                                                                Duration::from_secs(1),
+                                                            ),
+                                                        )
+                                                        .repeat(),
+                                                        |icon, delta| {
+                                                            icon.with_transformation(
+                                                                Transformation::rotate(
+                                                                    percentage(delta),
+                                                                ),
+                                                            )
+                                                        },
+                                                    ),
+                                            )
+                                        })
+                                        .when(has_error && !is_connecting, |s| {
+                                            s.child(
+                                                svg()
+                                                    .path("assets/icons/triangle-alert.svg")
+                                                    .size(px(12.0))
+                                                    .text_color(warning_color),
+                                            )
+                                        })
                                         .when(is_connected, |s| {
                                             s.child(
                                                 div()
@@ -1672,6 +3230,7 @@ impl SidebarView {
             .child(self.render_tool_button("terminal-here", "terminal", "Open Terminal Here", ToolAction::OpenTerminalHere, true, text_gray, text_light, hover_bg, icon_blue, cx))
             .child(self.render_tool_button("copy-path", "clipboard-paste", "Copy Path", ToolAction::CopyPath, true, text_gray, text_light, hover_bg, icon_blue, cx))
             .child(self.render_tool_button("refresh", "refresh-cw", "Refresh", ToolAction::Refresh, true, text_gray, text_light, hover_bg, icon_blue, cx))
+            .child(self.render_tool_button("find-duplicates", "files", "Find Duplicates", ToolAction::FindDuplicates, true, text_gray, text_light, hover_bg, icon_blue, cx))
             .child(div().h(px(1.0)).bg(gpui::rgb(0x21262d)).my_1())
             .child(self.render_toggle_hidden_button(text_gray, text_light, hover_bg, icon_blue, success_color, show_hidden, cx))
             .child(self.render_default_browser_button(text_gray, text_light, hover_bg, icon_blue, success_color, cx))
@@ -1964,12 +3523,18 @@ impl SidebarView {
         let bar_critical = gpui::rgba(0xf85149ff);
 
         let is_selected = selected_path.as_ref() == Some(&device.path);
-        let path_clone = device.path.clone();
+        let click_action = device.click_action();
+        let mounted_image = self.mounted_images.at_mount_point(&device.path).cloned();
+        let quick_action = if mounted_image.is_some() {
+            Some(DeviceAction::Eject)
+        } else {
+            device.quick_action()
+        };
+        let device_for_click = device.clone();
+        let device_for_quick = device.clone();
         let icon_name = device.device_type.icon_name();
         let display_name = device.name.clone();
         let is_read_only = device.is_read_only;
-        let is_removable = device.is_removable;
-        let is_wsl = matches!(device.device_type, DeviceType::WslDistribution);
 
         let has_space_info = device.total_space > 0;
         let usage_pct = if has_space_info {
@@ -1994,15 +3559,22 @@ impl SidebarView {
             bar_normal
         };
 
-        let tooltip_content = if has_space_info {
-            format_space_tooltip(device.total_space, device.free_space)
-        } else {
-            device.path.to_string_lossy().to_string()
+        let tooltip_content = match (&mounted_image, has_space_info) {
+            (Some(image), true) => format!(
+                "Mounted from {}\n{}",
+                image.image.display(),
+                format_space_tooltip(device.total_space, device.free_space)
+            ),
+            (Some(image), false) => format!("Mounted from {}", image.image.display()),
+            (None, true) => format_space_tooltip(device.total_space, device.free_space),
+            (None, false) => device.path.to_string_lossy().to_string(),
         };
+        let quick_action_label = mounted_image
+            .as_ref()
+            .map(|image| format!("Unmount {}", image.image_name()));
 
         let group_id = SharedString::from(format!("device-group-{}", device.id.0));
-        let device_id = device.id;
-        let is_unmounted = device.path.starts_with("/dev/");
+        let is_unmounted = device.needs_mount();
         let device_for_format = device.clone();
 
         div()
@@ -2020,7 +3592,7 @@ impl SidebarView {
             .on_mouse_down(
                 MouseButton::Left,
                 cx.listener(move |view, _event, window, cx| {
-                    view.handle_device_click(path_clone.clone(), window, cx);
+                    view.handle_device_action(&device_for_click, click_action, window, cx);
                 }),
             )
             .when(is_unmounted, |s| {
@@ -2031,30 +3603,44 @@ impl SidebarView {
                     }),
                 )
             })
-            .when(is_removable && !is_wsl && !is_unmounted, |s| {
-                s.on_mouse_down(
-                    MouseButton::Right,
-                    cx.listener(move |view, _event, _window, cx| {
-                        view.handle_device_eject(device_id, cx);
-                    }),
-                )
-            })
             .child(self.render_device_tooltip(group_id.clone(), tooltip_content, text_light))
-            .child(self.render_device_info_row(
-                icon_name,
-                display_name,
-                is_selected,
-                is_critical,
-                is_very_low,
-                is_read_only,
-                is_removable,
-                is_wsl,
-                text_gray,
-                text_light,
-                icon_blue,
-                warning_color,
-                bar_critical,
-            ))
+            .child(
+                self.render_device_info_row(
+                    icon_name,
+                    display_name,
+                    is_selected,
+                    is_critical,
+                    is_very_low,
+                    is_read_only,
+                    text_gray,
+                    text_light,
+                    icon_blue,
+                    warning_color,
+                    bar_critical,
+                )
+                .when(mounted_image.is_some(), |row| {
+                    row.child(
+                        svg()
+                            .path("assets/icons/disc.svg")
+                            .size(px(12.0))
+                            .flex_shrink_0()
+                            .text_color(text_gray),
+                    )
+                })
+                .when_some(quick_action, |row, action| {
+                    row.child(
+                        self.render_device_quick_action(action, text_gray, text_light, hover_bg)
+                            .when_some(quick_action_label.clone(), |s, label| s.tooltip_label(label))
+                            .on_mouse_down(
+                                MouseButton::Left,
+                                cx.listener(move |view, _event, window, cx| {
+                                    cx.stop_propagation();
+                                    view.handle_device_action(&device_for_quick, action, window, cx);
+                                }),
+                            ),
+                    )
+                }),
+            )
             .when(has_space_info, |s| {
                 s.child(self.render_device_usage_bar(
                     usage_pct,
@@ -2114,14 +3700,12 @@ impl SidebarView {
         is_critical: bool,
         is_very_low: bool,
         is_read_only: bool,
-        is_removable: bool,
-        is_wsl: bool,
         text_gray: gpui::Rgba,
         text_light: gpui::Rgba,
         icon_blue: gpui::Rgba,
         warning_color: gpui::Rgba,
         bar_critical: gpui::Rgba,
-    ) -> impl IntoElement {
+    ) -> Div {
         div()
             .flex()
             .items_center()
@@ -2156,15 +3740,37 @@ impl SidebarView {
                         .text_color(warning_color),
                 )
             })
-            .when(is_removable && !is_wsl && !is_critical, |s| {
-                s.child(
-                    svg()
-                        .path("assets/icons/external-link.svg")
-                        .size(px(10.0))
-                        .text_color(text_gray)
-                        .opacity(0.5),
-                )
-            })
+    }
+
+    fn render_device_quick_action(
+        &self,
+        action: DeviceAction,
+        text_gray: gpui::Rgba,
+        text_light: gpui::Rgba,
+        hover_bg: gpui::Rgba,
+    ) -> Stateful<Div> {
+        let base = div()
+            .id(SharedString::from(format!("device-quick-{}", action.label())))
+            .flex_shrink_0()
+            .rounded_sm()
+            .cursor_pointer()
+            .text_color(text_gray)
+            .hover(|h| h.bg(hover_bg).text_color(text_light));
+
+        match action {
+            DeviceAction::Mount => base
+                .px_1p5()
+                .text_xs()
+                .border_1()
+                .border_color(text_gray)
+                .child(action.label()),
+            _ => base.p(px(2.0)).child(
+                svg()
+                    .path("assets/icons/eject.svg")
+                    .size(px(12.0))
+                    .text_color(text_gray),
+            ),
+        }
     }
 
     fn render_device_usage_bar(
@@ -2220,21 +3826,83 @@ impl SidebarView {
         cx: &mut Context<Self>,
     ) -> impl IntoElement {
         let selected_path = self.sidebar.selected_path.clone();
-        let favorites = self.sidebar.favorites.items().to_vec();
+        let owned_members = |group: Option<FavoriteGroupId>| -> Vec<(usize, Favorite)> {
+            self.sidebar
+                .favorites
+                .members(group)
+                .into_iter()
+                .map(|(i, favorite)| (i, favorite.clone()))
+                .collect()
+        };
+        let ungrouped = owned_members(None);
+        let groups: Vec<(FavoriteGroup, Vec<(usize, Favorite)>)> = self
+            .sidebar
+            .favorites
+            .groups()
+            .iter()
+            .map(|group| (group.clone(), owned_members(Some(group.id))))
+            .collect();
         let is_drop_target = self.sidebar.is_drop_target;
         let is_full = self.sidebar.favorites.is_full();
         let dragging_index = self.dragging_favorite_index;
         let drop_target_index = self.drop_target_index;
+        let is_expanded = self.sidebar.is_favorites_expanded();
 
         div()
             .child(
                 div()
+                    .id("favorites-header")
                     .text_xs()
                     .font_weight(gpui::FontWeight::BOLD)
                     .text_color(label_color)
                     .mb_2()
                     .px_2()
-                    .child("FAVORITES"),
+                    .flex()
+                    .items_center()
+                    .justify_between()
+                    .cursor_pointer()
+                    .on_mouse_down(
+                        MouseButton::Left,
+                        cx.listener(|view, _event, _window, cx| {
+                            view.toggle_favorites_section(cx);
+                        }),
+                    )
+                    .child("FAVORITES")
+                    .child(
+                        div()
+                            .flex()
+                            .items_center()
+                            .gap_2()
+                            .child(
+                                div()
+                                    .id("favorites-add-group")
+                                    .cursor_pointer()
+                                    .hover(|h| h.opacity(0.7))
+                                    .on_mouse_down(
+                                        MouseButton::Left,
+                                        cx.listener(|view, _event, _window, cx| {
+                                            cx.stop_propagation();
+                                            view.add_favorite_group(cx);
+                                        }),
+                                    )
+                                    .child(
+                                        svg()
+                                            .path("assets/icons/folder-plus.svg")
+                                            .size(px(12.0))
+                                            .text_color(label_color),
+                                    ),
+                            )
+                            .child(
+                                svg()
+                                    .path(if is_expanded {
+                                        "assets/icons/chevron-down.svg"
+                                    } else {
+                                        "assets/icons/chevron-right.svg"
+                                    })
+                                    .size(px(12.0))
+                                    .text_color(label_color),
+                            ),
+                    ),
             )
             .child(
                 div()
@@ -2266,23 +3934,44 @@ impl SidebarView {
                     .on_drop(cx.listener(|view, dragged: &DraggedFolder, _window, cx| {
                         view.handle_drop(dragged.path.clone(), cx);
                     }))
-                    .children(favorites.into_iter().enumerate().map(|(i, favorite)| {
-                        self.render_favorite_item(
-                            i,
-                            favorite,
-                            &selected_path,
-                            dragging_index,
-                            drop_target_index,
-                            text_gray,
-                            text_light,
-                            hover_bg,
-                            selected_bg,
-                            icon_blue,
-                            warning_color,
-                            drop_zone_border,
-                            cx,
-                        )
-                    }))
+                    .when(is_expanded, |s| {
+                        s.children(ungrouped.into_iter().map(|(i, favorite)| {
+                            self.render_favorite_item(
+                                i,
+                                favorite,
+                                &selected_path,
+                                dragging_index,
+                                drop_target_index,
+                                text_gray,
+                                text_light,
+                                hover_bg,
+                                selected_bg,
+                                icon_blue,
+                                warning_color,
+                                drop_zone_border,
+                                cx,
+                            )
+                        }))
+                        .children(groups.into_iter().map(|(group, members)| {
+                            self.render_favorite_group(
+                                group,
+                                members,
+                                &selected_path,
+                                dragging_index,
+                                drop_target_index,
+                                label_color,
+                                text_gray,
+                                text_light,
+                                hover_bg,
+                                selected_bg,
+                                icon_blue,
+                                warning_color,
+                                drop_zone_bg,
+                                drop_zone_border,
+                                cx,
+                            )
+                        }))
+                    })
                     .when(is_drop_target && !is_full, |s| {
                         s.child(
                             div()
@@ -2297,6 +3986,162 @@ impl SidebarView {
             )
     }
 
+    fn render_favorite_group(
+        &self,
+        group: FavoriteGroup,
+        members: Vec<(usize, Favorite)>,
+        selected_path: &Option<PathBuf>,
+        dragging_index: Option<usize>,
+        drop_target_index: Option<usize>,
+        label_color: gpui::Rgba,
+        text_gray: gpui::Rgba,
+        text_light: gpui::Rgba,
+        hover_bg: gpui::Rgba,
+        selected_bg: gpui::Rgba,
+        icon_blue: gpui::Rgba,
+        warning_color: gpui::Rgba,
+        drop_zone_bg: gpui::Rgba,
+        drop_zone_border: gpui::Rgba,
+        cx: &mut Context<Self>,
+    ) -> impl IntoElement {
+        let group_id = group.id;
+        let rename_input = self
+            .favorite_group_rename
+            .as_ref()
+            .filter(|(id, _)| *id == group_id)
+            .map(|(_, input)| input.clone());
+        let is_renaming = rename_input.is_some();
+        let rename_name = group.name.clone();
+        let member_count = members.len();
+
+        div()
+            .flex()
+            .flex_col()
+            .gap_0p5()
+            .child(
+                div()
+                    .id(SharedString::from(format!("fav-group-{}", group_id.0)))
+                    .flex()
+                    .items_center()
+                    .gap_2()
+                    .mt_1()
+                    .px_2()
+                    .py_1()
+                    .rounded_md()
+                    .cursor_pointer()
+                    .text_xs()
+                    .font_weight(gpui::FontWeight::SEMIBOLD)
+                    .text_color(label_color)
+                    .hover(|h| h.bg(hover_bg).text_color(text_light))
+                    .drag_over::<DraggedFolder>(move |style, _, _, _| {
+                        style
+                            .bg(drop_zone_bg)
+                            .border_1()
+                            .border_color(drop_zone_border)
+                    })
+                    .when(!is_renaming, |s| {
+                        s.on_mouse_down(
+                            MouseButton::Left,
+                            cx.listener(move |view, _event, _window, cx| {
+                                view.sidebar.toggle_favorite_group(group_id);
+                                cx.notify();
+                            }),
+                        )
+                    })
+                    .on_mouse_down(
+                        MouseButton::Right,
+                        cx.listener(move |view, _event, _window, cx| {
+                            view.start_favorite_group_rename(group_id, rename_name.clone(), cx);
+                        }),
+                    )
+                    .on_drop(cx.listener(move |view, dragged: &DraggedFolder, _window, cx| {
+                        view.handle_group_drop(group_id, dragged.path.clone(), cx);
+                    }))
+                    .child(
+                        svg()
+                            .path(if group.collapsed {
+                                "assets/icons/chevron-right.svg"
+                            } else {
+                                "assets/icons/chevron-down.svg"
+                            })
+                            .size(px(10.0))
+                            .text_color(label_color),
+                    )
+                    .child(match rename_input {
+                        Some(input) => div()
+                            .flex_1()
+                            .flex()
+                            .items_center()
+                            .gap_1()
+                            .child(div().flex_1().child(input))
+                            .child(
+                                div()
+                                    .id(SharedString::from(format!("fav-group-remove-{}", group_id.0)))
+                                    .px_1()
+                                    .cursor_pointer()
+                                    .hover(|h| h.text_color(warning_color))
+                                    .on_mouse_down(
+                                        MouseButton::Left,
+                                        cx.listener(move |view, _event, _window, cx| {
+                                            view.remove_favorite_group(group_id, cx);
+                                        }),
+                                    )
+                                    .child("Ungroup"),
+                            )
+                            .child(
+                                div()
+                                    .id(SharedString::from(format!("fav-group-rename-cancel-{}", group_id.0)))
+                                    .px_1()
+                                    .cursor_pointer()
+                                    .hover(|h| h.text_color(text_light))
+                                    .on_mouse_down(
+                                        MouseButton::Left,
+                                        cx.listener(|view, _event, _window, cx| {
+                                            view.cancel_favorite_group_rename(cx);
+                                        }),
+                                    )
+                                    .child("✕"),
+                            ),
+                        None => div()
+                            .flex_1()
+                            .overflow_hidden()
+                            .child(middle_ellipsis(&group.name, SIDEBAR_NAME_MAX_CHARS)),
+                    })
+                    .child(
+                        div()
+                            .text_color(text_gray)
+                            .opacity(0.6)
+                            .child(member_count.to_string()),
+                    ),
+            )
+            .when(!group.collapsed, |s| {
+                s.child(
+                    div()
+                        .flex()
+                        .flex_col()
+                        .gap_0p5()
+                        .pl_3()
+                        .children(members.into_iter().map(|(i, favorite)| {
+                            self.render_favorite_item(
+                                i,
+                                favorite,
+                                selected_path,
+                                dragging_index,
+                                drop_target_index,
+                                text_gray,
+                                text_light,
+                                hover_bg,
+                                selected_bg,
+                                icon_blue,
+                                warning_color,
+                                drop_zone_border,
+                                cx,
+                            )
+                        })),
+                )
+            })
+    }
+
     fn render_favorite_item(
         &self,
         i: usize,
@@ -2324,6 +4169,9 @@ impl SidebarView {
 
         div()
             .id(SharedString::from(format!("fav-{}", i)))
+            .when(favorite.name.chars().count() > SIDEBAR_NAME_MAX_CHARS, |s| {
+                s.tooltip_label(favorite.name.clone())
+            })
             .flex()
             .items_center()
             .gap_3()
@@ -2397,7 +4245,7 @@ impl SidebarView {
                 div()
                     .flex_1()
                     .overflow_hidden()
-                    .child(favorite.name.clone()),
+                    .child(middle_ellipsis(&favorite.name, SIDEBAR_NAME_MAX_CHARS)),
             )
             .when(!is_valid, |s| {
                 s.child(
@@ -2497,3 +4345,7 @@ impl SidebarView {
         cx.notify();
     }
 }
+
+#[cfg(test)]
+#[path = "sidebar_tests.rs"]
+mod tests;
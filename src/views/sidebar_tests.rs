@@ -0,0 +1,129 @@
+use super::*;
+use std::fs;
+use tempfile::TempDir;
+
+fn create_tree() -> TempDir {
+    let temp = TempDir::new().unwrap();
+    fs::create_dir_all(temp.path().join("alpha/beta/gamma")).unwrap();
+    fs::create_dir_all(temp.path().join("alpha/delta")).unwrap();
+    fs::create_dir_all(temp.path().join("zeta")).unwrap();
+    fs::create_dir_all(temp.path().join(".hidden/inner")).unwrap();
+    fs::write(temp.path().join("alpha/file.txt"), "content").unwrap();
+    temp
+}
+
+fn root_item(temp: &TempDir) -> SidebarItem {
+    SidebarItem::new("root".to_string(), temp.path().to_path_buf(), true, 0)
+}
+
+#[test]
+fn test_load_children_only_directories_sorted() {
+    let temp = create_tree();
+    let mut root = root_item(&temp);
+    root.load_children(false);
+
+    let names: Vec<&str> = root.children.iter().map(|c| c.name.as_str()).collect();
+    assert_eq!(names, vec!["alpha", "zeta"]);
+    assert!(root.is_loaded);
+    assert!(root.children.iter().all(|c| c.depth == 1));
+}
+
+#[test]
+fn test_load_children_includes_hidden_when_requested() {
+    let temp = create_tree();
+    let mut root = root_item(&temp);
+    root.load_children(true);
+
+    assert!(root.children.iter().any(|c| c.name == ".hidden"));
+}
+
+#[test]
+fn test_children_are_loaded_lazily() {
+    let temp = create_tree();
+    let mut root = root_item(&temp);
+    root.load_children(false);
+
+    let alpha = root.children.iter().find(|c| c.name == "alpha").unwrap();
+    assert!(!alpha.is_loaded);
+    assert!(alpha.children.is_empty());
+}
+
+#[test]
+fn test_reveal_expands_every_ancestor() {
+    let temp = create_tree();
+    let mut root = root_item(&temp);
+    let target = temp.path().join("alpha/beta/gamma");
+
+    assert!(root.reveal(&target, false));
+
+    let alpha = root.children.iter().find(|c| c.name == "alpha").unwrap();
+    assert!(root.is_expanded);
+    assert!(alpha.is_expanded);
+    let beta = alpha.children.iter().find(|c| c.name == "beta").unwrap();
+    assert!(beta.is_expanded);
+    let gamma = beta.children.iter().find(|c| c.name == "gamma").unwrap();
+    assert!(!gamma.is_expanded);
+    assert_eq!(gamma.depth, 3);
+
+    let zeta = root.children.iter().find(|c| c.name == "zeta").unwrap();
+    assert!(!zeta.is_expanded);
+    assert!(!zeta.is_loaded);
+}
+
+#[test]
+fn test_reveal_outside_root_fails() {
+    let temp = create_tree();
+    let other = TempDir::new().unwrap();
+    let mut root = root_item(&temp);
+
+    assert!(!root.reveal(other.path(), false));
+    assert!(!root.is_expanded);
+}
+
+#[test]
+fn test_reveal_missing_path_fails() {
+    let temp = create_tree();
+    let mut root = root_item(&temp);
+
+    assert!(!root.reveal(&temp.path().join("alpha/missing"), false));
+}
+
+#[test]
+fn test_reveal_root_itself() {
+    let temp = create_tree();
+    let mut root = root_item(&temp);
+
+    assert!(root.reveal(temp.path(), false));
+    assert_eq!(root.visible_index_of(temp.path()), Some(0));
+}
+
+#[test]
+fn test_visible_index_after_reveal() {
+    let temp = create_tree();
+    let mut root = root_item(&temp);
+    let target = temp.path().join("alpha/beta/gamma");
+    root.reveal(&target, false);
+
+    let names: Vec<&str> = root
+        .visible_items()
+        .iter()
+        .map(|item| item.name.as_str())
+        .collect();
+    assert_eq!(names, vec!["root", "alpha", "beta", "gamma", "delta", "zeta"]);
+    assert_eq!(root.visible_index_of(&target), Some(3));
+}
+
+#[test]
+fn test_toggle_expanded_collapses_subtree() {
+    let temp = create_tree();
+    let mut root = root_item(&temp);
+    let alpha = temp.path().join("alpha");
+    root.reveal(&alpha.join("beta"), false);
+
+    assert!(root.toggle_expanded(&alpha, false));
+    assert_eq!(root.visible_items().len(), 3);
+    assert_eq!(root.visible_index_of(&alpha.join("beta")), None);
+
+    assert!(root.toggle_expanded(&alpha, false));
+    assert_eq!(root.visible_index_of(&alpha.join("beta")), Some(2));
+}
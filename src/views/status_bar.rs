@@ -1,34 +1,79 @@
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 
 use gpui::{
-    div, prelude::*, px, svg, App, Context, FocusHandle, Focusable, InteractiveElement,
-    IntoElement, MouseButton, ParentElement, Render, Styled, Window,
+    anchored, deferred, div, percentage, prelude::*, px, svg, Animation, AnimationExt, App,
+    Context, Corner, FocusHandle, Focusable, InteractiveElement, IntoElement, MouseButton,
+    ParentElement, Render, SharedString, Styled, Transformation, Window,
 };
 
-use crate::models::{theme_colors, FileEntry, ViewMode};
+use crate::models::{
+    find_repo_root, read_branch, resolve_git_dir, theme_colors, BackgroundActivity, FileEntry, IndexingStatus,
+    SortColumn, SortDirection, SortState, ViewMode, VisibleColumns, VolumeSpace,
+};
+use crate::views::{TooltipLabel, IconControl};
+
+
+const COMPACT_WIDTH: f32 = 720.0;
+
+
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnreadableItem {
+    pub path: PathBuf,
+    pub reason: String,
+}
+
+impl UnreadableItem {
+    pub fn from_errors(errors: &[(PathBuf, std::io::Error)]) -> Vec<Self> {
+        errors
+            .iter()
+            .map(|(path, error)| Self {
+                path: path.clone(),
+                reason: error.to_string(),
+            })
+            .collect()
+    }
+}
 
 
 #[derive(Debug, Clone)]
 pub struct StatusBarState {
     pub total_items: usize,
+    pub folder_count: usize,
+    pub file_count: usize,
     pub selected_count: usize,
     pub selected_size: u64,
     pub view_mode: ViewMode,
     pub git_branch: Option<String>,
     pub is_loading: bool,
     pub is_terminal_open: bool,
+    pub sort_state: SortState,
+    pub visible_columns: VisibleColumns,
+    pub unreadable: Vec<UnreadableItem>,
+    pub indexing: IndexingStatus,
+    pub background: BackgroundActivity,
+    pub volume_space: Option<VolumeSpace>,
 }
 
 impl Default for StatusBarState {
     fn default() -> Self {
         Self {
             total_items: 0,
+            folder_count: 0,
+            file_count: 0,
             selected_count: 0,
             selected_size: 0,
             view_mode: ViewMode::List,
             git_branch: None,
             is_loading: false,
             is_terminal_open: false,
+            sort_state: SortState::default(),
+            visible_columns: VisibleColumns::default(),
+            unreadable: Vec::new(),
+            indexing: IndexingStatus::Idle,
+            background: BackgroundActivity::new(),
+            volume_space: None,
         }
     }
 }
@@ -40,7 +85,7 @@ impl StatusBarState {
 
 
     pub fn update_from_entries(&mut self, entries: &[FileEntry], selected_indices: &[usize]) {
-        self.total_items = entries.len();
+        self.set_item_counts(entries);
         self.selected_count = selected_indices.len();
         self.selected_size = selected_indices
             .iter()
@@ -51,7 +96,7 @@ impl StatusBarState {
 
 
     pub fn update_from_file_list(&mut self, entries: &[FileEntry], selected_index: Option<usize>) {
-        self.total_items = entries.len();
+        self.set_item_counts(entries);
         if let Some(idx) = selected_index {
             self.selected_count = 1;
             self.selected_size = entries.get(idx).map(|e| e.size).unwrap_or(0);
@@ -62,6 +107,18 @@ impl StatusBarState {
     }
 
 
+    fn set_item_counts(&mut self, entries: &[FileEntry]) {
+        let (folders, files) = count_folders_and_files(entries);
+        self.total_items = entries.len();
+        self.folder_count = folders;
+        self.file_count = files;
+    }
+
+
+    pub fn item_breakdown(&self) -> String {
+        format_item_breakdown(self.folder_count, self.file_count)
+    }
+
     pub fn detect_git_branch(&mut self, path: &Path) {
         self.git_branch = detect_git_branch(path);
     }
@@ -74,6 +131,14 @@ impl StatusBarState {
         self.is_terminal_open = is_open;
     }
 
+    pub fn activity(&self) -> BackgroundActivity {
+        let mut activity = self.background.clone();
+        if let Some(label) = self.indexing.label() {
+            activity.push(label, None);
+        }
+        activity
+    }
+
     pub fn set_loading(&mut self, is_loading: bool) {
         self.is_loading = is_loading;
     }
@@ -81,40 +146,51 @@ impl StatusBarState {
 
 
 pub fn detect_git_branch(path: &Path) -> Option<String> {
-    let mut current = Some(path);
-
-    while let Some(dir) = current {
-        let git_dir = dir.join(".git");
-
-        if git_dir.is_dir() {
-            let head_path = git_dir.join("HEAD");
-            if let Ok(content) = std::fs::read_to_string(&head_path) {
-                let content = content.trim();
-                if let Some(branch) = content.strip_prefix("ref: refs/heads/") {
-                    return Some(branch.to_string());
-                } else if content.len() >= 7 {
-                    return Some(content[..7].to_string());
-                }
-            }
-            return None;
-        } else if git_dir.is_file() {
-            if let Ok(content) = std::fs::read_to_string(&git_dir) {
-                if let Some(gitdir) = content.trim().strip_prefix("gitdir: ") {
-                    let head_path = Path::new(gitdir).join("HEAD");
-                    if let Ok(head_content) = std::fs::read_to_string(&head_path) {
-                        let head_content = head_content.trim();
-                        if let Some(branch) = head_content.strip_prefix("ref: refs/heads/") {
-                            return Some(branch.to_string());
-                        }
-                    }
-                }
-            }
-        }
+    let root = find_repo_root(path)?;
+    read_branch(&resolve_git_dir(&root)?)
+}
 
-        current = dir.parent();
+
+pub fn count_folders_and_files(entries: &[FileEntry]) -> (usize, usize) {
+    let folders = entries.iter().filter(|e| e.is_dir).count();
+    (folders, entries.len() - folders)
+}
+
+
+pub fn format_item_breakdown(folders: usize, files: usize) -> String {
+    let plural = |count: usize, singular: &str, plural: &str| {
+        format!("{} {}", count, if count == 1 { singular } else { plural })
+    };
+
+    match (folders, files) {
+        (0, 0) => "No items".to_string(),
+        (0, files) => plural(files, "file", "files"),
+        (folders, 0) => plural(folders, "folder", "folders"),
+        (folders, files) => format!(
+            "{}, {}",
+            plural(folders, "folder", "folders"),
+            plural(files, "file", "files")
+        ),
     }
+}
 
-    None
+
+pub fn format_unreadable_count(count: usize) -> String {
+    if count == 1 {
+        "1 item could not be read".to_string()
+    } else {
+        format!("{} items could not be read", count)
+    }
+}
+
+
+fn sort_column_label(column: SortColumn) -> &'static str {
+    match column {
+        SortColumn::Name => "Name",
+        SortColumn::Date => "Date Modified",
+        SortColumn::Type => "Kind",
+        SortColumn::Size => "Size",
+    }
 }
 
 
@@ -142,6 +218,8 @@ pub fn format_size(size: u64) -> String {
 pub enum StatusBarAction {
     ToggleTerminal,
     ToggleViewMode,
+    SetSort(SortState),
+    ShowOperations,
 }
 
 
@@ -149,6 +227,8 @@ pub struct StatusBarView {
     state: StatusBarState,
     focus_handle: FocusHandle,
     pending_action: Option<StatusBarAction>,
+    sort_menu_open: bool,
+    unreadable_open: bool,
 }
 
 impl StatusBarView {
@@ -157,6 +237,8 @@ impl StatusBarView {
             state: StatusBarState::new(),
             focus_handle: cx.focus_handle(),
             pending_action: None,
+            sort_menu_open: false,
+            unreadable_open: false,
         }
     }
 
@@ -207,6 +289,222 @@ impl StatusBarView {
         self.state.set_terminal_open(is_open);
         cx.notify();
     }
+
+    pub fn set_sort_state(&mut self, sort_state: SortState, cx: &mut Context<Self>) {
+        if self.state.sort_state != sort_state {
+            self.state.sort_state = sort_state;
+            cx.notify();
+        }
+    }
+
+    pub fn set_visible_columns(&mut self, columns: VisibleColumns, cx: &mut Context<Self>) {
+        if self.state.visible_columns != columns {
+            self.state.visible_columns = columns;
+            cx.notify();
+        }
+    }
+
+    pub fn set_unreadable(&mut self, unreadable: Vec<UnreadableItem>, cx: &mut Context<Self>) {
+        if unreadable.is_empty() {
+            self.unreadable_open = false;
+        }
+        self.state.unreadable = unreadable;
+        cx.notify();
+    }
+
+    pub fn set_indexing_status(&mut self, status: IndexingStatus, cx: &mut Context<Self>) {
+        if self.state.indexing != status {
+            self.state.indexing = status;
+            cx.notify();
+        }
+    }
+
+    pub fn set_background_activity(&mut self, activity: BackgroundActivity, cx: &mut Context<Self>) {
+        if self.state.background != activity {
+            self.state.background = activity;
+            cx.notify();
+        }
+    }
+
+    pub fn set_volume_space(&mut self, space: Option<VolumeSpace>, cx: &mut Context<Self>) {
+        if self.state.volume_space != space {
+            self.state.volume_space = space;
+            cx.notify();
+        }
+    }
+
+    fn choose_sort(&mut self, sort_state: SortState, cx: &mut Context<Self>) {
+        self.sort_menu_open = false;
+        self.state.sort_state = sort_state;
+        self.pending_action = Some(StatusBarAction::SetSort(sort_state));
+        cx.notify();
+    }
+
+    fn render_sort_menu(&self, compact: bool, cx: &mut Context<Self>) -> impl IntoElement {
+        let theme = theme_colors();
+        let sort_state = self.state.sort_state;
+        let breakdown = self.state.item_breakdown();
+
+        let menu_row = |id: SharedString, label: &'static str, checked: bool| {
+            div()
+                .id(id)
+                .flex()
+                .items_center()
+                .gap_2()
+                .px_2()
+                .py_1()
+                .rounded_sm()
+                .cursor_pointer()
+                .text_color(if checked {
+                    theme.text_primary
+                } else {
+                    theme.text_secondary
+                })
+                .hover(|s| s.bg(theme.bg_hover))
+                .child(
+                    div().w(px(12.0)).when(checked, |el| {
+                        el.child(
+                            svg()
+                                .path("assets/icons/check.svg")
+                                .size(px(12.0))
+                                .text_color(theme.accent_primary),
+                        )
+                    }),
+                )
+                .child(label)
+        };
+
+        let columns = self.state.visible_columns.sort_options().into_iter().map(|column| {
+            let mut next = sort_state;
+            next.column = column;
+            menu_row(
+                SharedString::from(format!("status-sort-{:?}", column)),
+                sort_column_label(column),
+                sort_state.column == column,
+            )
+            .on_mouse_down(
+                MouseButton::Left,
+                cx.listener(move |view, _, _, cx| view.choose_sort(next, cx)),
+            )
+        });
+
+        let directions = [SortDirection::Ascending, SortDirection::Descending].map(|direction| {
+            let mut next = sort_state;
+            next.direction = direction;
+            let label = match direction {
+                SortDirection::Ascending => "Ascending",
+                SortDirection::Descending => "Descending",
+            };
+            menu_row(
+                SharedString::from(format!("status-sort-{:?}", direction)),
+                label,
+                sort_state.direction == direction,
+            )
+            .on_mouse_down(
+                MouseButton::Left,
+                cx.listener(move |view, _, _, cx| view.choose_sort(next, cx)),
+            )
+        });
+
+        deferred(
+            anchored()
+                .snap_to_window_with_margin(px(8.0))
+                .anchor(Corner::BottomRight)
+                .child(
+                    div()
+                        .id("status-sort-menu")
+                        .occlude()
+                        .mb_1()
+                        .min_w(px(160.0))
+                        .p_1()
+                        .flex()
+                        .flex_col()
+                        .bg(theme.bg_tertiary)
+                        .border_1()
+                        .border_color(theme.border_default)
+                        .rounded_md()
+                        .shadow_lg()
+                        .text_xs()
+                        .on_mouse_down_out(cx.listener(|view, _, _, cx| {
+                            view.sort_menu_open = false;
+                            cx.notify();
+                        }))
+                        .when(compact, |el| {
+                            el.child(
+                                div()
+                                    .px_2()
+                                    .py_1()
+                                    .text_color(theme.text_muted)
+                                    .child(breakdown),
+                            )
+                            .child(div().h(px(1.0)).my_1().bg(theme.border_default))
+                        })
+                        .child(div().px_2().py_1().text_color(theme.text_muted).child("Sort By"))
+                        .children(columns)
+                        .child(div().h(px(1.0)).my_1().bg(theme.border_default))
+                        .children(directions),
+                ),
+        )
+        .with_priority(1)
+    }
+}
+
+impl StatusBarView {
+    fn render_unreadable_details(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        let theme = theme_colors();
+        let rows = self.state.unreadable.iter().map(|item| {
+            div()
+                .flex()
+                .flex_col()
+                .px_2()
+                .py_1()
+                .child(
+                    div()
+                        .text_color(theme.text_primary)
+                        .child(item.path.display().to_string()),
+                )
+                .child(div().text_color(theme.text_muted).child(item.reason.clone()))
+        });
+
+        deferred(
+            anchored()
+                .snap_to_window_with_margin(px(8.0))
+                .anchor(Corner::BottomLeft)
+                .child(
+                    div()
+                        .id("status-unreadable-details")
+                        .occlude()
+                        .mb_1()
+                        .min_w(px(240.0))
+                        .max_w(px(480.0))
+                        .max_h(px(240.0))
+                        .overflow_y_scroll()
+                        .p_1()
+                        .flex()
+                        .flex_col()
+                        .bg(theme.bg_tertiary)
+                        .border_1()
+                        .border_color(theme.border_default)
+                        .rounded_md()
+                        .shadow_lg()
+                        .text_xs()
+                        .on_mouse_down_out(cx.listener(|view, _, _, cx| {
+                            view.unreadable_open = false;
+                            cx.notify();
+                        }))
+                        .child(
+                            div()
+                                .px_2()
+                                .py_1()
+                                .text_color(theme.text_muted)
+                                .child(format_unreadable_count(self.state.unreadable.len())),
+                        )
+                        .child(div().h(px(1.0)).my_1().bg(theme.border_default))
+                        .children(rows),
+                ),
+        )
+        .with_priority(1)
+    }
 }
 
 impl Focusable for StatusBarView {
@@ -216,7 +514,7 @@ impl Focusable for StatusBarView {
 }
 
 impl Render for StatusBarView {
-    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+    fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
         let theme = theme_colors();
         let bg_color = theme.bg_secondary;
         let border_color = theme.border_default;
@@ -231,6 +529,22 @@ impl Render for StatusBarView {
         let view_mode = self.state.view_mode;
         let git_branch = self.state.git_branch.clone();
         let is_terminal_open = self.state.is_terminal_open;
+        let sort_state = self.state.sort_state;
+        let sort_menu_open = self.sort_menu_open;
+        let unreadable_count = self.state.unreadable.len();
+        let unreadable_open = self.unreadable_open;
+        let warning = theme.warning;
+        let activity = self.state.activity();
+        let activity_count = activity.count();
+        let activity_progress = activity.progress();
+        let volume_space = self.state.volume_space;
+        let animate_spinner = !crate::models::reduced_motion();
+        let compact = window.viewport_size().width < px(COMPACT_WIDTH);
+        let item_label = if compact {
+            format!("{} items", total_items)
+        } else {
+            self.state.item_breakdown()
+        };
 
         div()
             .id("status-bar")
@@ -261,8 +575,110 @@ impl Render for StatusBarView {
                                     .size(px(12.0))
                                     .text_color(text_muted),
                             )
-                            .child(format!("{} items", total_items)),
+                            .child(item_label),
                     )
+                    .when_some(activity.summary(), |el, label| {
+                        let spinner = || {
+                            svg()
+                                .path("assets/icons/refresh-cw.svg")
+                                .size(px(12.0))
+                                .text_color(accent)
+                        };
+                        el.child(
+                            div()
+                                .id("status-activity")
+                                .tooltip_label(label.clone())
+                                .flex()
+                                .items_center()
+                                .gap_1()
+                                .px_1p5()
+                                .py_0p5()
+                                .rounded_sm()
+                                .cursor_pointer()
+                                .text_color(text_muted)
+                                .hover(|s| s.bg(hover_bg))
+                                .on_mouse_down(
+                                    MouseButton::Left,
+                                    cx.listener(|view, _event, _window, cx| {
+                                        view.pending_action = Some(StatusBarAction::ShowOperations);
+                                        cx.notify();
+                                    }),
+                                )
+                                .when(!animate_spinner, |el| el.child(spinner()))
+                                .when(animate_spinner, |el| {
+                                    el.child(spinner().with_animation(
+                                        "status-activity-spinner",
+                                        Animation::new(crate::models::motion_duration(Duration::from_secs(1)))
+                                            .repeat(),
+                                        |icon, delta| {
+                                            icon.with_transformation(Transformation::rotate(
+                                                percentage(delta),
+                                            ))
+                                        },
+                                    ))
+                                })
+                                .when(compact && activity_count > 1, |el| {
+                                    el.child(activity_count.to_string())
+                                })
+                                .when(!compact, |el| el.child(label))
+                                .when_some(activity_progress.filter(|_| !compact), |el, progress| {
+                                    el.child(
+                                        div()
+                                            .w(px(48.0))
+                                            .h(px(3.0))
+                                            .rounded_full()
+                                            .bg(border_color)
+                                            .child(
+                                                div()
+                                                    .h_full()
+                                                    .rounded_full()
+                                                    .bg(accent)
+                                                    .w(px(48.0 * progress)),
+                                            ),
+                                    )
+                                }),
+                        )
+                    })
+                    .when(unreadable_count > 0, |el| {
+                        el.child(
+                            div()
+                                .relative()
+                                .child(
+                                    div()
+                                        .id("status-unreadable-toggle")
+                                        .tooltip_label(format_unreadable_count(unreadable_count))
+                                        .flex()
+                                        .items_center()
+                                        .gap_1()
+                                        .px_1p5()
+                                        .py_0p5()
+                                        .rounded_sm()
+                                        .cursor_pointer()
+                                        .text_color(warning)
+                                        .hover(|s| s.bg(hover_bg))
+                                        .when(unreadable_open, |s| s.bg(hover_bg))
+                                        .on_mouse_down(
+                                            MouseButton::Left,
+                                            cx.listener(|view, _event, _window, cx| {
+                                                view.unreadable_open = !view.unreadable_open;
+                                                cx.notify();
+                                            }),
+                                        )
+                                        .child(
+                                            svg()
+                                                .path("assets/icons/triangle-alert.svg")
+                                                .size(px(12.0))
+                                                .text_color(warning),
+                                        )
+                                        .when(!compact, |el| {
+                                            el.child(format_unreadable_count(unreadable_count))
+                                        }),
+                                )
+                                .when(unreadable_open, |el| {
+                                    el.child(self.render_unreadable_details(cx))
+                                }),
+                        )
+                    })
                     .when(selected_count > 0, |el| {
                         el.child(
                             div()
@@ -287,6 +703,26 @@ impl Render for StatusBarView {
                     .flex()
                     .items_center()
                     .gap_2()
+                    .when_some(volume_space, |el, space| {
+                        let color = space.level().color(&theme);
+                        el.child(
+                            div()
+                                .id("status-free-space")
+                                .tooltip_label(space.tooltip())
+                                .flex()
+                                .items_center()
+                                .gap_1()
+                                .text_color(color)
+                                .child(
+                                    svg()
+                                        .path("assets/icons/hard-drive.svg")
+                                        .size(px(12.0))
+                                        .text_color(color),
+                                )
+                                .when(!compact, |el| el.child(space.label())),
+                        )
+                        .child(div().h(px(12.0)).w(px(1.0)).bg(border_color).mx_1())
+                    })
                     .when_some(git_branch, |el, branch| {
                         el.child(
                             div()
@@ -307,6 +743,9 @@ impl Render for StatusBarView {
                     .child(
                         div()
                             .id("status-terminal-toggle")
+                            .icon_control(IconControl::Terminal {
+                                open: is_terminal_open,
+                            })
                             .flex()
                             .items_center()
                             .gap_1()
@@ -340,9 +779,60 @@ impl Render for StatusBarView {
                             ),
                     )
                     .child(div().h(px(12.0)).w(px(1.0)).bg(border_color))
+                    .child(
+                        div()
+                            .relative()
+                            .child(
+                                div()
+                                    .id("status-sort-toggle")
+                                    .tooltip_label(format!(
+                                        "Sort by {}",
+                                        sort_column_label(sort_state.column)
+                                    ))
+                                    .flex()
+                                    .items_center()
+                                    .gap_1()
+                                    .px_1p5()
+                                    .py_0p5()
+                                    .rounded_sm()
+                                    .cursor_pointer()
+                                    .hover(|s| s.bg(hover_bg))
+                                    .when(sort_menu_open, |s| s.bg(hover_bg))
+                                    .on_mouse_down(
+                                        MouseButton::Left,
+                                        cx.listener(|view, _event, _window, cx| {
+                                            view.sort_menu_open = !view.sort_menu_open;
+                                            cx.notify();
+                                        }),
+                                    )
+                                    .child(
+                                        svg()
+                                            .path("assets/icons/arrow-down-up.svg")
+                                            .size(px(12.0))
+                                            .text_color(text_muted),
+                                    )
+                                    .when(!compact, |el| {
+                                        el.child(
+                                            div()
+                                                .text_color(text_muted)
+                                                .child(sort_column_label(sort_state.column)),
+                                        )
+                                    }),
+                            )
+                            .when(sort_menu_open, |el| {
+                                el.child(self.render_sort_menu(compact, cx))
+                            }),
+                    )
+                    .child(div().h(px(12.0)).w(px(1.0)).bg(border_color))
                     .child(
                         div()
                             .id("status-view-toggle")
+                            .icon_control(match view_mode {
+                                ViewMode::Grid => IconControl::ListView { active: false },
+                                ViewMode::List | ViewMode::Details => {
+                                    IconControl::GridView { active: false }
+                                }
+                            })
                             .flex()
                             .items_center()
                             .gap_1()
@@ -369,10 +859,12 @@ impl Render for StatusBarView {
                                     .size(px(12.0))
                                     .text_color(text_muted),
                             )
-                            .child(div().text_color(text_muted).child(match view_mode {
-                                ViewMode::List | ViewMode::Details => "List",
-                                ViewMode::Grid => "Grid",
-                            })),
+                            .when(!compact, |el| {
+                                el.child(div().text_color(text_muted).child(match view_mode {
+                                    ViewMode::List | ViewMode::Details => "List",
+                                    ViewMode::Grid => "Grid",
+                                }))
+                            }),
                     ),
             )
     }
@@ -408,6 +900,9 @@ mod tests {
             symlink_target: None,
             is_broken_symlink: false,
             is_shared: false,
+            hidden_kind: crate::models::HiddenKind::Visible,
+            created: None,
+            unix_mode: None,
         }
     }
 
@@ -423,6 +918,24 @@ mod tests {
         assert!(!state.is_terminal_open);
     }
 
+    #[test]
+    fn test_activity_indicator_counts_operations_and_hides_when_idle() {
+        let mut state = StatusBarState::default();
+        assert!(state.activity().is_idle());
+        assert_eq!(state.activity().summary(), None);
+
+        state.background.push("Copying", Some(0.5));
+        state.background.push("Compressing", None);
+        state.indexing = IndexingStatus::Scanning { scanned: 12 };
+        assert_eq!(state.activity().count(), 3);
+        assert_eq!(state.activity().summary().as_deref(), Some("3 operations running"));
+
+        state.indexing = IndexingStatus::Ready { total: 12 };
+        state.background = BackgroundActivity::new();
+        assert_eq!(state.activity().count(), 0);
+        assert_eq!(state.activity().summary(), None);
+    }
+
     #[test]
     fn test_update_from_file_list_no_selection() {
         let mut state = StatusBarState::new();
@@ -471,6 +984,49 @@ mod tests {
         assert_eq!(state.selected_size, 400);
     }
 
+    #[test]
+    fn test_item_breakdown_counts_mixed_entries() {
+        let mut state = StatusBarState::new();
+        let entries = vec![
+            create_test_entry("src", true, 0),
+            create_test_entry("docs", true, 0),
+            create_test_entry("main.rs", false, 100),
+            create_test_entry("README.md", false, 50),
+            create_test_entry("Cargo.toml", false, 20),
+        ];
+
+        state.update_from_file_list(&entries, None);
+
+        assert_eq!(state.total_items, 5);
+        assert_eq!(state.folder_count, 2);
+        assert_eq!(state.file_count, 3);
+        assert_eq!(state.item_breakdown(), "2 folders, 3 files");
+    }
+
+    #[test]
+    fn test_format_item_breakdown() {
+        assert_eq!(format_item_breakdown(0, 0), "No items");
+        assert_eq!(format_item_breakdown(1, 0), "1 folder");
+        assert_eq!(format_item_breakdown(0, 1), "1 file");
+        assert_eq!(format_item_breakdown(12, 48), "12 folders, 48 files");
+        assert_eq!(format_item_breakdown(1, 1), "1 folder, 1 file");
+    }
+
+    #[test]
+    fn test_unreadable_items_from_errors() {
+        let errors = vec![(
+            PathBuf::from("/data/locked"),
+            std::io::Error::new(std::io::ErrorKind::PermissionDenied, "Permission denied"),
+        )];
+
+        let items = UnreadableItem::from_errors(&errors);
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].path, PathBuf::from("/data/locked"));
+        assert_eq!(items[0].reason, "Permission denied");
+        assert_eq!(format_unreadable_count(1), "1 item could not be read");
+        assert_eq!(format_unreadable_count(3), "3 items could not be read");
+    }
+
     #[test]
     fn test_format_size() {
         assert_eq!(format_size(0), "0 B");
@@ -2,10 +2,11 @@ use std::path::PathBuf;
 
 use gpui::{
     div, prelude::*, px, svg, App, Context, Entity, FocusHandle, Focusable, InteractiveElement,
-    IntoElement, ParentElement, Render, Styled, Window,
+    IntoElement, KeyDownEvent, ParentElement, Render, Styled, Window,
 };
 
 use crate::models::theme_colors;
+use crate::views::{focus_ring, FocusTrap, TrapKey};
 use adabraka_ui::components::input::{InputEvent, InputState};
 
 
@@ -21,9 +22,14 @@ pub struct SymlinkDialog {
     link_name_input: Entity<InputState>,
     link_location_input: Entity<InputState>,
     focus_handle: FocusHandle,
+    focus_trap: FocusTrap,
     pending_action: Option<SymlinkDialogAction>,
 }
 
+
+const CANCEL_FOCUS: usize = 2;
+const CREATE_FOCUS: usize = 3;
+
 impl SymlinkDialog {
     pub fn new(target_path: PathBuf, default_location: PathBuf, cx: &mut Context<Self>) -> Self {
         let target_name = target_path
@@ -59,11 +65,19 @@ impl SymlinkDialog {
         })
         .detach();
 
+        let focus_trap = FocusTrap::new(vec![
+            link_name_input.read(cx).focus_handle(cx),
+            link_location_input.read(cx).focus_handle(cx),
+            cx.focus_handle(),
+            cx.focus_handle(),
+        ]);
+
         Self {
             target_path,
             link_name_input,
             link_location_input,
             focus_handle: cx.focus_handle(),
+            focus_trap,
             pending_action: None,
         }
     }
@@ -72,6 +86,29 @@ impl SymlinkDialog {
         self.pending_action.take()
     }
 
+
+    pub fn take_restore_focus(&mut self) -> Option<FocusHandle> {
+        self.focus_trap.take_restore_target()
+    }
+
+    fn handle_key_down(&mut self, event: &KeyDownEvent, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(key) = TrapKey::from_keystroke(&event.keystroke) else {
+            return;
+        };
+        match key {
+            TrapKey::Next | TrapKey::Previous => {
+                self.focus_trap.cycle(key == TrapKey::Previous, window);
+            }
+            TrapKey::Dismiss => self.cancel(cx),
+            TrapKey::Confirm => match self.focus_trap.focused_index(window) {
+                Some(CANCEL_FOCUS) => self.cancel(cx),
+                Some(CREATE_FOCUS) => self.submit(cx),
+                _ => return,
+            },
+        }
+        cx.stop_propagation();
+    }
+
     fn submit(&mut self, cx: &mut Context<Self>) {
         let link_name = self.link_name_input.read(cx).content.to_string();
         let link_location = self.link_location_input.read(cx).content.to_string();
@@ -102,7 +139,11 @@ impl Focusable for SymlinkDialog {
 }
 
 impl Render for SymlinkDialog {
-    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+    fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        self.focus_trap.activate(window, cx);
+        let cancel_focused = self.focus_trap.is_focused(CANCEL_FOCUS, window);
+        let create_focused = self.focus_trap.is_focused(CREATE_FOCUS, window);
+
         let colors = theme_colors();
         let bg_primary = colors.bg_primary;
         let bg_secondary = colors.bg_secondary;
@@ -117,6 +158,7 @@ impl Render for SymlinkDialog {
         div()
             .id("symlink-dialog")
             .track_focus(&self.focus_handle)
+            .on_key_down(cx.listener(Self::handle_key_down))
             .w(px(480.0))
             .bg(bg_primary)
             .border_1()
@@ -225,6 +267,7 @@ impl Render for SymlinkDialog {
                     .child(
                         div()
                             .id("cancel-btn")
+                            .track_focus(self.focus_trap.handle(CANCEL_FOCUS))
                             .px_4()
                             .py_2()
                             .rounded_md()
@@ -237,11 +280,13 @@ impl Render for SymlinkDialog {
                             .on_click(cx.listener(|dialog, _, _, cx| {
                                 dialog.cancel(cx);
                             }))
+                            .map(|el| focus_ring(el, cancel_focused))
                             .child("Cancel"),
                     )
                     .child(
                         div()
                             .id("create-btn")
+                            .track_focus(self.focus_trap.handle(CREATE_FOCUS))
                             .px_4()
                             .py_2()
                             .rounded_md()
@@ -253,6 +298,7 @@ impl Render for SymlinkDialog {
                             .on_click(cx.listener(|dialog, _, _, cx| {
                                 dialog.submit(cx);
                             }))
+                            .map(|el| focus_ring(el, create_focused))
                             .child("Create Link"),
                     ),
             )
@@ -1,34 +1,85 @@
 use gpui::{
-    div, prelude::*, px, svg, App, Context, FocusHandle, Focusable, InteractiveElement,
-    IntoElement, MouseButton, ParentElement, Render, SharedString, Styled, Window,
+    anchored, div, prelude::*, px, svg, App, Context, Corner, DragMoveEvent, ExternalPaths,
+    FocusHandle, Focusable, InteractiveElement, IntoElement, MouseButton, MouseDownEvent,
+    ParentElement, Pixels, Point, Render, SharedString, Styled, Window,
 };
 
-use crate::models::{theme_colors, TabId, TabState};
+use crate::models::{
+    tab_drop_action, theme_colors, GlobalSettings, TabDropAction, TabDropTarget, TabId, TabState,
+};
+
+
+#[derive(Clone)]
+pub struct DraggedTab {
+    pub id: TabId,
+    pub title: String,
+}
+
+
+pub struct DraggedTabView {
+    title: String,
+}
+
+impl Render for DraggedTabView {
+    fn render(&mut self, _window: &mut Window, _cx: &mut Context<Self>) -> impl IntoElement {
+        let theme = theme_colors();
+        div()
+            .px_3()
+            .py_1()
+            .bg(theme.bg_tertiary)
+            .border_1()
+            .border_color(theme.border_subtle)
+            .rounded_md()
+            .text_xs()
+            .text_color(theme.text_primary)
+            .child(self.title.clone())
+    }
+}
+
+#[derive(Clone, Copy)]
+enum BulkClose {
+    Others(TabId),
+    ToRight(TabId),
+    All,
+}
+
 
 pub struct TabBarView {
     tab_state: TabState,
     focus_handle: FocusHandle,
     pending_navigation: Option<TabId>,
-    pending_close: Option<TabId>,
+    pending_closes: Vec<TabId>,
     pending_new_tab: bool,
+    pending_drop: Option<TabDropAction>,
     scroll_offset: f32,
     max_visible_tabs: usize,
     hovered_tab: Option<TabId>,
     dragging_tab: Option<TabId>,
+    drop_slot: Option<usize>,
+    tab_menu: Option<(TabId, Point<Pixels>)>,
 }
 
 impl TabBarView {
     pub fn new(initial_path: std::path::PathBuf, cx: &mut Context<Self>) -> Self {
+        let pinned_paths: Vec<std::path::PathBuf> = GlobalSettings::load()
+            .pinned_tabs
+            .into_iter()
+            .filter(|path| path.is_dir())
+            .collect();
+
         Self {
-            tab_state: TabState::new(initial_path),
+            tab_state: TabState::with_pinned(initial_path, &pinned_paths),
             focus_handle: cx.focus_handle(),
             pending_navigation: None,
-            pending_close: None,
+            pending_closes: Vec::new(),
             pending_new_tab: false,
+            pending_drop: None,
             scroll_offset: 0.0,
             max_visible_tabs: 12,
             hovered_tab: None,
             dragging_tab: None,
+            drop_slot: None,
+            tab_menu: None,
         }
     }
 
@@ -37,12 +88,15 @@ impl TabBarView {
             tab_state,
             focus_handle: cx.focus_handle(),
             pending_navigation: None,
-            pending_close: None,
+            pending_closes: Vec::new(),
             pending_new_tab: false,
+            pending_drop: None,
             scroll_offset: 0.0,
             max_visible_tabs: 12,
             hovered_tab: None,
             dragging_tab: None,
+            drop_slot: None,
+            tab_menu: None,
         }
     }
 
@@ -61,6 +115,12 @@ impl TabBarView {
         id
     }
 
+    pub fn open_background_tab(&mut self, path: std::path::PathBuf, cx: &mut Context<Self>) -> TabId {
+        let id = self.tab_state.open_background_tab(path);
+        cx.notify();
+        id
+    }
+
     pub fn close_tab(&mut self, id: TabId, cx: &mut Context<Self>) -> bool {
         let result = self.tab_state.close_tab(id);
         cx.notify();
@@ -82,21 +142,43 @@ impl TabBarView {
 
     pub fn navigate_to(&mut self, path: std::path::PathBuf, cx: &mut Context<Self>) {
         self.tab_state.navigate_active_to(path);
+        if self.tab_state.active_tab().pinned {
+            self.save_pinned_tabs();
+        }
         cx.notify();
     }
 
+    pub fn set_pinned(&mut self, id: TabId, pinned: bool, cx: &mut Context<Self>) {
+        if self.tab_state.set_pinned(id, pinned) {
+            self.save_pinned_tabs();
+            self.ensure_tab_visible(self.tab_state.active_index());
+        }
+        self.tab_menu = None;
+        cx.notify();
+    }
+
+    fn save_pinned_tabs(&self) {
+        let mut settings = GlobalSettings::load();
+        settings.pinned_tabs = self.tab_state.pinned_paths();
+        let _ = settings.save();
+    }
+
     pub fn take_pending_navigation(&mut self) -> Option<TabId> {
         self.pending_navigation.take()
     }
 
-    pub fn take_pending_close(&mut self) -> Option<TabId> {
-        self.pending_close.take()
+    pub fn take_pending_closes(&mut self) -> Vec<TabId> {
+        std::mem::take(&mut self.pending_closes)
     }
 
     pub fn take_pending_new_tab(&mut self) -> bool {
         std::mem::take(&mut self.pending_new_tab)
     }
 
+    pub fn take_pending_drop(&mut self) -> Option<TabDropAction> {
+        self.pending_drop.take()
+    }
+
     pub fn tab_count(&self) -> usize {
         self.tab_state.tab_count()
     }
@@ -133,11 +215,47 @@ impl TabBarView {
     }
 
     fn handle_tab_close(&mut self, id: TabId, cx: &mut Context<Self>) {
-        self.pending_close = Some(id);
-        self.tab_state.close_tab(id);
+        self.tab_menu = None;
+        let was_active = self.tab_state.active_tab_id() == id;
+        if self.tab_state.close_tab(id) {
+            self.pending_closes.push(id);
+            if was_active {
+                self.pending_navigation = Some(self.tab_state.active_tab_id());
+            }
+        }
         cx.notify();
     }
 
+    fn handle_bulk_close(&mut self, action: BulkClose, cx: &mut Context<Self>) {
+        self.tab_menu = None;
+        let previous_active = self.tab_state.active_tab_id();
+        let closed = match action {
+            BulkClose::Others(id) => self.tab_state.close_other_tabs(id),
+            BulkClose::ToRight(id) => self.tab_state.close_tabs_to_right(id),
+            BulkClose::All => self.tab_state.close_unpinned_tabs(),
+        };
+
+        let active = self.tab_state.active_tab_id();
+        if active != previous_active {
+            self.pending_navigation = Some(active);
+        }
+        self.pending_closes.extend(closed);
+        self.scroll_offset = 0.0;
+        self.ensure_tab_visible(self.tab_state.active_index());
+        cx.notify();
+    }
+
+    fn open_tab_menu(&mut self, id: TabId, position: Point<Pixels>, cx: &mut Context<Self>) {
+        self.tab_menu = Some((id, position));
+        cx.notify();
+    }
+
+    fn close_tab_menu(&mut self, cx: &mut Context<Self>) {
+        if self.tab_menu.take().is_some() {
+            cx.notify();
+        }
+    }
+
     fn handle_new_tab(&mut self, cx: &mut Context<Self>) {
         self.pending_new_tab = true;
         let home = dirs::home_dir().unwrap_or_else(|| std::path::PathBuf::from("/"));
@@ -145,7 +263,56 @@ impl TabBarView {
     }
 
     fn handle_tab_middle_click(&mut self, id: TabId, cx: &mut Context<Self>) {
-        self.handle_tab_close(id, cx);
+        let is_pinned = self.tab_state.get_tab(id).is_some_and(|tab| tab.pinned);
+        if !is_pinned {
+            self.handle_tab_close(id, cx);
+        }
+    }
+
+    fn set_drop_slot(&mut self, slot: Option<usize>, cx: &mut Context<Self>) {
+        if self.drop_slot != slot {
+            self.drop_slot = slot;
+            cx.notify();
+        }
+    }
+
+    fn handle_external_drop(
+        &mut self,
+        target: TabDropTarget,
+        paths: &ExternalPaths,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(action) = paths
+            .paths()
+            .first()
+            .and_then(|path| tab_drop_action(target, path))
+        else {
+            return;
+        };
+
+        match &action {
+            TabDropAction::Navigate { tab, .. } => {
+                self.switch_to(*tab, cx);
+            }
+            TabDropAction::OpenTab { path, .. } => {
+                self.open_tab(path.clone(), cx);
+            }
+        }
+        self.pending_drop = Some(action);
+        cx.notify();
+    }
+
+    fn handle_tab_drop(&mut self, id: TabId, cx: &mut Context<Self>) {
+        self.dragging_tab = None;
+        if let Some(slot) = self.drop_slot.take() {
+            if self.tab_state.move_tab_to_slot(id, slot) {
+                if self.tab_state.get_tab(id).is_some_and(|tab| tab.pinned) {
+                    self.save_pinned_tabs();
+                }
+                self.ensure_tab_visible(self.tab_state.active_index());
+            }
+        }
+        cx.notify();
     }
 }
 
@@ -164,6 +331,15 @@ impl Render for TabBarView {
         let scroll_offset = self.scroll_offset as usize;
         let entity = cx.entity().clone();
 
+        if !cx.has_active_drag() {
+            self.dragging_tab = None;
+            self.drop_slot = None;
+        }
+        let dragging_tab = self.dragging_tab;
+        let drop_slot = self.drop_slot;
+        let tab_menu = self.tab_menu;
+        let visible_end = tabs.len().min(scroll_offset + self.max_visible_tabs);
+
         div()
             .id("tab-bar")
             .h(px(38.0))
@@ -201,6 +377,24 @@ impl Render for TabBarView {
                     .items_center()
                     .gap_0p5()
                     .overflow_hidden()
+                    .on_drag_move(cx.listener(
+                        move |view, event: &DragMoveEvent<DraggedTab>, _window, cx| {
+                            let position = event.event.position;
+                            let bounds = event.bounds;
+                            let slot = if position.y < bounds.top() || position.y > bounds.bottom()
+                            {
+                                None
+                            } else if position.x < bounds.left() {
+                                Some(scroll_offset)
+                            } else {
+                                Some(visible_end)
+                            };
+                            view.set_drop_slot(slot, cx);
+                        },
+                    ))
+                    .on_drop(cx.listener(|view, dragged: &DraggedTab, _window, cx| {
+                        view.handle_tab_drop(dragged.id, cx);
+                    }))
                     .children(
                         tabs.iter()
                             .enumerate()
@@ -215,6 +409,12 @@ impl Render for TabBarView {
                                 let entity_click = entity.clone();
                                 let entity_close = entity.clone();
                                 let entity_middle = entity.clone();
+                                let entity_drag = entity.clone();
+                                let drag_title = title.clone();
+                                let is_dragged = dragging_tab == Some(tab_id);
+                                let drop_before = drop_slot == Some(index);
+                                let drop_after =
+                                    drop_slot == Some(index + 1) && index + 1 == visible_end;
 
                                 div()
                                     .id(SharedString::from(format!("tab-{}", tab_id.0)))
@@ -233,6 +433,54 @@ impl Render for TabBarView {
                                             .border_color(theme.border_subtle)
                                     })
                                     .when(!is_active, |s| s.hover(|h| h.bg(theme.bg_hover)))
+                                    .when(is_dragged, |s| s.opacity(0.5))
+                                    .when(drop_before, |s| {
+                                        s.border_l_2().border_color(theme.accent_primary)
+                                    })
+                                    .when(drop_after, |s| {
+                                        s.border_r_2().border_color(theme.accent_primary)
+                                    })
+                                    .on_drag(
+                                        DraggedTab {
+                                            id: tab_id,
+                                            title: drag_title,
+                                        },
+                                        move |dragged: &DraggedTab, _position, _window, cx| {
+                                            entity_drag.update(cx, |view, _| {
+                                                view.dragging_tab = Some(dragged.id);
+                                            });
+                                            let title = dragged.title.clone();
+                                            cx.new(|_| DraggedTabView { title })
+                                        },
+                                    )
+                                    .on_drag_move(cx.listener(
+                                        move |view, event: &DragMoveEvent<DraggedTab>, _window, cx| {
+                                            let position = event.event.position;
+                                            if event.bounds.contains(&position) {
+                                                let slot = if position.x < event.bounds.center().x {
+                                                    index
+                                                } else {
+                                                    index + 1
+                                                };
+                                                view.set_drop_slot(Some(slot), cx);
+                                            }
+                                        },
+                                    ))
+                                    .drag_over::<ExternalPaths>(move |style, _, _, _| {
+                                        style
+                                            .bg(theme.bg_selected)
+                                            .border_1()
+                                            .border_color(theme.accent_primary)
+                                    })
+                                    .on_drop(cx.listener(
+                                        move |view, paths: &ExternalPaths, _window, cx| {
+                                            view.handle_external_drop(
+                                                TabDropTarget::Tab(tab_id),
+                                                paths,
+                                                cx,
+                                            );
+                                        },
+                                    ))
                                     .on_mouse_down(MouseButton::Left, move |_, _, cx| {
                                         entity_click.update(cx, |view, cx| {
                                             view.handle_tab_click(tab_id, cx);
@@ -243,6 +491,12 @@ impl Render for TabBarView {
                                             view.handle_tab_middle_click(tab_id, cx);
                                         });
                                     })
+                                    .on_mouse_down(
+                                        MouseButton::Right,
+                                        cx.listener(move |view, event: &MouseDownEvent, _, cx| {
+                                            view.open_tab_menu(tab_id, event.position, cx);
+                                        }),
+                                    )
                                     .child(
                                         svg()
                                             .path("assets/icons/folder.svg")
@@ -350,6 +604,15 @@ impl Render for TabBarView {
                     .justify_center()
                     .cursor_pointer()
                     .hover(|h| h.bg(theme.bg_hover))
+                    .drag_over::<ExternalPaths>(move |style, _, _, _| {
+                        style
+                            .bg(theme.bg_selected)
+                            .border_1()
+                            .border_color(theme.accent_primary)
+                    })
+                    .on_drop(cx.listener(|view, paths: &ExternalPaths, _window, cx| {
+                        view.handle_external_drop(TabDropTarget::NewTab, paths, cx);
+                    }))
                     .on_mouse_down(MouseButton::Left, {
                         let entity = entity.clone();
                         move |_, _, cx| {
@@ -363,5 +626,127 @@ impl Render for TabBarView {
                             .text_color(theme.text_muted),
                     ),
             )
+            .when_some(tab_menu, |this, (tab_id, position)| {
+                this.child(self.render_tab_menu(tab_id, position, cx))
+            })
+    }
+}
+
+impl TabBarView {
+    fn render_tab_menu(
+        &self,
+        tab_id: TabId,
+        position: Point<Pixels>,
+        cx: &mut Context<Self>,
+    ) -> impl IntoElement {
+        let theme = theme_colors();
+        let is_pinned = self
+            .tab_state
+            .get_tab(tab_id)
+            .is_some_and(|tab| tab.pinned);
+        let tabs = self.tab_state.tabs();
+        let tab_index = tabs.iter().position(|t| t.id == tab_id).unwrap_or(0);
+        let has_others = tabs.iter().any(|t| t.id != tab_id && !t.pinned);
+        let has_right = tabs[tab_index + 1..].iter().any(|t| !t.pinned);
+        let has_unpinned = tabs.iter().filter(|t| !t.pinned).count() > 1
+            || (self.tab_state.pinned_count() > 0 && tabs.iter().any(|t| !t.pinned));
+        let menu_item = |id: &str, label: &'static str, enabled: bool| {
+            div()
+                .id(SharedString::from(format!("{}-{}", id, tab_id.0)))
+                .px_3()
+                .py_1p5()
+                .rounded_md()
+                .text_sm()
+                .when(enabled, |s| {
+                    s.cursor_pointer()
+                        .text_color(theme.text_primary)
+                        .hover(|h| h.bg(theme.bg_hover))
+                })
+                .when(!enabled, |s| s.text_color(theme.text_muted).opacity(0.6))
+                .child(label)
+        };
+
+        anchored()
+            .snap_to_window_with_margin(px(8.0))
+            .anchor(Corner::TopLeft)
+            .position(position)
+            .child(
+                div()
+                    .id("tab-context-menu")
+                    .occlude()
+                    .min_w(px(180.0))
+                    .p_1()
+                    .bg(theme.bg_tertiary)
+                    .border_1()
+                    .border_color(theme.border_default)
+                    .rounded_lg()
+                    .shadow_lg()
+                    .flex()
+                    .flex_col()
+                    .on_mouse_down_out(cx.listener(|view, _, _, cx| {
+                        view.close_tab_menu(cx);
+                    }))
+                    .child(
+                        menu_item(
+                            "tab-menu-pin",
+                            if is_pinned { "Unpin Tab" } else { "Pin Tab" },
+                            true,
+                        )
+                        .on_mouse_down(
+                            MouseButton::Left,
+                            cx.listener(move |view, _, _, cx| {
+                                view.set_pinned(tab_id, !is_pinned, cx);
+                            }),
+                        ),
+                    )
+                    .child(
+                        menu_item("tab-menu-close", "Close Tab", !is_pinned).when(
+                            !is_pinned,
+                            |s| {
+                                s.on_mouse_down(
+                                    MouseButton::Left,
+                                    cx.listener(move |view, _, _, cx| {
+                                        view.handle_tab_close(tab_id, cx);
+                                    }),
+                                )
+                            },
+                        ),
+                    )
+                    .child(
+                        menu_item("tab-menu-close-others", "Close Other Tabs", has_others)
+                            .when(has_others, |s| {
+                                s.on_mouse_down(
+                                    MouseButton::Left,
+                                    cx.listener(move |view, _, _, cx| {
+                                        view.handle_bulk_close(BulkClose::Others(tab_id), cx);
+                                    }),
+                                )
+                            }),
+                    )
+                    .child(
+                        menu_item("tab-menu-close-right", "Close Tabs to the Right", has_right)
+                            .when(has_right, |s| {
+                                s.on_mouse_down(
+                                    MouseButton::Left,
+                                    cx.listener(move |view, _, _, cx| {
+                                        view.handle_bulk_close(BulkClose::ToRight(tab_id), cx);
+                                    }),
+                                )
+                            }),
+                    )
+                    .child(
+                        menu_item("tab-menu-close-all", "Close All", has_unpinned).when(
+                            has_unpinned,
+                            |s| {
+                                s.on_mouse_down(
+                                    MouseButton::Left,
+                                    cx.listener(move |view, _, _, cx| {
+                                        view.handle_bulk_close(BulkClose::All, cx);
+                                    }),
+                                )
+                            },
+                        ),
+                    ),
+            )
     }
 }
@@ -0,0 +1,313 @@
+use std::path::PathBuf;
+
+use gpui::{
+    div, prelude::*, px, svg, App, Context, FocusHandle, Focusable, InteractiveElement,
+    IntoElement, KeyDownEvent, ParentElement, Render, SharedString, Styled, Window,
+};
+
+use crate::models::{theme_colors, BulkTagEdit, Tag, TagCheckState, TagDiff};
+use crate::views::{focus_ring, render_tag_dot, FocusTrap, TrapKey};
+
+
+#[derive(Clone, Debug)]
+pub enum BulkTagDialogAction {
+    Apply { paths: Vec<PathBuf>, diff: TagDiff },
+    Cancel,
+}
+
+
+pub struct BulkTagDialog {
+    edit: BulkTagEdit,
+    tags: Vec<Tag>,
+    focus_handle: FocusHandle,
+    focus_trap: FocusTrap,
+    pending_action: Option<BulkTagDialogAction>,
+}
+
+
+const CANCEL_FOCUS: usize = 0;
+const APPLY_FOCUS: usize = 1;
+
+impl BulkTagDialog {
+    pub fn new(edit: BulkTagEdit, mut tags: Vec<Tag>, cx: &mut Context<Self>) -> Self {
+        tags.sort_by_key(|tag| tag.id.0);
+
+        Self {
+            edit,
+            tags,
+            focus_handle: cx.focus_handle(),
+            focus_trap: FocusTrap::new(vec![cx.focus_handle(), cx.focus_handle()]),
+            pending_action: None,
+        }
+    }
+
+    pub fn take_pending_action(&mut self) -> Option<BulkTagDialogAction> {
+        self.pending_action.take()
+    }
+
+
+    pub fn take_restore_focus(&mut self) -> Option<FocusHandle> {
+        self.focus_trap.take_restore_target()
+    }
+
+    fn handle_key_down(&mut self, event: &KeyDownEvent, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(key) = TrapKey::from_keystroke(&event.keystroke) else {
+            return;
+        };
+        match key {
+            TrapKey::Next | TrapKey::Previous => {
+                self.focus_trap.cycle(key == TrapKey::Previous, window);
+            }
+            TrapKey::Dismiss => self.cancel(cx),
+            TrapKey::Confirm => match self.focus_trap.focused_index(window) {
+                Some(CANCEL_FOCUS) => self.cancel(cx),
+                _ => self.apply(cx),
+            },
+        }
+        cx.stop_propagation();
+    }
+
+    fn apply(&mut self, cx: &mut Context<Self>) {
+        let diff = self.edit.diff();
+        self.pending_action = Some(if diff.is_empty() {
+            BulkTagDialogAction::Cancel
+        } else {
+            BulkTagDialogAction::Apply {
+                paths: self.edit.paths().to_vec(),
+                diff,
+            }
+        });
+        cx.notify();
+    }
+
+    fn cancel(&mut self, cx: &mut Context<Self>) {
+        self.pending_action = Some(BulkTagDialogAction::Cancel);
+        cx.notify();
+    }
+
+    fn render_tag_row(&self, tag: &Tag, cx: &mut Context<Self>) -> impl IntoElement {
+        let colors = theme_colors();
+        let state = self.edit.state(tag.id);
+        let tag_id = tag.id;
+        let checked = state != TagCheckState::Unchecked;
+
+        let checkbox = div()
+            .size(px(16.0))
+            .flex_shrink_0()
+            .rounded(px(4.0))
+            .border_1()
+            .flex()
+            .items_center()
+            .justify_center()
+            .map(|el| {
+                if checked {
+                    el.bg(colors.accent_primary).border_color(colors.accent_primary)
+                } else {
+                    el.border_color(colors.border_default)
+                }
+            })
+            .map(|el| match state {
+                TagCheckState::Checked => el.child(
+                    svg()
+                        .path("assets/icons/check.svg")
+                        .size(px(12.0))
+                        .text_color(gpui::rgb(0xffffff)),
+                ),
+                TagCheckState::Mixed => el.child(
+                    svg()
+                        .path("assets/icons/minus.svg")
+                        .size(px(12.0))
+                        .text_color(gpui::rgb(0xffffff)),
+                ),
+                TagCheckState::Unchecked => el,
+            });
+
+        div()
+            .id(SharedString::from(format!("bulk-tag-{}", tag.id.0)))
+            .px_3()
+            .py(px(6.0))
+            .flex()
+            .items_center()
+            .gap_2()
+            .rounded_md()
+            .cursor_pointer()
+            .hover(|s| s.bg(colors.bg_hover))
+            .on_click(cx.listener(move |dialog, _, _, cx| {
+                dialog.edit.toggle(tag_id);
+                cx.notify();
+            }))
+            .child(checkbox)
+            .child(render_tag_dot(tag.color))
+            .child(
+                div()
+                    .flex_1()
+                    .text_sm()
+                    .text_color(colors.text_primary)
+                    .child(tag.name.clone()),
+            )
+            .when(state == TagCheckState::Mixed, |el| {
+                el.child(
+                    div()
+                        .text_xs()
+                        .text_color(colors.text_muted)
+                        .child("Some items"),
+                )
+            })
+    }
+}
+
+impl Focusable for BulkTagDialog {
+    fn focus_handle(&self, _cx: &App) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for BulkTagDialog {
+    fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        self.focus_trap.activate(window, cx);
+        let cancel_focused = self.focus_trap.is_focused(CANCEL_FOCUS, window);
+        let apply_focused = self.focus_trap.is_focused(APPLY_FOCUS, window);
+
+        let colors = theme_colors();
+        let bg_primary = colors.bg_primary;
+        let bg_secondary = colors.bg_secondary;
+        let border_color = colors.border_default;
+        let text_primary = colors.text_primary;
+        let text_secondary = colors.text_secondary;
+        let accent_primary = colors.accent_primary;
+        let hover_bg = colors.bg_hover;
+
+        let count = self.edit.paths().len();
+        let subtitle = if count == 1 {
+            self.edit.paths()[0]
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string())
+                .unwrap_or_default()
+        } else {
+            format!("{} items selected", count)
+        };
+        let is_dirty = self.edit.is_dirty();
+        let rows: Vec<_> = self
+            .tags
+            .iter()
+            .map(|tag| self.render_tag_row(tag, cx).into_any_element())
+            .collect();
+
+        div()
+            .id("bulk-tag-dialog")
+            .track_focus(&self.focus_handle)
+            .on_key_down(cx.listener(Self::handle_key_down))
+            .w(px(360.0))
+            .bg(bg_primary)
+            .border_1()
+            .border_color(border_color)
+            .rounded_lg()
+            .shadow_xl()
+            .flex()
+            .flex_col()
+            .overflow_hidden()
+            .child(
+                div()
+                    .px_4()
+                    .py_3()
+                    .bg(bg_secondary)
+                    .border_b_1()
+                    .border_color(border_color)
+                    .flex()
+                    .items_center()
+                    .gap_2()
+                    .child(
+                        svg()
+                            .path("assets/icons/tag.svg")
+                            .size(px(18.0))
+                            .text_color(accent_primary),
+                    )
+                    .child(
+                        div()
+                            .flex()
+                            .flex_col()
+                            .child(
+                                div()
+                                    .text_sm()
+                                    .font_weight(gpui::FontWeight::SEMIBOLD)
+                                    .text_color(text_primary)
+                                    .child("Edit Tags"),
+                            )
+                            .child(
+                                div()
+                                    .text_xs()
+                                    .text_color(text_secondary)
+                                    .child(subtitle),
+                            ),
+                    ),
+            )
+            .child(
+                div()
+                    .id("bulk-tag-list")
+                    .p_2()
+                    .max_h(px(320.0))
+                    .overflow_y_scroll()
+                    .flex()
+                    .flex_col()
+                    .when(rows.is_empty(), |el| {
+                        el.child(
+                            div()
+                                .p_3()
+                                .text_sm()
+                                .text_color(text_secondary)
+                                .child("No tags have been created yet"),
+                        )
+                    })
+                    .children(rows),
+            )
+            .child(
+                div()
+                    .px_4()
+                    .py_3()
+                    .bg(bg_secondary)
+                    .border_t_1()
+                    .border_color(border_color)
+                    .flex()
+                    .justify_end()
+                    .gap_2()
+                    .child(
+                        div()
+                            .id("bulk-tag-cancel-btn")
+                            .track_focus(self.focus_trap.handle(CANCEL_FOCUS))
+                            .px_4()
+                            .py_2()
+                            .rounded_md()
+                            .border_1()
+                            .border_color(border_color)
+                            .text_sm()
+                            .text_color(text_primary)
+                            .cursor_pointer()
+                            .hover(|s| s.bg(hover_bg))
+                            .on_click(cx.listener(|dialog, _, _, cx| {
+                                dialog.cancel(cx);
+                            }))
+                            .map(|el| focus_ring(el, cancel_focused))
+                            .child("Cancel"),
+                    )
+                    .child(
+                        div()
+                            .id("bulk-tag-apply-btn")
+                            .track_focus(self.focus_trap.handle(APPLY_FOCUS))
+                            .px_4()
+                            .py_2()
+                            .rounded_md()
+                            .bg(accent_primary)
+                            .text_sm()
+                            .text_color(gpui::rgb(0xffffff))
+                            .cursor_pointer()
+                            .when(!is_dirty, |el| el.opacity(0.5))
+                            .hover(|s| s.opacity(0.9))
+                            .on_click(cx.listener(|dialog, _, _, cx| {
+                                dialog.apply(cx);
+                            }))
+                            .map(|el| focus_ring(el, apply_focused))
+                            .child("Apply"),
+                    ),
+            )
+    }
+}
@@ -1,7 +1,17 @@
-use gpui::{div, prelude::*, px, Hsla, IntoElement, ParentElement, SharedString, Styled};
+use gpui::{
+    div, prelude::*, px, App, Hsla, IntoElement, MouseButton, ParentElement, SharedString,
+    Styled, Window,
+};
 
-use crate::models::{Tag, TagColor, TagId, TagManager};
+use crate::models::{FileEntry, Tag, TagColor, TagId, TagManager};
 use std::path::Path;
+use std::rc::Rc;
+
+
+pub const MAX_VISIBLE_TAG_DOTS: usize = 3;
+
+
+pub type TagSelectHandler = Rc<dyn Fn(&Tag, &mut Window, &mut App)>;
 
 
 pub fn render_tag_dot(color: TagColor) -> impl IntoElement {
@@ -17,18 +27,56 @@ pub fn render_tag_dot(color: TagColor) -> impl IntoElement {
 }
 
 
+pub fn tag_dot_layout<'a>(tags: &[&'a Tag]) -> (Vec<&'a Tag>, usize) {
+    let mut sorted = tags.to_vec();
+    sorted.sort_by_key(|tag| tag.id.0);
+    let overflow = sorted.len().saturating_sub(MAX_VISIBLE_TAG_DOTS);
+    sorted.truncate(MAX_VISIBLE_TAG_DOTS);
+    (sorted, overflow)
+}
+
+
+pub fn tags_for_entries(cx: &App, entries: &[FileEntry]) -> Vec<Vec<Tag>> {
+    match cx.try_global::<TagManager>() {
+        Some(manager) => entries
+            .iter()
+            .map(|entry| {
+                manager
+                    .tags_for_file(&entry.path)
+                    .into_iter()
+                    .cloned()
+                    .collect()
+            })
+            .collect(),
+        None => vec![Vec::new(); entries.len()],
+    }
+}
+
+
+pub fn tag_filter_query(tag: &Tag) -> String {
+    if tag
+        .name
+        .chars()
+        .any(|c| c.is_whitespace() || c == '"' || c == '\\')
+    {
+        let escaped = tag.name.replace('\\', "\\\\").replace('"', "\\\"");
+        format!("tag:\"{}\"", escaped)
+    } else {
+        format!("tag:{}", tag.name)
+    }
+}
+
+
 pub fn render_tag_dots(tags: &[&Tag]) -> impl IntoElement {
     let mut container = div().flex().items_center().gap(px(2.0)).flex_shrink_0();
 
-    let max_visible = 3;
-    let visible_tags = tags.iter().take(max_visible);
+    let (visible_tags, remaining) = tag_dot_layout(tags);
 
     for tag in visible_tags {
         container = container.child(render_tag_dot(tag.color));
     }
 
-    if tags.len() > max_visible {
-        let remaining = tags.len() - max_visible;
+    if remaining > 0 {
         container = container.child(
             div()
                 .text_xs()
@@ -47,15 +95,13 @@ pub fn render_file_tag_dots(tags: Vec<&Tag>) -> impl IntoElement {
     } else {
         let mut container = div().flex().items_center().gap(px(2.0)).flex_shrink_0();
 
-        let max_visible = 3;
-        let visible_tags = tags.iter().take(max_visible);
+        let (visible_tags, remaining) = tag_dot_layout(&tags);
 
         for tag in visible_tags {
             container = container.child(render_tag_dot(tag.color));
         }
 
-        if tags.len() > max_visible {
-            let remaining = tags.len() - max_visible;
+        if remaining > 0 {
             container = container.child(
                 div()
                     .text_xs()
@@ -69,6 +115,45 @@ pub fn render_file_tag_dots(tags: Vec<&Tag>) -> impl IntoElement {
 }
 
 
+pub fn render_tag_dot_buttons(
+    id_prefix: &str,
+    tags: &[Tag],
+    on_select: TagSelectHandler,
+) -> impl IntoElement {
+    let tag_refs: Vec<&Tag> = tags.iter().collect();
+    let (visible_tags, remaining) = tag_dot_layout(&tag_refs);
+
+    div()
+        .flex()
+        .items_center()
+        .gap(px(2.0))
+        .flex_shrink_0()
+        .children(visible_tags.into_iter().map(|tag| {
+            let tag = tag.clone();
+            let on_select = on_select.clone();
+            div()
+                .id(SharedString::from(format!("{}-tag-{}", id_prefix, tag.id.0)))
+                .p(px(1.0))
+                .rounded_full()
+                .cursor_pointer()
+                .hover(|s| s.bg(gpui::rgba(0xffffff22)))
+                .on_mouse_down(MouseButton::Left, move |_event, window, cx| {
+                    cx.stop_propagation();
+                    on_select(&tag, window, cx);
+                })
+                .child(render_tag_dot(tag.color))
+        }))
+        .when(remaining > 0, |this| {
+            this.child(
+                div()
+                    .text_xs()
+                    .text_color(gpui::rgb(0x8b949e))
+                    .child(format!("+{}", remaining)),
+            )
+        })
+}
+
+
 pub fn render_tag_filter_item(tag: &Tag, is_selected: bool, file_count: usize) -> impl IntoElement {
     let theme_bg_hover = gpui::rgb(0x161b22);
     let theme_bg_selected = gpui::rgb(0x1f3a5f);
@@ -167,7 +252,35 @@ pub fn parse_tag_query(query: &str) -> (String, Vec<String>) {
     let mut remaining_parts = Vec::new();
     let mut tag_filters = Vec::new();
 
-    for part in query.split_whitespace() {
+    let mut rest = query.trim_start();
+    while !rest.is_empty() {
+        if let Some(quoted) = rest.strip_prefix("tag:\"") {
+            let mut tag_name = String::new();
+            let mut end = quoted.len();
+            let mut chars = quoted.char_indices();
+            while let Some((idx, c)) = chars.next() {
+                match c {
+                    '\\' => {
+                        if let Some((_, escaped)) = chars.next() {
+                            tag_name.push(escaped);
+                        }
+                    }
+                    '"' => {
+                        end = idx + 1;
+                        break;
+                    }
+                    _ => tag_name.push(c),
+                }
+            }
+            if !tag_name.is_empty() {
+                tag_filters.push(tag_name);
+            }
+            rest = quoted[end..].trim_start();
+            continue;
+        }
+
+        let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+        let part = &rest[..end];
         if let Some(tag_name) = part.strip_prefix("tag:") {
             if !tag_name.is_empty() {
                 tag_filters.push(tag_name.to_string());
@@ -175,6 +288,7 @@ pub fn parse_tag_query(query: &str) -> (String, Vec<String>) {
         } else {
             remaining_parts.push(part);
         }
+        rest = rest[end..].trim_start();
     }
 
     (remaining_parts.join(" "), tag_filters)
@@ -212,6 +326,69 @@ mod tests {
         assert_eq!(tags, vec!["blue", "green"]);
     }
 
+    #[test]
+    fn test_three_tags_render_three_distinct_dots() {
+        let work = Tag::new(TagId::new(3), "work".to_string(), TagColor::Blue);
+        let urgent = Tag::new(TagId::new(1), "urgent".to_string(), TagColor::Red);
+        let done = Tag::new(TagId::new(2), "done".to_string(), TagColor::Green);
+
+        let (visible, remaining) = tag_dot_layout(&[&work, &urgent, &done]);
+
+        assert_eq!(remaining, 0);
+        assert_eq!(visible.len(), 3);
+        let colors: std::collections::HashSet<TagColor> =
+            visible.iter().map(|tag| tag.color).collect();
+        assert_eq!(colors.len(), 3);
+        let ids: Vec<u64> = visible.iter().map(|tag| tag.id.0).collect();
+        assert_eq!(ids, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_tag_dots_overflow_beyond_three() {
+        let tags: Vec<Tag> = TagColor::all()
+            .iter()
+            .take(5)
+            .enumerate()
+            .map(|(i, color)| Tag::new(TagId::new(i as u64), format!("t{}", i), *color))
+            .collect();
+        let refs: Vec<&Tag> = tags.iter().collect();
+
+        let (visible, remaining) = tag_dot_layout(&refs);
+
+        assert_eq!(visible.len(), MAX_VISIBLE_TAG_DOTS);
+        assert_eq!(remaining, 2);
+    }
+
+    #[test]
+    fn test_tag_filter_query() {
+        let tag = Tag::new(TagId::new(1), "work".to_string(), TagColor::Blue);
+        assert_eq!(parse_tag_query(&tag_filter_query(&tag)).1, vec!["work"]);
+    }
+
+    #[test]
+    fn test_tag_filter_query_multi_word_name() {
+        let tag = Tag::new(TagId::new(1), "Work Stuff".to_string(), TagColor::Blue);
+        let query = tag_filter_query(&tag);
+        assert_eq!(query, "tag:\"Work Stuff\"");
+
+        let (text, tags) = parse_tag_query(&query);
+        assert_eq!(text, "");
+        assert_eq!(tags, vec!["Work Stuff"]);
+
+        let (text, tags) = parse_tag_query(&format!("report {} draft", query));
+        assert_eq!(text, "report draft");
+        assert_eq!(tags, vec!["Work Stuff"]);
+    }
+
+    #[test]
+    fn test_tag_filter_query_escapes_quotes() {
+        let tag = Tag::new(TagId::new(1), "say \"hi\" \\ bye".to_string(), TagColor::Red);
+        assert_eq!(
+            parse_tag_query(&tag_filter_query(&tag)).1,
+            vec!["say \"hi\" \\ bye"]
+        );
+    }
+
     #[test]
     fn test_parse_tag_query_empty_tag() {
         let (query, tags) = parse_tag_query("tag: document");
@@ -7,7 +7,8 @@ use std::path::PathBuf;
 use std::time::{Duration, Instant};
 
 use crate::models::{
-    key_codes, theme_colors, AnsiParser, ClearMode, ParsedSegment, PtyService, TerminalState,
+    key_codes, terminal_palette, theme_colors, AnsiParser, ClearMode, ParsedSegment, PtyService,
+    TerminalPalette, TerminalState,
 };
 
 
@@ -28,6 +29,7 @@ const CURSOR_BLINK_INTERVAL_MS: u64 = 530;
 pub struct TerminalView {
     state: TerminalState,
     parser: AnsiParser,
+    palette: TerminalPalette,
     pty: Option<PtyService>,
     focus_handle: FocusHandle,
     is_visible: bool,
@@ -44,9 +46,11 @@ pub struct TerminalView {
 
 impl TerminalView {
     pub fn new(cx: &mut Context<Self>) -> Self {
+        let palette = terminal_palette();
         Self {
             state: TerminalState::default(),
-            parser: AnsiParser::new(),
+            parser: AnsiParser::with_palette(&palette),
+            palette,
             pty: None,
             focus_handle: cx.focus_handle(),
             is_visible: false,
@@ -196,6 +200,14 @@ impl TerminalView {
     }
 
 
+    fn sync_palette(&mut self) {
+        let palette = terminal_palette();
+        if palette != self.palette {
+            self.parser.set_palette(&palette);
+            self.palette = palette;
+        }
+    }
+
     pub fn process_output(&mut self) {
         if let Some(pty) = &self.pty {
             let output = pty.drain_output();
@@ -728,6 +740,11 @@ impl TerminalView {
 
 
     pub fn update_cursor_blink(&mut self) {
+        if crate::models::reduced_motion() {
+            self.cursor_blink_state = true;
+            return;
+        }
+
         let now = Instant::now();
         if now.duration_since(self.last_blink_time)
             >= Duration::from_millis(CURSOR_BLINK_INTERVAL_MS)
@@ -757,11 +774,10 @@ impl TerminalView {
             && self.cursor_blink_state
             && self.state.cursor_visible();
 
-        let theme = theme_colors();
-        let default_fg = theme.terminal_fg;
-        let cursor_bg = theme.terminal_cursor;
-        let cursor_fg = theme.terminal_bg;
-        let selection_bg = theme.terminal_selection;
+        let default_fg = self.palette.foreground;
+        let cursor_bg = self.palette.cursor;
+        let cursor_fg = self.palette.background;
+        let selection_bg = self.palette.selection;
 
         let has_selection = self.has_selection() && {
             let (start, end) = (self.selection_start.unwrap(), self.selection_end.unwrap());
@@ -863,6 +879,7 @@ impl Focusable for TerminalView {
 
 impl Render for TerminalView {
     fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        self.sync_palette();
         self.process_output();
 
         if self.is_visible && self.is_running() && !self.polling_started {
@@ -892,7 +909,7 @@ impl Render for TerminalView {
         }
 
         let theme = theme_colors();
-        let bg_color = theme.terminal_bg;
+        let bg_color = self.palette.background;
         let border_color = theme.border_default;
         let header_bg = theme.bg_tertiary;
         let text_muted = theme.text_muted;
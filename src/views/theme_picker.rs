@@ -2,9 +2,12 @@ use gpui::{
     div, prelude::*, px, App, Context, FocusHandle, Focusable, InteractiveElement, IntoElement,
     MouseButton, ParentElement, Render, Styled, Window,
 };
-use std::time::{Duration, Instant};
+use std::time::Duration;
 
-use crate::models::{theme_colors, Theme, ThemeColors, ThemeId};
+use crate::models::{
+    accent_override, set_accent_override, theme_colors, Theme, ThemeColors, ThemeId, Transition,
+    ACCENT_HUE_PRESETS,
+};
 
 
 pub type OnThemeSelect = Box<dyn Fn(ThemeId) + 'static>;
@@ -14,8 +17,7 @@ pub type OnThemeSelect = Box<dyn Fn(ThemeId) + 'static>;
 struct TransitionState {
     from_theme: Option<ThemeId>,
     to_theme: ThemeId,
-    start_time: Instant,
-    duration: Duration,
+    timing: Transition,
 }
 
 impl TransitionState {
@@ -23,8 +25,7 @@ impl TransitionState {
         Self {
             from_theme: None,
             to_theme,
-            start_time: Instant::now(),
-            duration: Duration::from_millis(250),
+            timing: Transition::new(Duration::from_millis(250)),
         }
     }
 
@@ -34,12 +35,15 @@ impl TransitionState {
     }
 
     fn progress(&self) -> f32 {
-        let elapsed = self.start_time.elapsed();
-        (elapsed.as_secs_f32() / self.duration.as_secs_f32()).min(1.0)
+        self.timing.progress()
+    }
+
+    fn eased_progress(&self) -> f32 {
+        self.timing.eased_progress()
     }
 
     fn is_complete(&self) -> bool {
-        self.progress() >= 1.0
+        self.timing.is_complete()
     }
 }
 
@@ -51,6 +55,7 @@ pub struct ThemePickerView {
     transition: Option<TransitionState>,
     on_theme_select: Option<OnThemeSelect>,
     hovered_theme: Option<ThemeId>,
+    accent_hue: Option<f32>,
 }
 
 
@@ -65,6 +70,7 @@ impl ThemePickerView {
             transition: None,
             on_theme_select: None,
             hovered_theme: None,
+            accent_hue: accent_override(),
         }
     }
 
@@ -117,12 +123,100 @@ impl ThemePickerView {
     }
 
 
+    pub fn accent_hue(&self) -> Option<f32> {
+        self.accent_hue
+    }
+
+
+    pub fn set_accent_hue(&mut self, hue: Option<f32>, cx: &mut Context<Self>) {
+        if self.accent_hue != hue {
+            self.accent_hue = hue;
+            set_accent_override(hue);
+            cx.notify();
+        }
+    }
+
+
     fn set_hovered_theme(&mut self, theme_id: Option<ThemeId>, cx: &mut Context<Self>) {
         if self.hovered_theme != theme_id {
             self.hovered_theme = theme_id;
             cx.notify();
         }
     }
+
+    fn render_accent_picker(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        let colors = theme_colors();
+        let base = Theme::from_id(self.selected_theme).colors;
+        let active_hue = self.accent_hue;
+
+        div()
+            .px_6()
+            .py_3()
+            .border_t_1()
+            .border_color(colors.border_subtle)
+            .flex()
+            .items_center()
+            .gap_3()
+            .child(
+                div()
+                    .text_sm()
+                    .text_color(colors.text_secondary)
+                    .child("Accent"),
+            )
+            .child(
+                div()
+                    .flex()
+                    .items_center()
+                    .gap_2()
+                    .children(ACCENT_HUE_PRESETS.iter().enumerate().map(|(idx, &hue)| {
+                        let is_active = active_hue == Some(hue);
+                        div()
+                            .id(("accent-hue", idx))
+                            .w(px(18.0))
+                            .h(px(18.0))
+                            .rounded_full()
+                            .cursor_pointer()
+                            .bg(base.clone().with_accent_hue(hue).accent_primary)
+                            .border_2()
+                            .border_color(if is_active {
+                                colors.text_primary
+                            } else {
+                                colors.border_default
+                            })
+                            .hover(|s| s.border_color(colors.border_emphasis))
+                            .on_mouse_down(
+                                MouseButton::Left,
+                                cx.listener(move |view, _, _, cx| {
+                                    view.set_accent_hue(Some(hue), cx);
+                                }),
+                            )
+                    })),
+            )
+            .child(
+                div()
+                    .id("reset-accent")
+                    .ml_auto()
+                    .px_2()
+                    .py_1()
+                    .rounded_md()
+                    .text_xs()
+                    .text_color(if active_hue.is_some() {
+                        colors.text_secondary
+                    } else {
+                        colors.text_muted
+                    })
+                    .when(active_hue.is_some(), |s| {
+                        s.cursor_pointer().hover(|s| s.bg(colors.bg_hover))
+                    })
+                    .on_mouse_down(
+                        MouseButton::Left,
+                        cx.listener(|view, _, _, cx| {
+                            view.set_accent_hue(None, cx);
+                        }),
+                    )
+                    .child("Reset accent"),
+            )
+    }
 }
 
 
@@ -555,8 +649,10 @@ impl Render for ThemePickerView {
         let is_transitioning = transition_progress < 1.0;
 
         let crossfade_opacity = if is_transitioning {
-            let t = transition_progress;
-            1.0 - (1.0 - t).powi(3)
+            self.transition
+                .as_ref()
+                .map(|t| t.eased_progress())
+                .unwrap_or(1.0)
         } else {
             1.0
         };
@@ -583,7 +679,7 @@ impl Render for ThemePickerView {
                     .border_color(current_theme.border_default)
                     .overflow_hidden()
                     .when(is_transitioning, |s| {
-                        s.opacity(0.95 + 0.05 * crossfade_opacity)
+                        s.opacity((0.95 + 0.05 * crossfade_opacity).min(1.0))
                     })
                     .child(
                         div()
@@ -665,6 +761,7 @@ impl Render for ThemePickerView {
                                             .child(render_theme_card_animated(theme, is_selected, false, &current_theme, is_transitioning && is_selected, crossfade_opacity))
                                     }))
                             )
+                            .child(self.render_accent_picker(cx))
                             .child(
                                 div()
                                     .px_6()
@@ -685,7 +782,7 @@ impl Render for ThemePickerView {
                                                     .w(px(8.0))
                                                     .h(px(8.0))
                                                     .rounded_full()
-                                                    .bg(Theme::from_id(selected).colors.accent_primary)
+                                                    .bg(current_theme.accent_primary)
                                             )
                                             .child(
                                                 div()
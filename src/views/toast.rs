@@ -118,6 +118,25 @@ impl ToastManager {
         self.show(toast, cx);
     }
 
+    pub fn show_progress(&mut self, title: impl Into<SharedString>, cx: &mut Context<Self>) -> u64 {
+        let id = self.next_id();
+        self.toasts.push(Toast::new(id, title));
+        cx.notify();
+        id
+    }
+
+    pub fn set_description(
+        &mut self,
+        id: u64,
+        description: impl Into<SharedString>,
+        cx: &mut Context<Self>,
+    ) {
+        if let Some(toast) = self.toasts.iter_mut().find(|t| t.id == id) {
+            toast.description = Some(description.into());
+            cx.notify();
+        }
+    }
+
     pub fn dismiss(&mut self, id: u64, cx: &mut Context<Self>) {
         self.toasts.retain(|t| t.id != id);
         cx.notify();
@@ -1,5 +1,5 @@
 use file_explorer::models::{
-    DateFilter, FileEntry, FileType, IconKey, SearchQuery, SizeFilter,
+    DateFilter, FileEntry, FileType, HiddenKind, IconKey, SearchQuery, SizeFilter,
     SmartFolderManager, TagId, CloudSyncStatus,
 };
 use std::collections::HashSet;
@@ -31,6 +31,7 @@ fn create_test_entry(name: &str, is_dir: bool, size: u64) -> FileEntry {
         symlink_target: None,
         is_broken_symlink: false,
         is_shared: false,
+        hidden_kind: HiddenKind::Visible,
     }
 }
 
@@ -52,6 +53,7 @@ fn create_entry_with_extension(name: &str, ext: &str, size: u64) -> FileEntry {
         symlink_target: None,
         is_broken_symlink: false,
         is_shared: false,
+        hidden_kind: HiddenKind::Visible,
     }
 }
 